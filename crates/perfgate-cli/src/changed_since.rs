@@ -0,0 +1,106 @@
+//! Change-aware bench selection for `perfgate check --changed-since`.
+//!
+//! The changed files are everything that differs between the merge-base of
+//! `HEAD` and the given ref and the working tree, plus untracked files. Benches
+//! whose `paths` match none of them are skipped; each still gets a
+//! `report.json` and `comment.md` recording why.
+
+use crate::repair_context::{run_git_capture, run_git_capture_bytes};
+use crate::{remove_stale_compare_file, write_json};
+use anyhow::Context;
+use perfgate::app::changed_paths::{
+    ChangeDecision, build_skipped_report, decide_bench_for_changes, render_skipped_markdown,
+};
+use perfgate_types::ConfigFile;
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+
+/// Selected benches split into the ones to run and the ones skipped.
+pub(crate) struct ChangedBenchPlan {
+    pub run: Vec<String>,
+    /// Skipped bench names with the reason.
+    pub skipped: Vec<(String, String)>,
+}
+
+/// Split `bench_names` by the files changed since `since`. Without `since`,
+/// every bench runs.
+pub(crate) fn plan_changed_benches(
+    config: &ConfigFile,
+    bench_names: Vec<String>,
+    since: Option<&str>,
+) -> anyhow::Result<ChangedBenchPlan> {
+    let Some(since) = since else {
+        return Ok(ChangedBenchPlan {
+            run: bench_names,
+            skipped: Vec::new(),
+        });
+    };
+
+    let changed = changed_files_since(since)?;
+    let mut plan = ChangedBenchPlan {
+        run: Vec::new(),
+        skipped: Vec::new(),
+    };
+    for name in bench_names {
+        let bench = config
+            .benches
+            .iter()
+            .find(|bench| bench.name == name)
+            .with_context(|| format!("bench '{}' not found in config", name))?;
+        match decide_bench_for_changes(bench, &changed, since)? {
+            ChangeDecision::Run(_) => plan.run.push(name),
+            ChangeDecision::Skip(reason) => {
+                eprintln!("note: [{}] skipped: {}", name, reason);
+                plan.skipped.push((name, reason));
+            }
+        }
+    }
+    Ok(plan)
+}
+
+/// Repository-relative paths changed since the merge-base of `HEAD` and
+/// `reference`, including uncommitted and untracked files.
+fn changed_files_since(reference: &str) -> anyhow::Result<Vec<String>> {
+    let merge_base = run_git_capture(&["merge-base", "HEAD", reference])
+        .with_context(|| format!("could not compute merge-base of HEAD and {reference}"))?;
+    let diff = run_git_capture_bytes(&["diff", "--name-only", "-z", &merge_base])
+        .with_context(|| format!("could not list files changed since {reference}"))?;
+    let untracked = run_git_capture_bytes(&[
+        "ls-files",
+        "--others",
+        "--exclude-standard",
+        "--full-name",
+        "-z",
+    ])
+    .context("could not list untracked files")?;
+
+    let files: BTreeSet<String> = diff
+        .split(|byte| *byte == b'\0')
+        .chain(untracked.split(|byte| *byte == b'\0'))
+        .filter(|entry| !entry.is_empty())
+        .map(|entry| String::from_utf8_lossy(entry).into_owned())
+        .collect();
+    Ok(files.into_iter().collect())
+}
+
+/// Write the skip report and comment for a bench skipped by `--changed-since`.
+pub(crate) fn write_skipped_bench(
+    out_dir: &Path,
+    bench_name: &str,
+    reason: &str,
+    pretty: bool,
+) -> anyhow::Result<()> {
+    fs::create_dir_all(out_dir)
+        .with_context(|| format!("create output dir {}", out_dir.display()))?;
+    remove_stale_compare_file(&out_dir.join("compare.json"))?;
+
+    write_json(
+        &out_dir.join("report.json"),
+        &build_skipped_report(reason),
+        pretty,
+    )?;
+    let markdown_path = out_dir.join("comment.md");
+    fs::write(&markdown_path, render_skipped_markdown(bench_name, reason))
+        .with_context(|| format!("write {}", markdown_path.display()))
+}
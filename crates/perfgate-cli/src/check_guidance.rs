@@ -300,7 +300,8 @@ pub(super) fn classify_check_error(error: &anyhow::Error) -> FailureClass {
         FailureClass::SetupCommandFailed
     } else if message.contains("no benchmarks")
         || message.contains("not found in config")
-        || message.contains("either --bench or --all")
+        || message.contains("either --bench")
+        || message.contains("did not match any benchmarks")
     {
         FailureClass::SetupMissingBench
     } else if message.contains("baseline") {
@@ -0,0 +1,95 @@
+//! `perfgate config` subcommands.
+//!
+//! `config resolve` prints a config file with its `extends` chain and active
+//! profile merged in, together with the file, preset or profile each value
+//! came from.
+
+use anyhow::Context;
+use clap::{Args, Subcommand, ValueEnum};
+use perfgate_types::config::{ResolvedConfig, resolve_config_file_with_profile};
+use perfgate_types::error::ConfigValidationError;
+use serde_json::json;
+use std::path::PathBuf;
+
+#[derive(Debug, Subcommand)]
+pub enum ConfigAction {
+    /// Print the fully merged config and where each value came from.
+    ///
+    /// Follows `extends` entries (bundled `preset:<name>` presets and relative
+    /// config paths), applies the active `[profile.<name>]` and validates the
+    /// merged result.
+    Resolve(ConfigResolveArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct ConfigResolveArgs {
+    /// Path to the config file (TOML or JSON).
+    #[arg(long, default_value = "perfgate.toml")]
+    pub config: PathBuf,
+
+    /// Config profile to apply (`[profile.<name>]`). Defaults to $PERFGATE_PROFILE,
+    /// then `ci` under CI or `local` elsewhere when the config defines it.
+    #[arg(long = "profile", value_name = "NAME")]
+    pub config_profile: Option<String>,
+
+    /// Output format.
+    #[arg(long, value_enum, default_value_t = ConfigResolveFormat::Toml)]
+    pub format: ConfigResolveFormat,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ConfigResolveFormat {
+    /// Merged TOML with sources listed in comments.
+    Toml,
+    /// JSON object with `config`, `sources`, `provenance` and `profile`.
+    Json,
+}
+
+pub(crate) fn execute_config_action(action: ConfigAction) -> anyhow::Result<()> {
+    match action {
+        ConfigAction::Resolve(args) => execute_config_resolve(args),
+    }
+}
+
+fn execute_config_resolve(args: ConfigResolveArgs) -> anyhow::Result<()> {
+    let resolved = resolve_config_file_with_profile(&args.config, &args.config_profile.into())
+        .with_context(|| format!("resolve config {}", args.config.display()))?;
+    resolved
+        .config
+        .validate()
+        .map_err(ConfigValidationError::ConfigFile)?;
+
+    match args.format {
+        ConfigResolveFormat::Toml => print!("{}", render_resolved_toml(&resolved)?),
+        ConfigResolveFormat::Json => {
+            let value = json!({
+                "config": resolved.config,
+                "sources": resolved.sources,
+                "provenance": resolved.provenance,
+                "profile": resolved.profile,
+            });
+            println!("{}", serde_json::to_string_pretty(&value)?);
+        }
+    }
+    Ok(())
+}
+
+/// Render the merged config as TOML, bracketed by comments listing the merge
+/// order and the source of every value.
+fn render_resolved_toml(resolved: &ResolvedConfig) -> anyhow::Result<String> {
+    let mut out = String::from("# Resolved from (lowest precedence first):\n");
+    for source in &resolved.sources {
+        out.push_str(&format!("#   {}\n", source));
+    }
+    if let Some(profile) = &resolved.profile {
+        out.push_str(&format!("# Active profile: {}\n", profile));
+    }
+    out.push('\n');
+    out.push_str(&toml::to_string_pretty(&resolved.config).context("serialize merged config")?);
+
+    out.push_str("\n# Value sources:\n");
+    for (path, source) in &resolved.provenance {
+        out.push_str(&format!("#   {} <- {}\n", path, source));
+    }
+    Ok(out)
+}
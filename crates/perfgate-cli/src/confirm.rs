@@ -0,0 +1,190 @@
+//! Confirmation re-measurement for `perfgate check --confirm`.
+//!
+//! When a bench warns or fails, it is measured once more before the verdict is
+//! finalized, either by re-running the check against the same baseline or by a
+//! paired run against a baseline command. The confirmation evidence is written
+//! under `<bench out dir>/confirm/` next to the original artifacts, and the
+//! outcome keeps a regression only when the confirmation reproduces it.
+
+use crate::{tool_info, write_check_artifacts, write_json};
+use anyhow::Context;
+use perfgate::app::{
+    CheckOutcome, CheckRequest, CheckUseCase, ConfirmationEvidence, PairedRunRequest,
+    PairedRunUseCase, SystemClock, apply_check_confirmation,
+};
+use perfgate::runtime::{StdHostProbe, StdProcessRunner};
+use perfgate_types::VerdictStatus;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Sub-directory of a bench's output directory holding confirmation evidence.
+const CONFIRM_DIR: &str = "confirm";
+
+/// How `check --confirm` re-measures a flagged bench.
+#[derive(Debug, Clone)]
+pub(crate) enum ConfirmStrategy {
+    /// Run the check again against the same baseline.
+    Rerun,
+    /// Paired run against an explicit baseline command.
+    PairedCommand(Vec<String>),
+    /// Paired run against the bench command with its program replaced.
+    PairedBinary(PathBuf),
+}
+
+impl ConfirmStrategy {
+    /// Build the strategy from `--confirm`, `--confirm-baseline-cmd` and
+    /// `--confirm-baseline-bin`.
+    pub(crate) fn from_args(
+        confirm: bool,
+        baseline_cmd: Option<String>,
+        baseline_bin: Option<PathBuf>,
+    ) -> anyhow::Result<Option<Self>> {
+        if !confirm {
+            return Ok(None);
+        }
+        Ok(Some(match (baseline_cmd, baseline_bin) {
+            (Some(cmd), _) => {
+                let argv = shell_words::split(&cmd)
+                    .with_context(|| format!("failed to parse confirm baseline command: {cmd}"))?;
+                if argv.is_empty() {
+                    anyhow::bail!("--confirm-baseline-cmd must not be empty");
+                }
+                Self::PairedCommand(argv)
+            }
+            (None, Some(bin)) => Self::PairedBinary(bin),
+            (None, None) => Self::Rerun,
+        }))
+    }
+}
+
+/// Re-measure a bench whose check warned or failed and fold the result into
+/// `outcome`. Passing, skipped, and no-baseline outcomes are left untouched.
+pub(crate) fn confirm_check_outcome(
+    outcome: &mut CheckOutcome,
+    strategy: &ConfirmStrategy,
+    request: CheckRequest,
+    pretty: bool,
+) -> anyhow::Result<()> {
+    let Some(status) = outcome
+        .compare_receipt
+        .as_ref()
+        .map(|compare| compare.verdict.status)
+    else {
+        return Ok(());
+    };
+    if !matches!(status, VerdictStatus::Warn | VerdictStatus::Fail) {
+        return Ok(());
+    }
+
+    let bench = &outcome.run_receipt.bench;
+    if !matches!(strategy, ConfirmStrategy::Rerun)
+        && (bench.service.is_some() || bench.load.is_some())
+    {
+        eprintln!(
+            "warning: [{}] paired confirmation does not support service or load benches; keeping the unconfirmed result",
+            request.bench_name
+        );
+        return Ok(());
+    }
+
+    let bench_name = request.bench_name.clone();
+    let confirm_dir = request.out_dir.join(CONFIRM_DIR);
+    std::fs::create_dir_all(&confirm_dir)
+        .with_context(|| format!("create confirmation dir {}", confirm_dir.display()))?;
+
+    match strategy {
+        ConfirmStrategy::Rerun => {
+            eprintln!(
+                "note: [{}] regression flagged; re-running to confirm",
+                bench_name
+            );
+            let fail_on_warn = request.fail_on_warn;
+            let usecase = CheckUseCase::new(StdProcessRunner, StdHostProbe, SystemClock);
+            let rerun = usecase
+                .execute(CheckRequest {
+                    out_dir: confirm_dir,
+                    ..request
+                })
+                .with_context(|| format!("confirmation re-run of bench '{bench_name}'"))?;
+            write_check_artifacts(&rerun, pretty)?;
+
+            let Some(compare) = &rerun.compare_receipt else {
+                return Ok(());
+            };
+            apply_check_confirmation(
+                outcome,
+                ConfirmationEvidence::Rerun(compare),
+                ["run.json", "compare.json", "report.json"]
+                    .iter()
+                    .map(|name| format!("{CONFIRM_DIR}/{name}"))
+                    .collect(),
+                fail_on_warn,
+            );
+        }
+        ConfirmStrategy::PairedCommand(argv) => {
+            confirm_paired(outcome, argv.clone(), &request, &confirm_dir, pretty)?;
+        }
+        ConfirmStrategy::PairedBinary(bin) => {
+            let Some((_, args)) = outcome.run_receipt.bench.command.split_first() else {
+                anyhow::bail!("bench '{bench_name}' has an empty command");
+            };
+            let mut argv = vec![bin.to_string_lossy().into_owned()];
+            argv.extend(args.iter().cloned());
+            confirm_paired(outcome, argv, &request, &confirm_dir, pretty)?;
+        }
+    }
+    Ok(())
+}
+
+/// Run the bench interleaved with `baseline_command` and fold the paired
+/// result into `outcome`.
+fn confirm_paired(
+    outcome: &mut CheckOutcome,
+    baseline_command: Vec<String>,
+    request: &CheckRequest,
+    confirm_dir: &Path,
+    pretty: bool,
+) -> anyhow::Result<()> {
+    let bench = &outcome.run_receipt.bench;
+    eprintln!(
+        "note: [{}] regression flagged; running a paired confirmation",
+        request.bench_name
+    );
+    let usecase = PairedRunUseCase::new(StdProcessRunner, StdHostProbe, SystemClock, tool_info());
+    let paired = usecase
+        .execute(PairedRunRequest {
+            name: request.bench_name.clone(),
+            cwd: bench.cwd.as_ref().map(PathBuf::from),
+            baseline_command,
+            current_command: bench.command.clone(),
+            repeat: bench.repeat,
+            warmup: bench.warmup,
+            work_units: bench.work_units,
+            timeout: bench.timeout_ms.map(Duration::from_millis),
+            env: request.env.clone(),
+            output_cap_bytes: request.output_cap_bytes,
+            allow_nonzero: request.allow_nonzero,
+            include_hostname_hash: false,
+            significance_alpha: request.significance_alpha,
+            significance_min_samples: Some(request.significance_min_samples),
+            require_significance: false,
+            max_retries: 0,
+            fail_on_regression: None,
+            cv_threshold: None,
+            cpu_affinity: request
+                .concurrency
+                .as_ref()
+                .map(|concurrency| concurrency.cpus.clone())
+                .unwrap_or_default(),
+        })
+        .with_context(|| format!("paired confirmation of bench '{}'", request.bench_name))?;
+    write_json(&confirm_dir.join("paired.json"), &paired.receipt, pretty)?;
+
+    apply_check_confirmation(
+        outcome,
+        ConfirmationEvidence::Paired(&paired.receipt),
+        vec![format!("{CONFIRM_DIR}/paired.json")],
+        request.fail_on_warn,
+    );
+    Ok(())
+}
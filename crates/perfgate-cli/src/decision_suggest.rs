@@ -565,6 +565,16 @@ mod tests {
                     metrics: None,
                     budgets: None,
                     scaling: None,
+                    matrix: None,
+                    matrix_overrides: None,
+                    service: None,
+                    tags: Vec::new(),
+                    paths: Vec::new(),
+                    always: false,
+                    load: None,
+                    ready: None,
+                    ready_timeout: None,
+                    terminate_on_ready: false,
                 })
                 .collect(),
             ..Default::default()
@@ -708,14 +718,18 @@ mod tests {
                 warmup: 0,
                 work_units: None,
                 timeout_ms: None,
+                service: None,
+                load: None,
             },
             baseline_ref: CompareRef {
                 path: Some("baseline.json".into()),
                 run_id: Some("base".into()),
+                profile: None,
             },
             current_ref: CompareRef {
                 path: Some("current.json".into()),
                 run_id: Some("cur".into()),
+                profile: None,
             },
             budgets: BTreeMap::<Metric, Budget>::new(),
             deltas,
@@ -6,7 +6,7 @@
 use crate::{
     COMPARE_RECEIPT_FILE, CalibrateArgs, DoctorArgs, RUN_RECEIPT_FILE, ServerFlags,
     SignalDoctorArgs, check_command, load_optional_baseline_receipt, paired_command, read_json,
-    resolve_configured_out_dir, run_git_capture, with_tokio_runtime,
+    resolve_configured_out_dir, run_git_capture, select_tagged_bench_names, with_tokio_runtime,
 };
 use chrono::{DateTime, Utc};
 use perfgate::app::baseline_resolve::{is_remote_storage_uri, resolve_baseline_path};
@@ -14,7 +14,9 @@ use perfgate::app::init::{CiPlatform, ci_workflow_path};
 use perfgate_client::{BaselineClient, ClientConfig, RetryConfig};
 use perfgate_types::config::load_config_file;
 use perfgate_types::error::ConfigValidationError;
-use perfgate_types::{CompareReceipt, ConfigFile, Metric, RunReceipt};
+use perfgate_types::{
+    BenchConfigFile, BudgetThreshold, CompareReceipt, ConfigFile, Metric, RunReceipt,
+};
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::time::Duration;
@@ -275,26 +277,49 @@ pub(crate) fn execute_calibrate(args: CalibrateArgs) -> anyhow::Result<()> {
     config
         .validate()
         .map_err(ConfigValidationError::ConfigFile)?;
-    let bench = config
-        .benches
-        .iter()
-        .find(|bench| bench.name == args.bench)
-        .ok_or_else(|| {
-            ConfigValidationError::BenchName(format!("bench '{}' not found in config", args.bench))
-        })?;
+    let bench_names = match &args.bench {
+        Some(name) => vec![name.clone()],
+        None => select_tagged_bench_names(&config, &args.tag_selection.clone().into_filter())?,
+    };
 
-    let out_dir = resolve_configured_out_dir(args.out_dir.as_ref(), Some(&config));
+    for (index, bench_name) in bench_names.iter().enumerate() {
+        if index > 0 {
+            println!();
+        }
+        let bench = config
+            .benches
+            .iter()
+            .find(|bench| &bench.name == bench_name)
+            .ok_or_else(|| {
+                ConfigValidationError::BenchName(format!(
+                    "bench '{}' not found in config",
+                    bench_name
+                ))
+            })?;
+        calibrate_bench(&args, &config, bench)?;
+    }
+    Ok(())
+}
+
+/// Print calibration guidance for one configured bench.
+fn calibrate_bench(
+    args: &CalibrateArgs,
+    config: &ConfigFile,
+    bench: &BenchConfigFile,
+) -> anyhow::Result<()> {
+    let bench_name = &bench.name;
+    let out_dir = resolve_configured_out_dir(args.out_dir.as_ref(), Some(config));
     let run_path = args
         .run
         .clone()
-        .or_else(|| find_calibration_run_path(&out_dir, &args.bench));
+        .or_else(|| find_calibration_run_path(&out_dir, bench_name));
     let run_receipt = run_path
         .as_ref()
         .filter(|path| path.exists())
         .map(|path| read_json::<RunReceipt>(path))
         .transpose()?;
 
-    let baseline_path = resolve_baseline_path(&args.baseline, &args.bench, &config);
+    let baseline_path = resolve_baseline_path(&args.baseline, bench_name, config);
     let baseline_receipt = load_optional_baseline_receipt(&baseline_path)?;
     let evidence_receipt = run_receipt.as_ref().or(baseline_receipt.as_ref());
     let cv = run_receipt
@@ -308,12 +333,12 @@ pub(crate) fn execute_calibrate(args: CalibrateArgs) -> anyhow::Result<()> {
     let sample_count = evidence_receipt
         .map(measured_sample_count)
         .unwrap_or_default();
-    let configured_threshold = configured_wall_threshold(&config, bench);
+    let configured_threshold = configured_wall_threshold(config, bench);
     let suggestion = suggest_calibration(cv, sample_count, configured_threshold);
 
     println!("perfgate calibrate");
     println!();
-    println!("Bench: {}", args.bench);
+    println!("Bench: {}", bench_name);
     if sample_count == 0 {
         println!("Samples: unavailable");
     } else {
@@ -388,14 +413,11 @@ pub(crate) fn execute_calibrate(args: CalibrateArgs) -> anyhow::Result<()> {
     println!();
     println!("Next:");
     if run_receipt.is_none() {
-        println!(
-            "  {}",
-            check_command(&args.config, Some(&args.bench), false)
-        );
+        println!("  {}", check_command(&args.config, Some(bench_name), false));
     }
-    println!("  {}", check_command(&args.config, Some(&args.bench), true));
+    println!("  {}", check_command(&args.config, Some(bench_name), true));
     if suggestion.suggest_paired {
-        println!("  {}", paired_command(Some(&args.bench)));
+        println!("  {}", paired_command(Some(bench_name)));
     }
     println!("Do not:");
     println!("  do not auto-edit thresholds from this advisory output; review the benchmark first");
@@ -482,7 +504,7 @@ fn configured_wall_threshold(config: &ConfigFile, bench: &perfgate_types::BenchC
         .budgets
         .as_ref()
         .and_then(|budgets| budgets.get(&Metric::WallMs))
-        .and_then(|budget| budget.threshold)
+        .and_then(|budget| budget.threshold.and_then(BudgetThreshold::fixed))
         .or(config.defaults.threshold)
         .unwrap_or(0.20)
 }
@@ -3,16 +3,22 @@
 mod artifact_explain;
 mod baseline;
 mod baseline_doctor;
+mod changed_since;
 mod check_guidance;
 mod cli_parsing;
+mod config_resolve;
+mod confirm;
 mod decision_suggest;
 mod doctor;
 mod init;
 mod ledger_doctor;
+mod merge_base;
+mod parallel;
 mod policy;
 mod probe_templates;
 mod repair_context;
 mod storage;
+mod threshold_history;
 
 use storage::{
     atomic_write, load_optional_baseline_receipt, location_exists, read_json,
@@ -23,23 +29,34 @@ use anyhow::Context;
 use artifact_explain::execute_explain_action;
 use baseline::{BaselineSelector, parse_baseline_selector};
 use baseline_doctor::execute_baseline_doctor;
+use changed_since::{plan_changed_benches, write_skipped_bench};
 use check_guidance::{
     FailureClass, check_command, classify_check_error, emit_check_outcome_guidance, paired_command,
     print_check_failure_guidance,
 };
 use clap::{Args, Parser, Subcommand, ValueEnum};
 use cli_parsing::*;
+use config_resolve::{ConfigAction, execute_config_action};
+use confirm::{ConfirmStrategy, confirm_check_outcome};
 use decision_suggest::execute_decision_suggest;
 use glob::glob;
 use init::execute_init;
 use ledger_doctor::execute_ledger_action;
+use merge_base::MergeBaseSearch;
 use perfgate::app as perfgate_app;
+use perfgate::app::changed_paths::{build_skipped_report, render_skipped_markdown};
+use perfgate::app::interleave::{ReplayRunner, run_interleaved_samples};
 use perfgate::domain as perfgate_domain;
 use perfgate::integrations::github::{self, CommentOptions, GitHubClient};
 use perfgate::integrations::ingest::{self, IngestFormat};
 use perfgate::runtime::profile::{ProfileRequest, capture_flamegraph};
-use perfgate::runtime::{HostProbe, HostProbeOptions, StdHostProbe, StdProcessRunner};
-use perfgate_app::baseline_resolve::{is_remote_storage_uri, resolve_baseline_path};
+use perfgate::runtime::{
+    HostProbe, HostProbeOptions, ProcessRunner, StdHostProbe, StdProcessRunner,
+};
+use perfgate_app::baseline_resolve::{
+    DEFAULT_MERGE_BASE_SEARCH_DEPTH, is_remote_storage_uri, render_history_baseline_path,
+    resolve_baseline_path, resolve_merge_base_target,
+};
 use perfgate_app::comparison_logic::{build_budgets, build_metric_statistics, verdict_from_counts};
 use perfgate_app::render::summary::{SummaryRequest, SummaryUseCase};
 use perfgate_app::{
@@ -49,9 +66,11 @@ use perfgate_app::{
     ExportUseCase, PairedRunRequest, PairedRunUseCase, ProbeCompareRequest, ProbeCompareUseCase,
     PromoteRequest, PromoteUseCase, RatchetUseCase, ReportRequest, ReportUseCase, RunBenchRequest,
     RunBenchUseCase, ScenarioEvaluateInput, ScenarioEvaluateRequest, ScenarioUseCase,
-    SensorReportBuilder, SystemClock, TradeoffEvaluateRequest, TradeoffUseCase, classify_error,
+    SensorReportBuilder, SuiteCompareRequest, SuiteCompareUseCase, SystemClock,
+    TradeoffEvaluateRequest, TradeoffUseCase, apply_check_false_discovery_rate, classify_error,
     github_annotations, is_host_mismatch_reason, preview_lines, render_json_diff, render_markdown,
-    render_markdown_template, render_terminal_diff, render_tradeoff_markdown,
+    render_markdown_template, render_suite_markdown, render_terminal_diff,
+    render_tradeoff_markdown,
     watch::{Debouncer, WatchRunRequest, WatchState, execute_watch_run, render_watch_display},
 };
 use perfgate_client::types::auth::Role;
@@ -67,18 +86,20 @@ use perfgate_domain::scaling::{
 };
 use perfgate_domain::{DependencyChangeType, SignificancePolicy};
 use perfgate_types::config::{
-    apply_ratchet_toml_changes, load_config_file, preview_ratchet_toml_changes,
+    ProfileSelection, apply_ratchet_toml_changes, load_config_file, preview_ratchet_toml_changes,
+    resolve_config_str_with_profile,
 };
 use perfgate_types::error::{ConfigValidationError, IoError, PerfgateError};
 use perfgate_types::fingerprint::sha256_hex;
 use perfgate_types::{
     AggregateWeightMode, AggregationPolicy, BASELINE_REASON_NO_BASELINE, BaselineServerConfig,
-    CompareReceipt, CompareRef, ConfigFile, DECISION_BUNDLE_SCHEMA_V1, DECISION_INDEX_SCHEMA_V1,
-    DecisionArtifactIndex, DecisionBundleArtifact, DecisionBundleArtifactContent,
-    DecisionBundleArtifactKind, DecisionBundleMetadata, DecisionBundleReceipt, HostMismatchPolicy,
-    MetricStatus, PerfgateReport, ProbeCompareReceipt, ProbeReceipt, RatchetConfig, RunReceipt,
-    ScenarioConfigFile, ScenarioReceipt, SensorVerdictStatus, ToolInfo, TradeoffReceipt,
-    VerdictStatus,
+    BenchTagFilter, CompareReceipt, CompareRef, ConfigFile, DECISION_BUNDLE_SCHEMA_V1,
+    DECISION_INDEX_SCHEMA_V1, DecisionArtifactIndex, DecisionBundleArtifact,
+    DecisionBundleArtifactContent, DecisionBundleArtifactKind, DecisionBundleMetadata,
+    DecisionBundleReceipt, HostMismatchPolicy, MergeBaseSelection, MetricStatus, PerfgateReport,
+    ProbeCompareReceipt, ProbeReceipt, RUN_BUNDLE_SCHEMA_V1, RatchetConfig, RunBundle, RunReceipt,
+    SampleInterleave, ScenarioConfigFile, ScenarioReceipt, SensorVerdictStatus, ToolInfo,
+    TradeoffReceipt, VerdictStatus,
 };
 use policy::{PolicyAction, execute_policy_action};
 use regex::Regex;
@@ -93,6 +114,7 @@ use std::process::Command as ProcessCommand;
 use std::process::ExitCode;
 use std::sync::Arc;
 use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use threshold_history::load_threshold_history;
 
 use doctor::{
     DoctorCheck, DoctorStatus, execute_calibrate, execute_doctor, execute_signal_doctor, plural,
@@ -356,6 +378,12 @@ enum Command {
         action: ProbeAction,
     },
 
+    /// Compare whole suites (directories or bundles) of run receipts.
+    Suite {
+        #[command(subcommand)]
+        action: SuiteAction,
+    },
+
     /// Evaluate configured workload scenarios from compare receipts.
     Scenario {
         #[command(subcommand)]
@@ -376,8 +404,11 @@ enum Command {
 
     /// Wrap `cargo bench` and produce perfgate run receipts.
     ///
-    /// Auto-detects Criterion or libtest bench output. Scans `target/criterion/`
-    /// for Criterion JSON, or parses libtest bench output format.
+    /// Auto-detects Criterion, libtest, divan, and iai-callgrind bench output.
+    /// Scans `target/criterion/` for Criterion JSON and parses the other
+    /// formats from the bench output. iai-callgrind counters are recorded as
+    /// the deterministic `instructions`, `estimated_cycles`, `l1_hits`,
+    /// `ll_hits`, and `ram_hits` metrics.
     ///
     /// Exit codes: 0 for success, 1 for errors.
     CargoBench(Box<CargoBenchArgs>),
@@ -475,6 +506,12 @@ enum Command {
     /// dashboard in the default browser unless --no-open is passed.
     Serve(Box<ServeArgs>),
 
+    /// Inspect how a config file resolves after `extends` merging.
+    Config {
+        #[command(subcommand)]
+        action: ConfigAction,
+    },
+
     /// Inspect optional decision-ledger readiness without making it required.
     Ledger {
         #[command(subcommand)]
@@ -678,9 +715,12 @@ pub struct BlameArgs {
 #[derive(Debug, Args)]
 pub struct DiffArgs {
     /// Run only a specific benchmark (must match a [[bench]] in config).
-    #[arg(long)]
+    #[arg(long, conflicts_with_all = ["tags", "exclude_tags"])]
     pub bench: Option<String>,
 
+    #[command(flatten)]
+    pub tag_selection: BenchTagArgs,
+
     /// Compare against a specific git ref (commit or branch). Reserved for future use.
     #[arg(long)]
     pub against: Option<String>,
@@ -1065,14 +1105,40 @@ pub struct ReportArgs {
     pub pretty: bool,
 }
 
+/// Tag-based benchmark selection shared by commands that pick benches from the config.
+#[derive(Debug, Clone, Default, Args)]
+pub struct BenchTagArgs {
+    /// Select benchmarks with this tag (repeatable; a benchmark with any of the tags matches)
+    #[arg(long = "tag", value_name = "TAG")]
+    pub tags: Vec<String>,
+
+    /// Skip benchmarks with this tag (repeatable)
+    #[arg(long = "exclude-tag", value_name = "TAG")]
+    pub exclude_tags: Vec<String>,
+}
+
+impl BenchTagArgs {
+    fn into_filter(self) -> BenchTagFilter {
+        BenchTagFilter {
+            include: self.tags,
+            exclude: self.exclude_tags,
+        }
+    }
+}
+
 #[derive(Debug, Args)]
 pub struct CheckArgs {
     /// Path to the config file (TOML or JSON)
     #[arg(long, default_value = "perfgate.toml")]
     pub config: PathBuf,
 
+    /// Config profile to apply (`[profile.<name>]`). Defaults to $PERFGATE_PROFILE,
+    /// then `ci` under CI or `local` elsewhere when the config defines it.
+    #[arg(long = "profile", value_name = "NAME")]
+    pub config_profile: Option<String>,
+
     /// Name of the benchmark to run (must match a [[bench]] in config)
-    #[arg(long, conflicts_with = "all")]
+    #[arg(long, conflicts_with_all = ["all", "tags", "exclude_tags"])]
     pub bench: Option<String>,
 
     /// Run all benchmarks defined in the config file
@@ -1083,14 +1149,44 @@ pub struct CheckArgs {
     #[arg(long, requires = "all")]
     pub bench_regex: Option<String>,
 
+    #[command(flatten)]
+    pub tag_selection: BenchTagArgs,
+
     /// Output directory for artifacts. Defaults to [defaults].out_dir or artifacts/perfgate.
     #[arg(long, value_name = "DIR")]
     pub out_dir: Option<PathBuf>,
 
     /// Path or cloud URI to the baseline file.
-    #[arg(long, conflicts_with = "all")]
+    #[arg(long, conflicts_with_all = ["all", "tags", "exclude_tags"])]
     pub baseline: Option<PathBuf>,
 
+    /// Compare against the baseline recorded for the merge-base of HEAD and this
+    /// branch (or its nearest ancestor with one). Defaults to [defaults].merge_base.
+    #[arg(long, value_name = "REF", conflicts_with = "baseline")]
+    pub merge_base: Option<String>,
+
+    /// Run only benches whose `paths` match files changed since the merge-base of
+    /// HEAD and REF; benches with `always = true` or no `paths` always run. Skipped
+    /// benches still get a report recording why.
+    #[arg(long, value_name = "REF")]
+    pub changed_since: Option<String>,
+
+    /// Run up to N benches concurrently, each pinned to its own disjoint set of
+    /// CPUs. Receipts record the concurrency so serial and parallel baselines
+    /// are flagged when compared.
+    #[arg(long, value_name = "N", default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+    pub jobs: u32,
+
+    /// Interleave the samples of the selected benches in a seeded random order
+    /// instead of running each bench's samples back to back. The seed is
+    /// recorded in every run receipt.
+    #[arg(long, default_value_t = false, conflicts_with = "jobs")]
+    pub interleave: bool,
+
+    /// Seed for --interleave; pass a recorded seed to replay a run's sample order.
+    #[arg(long, value_name = "SEED", requires = "interleave")]
+    pub seed: Option<u64>,
+
     /// Fail if baseline is missing (default: warn and continue)
     #[arg(long, default_value_t = false)]
     pub require_baseline: bool,
@@ -1135,6 +1231,31 @@ pub struct CheckArgs {
     #[arg(long, default_value_t = false)]
     pub require_significance: bool,
 
+    /// With --significance-alpha, apply a Benjamini–Hochberg false discovery rate
+    /// correction across every bench and metric of the run before computing verdicts.
+    #[arg(long, default_value_t = false, requires = "significance_alpha")]
+    pub fdr: bool,
+
+    /// Re-measure benches that warn or fail before finalizing the verdict. A
+    /// regression stands only when the confirmation measurement reproduces it.
+    #[arg(long, default_value_t = false)]
+    pub confirm: bool,
+
+    /// With --confirm, re-measure in paired mode against this baseline command
+    /// (shell string) instead of re-running the bench alone.
+    #[arg(
+        long,
+        value_name = "CMD",
+        requires = "confirm",
+        conflicts_with_all = ["confirm_baseline_bin", "all"]
+    )]
+    pub confirm_baseline_cmd: Option<String>,
+
+    /// With --confirm, re-measure in paired mode against each bench command run
+    /// with this program in place of its own (e.g. the previous release binary).
+    #[arg(long, value_name = "PATH", requires = "confirm")]
+    pub confirm_baseline_bin: Option<PathBuf>,
+
     /// Pretty-print JSON
     #[arg(long, default_value_t = false)]
     pub pretty: bool,
@@ -1174,19 +1295,26 @@ pub struct CalibrateArgs {
     pub config: PathBuf,
 
     /// Name of the benchmark to calibrate (must match a [[bench]] in config)
-    #[arg(long)]
-    pub bench: String,
+    #[arg(
+        long,
+        required_unless_present_any = ["tags", "exclude_tags"],
+        conflicts_with_all = ["tags", "exclude_tags"]
+    )]
+    pub bench: Option<String>,
+
+    #[command(flatten)]
+    pub tag_selection: BenchTagArgs,
 
     /// Output directory containing recent artifacts. Defaults to [defaults].out_dir or artifacts/perfgate.
     #[arg(long, value_name = "DIR")]
     pub out_dir: Option<PathBuf>,
 
     /// Explicit recent run receipt. Defaults to <out-dir>/<bench>/run.json, then <out-dir>/run.json.
-    #[arg(long)]
+    #[arg(long, requires = "bench")]
     pub run: Option<PathBuf>,
 
     /// Explicit baseline receipt. Defaults to the configured baseline path.
-    #[arg(long)]
+    #[arg(long, requires = "bench")]
     pub baseline: Option<PathBuf>,
 
     /// Emit a copy-ready advisory TOML fragment with reasons and guardrails.
@@ -1331,7 +1459,7 @@ pub struct IngestArgs {
     #[command(subcommand)]
     pub command: Option<IngestCommand>,
 
-    /// Input format: generic-command-json, criterion, hyperfine, gobench, pytest, otel
+    /// Input format: generic-command-json, criterion, hyperfine, gobench, google-benchmark, jmh, pytest, otel, tinybench, benchmark-js, k6, oha, vegeta, wrk, perf-stat, cachegrind, mapped
     #[arg(long)]
     pub format: Option<String>,
 
@@ -1339,7 +1467,8 @@ pub struct IngestArgs {
     #[arg(long)]
     pub input: Option<PathBuf>,
 
-    /// Benchmark name (default: derived from input data)
+    /// Benchmark name (default: derived from input data). With --out-dir or
+    /// --bundle, a prefix for every derived bench name
     #[arg(long)]
     pub name: Option<String>,
 
@@ -1351,10 +1480,24 @@ pub struct IngestArgs {
     #[arg(long = "exclude-span")]
     pub exclude_span: Vec<String>,
 
+    /// TOML field mapping describing JSON or CSV input (required by --format mapped)
+    #[arg(long)]
+    pub mapping: Option<PathBuf>,
+
     /// Output file path
     #[arg(long, default_value = "perfgate-ingest.json")]
     pub out: PathBuf,
 
+    /// Write one receipt per benchmark in the input to <DIR>/<bench>.json
+    /// instead of only the first benchmark to --out
+    #[arg(long, conflicts_with = "out")]
+    pub out_dir: Option<PathBuf>,
+
+    /// Write every benchmark in the input to one perfgate.run_bundle.v1 file,
+    /// which `perfgate suite compare` accepts
+    #[arg(long, conflicts_with = "out")]
+    pub bundle: Option<PathBuf>,
+
     /// Pretty-print JSON
     #[arg(long, default_value_t = false)]
     pub pretty: bool,
@@ -1364,6 +1507,8 @@ pub struct IngestArgs {
 pub enum IngestCommand {
     /// Ingest language-agnostic probe JSONL into a perfgate.probe.v1 receipt.
     Probes(IngestProbesArgs),
+    /// Ingest an OTLP trace export (JSON or protobuf) into a perfgate.probe.v1 receipt.
+    OtelProbes(IngestOtelProbesArgs),
 }
 
 #[derive(Debug, Subcommand)]
@@ -1374,6 +1519,12 @@ pub enum ProbeAction {
     Compare(ProbeCompareArgs),
 }
 
+#[derive(Debug, Subcommand)]
+pub enum SuiteAction {
+    /// Match run receipts by bench name and emit one perfgate.suite_compare.v1 receipt.
+    Compare(SuiteCompareArgs),
+}
+
 #[derive(Debug, Args)]
 pub struct ProbeInitArgs {
     /// Starter template to generate.
@@ -1718,6 +1869,41 @@ pub struct IngestProbesArgs {
     pub pretty: bool,
 }
 
+#[derive(Debug, Args)]
+pub struct IngestOtelProbesArgs {
+    /// Path to the OTLP trace export (OTLP JSON or binary protobuf).
+    #[arg(long)]
+    pub file: PathBuf,
+
+    /// Split probes by a span attribute value, e.g. http.route (repeatable).
+    #[arg(long = "group-by")]
+    pub group_by: Vec<String>,
+
+    /// Include span names (exact match, repeatable).
+    #[arg(long = "include-span")]
+    pub include_span: Vec<String>,
+
+    /// Exclude span names (exact match, repeatable).
+    #[arg(long = "exclude-span")]
+    pub exclude_span: Vec<String>,
+
+    /// Optional benchmark name to attach to the probe receipt.
+    #[arg(long)]
+    pub bench: Option<String>,
+
+    /// Optional scenario name to attach to the probe receipt.
+    #[arg(long)]
+    pub scenario: Option<String>,
+
+    /// Output file path.
+    #[arg(long, default_value = "probe.json")]
+    pub out: PathBuf,
+
+    /// Pretty-print JSON.
+    #[arg(long, default_value_t = false)]
+    pub pretty: bool,
+}
+
 #[derive(Debug, Args)]
 pub struct ProbeCompareArgs {
     /// Baseline perfgate.probe.v1 receipt.
@@ -1737,6 +1923,74 @@ pub struct ProbeCompareArgs {
     pub pretty: bool,
 }
 
+#[derive(Debug, Args)]
+pub struct SuiteCompareArgs {
+    /// Baseline directory of run receipts, or a perfgate.run_bundle.v1 file.
+    #[arg(long)]
+    pub baseline: PathBuf,
+
+    /// Current directory of run receipts (e.g. `check --all` output), or a perfgate.run_bundle.v1 file.
+    #[arg(long)]
+    pub current: PathBuf,
+
+    /// Global regression threshold (0.20 = 20%)
+    #[arg(long, default_value_t = 0.20)]
+    pub threshold: f64,
+
+    /// Global warn factor (warn_threshold = threshold * warn_factor)
+    #[arg(long, default_value_t = 0.90)]
+    pub warn_factor: f64,
+
+    /// Global noise threshold (coefficient of variation).
+    #[arg(long)]
+    pub noise_threshold: Option<f64>,
+
+    /// Global noise policy (warn|skip|ignore)
+    #[arg(long, value_parser = parse_noise_policy)]
+    pub noise_policy: Option<perfgate_types::NoisePolicy>,
+
+    /// Override per-metric threshold, e.g. wall_ms=0.10
+    #[arg(long, value_parser = parse_key_val_f64)]
+    pub metric_threshold: Vec<(String, f64)>,
+
+    /// Override per-metric direction, e.g. throughput_per_s=higher
+    #[arg(long, value_parser = parse_key_val_string)]
+    pub direction: Vec<(String, String)>,
+
+    /// Compute per-metric significance metadata using Welch's t-test (p <= alpha).
+    #[arg(long, value_parser = parse_significance_alpha)]
+    pub significance_alpha: Option<f64>,
+
+    /// Minimum samples required in each run before significance is computed.
+    #[arg(long, default_value_t = 8)]
+    pub significance_min_samples: u32,
+
+    /// When set with --significance-alpha, warn/fail statuses require significance.
+    #[arg(long, default_value_t = false)]
+    pub require_significance: bool,
+
+    /// With --significance-alpha, apply a Benjamini–Hochberg false discovery rate
+    /// correction across every bench and metric before computing verdicts.
+    #[arg(long, default_value_t = false, requires = "significance_alpha")]
+    pub fdr: bool,
+
+    /// Treat WARN verdict as a failing exit code
+    #[arg(long, default_value_t = false)]
+    pub fail_on_warn: bool,
+
+    /// Policy for handling host mismatches between baseline and current runs.
+    #[arg(long, default_value = "warn", value_parser = parse_host_mismatch_policy)]
+    pub host_mismatch: HostMismatchPolicy,
+
+    /// Output directory for suite-compare.json, report.json, and comment.md.
+    #[arg(long, value_name = "DIR", default_value = "artifacts/perfgate/suite")]
+    pub out_dir: PathBuf,
+
+    /// Pretty-print JSON
+    #[arg(long, default_value_t = false)]
+    pub pretty: bool,
+}
+
 #[derive(Debug, Clone, Copy, ValueEnum)]
 pub enum InitPreset {
     /// Balanced accuracy and speed (repeat=7, warmup=1, threshold=20%)
@@ -1825,14 +2079,22 @@ pub struct WatchArgs {
     #[arg(long, default_value = "perfgate.toml")]
     pub config: PathBuf,
 
+    /// Config profile to apply (`[profile.<name>]`). Defaults to $PERFGATE_PROFILE,
+    /// then `ci` under CI or `local` elsewhere when the config defines it.
+    #[arg(long = "profile", value_name = "NAME")]
+    pub config_profile: Option<String>,
+
     /// Name of the benchmark to watch (must match a [[bench]] in config)
-    #[arg(long)]
+    #[arg(long, conflicts_with_all = ["tags", "exclude_tags"])]
     pub bench: Option<String>,
 
     /// Watch all benchmarks defined in the config file
     #[arg(long, default_value_t = false)]
     pub all: bool,
 
+    #[command(flatten)]
+    pub tag_selection: BenchTagArgs,
+
     /// Debounce interval in milliseconds (wait for changes to settle)
     #[arg(long, default_value_t = 500)]
     pub debounce: u64,
@@ -2031,8 +2293,11 @@ enum BaselineAction {
         config: PathBuf,
 
         /// Limit status to one configured benchmark
-        #[arg(long)]
+        #[arg(long, conflicts_with_all = ["tags", "exclude_tags"])]
         bench: Option<String>,
+
+        #[command(flatten)]
+        tag_selection: BenchTagArgs,
     },
 
     /// Create the local baseline directory declared by the config file.
@@ -2341,13 +2606,14 @@ fn resolve_bench_names(
     bench: Option<&str>,
     all: bool,
     bench_regex: Option<&str>,
+    tags: &BenchTagFilter,
 ) -> anyhow::Result<Vec<String>> {
-    if all {
+    if all || !tags.is_empty() {
         if config_file.benches.is_empty() {
             anyhow::bail!("no benchmarks defined in config file");
         }
 
-        let mut names: Vec<String> = config_file.benches.iter().map(|b| b.name.clone()).collect();
+        let mut names = select_tagged_bench_names(config_file, tags)?;
 
         if let Some(pattern) = bench_regex {
             let regex = Regex::new(pattern)
@@ -2373,7 +2639,27 @@ fn resolve_bench_names(
         return Ok(vec![name.to_string()]);
     }
 
-    anyhow::bail!("either --bench or --all must be specified")
+    anyhow::bail!("either --bench, --all or --tag must be specified")
+}
+
+/// Names of the configured benches matching `tags`, in config order.
+///
+/// Fails when a non-empty filter matches nothing, so a mistyped tag does not
+/// silently run zero benchmarks.
+fn select_tagged_bench_names(
+    config_file: &ConfigFile,
+    tags: &BenchTagFilter,
+) -> anyhow::Result<Vec<String>> {
+    let names: Vec<String> = config_file
+        .benches
+        .iter()
+        .filter(|bench| tags.matches(bench))
+        .map(|bench| bench.name.clone())
+        .collect();
+    if names.is_empty() && !tags.is_empty() {
+        anyhow::bail!("{} did not match any benchmarks in config", tags.describe());
+    }
+    Ok(names)
 }
 
 fn main() -> ExitCode {
@@ -2436,6 +2722,8 @@ fn run_command(cmd: Command, server_flags: ServerFlags) -> anyhow::Result<()> {
                 output_cap_bytes,
                 allow_nonzero,
                 include_hostname_hash,
+                cpu_affinity: Vec::new(),
+                ready: None,
             })?;
 
             write_json(&out, &outcome.receipt, pretty)?;
@@ -2561,6 +2849,7 @@ fn run_command(cmd: Command, server_flags: ServerFlags) -> anyhow::Result<()> {
                             format!("@server:{benchmark}")
                         }),
                         run_id: Some(receipt.run.id.clone()),
+                        profile: None,
                     };
                     (receipt, ref_info)
                 }
@@ -2569,6 +2858,7 @@ fn run_command(cmd: Command, server_flags: ServerFlags) -> anyhow::Result<()> {
                     let ref_info = CompareRef {
                         path: Some(path.display().to_string()),
                         run_id: Some(receipt.run.id.clone()),
+                        profile: None,
                     };
                     (receipt, ref_info)
                 }
@@ -2611,6 +2901,7 @@ fn run_command(cmd: Command, server_flags: ServerFlags) -> anyhow::Result<()> {
                 current_ref: CompareRef {
                     path: Some(current.display().to_string()),
                     run_id: Some(current_receipt.run.id.clone()),
+                    profile: None,
                 },
                 tool: tool_info(),
                 host_mismatch_policy: host_mismatch,
@@ -2623,6 +2914,20 @@ fn run_command(cmd: Command, server_flags: ServerFlags) -> anyhow::Result<()> {
                     eprintln!("warning: host mismatch: {}", reason);
                 }
             }
+            if let Some(mismatch) = &compare_result.profile_mismatch {
+                eprintln!("warning: profile mismatch: {}", mismatch);
+            }
+            if let Some(mismatch) = &compare_result.concurrency_mismatch {
+                eprintln!("warning: concurrency mismatch: {}", mismatch);
+            }
+            for (side, receipt) in [
+                ("baseline", &baseline_receipt),
+                ("current", &current_receipt),
+            ] {
+                for warning in &receipt.run.noise_warnings {
+                    eprintln!("warning: {side} noise: {warning}");
+                }
+            }
 
             // Submit verdict to server if configured
             submit_verdict_if_possible(&server_flags, &config_file, &compare_result.receipt);
@@ -2868,11 +3173,18 @@ fn run_command(cmd: Command, server_flags: ServerFlags) -> anyhow::Result<()> {
         Command::Check(args) => {
             let CheckArgs {
                 config,
+                config_profile,
                 bench,
                 all,
                 bench_regex,
+                tag_selection,
                 out_dir,
                 baseline,
+                merge_base,
+                changed_since,
+                jobs,
+                interleave,
+                seed,
                 require_baseline,
                 fail_on_warn,
                 noise_threshold,
@@ -2884,6 +3196,10 @@ fn run_command(cmd: Command, server_flags: ServerFlags) -> anyhow::Result<()> {
                 significance_alpha,
                 significance_min_samples,
                 require_significance,
+                fdr,
+                confirm,
+                confirm_baseline_cmd,
+                confirm_baseline_bin,
                 pretty,
                 mode,
                 md_template,
@@ -2895,11 +3211,18 @@ fn run_command(cmd: Command, server_flags: ServerFlags) -> anyhow::Result<()> {
 
             let req = CheckConfig {
                 config_path: config,
+                profile: config_profile.into(),
                 bench,
                 all,
                 bench_regex,
+                tags: tag_selection.into_filter(),
                 out_dir,
                 baseline,
+                merge_base,
+                changed_since,
+                jobs,
+                interleave,
+                seed,
                 require_baseline,
                 fail_on_warn,
                 noise_threshold,
@@ -2911,6 +3234,12 @@ fn run_command(cmd: Command, server_flags: ServerFlags) -> anyhow::Result<()> {
                 significance_alpha,
                 significance_min_samples,
                 require_significance,
+                fdr,
+                confirm: ConfirmStrategy::from_args(
+                    confirm,
+                    confirm_baseline_cmd,
+                    confirm_baseline_bin,
+                )?,
                 pretty,
                 md_template,
                 output_github,
@@ -3002,6 +3331,7 @@ fn run_command(cmd: Command, server_flags: ServerFlags) -> anyhow::Result<()> {
                 max_retries,
                 fail_on_regression,
                 cv_threshold,
+                cpu_affinity: Vec::new(),
             })?;
 
             write_json(&out, &outcome.receipt, pretty)?;
@@ -3085,9 +3415,11 @@ fn run_command(cmd: Command, server_flags: ServerFlags) -> anyhow::Result<()> {
         }
 
         Command::Decision { action } => execute_decision_action(action, &server_flags),
+        Command::Config { action } => execute_config_action(action),
         Command::Ledger { action } => execute_ledger_action(action, &server_flags),
         Command::Policy { action } => execute_policy_action(action),
         Command::Probe { action } => execute_probe_action(action),
+        Command::Suite { action } => execute_suite_action(action),
         Command::Scenario { action } => execute_scenario_action(action),
         Command::Tradeoff { action } => execute_tradeoff_action(action),
 
@@ -3139,13 +3471,17 @@ fn run_command(cmd: Command, server_flags: ServerFlags) -> anyhow::Result<()> {
                 name,
                 include_span,
                 exclude_span,
+                mapping,
                 out,
+                out_dir,
+                bundle,
                 pretty,
             } = *args;
 
             if let Some(command) = command {
                 return match command {
                     IngestCommand::Probes(args) => execute_ingest_probes(args),
+                    IngestCommand::OtelProbes(args) => execute_ingest_otel_probes(args),
                 };
             }
 
@@ -3158,13 +3494,24 @@ fn run_command(cmd: Command, server_flags: ServerFlags) -> anyhow::Result<()> {
 
             let format = IngestFormat::parse(&format).ok_or_else(|| {
                 anyhow::anyhow!(
-                    "unknown ingest format '{}'; supported: generic-command-json, criterion, hyperfine, gobench, pytest, otel",
+                    "unknown ingest format '{}'; supported: generic-command-json, criterion, hyperfine, gobench, google-benchmark, jmh, pytest, otel, tinybench, benchmark-js, k6, oha, vegeta, wrk, perf-stat, cachegrind, mapped",
                     format
                 )
             })?;
 
             let content = fs::read_to_string(&input)
                 .with_context(|| format!("read input file {}", input.display()))?;
+            let mapping = match (format, mapping) {
+                (IngestFormat::Mapped, None) => {
+                    anyhow::bail!("ingest --format mapped requires --mapping <FILE>")
+                }
+                (IngestFormat::Mapped, Some(path)) => Some(
+                    fs::read_to_string(&path)
+                        .with_context(|| format!("read mapping file {}", path.display()))?,
+                ),
+                (_, Some(_)) => anyhow::bail!("--mapping only applies to --format mapped"),
+                (_, None) => None,
+            };
 
             let request = ingest::IngestRequest {
                 format,
@@ -3172,88 +3519,42 @@ fn run_command(cmd: Command, server_flags: ServerFlags) -> anyhow::Result<()> {
                 name,
                 include_spans: include_span,
                 exclude_spans: exclude_span,
+                mapping,
             };
 
-            let receipt = ingest::ingest(&request)?;
-            write_json(&out, &receipt, pretty)?;
-            eprintln!("Ingested {} -> {}", input.display(), out.display());
-            if format == IngestFormat::GenericCommandJson {
-                eprintln!(
-                    "Evidence source: generic_command_json; unit and direction came from the input and ambiguous mappings fail closed."
-                );
-                if receipt.run.host.os == "unknown" || receipt.run.host.arch == "unknown" {
-                    eprintln!(
-                        "Host context: unknown; do not infer host compatibility from this import."
-                    );
-                }
-                if receipt.samples.is_empty() {
-                    eprintln!(
-                        "Sample model: summary-only; noise and maturity support are limited until raw samples are available."
-                    );
-                }
-                eprintln!(
-                    "Non-inferences: imported evidence remains advisory; no baseline was promoted; policy posture still requires policy doctor or review-packet output."
-                );
-            } else if format == IngestFormat::Hyperfine {
-                eprintln!(
-                    "Evidence source: hyperfine_json; seconds were mapped to lower-is-better wall_ms and raw timing runs were preserved as samples."
-                );
-                if receipt.stats.cpu_ms.is_some() {
-                    eprintln!(
-                        "CPU timing: hyperfine user+system time was mapped to cpu_ms; separate user/system fields are not preserved in perfgate.run.v1."
-                    );
-                }
-                if receipt.run.host.os == "unknown" || receipt.run.host.arch == "unknown" {
-                    eprintln!(
-                        "Host context: unknown; hyperfine JSON does not prove host compatibility."
-                    );
-                }
-                eprintln!(
-                    "Non-inferences: hyperfine command timing may include shell, setup, cache, or compile overhead; imported evidence remains advisory until maturity and policy surfaces support promotion."
-                );
-            } else if format == IngestFormat::Criterion {
-                eprintln!(
-                    "Evidence source: criterion; clear wall-time fields were mapped to lower-is-better wall_ms."
-                );
-                if receipt.samples.is_empty() {
-                    eprintln!(
-                        "Sample model: summary-only; Criterion estimates.json does not provide raw per-sample evidence to perfgate."
-                    );
-                } else {
-                    eprintln!(
-                        "Sample model: Criterion measured samples were preserved where cargo-criterion JSONL or raw.csv provided them."
-                    );
-                }
-                if receipt.run.host.os == "unknown" || receipt.run.host.arch == "unknown" {
-                    eprintln!(
-                        "Host context: unknown; Criterion output does not prove host compatibility."
-                    );
-                }
-                eprintln!(
-                    "Non-inferences: Criterion statistics are not perfgate maturity policy; imported evidence remains advisory until baseline, signal, and policy surfaces support promotion."
-                );
-            } else if format == IngestFormat::PytestBenchmark {
-                eprintln!(
-                    "Evidence source: pytest_benchmark_json; seconds were mapped to lower-is-better wall_ms and pytest ops were mapped to higher-is-better throughput_per_s when present."
-                );
-                if receipt.samples.is_empty() {
-                    eprintln!(
-                        "Sample model: summary-only; pytest-benchmark JSON without stats.data does not provide raw sample evidence to perfgate."
-                    );
-                } else {
-                    eprintln!(
-                        "Sample model: pytest-benchmark stats.data entries were preserved as measured wall_ms samples."
-                    );
+            if out_dir.is_some() || bundle.is_some() {
+                let receipts = ingest::ingest_all(&request)?;
+                if let Some(out_dir) = &out_dir {
+                    for (name, receipt) in &receipts {
+                        let path = out_dir.join(format!("{name}.json"));
+                        write_json(&path, receipt, pretty)?;
+                        eprintln!("Ingested {} -> {}", name, path.display());
+                    }
                 }
-                if receipt.run.host.os == "unknown" || receipt.run.host.arch == "unknown" {
+                if let Some(bundle) = &bundle {
+                    let run_bundle = RunBundle {
+                        schema: RUN_BUNDLE_SCHEMA_V1.to_string(),
+                        tool: tool_info(),
+                        receipts: receipts.values().cloned().collect(),
+                    };
+                    write_json(bundle, &run_bundle, pretty)?;
                     eprintln!(
-                        "Host context: unknown or partial; do not infer host compatibility from this import."
+                        "Bundled {} benchmark(s) -> {}",
+                        receipts.len(),
+                        bundle.display()
                     );
                 }
-                eprintln!(
-                    "Non-inferences: passing pytest tests are correctness evidence, not performance maturity; imported evidence remains advisory until baseline, signal, and policy surfaces support promotion."
-                );
+                let receipts: Vec<&RunReceipt> = receipts.values().collect();
+                print_ingest_notes(format, &receipts);
+                print_ingest_warnings(&receipts);
+                return Ok(());
             }
+
+            let receipt = ingest::ingest(&request)?;
+            write_json(&out, &receipt, pretty)?;
+            eprintln!("Ingested {} -> {}", input.display(), out.display());
+            print_ingest_notes(format, &[&receipt]);
+            print_ingest_warnings(&[&receipt]);
             Ok(())
         }
         Command::Badge(args) => execute_badge(*args),
@@ -3281,6 +3582,7 @@ fn run_command(cmd: Command, server_flags: ServerFlags) -> anyhow::Result<()> {
         Command::Diff(args) => {
             let DiffArgs {
                 bench,
+                tag_selection,
                 against,
                 quick,
                 json,
@@ -3299,12 +3601,21 @@ fn run_command(cmd: Command, server_flags: ServerFlags) -> anyhow::Result<()> {
             let outcome = usecase.execute(DiffRequest {
                 config_path: config,
                 bench_filter: bench,
+                tags: tag_selection.into_filter(),
                 against,
                 quick,
                 json,
                 tool: tool_info(),
             })?;
 
+            for warning in outcome
+                .bench_outcomes
+                .iter()
+                .flat_map(|bench| &bench.warnings)
+            {
+                eprintln!("warning: {warning}");
+            }
+
             if json {
                 let output = render_json_diff(&outcome)?;
                 println!("{output}");
@@ -3328,6 +3639,222 @@ fn run_command(cmd: Command, server_flags: ServerFlags) -> anyhow::Result<()> {
     }
 }
 
+/// Print the provenance notes for an ingest, covering every ingested receipt.
+fn print_ingest_notes(format: IngestFormat, receipts: &[&RunReceipt]) {
+    let unknown_host = receipts
+        .iter()
+        .any(|receipt| receipt.run.host.os == "unknown" || receipt.run.host.arch == "unknown");
+    let summary_only = receipts.iter().any(|receipt| receipt.samples.is_empty());
+    if format == IngestFormat::GenericCommandJson {
+        eprintln!(
+            "Evidence source: generic_command_json; unit and direction came from the input and ambiguous mappings fail closed."
+        );
+        if unknown_host {
+            eprintln!("Host context: unknown; do not infer host compatibility from this import.");
+        }
+        if summary_only {
+            eprintln!(
+                "Sample model: summary-only; noise and maturity support are limited until raw samples are available."
+            );
+        }
+        eprintln!(
+            "Non-inferences: imported evidence remains advisory; no baseline was promoted; policy posture still requires policy doctor or review-packet output."
+        );
+    } else if format == IngestFormat::Hyperfine {
+        eprintln!(
+            "Evidence source: hyperfine_json; seconds were mapped to lower-is-better wall_ms and raw timing runs were preserved as samples."
+        );
+        if receipts
+            .iter()
+            .any(|receipt| receipt.stats.cpu_ms.is_some())
+        {
+            eprintln!(
+                "CPU timing: hyperfine user+system time was mapped to cpu_ms; separate user/system fields are not preserved in perfgate.run.v1."
+            );
+        }
+        if unknown_host {
+            eprintln!("Host context: unknown; hyperfine JSON does not prove host compatibility.");
+        }
+        eprintln!(
+            "Non-inferences: hyperfine command timing may include shell, setup, cache, or compile overhead; imported evidence remains advisory until maturity and policy surfaces support promotion."
+        );
+    } else if format == IngestFormat::Criterion {
+        eprintln!(
+            "Evidence source: criterion; clear wall-time fields were mapped to lower-is-better wall_ms."
+        );
+        if summary_only {
+            eprintln!(
+                "Sample model: summary-only; Criterion estimates.json does not provide raw per-sample evidence to perfgate."
+            );
+        } else {
+            eprintln!(
+                "Sample model: Criterion measured samples were preserved where cargo-criterion JSONL or raw.csv provided them."
+            );
+        }
+        if unknown_host {
+            eprintln!("Host context: unknown; Criterion output does not prove host compatibility.");
+        }
+        eprintln!(
+            "Non-inferences: Criterion statistics are not perfgate maturity policy; imported evidence remains advisory until baseline, signal, and policy surfaces support promotion."
+        );
+    } else if format == IngestFormat::GoBench {
+        eprintln!(
+            "Evidence source: go_bench; ns/op was mapped to wall_ms, MB/s to throughput_per_s (bytes per second), B/op and allocs/op to alloc_bytes and allocs, and b.ReportMetric units to stats.custom."
+        );
+        if receipts.iter().any(|receipt| receipt.samples.len() < 2) {
+            eprintln!(
+                "Sample model: one result line per benchmark; run `go test -count N` to record repetitions as samples for significance tests."
+            );
+        } else {
+            eprintln!("Sample model: each -count repetition was preserved as a measured sample.");
+        }
+        eprintln!(
+            "Non-inferences: the -N GOMAXPROCS suffix is dropped from bench names, so it does not prove matching parallelism; imported evidence remains advisory until baseline, signal, and policy surfaces support promotion."
+        );
+    } else if format == IngestFormat::GoogleBenchmark {
+        eprintln!(
+            "Evidence source: google_benchmark_json; real_time/cpu_time were mapped to wall_ms/cpu_ms, items_per_second (or bytes_per_second) to throughput_per_s, and user counters to stats.custom."
+        );
+        if summary_only {
+            eprintln!(
+                "Sample model: summary-only; only aggregate rows were reported, so repetitions are not available as samples."
+            );
+        } else {
+            eprintln!(
+                "Sample model: Google Benchmark repetitions were preserved as measured samples."
+            );
+        }
+        if receipts
+            .iter()
+            .any(|receipt| !receipt.stats.custom.is_empty())
+        {
+            eprintln!(
+                "Custom metrics: user counters are recorded for reference; budgets and verdicts do not gate them."
+            );
+        }
+        eprintln!(
+            "Non-inferences: Google Benchmark adjusts iteration counts per run; imported evidence remains advisory until baseline, signal, and policy surfaces support promotion."
+        );
+    } else if format == IngestFormat::Jmh {
+        eprintln!(
+            "Evidence source: jmh_json; thrpt scores were mapped to higher-is-better throughput_per_s (with wall_ms as time per operation), avgt/sample/ss scores to lower-is-better wall_ms, and secondary metrics to stats.custom."
+        );
+        if summary_only {
+            eprintln!(
+                "Sample model: summary-only; JMH results without rawData (such as sample mode) do not provide iteration samples to perfgate."
+            );
+        } else {
+            eprintln!(
+                "Sample model: JMH measurement iterations from every fork were preserved as samples."
+            );
+        }
+        eprintln!(
+            "Non-inferences: JMH score errors are confidence intervals, not perfgate noise policy; JIT and GC behavior depend on the JVM; imported evidence remains advisory until baseline, signal, and policy surfaces support promotion."
+        );
+    } else if format == IngestFormat::Tinybench || format == IngestFormat::BenchmarkJs {
+        let source = if format == IngestFormat::Tinybench {
+            "tinybench_json"
+        } else {
+            "benchmark_js_json"
+        };
+        eprintln!(
+            "Evidence source: {source}; per-operation times were mapped to lower-is-better wall_ms and hz to higher-is-better throughput_per_s."
+        );
+        if summary_only {
+            eprintln!(
+                "Sample model: summary-only; the input carried no per-sample times, so noise and maturity support are limited."
+            );
+        }
+        eprintln!(
+            "Non-inferences: JavaScript engine warmup, JIT tiers, and GC pauses are not controlled by perfgate; imported evidence remains advisory until baseline, signal, and policy surfaces support promotion."
+        );
+    } else if matches!(
+        format,
+        IngestFormat::K6 | IngestFormat::Oha | IngestFormat::Vegeta | IngestFormat::Wrk
+    ) {
+        let source = match format {
+            IngestFormat::K6 => "k6_summary_json",
+            IngestFormat::Oha => "oha_json",
+            IngestFormat::Vegeta => "vegeta_json",
+            _ => "wrk_lua_json",
+        };
+        eprintln!(
+            "Evidence source: {source}; latency percentiles were mapped to lower-is-better latency_p50_us/p95_us/p99_us, failed requests to lower-is-better error_rate, and successful requests per second to higher-is-better throughput_per_s."
+        );
+        eprintln!(
+            "Sample model: summary-only; the whole load test is one sample, so noise and significance support are limited."
+        );
+        eprintln!(
+            "Non-inferences: load generator placement, connection reuse, and target warmup are not controlled by perfgate; wall_ms is the test duration, not request latency; imported evidence remains advisory until baseline, signal, and policy surfaces support promotion."
+        );
+    } else if format == IngestFormat::PerfStat {
+        eprintln!(
+            "Evidence source: perf_stat_csv; duration_time was mapped to wall_ms, task-clock to cpu_ms, instructions, page-faults and context-switches to their metrics, and other events to stats.custom."
+        );
+        if receipts
+            .iter()
+            .any(|receipt| receipt.stats.wall_ms.max == 0)
+        {
+            eprintln!(
+                "Wall time: no duration_time event was recorded, so wall_ms is 0 and compare skips it; add `-e duration_time` to gate elapsed time."
+            );
+        }
+        eprintln!(
+            "Non-inferences: hardware counters depend on the CPU model, kernel, and perf version; imported evidence remains advisory until baseline, signal, and policy surfaces support promotion."
+        );
+    } else if format == IngestFormat::Cachegrind {
+        eprintln!(
+            "Evidence source: cachegrind; Ir was mapped to instructions, cache-simulation misses to l1_hits/ll_hits/ram_hits and estimated_cycles, and other events to stats.custom."
+        );
+        eprintln!(
+            "Non-inferences: cachegrind simulates caches instead of timing them, so wall_ms is 0; counts are not comparable across compilers, Valgrind versions, or targets."
+        );
+    } else if format == IngestFormat::Mapped {
+        eprintln!(
+            "Evidence source: mapped; bench names, units, and directions came from the field mapping and values were converted to perfgate units."
+        );
+        if summary_only {
+            eprintln!(
+                "Sample model: summary-only; noise and maturity support are limited until raw samples are mapped."
+            );
+        }
+        eprintln!(
+            "Non-inferences: a mapping proves where values live, not how they were measured; imported evidence remains advisory until baseline, signal, and policy surfaces support promotion."
+        );
+    } else if format == IngestFormat::PytestBenchmark {
+        eprintln!(
+            "Evidence source: pytest_benchmark_json; seconds were mapped to lower-is-better wall_ms and pytest ops were mapped to higher-is-better throughput_per_s when present."
+        );
+        if summary_only {
+            eprintln!(
+                "Sample model: summary-only; pytest-benchmark JSON without stats.data does not provide raw sample evidence to perfgate."
+            );
+        } else {
+            eprintln!(
+                "Sample model: pytest-benchmark stats.data entries were preserved as measured wall_ms samples."
+            );
+        }
+        if unknown_host {
+            eprintln!(
+                "Host context: unknown or partial; do not infer host compatibility from this import."
+            );
+        }
+        eprintln!(
+            "Non-inferences: passing pytest tests are correctness evidence, not performance maturity; imported evidence remains advisory until baseline, signal, and policy surfaces support promotion."
+        );
+    }
+}
+
+/// Print the noise warnings recorded on the ingested receipts.
+fn print_ingest_warnings(receipts: &[&RunReceipt]) {
+    for warning in receipts
+        .iter()
+        .flat_map(|receipt| &receipt.run.noise_warnings)
+    {
+        eprintln!("Noise warning: {warning}");
+    }
+}
+
 fn execute_ingest_probes(args: IngestProbesArgs) -> anyhow::Result<()> {
     let content = fs::read_to_string(&args.file)
         .with_context(|| format!("read probe JSONL file {}", args.file.display()))?;
@@ -3346,6 +3873,28 @@ fn execute_ingest_probes(args: IngestProbesArgs) -> anyhow::Result<()> {
     Ok(())
 }
 
+fn execute_ingest_otel_probes(args: IngestOtelProbesArgs) -> anyhow::Result<()> {
+    let content = fs::read(&args.file)
+        .with_context(|| format!("read OTLP trace file {}", args.file.display()))?;
+    let request = ingest::OtelProbeIngestRequest {
+        input: content,
+        bench: args.bench,
+        scenario: args.scenario,
+        group_by: args.group_by,
+        include_spans: args.include_span,
+        exclude_spans: args.exclude_span,
+    };
+    let receipt = ingest::ingest_otel_probes(&request)?;
+    write_json(&args.out, &receipt, args.pretty)?;
+    eprintln!(
+        "Ingested {} span probe(s) {} -> {}",
+        receipt.probes.len(),
+        args.file.display(),
+        args.out.display()
+    );
+    Ok(())
+}
+
 fn execute_probe_action(action: ProbeAction) -> anyhow::Result<()> {
     match action {
         ProbeAction::Init(args) => execute_probe_init(args),
@@ -3363,10 +3912,12 @@ fn execute_probe_compare(args: ProbeCompareArgs) -> anyhow::Result<()> {
         baseline_ref: CompareRef {
             path: Some(args.baseline.display().to_string()),
             run_id: Some(baseline.run.id.clone()),
+            profile: None,
         },
         current_ref: CompareRef {
             path: Some(args.current.display().to_string()),
             run_id: Some(current.run.id.clone()),
+            profile: None,
         },
         baseline,
         current,
@@ -3378,6 +3929,89 @@ fn execute_probe_compare(args: ProbeCompareArgs) -> anyhow::Result<()> {
     Ok(())
 }
 
+fn execute_suite_action(action: SuiteAction) -> anyhow::Result<()> {
+    match action {
+        SuiteAction::Compare(args) => execute_suite_compare(args),
+    }
+}
+
+fn execute_suite_compare(args: SuiteCompareArgs) -> anyhow::Result<()> {
+    let significance = args
+        .significance_alpha
+        .map(|alpha| {
+            SignificancePolicy::new(
+                alpha,
+                args.significance_min_samples as usize,
+                args.require_significance,
+            )
+        })
+        .transpose()?;
+
+    let outcome = SuiteCompareUseCase::execute(SuiteCompareRequest {
+        baseline: args.baseline,
+        current: args.current,
+        threshold: args.threshold,
+        warn_factor: args.warn_factor,
+        noise_threshold: args.noise_threshold,
+        noise_policy: args.noise_policy,
+        metric_thresholds: args.metric_threshold,
+        direction_overrides: args.direction,
+        significance,
+        false_discovery_rate: args.fdr,
+        host_mismatch_policy: args.host_mismatch,
+        tool: tool_info(),
+    })?;
+
+    for warning in &outcome.receipt.warnings {
+        eprintln!("warning: {}", warning);
+    }
+
+    fs::create_dir_all(&args.out_dir)
+        .with_context(|| format!("create output dir {}", args.out_dir.display()))?;
+    let receipt_path = args.out_dir.join("suite-compare.json");
+    write_json(&receipt_path, &outcome.receipt, args.pretty)?;
+    write_json(
+        &args.out_dir.join("report.json"),
+        &outcome.report,
+        args.pretty,
+    )?;
+    atomic_write(
+        &args.out_dir.join("comment.md"),
+        render_suite_markdown(&outcome.receipt).as_bytes(),
+    )?;
+
+    let summary = &outcome.receipt;
+    eprintln!(
+        "Suite compare: {} ({} compared, {} added, {} removed)",
+        summary.verdict.status.as_str(),
+        summary.benches.len(),
+        summary.added.len(),
+        summary.removed.len()
+    );
+    if let Some(geomean) = summary.geomean.get(&perfgate_types::Metric::WallMs) {
+        eprintln!(
+            "  wall_ms geometric mean: {}",
+            perfgate_app::format_pct(geomean.pct)
+        );
+    }
+    eprintln!(
+        "Suite compare receipt written to {}",
+        receipt_path.display()
+    );
+
+    match outcome.receipt.verdict.status {
+        VerdictStatus::Pass | VerdictStatus::Skip => Ok(()),
+        VerdictStatus::Warn => {
+            if args.fail_on_warn {
+                exit_with_code(3)
+            } else {
+                Ok(())
+            }
+        }
+        VerdictStatus::Fail => exit_with_code(2),
+    }
+}
+
 fn execute_scenario_action(action: ScenarioAction) -> anyhow::Result<()> {
     match action {
         ScenarioAction::Evaluate(args) => execute_scenario_evaluate(args),
@@ -3448,6 +4082,7 @@ fn evaluate_configured_scenarios(
             compare_ref: CompareRef {
                 path: Some(compare_path.display().to_string()),
                 run_id,
+                profile: None,
             },
             compare,
             probe_compare_ref,
@@ -3496,10 +4131,12 @@ fn run_configured_probe_compares(
             baseline_ref: CompareRef {
                 path: Some(baseline_path.display().to_string()),
                 run_id: Some(baseline.run.id.clone()),
+                profile: None,
             },
             current_ref: CompareRef {
                 path: Some(current_path.display().to_string()),
                 run_id: Some(current.run.id.clone()),
+                profile: None,
             },
             baseline,
             current,
@@ -3588,6 +4225,7 @@ fn load_scenario_probe_compare(
     let compare_ref = CompareRef {
         path: Some(path.display().to_string()),
         run_id: None,
+        profile: None,
     };
     if !location_exists(&path)? {
         return Ok((
@@ -3606,6 +4244,7 @@ fn load_scenario_probe_compare(
     let compare_ref = CompareRef {
         path: Some(path.display().to_string()),
         run_id: Some(receipt.run.id.clone()),
+        profile: None,
     };
     Ok((Some(compare_ref), Some(receipt), None))
 }
@@ -4674,6 +5313,12 @@ fn print_discover_table(benchmarks: &[perfgate_app::discover::DiscoveredBenchmar
         );
     }
 
+    for b in benchmarks {
+        if let Some(hint) = &b.manual_export {
+            println!("\nnote: {} needs a manual JSON export: {}", b.name, hint);
+        }
+    }
+
     println!("\nDiscovered {} benchmark(s)", benchmarks.len());
 }
 
@@ -4809,7 +5454,7 @@ fn execute_blame(args: BlameArgs) -> anyhow::Result<()> {
 fn execute_cargo_bench(args: CargoBenchArgs) -> anyhow::Result<()> {
     use perfgate_app::cargo_bench::{
         BenchSource, benchmarks_to_individual_receipts, benchmarks_to_receipt,
-        build_cargo_bench_command, detect_criterion, detect_target_dir, parse_libtest_output,
+        build_cargo_bench_command, detect_criterion, detect_target_dir, parse_bench_output,
         scan_criterion_dir,
     };
 
@@ -4842,35 +5487,42 @@ fn execute_cargo_bench(args: CargoBenchArgs) -> anyhow::Result<()> {
         eprint!("{stderr}");
     }
 
-    // Determine source: check Criterion first, then libtest
-    let benchmarks = if detect_criterion(&target_dir) {
+    // Criterion writes JSON under the target dir; the other frameworks print
+    // their results, usually to stdout.
+    let mut benchmarks = if detect_criterion(&target_dir) {
         let criterion_dir = target_dir.join("criterion");
         eprintln!("Detected Criterion output in {}", criterion_dir.display());
         scan_criterion_dir(&criterion_dir)?
     } else {
-        // Try parsing libtest output from stdout
-        let parsed = parse_libtest_output(&stdout);
-        if parsed.is_empty() {
-            // Also try stderr (some runners print there)
-            let parsed_stderr = parse_libtest_output(&stderr);
-            if parsed_stderr.is_empty() {
-                anyhow::bail!(
-                    "no benchmark results found: no Criterion data in {} and no libtest bench output detected",
-                    target_dir.display()
-                );
-            }
-            parsed_stderr
-        } else {
-            parsed
-        }
+        Vec::new()
     };
+    let parsed = parse_bench_output(&stdout);
+    if parsed.is_empty() {
+        // Also try stderr (some runners print there)
+        benchmarks.extend(parse_bench_output(&stderr));
+    } else {
+        benchmarks.extend(parsed);
+    }
+    if benchmarks.is_empty() {
+        anyhow::bail!(
+            "no benchmark results found: no Criterion data in {} and no libtest, divan, or iai-callgrind bench output detected",
+            target_dir.display()
+        );
+    }
 
-    let source = benchmarks
-        .first()
-        .map(|b| b.source)
-        .unwrap_or(BenchSource::Libtest);
+    let mut sources: Vec<BenchSource> = Vec::new();
+    for bench in &benchmarks {
+        if !sources.contains(&bench.source) {
+            sources.push(bench.source);
+        }
+    }
+    let sources: Vec<String> = sources.iter().map(|s| format!("{s:?}")).collect();
 
-    eprintln!("Found {} benchmark(s) from {:?}", benchmarks.len(), source);
+    eprintln!(
+        "Found {} benchmark(s) from {}",
+        benchmarks.len(),
+        sources.join(", ")
+    );
 
     // Collect host info and tool info
     let tool = tool_info();
@@ -4925,10 +5577,12 @@ fn execute_cargo_bench(args: CargoBenchArgs) -> anyhow::Result<()> {
             baseline_ref: CompareRef {
                 path: Some(baseline_path.to_string_lossy().to_string()),
                 run_id: None,
+                profile: None,
             },
             current_ref: CompareRef {
                 path: Some(args.out.to_string_lossy().to_string()),
                 run_id: None,
+                profile: None,
             },
             tool: tool.clone(),
             host_mismatch_policy: HostMismatchPolicy::Warn,
@@ -4953,6 +5607,12 @@ fn execute_cargo_bench(args: CargoBenchArgs) -> anyhow::Result<()> {
                 mismatch.reasons.join("; ")
             );
         }
+        if let Some(mismatch) = &compare_result.profile_mismatch {
+            eprintln!("Warning: profile mismatch detected: {}", mismatch);
+        }
+        if let Some(mismatch) = &compare_result.concurrency_mismatch {
+            eprintln!("Warning: concurrency mismatch detected: {}", mismatch);
+        }
 
         // Print markdown summary
         let md = perfgate_app::render_markdown(&compare_result.receipt);
@@ -5786,9 +6446,22 @@ fn local_baseline_dirs(config: &ConfigFile) -> Vec<PathBuf> {
     dirs.into_iter().collect()
 }
 
-fn execute_local_baseline_status(config_path: &Path, bench: Option<&str>) -> anyhow::Result<()> {
+fn execute_local_baseline_status(
+    config_path: &Path,
+    bench: Option<&str>,
+    tags: &BenchTagFilter,
+) -> anyhow::Result<()> {
     let config = load_validated_baseline_config(config_path)?;
-    let benches = configured_baseline_benches(&config, bench)?;
+    let benches = if tags.is_empty() {
+        configured_baseline_benches(&config, bench)?
+    } else {
+        select_tagged_bench_names(&config, tags)?
+    };
+    let check_selector = if tags.is_empty() {
+        "--all".to_string()
+    } else {
+        tags.describe()
+    };
 
     println!("Baseline status ({})", config_path.display());
     if benches.is_empty() {
@@ -5833,8 +6506,9 @@ fn execute_local_baseline_status(config_path: &Path, bench: Option<&str>) -> any
         println!("Next:");
         if missing.len() == 1 {
             println!(
-                "  1. Run: perfgate check --config {} --all",
-                config_path.display()
+                "  1. Run: perfgate check --config {} {}",
+                config_path.display(),
+                check_selector
             );
             if bench.is_some() {
                 let bench_name = &missing[0];
@@ -5851,8 +6525,9 @@ fn execute_local_baseline_status(config_path: &Path, bench: Option<&str>) -> any
             }
         } else {
             println!(
-                "  1. Run: perfgate check --config {} --all",
-                config_path.display()
+                "  1. Run: perfgate check --config {} {}",
+                config_path.display(),
+                check_selector
             );
             println!(
                 "  2. Promote missing baselines: perfgate baseline promote --config {} --all",
@@ -6012,6 +6687,20 @@ fn promote_one_local_baseline(
     eprintln!("  current: {}", current_path.display());
     eprintln!("  baseline: {}", baseline_path.display());
 
+    // Record a per-commit copy so merge-base aware checks can find it later.
+    if let Some(history_dir) = &config.defaults.baseline_history_dir {
+        match run_git_capture(&["rev-parse", "HEAD"]) {
+            Some(sha) => {
+                let history_path = render_history_baseline_path(history_dir, &sha, bench);
+                write_json_to_location(&history_path, &result.receipt, options.pretty)?;
+                eprintln!("  history: {}", history_path.display());
+            }
+            None => eprintln!(
+                "warning: baseline_history_dir is set but HEAD could not be resolved; history not recorded"
+            ),
+        }
+    }
+
     Ok(())
 }
 
@@ -6021,9 +6710,11 @@ fn execute_baseline_action(
     server_flags: &ServerFlags,
 ) -> anyhow::Result<()> {
     match action {
-        BaselineAction::Status { config, bench } => {
-            execute_local_baseline_status(&config, bench.as_deref())
-        }
+        BaselineAction::Status {
+            config,
+            bench,
+            tag_selection,
+        } => execute_local_baseline_status(&config, bench.as_deref(), &tag_selection.into_filter()),
         BaselineAction::Init { config } => execute_local_baseline_init(&config),
         BaselineAction::Doctor { config, bench } => {
             execute_baseline_doctor(&config, bench.as_deref())
@@ -6736,11 +7427,18 @@ fn exit_with_code(code: i32) -> ! {
 #[derive(Debug, Clone)]
 struct CheckConfig {
     config_path: PathBuf,
+    profile: ProfileSelection,
     bench: Option<String>,
     all: bool,
     bench_regex: Option<String>,
+    tags: BenchTagFilter,
     out_dir: Option<PathBuf>,
     baseline: Option<PathBuf>,
+    merge_base: Option<String>,
+    changed_since: Option<String>,
+    jobs: u32,
+    interleave: bool,
+    seed: Option<u64>,
     require_baseline: bool,
     fail_on_warn: bool,
     noise_threshold: Option<f64>,
@@ -6752,6 +7450,8 @@ struct CheckConfig {
     significance_alpha: Option<f64>,
     significance_min_samples: u32,
     require_significance: bool,
+    fdr: bool,
+    confirm: Option<ConfirmStrategy>,
     pretty: bool,
     md_template: Option<PathBuf>,
     output_github: bool,
@@ -6761,6 +7461,13 @@ struct CheckConfig {
     local_db: bool,
 }
 
+impl CheckConfig {
+    /// Whether several benches may run, each writing to its own subdirectory.
+    fn selects_many(&self) -> bool {
+        self.all || !self.tags.is_empty()
+    }
+}
+
 fn resolve_configured_out_dir(
     cli_out_dir: Option<&PathBuf>,
     config: Option<&ConfigFile>,
@@ -6776,6 +7483,74 @@ fn resolve_configured_out_dir(
     PathBuf::from(DEFAULT_ARTIFACT_DIR)
 }
 
+/// Prepare merge-base aware baseline selection for a check run, when a
+/// merge-base target is set (from `--merge-base` or `[defaults].merge_base`)
+/// and no explicit `--baseline` overrides it.
+fn prepare_merge_base_search(
+    req: &CheckConfig,
+    config_file: &ConfigFile,
+) -> Option<MergeBaseSearch> {
+    if req.baseline.is_some() {
+        return None;
+    }
+    let target = resolve_merge_base_target(req.merge_base.as_deref(), config_file)?;
+    match MergeBaseSearch::new(&target) {
+        Ok(search) => Some(search),
+        Err(err) => {
+            eprintln!(
+                "warning: merge-base baseline selection failed: {:#}; using configured baselines",
+                err
+            );
+            None
+        }
+    }
+}
+
+/// Resolve the baseline for one bench of a check run.
+///
+/// With a merge-base search, the baseline recorded for the nearest ancestor of
+/// the merge-base wins and the selected commit is returned for the report;
+/// otherwise, or when none is recorded, the configured baseline path is used.
+fn resolve_check_baseline(
+    req: &CheckConfig,
+    bench_name: &str,
+    config_file: &ConfigFile,
+    merge_base: Option<&MergeBaseSearch>,
+) -> anyhow::Result<(PathBuf, Option<RunReceipt>, Option<MergeBaseSelection>)> {
+    let baseline_path = resolve_baseline_path(&req.baseline, bench_name, config_file);
+
+    if let Some(search) = merge_base {
+        match search.resolve(bench_name, config_file, &req.server_flags) {
+            Ok(Some(found)) => {
+                eprintln!(
+                    "note: [{}] {} ({})",
+                    bench_name,
+                    found.selection.describe(),
+                    found.path.display()
+                );
+                return Ok((found.path, Some(found.receipt), Some(found.selection)));
+            }
+            Ok(None) => eprintln!(
+                "warning: [{}] no baseline recorded within {} commits of the merge-base with {}; using {}",
+                bench_name,
+                DEFAULT_MERGE_BASE_SEARCH_DEPTH,
+                search.target(),
+                baseline_path.display()
+            ),
+            Err(err) => eprintln!(
+                "warning: [{}] merge-base baseline selection failed: {:#}; using {}",
+                bench_name,
+                err,
+                baseline_path.display()
+            ),
+        }
+    }
+
+    let baseline_receipt = load_optional_baseline_receipt(&baseline_path)
+        .map_err(|e| PerfgateError::Io(IoError::BaselineResolve(e.to_string())))?;
+    Ok((baseline_path, baseline_receipt, None))
+}
+
 /// Returns true if the verdict indicates a regression (warn or fail).
 fn is_regression(status: VerdictStatus) -> bool {
     matches!(status, VerdictStatus::Warn | VerdictStatus::Fail)
@@ -6876,18 +7651,9 @@ fn run_check_standard(req: CheckConfig) -> anyhow::Result<()> {
         })
         .with_context(|| format!("read {}", req.config_path.display()))?;
 
-    let config_file: ConfigFile = if req
-        .config_path
-        .extension()
-        .map(|e| e == "json")
-        .unwrap_or(false)
-    {
-        serde_json::from_str(&config_content)
-            .with_context(|| format!("parse JSON config {}", req.config_path.display()))?
-    } else {
-        toml::from_str(&config_content)
-            .with_context(|| format!("parse TOML config {}", req.config_path.display()))?
-    };
+    let resolved = resolve_config_str_with_profile(&req.config_path, &config_content, &req.profile)
+        .with_context(|| format!("load config {}", req.config_path.display()))?;
+    let config_file = resolved.config;
 
     config_file
         .validate()
@@ -6899,6 +7665,7 @@ fn run_check_standard(req: CheckConfig) -> anyhow::Result<()> {
         req.bench.as_deref(),
         req.all,
         req.bench_regex.as_deref(),
+        &req.tags,
     )
     .inspect_err(|error| {
         print_check_failure_guidance(
@@ -6910,6 +7677,7 @@ fn run_check_standard(req: CheckConfig) -> anyhow::Result<()> {
         );
     })?;
     let bench_count = bench_names.len() as u32;
+    let plan = plan_changed_benches(&config_file, bench_names, req.changed_since.as_deref())?;
 
     let markdown_template_path = req.md_template.clone().or_else(|| {
         config_file
@@ -6929,66 +7697,16 @@ fn run_check_standard(req: CheckConfig) -> anyhow::Result<()> {
     let mut total_warn: u32 = 0;
     let mut total_fail: u32 = 0;
 
-    for bench_name in &bench_names {
-        // For --all mode, use per-bench subdirectories
-        let bench_out_dir = if req.all {
-            out_dir.join(bench_name)
-        } else {
-            out_dir.clone()
-        };
-
-        // Resolve baseline path (--baseline flag only valid for single bench mode)
-        let baseline_path = resolve_baseline_path(&req.baseline, bench_name, &config_file);
-        let baseline_receipt = load_optional_baseline_receipt(&baseline_path)
-            .map_err(|e| PerfgateError::Io(IoError::BaselineResolve(e.to_string())))?;
-
-        // Create output directory
-        fs::create_dir_all(&bench_out_dir).map_err(|e| {
-            PerfgateError::Io(IoError::ArtifactWrite(format!(
-                "create output dir {}: {}",
-                bench_out_dir.display(),
-                e
-            )))
-        })?;
-
-        // Execute check
-        let runner = StdProcessRunner;
-        let host_probe = StdHostProbe;
-        let clock = SystemClock;
-        let usecase = CheckUseCase::new(runner, host_probe, clock);
-
-        let outcome = match usecase.execute(CheckRequest {
-            config: config_file.clone(),
-            bench_name: bench_name.clone(),
-            out_dir: bench_out_dir.clone(),
-            baseline: baseline_receipt,
-            baseline_path: Some(baseline_path.clone()),
-            require_baseline: req.require_baseline,
-            fail_on_warn: req.fail_on_warn,
-            noise_threshold: req.noise_threshold,
-            noise_policy: req.noise_policy,
-            tool: tool_info(),
-            env: req.env.clone(),
-            output_cap_bytes: req.output_cap_bytes,
-            allow_nonzero: req.allow_nonzero,
-            host_mismatch_policy: req.host_mismatch,
-            significance_alpha: req.significance_alpha,
-            significance_min_samples: req.significance_min_samples,
-            require_significance: req.require_significance,
-        }) {
-            Ok(outcome) => outcome,
-            Err(error) => {
-                print_check_failure_guidance(
-                    classify_check_error(&error),
-                    &req.config_path,
-                    Some(bench_name),
-                    Some(&bench_out_dir),
-                    None,
-                );
-                return Err(error);
-            }
-        };
+    // With --fdr, every bench runs before any artifact is written so the
+    // correction can see all significance tests at once.
+    let correct_fdr = req.fdr && req.significance_alpha.is_some();
+    let mut pending: Vec<(String, PathBuf, PathBuf, CheckOutcome)> = Vec::new();
 
+    let mut finish_bench = |bench_name: &str,
+                            bench_out_dir: &Path,
+                            baseline_path: &Path,
+                            outcome: CheckOutcome|
+     -> anyhow::Result<()> {
         // Submit verdict to server if configured
         if let Some(compare) = &outcome.compare_receipt {
             submit_verdict_if_possible(&req.server_flags, &config_file, compare);
@@ -7003,7 +7721,7 @@ fn run_check_standard(req: CheckConfig) -> anyhow::Result<()> {
                 FailureClass::ServerUploadFailed,
                 &req.config_path,
                 Some(bench_name),
-                Some(&bench_out_dir),
+                Some(bench_out_dir),
                 outcome.compare_path.as_deref(),
             );
         }
@@ -7014,7 +7732,7 @@ fn run_check_standard(req: CheckConfig) -> anyhow::Result<()> {
 
         maybe_write_repair_context(
             &outcome,
-            Some(&baseline_path),
+            Some(baseline_path),
             req.emit_repair_context,
             req.pretty,
         )
@@ -7029,32 +7747,38 @@ fn run_check_standard(req: CheckConfig) -> anyhow::Result<()> {
                 &compare.bench.command,
                 compare.bench.cwd.as_deref(),
                 &compare.bench.name,
-                &bench_out_dir,
+                bench_out_dir,
             );
         }
 
         if let Some(compare) = &outcome.compare_receipt {
-            let markdown =
+            let mut markdown =
                 render_markdown_with_optional_template(compare, markdown_template_path.as_deref())?;
+            if let Some(selection) = &outcome.report.baseline_selection {
+                markdown.push_str(&perfgate_app::render_baseline_selection_section(selection));
+            }
+            if let Some(confirmation) = &outcome.report.confirmation {
+                markdown.push_str(&perfgate_app::render_confirmation_section(confirmation));
+            }
             atomic_write(&outcome.markdown_path, markdown.as_bytes())
                 .map_err(|e| PerfgateError::Io(IoError::ArtifactWrite(e.to_string())))?;
         } else {
             let msg = "markdown template ignored for no-baseline bench".to_string();
-            if req.all {
+            if req.selects_many() {
                 all_warnings.push(format!("[{}] {}", bench_name, msg));
             } else {
                 all_warnings.push(msg);
             }
         }
         for warning in &outcome.warnings {
-            if req.all {
+            if req.selects_many() {
                 all_warnings.push(format!("[{}] {}", bench_name, warning));
             } else {
                 all_warnings.push(warning.clone());
             }
         }
 
-        emit_check_outcome_guidance(&req, bench_name, &bench_out_dir, &outcome);
+        emit_check_outcome_guidance(&req, bench_name, bench_out_dir, &outcome);
 
         total_pass += outcome.report.summary.pass_count;
         total_warn += outcome.report.summary.warn_count;
@@ -7063,6 +7787,172 @@ fn run_check_standard(req: CheckConfig) -> anyhow::Result<()> {
         // Update aggregate exit code (worst wins)
         // Priority: 2 (fail) > 3 (warn-as-fail) > 0 (pass)
         update_max_exit_code(&mut max_exit_code, outcome.exit_code);
+        Ok(())
+    };
+
+    for (bench_name, reason) in &plan.skipped {
+        let bench_out_dir = if req.selects_many() {
+            out_dir.join(bench_name)
+        } else {
+            out_dir.clone()
+        };
+        write_skipped_bench(&bench_out_dir, bench_name, reason, req.pretty)
+            .map_err(|e| PerfgateError::Io(IoError::ArtifactWrite(e.to_string())))?;
+    }
+
+    // Baselines and output directories are resolved up front, in order, so
+    // benches can then run serially or across `--jobs` workers.
+    let merge_base = prepare_merge_base_search(&req, &config_file);
+    let mut prepared: Vec<(String, PathBuf, PathBuf, CheckRequest)> = Vec::new();
+    for bench_name in &plan.run {
+        // For --all and tag selection, use per-bench subdirectories
+        let bench_out_dir = if req.selects_many() {
+            out_dir.join(bench_name)
+        } else {
+            out_dir.clone()
+        };
+
+        // Resolve baseline path (--baseline flag only valid for single bench mode)
+        let (baseline_path, baseline_receipt, baseline_selection) =
+            resolve_check_baseline(&req, bench_name, &config_file, merge_base.as_ref())?;
+
+        // Create output directory
+        fs::create_dir_all(&bench_out_dir).map_err(|e| {
+            PerfgateError::Io(IoError::ArtifactWrite(format!(
+                "create output dir {}: {}",
+                bench_out_dir.display(),
+                e
+            )))
+        })?;
+
+        let check_request = CheckRequest {
+            config: config_file.clone(),
+            bench_name: bench_name.clone(),
+            out_dir: bench_out_dir.clone(),
+            baseline: baseline_receipt,
+            baseline_path: Some(baseline_path.clone()),
+            baseline_selection,
+            require_baseline: req.require_baseline,
+            fail_on_warn: req.fail_on_warn,
+            noise_threshold: req.noise_threshold,
+            noise_policy: req.noise_policy,
+            tool: tool_info(),
+            env: req.env.clone(),
+            output_cap_bytes: req.output_cap_bytes,
+            allow_nonzero: req.allow_nonzero,
+            host_mismatch_policy: req.host_mismatch,
+            significance_alpha: req.significance_alpha,
+            significance_min_samples: req.significance_min_samples,
+            require_significance: req.require_significance,
+            threshold_history: load_threshold_history(bench_name, &config_file, &req.server_flags),
+            profile: resolved.profile.clone(),
+            concurrency: None,
+            interleave: None,
+        };
+        prepared.push((
+            bench_name.clone(),
+            bench_out_dir,
+            baseline_path,
+            check_request,
+        ));
+    }
+
+    let mut collect_outcome = |bench_name: String,
+                               bench_out_dir: PathBuf,
+                               baseline_path: PathBuf,
+                               outcome: CheckOutcome|
+     -> anyhow::Result<()> {
+        if correct_fdr {
+            pending.push((bench_name, bench_out_dir, baseline_path, outcome));
+            Ok(())
+        } else {
+            finish_bench(&bench_name, &bench_out_dir, &baseline_path, outcome)
+        }
+    };
+
+    let slots = parallel::worker_slots(req.jobs, prepared.len());
+    if req.interleave {
+        if let Some((bench_name, ..)) = prepared.iter().find(|(_, _, _, check_request)| {
+            check_request.config.benches.iter().any(|bench| {
+                bench.name == check_request.bench_name
+                    && (bench.service.is_some() || bench.load.is_some())
+            })
+        }) {
+            anyhow::bail!(
+                "--interleave does not support service or load benches; bench '{}' has a [bench.service] or [bench.load]",
+                bench_name
+            );
+        }
+        // Mask to 53 bits so the seed survives JSON tools that read numbers as doubles.
+        let seed = req
+            .seed
+            .unwrap_or_else(|| uuid::Uuid::new_v4().as_u64_pair().0 & ((1 << 53) - 1));
+        let planner = CheckUseCase::new(StdProcessRunner, StdHostProbe, SystemClock);
+        let run_requests = prepared
+            .iter()
+            .map(|(_, _, _, check_request)| planner.run_request(check_request))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        let interleave = SampleInterleave {
+            seed,
+            benches: prepared.iter().map(|(name, ..)| name.clone()).collect(),
+        };
+        eprintln!(
+            "note: interleaving the samples of {} benches with seed {} (replay with --interleave --seed {})",
+            prepared.len(),
+            seed,
+            seed
+        );
+
+        let recorded = run_interleaved_samples(&StdProcessRunner, &run_requests, seed);
+        for ((bench_name, bench_out_dir, baseline_path, check_request), (run_request, samples)) in
+            prepared
+                .into_iter()
+                .zip(run_requests.into_iter().zip(recorded))
+        {
+            let runner = ReplayRunner::new(StdProcessRunner, run_request.command, samples);
+            let check_request = CheckRequest {
+                interleave: Some(interleave.clone()),
+                ..check_request
+            };
+            let outcome = execute_check_bench(&req, runner, check_request)?;
+            collect_outcome(bench_name, bench_out_dir, baseline_path, outcome)?;
+        }
+    } else if slots.is_empty() {
+        for (bench_name, bench_out_dir, baseline_path, check_request) in prepared {
+            let outcome = execute_check_bench(&req, StdProcessRunner, check_request)?;
+            collect_outcome(bench_name, bench_out_dir, baseline_path, outcome)?;
+        }
+    } else {
+        eprintln!(
+            "note: running {} benches with {} concurrent jobs",
+            prepared.len(),
+            slots[0].jobs
+        );
+        let executed = parallel::run_in_slots(prepared, &slots, |prepared, slot| {
+            let (bench_name, bench_out_dir, baseline_path, check_request) = prepared;
+            let check_request = CheckRequest {
+                concurrency: Some(slot.clone()),
+                ..check_request
+            };
+            let outcome = execute_check_bench(&req, StdProcessRunner, check_request);
+            (bench_name, bench_out_dir, baseline_path, outcome)
+        });
+        // Artifacts are written in selection order once every bench is done.
+        for (bench_name, bench_out_dir, baseline_path, outcome) in executed {
+            collect_outcome(bench_name, bench_out_dir, baseline_path, outcome?)?;
+        }
+    }
+
+    if correct_fdr {
+        let (names, mut outcomes): (Vec<_>, Vec<CheckOutcome>) = pending
+            .into_iter()
+            .map(|(name, dir, baseline, outcome)| ((name, dir, baseline), outcome))
+            .unzip();
+        apply_check_false_discovery_rate(&mut outcomes, req.require_significance, req.fail_on_warn);
+        for ((bench_name, bench_out_dir, baseline_path), outcome) in names.into_iter().zip(outcomes)
+        {
+            finish_bench(&bench_name, &bench_out_dir, &baseline_path, outcome)?;
+        }
     }
 
     if let Some(path) = github_output_path.as_deref() {
@@ -7092,6 +7982,41 @@ fn run_check_standard(req: CheckConfig) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Run one bench's check with `runner`, then confirm a flagged regression if
+/// `--confirm` is set.
+fn execute_check_bench<R: ProcessRunner + Clone>(
+    req: &CheckConfig,
+    runner: R,
+    check_request: CheckRequest,
+) -> anyhow::Result<CheckOutcome> {
+    let bench_name = check_request.bench_name.clone();
+    let bench_out_dir = check_request.out_dir.clone();
+    let usecase = CheckUseCase::new(runner, StdHostProbe, SystemClock);
+    // Confirmation samples run on their own, never interleaved.
+    let confirm_request = req.confirm.as_ref().map(|_| CheckRequest {
+        interleave: None,
+        ..check_request.clone()
+    });
+    let mut outcome = match usecase.execute(check_request) {
+        Ok(outcome) => outcome,
+        Err(error) => {
+            print_check_failure_guidance(
+                classify_check_error(&error),
+                &req.config_path,
+                Some(&bench_name),
+                Some(&bench_out_dir),
+                None,
+            );
+            return Err(error);
+        }
+    };
+
+    if let (Some(strategy), Some(request)) = (&req.confirm, confirm_request) {
+        confirm_check_outcome(&mut outcome, strategy, request, req.pretty)?;
+    }
+    Ok(outcome)
+}
+
 /// Run check in cockpit mode (always write receipt, exit 0 unless catastrophic).
 fn run_check_cockpit(req: CheckConfig) -> anyhow::Result<()> {
     let clock = SystemClock;
@@ -7175,18 +8100,9 @@ fn run_check_cockpit_inner(
     let config_content = fs::read_to_string(&req.config_path)
         .with_context(|| format!("read {}", req.config_path.display()))?;
 
-    let config_file: ConfigFile = if req
-        .config_path
-        .extension()
-        .map(|e| e == "json")
-        .unwrap_or(false)
-    {
-        serde_json::from_str(&config_content)
-            .with_context(|| format!("parse JSON config {}", req.config_path.display()))?
-    } else {
-        toml::from_str(&config_content)
-            .with_context(|| format!("parse TOML config {}", req.config_path.display()))?
-    };
+    let resolved = resolve_config_str_with_profile(&req.config_path, &config_content, &req.profile)
+        .with_context(|| format!("load config {}", req.config_path.display()))?;
+    let config_file = resolved.config;
 
     config_file
         .validate()
@@ -7198,6 +8114,12 @@ fn run_check_cockpit_inner(
         req.bench.as_deref(),
         req.all,
         req.bench_regex.as_deref(),
+        &req.tags,
+    )?;
+    let plan = plan_changed_benches(
+        &config_file,
+        bench_names.clone(),
+        req.changed_since.as_deref(),
     )?;
     let markdown_template_path = req.md_template.clone().or_else(|| {
         config_file
@@ -7210,55 +8132,119 @@ fn run_check_cockpit_inner(
     let out_dir = resolve_configured_out_dir(req.out_dir.as_ref(), Some(&config_file));
 
     let multi_bench = bench_names.len() > 1;
+    if req.jobs > 1 {
+        eprintln!("warning: --jobs is not applied in cockpit mode; benches run serially");
+    }
+    if req.interleave {
+        eprintln!(
+            "warning: --interleave is not applied in cockpit mode; samples are not interleaved"
+        );
+    }
 
-    // Collect per-bench outcomes
-    let mut bench_outcomes: Vec<BenchOutcome> = Vec::new();
-
+    // Every bench is executed before any artifact is written, so --fdr can
+    // correct across the whole selection. Skipped benches stay `None`.
+    let merge_base = prepare_merge_base_search(req, &config_file);
+    let mut executed: Vec<Option<anyhow::Result<(PathBuf, PathBuf, CheckOutcome)>>> = Vec::new();
     for bench_name in &bench_names {
-        let outcome: BenchOutcome = (|| -> anyhow::Result<BenchOutcome> {
-            // Create extras directory for native artifacts
-            let extras_dir = if multi_bench {
-                out_dir.join("extras").join(bench_name)
-            } else {
-                out_dir.join("extras")
-            };
-            fs::create_dir_all(&extras_dir).map_err(|e| {
-                PerfgateError::Io(IoError::ArtifactWrite(format!(
-                    "create extras dir {}: {}",
-                    extras_dir.display(),
-                    e
-                )))
-            })?;
+        if plan.skipped.iter().any(|(name, _)| name == bench_name) {
+            executed.push(None);
+            continue;
+        }
+
+        executed.push(Some(
+            (|| -> anyhow::Result<(PathBuf, PathBuf, CheckOutcome)> {
+                // Create extras directory for native artifacts
+                let extras_dir = if multi_bench {
+                    out_dir.join("extras").join(bench_name)
+                } else {
+                    out_dir.join("extras")
+                };
+                fs::create_dir_all(&extras_dir).map_err(|e| {
+                    PerfgateError::Io(IoError::ArtifactWrite(format!(
+                        "create extras dir {}: {}",
+                        extras_dir.display(),
+                        e
+                    )))
+                })?;
 
-            // Resolve baseline path
-            let baseline_path = resolve_baseline_path(&req.baseline, bench_name, &config_file);
-            let baseline_receipt = load_optional_baseline_receipt(&baseline_path)
-                .map_err(|e| PerfgateError::Io(IoError::BaselineResolve(e.to_string())))?;
+                // Resolve baseline path
+                let (baseline_path, baseline_receipt, baseline_selection) =
+                    resolve_check_baseline(req, bench_name, &config_file, merge_base.as_ref())?;
+
+                // Execute check
+                let runner = StdProcessRunner;
+                let host_probe = StdHostProbe;
+                let usecase = CheckUseCase::new(runner, host_probe, clock.clone());
+
+                let check_request = CheckRequest {
+                    config: config_file.clone(),
+                    bench_name: bench_name.clone(),
+                    out_dir: extras_dir.clone(),
+                    baseline: baseline_receipt,
+                    baseline_path: Some(baseline_path.clone()),
+                    baseline_selection,
+                    require_baseline: req.require_baseline,
+                    fail_on_warn: req.fail_on_warn,
+                    noise_threshold: req.noise_threshold,
+                    noise_policy: req.noise_policy,
+                    tool: tool_info(),
+                    env: req.env.clone(),
+                    output_cap_bytes: req.output_cap_bytes,
+                    allow_nonzero: req.allow_nonzero,
+                    host_mismatch_policy: req.host_mismatch,
+                    significance_alpha: req.significance_alpha,
+                    significance_min_samples: req.significance_min_samples,
+                    require_significance: req.require_significance,
+                    threshold_history: load_threshold_history(
+                        bench_name,
+                        &config_file,
+                        &req.server_flags,
+                    ),
+                    profile: resolved.profile.clone(),
+                    concurrency: None,
+                    interleave: None,
+                };
+                let confirm_request = req.confirm.as_ref().map(|_| check_request.clone());
+                let mut check_outcome = usecase.execute(check_request)?;
 
-            // Execute check
-            let runner = StdProcessRunner;
-            let host_probe = StdHostProbe;
-            let usecase = CheckUseCase::new(runner, host_probe, clock.clone());
+                if let (Some(strategy), Some(request)) = (&req.confirm, confirm_request) {
+                    confirm_check_outcome(&mut check_outcome, strategy, request, req.pretty)?;
+                }
+                Ok((extras_dir, baseline_path, check_outcome))
+            })(),
+        ));
+    }
 
-            let check_outcome = usecase.execute(CheckRequest {
-                config: config_file.clone(),
-                bench_name: bench_name.clone(),
-                out_dir: extras_dir.clone(),
-                baseline: baseline_receipt,
-                baseline_path: Some(baseline_path.clone()),
-                require_baseline: req.require_baseline,
-                fail_on_warn: req.fail_on_warn,
-                noise_threshold: req.noise_threshold,
-                noise_policy: req.noise_policy,
-                tool: tool_info(),
-                env: req.env.clone(),
-                output_cap_bytes: req.output_cap_bytes,
-                allow_nonzero: req.allow_nonzero,
-                host_mismatch_policy: req.host_mismatch,
-                significance_alpha: req.significance_alpha,
-                significance_min_samples: req.significance_min_samples,
-                require_significance: req.require_significance,
-            })?;
+    if req.fdr && req.significance_alpha.is_some() {
+        apply_check_false_discovery_rate(
+            executed
+                .iter_mut()
+                .flatten()
+                .filter_map(|executed| executed.as_mut().ok())
+                .map(|(_, _, check_outcome)| check_outcome),
+            req.require_significance,
+            req.fail_on_warn,
+        );
+    }
+
+    // Collect per-bench outcomes
+    let mut bench_outcomes: Vec<BenchOutcome> = Vec::new();
+
+    for (bench_name, executed) in bench_names.iter().zip(executed) {
+        let Some(executed) = executed else {
+            if let Some((_, reason)) = plan.skipped.iter().find(|(name, _)| name == bench_name) {
+                bench_outcomes.push(BenchOutcome::Success {
+                    bench_name: bench_name.clone(),
+                    report: Box::new(build_skipped_report(reason)),
+                    markdown: render_skipped_markdown(bench_name, reason),
+                    extras_prefix: None,
+                });
+            }
+            continue;
+        };
+
+        let outcome: BenchOutcome = (|| -> anyhow::Result<BenchOutcome> {
+            let (extras_dir, baseline_path, check_outcome) = executed?;
 
             // Submit verdict to server if configured
             if let Some(compare) = &check_outcome.compare_receipt {
@@ -7298,10 +8284,16 @@ fn run_check_cockpit_inner(
             }
 
             let final_markdown = if let Some(compare) = &check_outcome.compare_receipt {
-                let rendered = render_markdown_with_optional_template(
+                let mut rendered = render_markdown_with_optional_template(
                     compare,
                     markdown_template_path.as_deref(),
                 )?;
+                if let Some(selection) = &check_outcome.report.baseline_selection {
+                    rendered.push_str(&perfgate_app::render_baseline_selection_section(selection));
+                }
+                if let Some(confirmation) = &check_outcome.report.confirmation {
+                    rendered.push_str(&perfgate_app::render_confirmation_section(confirmation));
+                }
                 atomic_write(&check_outcome.markdown_path, rendered.as_bytes())
                     .map_err(|e| PerfgateError::Io(IoError::ArtifactWrite(e.to_string())))?;
                 rendered
@@ -7363,8 +8355,11 @@ fn run_check_cockpit_inner(
         None
     };
 
+    // Benches skipped by --changed-since never need a baseline.
     let all_baseline_available = bench_outcomes.iter().all(|o| match o {
-        BenchOutcome::Success { report, .. } => report.compare.is_some(),
+        BenchOutcome::Success { report, .. } => {
+            report.compare.is_some() || report.skip_reason.is_some()
+        }
         _ => false,
     });
 
@@ -7549,8 +8544,10 @@ fn run_watch(args: WatchArgs) -> anyhow::Result<()> {
 
     let WatchArgs {
         config: config_path,
+        config_profile,
         bench,
         all,
+        tag_selection,
         debounce,
         no_clear,
         watch_dir,
@@ -7562,25 +8559,20 @@ fn run_watch(args: WatchArgs) -> anyhow::Result<()> {
     let config_content = fs::read_to_string(&config_path)
         .with_context(|| format!("read {}", config_path.display()))?;
 
-    let config_file: ConfigFile = if config_path
-        .extension()
-        .map(|e| e == "json")
-        .unwrap_or(false)
-    {
-        serde_json::from_str(&config_content)
-            .with_context(|| format!("parse JSON config {}", config_path.display()))?
-    } else {
-        toml::from_str(&config_content)
-            .with_context(|| format!("parse TOML config {}", config_path.display()))?
-    };
+    let resolved =
+        resolve_config_str_with_profile(&config_path, &config_content, &config_profile.into())
+            .with_context(|| format!("load config {}", config_path.display()))?;
+    let config_file = resolved.config;
 
     config_file
         .validate()
         .map_err(ConfigValidationError::ConfigFile)?;
 
+    let tags = tag_selection.into_filter();
+
     // Determine which bench to run
-    let bench_name = if all {
-        // For --all, we run each bench sequentially on each change
+    let bench_name = if all || !tags.is_empty() {
+        // For --all and tag selection, we run each bench sequentially on each change
         None
     } else if let Some(name) = bench {
         Some(name)
@@ -7615,10 +8607,14 @@ fn run_watch(args: WatchArgs) -> anyhow::Result<()> {
         }
         vec![name.clone()]
     } else {
-        config_file.benches.iter().map(|b| b.name.clone()).collect()
+        select_tagged_bench_names(&config_file, &tags)?
     };
 
-    let display_name = bench_name.as_deref().unwrap_or("all").to_string();
+    let display_name = match &bench_name {
+        Some(name) => name.clone(),
+        None if tags.is_empty() => "all".to_string(),
+        None => tags.describe(),
+    };
 
     // Setup Ctrl+C handler
     let running = Arc::new(AtomicBool::new(true));
@@ -7681,6 +8677,7 @@ fn run_watch(args: WatchArgs) -> anyhow::Result<()> {
         out_dir: &out_dir,
         env: &env,
         host_mismatch_policy: host_mismatch,
+        profile: resolved.profile.as_deref(),
         no_clear,
         display_name: &display_name,
     };
@@ -7727,6 +8724,7 @@ struct WatchIterationCtx<'a> {
     out_dir: &'a Path,
     env: &'a [(String, String)],
     host_mismatch_policy: HostMismatchPolicy,
+    profile: Option<&'a str>,
     no_clear: bool,
     display_name: &'a str,
 }
@@ -7762,6 +8760,7 @@ fn run_watch_iteration(ctx: &WatchIterationCtx<'_>, state: &mut WatchState) {
             env: ctx.env.to_vec(),
             output_cap_bytes: 8192,
             host_mismatch_policy: ctx.host_mismatch_policy,
+            profile: ctx.profile.map(str::to_string),
         };
 
         // Print status
@@ -7898,6 +8897,10 @@ mod tests {
                     memory_bytes: Some(8 * 1024 * 1024 * 1024),
                     hostname_hash: None,
                 },
+                profile: None,
+                concurrency: None,
+                interleave: None,
+                noise_warnings: Vec::new(),
             },
             bench: BenchMeta {
                 name: "bench".to_string(),
@@ -7907,6 +8910,8 @@ mod tests {
                 warmup: 0,
                 work_units: None,
                 timeout_ms: None,
+                service: None,
+                load: None,
             },
             samples: Vec::new(),
             stats,
@@ -7926,6 +8931,19 @@ mod tests {
             energy_uj: None,
             binary_bytes: None,
             throughput_per_s: None,
+            latency_p50_us: None,
+            latency_p95_us: None,
+            latency_p99_us: None,
+            error_rate: None,
+            time_to_ready_ms: None,
+            instructions: None,
+            estimated_cycles: None,
+            l1_hits: None,
+            ll_hits: None,
+            ram_hits: None,
+            alloc_bytes: None,
+            allocs: None,
+            custom: Default::default(),
         }
     }
 
@@ -8307,6 +9325,9 @@ mod tests {
             },
             complexity: None,
             profile_path: None,
+            confirmation: None,
+            skip_reason: None,
+            baseline_selection: None,
         };
 
         let outcome = CheckOutcome {
@@ -8368,6 +9389,9 @@ mod tests {
             },
             complexity: None,
             profile_path: None,
+            confirmation: None,
+            skip_reason: None,
+            baseline_selection: None,
         };
 
         let outcome = CheckOutcome {
@@ -8429,6 +9453,9 @@ mod tests {
                 },
                 complexity: None,
                 profile_path: Some("profiles/bench.svg".to_string()),
+                confirmation: None,
+                skip_reason: None,
+                baseline_selection: None,
             },
             report_path: out_dir.join("report.json"),
             markdown: String::new(),
@@ -8478,6 +9505,9 @@ mod tests {
                 },
                 complexity: None,
                 profile_path: None,
+                confirmation: None,
+                skip_reason: None,
+                baseline_selection: None,
             },
             report_path: out_dir.join("report.json"),
             markdown: String::new(),
@@ -8527,6 +9557,9 @@ mod tests {
                 },
                 complexity: None,
                 profile_path: None,
+                confirmation: None,
+                skip_reason: None,
+                baseline_selection: None,
             },
             report_path: out_dir.join("report.json"),
             markdown: String::new(),
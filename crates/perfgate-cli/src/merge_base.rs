@@ -0,0 +1,249 @@
+//! Merge-base aware baseline selection for `perfgate check`.
+//!
+//! Instead of comparing against whatever baseline currently sits at the
+//! configured path, `check --merge-base <branch>` computes the merge-base of
+//! `HEAD` and the target branch and walks its first-parent ancestors until it
+//! finds a commit with a recorded baseline. Recorded baselines are looked up
+//! in the local history store (`[defaults].baseline_history_dir`) first and in
+//! the baseline server's git SHA index second. The merge-base, its ancestors
+//! and the server index are computed once per `check` and shared by its benches.
+
+use crate::ServerFlags;
+use crate::repair_context::run_git_capture;
+use crate::storage::{location_exists, read_json_from_location, with_tokio_runtime};
+use anyhow::Context;
+use chrono::{DateTime, Utc};
+use perfgate::app::baseline_resolve::{
+    DEFAULT_MERGE_BASE_SEARCH_DEPTH, git_sha_matches, render_history_baseline_path,
+    select_nearest_recorded_ancestor,
+};
+use perfgate_client::{BaselineClient, BaselineSummary, ListBaselinesQuery};
+use perfgate_types::{ConfigFile, MergeBaseSelection, RunReceipt};
+use std::cell::OnceCell;
+use std::path::PathBuf;
+
+/// Baseline chosen by merge-base aware selection.
+pub(crate) struct MergeBaseBaseline {
+    /// Location label of the selected baseline (history path or `@server:` reference).
+    pub path: PathBuf,
+    pub receipt: RunReceipt,
+    pub selection: MergeBaseSelection,
+}
+
+/// The merge-base of `HEAD` and a target branch, and the first-parent
+/// ancestors searched for recorded baselines.
+///
+/// Computed once per `check` invocation and shared by all of its benches,
+/// along with the server index, which is fetched the first time a bench needs it.
+pub(crate) struct MergeBaseSearch {
+    target: String,
+    merge_base: String,
+    ancestors: Vec<String>,
+    /// Commit time of the oldest searched ancestor; no baseline created before
+    /// it can have been recorded for one of the ancestors.
+    oldest_commit_at: Option<DateTime<Utc>>,
+    server: OnceCell<Option<ServerIndex>>,
+}
+
+impl MergeBaseSearch {
+    /// Compute the merge-base of `HEAD` and `target` and list up to
+    /// [`DEFAULT_MERGE_BASE_SEARCH_DEPTH`] of its first-parent ancestors.
+    pub(crate) fn new(target: &str) -> anyhow::Result<Self> {
+        let merge_base = run_git_capture(&["merge-base", "HEAD", target])
+            .with_context(|| format!("could not compute merge-base of HEAD and {target}"))?;
+        let max_count = format!("--max-count={}", DEFAULT_MERGE_BASE_SEARCH_DEPTH);
+        let ancestors: Vec<String> =
+            run_git_capture(&["rev-list", "--first-parent", &max_count, &merge_base])
+                .with_context(|| format!("could not list ancestors of {merge_base}"))?
+                .lines()
+                .map(|line| line.trim().to_string())
+                .filter(|line| !line.is_empty())
+                .collect();
+        let oldest_commit_at = ancestors.last().and_then(|oldest| {
+            let committed = run_git_capture(&["log", "-1", "--format=%cI", oldest])?;
+            DateTime::parse_from_rfc3339(committed.trim())
+                .ok()
+                .map(|at| at.with_timezone(&Utc))
+        });
+
+        Ok(Self {
+            target: target.to_string(),
+            merge_base,
+            ancestors,
+            oldest_commit_at,
+            server: OnceCell::new(),
+        })
+    }
+
+    pub(crate) fn target(&self) -> &str {
+        &self.target
+    }
+
+    /// Find the baseline recorded for `bench_name` at the merge-base, or at
+    /// the nearest first-parent ancestor of it that has one.
+    ///
+    /// Returns `Ok(None)` when no ancestor within the search depth has a
+    /// recorded baseline.
+    pub(crate) fn resolve(
+        &self,
+        bench_name: &str,
+        config: &ConfigFile,
+        server_flags: &ServerFlags,
+    ) -> anyhow::Result<Option<MergeBaseBaseline>> {
+        let history_dir = config.defaults.baseline_history_dir.as_deref();
+        let server = self
+            .server
+            .get_or_init(|| ServerIndex::load(self.oldest_commit_at, config, server_flags))
+            .as_ref();
+
+        let has_history = |sha: &str| {
+            history_dir.is_some_and(|dir| {
+                location_exists(&render_history_baseline_path(dir, sha, bench_name))
+                    .unwrap_or(false)
+            })
+        };
+
+        let Some((distance, selected_sha)) =
+            select_nearest_recorded_ancestor(&self.ancestors, |sha| {
+                has_history(sha)
+                    || server.is_some_and(|index| index.find(bench_name, sha).is_some())
+            })
+        else {
+            return Ok(None);
+        };
+
+        let selection = MergeBaseSelection {
+            target: self.target.clone(),
+            merge_base: self.merge_base.clone(),
+            selected_sha: selected_sha.to_string(),
+            distance,
+        };
+
+        if let Some(dir) = history_dir.filter(|_| has_history(selected_sha)) {
+            let path = render_history_baseline_path(dir, selected_sha, bench_name);
+            let receipt: RunReceipt = read_json_from_location(&path)
+                .with_context(|| format!("read history baseline {}", path.display()))?;
+            return Ok(Some(MergeBaseBaseline {
+                path,
+                receipt,
+                selection,
+            }));
+        }
+
+        let index = server.context("selected baseline is missing from the server index")?;
+        let summary = index
+            .find(bench_name, selected_sha)
+            .context("selected baseline is missing from the server index")?;
+        let receipt = index.fetch(summary)?;
+        Ok(Some(MergeBaseBaseline {
+            path: PathBuf::from(format!("@server:{}@{}", bench_name, summary.version)),
+            receipt,
+            selection,
+        }))
+    }
+}
+
+/// Baselines requested per page while building the server index.
+const SERVER_INDEX_PAGE_SIZE: u32 = 100;
+
+/// Most pages fetched while building the server index: enough for every
+/// searched ancestor to carry a full page of benches.
+const SERVER_INDEX_MAX_PAGES: usize = DEFAULT_MERGE_BASE_SEARCH_DEPTH;
+
+/// Baselines the server holds for the project, indexed by benchmark and git SHA.
+struct ServerIndex {
+    client: BaselineClient,
+    project: String,
+    baselines: Vec<BaselineSummary>,
+}
+
+impl ServerIndex {
+    /// Fetch the project's server index, or `None` when no server is
+    /// configured or it cannot be reached.
+    ///
+    /// The server lists baselines newest first, so paging stops at the first
+    /// baseline created before `oldest_commit_at`, when the list runs out, or
+    /// after [`SERVER_INDEX_MAX_PAGES`] pages.
+    fn load(
+        oldest_commit_at: Option<DateTime<Utc>>,
+        config: &ConfigFile,
+        server_flags: &ServerFlags,
+    ) -> Option<Self> {
+        let server_config = server_flags.resolve(&config.baseline_server);
+        server_config.url.as_ref()?;
+
+        let result = (|| -> anyhow::Result<Self> {
+            let client = server_config
+                .require_client("baseline server is not configured for merge-base selection")?;
+            let project = server_config.resolve_project(None)?;
+            let mut index = Self {
+                client,
+                project,
+                baselines: Vec::new(),
+            };
+            for _ in 0..SERVER_INDEX_MAX_PAGES {
+                let query = ListBaselinesQuery::new()
+                    .with_limit(SERVER_INDEX_PAGE_SIZE)
+                    .with_offset(index.baselines.len() as u64);
+                let response = with_tokio_runtime(async {
+                    Ok(index.client.list_baselines(&index.project, &query).await?)
+                })?;
+                let exhausted = !response.pagination.has_more || response.baselines.is_empty();
+                let reached_oldest = oldest_commit_at.is_some_and(|oldest| {
+                    response
+                        .baselines
+                        .last()
+                        .is_some_and(|summary| summary.created_at < oldest)
+                });
+                index.baselines.extend(response.baselines);
+                if exhausted || reached_oldest {
+                    return Ok(index);
+                }
+            }
+            eprintln!(
+                "warning: merge-base selection read only the newest {} baselines from the server",
+                index.baselines.len()
+            );
+            Ok(index)
+        })();
+
+        match result {
+            Ok(index) => Some(index),
+            Err(err) => {
+                eprintln!(
+                    "warning: could not query baseline server for merge-base selection: {:#}",
+                    err
+                );
+                None
+            }
+        }
+    }
+
+    fn find(&self, bench_name: &str, sha: &str) -> Option<&BaselineSummary> {
+        self.baselines.iter().find(|summary| {
+            summary.benchmark == bench_name
+                && summary
+                    .git_sha
+                    .as_deref()
+                    .is_some_and(|recorded| git_sha_matches(recorded, sha))
+        })
+    }
+
+    fn fetch(&self, summary: &BaselineSummary) -> anyhow::Result<RunReceipt> {
+        if let Some(receipt) = &summary.receipt {
+            return Ok(receipt.clone());
+        }
+        let record = with_tokio_runtime(async {
+            self.client
+                .get_baseline_version(&self.project, &summary.benchmark, &summary.version)
+                .await
+                .with_context(|| {
+                    format!(
+                        "Failed to fetch baseline '{}' version {} from server (project: {})",
+                        summary.benchmark, summary.version, self.project
+                    )
+                })
+        })?;
+        Ok(record.receipt)
+    }
+}
@@ -0,0 +1,124 @@
+//! Parallel bench execution for `perfgate check --jobs`.
+//!
+//! The CPUs available to perfgate are split into one disjoint set per worker.
+//! Each worker takes the next bench off a shared queue and runs it pinned to
+//! its set, so concurrently running benches never share a core. Every run
+//! receipt records the concurrency level and its cores, which lets compare
+//! flag baselines captured with a different level.
+
+use perfgate::runtime::{CPU_PINNING_SUPPORTED, available_cpus, partition_cpus};
+use perfgate_types::RunConcurrency;
+use std::sync::Mutex;
+
+/// Worker slots for running `bench_count` benches with up to `jobs` at once.
+///
+/// Returns an empty list when the benches should run serially: `--jobs 1`, a
+/// single bench, or a single available CPU.
+pub(crate) fn worker_slots(jobs: u32, bench_count: usize) -> Vec<RunConcurrency> {
+    let workers = (jobs as usize).min(bench_count);
+    if workers <= 1 {
+        return Vec::new();
+    }
+
+    let cpus = available_cpus();
+    let sets = partition_cpus(&cpus, workers);
+    if sets.len() < workers {
+        eprintln!(
+            "warning: --jobs {} exceeds the {} available CPUs; running {} benches at a time",
+            jobs,
+            cpus.len(),
+            sets.len()
+        );
+    }
+    if sets.len() <= 1 {
+        return Vec::new();
+    }
+    if !CPU_PINNING_SUPPORTED {
+        eprintln!(
+            "warning: CPU pinning is not supported on this platform; parallel benches share all cores"
+        );
+    }
+
+    let jobs = sets.len() as u32;
+    sets.into_iter()
+        .map(|cpus| RunConcurrency {
+            jobs,
+            cpus: if CPU_PINNING_SUPPORTED {
+                cpus
+            } else {
+                Vec::new()
+            },
+        })
+        .collect()
+}
+
+/// Run `work` over `items` with one thread per slot, returning the results in
+/// the order of `items`.
+pub(crate) fn run_in_slots<T, R, F>(items: Vec<T>, slots: &[RunConcurrency], work: F) -> Vec<R>
+where
+    T: Send,
+    R: Send,
+    F: Fn(T, &RunConcurrency) -> R + Sync,
+{
+    let count = items.len();
+    let queue = Mutex::new(items.into_iter().enumerate());
+    let results: Mutex<Vec<Option<R>>> = Mutex::new((0..count).map(|_| None).collect());
+
+    std::thread::scope(|scope| {
+        for slot in slots {
+            let (queue, results, work) = (&queue, &results, &work);
+            scope.spawn(move || {
+                loop {
+                    let next = queue.lock().expect("bench queue poisoned").next();
+                    let Some((index, item)) = next else {
+                        break;
+                    };
+                    let result = work(item, slot);
+                    results.lock().expect("bench results poisoned")[index] = Some(result);
+                }
+            });
+        }
+    });
+
+    results
+        .into_inner()
+        .expect("bench results poisoned")
+        .into_iter()
+        .map(|result| result.expect("every queued bench produces a result"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn serial_when_one_job_or_one_bench() {
+        assert!(worker_slots(1, 10).is_empty());
+        assert!(worker_slots(8, 1).is_empty());
+    }
+
+    #[test]
+    fn run_in_slots_preserves_order_and_uses_every_slot() {
+        let slots = vec![
+            RunConcurrency {
+                jobs: 2,
+                cpus: vec![0],
+            },
+            RunConcurrency {
+                jobs: 2,
+                cpus: vec![1],
+            },
+        ];
+        let used = Mutex::new(BTreeSet::new());
+        let results = run_in_slots((0..20).collect(), &slots, |n: u32, slot| {
+            used.lock().unwrap().insert(slot.cpus.clone());
+            std::thread::sleep(std::time::Duration::from_millis(2));
+            n * 10
+        });
+
+        assert_eq!(results, (0..20).map(|n| n * 10).collect::<Vec<_>>());
+        assert_eq!(used.into_inner().unwrap().len(), 2);
+    }
+}
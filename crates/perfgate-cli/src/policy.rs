@@ -3,7 +3,7 @@
 use clap::{Args, Subcommand, ValueEnum};
 use perfgate_types::config::load_config_file;
 use perfgate_types::error::ConfigValidationError;
-use perfgate_types::{CompareReceipt, ConfigFile, Metric, NoisePolicy};
+use perfgate_types::{BudgetThreshold, CompareReceipt, ConfigFile, Metric, NoisePolicy};
 use std::fs;
 use std::path::{Path, PathBuf};
 
@@ -625,7 +625,7 @@ fn patch_budget_suggestion(config: &ConfigFile, bench_name: &str) -> PolicyBudge
 
     PolicyBudgetSuggestion {
         threshold: wall_budget
-            .and_then(|budget| budget.threshold)
+            .and_then(|budget| budget.threshold.and_then(BudgetThreshold::fixed))
             .or(config.defaults.threshold)
             .unwrap_or(0.20),
         warn_factor: wall_budget
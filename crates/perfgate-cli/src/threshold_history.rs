@@ -0,0 +1,127 @@
+//! History lookup for `threshold = "auto"` budgets.
+//!
+//! Auto thresholds are derived from the run-to-run variance of previously
+//! recorded runs of the same bench. Those runs are read from the local history
+//! store (`[defaults].baseline_history_dir`, laid out as `<sha>/<bench>.json`)
+//! first and from the baseline server's version list second.
+
+use crate::ServerFlags;
+use crate::storage::{read_json, with_tokio_runtime};
+use perfgate::app::ThresholdHistory;
+use perfgate::app::baseline_resolve::is_remote_storage_uri;
+use perfgate_client::ListBaselinesQuery;
+use perfgate_types::{AutoThresholdSource, BudgetThreshold, ConfigFile, RunReceipt};
+use std::path::Path;
+
+/// Maximum number of historical runs an auto threshold is derived from.
+pub(crate) const AUTO_THRESHOLD_HISTORY_LIMIT: usize = 30;
+
+/// Load the history used by `threshold = "auto"` budgets of `bench_name`.
+///
+/// Returns `None` when the bench has no auto budgets, so benches with fixed
+/// thresholds never touch the history store or the server.
+pub(crate) fn load_threshold_history(
+    bench_name: &str,
+    config: &ConfigFile,
+    server_flags: &ServerFlags,
+) -> Option<ThresholdHistory> {
+    let bench = config
+        .benches
+        .iter()
+        .find(|bench| bench.name == bench_name)?;
+    let has_auto = bench.budgets.as_ref().is_some_and(|budgets| {
+        budgets
+            .values()
+            .any(|budget| budget.threshold.is_some_and(BudgetThreshold::is_auto))
+    });
+    if !has_auto {
+        return None;
+    }
+
+    if let Some(dir) = config
+        .defaults
+        .baseline_history_dir
+        .as_deref()
+        .filter(|dir| !is_remote_storage_uri(dir))
+    {
+        let receipts = load_history_dir(Path::new(dir), bench_name);
+        if !receipts.is_empty() {
+            return Some(ThresholdHistory {
+                source: AutoThresholdSource::HistoryDir,
+                receipts,
+            });
+        }
+    }
+
+    load_server_history(bench_name, config, server_flags)
+}
+
+/// Read `<dir>/*/<bench>.json`, newest runs first.
+fn load_history_dir(dir: &Path, bench_name: &str) -> Vec<RunReceipt> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let file_name = format!("{}.json", bench_name);
+    let mut receipts: Vec<RunReceipt> = entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path().join(&file_name))
+        .filter(|path| path.is_file())
+        .filter_map(|path| match read_json::<RunReceipt>(&path) {
+            Ok(receipt) => Some(receipt),
+            Err(err) => {
+                eprintln!(
+                    "warning: skipping unreadable history receipt {}: {:#}",
+                    path.display(),
+                    err
+                );
+                None
+            }
+        })
+        .filter(|receipt| receipt.bench.name == bench_name)
+        .collect();
+    receipts.sort_by(|a, b| b.run.started_at.cmp(&a.run.started_at));
+    receipts.truncate(AUTO_THRESHOLD_HISTORY_LIMIT);
+    receipts
+}
+
+/// Fetch recent baseline versions of `bench_name` from the server, or `None`
+/// when no server is configured or it cannot be reached.
+fn load_server_history(
+    bench_name: &str,
+    config: &ConfigFile,
+    server_flags: &ServerFlags,
+) -> Option<ThresholdHistory> {
+    let server_config = server_flags.resolve(&config.baseline_server);
+    server_config.url.as_ref()?;
+
+    let result = (|| -> anyhow::Result<Vec<RunReceipt>> {
+        let client = server_config
+            .require_client("baseline server is not configured for auto thresholds")?;
+        let project = server_config.resolve_project(None)?;
+        let query = ListBaselinesQuery::new()
+            .with_benchmark(bench_name)
+            .with_limit(AUTO_THRESHOLD_HISTORY_LIMIT as u32)
+            .with_receipts();
+        let response =
+            with_tokio_runtime(async { Ok(client.list_baselines(&project, &query).await?) })?;
+        Ok(response
+            .baselines
+            .into_iter()
+            .filter_map(|summary| summary.receipt)
+            .collect())
+    })();
+
+    match result {
+        Ok(receipts) => Some(ThresholdHistory {
+            source: AutoThresholdSource::Server,
+            receipts,
+        }),
+        Err(err) => {
+            eprintln!(
+                "warning: could not query baseline server for auto threshold history: {:#}",
+                err
+            );
+            None
+        }
+    }
+}
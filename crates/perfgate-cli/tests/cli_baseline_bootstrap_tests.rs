@@ -126,6 +126,46 @@ fn baseline_status_reports_missing_then_found_local_baseline() {
         ));
 }
 
+#[test]
+fn baseline_status_filters_benches_by_tag() {
+    let temp_dir = tempfile::tempdir().expect("create temp dir");
+    fs::write(
+        temp_dir.path().join("perfgate.toml"),
+        r#"[defaults]
+baseline_dir = "baselines"
+
+[[bench]]
+name = "fast-benchmark"
+command = ["echo", "fast"]
+tags = ["fast"]
+
+[[bench]]
+name = "nightly-benchmark"
+command = ["echo", "nightly"]
+tags = ["nightly"]
+"#,
+    )
+    .expect("write config");
+
+    perfgate_cmd()
+        .current_dir(temp_dir.path())
+        .args([
+            "baseline",
+            "status",
+            "--config",
+            "perfgate.toml",
+            "--tag",
+            "fast",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("MISSING fast-benchmark"))
+        .stdout(predicate::str::contains("nightly-benchmark").not())
+        .stdout(predicate::str::contains(
+            "perfgate check --config perfgate.toml --tag fast",
+        ));
+}
+
 #[test]
 fn baseline_doctor_reports_mature_and_missing_local_baselines() {
     let temp_dir = tempfile::tempdir().expect("create temp dir");
@@ -211,3 +211,50 @@ fn calibrate_handles_missing_run_receipt_with_next_check_command() {
         "stdout: {stdout}"
     );
 }
+
+#[test]
+fn calibrate_with_tag_reports_each_selected_bench() {
+    let temp_dir = tempdir().expect("temp dir");
+    let config_path = temp_dir.path().join("perfgate.toml");
+    fs::write(
+        &config_path,
+        r#"
+[defaults]
+out_dir = "artifacts/perfgate"
+
+[[bench]]
+name = "parser"
+command = ["echo", "parser"]
+tags = ["fast"]
+
+[[bench]]
+name = "lexer"
+command = ["echo", "lexer"]
+tags = ["fast"]
+
+[[bench]]
+name = "soak"
+command = ["echo", "soak"]
+tags = ["nightly"]
+"#,
+    )
+    .expect("write config");
+
+    let output = perfgate_cmd()
+        .current_dir(temp_dir.path())
+        .arg("calibrate")
+        .arg("--config")
+        .arg(&config_path)
+        .args(["--tag", "fast"])
+        .output()
+        .expect("run calibrate");
+    assert!(
+        output.status.success(),
+        "calibrate --tag should succeed: stderr {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Bench: parser"), "stdout: {stdout}");
+    assert!(stdout.contains("Bench: lexer"), "stdout: {stdout}");
+    assert!(!stdout.contains("Bench: soak"), "stdout: {stdout}");
+}
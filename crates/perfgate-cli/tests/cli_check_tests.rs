@@ -763,6 +763,79 @@ command = [{}]
     config_path
 }
 
+/// Test --tag and --exclude-tag select benches by their config tags
+#[test]
+fn test_check_tag_selection_runs_matching_benches() {
+    let temp_dir = tempdir().expect("failed to create temp dir");
+    let out_dir = temp_dir.path().join("artifacts");
+    let config_path = temp_dir.path().join("perfgate.toml");
+    let cmd_str = success_command()
+        .iter()
+        .map(|s| format!("\"{}\"", s))
+        .collect::<Vec<_>>()
+        .join(", ");
+    fs::write(
+        &config_path,
+        format!(
+            r#"
+[defaults]
+repeat = 2
+warmup = 0
+
+[[bench]]
+name = "parse"
+command = [{cmd_str}]
+tags = ["fast"]
+
+[[bench]]
+name = "alloc"
+command = [{cmd_str}]
+tags = ["fast", "memory"]
+
+[[bench]]
+name = "soak"
+command = [{cmd_str}]
+tags = ["nightly"]
+"#
+        ),
+    )
+    .expect("write config");
+
+    let output = perfgate_cmd()
+        .arg("check")
+        .arg("--config")
+        .arg(&config_path)
+        .args(["--tag", "fast", "--exclude-tag", "memory"])
+        .arg("--out-dir")
+        .arg(&out_dir)
+        .output()
+        .expect("failed to execute check");
+    assert!(
+        output.status.success(),
+        "check --tag should succeed: stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(out_dir.join("parse").join("run.json").exists());
+    assert!(!out_dir.join("alloc").exists());
+    assert!(!out_dir.join("soak").exists());
+
+    let output = perfgate_cmd()
+        .arg("check")
+        .arg("--config")
+        .arg(&config_path)
+        .args(["--tag", "slow"])
+        .arg("--out-dir")
+        .arg(&out_dir)
+        .output()
+        .expect("failed to execute check");
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("--tag slow did not match any benchmarks"),
+        "stderr: {stderr}"
+    );
+}
+
 /// Test --all flag runs all benches and creates per-bench subdirectories
 #[test]
 fn test_check_all_runs_all_benches() {
@@ -815,6 +888,221 @@ fn test_check_all_runs_all_benches() {
     }
 }
 
+/// Test --jobs runs benches concurrently and records the concurrency in receipts
+#[test]
+fn test_check_all_jobs_records_concurrency() {
+    let temp_dir = tempdir().expect("failed to create temp dir");
+    let out_dir = temp_dir.path().join("artifacts");
+    let config_path =
+        create_multi_bench_config(temp_dir.path(), &["bench-a", "bench-b", "bench-c"]);
+    create_baseline_receipt(temp_dir.path(), "bench-a");
+
+    let output = perfgate_cmd()
+        .current_dir(temp_dir.path())
+        .arg("check")
+        .arg("--config")
+        .arg(&config_path)
+        .arg("--all")
+        .args(["--jobs", "2", "--host-mismatch", "warn"])
+        .arg("--out-dir")
+        .arg(&out_dir)
+        .output()
+        .expect("failed to execute check");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        output.status.success(),
+        "check --all --jobs 2 should succeed: stderr: {}",
+        stderr
+    );
+
+    // Hosts with a single CPU fall back to serial execution.
+    let parallel = stderr.contains("note: running 3 benches with 2 concurrent jobs");
+    for bench_name in &["bench-a", "bench-b", "bench-c"] {
+        let run: serde_json::Value = serde_json::from_str(
+            &fs::read_to_string(out_dir.join(bench_name).join("run.json")).expect("read run"),
+        )
+        .expect("parse run");
+        let concurrency = &run["run"]["concurrency"];
+        if parallel {
+            assert_eq!(concurrency["jobs"], 2, "{bench_name}: {concurrency}");
+            if cfg!(target_os = "linux") {
+                assert!(
+                    !concurrency["cpus"].as_array().expect("cpus").is_empty(),
+                    "{bench_name}: {concurrency}"
+                );
+            }
+        } else {
+            assert!(concurrency.is_null(), "{bench_name}: {concurrency}");
+        }
+    }
+
+    if parallel {
+        assert!(
+            stderr.contains(
+                "concurrency mismatch: baseline was recorded serially, current with 2 concurrent jobs"
+            ),
+            "stderr: {stderr}"
+        );
+    } else {
+        assert!(
+            stderr.contains("--jobs 2 exceeds the 1 available CPUs"),
+            "stderr: {stderr}"
+        );
+    }
+}
+
+/// Test --interleave shuffles samples across benches and replays by seed
+#[test]
+#[cfg(unix)]
+fn test_check_all_interleave_replays_with_seed() {
+    let temp_dir = tempdir().expect("failed to create temp dir");
+    let log = temp_dir.path().join("order.log");
+    let config_path = temp_dir.path().join("perfgate.toml");
+    fs::write(
+        &config_path,
+        format!(
+            r#"
+[defaults]
+repeat = 6
+warmup = 1
+
+[[bench]]
+name = "a"
+command = ["sh", "-c", "echo a >> '{log}'"]
+
+[[bench]]
+name = "b"
+command = ["sh", "-c", "echo b >> '{log}'"]
+"#,
+            log = log.display()
+        ),
+    )
+    .expect("write config");
+
+    let run = |out_dir: &std::path::Path| {
+        let output = perfgate_cmd()
+            .arg("check")
+            .arg("--config")
+            .arg(&config_path)
+            .arg("--all")
+            .args(["--interleave", "--seed", "42"])
+            .arg("--out-dir")
+            .arg(out_dir)
+            .output()
+            .expect("failed to execute check");
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(
+            output.status.success(),
+            "check --interleave should succeed: stderr: {}",
+            stderr
+        );
+        assert!(
+            stderr.contains("interleaving the samples of 2 benches with seed 42"),
+            "stderr: {stderr}"
+        );
+        fs::read_to_string(&log).expect("read order log")
+    };
+
+    let first = run(&temp_dir.path().join("first"));
+    fs::remove_file(&log).expect("reset order log");
+    let second = run(&temp_dir.path().join("second"));
+
+    assert_eq!(first, second, "same seed must replay the same order");
+    let order: Vec<&str> = first.lines().collect();
+    assert_eq!(order.len(), 14);
+    assert_ne!(
+        order,
+        [vec!["a"; 7], vec!["b"; 7]].concat(),
+        "samples should be interleaved"
+    );
+
+    for bench in ["a", "b"] {
+        let receipt: serde_json::Value = serde_json::from_str(
+            &fs::read_to_string(temp_dir.path().join("first").join(bench).join("run.json"))
+                .expect("read run"),
+        )
+        .expect("parse run");
+        assert_eq!(receipt["run"]["interleave"]["seed"], 42);
+        assert_eq!(
+            receipt["run"]["interleave"]["benches"],
+            serde_json::json!(["a", "b"])
+        );
+        assert_eq!(receipt["samples"].as_array().expect("samples").len(), 7);
+    }
+}
+
+/// Test a service bench drives its workload against a ready server and stops it
+#[test]
+#[cfg(unix)]
+fn test_check_service_bench_starts_and_stops_server() {
+    let temp_dir = tempdir().expect("failed to create temp dir");
+    let up = temp_dir.path().join("up");
+    let stopped = temp_dir.path().join("stopped");
+    let config_path = temp_dir.path().join("perfgate.toml");
+    fs::write(
+        &config_path,
+        format!(
+            r#"
+[[bench]]
+name = "api"
+repeat = 3
+command = ["sh", "-c", "test -f '{up}'"]
+
+[bench.service]
+command = ["sh", "-c", "trap 'echo bye > {stopped}; exit 0' TERM; touch '{up}'; echo listening; while :; do sleep 0.05; done"]
+ready = {{ stdout = "^listening" }}
+ready_timeout = "10s"
+"#,
+            up = up.display(),
+            stopped = stopped.display()
+        ),
+    )
+    .expect("write config");
+
+    let out_dir = temp_dir.path().join("out");
+    let output = perfgate_cmd()
+        .arg("check")
+        .arg("--config")
+        .arg(&config_path)
+        .args(["--bench", "api"])
+        .arg("--out-dir")
+        .arg(&out_dir)
+        .output()
+        .expect("failed to execute check");
+    assert!(
+        output.status.success(),
+        "service check should succeed: stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(stopped.exists(), "server should be stopped with SIGTERM");
+
+    let receipt: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(out_dir.join("run.json")).expect("read run"))
+            .expect("parse run");
+    assert_eq!(receipt["bench"]["service"]["command"][0], "sh");
+    assert!(receipt["bench"]["service"]["ready_ms"].is_u64());
+    let samples = receipt["samples"].as_array().expect("samples");
+    assert_eq!(samples.len(), 3);
+    assert!(samples.iter().all(|sample| sample["exit_code"] == 0));
+}
+
+/// Test --jobs rejects zero
+#[test]
+fn test_check_jobs_zero_rejected() {
+    let temp_dir = tempdir().expect("failed to create temp dir");
+    let config_path = create_config_file(temp_dir.path(), "bench");
+
+    let output = perfgate_cmd()
+        .arg("check")
+        .arg("--config")
+        .arg(&config_path)
+        .arg("--all")
+        .args(["--jobs", "0"])
+        .output()
+        .expect("failed to execute check");
+    assert!(!output.status.success());
+}
+
 /// Test baseline auto-discovery via defaults.baseline_pattern.
 #[test]
 fn test_check_baseline_pattern_autodiscovery() {
@@ -1456,3 +1744,421 @@ fn test_check_baseline_and_all_mutually_exclusive() {
         stderr
     );
 }
+
+/// Run a git command in `dir`, panicking on failure, and return trimmed stdout.
+#[cfg(unix)]
+fn git(dir: &std::path::Path, args: &[&str]) -> String {
+    let output = std::process::Command::new("git")
+        .args([
+            "-c",
+            "user.name=perfgate",
+            "-c",
+            "user.email=perfgate@example.com",
+            "-c",
+            "commit.gpgsign=false",
+        ])
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .expect("failed to run git");
+    assert!(
+        output.status.success(),
+        "git {:?} failed: {}",
+        args,
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8_lossy(&output.stdout).trim().to_string()
+}
+
+/// Test check --merge-base selects the nearest ancestor with a recorded baseline
+#[test]
+#[cfg(unix)]
+fn test_check_merge_base_selects_nearest_recorded_ancestor() {
+    let temp_dir = tempdir().expect("failed to create temp dir");
+    let repo = temp_dir.path();
+    let config_path = create_config_file(repo, "mb-bench");
+    let config = fs::read_to_string(&config_path).expect("read config");
+    fs::write(
+        &config_path,
+        config.replace(
+            "[defaults]\n",
+            "[defaults]\nbaseline_history_dir = \"history\"\n",
+        ),
+    )
+    .expect("write config");
+
+    git(repo, &["init", "-q"]);
+    git(repo, &["checkout", "-q", "-b", "main"]);
+    git(repo, &["commit", "-q", "--allow-empty", "-m", "recorded"]);
+    let recorded = git(repo, &["rev-parse", "HEAD"]);
+    git(repo, &["commit", "-q", "--allow-empty", "-m", "unrecorded"]);
+    git(repo, &["checkout", "-q", "-b", "feature"]);
+    git(repo, &["commit", "-q", "--allow-empty", "-m", "change"]);
+
+    // The configured baseline path is deliberately fast so that only the
+    // history baseline (10s) lets the check pass.
+    create_baseline_receipt_at(&repo.join("baselines").join("mb-bench.json"), "mb-bench", 1);
+    let history_path = repo.join("history").join(&recorded).join("mb-bench.json");
+    create_baseline_receipt_at(&history_path, "mb-bench", 10_000);
+
+    let out_dir = repo.join("artifacts");
+    let mut cmd = perfgate_cmd();
+    cmd.current_dir(repo)
+        .arg("check")
+        .arg("--config")
+        .arg(&config_path)
+        .arg("--bench")
+        .arg("mb-bench")
+        .arg("--merge-base")
+        .arg("main")
+        .arg("--out-dir")
+        .arg(&out_dir);
+
+    let output = cmd.output().expect("failed to execute check");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        output.status.success(),
+        "check --merge-base should pass: {:?}, stderr: {}",
+        output.status.code(),
+        stderr
+    );
+    assert!(
+        stderr.contains(&format!(
+            "baseline from {} (1 commit before merge-base",
+            &recorded[..12]
+        )),
+        "stderr should report the selected commit: {}",
+        stderr
+    );
+
+    let compare: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(out_dir.join("compare.json")).unwrap())
+            .expect("parse compare.json");
+    let baseline_ref = compare["baseline_ref"]["path"].as_str().unwrap_or_default();
+    assert!(
+        baseline_ref.contains(&recorded),
+        "compare baseline_ref should point at the history baseline: {}",
+        baseline_ref
+    );
+
+    let report: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(out_dir.join("report.json")).unwrap())
+            .expect("parse report.json");
+    let selection = &report["baseline_selection"];
+    assert_eq!(selection["target"], "main");
+    assert_eq!(selection["selected_sha"], recorded.as_str());
+    assert_eq!(selection["distance"], 1);
+
+    let comment = fs::read_to_string(out_dir.join("comment.md")).expect("read comment.md");
+    assert!(
+        comment.contains("### Baseline") && comment.contains(&recorded),
+        "comment.md should name the selected commit: {}",
+        comment
+    );
+}
+
+/// Test check --changed-since runs only benches whose paths match changed files
+#[test]
+#[cfg(unix)]
+fn test_check_changed_since_skips_unaffected_benches() {
+    let temp_dir = tempdir().expect("failed to create temp dir");
+    let repo = temp_dir.path();
+    let cmd_str = success_command()
+        .iter()
+        .map(|s| format!("\"{}\"", s))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let config_path = repo.join("perfgate.toml");
+    fs::write(
+        &config_path,
+        format!(
+            r#"
+[defaults]
+repeat = 2
+warmup = 0
+
+[[bench]]
+name = "parser"
+command = [{cmd_str}]
+paths = ["crates/parser/**"]
+
+[[bench]]
+name = "docs"
+command = [{cmd_str}]
+paths = ["docs/**"]
+
+[[bench]]
+name = "smoke"
+command = [{cmd_str}]
+paths = ["docs/**"]
+always = true
+"#
+        ),
+    )
+    .expect("write config");
+
+    git(repo, &["init", "-q"]);
+    git(repo, &["add", "perfgate.toml"]);
+    git(repo, &["commit", "-q", "-m", "config"]);
+    fs::create_dir_all(repo.join("crates/parser/src")).expect("create parser dir");
+    fs::write(repo.join("crates/parser/src/lib.rs"), "// changed\n").expect("write source");
+
+    let out_dir = repo.join("artifacts");
+    let output = perfgate_cmd()
+        .current_dir(repo)
+        .arg("check")
+        .arg("--config")
+        .arg(&config_path)
+        .arg("--all")
+        .args(["--changed-since", "HEAD"])
+        .arg("--out-dir")
+        .arg(&out_dir)
+        .output()
+        .expect("failed to execute check");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        output.status.success(),
+        "check --changed-since should succeed: stderr: {}",
+        stderr
+    );
+    assert!(
+        stderr.contains("note: [docs] skipped: no files changed since HEAD match its paths"),
+        "stderr: {stderr}"
+    );
+
+    assert!(out_dir.join("parser").join("run.json").exists());
+    assert!(out_dir.join("smoke").join("run.json").exists());
+    assert!(!out_dir.join("docs").join("run.json").exists());
+
+    let report: serde_json::Value = serde_json::from_str(
+        &fs::read_to_string(out_dir.join("docs").join("report.json")).expect("read report"),
+    )
+    .expect("parse report");
+    assert_eq!(report["verdict"]["status"], "skip");
+    assert_eq!(
+        report["skip_reason"],
+        "no files changed since HEAD match its paths"
+    );
+    let comment = fs::read_to_string(out_dir.join("docs").join("comment.md")).expect("comment");
+    assert!(comment.contains("perfgate: skipped"), "comment: {comment}");
+}
+
+/// Test check derives `threshold = "auto"` budgets from the history directory
+#[test]
+fn test_check_auto_threshold_from_history_dir() {
+    let temp_dir = tempdir().expect("failed to create temp dir");
+    let root = temp_dir.path();
+    let config_path = create_config_file(root, "auto-bench");
+    let config = fs::read_to_string(&config_path).expect("read config");
+    fs::write(
+        &config_path,
+        format!(
+            "{}\n[bench.budgets.wall_ms]\nthreshold = \"auto\"\nthreshold_max = 0.40\n",
+            config.replace(
+                "[defaults]\n",
+                "[defaults]\nbaseline_history_dir = \"history\"\n"
+            )
+        ),
+    )
+    .expect("write config");
+
+    create_baseline_receipt_at(
+        &root.join("baselines").join("auto-bench.json"),
+        "auto-bench",
+        10_000,
+    );
+    for (i, wall_ms) in [9_000, 11_000, 9_500, 10_500, 10_000]
+        .into_iter()
+        .enumerate()
+    {
+        let path = root
+            .join("history")
+            .join(format!("{:040x}", i))
+            .join("auto-bench.json");
+        create_baseline_receipt_at(&path, "auto-bench", wall_ms);
+    }
+
+    let out_dir = root.join("artifacts");
+    let output = perfgate_cmd()
+        .current_dir(root)
+        .arg("check")
+        .arg("--config")
+        .arg(&config_path)
+        .arg("--bench")
+        .arg("auto-bench")
+        .arg("--out-dir")
+        .arg(&out_dir)
+        .output()
+        .expect("failed to execute check");
+    assert!(
+        output.status.success(),
+        "check should pass: {:?}, stderr: {}",
+        output.status.code(),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let compare: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(out_dir.join("compare.json")).unwrap())
+            .expect("parse compare.json");
+    let wall = &compare["budgets"]["wall_ms"];
+    assert_eq!(wall["auto_threshold"]["source"], "history_dir");
+    assert_eq!(wall["auto_threshold"]["runs"], 5);
+    let cv = wall["auto_threshold"]["cv"].as_f64().expect("cv");
+    let threshold = wall["threshold"].as_f64().expect("threshold");
+    assert!((threshold - 3.0 * cv).abs() < 1e-9);
+}
+
+/// Create a config whose single bench sleeps briefly, with a baseline recorded
+/// at 1ms so that every check of it fails.
+fn create_regressing_bench(root: &std::path::Path, bench_name: &str) -> std::path::PathBuf {
+    let cmd_str = slow_command()
+        .iter()
+        .map(|s| format!("\"{}\"", s))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let config_path = root.join("perfgate.toml");
+    fs::write(
+        &config_path,
+        format!(
+            "[defaults]\nrepeat = 3\nwarmup = 0\nthreshold = 0.20\n\n[[bench]]\nname = \"{}\"\ncommand = [{}]\n",
+            bench_name, cmd_str
+        ),
+    )
+    .expect("write config");
+    create_baseline_receipt_at(
+        &root.join("baselines").join(format!("{bench_name}.json")),
+        bench_name,
+        1,
+    );
+    config_path
+}
+
+/// Test check --confirm re-runs a failing bench and keeps a reproduced regression
+#[test]
+fn test_check_confirm_rerun_keeps_reproduced_regression() {
+    let temp_dir = tempdir().expect("failed to create temp dir");
+    let root = temp_dir.path();
+    let config_path = create_regressing_bench(root, "confirm-bench");
+    let out_dir = root.join("artifacts");
+
+    perfgate_cmd()
+        .current_dir(root)
+        .arg("check")
+        .arg("--config")
+        .arg(&config_path)
+        .arg("--bench")
+        .arg("confirm-bench")
+        .arg("--confirm")
+        .arg("--out-dir")
+        .arg(&out_dir)
+        .assert()
+        .code(2);
+
+    assert!(out_dir.join("run.json").exists());
+    assert!(out_dir.join("confirm").join("run.json").exists());
+    assert!(out_dir.join("confirm").join("compare.json").exists());
+
+    let report: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(out_dir.join("report.json")).unwrap())
+            .expect("parse report.json");
+    let confirmation = &report["confirmation"];
+    assert_eq!(confirmation["mode"], "rerun");
+    assert_eq!(confirmation["confirmed"], true);
+    assert_eq!(confirmation["original_status"], "fail");
+    assert_eq!(confirmation["metrics"]["wall_ms"]["confirmation"], "fail");
+    let comment = fs::read_to_string(out_dir.join("comment.md")).expect("read comment");
+    assert!(comment.contains("regression confirmed"));
+}
+
+/// Test check --confirm-baseline-bin clears a regression a paired run does not reproduce
+#[test]
+#[cfg(unix)]
+fn test_check_confirm_paired_clears_unreproduced_regression() {
+    let temp_dir = tempdir().expect("failed to create temp dir");
+    let root = temp_dir.path();
+    let config_path = create_regressing_bench(root, "paired-confirm");
+    let out_dir = root.join("artifacts");
+
+    // Pairing the bench with itself never reproduces the regression.
+    let output = perfgate_cmd()
+        .current_dir(root)
+        .arg("check")
+        .arg("--config")
+        .arg(&config_path)
+        .arg("--bench")
+        .arg("paired-confirm")
+        .arg("--confirm")
+        .arg("--confirm-baseline-bin")
+        .arg("sh")
+        .arg("--out-dir")
+        .arg(&out_dir)
+        .output()
+        .expect("failed to execute check");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        output.status.success(),
+        "unconfirmed regression should pass: {:?}, stderr: {}",
+        output.status.code(),
+        stderr
+    );
+    assert!(stderr.contains("not confirmed"), "stderr: {}", stderr);
+    assert!(out_dir.join("confirm").join("paired.json").exists());
+
+    let report: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(out_dir.join("report.json")).unwrap())
+            .expect("parse report.json");
+    assert_eq!(report["verdict"]["status"], "pass");
+    assert_eq!(report["confirmation"]["mode"], "paired");
+    assert_eq!(report["confirmation"]["confirmed"], false);
+    assert_eq!(report["confirmation"]["original_status"], "fail");
+}
+
+/// Test a ready probe records time_to_ready_ms and stops a server once ready
+#[test]
+#[cfg(unix)]
+fn test_check_ready_probe_records_time_to_ready() {
+    let temp_dir = tempdir().expect("failed to create temp dir");
+    let config_path = temp_dir.path().join("perfgate.toml");
+    fs::write(
+        &config_path,
+        r#"
+[[bench]]
+name = "startup"
+repeat = 2
+command = ["sh", "-c", "sleep 0.05; echo 'listening on 8080'; exec sleep 30"]
+ready = { stdout = "^listening on \\d+" }
+ready_timeout = "10s"
+terminate_on_ready = true
+"#,
+    )
+    .expect("write config");
+
+    let out_dir = temp_dir.path().join("out");
+    let output = perfgate_cmd()
+        .arg("check")
+        .arg("--config")
+        .arg(&config_path)
+        .args(["--bench", "startup"])
+        .arg("--out-dir")
+        .arg(&out_dir)
+        .output()
+        .expect("failed to execute check");
+    assert!(
+        output.status.success(),
+        "ready probe check should succeed: stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let receipt: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(out_dir.join("run.json")).expect("read run"))
+            .expect("parse run");
+    let samples = receipt["samples"].as_array().expect("samples");
+    assert_eq!(samples.len(), 2);
+    assert!(samples.iter().all(|sample| {
+        sample["exit_code"] == 0
+            && sample["time_to_ready_ms"]
+                .as_u64()
+                .is_some_and(|ms| ms >= 50)
+    }));
+    assert!(receipt["stats"]["time_to_ready_ms"]["median"].is_u64());
+}
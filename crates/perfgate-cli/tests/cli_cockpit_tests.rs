@@ -1018,6 +1018,57 @@ fn test_cockpit_multi_bench_artifact_layout_with_baselines() {
     }
 }
 
+/// Test that `--fdr` corrects significance across every cockpit bench.
+#[test]
+fn test_cockpit_multi_bench_fdr_adjusts_p_values() {
+    let temp_dir = tempdir().expect("failed to create temp dir");
+    let out_dir = temp_dir.path().join("artifacts/perfgate");
+    let bench_names = ["bench-a", "bench-b"];
+    let config_path = create_multi_bench_config(temp_dir.path(), &bench_names);
+    for name in &bench_names {
+        create_baseline_receipt(temp_dir.path(), name);
+    }
+
+    let output = perfgate_cmd()
+        .current_dir(temp_dir.path())
+        .arg("check")
+        .arg("--config")
+        .arg(&config_path)
+        .arg("--all")
+        .arg("--out-dir")
+        .arg(&out_dir)
+        .args(["--mode", "cockpit"])
+        .args([
+            "--significance-alpha",
+            "0.05",
+            "--significance-min-samples",
+            "2",
+        ])
+        .arg("--fdr")
+        .output()
+        .expect("failed to execute check");
+    assert!(
+        output.status.success(),
+        "cockpit --fdr should exit 0: stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(!String::from_utf8_lossy(&output.stderr).contains("--fdr is not applied"));
+
+    for name in &bench_names {
+        let path = out_dir
+            .join("extras")
+            .join(name)
+            .join("perfgate.compare.v1.json");
+        let content = fs::read_to_string(&path).expect("read compare receipt");
+        let compare: Value = serde_json::from_str(&content).expect("parse compare receipt");
+        let significance = &compare["deltas"]["wall_ms"]["significance"];
+        assert!(
+            significance["adjusted_p_value"].is_number(),
+            "{name} should carry an FDR-adjusted p-value: {significance}"
+        );
+    }
+}
+
 /// Test multi-bench cockpit output validates against vendored schema.
 #[test]
 fn test_cockpit_multi_bench_schema_validation() {
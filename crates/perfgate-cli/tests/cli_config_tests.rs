@@ -0,0 +1,366 @@
+//! Integration tests for config loading: `extends` inheritance, bench
+//! matrices, profiles and `perfgate config resolve`.
+
+use predicates::prelude::*;
+use std::fs;
+use std::path::Path;
+use tempfile::tempdir;
+
+mod common;
+use common::perfgate_cmd;
+
+#[cfg(unix)]
+fn success_command() -> &'static str {
+    r#"["true"]"#
+}
+
+#[cfg(windows)]
+fn success_command() -> &'static str {
+    r#"["cmd", "/c", "exit", "0"]"#
+}
+
+/// Write `shared/perfgate.base.toml` (extending the standard preset) and a
+/// `perfgate.toml` that extends it and overrides one budget key.
+fn write_config_chain(dir: &Path) {
+    fs::create_dir_all(dir.join("shared")).expect("create shared dir");
+    fs::write(
+        dir.join("shared").join("perfgate.base.toml"),
+        format!(
+            r#"extends = ["preset:standard"]
+
+[defaults]
+threshold = 0.15
+
+[[bench]]
+name = "parse"
+command = {}
+repeat = 2
+warmup = 0
+
+[bench.budgets.wall_ms]
+threshold = 0.10
+"#,
+            success_command()
+        ),
+    )
+    .expect("write base config");
+    fs::write(
+        dir.join("perfgate.toml"),
+        r#"extends = ["shared/perfgate.base.toml"]
+
+[defaults]
+out_dir = "out"
+
+[[bench]]
+name = "parse"
+
+[bench.budgets.wall_ms]
+warn_factor = 0.4
+"#,
+    )
+    .expect("write config");
+}
+
+#[test]
+fn test_config_resolve_reports_merged_values_and_sources() {
+    let temp_dir = tempdir().expect("failed to create temp dir");
+    write_config_chain(temp_dir.path());
+
+    let output = perfgate_cmd()
+        .current_dir(temp_dir.path())
+        .args(["config", "resolve", "--format", "json"])
+        .output()
+        .expect("run config resolve");
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let resolved: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("resolve output should be JSON");
+
+    let config = &resolved["config"];
+    assert_eq!(config["defaults"]["repeat"], 7);
+    assert_eq!(config["defaults"]["threshold"], 0.15);
+    assert_eq!(config["defaults"]["out_dir"], "out");
+    let budget = &config["bench"][0]["budgets"]["wall_ms"];
+    assert_eq!(budget["threshold"], 0.10);
+    assert_eq!(budget["warn_factor"], 0.4);
+    assert!(config.get("extends").is_none());
+
+    let base = Path::new("shared")
+        .join("perfgate.base.toml")
+        .display()
+        .to_string();
+    assert_eq!(
+        resolved["sources"],
+        serde_json::json!(["preset:standard", base, "perfgate.toml"])
+    );
+    let provenance = &resolved["provenance"];
+    assert_eq!(provenance["defaults.repeat"], "preset:standard");
+    assert_eq!(provenance["defaults.threshold"], base.as_str());
+    assert_eq!(provenance["defaults.out_dir"], "perfgate.toml");
+    assert_eq!(
+        provenance["bench.parse.budgets.wall_ms.threshold"],
+        base.as_str()
+    );
+    assert_eq!(
+        provenance["bench.parse.budgets.wall_ms.warn_factor"],
+        "perfgate.toml"
+    );
+
+    perfgate_cmd()
+        .current_dir(temp_dir.path())
+        .args(["config", "resolve"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "# Resolved from (lowest precedence first):",
+        ))
+        .stdout(predicate::str::contains("threshold = 0.15"))
+        .stdout(predicate::str::contains(
+            "#   defaults.warmup <- preset:standard",
+        ));
+}
+
+#[test]
+fn test_check_uses_inherited_bench_definition() {
+    let temp_dir = tempdir().expect("failed to create temp dir");
+    write_config_chain(temp_dir.path());
+
+    perfgate_cmd()
+        .current_dir(temp_dir.path())
+        .args(["check", "--config", "perfgate.toml", "--bench", "parse"])
+        .assert()
+        .success();
+
+    let run: serde_json::Value = serde_json::from_str(
+        &fs::read_to_string(temp_dir.path().join("out").join("run.json")).expect("read run"),
+    )
+    .expect("parse run receipt");
+    assert_eq!(run["bench"]["name"], "parse");
+    assert_eq!(run["bench"]["repeat"], 2);
+}
+
+#[test]
+fn test_config_resolve_rejects_unknown_preset() {
+    let temp_dir = tempdir().expect("failed to create temp dir");
+    fs::write(
+        temp_dir.path().join("perfgate.toml"),
+        "extends = [\"preset:nightly\"]\n",
+    )
+    .expect("write config");
+
+    perfgate_cmd()
+        .current_dir(temp_dir.path())
+        .args(["config", "resolve"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("unknown preset 'nightly'"));
+}
+
+#[test]
+fn test_check_all_runs_each_matrix_expansion() {
+    let temp_dir = tempdir().expect("failed to create temp dir");
+    let command = success_command().trim_end_matches(']').to_string() + r#", "{size}"]"#;
+    fs::write(
+        temp_dir.path().join("perfgate.toml"),
+        format!(
+            r#"[defaults]
+repeat = 2
+warmup = 0
+out_dir = "out"
+baseline_dir = "baselines"
+
+[[bench]]
+name = "parse_{{size}}"
+command = {command}
+work = "{{size}}"
+
+[bench.matrix]
+size = [10, 20]
+
+[bench.matrix_overrides.parse_20]
+repeat = 3
+"#
+        ),
+    )
+    .expect("write config");
+
+    perfgate_cmd()
+        .current_dir(temp_dir.path())
+        .args(["check", "--config", "perfgate.toml", "--all"])
+        .assert()
+        .success();
+
+    for (name, work, repeat) in [("parse_10", 10, 2), ("parse_20", 20, 3)] {
+        let run_path = temp_dir.path().join("out").join(name).join("run.json");
+        let run: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&run_path).expect("read run"))
+                .expect("parse run receipt");
+        assert_eq!(run["bench"]["name"], name);
+        assert_eq!(run["bench"]["work_units"], work);
+        assert_eq!(run["bench"]["repeat"], repeat);
+        assert_eq!(
+            run["bench"]["command"]
+                .as_array()
+                .expect("argv")
+                .last()
+                .unwrap(),
+            &serde_json::json!(work.to_string())
+        );
+    }
+}
+
+/// Write a `perfgate.toml` with `ci` and `local` profiles that change the
+/// repeat count of the `parse` bench.
+fn write_profiled_config(dir: &Path) {
+    fs::write(
+        dir.join("perfgate.toml"),
+        format!(
+            r#"[defaults]
+repeat = 2
+warmup = 0
+out_dir = "out"
+baseline_dir = "baselines"
+
+[[bench]]
+name = "parse"
+command = {}
+
+[profile.ci.defaults]
+repeat = 4
+
+[[profile.local.bench]]
+name = "parse"
+repeat = 3
+"#,
+            success_command()
+        ),
+    )
+    .expect("write config");
+}
+
+/// `perfgate` with every CI marker variable cleared.
+fn perfgate_outside_ci() -> assert_cmd::Command {
+    let mut cmd = perfgate_cmd();
+    for var in [
+        "PERFGATE_PROFILE",
+        "CI",
+        "GITHUB_ACTIONS",
+        "GITLAB_CI",
+        "BUILDKITE",
+        "CIRCLECI",
+        "TF_BUILD",
+        "JENKINS_URL",
+        "TEAMCITY_VERSION",
+    ] {
+        cmd.env_remove(var);
+    }
+    cmd
+}
+
+fn read_json(path: &Path) -> serde_json::Value {
+    serde_json::from_str(&fs::read_to_string(path).expect("read receipt")).expect("parse receipt")
+}
+
+#[test]
+fn test_check_applies_and_records_profiles() {
+    let temp_dir = tempdir().expect("failed to create temp dir");
+    write_profiled_config(temp_dir.path());
+    let run_path = temp_dir.path().join("out").join("run.json");
+
+    perfgate_outside_ci()
+        .current_dir(temp_dir.path())
+        .args(["check", "--bench", "parse"])
+        .assert()
+        .success();
+    let run = read_json(&run_path);
+    assert_eq!(run["run"]["profile"], "local");
+    assert_eq!(run["bench"]["repeat"], 3);
+
+    perfgate_outside_ci()
+        .current_dir(temp_dir.path())
+        .env("GITHUB_ACTIONS", "true")
+        .args(["check", "--bench", "parse"])
+        .assert()
+        .success();
+    let run = read_json(&run_path);
+    assert_eq!(run["run"]["profile"], "ci");
+    assert_eq!(run["bench"]["repeat"], 4);
+
+    perfgate_outside_ci()
+        .current_dir(temp_dir.path())
+        .args(["check", "--bench", "parse", "--profile", "release"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "unknown profile 'release' (available: ci, local)",
+        ));
+}
+
+#[test]
+fn test_check_flags_cross_profile_comparison() {
+    let temp_dir = tempdir().expect("failed to create temp dir");
+    write_profiled_config(temp_dir.path());
+
+    perfgate_outside_ci()
+        .current_dir(temp_dir.path())
+        .args(["check", "--bench", "parse", "--profile", "local"])
+        .assert()
+        .success();
+    fs::create_dir_all(temp_dir.path().join("baselines")).expect("create baselines");
+    fs::copy(
+        temp_dir.path().join("out").join("run.json"),
+        temp_dir.path().join("baselines").join("parse.json"),
+    )
+    .expect("promote baseline");
+
+    perfgate_outside_ci()
+        .current_dir(temp_dir.path())
+        .args(["check", "--bench", "parse", "--profile", "ci"])
+        .assert()
+        .stderr(predicate::str::contains(
+            "profile mismatch: baseline was recorded with profile 'local', current with profile 'ci'",
+        ));
+    let compare = read_json(&temp_dir.path().join("out").join("compare.json"));
+    assert_eq!(compare["baseline_ref"]["profile"], "local");
+    assert_eq!(compare["current_ref"]["profile"], "ci");
+
+    perfgate_outside_ci()
+        .current_dir(temp_dir.path())
+        .args([
+            "check",
+            "--bench",
+            "parse",
+            "--profile",
+            "ci",
+            "--host-mismatch",
+            "error",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("profile mismatch detected"));
+}
+
+#[test]
+fn test_config_resolve_reports_active_profile() {
+    let temp_dir = tempdir().expect("failed to create temp dir");
+    write_profiled_config(temp_dir.path());
+
+    let output = perfgate_outside_ci()
+        .current_dir(temp_dir.path())
+        .args(["config", "resolve", "--profile", "ci", "--format", "json"])
+        .output()
+        .expect("run config resolve");
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let resolved: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("resolve output should be JSON");
+    assert_eq!(resolved["profile"], "ci");
+    assert_eq!(resolved["config"]["defaults"]["repeat"], 4);
+    assert_eq!(resolved["provenance"]["defaults.repeat"], "profile.ci");
+}
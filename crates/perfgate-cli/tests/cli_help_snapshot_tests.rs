@@ -548,6 +548,34 @@ fn cli_help_probe_compare() {
         .stdout(predicate::str::contains("--out"));
 }
 
+#[test]
+fn cli_help_suite_compare() {
+    perfgate_cmd()
+        .args(["suite", "compare", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Match run receipts by bench name and emit one perfgate.suite_compare.v1 receipt",
+        ))
+        .stdout(predicate::str::contains("--baseline"))
+        .stdout(predicate::str::contains("--current"))
+        .stdout(predicate::str::contains("--out-dir"));
+}
+
+#[test]
+fn cli_help_config_resolve() {
+    perfgate_cmd()
+        .args(["config", "resolve", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Print the fully merged config and where each value came from",
+        ))
+        .stdout(predicate::str::contains("--config"))
+        .stdout(predicate::str::contains("--profile <NAME>"))
+        .stdout(predicate::str::contains("--format"));
+}
+
 // ── insta full-output snapshot tests ─────────────────────────────────
 
 fn help_output(args: &[&str]) -> String {
@@ -787,3 +815,11 @@ fn snapshot_help_probe_compare() {
         help_output(&["probe", "compare", "--help"])
     );
 }
+
+#[test]
+fn snapshot_help_suite_compare() {
+    insta::assert_snapshot!(
+        "help_suite_compare",
+        help_output(&["suite", "compare", "--help"])
+    );
+}
@@ -110,6 +110,337 @@ fn test_ingest_hyperfine_exit_code_mismatch_fails_actionably() {
         .stderr(predicate::str::contains("exit_codes length"));
 }
 
+#[test]
+fn test_ingest_out_dir_writes_one_receipt_per_benchmark_and_bundle() {
+    let temp_dir = tempdir().expect("failed to create temp dir");
+    let input_path = temp_dir.path().join("bench.txt");
+    let out_dir = temp_dir.path().join("receipts");
+    let bundle_path = temp_dir.path().join("bundle.json");
+
+    fs::write(
+        &input_path,
+        "goos: linux\n\
+BenchmarkParse-8\t1000\t2000000 ns/op\t512 B/op\t4 allocs/op\n\
+BenchmarkRender-8\t500\t5000000 ns/op\n\
+BenchmarkParse-8\t1000\t2200000 ns/op\t512 B/op\t4 allocs/op\n\
+PASS\n",
+    )
+    .expect("failed to write go bench input");
+
+    let mut cmd = perfgate_cmd();
+    cmd.arg("ingest")
+        .arg("--format")
+        .arg("gobench")
+        .arg("--input")
+        .arg(&input_path)
+        .arg("--name")
+        .arg("go")
+        .arg("--out-dir")
+        .arg(&out_dir)
+        .arg("--bundle")
+        .arg(&bundle_path);
+
+    cmd.assert()
+        .success()
+        .stderr(predicate::str::contains("Bundled 2 benchmark(s)"));
+
+    let render: Value = serde_json::from_str(
+        &fs::read_to_string(out_dir.join("go/benchmarkrender.json"))
+            .expect("failed to read per-bench receipt"),
+    )
+    .expect("receipt should be JSON");
+    assert_eq!(render["schema"], "perfgate.run.v1");
+    assert_eq!(render["bench"]["name"], "go/benchmarkrender");
+    assert_eq!(render["stats"]["wall_ms"]["median"], 5);
+
+    let parse: Value = serde_json::from_str(
+        &fs::read_to_string(out_dir.join("go/benchmarkparse.json"))
+            .expect("failed to read per-bench receipt"),
+    )
+    .expect("receipt should be JSON");
+    assert_eq!(parse["samples"].as_array().expect("samples").len(), 2);
+    assert_eq!(parse["stats"]["alloc_bytes"]["median"], 512);
+    assert_eq!(parse["stats"]["allocs"]["median"], 4);
+
+    let bundle: Value =
+        serde_json::from_str(&fs::read_to_string(&bundle_path).expect("failed to read bundle"))
+            .expect("bundle should be JSON");
+    assert_eq!(bundle["schema"], "perfgate.run_bundle.v1");
+    let names: Vec<&str> = bundle["receipts"]
+        .as_array()
+        .expect("receipts array")
+        .iter()
+        .map(|receipt| receipt["bench"]["name"].as_str().unwrap())
+        .collect();
+    assert_eq!(names, ["go/benchmarkparse", "go/benchmarkrender"]);
+}
+
+#[test]
+fn test_ingest_gobench_allocs_regression_fails_compare() {
+    let temp_dir = tempdir().expect("failed to create temp dir");
+    let mut receipts = Vec::new();
+    for (label, allocs) in [("baseline", 2), ("current", 3)] {
+        let input_path = temp_dir.path().join(format!("{label}-bench.txt"));
+        let receipt_path = temp_dir.path().join(format!("{label}.json"));
+        fs::write(
+            &input_path,
+            format!("BenchmarkParse-8\t1000\t2000000 ns/op\t0 B/op\t{allocs} allocs/op\nPASS\n"),
+        )
+        .expect("failed to write go bench input");
+
+        let mut cmd = perfgate_cmd();
+        cmd.arg("ingest")
+            .arg("--format")
+            .arg("gobench")
+            .arg("--input")
+            .arg(&input_path)
+            .arg("--name")
+            .arg("parse")
+            .arg("--out")
+            .arg(&receipt_path);
+        cmd.assert().success();
+        receipts.push(receipt_path);
+    }
+
+    let compare_path = temp_dir.path().join("compare.json");
+    let mut cmd = perfgate_cmd();
+    cmd.arg("compare")
+        .arg("--baseline")
+        .arg(&receipts[0])
+        .arg("--current")
+        .arg(&receipts[1])
+        .arg("--out")
+        .arg(&compare_path);
+    cmd.assert().failure();
+
+    let compare: Value =
+        serde_json::from_str(&fs::read_to_string(&compare_path).expect("read compare receipt"))
+            .expect("compare should be JSON");
+    assert_eq!(compare["verdict"]["status"], "fail");
+    assert_eq!(compare["deltas"]["allocs"]["status"], "fail");
+    assert_eq!(compare["deltas"]["allocs"]["regression"], 0.5);
+    assert_eq!(compare["deltas"]["wall_ms"]["status"], "pass");
+}
+
+#[test]
+fn test_ingest_google_benchmark_keeps_repetitions_and_counters() {
+    let temp_dir = tempdir().expect("failed to create temp dir");
+    let input_path = temp_dir.path().join("gbench.json");
+    let output_path = temp_dir.path().join("run.json");
+
+    fs::write(
+        &input_path,
+        r#"{"context": {"num_cpus": 4}, "benchmarks": [
+          {"name": "BM_Parse", "run_name": "BM_Parse", "run_type": "iteration",
+           "iterations": 10, "real_time": 2.0, "cpu_time": 1.0, "time_unit": "ms",
+           "items_per_second": 5000.0, "Tokens": 120.0},
+          {"name": "BM_Parse", "run_name": "BM_Parse", "run_type": "iteration",
+           "iterations": 10, "real_time": 4.0, "cpu_time": 3.0, "time_unit": "ms",
+           "items_per_second": 2500.0, "Tokens": 120.0}
+        ]}"#,
+    )
+    .expect("failed to write Google Benchmark input");
+
+    let mut cmd = perfgate_cmd();
+    cmd.arg("ingest")
+        .arg("--format")
+        .arg("google-benchmark")
+        .arg("--input")
+        .arg(&input_path)
+        .arg("--out")
+        .arg(&output_path);
+
+    cmd.assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "Evidence source: google_benchmark_json",
+        ))
+        .stderr(predicate::str::contains("Custom metrics:"));
+
+    let receipt: Value = serde_json::from_str(
+        &fs::read_to_string(&output_path).expect("failed to read ingest output"),
+    )
+    .expect("ingest output should be JSON");
+    assert_eq!(receipt["bench"]["name"], "BM_Parse");
+    assert_eq!(receipt["samples"].as_array().map(Vec::len), Some(2));
+    assert_eq!(receipt["samples"][1]["wall_ms"], 4);
+    assert_eq!(receipt["samples"][1]["cpu_ms"], 3);
+    assert_eq!(receipt["stats"]["throughput_per_s"]["median"], 3750.0);
+    assert_eq!(
+        receipt["stats"]["custom"]["Tokens"]["summary"]["median"],
+        120.0
+    );
+}
+
+#[test]
+fn test_ingest_jmh_out_dir_names_benches_by_params() {
+    let temp_dir = tempdir().expect("failed to create temp dir");
+    let input_path = temp_dir.path().join("jmh.json");
+    let out_dir = temp_dir.path().join("runs");
+
+    fs::write(
+        &input_path,
+        r#"[
+          {"benchmark": "org.example.Codec.encode", "mode": "avgt", "params": {"size": "10"},
+           "primaryMetric": {"score": 2.0, "scoreUnit": "ms/op", "rawData": [[1.0, 3.0]]}},
+          {"benchmark": "org.example.Codec.encode", "mode": "avgt", "params": {"size": "1000"},
+           "primaryMetric": {"score": 20.0, "scoreUnit": "ms/op", "rawData": [[19.0, 21.0]]}}
+        ]"#,
+    )
+    .expect("failed to write JMH input");
+
+    let mut cmd = perfgate_cmd();
+    cmd.arg("ingest")
+        .arg("--format")
+        .arg("jmh")
+        .arg("--input")
+        .arg(&input_path)
+        .arg("--out-dir")
+        .arg(&out_dir);
+
+    cmd.assert()
+        .success()
+        .stderr(predicate::str::contains("Evidence source: jmh_json"));
+
+    let receipt: Value = serde_json::from_str(
+        &fs::read_to_string(out_dir.join("org.example.codec.encode/size-1000.json"))
+            .expect("failed to read ingest output"),
+    )
+    .expect("ingest output should be JSON");
+    assert_eq!(
+        receipt["bench"]["name"],
+        "org.example.codec.encode/size-1000"
+    );
+    assert_eq!(receipt["samples"].as_array().map(Vec::len), Some(2));
+    assert_eq!(receipt["stats"]["wall_ms"]["median"], 20);
+    assert!(
+        out_dir
+            .join("org.example.codec.encode/size-10.json")
+            .exists()
+    );
+}
+
+#[test]
+fn test_ingest_vitest_bench_json_writes_bundle() {
+    let temp_dir = tempdir().expect("failed to create temp dir");
+    let input_path = temp_dir.path().join("vitest-bench.json");
+    let bundle_path = temp_dir.path().join("bundle.json");
+
+    fs::write(
+        &input_path,
+        r#"{"files": [{"filepath": "/repo/src/sort.bench.ts", "groups": [{
+          "fullName": "src/sort.bench.ts > sorting",
+          "benchmarks": [
+            {"name": "native", "hz": 500.0, "mean": 2.0, "min": 1.0, "max": 3.0,
+             "median": 2.0, "sd": 0.5, "samples": [1.0, 2.0, 3.0]},
+            {"name": "merge sort", "hz": 250.0, "mean": 4.0, "min": 3.0, "max": 5.0,
+             "median": 4.0, "sd": 0.5, "samples": [3.0, 4.0, 5.0]}
+          ]
+        }]}]}"#,
+    )
+    .expect("failed to write vitest input");
+
+    let mut cmd = perfgate_cmd();
+    cmd.arg("ingest")
+        .arg("--format")
+        .arg("vitest")
+        .arg("--input")
+        .arg(&input_path)
+        .arg("--name")
+        .arg("js")
+        .arg("--bundle")
+        .arg(&bundle_path);
+
+    cmd.assert()
+        .success()
+        .stderr(predicate::str::contains("Evidence source: tinybench_json"))
+        .stderr(predicate::str::contains("Bundled 2 benchmark(s)"));
+
+    let bundle: Value =
+        serde_json::from_str(&fs::read_to_string(&bundle_path).expect("failed to read bundle"))
+            .expect("bundle should be JSON");
+    let receipts = bundle["receipts"].as_array().expect("receipts array");
+    let names: Vec<&str> = receipts
+        .iter()
+        .map(|receipt| receipt["bench"]["name"].as_str().unwrap())
+        .collect();
+    assert_eq!(
+        names,
+        vec![
+            "js/src/sort.bench.ts/sorting/merge-sort",
+            "js/src/sort.bench.ts/sorting/native"
+        ]
+    );
+    assert_eq!(receipts[1]["stats"]["throughput_per_s"]["median"], 500.0);
+    assert_eq!(receipts[1]["samples"].as_array().map(Vec::len), Some(3));
+}
+
+#[test]
+fn test_ingest_mapped_csv_writes_run_receipt() {
+    let temp_dir = tempdir().expect("failed to create temp dir");
+    let input_path = temp_dir.path().join("harness.csv");
+    let mapping_path = temp_dir.path().join("mapping.toml");
+    let output_path = temp_dir.path().join("run.json");
+
+    fs::write(
+        &input_path,
+        "case,elapsed_ns,rss_bytes\nparse,2000000,4096\nparse,4000000,8192\n",
+    )
+    .expect("failed to write CSV input");
+    fs::write(
+        &mapping_path,
+        r#"format = "csv"
+name = "case"
+
+[metrics.wall_ms]
+samples = "elapsed_ns"
+unit = "ns"
+direction = "lower_is_better"
+
+[metrics.max_rss_kb]
+samples = "rss_bytes"
+unit = "bytes"
+direction = "lower_is_better"
+"#,
+    )
+    .expect("failed to write mapping");
+
+    let mut cmd = perfgate_cmd();
+    cmd.arg("ingest")
+        .arg("--format")
+        .arg("mapped")
+        .arg("--mapping")
+        .arg(&mapping_path)
+        .arg("--input")
+        .arg(&input_path)
+        .arg("--out")
+        .arg(&output_path);
+
+    cmd.assert()
+        .success()
+        .stderr(predicate::str::contains("Evidence source: mapped"));
+
+    let receipt: Value = serde_json::from_str(
+        &fs::read_to_string(&output_path).expect("failed to read ingest output"),
+    )
+    .expect("ingest output should be JSON");
+    assert_eq!(receipt["bench"]["name"], "parse");
+    assert_eq!(receipt["samples"].as_array().map(Vec::len), Some(2));
+    assert_eq!(receipt["samples"][1]["wall_ms"], 4);
+    assert_eq!(receipt["samples"][1]["max_rss_kb"], 8);
+    assert_eq!(receipt["stats"]["wall_ms"]["median"], 3);
+
+    let mut cmd = perfgate_cmd();
+    cmd.arg("ingest")
+        .arg("--format")
+        .arg("mapped")
+        .arg("--input")
+        .arg(&input_path);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("requires --mapping"));
+}
+
 #[test]
 fn test_ingest_criterion_jsonl_writes_run_receipt() {
     let temp_dir = tempdir().expect("failed to create temp dir");
@@ -673,3 +1004,265 @@ fn test_ingest_probes_writes_probe_receipt() {
     );
     assert_eq!(receipt["probes"][1]["parent"], "parser.total");
 }
+
+/// Length-delimited protobuf field; test messages stay under 128 bytes.
+fn otlp_bytes(field: u8, value: &[u8]) -> Vec<u8> {
+    let mut out = vec![(field << 3) | 2, value.len() as u8];
+    out.extend_from_slice(value);
+    out
+}
+
+fn otlp_span(span_id: u8, parent: Option<u8>, name: &str, start_ns: u64, end_ns: u64) -> Vec<u8> {
+    let mut span = otlp_bytes(1, &[0x01]);
+    span.extend(otlp_bytes(2, &[span_id]));
+    if let Some(parent) = parent {
+        span.extend(otlp_bytes(4, &[parent]));
+    }
+    span.extend(otlp_bytes(5, name.as_bytes()));
+    span.push((7 << 3) | 1);
+    span.extend(start_ns.to_le_bytes());
+    span.push((8 << 3) | 1);
+    span.extend(end_ns.to_le_bytes());
+    span
+}
+
+#[test]
+fn test_ingest_otel_probes_reads_protobuf_with_self_time() {
+    let temp_dir = tempdir().expect("failed to create temp dir");
+    let input_path = temp_dir.path().join("trace.pb");
+    let output_path = temp_dir.path().join("probe.json");
+
+    let mut root = otlp_span(0x0a, None, "GET", 0, 50_000_000);
+    let route = otlp_bytes(2, &otlp_bytes(1, b"/items"));
+    let mut attribute = otlp_bytes(1, b"http.route");
+    attribute.extend(route);
+    root.extend(otlp_bytes(9, &attribute));
+    let child = otlp_span(0x0b, Some(0x0a), "SELECT", 10_000_000, 30_000_000);
+    let mut scope = otlp_bytes(2, &root);
+    scope.extend(otlp_bytes(2, &child));
+    let trace = otlp_bytes(1, &otlp_bytes(2, &scope));
+    fs::write(&input_path, trace).expect("failed to write trace input");
+
+    let mut cmd = perfgate_cmd();
+    cmd.arg("ingest")
+        .arg("otel-probes")
+        .arg("--file")
+        .arg(&input_path)
+        .arg("--group-by")
+        .arg("http.route")
+        .arg("--bench")
+        .arg("api")
+        .arg("--out")
+        .arg(&output_path);
+
+    cmd.assert()
+        .success()
+        .stderr(predicate::str::contains("Ingested 2 span probe(s)"));
+
+    let receipt: Value = serde_json::from_str(
+        &fs::read_to_string(&output_path).expect("failed to read probe output"),
+    )
+    .expect("probe output should be JSON");
+
+    assert_eq!(receipt["schema"], "perfgate.probe.v1");
+    assert_eq!(receipt["bench"]["name"], "api");
+    assert_eq!(receipt["probes"][0]["name"], "GET[http.route=/items]");
+    assert_eq!(receipt["probes"][0]["metrics"]["wall_ms"]["value"], 50.0);
+    assert_eq!(receipt["probes"][0]["metrics"]["self_ms"]["value"], 30.0);
+    assert_eq!(receipt["probes"][1]["name"], "SELECT");
+    assert_eq!(receipt["probes"][1]["parent"], "GET[http.route=/items]");
+}
+
+fn vegeta_report(p95_ns: u64, success: f64, throughput: f64) -> String {
+    format!(
+        r#"{{"latencies": {{"50th": 800000, "95th": {p95_ns}, "99th": 9000000}},
+            "duration": 30000000000, "wait": 0, "requests": 1500, "rate": 50.0,
+            "throughput": {throughput}, "success": {success}}}"#
+    )
+}
+
+#[test]
+fn test_ingest_vegeta_reports_gate_latency_and_errors_in_compare() {
+    let temp_dir = tempdir().expect("failed to create temp dir");
+    let mut receipts = Vec::new();
+    for (label, report) in [
+        ("baseline", vegeta_report(3_000_000, 0.99, 49.5)),
+        ("current", vegeta_report(4_500_000, 0.95, 47.5)),
+    ] {
+        let input_path = temp_dir.path().join(format!("{label}-vegeta.json"));
+        let receipt_path = temp_dir.path().join(format!("{label}.json"));
+        fs::write(&input_path, report).expect("failed to write vegeta input");
+
+        let mut cmd = perfgate_cmd();
+        cmd.arg("ingest")
+            .arg("--format")
+            .arg("vegeta")
+            .arg("--input")
+            .arg(&input_path)
+            .arg("--name")
+            .arg("api")
+            .arg("--out")
+            .arg(&receipt_path);
+        cmd.assert()
+            .success()
+            .stderr(predicate::str::contains("Evidence source: vegeta_json"));
+        receipts.push(receipt_path);
+    }
+
+    let receipt: Value =
+        serde_json::from_str(&fs::read_to_string(&receipts[0]).expect("read baseline receipt"))
+            .expect("receipt should be JSON");
+    assert_eq!(receipt["stats"]["latency_p95_us"]["median"], 3000);
+    assert_eq!(receipt["stats"]["throughput_per_s"]["median"], 49.5);
+
+    let compare_path = temp_dir.path().join("compare.json");
+    let mut cmd = perfgate_cmd();
+    cmd.arg("compare")
+        .arg("--baseline")
+        .arg(&receipts[0])
+        .arg("--current")
+        .arg(&receipts[1])
+        .arg("--out")
+        .arg(&compare_path);
+    cmd.assert().failure();
+
+    let compare: Value =
+        serde_json::from_str(&fs::read_to_string(&compare_path).expect("read compare receipt"))
+            .expect("compare should be JSON");
+    assert_eq!(compare["verdict"]["status"], "fail");
+    assert_eq!(compare["deltas"]["latency_p95_us"]["status"], "fail");
+    assert_eq!(compare["budgets"]["latency_p95_us"]["direction"], "lower");
+    assert_eq!(compare["deltas"]["error_rate"]["status"], "fail");
+    assert_eq!(
+        compare["budgets"]["throughput_per_s"]["direction"],
+        "higher"
+    );
+}
+
+fn k6_summary(failed: u64) -> String {
+    format!(
+        r#"{{"metrics": {{
+            "http_req_duration": {{"med": 12.0, "p(95)": 25.5}},
+            "http_req_failed": {{"passes": {failed}, "fails": {}, "value": {}}},
+            "http_reqs": {{"count": 1000, "rate": 100.0}}
+        }}}}"#,
+        1000 - failed,
+        failed as f64 / 1000.0
+    )
+}
+
+#[test]
+fn test_ingest_k6_error_rate_increase_fails_compare() {
+    let temp_dir = tempdir().expect("failed to create temp dir");
+    let mut receipts = Vec::new();
+    for (label, failed) in [("baseline", 10), ("current", 50)] {
+        let input_path = temp_dir.path().join(format!("{label}-k6.json"));
+        let receipt_path = temp_dir.path().join(format!("{label}.json"));
+        fs::write(&input_path, k6_summary(failed)).expect("failed to write k6 input");
+
+        let mut cmd = perfgate_cmd();
+        cmd.arg("ingest")
+            .arg("--format")
+            .arg("k6")
+            .arg("--input")
+            .arg(&input_path)
+            .arg("--name")
+            .arg("api/checkout")
+            .arg("--out")
+            .arg(&receipt_path);
+        cmd.assert().success();
+        receipts.push(receipt_path);
+    }
+
+    let compare_path = temp_dir.path().join("compare.json");
+    let mut cmd = perfgate_cmd();
+    cmd.arg("compare")
+        .arg("--baseline")
+        .arg(&receipts[0])
+        .arg("--current")
+        .arg(&receipts[1])
+        .arg("--out")
+        .arg(&compare_path);
+    cmd.assert().failure();
+
+    let compare: Value =
+        serde_json::from_str(&fs::read_to_string(&compare_path).expect("read compare receipt"))
+            .expect("compare should be JSON");
+    assert_eq!(compare["verdict"]["status"], "fail");
+    assert_eq!(compare["deltas"]["error_rate"]["baseline"], 0.01);
+    assert_eq!(compare["deltas"]["error_rate"]["regression"], 4.0);
+    assert_eq!(compare["deltas"]["error_rate"]["status"], "fail");
+    assert_eq!(compare["deltas"]["latency_p95_us"]["status"], "pass");
+    assert_eq!(compare["deltas"]["throughput_per_s"]["status"], "pass");
+}
+
+#[test]
+fn test_ingest_perf_stat_warns_about_multiplexed_counters() {
+    let temp_dir = tempdir().expect("failed to create temp dir");
+    let input_path = temp_dir.path().join("perf-stat.csv");
+    let output_path = temp_dir.path().join("run.json");
+
+    fs::write(
+        &input_path,
+        "1204.51,msec,task-clock,1204510000,100.00,0.998,CPUs utilized\n\
+         1320000000,ns,duration_time,1320000000,100.00,,\n\
+         4567890123,,cycles,602000000,49.98,3.792,GHz\n\
+         9876543210,,instructions,602510000,50.02,2.16,insn per cycle\n",
+    )
+    .expect("failed to write perf stat input");
+
+    let mut cmd = perfgate_cmd();
+    cmd.arg("ingest")
+        .arg("--format")
+        .arg("perf-stat")
+        .arg("--input")
+        .arg(&input_path)
+        .arg("--name")
+        .arg("sort")
+        .arg("--out")
+        .arg(&output_path);
+
+    cmd.assert()
+        .success()
+        .stderr(predicate::str::contains("Evidence source: perf_stat_csv"))
+        .stderr(predicate::str::contains(
+            "Noise warning: cycles was counted 49.98% of the time (multiplexed)",
+        ))
+        .stderr(predicate::str::contains(
+            "Noise warning: instructions was counted 50.02%",
+        ));
+
+    let receipt: Value =
+        serde_json::from_str(&fs::read_to_string(&output_path).expect("read receipt"))
+            .expect("receipt should be JSON");
+    assert_eq!(receipt["bench"]["name"], "sort");
+    assert_eq!(receipt["stats"]["wall_ms"]["median"], 1320);
+    assert_eq!(receipt["stats"]["cpu_ms"]["median"], 1205);
+    assert_eq!(receipt["stats"]["instructions"]["median"], 9_876_543_210u64);
+    assert_eq!(
+        receipt["stats"]["custom"]["cycles"]["summary"]["median"],
+        4_567_890_123.0
+    );
+    assert_eq!(
+        receipt["run"]["noise_warnings"].as_array().map(Vec::len),
+        Some(2)
+    );
+
+    let compare_path = temp_dir.path().join("compare.json");
+    let mut cmd = perfgate_cmd();
+    cmd.arg("compare")
+        .arg("--baseline")
+        .arg(&output_path)
+        .arg("--current")
+        .arg(&output_path)
+        .arg("--out")
+        .arg(&compare_path);
+    cmd.assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "warning: baseline noise: cycles was counted 49.98% of the time",
+        ))
+        .stderr(predicate::str::contains(
+            "warning: current noise: instructions was counted 50.02%",
+        ));
+}
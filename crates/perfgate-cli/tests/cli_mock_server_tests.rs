@@ -1563,3 +1563,151 @@ async fn test_compare_with_explicit_baseline_project() {
     assert_eq!(wall_ms_delta["current"].as_f64(), Some(110.0));
     assert_eq!(wall_ms_delta["pct"].as_f64(), Some(0.1));
 }
+
+/// Run a git command in `dir`, panicking on failure, and return trimmed stdout.
+/// Commits are dated 2026-01-01.
+#[cfg(unix)]
+fn git(dir: &std::path::Path, args: &[&str]) -> String {
+    let output = std::process::Command::new("git")
+        .args([
+            "-c",
+            "user.name=perfgate",
+            "-c",
+            "user.email=perfgate@example.com",
+            "-c",
+            "commit.gpgsign=false",
+        ])
+        .args(args)
+        .env("GIT_AUTHOR_DATE", "2026-01-01T00:00:00Z")
+        .env("GIT_COMMITTER_DATE", "2026-01-01T00:00:00Z")
+        .current_dir(dir)
+        .output()
+        .expect("failed to run git");
+    assert!(
+        output.status.success(),
+        "git {:?} failed: {}",
+        args,
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8_lossy(&output.stdout).trim().to_string()
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_check_merge_base_pages_through_server_index() {
+    let mock_server = MockServer::start().await;
+    let temp_dir = TempDir::new().expect("failed to create temp dir");
+    let repo = temp_dir.path();
+    let config_path = repo.join("perfgate.toml");
+    fs::write(
+        &config_path,
+        r#"
+[defaults]
+repeat = 2
+warmup = 0
+
+[[bench]]
+name = "mb-bench"
+command = ["echo", "hello"]
+
+[[bench]]
+name = "mb-other"
+command = ["echo", "hello"]
+"#,
+    )
+    .unwrap();
+
+    git(repo, &["init", "-q"]);
+    git(repo, &["checkout", "-q", "-b", "main"]);
+    git(repo, &["commit", "-q", "--allow-empty", "-m", "recorded"]);
+    let recorded = git(repo, &["rev-parse", "HEAD"]);
+    git(repo, &["checkout", "-q", "-b", "feature"]);
+    git(repo, &["commit", "-q", "--allow-empty", "-m", "change"]);
+
+    // Newest first: an unrelated commit, then the merge-base for each bench,
+    // then a baseline older than every searched commit, which ends paging.
+    let summary = |id: &str, bench: &str, git_sha: &str, created_at: &str| {
+        serde_json::json!({
+            "id": id,
+            "benchmark": bench,
+            "version": id,
+            "created_at": created_at,
+            "git_sha": git_sha,
+            "tags": [],
+            "receipt": run_receipt(id, bench, 10_000)
+        })
+    };
+    let pages = [
+        summary(
+            "unrelated",
+            "mb-bench",
+            "0123456789abcdef0123456789abcdef01234567",
+            "2026-01-03T00:00:00Z",
+        ),
+        summary("recorded", "mb-bench", &recorded, "2026-01-02T00:00:00Z"),
+        summary("other", "mb-other", &recorded, "2026-01-02T00:00:00Z"),
+        summary(
+            "stale",
+            "mb-other",
+            "89abcdef0123456789abcdef0123456789abcdef",
+            "2025-12-01T00:00:00Z",
+        ),
+    ];
+    for (offset, baseline) in pages.into_iter().enumerate() {
+        Mock::given(method("GET"))
+            .and(path("/api/v1/projects/test-project/baselines"))
+            .and(query_param("offset", offset.to_string()))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "baselines": [baseline],
+                "pagination": {"total": 5, "limit": 1, "offset": offset, "has_more": true}
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+    }
+    Mock::given(method("GET"))
+        .and(path("/api/v1/projects/test-project/baselines"))
+        .and(query_param("offset", "4"))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let out_dir = repo.join("artifacts");
+    let output = perfgate_cmd()
+        .current_dir(repo)
+        .arg("check")
+        .arg("--config")
+        .arg(&config_path)
+        .args(["--all", "--merge-base", "main"])
+        .arg("--out-dir")
+        .arg(&out_dir)
+        .arg("--baseline-server")
+        .arg(format!("{}/api/v1", mock_server.uri()))
+        .arg("--project")
+        .arg("test-project")
+        .output()
+        .expect("failed to execute check");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "check should pass: {stderr}");
+    assert!(
+        stderr.contains("@server:mb-bench@recorded"),
+        "stderr should report the server baseline: {stderr}"
+    );
+    assert!(
+        stderr.contains("@server:mb-other@other"),
+        "stderr should report the server baseline: {stderr}"
+    );
+
+    for bench in ["mb-bench", "mb-other"] {
+        let report: serde_json::Value = serde_json::from_str(
+            &fs::read_to_string(out_dir.join(bench).join("report.json")).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            report["baseline_selection"]["selected_sha"],
+            recorded.as_str()
+        );
+        assert_eq!(report["baseline_selection"]["distance"], 0);
+    }
+}
@@ -0,0 +1,184 @@
+//! Integration tests for `perfgate suite compare`.
+
+use predicates::prelude::*;
+use std::fs;
+use std::path::Path;
+use tempfile::tempdir;
+
+mod common;
+use common::{fixtures_dir, perfgate_cmd};
+
+/// Copy a run-receipt fixture into `dir` with its bench renamed to `bench`.
+fn write_receipt(dir: &Path, fixture: &str, bench: &str) -> serde_json::Value {
+    let content = fs::read_to_string(fixtures_dir().join(fixture)).expect("read fixture");
+    let mut receipt: serde_json::Value = serde_json::from_str(&content).expect("parse fixture");
+    receipt["bench"]["name"] = serde_json::Value::String(bench.to_string());
+    fs::create_dir_all(dir).expect("create suite dir");
+    fs::write(
+        dir.join(format!("{bench}.json")),
+        serde_json::to_string_pretty(&receipt).expect("serialize fixture"),
+    )
+    .expect("write receipt");
+    receipt
+}
+
+fn read_json(path: &Path) -> serde_json::Value {
+    let content = fs::read_to_string(path).expect("read output");
+    serde_json::from_str(&content).expect("output should be valid JSON")
+}
+
+#[test]
+fn test_suite_compare_directories_match_benches_by_name() {
+    let temp_dir = tempdir().expect("failed to create temp dir");
+    let baseline_dir = temp_dir.path().join("baseline");
+    let current_dir = temp_dir.path().join("current");
+    let out_dir = temp_dir.path().join("out");
+
+    write_receipt(&baseline_dir, "baseline.json", "parse");
+    write_receipt(&baseline_dir, "baseline.json", "render");
+    write_receipt(&baseline_dir, "baseline.json", "legacy");
+    write_receipt(&current_dir, "current_pass.json", "parse");
+    write_receipt(&current_dir.join("nested"), "current_pass.json", "render");
+    write_receipt(&current_dir, "current_pass.json", "fresh");
+
+    perfgate_cmd()
+        .args(["suite", "compare", "--baseline"])
+        .arg(&baseline_dir)
+        .arg("--current")
+        .arg(&current_dir)
+        .arg("--out-dir")
+        .arg(&out_dir)
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "Suite compare: warn (2 compared, 1 added, 1 removed)",
+        ));
+
+    let receipt = read_json(&out_dir.join("suite-compare.json"));
+    assert_eq!(receipt["schema"], "perfgate.suite_compare.v1");
+    let names: Vec<&str> = receipt["benches"]
+        .as_array()
+        .expect("benches array")
+        .iter()
+        .map(|bench| bench["name"].as_str().expect("bench name"))
+        .collect();
+    assert_eq!(names, vec!["parse", "render"]);
+    assert_eq!(receipt["added"], serde_json::json!(["fresh"]));
+    assert_eq!(receipt["removed"], serde_json::json!(["legacy"]));
+    assert_eq!(receipt["geomean"]["wall_ms"]["bench_count"], 2);
+    // A removed benchmark is surfaced as a warning-level suite verdict.
+    assert_eq!(receipt["verdict"]["status"], "warn");
+
+    let report = read_json(&out_dir.join("report.json"));
+    assert_eq!(report["report_type"], "perfgate.report.v1");
+    let comment = fs::read_to_string(out_dir.join("comment.md")).expect("read comment");
+    assert!(comment.contains("perfgate suite"));
+    assert!(comment.contains("`legacy`"));
+}
+
+#[test]
+fn test_suite_compare_bundle_fails_on_regression() {
+    let temp_dir = tempdir().expect("failed to create temp dir");
+    let baseline_dir = temp_dir.path().join("baseline");
+    let scratch = temp_dir.path().join("scratch");
+    let out_dir = temp_dir.path().join("out");
+
+    write_receipt(&baseline_dir, "baseline.json", "parse");
+    write_receipt(&baseline_dir, "baseline.json", "render");
+    let parse = write_receipt(&scratch, "current_pass.json", "parse");
+    let render = write_receipt(&scratch, "current_fail.json", "render");
+
+    let bundle_path = temp_dir.path().join("current-bundle.json");
+    let bundle = serde_json::json!({
+        "schema": "perfgate.run_bundle.v1",
+        "tool": parse["tool"].clone(),
+        "receipts": [parse, render],
+    });
+    fs::write(&bundle_path, bundle.to_string()).expect("write bundle");
+
+    perfgate_cmd()
+        .args(["suite", "compare", "--baseline"])
+        .arg(&baseline_dir)
+        .arg("--current")
+        .arg(&bundle_path)
+        .arg("--out-dir")
+        .arg(&out_dir)
+        .assert()
+        .code(2);
+
+    let receipt = read_json(&out_dir.join("suite-compare.json"));
+    assert_eq!(receipt["verdict"]["status"], "fail");
+    assert_eq!(receipt["verdict"]["counts"]["fail"], 1);
+    assert_eq!(receipt["verdict"]["counts"]["pass"], 1);
+}
+
+/// Write a run receipt for `bench` whose wall-time samples are `walls`.
+fn write_sampled_receipt(dir: &Path, bench: &str, walls: &[u64]) {
+    let content = fs::read_to_string(fixtures_dir().join("baseline.json")).expect("read fixture");
+    let mut receipt: serde_json::Value = serde_json::from_str(&content).expect("parse fixture");
+    let mut sorted = walls.to_vec();
+    sorted.sort_unstable();
+    receipt["bench"]["name"] = serde_json::Value::String(bench.to_string());
+    receipt["samples"] = walls
+        .iter()
+        .map(|wall_ms| {
+            serde_json::json!({
+                "wall_ms": wall_ms,
+                "exit_code": 0,
+                "warmup": false,
+                "timed_out": false,
+            })
+        })
+        .collect();
+    receipt["stats"] = serde_json::json!({
+        "wall_ms": {
+            "median": (sorted[3] + sorted[4]) / 2,
+            "min": sorted[0],
+            "max": sorted[sorted.len() - 1],
+        }
+    });
+    fs::create_dir_all(dir).expect("create suite dir");
+    fs::write(dir.join(format!("{bench}.json")), receipt.to_string()).expect("write receipt");
+}
+
+#[test]
+fn test_suite_compare_fdr_demotes_marginal_regression() {
+    let temp_dir = tempdir().expect("failed to create temp dir");
+    let baseline_dir = temp_dir.path().join("baseline");
+    let current_dir = temp_dir.path().join("current");
+    let noisy = [75, 125, 80, 120, 85, 115, 90, 110];
+    let shifted: Vec<u64> = noisy.iter().map(|v| v + 25).collect();
+    write_sampled_receipt(&baseline_dir, "marginal", &noisy);
+    write_sampled_receipt(&current_dir, "marginal", &shifted);
+    for bench in ["steady-a", "steady-b"] {
+        write_sampled_receipt(&baseline_dir, bench, &noisy);
+        write_sampled_receipt(&current_dir, bench, &noisy);
+    }
+
+    let run = |fdr: bool, out_dir: &Path| {
+        let mut cmd = perfgate_cmd();
+        cmd.args(["suite", "compare", "--baseline"])
+            .arg(&baseline_dir)
+            .arg("--current")
+            .arg(&current_dir)
+            .args(["--significance-alpha", "0.05", "--require-significance"])
+            .arg("--out-dir")
+            .arg(out_dir);
+        if fdr {
+            cmd.arg("--fdr");
+        }
+        cmd.assert()
+    };
+
+    // Uncorrected, the marginal regression is significant on its own.
+    run(false, &temp_dir.path().join("raw")).code(2);
+
+    let out_dir = temp_dir.path().join("fdr");
+    run(true, &out_dir).success();
+    let receipt = read_json(&out_dir.join("suite-compare.json"));
+    assert_eq!(receipt["verdict"]["status"], "pass");
+    let significance = &receipt["benches"][0]["compare"]["deltas"]["wall_ms"]["significance"];
+    assert!(significance["p_value"].as_f64().unwrap() <= 0.05);
+    assert!(significance["adjusted_p_value"].as_f64().unwrap() > 0.05);
+    assert_eq!(significance["significant"], false);
+}
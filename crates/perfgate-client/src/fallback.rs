@@ -438,6 +438,10 @@ mod tests {
                     memory_bytes: Some(16000000000),
                     hostname_hash: None,
                 },
+                profile: None,
+                concurrency: None,
+                interleave: None,
+                noise_warnings: Vec::new(),
             },
             bench: BenchMeta {
                 name: benchmark.to_string(),
@@ -447,6 +451,8 @@ mod tests {
                 warmup: 1,
                 work_units: None,
                 timeout_ms: None,
+                service: None,
+                load: None,
             },
             samples: vec![],
             stats: Stats {
@@ -461,6 +467,19 @@ mod tests {
                 energy_uj: None,
                 binary_bytes: None,
                 throughput_per_s: None,
+                latency_p50_us: None,
+                latency_p95_us: None,
+                latency_p99_us: None,
+                error_rate: None,
+                time_to_ready_ms: None,
+                instructions: None,
+                estimated_cycles: None,
+                l1_hits: None,
+                ll_hits: None,
+                ram_hits: None,
+                alloc_bytes: None,
+                allocs: None,
+                custom: Default::default(),
             },
         }
     }
@@ -30,6 +30,10 @@ fn create_test_receipt() -> RunReceipt {
                 memory_bytes: None,
                 hostname_hash: None,
             },
+            profile: None,
+            concurrency: None,
+            interleave: None,
+            noise_warnings: Vec::new(),
         },
         bench: BenchMeta {
             name: "my-bench".into(),
@@ -39,6 +43,8 @@ fn create_test_receipt() -> RunReceipt {
             warmup: 0,
             work_units: None,
             timeout_ms: None,
+            service: None,
+            load: None,
         },
         samples: vec![],
         stats: Stats {
@@ -53,6 +59,19 @@ fn create_test_receipt() -> RunReceipt {
             energy_uj: None,
             binary_bytes: None,
             throughput_per_s: None,
+            latency_p50_us: None,
+            latency_p95_us: None,
+            latency_p99_us: None,
+            error_rate: None,
+            time_to_ready_ms: None,
+            instructions: None,
+            estimated_cycles: None,
+            l1_hits: None,
+            ll_hits: None,
+            ram_hits: None,
+            alloc_bytes: None,
+            allocs: None,
+            custom: Default::default(),
         },
     }
 }
@@ -49,6 +49,8 @@ fn main() {
         env: vec![],
         timeout: None,
         output_cap_bytes: 1024,
+        cpu_affinity: Vec::new(),
+        ready: None,
     };
 
     match runner.run(&spec1) {
@@ -68,6 +70,8 @@ fn main() {
         env: vec![],
         timeout: None,
         output_cap_bytes: 1024,
+        cpu_affinity: Vec::new(),
+        ready: None,
     };
 
     match runner.run(&spec2) {
@@ -87,6 +91,8 @@ fn main() {
         env: vec![],
         timeout: None,
         output_cap_bytes: 1024,
+        cpu_affinity: Vec::new(),
+        ready: None,
     };
 
     let r1 = runner.run(&spec3).unwrap();
@@ -103,6 +109,8 @@ fn main() {
         env: vec![],
         timeout: None,
         output_cap_bytes: 1024,
+        cpu_affinity: Vec::new(),
+        ready: None,
     };
 
     match runner.run(&unknown_spec) {
@@ -131,6 +139,8 @@ fn main() {
         env: vec![],
         timeout: None,
         output_cap_bytes: 1024,
+        cpu_affinity: Vec::new(),
+        ready: None,
     };
 
     match runner.run(&another_unknown) {
@@ -160,6 +170,8 @@ fn main() {
         env: vec![],
         timeout: None,
         output_cap_bytes: 1024,
+        cpu_affinity: Vec::new(),
+        ready: None,
     };
 
     if let Ok(result) = runner.run(&detailed_spec) {
@@ -169,6 +169,12 @@ impl MockProcessBuilder {
             binary_bytes: self.binary_bytes,
             stdout: self.stdout,
             stderr: self.stderr,
+            latency_p50_us: None,
+            latency_p95_us: None,
+            latency_p99_us: None,
+            error_rate: None,
+            throughput_per_s: None,
+            time_to_ready_ms: None,
         }
     }
 }
@@ -38,6 +38,8 @@
 //!     env: vec![],
 //!     timeout: None,
 //!     output_cap_bytes: 1024,
+//!     cpu_affinity: vec![],
+//!     ready: None,
 //! };
 //!
 //! let output = runner.run(&spec).unwrap();
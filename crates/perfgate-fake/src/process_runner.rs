@@ -41,6 +41,12 @@ use std::sync::{Arc, Mutex};
 ///         binary_bytes: None,
 ///         stdout: b"hello\n".to_vec(),
 ///         stderr: vec![],
+///         latency_p50_us: None,
+///         latency_p95_us: None,
+///         latency_p99_us: None,
+///         error_rate: None,
+///         throughput_per_s: None,
+///         time_to_ready_ms: None,
 ///     },
 /// );
 ///
@@ -154,6 +160,12 @@ mod tests {
             binary_bytes: None,
             stdout: vec![],
             stderr: vec![],
+            latency_p50_us: None,
+            latency_p95_us: None,
+            latency_p99_us: None,
+            error_rate: None,
+            throughput_per_s: None,
+            time_to_ready_ms: None,
         }
     }
 
@@ -165,6 +177,8 @@ mod tests {
             env: vec![],
             timeout: None,
             output_cap_bytes: 1024,
+            cpu_affinity: Vec::new(),
+            ready: None,
         }
     }
 
@@ -588,6 +588,10 @@ mod tests {
                         cpu_count: None,
                         memory_bytes: None,
                     },
+                    profile: None,
+                    concurrency: None,
+                    interleave: None,
+                    noise_warnings: Vec::new(),
                 },
                 bench: BenchMeta {
                     name: "bench".to_string(),
@@ -595,8 +599,10 @@ mod tests {
                     repeat: 1,
                     warmup: 0,
                     timeout_ms: None,
+                    service: None,
                     cwd: None,
                     work_units: None,
+                    load: None,
                 },
                 samples: vec![Sample {
                     wall_ms: 1,
@@ -614,6 +620,19 @@ mod tests {
                     binary_bytes: None,
                     stdout: None,
                     stderr: None,
+                    latency_p50_us: None,
+                    latency_p95_us: None,
+                    latency_p99_us: None,
+                    error_rate: None,
+                    throughput_per_s: None,
+                    time_to_ready_ms: None,
+                    instructions: None,
+                    estimated_cycles: None,
+                    l1_hits: None,
+                    ll_hits: None,
+                    ram_hits: None,
+                    alloc_bytes: None,
+                    allocs: None,
                 }],
                 stats: Stats {
                     wall_ms: U64Summary::new(1, 1, 1),
@@ -627,6 +646,19 @@ mod tests {
                     ctx_switches: None,
                     binary_bytes: None,
                     throughput_per_s: None,
+                    latency_p50_us: None,
+                    latency_p95_us: None,
+                    latency_p99_us: None,
+                    error_rate: None,
+                    time_to_ready_ms: None,
+                    instructions: None,
+                    estimated_cycles: None,
+                    l1_hits: None,
+                    ll_hits: None,
+                    ram_hits: None,
+                    alloc_bytes: None,
+                    allocs: None,
+                    custom: Default::default(),
                 },
             },
             metadata: std::collections::BTreeMap::new(),
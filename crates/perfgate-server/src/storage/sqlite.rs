@@ -1137,6 +1137,10 @@ mod tests {
                     memory_bytes: Some(16 * 1024 * 1024 * 1024),
                     hostname_hash: None,
                 },
+                profile: None,
+                concurrency: None,
+                interleave: None,
+                noise_warnings: Vec::new(),
             },
             bench: BenchMeta {
                 name: name.to_string(),
@@ -1146,6 +1150,8 @@ mod tests {
                 warmup: 1,
                 work_units: None,
                 timeout_ms: None,
+                service: None,
+                load: None,
             },
             samples: vec![],
             stats: Stats {
@@ -1160,6 +1166,19 @@ mod tests {
                 energy_uj: None,
                 binary_bytes: None,
                 throughput_per_s: None,
+                latency_p50_us: None,
+                latency_p95_us: None,
+                latency_p99_us: None,
+                error_rate: None,
+                time_to_ready_ms: None,
+                instructions: None,
+                estimated_cycles: None,
+                l1_hits: None,
+                ll_hits: None,
+                ram_hits: None,
+                alloc_bytes: None,
+                allocs: None,
+                custom: Default::default(),
             },
         }
     }
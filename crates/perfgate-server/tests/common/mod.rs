@@ -49,6 +49,10 @@ pub fn create_test_receipt(benchmark: &str) -> perfgate_types::RunReceipt {
                 cpu_count: Some(8),
                 memory_bytes: None,
             },
+            profile: None,
+            concurrency: None,
+            interleave: None,
+            noise_warnings: Vec::new(),
         },
         bench: BenchMeta {
             name: benchmark.to_string(),
@@ -56,8 +60,10 @@ pub fn create_test_receipt(benchmark: &str) -> perfgate_types::RunReceipt {
             repeat: 3,
             warmup: 0,
             timeout_ms: None,
+            service: None,
             cwd: None,
             work_units: None,
+            load: None,
         },
         samples: vec![
             Sample {
@@ -76,6 +82,19 @@ pub fn create_test_receipt(benchmark: &str) -> perfgate_types::RunReceipt {
                 binary_bytes: None,
                 stdout: None,
                 stderr: None,
+                latency_p50_us: None,
+                latency_p95_us: None,
+                latency_p99_us: None,
+                error_rate: None,
+                throughput_per_s: None,
+                time_to_ready_ms: None,
+                instructions: None,
+                estimated_cycles: None,
+                l1_hits: None,
+                ll_hits: None,
+                ram_hits: None,
+                alloc_bytes: None,
+                allocs: None,
             },
             Sample {
                 wall_ms: 102,
@@ -93,6 +112,19 @@ pub fn create_test_receipt(benchmark: &str) -> perfgate_types::RunReceipt {
                 binary_bytes: None,
                 stdout: None,
                 stderr: None,
+                latency_p50_us: None,
+                latency_p95_us: None,
+                latency_p99_us: None,
+                error_rate: None,
+                throughput_per_s: None,
+                time_to_ready_ms: None,
+                instructions: None,
+                estimated_cycles: None,
+                l1_hits: None,
+                ll_hits: None,
+                ram_hits: None,
+                alloc_bytes: None,
+                allocs: None,
             },
             Sample {
                 wall_ms: 98,
@@ -110,6 +142,19 @@ pub fn create_test_receipt(benchmark: &str) -> perfgate_types::RunReceipt {
                 binary_bytes: None,
                 stdout: None,
                 stderr: None,
+                latency_p50_us: None,
+                latency_p95_us: None,
+                latency_p99_us: None,
+                error_rate: None,
+                throughput_per_s: None,
+                time_to_ready_ms: None,
+                instructions: None,
+                estimated_cycles: None,
+                l1_hits: None,
+                ll_hits: None,
+                ram_hits: None,
+                alloc_bytes: None,
+                allocs: None,
             },
         ],
         stats: Stats {
@@ -124,6 +169,19 @@ pub fn create_test_receipt(benchmark: &str) -> perfgate_types::RunReceipt {
             ctx_switches: None,
             binary_bytes: None,
             throughput_per_s: None,
+            latency_p50_us: None,
+            latency_p95_us: None,
+            latency_p99_us: None,
+            error_rate: None,
+            time_to_ready_ms: None,
+            instructions: None,
+            estimated_cycles: None,
+            l1_hits: None,
+            ll_hits: None,
+            ram_hits: None,
+            alloc_bytes: None,
+            allocs: None,
+            custom: Default::default(),
         },
     }
 }
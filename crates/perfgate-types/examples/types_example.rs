@@ -24,6 +24,19 @@ fn main() {
             binary_bytes: None,
             stdout: None,
             stderr: None,
+            latency_p50_us: None,
+            latency_p95_us: None,
+            latency_p99_us: None,
+            error_rate: None,
+            throughput_per_s: None,
+            time_to_ready_ms: None,
+            instructions: None,
+            estimated_cycles: None,
+            l1_hits: None,
+            ll_hits: None,
+            ram_hits: None,
+            alloc_bytes: None,
+            allocs: None,
         })
         .collect();
 
@@ -40,6 +53,19 @@ fn main() {
         energy_uj: None,
         binary_bytes: None,
         throughput_per_s: None,
+        latency_p50_us: None,
+        latency_p95_us: None,
+        latency_p99_us: None,
+        error_rate: None,
+        time_to_ready_ms: None,
+        instructions: None,
+        estimated_cycles: None,
+        l1_hits: None,
+        ll_hits: None,
+        ram_hits: None,
+        alloc_bytes: None,
+        allocs: None,
+        custom: Default::default(),
     };
 
     // Assemble the receipt
@@ -60,6 +86,10 @@ fn main() {
                 memory_bytes: Some(16_000_000_000),
                 hostname_hash: None,
             },
+            profile: None,
+            concurrency: None,
+            interleave: None,
+            noise_warnings: Vec::new(),
         },
         bench: BenchMeta {
             name: "my-benchmark".to_string(),
@@ -69,6 +99,8 @@ fn main() {
             warmup: 0,
             work_units: None,
             timeout_ms: None,
+            service: None,
+            load: None,
         },
         samples,
         stats,
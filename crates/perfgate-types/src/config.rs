@@ -3,9 +3,11 @@
 //! This module keeps the stable `perfgate.toml` / `perfgate.json` contract
 //! next to the receipt and schema types it configures.
 
-use crate::read_json_file;
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value as JsonValue};
+use std::collections::BTreeMap;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use thiserror::Error;
 use toml_edit::{DocumentMut, Item, Table, Value};
 
@@ -44,6 +46,39 @@ pub enum ConfigLoadError {
         #[source]
         source: crate::ReadJsonError,
     },
+    /// An `extends` entry could not be resolved.
+    #[error("extends in {path}: {message}")]
+    Extends {
+        /// Config file declaring the entry.
+        path: String,
+        /// What was wrong with the entry.
+        message: String,
+    },
+    /// A bench `matrix` could not be expanded.
+    #[error("expand bench matrix in {path}: {message}")]
+    Matrix {
+        /// Config file being loaded.
+        path: String,
+        /// What was wrong with the matrix.
+        message: String,
+    },
+    /// The selected `[profile.<name>]` is unknown or cannot be applied.
+    #[error("profile in {path}: {message}")]
+    Profile {
+        /// Config file being loaded.
+        path: String,
+        /// What was wrong with the profile.
+        message: String,
+    },
+    /// The merged result of an `extends` chain is not a valid config.
+    #[error("merge config {path}: {source}")]
+    Merged {
+        /// Config file at the root of the chain.
+        path: String,
+        /// Underlying deserialization error.
+        #[source]
+        source: serde_json::Error,
+    },
 }
 
 /// Error returned while applying ratchet edits to a TOML config file.
@@ -83,33 +118,603 @@ pub enum RatchetTomlEditError {
 
 /// Loads a `perfgate.toml` or `perfgate.json` config file.
 ///
-/// Returns [`ConfigFile::default`] when the path does not exist.
+/// Returns [`ConfigFile::default`] when the path does not exist. `extends`
+/// entries are merged in as described in [`resolve_config_file`].
 pub fn load_config_file(path: &Path) -> Result<ConfigFile, ConfigLoadError> {
     if !path.exists() {
         return Ok(ConfigFile::default());
     }
 
-    if path
-        .extension()
+    resolve_config_file(path).map(|resolved| resolved.config)
+}
+
+/// Prefix of `extends` entries that name a bundled preset.
+pub const PRESET_PREFIX: &str = "preset:";
+
+/// Bundled presets available to `extends = ["preset:<name>"]`.
+///
+/// These mirror `presets/*.toml` in the repository.
+pub const BUNDLED_PRESETS: &[(&str, &str)] = &[
+    (
+        "standard",
+        r#"# Standard preset: balanced accuracy and speed.
+# Suitable for regular PR checks.
+
+[defaults]
+repeat = 7
+warmup = 1
+threshold = 0.20
+warn_factor = 0.50
+noise_threshold = 0.10
+noise_policy = "warn"
+out_dir = "artifacts/perfgate"
+baseline_dir = "baselines"
+"#,
+    ),
+    (
+        "release",
+        r#"# Release preset: high accuracy with tight threshold.
+# Suitable for release branches and nightly checks.
+
+[defaults]
+repeat = 10
+warmup = 2
+threshold = 0.10
+warn_factor = 0.50
+noise_threshold = 0.08
+noise_policy = "warn"
+out_dir = "artifacts/perfgate"
+baseline_dir = "baselines"
+"#,
+    ),
+    (
+        "tier1-fast",
+        r#"# Tier-1 fast preset: quick validation with wide threshold.
+# Suitable for draft PRs and fast feedback loops.
+
+[defaults]
+repeat = 3
+warmup = 1
+threshold = 0.30
+warn_factor = 0.50
+noise_threshold = 0.15
+noise_policy = "warn"
+out_dir = "artifacts/perfgate"
+baseline_dir = "baselines"
+"#,
+    ),
+];
+
+/// Environment variable naming the config profile to activate.
+pub const PROFILE_ENV: &str = "PERFGATE_PROFILE";
+
+/// Profile activated automatically under CI, when the config defines it.
+pub const CI_PROFILE: &str = "ci";
+
+/// Profile activated automatically outside CI, when the config defines it.
+pub const LOCAL_PROFILE: &str = "local";
+
+/// Environment variables whose presence marks a CI run.
+const CI_ENV_VARS: &[&str] = &[
+    "CI",
+    "GITHUB_ACTIONS",
+    "GITLAB_CI",
+    "BUILDKITE",
+    "CIRCLECI",
+    "TF_BUILD",
+    "JENKINS_URL",
+    "TEAMCITY_VERSION",
+];
+
+/// How the active `[profile.<name>]` is chosen while resolving a config.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum ProfileSelection {
+    /// Use [`PROFILE_ENV`] when set; otherwise [`CI_PROFILE`] under CI or
+    /// [`LOCAL_PROFILE`] elsewhere, if the config defines it.
+    #[default]
+    Auto,
+    /// Use this profile, which the config must define.
+    Named(String),
+}
+
+impl From<Option<String>> for ProfileSelection {
+    /// `Some(name)` selects that profile; `None` selects automatically.
+    fn from(name: Option<String>) -> Self {
+        name.map_or(ProfileSelection::Auto, ProfileSelection::Named)
+    }
+}
+
+/// Whether a CI environment variable such as `CI` or `GITHUB_ACTIONS` is set
+/// (`CI=false` and `CI=0` do not count).
+fn ci_detected(env: impl Fn(&str) -> Option<String>) -> bool {
+    CI_ENV_VARS.iter().any(|key| {
+        env(key).is_some_and(|value| !matches!(value.trim(), "" | "0" | "false" | "FALSE"))
+    })
+}
+
+/// Top-level arrays whose entries are merged by `name` across `extends`.
+const NAMED_ARRAYS: &[&str] = &["bench", "scenario", "tradeoff"];
+
+/// A config file with its `extends` chain merged in.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResolvedConfig {
+    /// The fully merged config. `extends` is always empty.
+    pub config: ConfigFile,
+    /// Every file and preset that contributed, lowest precedence first; the
+    /// resolved file itself comes next, then `profile.<name>` when a profile
+    /// is active.
+    pub sources: Vec<String>,
+    /// The source that set each value, keyed by dotted path such as
+    /// `defaults.threshold` or `bench.parse.budgets.wall_ms.threshold`
+    /// (array entries merged by name are keyed by their name).
+    pub provenance: BTreeMap<String, String>,
+    /// The `[profile.<name>]` applied over the merged config, if any.
+    pub profile: Option<String>,
+}
+
+/// Loads a config file and merges everything it `extends`.
+///
+/// Each `extends` entry is either `preset:<name>` (see [`BUNDLED_PRESETS`]) or
+/// a path relative to the declaring file. Entries apply in order, each one
+/// overriding the ones before it, and the declaring file overrides them all:
+///
+/// - tables merge key by key;
+/// - `[[bench]]`, `[[scenario]]` and `[[tradeoff]]` entries merge by `name`,
+///   and entries with new names are appended;
+/// - every other value, arrays included, replaces the inherited one.
+///
+/// Extended files may extend other files; cycles are rejected. The profile
+/// picked by [`ProfileSelection::Auto`] is then merged over the result the
+/// same way, and bench `matrix` tables are expanded last (see
+/// [`BenchConfigFile::expand_matrix`](crate::BenchConfigFile::expand_matrix)).
+pub fn resolve_config_file(path: &Path) -> Result<ResolvedConfig, ConfigLoadError> {
+    resolve_config_file_with_profile(path, &ProfileSelection::Auto)
+}
+
+/// Like [`resolve_config_file`], choosing the active profile by `profile`.
+pub fn resolve_config_file_with_profile(
+    path: &Path,
+    profile: &ProfileSelection,
+) -> Result<ResolvedConfig, ConfigLoadError> {
+    let content = fs::read_to_string(path).map_err(|source| ConfigLoadError::Read {
+        path: path.display().to_string(),
+        source,
+    })?;
+    resolve_config_str_with_profile(path, &content, profile)
+}
+
+/// Like [`resolve_config_file`], for a config whose content was already read.
+///
+/// `path` decides the format (`.json` or TOML) and anchors relative `extends`
+/// entries.
+///
+/// # Examples
+///
+/// ```
+/// use perfgate_types::config::resolve_config_str;
+/// use std::path::Path;
+///
+/// let resolved = resolve_config_str(
+///     Path::new("perfgate.toml"),
+///     r#"
+/// extends = ["preset:standard"]
+///
+/// [defaults]
+/// repeat = 3
+/// "#,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(resolved.config.defaults.repeat, Some(3));
+/// assert_eq!(resolved.config.defaults.threshold, Some(0.20));
+/// assert_eq!(resolved.provenance["defaults.threshold"], "preset:standard");
+/// assert_eq!(resolved.provenance["defaults.repeat"], "perfgate.toml");
+/// ```
+pub fn resolve_config_str(path: &Path, content: &str) -> Result<ResolvedConfig, ConfigLoadError> {
+    resolve_config_str_with_profile(path, content, &ProfileSelection::Auto)
+}
+
+/// Like [`resolve_config_str`], choosing the active profile by `profile`.
+///
+/// # Examples
+///
+/// ```
+/// use perfgate_types::config::{ProfileSelection, resolve_config_str_with_profile};
+/// use std::path::Path;
+///
+/// let content = r#"
+/// [defaults]
+/// threshold = 0.10
+///
+/// [profile.laptop.defaults]
+/// threshold = 0.30
+/// "#;
+/// let selection = ProfileSelection::Named("laptop".to_string());
+/// let resolved =
+///     resolve_config_str_with_profile(Path::new("perfgate.toml"), content, &selection).unwrap();
+///
+/// assert_eq!(resolved.profile.as_deref(), Some("laptop"));
+/// assert_eq!(resolved.config.defaults.threshold, Some(0.30));
+/// assert_eq!(resolved.provenance["defaults.threshold"], "profile.laptop");
+/// ```
+pub fn resolve_config_str_with_profile(
+    path: &Path,
+    content: &str,
+    profile: &ProfileSelection,
+) -> Result<ResolvedConfig, ConfigLoadError> {
+    let label = path.display().to_string();
+    let is_json = is_json_path(path);
+    let mut value = parse_config::<JsonValue>(&label, content, is_json)?;
+    let extends = take_extends(&label, &mut value)?;
+
+    let mut resolver = ExtendsResolver::default();
+    let mut config = if extends.is_empty() {
+        // Parse the typed config straight from the source so errors keep
+        // their line numbers.
+        let config = parse_config::<ConfigFile>(&label, content, is_json)?;
+        resolver.merge_layer(value, &label);
+        config
+    } else {
+        resolver.stack.push(stack_key(path));
+        resolver.apply_extends(path.parent(), &label, extends)?;
+        resolver.merge_layer(value, &label);
+        serde_json::from_value::<ConfigFile>(resolver.merged.clone()).map_err(|source| {
+            ConfigLoadError::Merged {
+                path: label.clone(),
+                source,
+            }
+        })?
+    };
+
+    let profile = select_profile(&label, &config, profile, |key| std::env::var(key).ok())?;
+    if let Some(name) = &profile {
+        resolver.apply_profile(&label, name)?;
+        config = serde_json::from_value::<ConfigFile>(std::mem::take(&mut resolver.merged))
+            .map_err(|source| ConfigLoadError::Merged {
+                path: label.clone(),
+                source,
+            })?;
+    }
+
+    config
+        .expand_matrices()
+        .map_err(|message| ConfigLoadError::Matrix {
+            path: label.clone(),
+            message,
+        })?;
+
+    Ok(ResolvedConfig {
+        config,
+        sources: resolver.sources,
+        provenance: resolver.provenance,
+        profile,
+    })
+}
+
+/// Pick the profile to apply to `config`, reading the environment via `env`.
+fn select_profile(
+    label: &str,
+    config: &ConfigFile,
+    selection: &ProfileSelection,
+    env: impl Fn(&str) -> Option<String>,
+) -> Result<Option<String>, ConfigLoadError> {
+    let requested = match selection {
+        ProfileSelection::Named(name) => Some(name.clone()),
+        ProfileSelection::Auto => env(PROFILE_ENV).filter(|name| !name.trim().is_empty()),
+    };
+    let Some(name) = requested else {
+        let automatic = if ci_detected(&env) {
+            CI_PROFILE
+        } else {
+            LOCAL_PROFILE
+        };
+        return Ok(config
+            .profiles
+            .contains_key(automatic)
+            .then(|| automatic.to_string()));
+    };
+
+    if config.profiles.contains_key(&name) {
+        return Ok(Some(name));
+    }
+    let available = if config.profiles.is_empty() {
+        "no profiles are defined".to_string()
+    } else {
+        let names: Vec<&str> = config.profiles.keys().map(String::as_str).collect();
+        format!("available: {}", names.join(", "))
+    };
+    Err(ConfigLoadError::Profile {
+        path: label.to_string(),
+        message: format!("unknown profile '{}' ({})", name, available),
+    })
+}
+
+#[derive(Default)]
+struct ExtendsResolver {
+    merged: JsonValue,
+    sources: Vec<String>,
+    provenance: BTreeMap<String, String>,
+    /// Files and presets currently being resolved, for cycle detection.
+    stack: Vec<String>,
+}
+
+/// One loaded `extends` entry.
+struct ExtendsSource {
+    /// Identity used for cycle detection.
+    key: String,
+    /// Name recorded in `sources` and provenance.
+    label: String,
+    content: String,
+    is_json: bool,
+    /// Directory its own relative `extends` resolve against; `None` for presets.
+    dir: Option<PathBuf>,
+}
+
+impl ExtendsSource {
+    /// Load `entry`, declared by `label` located in `dir`.
+    fn load(dir: Option<&Path>, label: &str, entry: &str) -> Result<Self, ConfigLoadError> {
+        if let Some(name) = entry.strip_prefix(PRESET_PREFIX) {
+            let Some((_, content)) = BUNDLED_PRESETS.iter().find(|(preset, _)| *preset == name)
+            else {
+                let available: Vec<&str> = BUNDLED_PRESETS.iter().map(|(name, _)| *name).collect();
+                return Err(ConfigLoadError::Extends {
+                    path: label.to_string(),
+                    message: format!(
+                        "unknown preset '{}' (available: {})",
+                        name,
+                        available.join(", ")
+                    ),
+                });
+            };
+            return Ok(Self {
+                key: entry.to_string(),
+                label: entry.to_string(),
+                content: content.to_string(),
+                is_json: false,
+                dir: None,
+            });
+        }
+
+        let Some(dir) = dir else {
+            return Err(ConfigLoadError::Extends {
+                path: label.to_string(),
+                message: format!("'{}': presets cannot extend files", entry),
+            });
+        };
+        let file = dir.join(entry);
+        let file_label = file.display().to_string();
+        let content = fs::read_to_string(&file).map_err(|source| ConfigLoadError::Read {
+            path: file_label.clone(),
+            source,
+        })?;
+        Ok(Self {
+            key: stack_key(&file),
+            label: file_label,
+            content,
+            is_json: is_json_path(&file),
+            dir: file.parent().map(Path::to_path_buf),
+        })
+    }
+}
+
+impl ExtendsResolver {
+    /// Merge each `extends` entry of the file `label` (located in `dir`).
+    fn apply_extends(
+        &mut self,
+        dir: Option<&Path>,
+        label: &str,
+        extends: Vec<String>,
+    ) -> Result<(), ConfigLoadError> {
+        for entry in extends {
+            let source = ExtendsSource::load(dir, label, &entry)?;
+            if self.stack.contains(&source.key) {
+                return Err(ConfigLoadError::Extends {
+                    path: label.to_string(),
+                    message: format!("'{}' forms an extends cycle", entry),
+                });
+            }
+
+            let mut value =
+                parse_config::<JsonValue>(&source.label, &source.content, source.is_json)?;
+            let nested = take_extends(&source.label, &mut value)?;
+            self.stack.push(source.key);
+            self.apply_extends(source.dir.as_deref(), &source.label, nested)?;
+            self.stack.pop();
+            self.merge_layer(value, &source.label);
+        }
+        Ok(())
+    }
+
+    /// Merge `[profile.<name>]`'s `defaults` and `bench` tables over the
+    /// merged config.
+    fn apply_profile(&mut self, label: &str, name: &str) -> Result<(), ConfigLoadError> {
+        let Some(profile) = self
+            .merged
+            .get("profile")
+            .and_then(|profiles| profiles.get(name))
+            .and_then(JsonValue::as_object)
+        else {
+            return Ok(());
+        };
+
+        let mut overlay = Map::new();
+        if let Some(defaults) = profile.get("defaults") {
+            overlay.insert("defaults".to_string(), defaults.clone());
+        }
+        if let Some(JsonValue::Array(entries)) = profile.get("bench") {
+            let known: Vec<&str> = self
+                .merged
+                .get("bench")
+                .and_then(JsonValue::as_array)
+                .map(|benches| {
+                    benches
+                        .iter()
+                        .filter_map(|bench| bench.get("name").and_then(JsonValue::as_str))
+                        .collect()
+                })
+                .unwrap_or_default();
+            for entry in entries {
+                let bench = entry.get("name").and_then(JsonValue::as_str).unwrap_or("");
+                if !known.contains(&bench) {
+                    return Err(ConfigLoadError::Profile {
+                        path: label.to_string(),
+                        message: format!("profile '{}' overrides unknown bench '{}'", name, bench),
+                    });
+                }
+            }
+            overlay.insert("bench".to_string(), JsonValue::Array(entries.clone()));
+        }
+
+        self.merge_layer(JsonValue::Object(overlay), &format!("profile.{}", name));
+        Ok(())
+    }
+
+    /// Merge one parsed file over everything merged so far.
+    fn merge_layer(&mut self, value: JsonValue, source: &str) {
+        if !self.merged.is_object() {
+            self.merged = JsonValue::Object(Map::new());
+        }
+        let mut merged = std::mem::take(&mut self.merged);
+        self.merge_value(&mut merged, value, "", source);
+        self.merged = merged;
+        self.sources.push(source.to_string());
+    }
+
+    fn merge_value(&mut self, base: &mut JsonValue, overlay: JsonValue, path: &str, source: &str) {
+        let (base, overlay) = match (base, overlay) {
+            (JsonValue::Object(base), JsonValue::Object(overlay)) => (base, overlay),
+            (base, overlay) => {
+                self.record(&overlay, path, source);
+                *base = overlay;
+                return;
+            }
+        };
+        for (key, value) in overlay {
+            let child = join_path(path, &key);
+            let named = path.is_empty() && NAMED_ARRAYS.contains(&key.as_str());
+            match (base.get_mut(&key), value) {
+                (Some(JsonValue::Array(existing)), JsonValue::Array(entries)) if named => {
+                    self.merge_named(existing, entries, &child, source);
+                }
+                (None, JsonValue::Array(entries)) if named => {
+                    let mut merged = Vec::new();
+                    self.merge_named(&mut merged, entries, &child, source);
+                    base.insert(key, JsonValue::Array(merged));
+                }
+                (Some(existing), value) => self.merge_value(existing, value, &child, source),
+                (None, value) => {
+                    self.record(&value, &child, source);
+                    base.insert(key, value);
+                }
+            }
+        }
+    }
+
+    /// Merge `[[bench]]`-style entries into `base`, matching them by `name`.
+    fn merge_named(
+        &mut self,
+        base: &mut Vec<JsonValue>,
+        entries: Vec<JsonValue>,
+        path: &str,
+        source: &str,
+    ) {
+        for entry in entries {
+            let name = entry
+                .get("name")
+                .and_then(JsonValue::as_str)
+                .map(str::to_string)
+                .unwrap_or_else(|| base.len().to_string());
+            let child = join_path(path, &name);
+            let position = base
+                .iter()
+                .position(|item| item.get("name").and_then(JsonValue::as_str) == Some(&name));
+            match position {
+                Some(index) => self.merge_value(&mut base[index], entry, &child, source),
+                None => {
+                    self.record(&entry, &child, source);
+                    base.push(entry);
+                }
+            }
+        }
+    }
+
+    /// Attribute every leaf under `path` to `source`, replacing stale entries.
+    fn record(&mut self, value: &JsonValue, path: &str, source: &str) {
+        let prefix = format!("{}.", path);
+        self.provenance
+            .retain(|key, _| key != path && !key.starts_with(&prefix));
+        self.record_leaves(value, path, source);
+    }
+
+    fn record_leaves(&mut self, value: &JsonValue, path: &str, source: &str) {
+        match value {
+            JsonValue::Object(map) if !map.is_empty() => {
+                for (key, value) in map {
+                    self.record_leaves(value, &join_path(path, key), source);
+                }
+            }
+            _ => {
+                self.provenance.insert(path.to_string(), source.to_string());
+            }
+        }
+    }
+}
+
+fn join_path(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", path, key)
+    }
+}
+
+fn is_json_path(path: &Path) -> bool {
+    path.extension()
         .and_then(|ext| ext.to_str())
         .is_some_and(|ext| ext == "json")
-    {
-        read_json_file(path).map_err(|source| ConfigLoadError::Json {
-            path: path.display().to_string(),
-            source,
+}
+
+/// Identity of a file on the extends stack, canonicalized when possible.
+fn stack_key(path: &Path) -> String {
+    fs::canonicalize(path)
+        .unwrap_or_else(|_| PathBuf::from(path))
+        .display()
+        .to_string()
+}
+
+/// Parse a config source as `T`, reporting errors against `label`.
+fn parse_config<T: DeserializeOwned>(
+    label: &str,
+    content: &str,
+    is_json: bool,
+) -> Result<T, ConfigLoadError> {
+    if is_json {
+        serde_json::from_str(content).map_err(|source| ConfigLoadError::Json {
+            path: label.to_string(),
+            source: crate::ReadJsonError::Parse {
+                path: label.to_string(),
+                source,
+            },
         })
     } else {
-        let content = fs::read_to_string(path).map_err(|source| ConfigLoadError::Read {
-            path: path.display().to_string(),
-            source,
-        })?;
-        toml::from_str::<ConfigFile>(&content).map_err(|source| ConfigLoadError::TomlParse {
-            path: path.display().to_string(),
+        toml::from_str(content).map_err(|source| ConfigLoadError::TomlParse {
+            path: label.to_string(),
             source,
         })
     }
 }
 
+/// Remove and return the `extends` list of a parsed config.
+fn take_extends(label: &str, value: &mut JsonValue) -> Result<Vec<String>, ConfigLoadError> {
+    let Some(extends) = value.as_object_mut().and_then(|map| map.remove("extends")) else {
+        return Ok(Vec::new());
+    };
+    serde_json::from_value(extends).map_err(|_| ConfigLoadError::Extends {
+        path: label.to_string(),
+        message: "`extends` must be a list of strings".to_string(),
+    })
+}
+
 /// Preview ratchet edits as human-readable lines.
 pub fn preview_ratchet_toml_changes(changes: &[RatchetChange]) -> Vec<String> {
     if changes.is_empty() {
@@ -193,6 +798,14 @@ pub fn apply_ratchet_toml_changes(
                 RatchetTomlEditError::Malformed(format!("budgets.{metric_key} is not a table"))
             })?;
 
+            // Auto thresholds are derived at compare time; never pin them.
+            if metric_table
+                .get("threshold")
+                .and_then(Item::as_str)
+                .is_some()
+            {
+                continue;
+            }
             let current = metric_table
                 .get("threshold")
                 .and_then(Item::as_float)
@@ -250,4 +863,276 @@ threshold = 0.2 # inline comment
         assert!(updated.contains("# bench comment"));
         assert!(updated.contains("threshold = 0.18"));
     }
+
+    #[test]
+    fn extends_merges_presets_files_and_named_entries() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let shared = dir.path().join("shared");
+        std::fs::create_dir(&shared).expect("mkdir");
+        std::fs::write(
+            shared.join("perfgate.base.toml"),
+            r#"
+extends = ["preset:standard"]
+
+[defaults]
+threshold = 0.15
+
+[[bench]]
+name = "parse"
+command = ["parse", "--fast"]
+repeat = 9
+
+[bench.budgets.wall_ms]
+threshold = 0.10
+warn_factor = 0.5
+
+[[bench]]
+name = "render"
+command = ["render"]
+"#,
+        )
+        .expect("write base");
+        let path = dir.path().join("perfgate.toml");
+        std::fs::write(
+            &path,
+            r#"
+extends = ["shared/perfgate.base.toml"]
+
+[defaults]
+repeat = 4
+
+[[bench]]
+name = "parse"
+command = ["parse"]
+
+[bench.budgets.wall_ms]
+threshold = 0.05
+
+[[bench]]
+name = "serialize"
+command = ["serialize"]
+"#,
+        )
+        .expect("write config");
+
+        let resolved = resolve_config_file(&path).expect("resolve");
+        let config = &resolved.config;
+        let base_label = shared.join("perfgate.base.toml").display().to_string();
+        let own_label = path.display().to_string();
+
+        assert!(config.extends.is_empty());
+        assert_eq!(
+            resolved.sources,
+            vec![
+                "preset:standard".to_string(),
+                base_label.clone(),
+                own_label.clone()
+            ]
+        );
+        assert_eq!(config.defaults.repeat, Some(4));
+        assert_eq!(config.defaults.threshold, Some(0.15));
+        assert_eq!(config.defaults.warmup, Some(1));
+
+        let names: Vec<&str> = config.benches.iter().map(|b| b.name.as_str()).collect();
+        assert_eq!(names, vec!["parse", "render", "serialize"]);
+        let parse = &config.benches[0];
+        assert_eq!(parse.command, vec!["parse".to_string()]);
+        assert_eq!(parse.repeat, Some(9));
+        let wall = &parse.budgets.as_ref().expect("budgets")[&Metric::WallMs];
+        assert_eq!(
+            wall.threshold.and_then(crate::BudgetThreshold::fixed),
+            Some(0.05)
+        );
+        assert_eq!(wall.warn_factor, Some(0.5));
+
+        let provenance = &resolved.provenance;
+        assert_eq!(provenance["defaults.warmup"], "preset:standard");
+        assert_eq!(provenance["defaults.threshold"], base_label);
+        assert_eq!(provenance["defaults.repeat"], own_label);
+        assert_eq!(provenance["bench.parse.command"], own_label);
+        assert_eq!(provenance["bench.parse.repeat"], base_label);
+        assert_eq!(
+            provenance["bench.parse.budgets.wall_ms.threshold"],
+            own_label
+        );
+        assert_eq!(
+            provenance["bench.parse.budgets.wall_ms.warn_factor"],
+            base_label
+        );
+        assert_eq!(provenance["bench.serialize.name"], own_label);
+    }
+
+    #[test]
+    fn extends_rejects_cycles_and_unknown_presets() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let a = dir.path().join("a.toml");
+        let b = dir.path().join("b.toml");
+        std::fs::write(&a, "extends = [\"b.toml\"]\n").expect("write a");
+        std::fs::write(&b, "extends = [\"a.toml\"]\n").expect("write b");
+        let err = resolve_config_file(&a).expect_err("cycle");
+        assert!(err.to_string().contains("extends cycle"), "{err}");
+
+        let err = resolve_config_str(
+            Path::new("perfgate.toml"),
+            "extends = [\"preset:nightly\"]\n",
+        )
+        .expect_err("unknown preset");
+        assert!(
+            err.to_string().contains("unknown preset 'nightly'"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn extends_supports_json_configs() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        std::fs::write(
+            dir.path().join("base.toml"),
+            "[defaults]\nrepeat = 11\nwarmup = 3\n",
+        )
+        .expect("write base");
+        let path = dir.path().join("perfgate.json");
+        std::fs::write(
+            &path,
+            r#"{"extends": ["base.toml"], "defaults": {"warmup": 0}}"#,
+        )
+        .expect("write config");
+
+        let config = load_config_file(&path).expect("load");
+        assert_eq!(config.defaults.repeat, Some(11));
+        assert_eq!(config.defaults.warmup, Some(0));
+    }
+
+    const PROFILED_CONFIG: &str = r#"
+[defaults]
+repeat = 5
+threshold = 0.10
+
+[[bench]]
+name = "parse"
+command = ["true"]
+
+[bench.budgets.wall_ms]
+threshold = 0.05
+warn_factor = 0.5
+
+[profile.ci.defaults]
+repeat = 15
+
+[[profile.ci.bench]]
+name = "parse"
+warmup = 3
+
+[profile.ci.bench.budgets.wall_ms]
+threshold = 0.08
+
+[profile.local.defaults]
+threshold = 0.30
+"#;
+
+    #[test]
+    fn profile_overrides_defaults_and_benches() {
+        let resolved = resolve_config_str_with_profile(
+            Path::new("perfgate.toml"),
+            PROFILED_CONFIG,
+            &ProfileSelection::Named("ci".to_string()),
+        )
+        .expect("resolve");
+
+        assert_eq!(resolved.profile.as_deref(), Some("ci"));
+        let config = &resolved.config;
+        assert_eq!(config.defaults.repeat, Some(15));
+        assert_eq!(config.defaults.threshold, Some(0.10));
+        let bench = &config.benches[0];
+        assert_eq!(bench.warmup, Some(3));
+        let budget = &bench.budgets.as_ref().expect("budgets")[&Metric::WallMs];
+        assert_eq!(budget.threshold, Some(0.08.into()));
+        assert_eq!(budget.warn_factor, Some(0.5));
+        assert_eq!(
+            resolved.sources,
+            vec!["perfgate.toml".to_string(), "profile.ci".to_string()]
+        );
+        assert_eq!(resolved.provenance["defaults.repeat"], "profile.ci");
+        assert_eq!(resolved.provenance["defaults.threshold"], "perfgate.toml");
+        assert_eq!(
+            resolved.provenance["bench.parse.budgets.wall_ms.threshold"],
+            "profile.ci"
+        );
+    }
+
+    #[test]
+    fn profile_selection_follows_environment() {
+        let config: ConfigFile = toml::from_str(PROFILED_CONFIG).expect("parse");
+        let select = |vars: &[(&str, &str)]| {
+            select_profile("perfgate.toml", &config, &ProfileSelection::Auto, |key| {
+                vars.iter()
+                    .find(|(name, _)| *name == key)
+                    .map(|(_, value)| value.to_string())
+            })
+            .expect("select")
+        };
+
+        assert_eq!(select(&[]).as_deref(), Some("local"));
+        assert_eq!(select(&[("CI", "false")]).as_deref(), Some("local"));
+        assert_eq!(select(&[("GITHUB_ACTIONS", "true")]).as_deref(), Some("ci"));
+        assert_eq!(
+            select(&[("CI", "true"), (PROFILE_ENV, "local")]).as_deref(),
+            Some("local")
+        );
+
+        let unprofiled = ConfigFile::default();
+        let none = select_profile(
+            "perfgate.toml",
+            &unprofiled,
+            &ProfileSelection::Auto,
+            |key| (key == "CI").then(|| "1".to_string()),
+        )
+        .expect("select");
+        assert_eq!(none, None);
+    }
+
+    #[test]
+    fn profile_rejects_unknown_names_and_benches() {
+        let err = resolve_config_str_with_profile(
+            Path::new("perfgate.toml"),
+            PROFILED_CONFIG,
+            &ProfileSelection::Named("release".to_string()),
+        )
+        .expect_err("unknown profile");
+        assert!(
+            err.to_string()
+                .contains("unknown profile 'release' (available: ci, local)"),
+            "{err}"
+        );
+
+        let err = resolve_config_str_with_profile(
+            Path::new("perfgate.toml"),
+            "[[profile.ci.bench]]\nname = \"missing\"\nrepeat = 2\n",
+            &ProfileSelection::Named("ci".to_string()),
+        )
+        .expect_err("unknown bench");
+        assert!(
+            err.to_string()
+                .contains("profile 'ci' overrides unknown bench 'missing'"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn bundled_presets_match_repository_presets() {
+        let presets_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../presets");
+        if !presets_dir.is_dir() {
+            return;
+        }
+        for (name, content) in BUNDLED_PRESETS {
+            let on_disk = std::fs::read_to_string(presets_dir.join(format!("{name}.toml")))
+                .expect("read preset");
+            let bundled: ConfigFile = toml::from_str(content).expect("parse bundled");
+            let repo: ConfigFile = toml::from_str(&on_disk).expect("parse repo");
+            assert_eq!(
+                bundled, repo,
+                "preset {name} drifted from presets/{name}.toml"
+            );
+        }
+    }
 }
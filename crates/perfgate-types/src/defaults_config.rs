@@ -34,6 +34,17 @@ pub struct DefaultsConfig {
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub baseline_pattern: Option<String>,
 
+    /// Optional baseline history store laid out as `<dir>/<git_sha>/<bench>.json`.
+    /// Used by merge-base aware baseline selection.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub baseline_history_dir: Option<String>,
+
+    /// Optional target branch (e.g. `origin/main`). When set, `check` compares
+    /// against the baseline recorded for the merge-base of `HEAD` and this
+    /// branch, or the nearest ancestor that has one.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub merge_base: Option<String>,
+
     /// Optional Handlebars template path for markdown comments.
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub markdown_template: Option<String>,
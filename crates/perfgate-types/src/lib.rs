@@ -31,6 +31,7 @@ mod io;
 mod paired;
 mod repair_context;
 mod structured_evidence;
+mod suite;
 pub mod validation;
 
 pub use paired::{
@@ -42,6 +43,7 @@ pub use defaults_config::*;
 pub use io::{ReadJsonError, read_json_file};
 pub use repair_context::*;
 pub use structured_evidence::*;
+pub use suite::*;
 
 pub use validation::{
     BENCH_NAME_MAX_LEN, BENCH_NAME_PATTERN, ValidationError as BenchNameValidationError,
@@ -68,6 +70,8 @@ pub const REPORT_SCHEMA_V1: &str = "perfgate.report.v1";
 pub const CONFIG_SCHEMA_V1: &str = "perfgate.config.v1";
 pub const RATCHET_SCHEMA_V1: &str = "perfgate.ratchet.v1";
 pub const REPAIR_CONTEXT_SCHEMA_V1: &str = "perfgate.repair_context.v1";
+pub const RUN_BUNDLE_SCHEMA_V1: &str = "perfgate.run_bundle.v1";
+pub const SUITE_COMPARE_SCHEMA_V1: &str = "perfgate.suite_compare.v1";
 
 // Stable contract identifiers and tokens.
 pub const CHECK_ID_BUDGET: &str = "perf.budget";
@@ -75,6 +79,8 @@ pub const CHECK_ID_BASELINE: &str = "perf.baseline";
 pub const CHECK_ID_COMPLEXITY: &str = "perf.complexity";
 pub const CHECK_ID_HOST: &str = "perf.host";
 pub const CHECK_ID_TOOL_RUNTIME: &str = "tool.runtime";
+pub const CHECK_ID_SUITE: &str = "perf.suite";
+pub const CHECK_ID_SELECTION: &str = "perf.selection";
 pub const FINDING_CODE_METRIC_WARN: &str = "metric_warn";
 pub const FINDING_CODE_METRIC_FAIL: &str = "metric_fail";
 pub const FINDING_CODE_BASELINE_MISSING: &str = "missing";
@@ -82,10 +88,14 @@ pub const FINDING_CODE_HOST_MISMATCH: &str = "host_mismatch";
 pub const FINDING_CODE_RUNTIME_ERROR: &str = "runtime_error";
 pub const FINDING_CODE_COMPLEXITY_FAIL: &str = "complexity_fail";
 pub const FINDING_CODE_COMPLEXITY_INCONCLUSIVE: &str = "complexity_inconclusive";
+pub const FINDING_CODE_BENCH_REMOVED: &str = "bench_removed";
+pub const FINDING_CODE_BENCH_SKIPPED: &str = "bench_skipped";
 pub const VERDICT_REASON_NO_BASELINE: &str = "no_baseline";
+pub const VERDICT_REASON_UNCHANGED: &str = "unchanged";
 pub const VERDICT_REASON_HOST_MISMATCH: &str = "host_mismatch";
 pub const VERDICT_REASON_TOOL_ERROR: &str = "tool_error";
 pub const VERDICT_REASON_TRUNCATED: &str = "truncated";
+pub const VERDICT_REASON_SUITE_BENCH_REMOVED: &str = "suite_bench_removed";
 pub const VERDICT_REASON_TRADEOFF_RULE_NOT_SATISFIED: &str = "tradeoff_rule_not_satisfied";
 pub const VERDICT_REASON_TRADEOFF_MISSING_REQUIRED_METRIC: &str =
     "tradeoff_missing_required_metric";
@@ -325,6 +335,51 @@ pub struct RunMeta {
     pub started_at: String,
     pub ended_at: String,
     pub host: HostInfo,
+
+    /// Config profile (`[profile.<name>]`) active when the run was recorded.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub profile: Option<String>,
+
+    /// Parallel execution settings (`check --jobs`); absent for serial runs.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub concurrency: Option<RunConcurrency>,
+
+    /// Sample interleaving (`check --interleave`); absent when the bench's
+    /// samples ran back to back.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub interleave: Option<SampleInterleave>,
+
+    /// Caveats about how the run was measured that widen its noise, such as
+    /// multiplexed hardware counters in ingested `perf stat` output.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub noise_warnings: Vec<String>,
+}
+
+/// How samples were interleaved across benches by `check --interleave`.
+///
+/// Re-running `check --interleave --seed <seed>` over the same benches
+/// reproduces the sample order exactly.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct SampleInterleave {
+    /// Seed of the shuffled sample order.
+    pub seed: u64,
+
+    /// Benches whose samples were interleaved, in selection order.
+    pub benches: Vec<String>,
+}
+
+/// How a bench was scheduled when `check --jobs` ran several benches at once.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct RunConcurrency {
+    /// Number of benches that ran concurrently.
+    pub jobs: u32,
+
+    /// CPUs the bench was pinned to. Empty when pinning is unsupported on the
+    /// host platform.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub cpus: Vec<u32>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
@@ -347,6 +402,47 @@ pub struct BenchMeta {
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub timeout_ms: Option<u64>,
+
+    /// Server of a service bench; `command` is then the client workload.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub service: Option<ServiceMeta>,
+
+    /// Load of an HTTP load bench; `command` is then empty.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub load: Option<HttpLoadMeta>,
+}
+
+/// Server measured by a service bench.
+///
+/// In service benches `cpu_ms` and `max_rss_kb` describe this server during
+/// each sample's workload rather than the client command.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct ServiceMeta {
+    /// argv vector of the server (no shell parsing).
+    pub command: Vec<String>,
+
+    /// Time from starting the server until its readiness check passed.
+    pub ready_ms: u64,
+}
+
+/// Load driven by the built-in HTTP load generator.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct HttpLoadMeta {
+    pub url: String,
+    pub method: String,
+    pub concurrency: u32,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration_ms: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requests: Option<u64>,
+
+    /// Target request rate per second of open-loop load.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rate: Option<u64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
@@ -396,6 +492,59 @@ pub struct Sample {
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub binary_bytes: Option<u64>,
 
+    /// Median request latency in microseconds (HTTP load benches).
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub latency_p50_us: Option<u64>,
+
+    /// 95th percentile request latency in microseconds (HTTP load benches).
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub latency_p95_us: Option<u64>,
+
+    /// 99th percentile request latency in microseconds (HTTP load benches).
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub latency_p99_us: Option<u64>,
+
+    /// Fraction of requests that failed, 0.0 to 1.0 (HTTP load benches).
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub error_rate: Option<f64>,
+
+    /// Successful requests per second (HTTP load benches); takes precedence
+    /// over throughput derived from `work_units`.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub throughput_per_s: Option<f64>,
+
+    /// Time from spawning the command until its ready probe passed.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub time_to_ready_ms: Option<u64>,
+
+    /// Instructions executed (deterministic counters, e.g. Callgrind).
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub instructions: Option<u64>,
+
+    /// Callgrind estimated cycles.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub estimated_cycles: Option<u64>,
+
+    /// Simulated L1 cache hits.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub l1_hits: Option<u64>,
+
+    /// Simulated last-level cache hits.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub ll_hits: Option<u64>,
+
+    /// Simulated RAM hits.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub ram_hits: Option<u64>,
+
+    /// Bytes allocated per operation (e.g. Go `B/op`).
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub alloc_bytes: Option<u64>,
+
+    /// Heap allocations per operation (e.g. Go `allocs/op`).
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub allocs: Option<u64>,
+
     /// Truncated stdout (bytes interpreted as UTF-8 lossily).
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub stdout: Option<String>,
@@ -490,6 +639,15 @@ impl F64Summary {
 ///     energy_uj: None,
 ///     binary_bytes: None,
 ///     throughput_per_s: None,
+///     latency_p50_us: None,
+///     latency_p95_us: None,
+///     latency_p99_us: None,
+///     error_rate: None,
+///     time_to_ready_ms: None,
+///     instructions: None, estimated_cycles: None,
+///     l1_hits: None, ll_hits: None, ram_hits: None,
+///     alloc_bytes: None, allocs: None,
+///     custom: Default::default(),
 /// };
 /// assert_eq!(stats.wall_ms.median, 100);
 /// assert_eq!(stats.max_rss_kb.unwrap().median, 4096);
@@ -536,6 +694,73 @@ pub struct Stats {
 
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub throughput_per_s: Option<F64Summary>,
+
+    /// Median request latency summary in microseconds.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub latency_p50_us: Option<U64Summary>,
+
+    /// 95th percentile request latency summary in microseconds.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub latency_p95_us: Option<U64Summary>,
+
+    /// 99th percentile request latency summary in microseconds.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub latency_p99_us: Option<U64Summary>,
+
+    /// Request error rate summary (fraction of failed requests).
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub error_rate: Option<F64Summary>,
+
+    /// Time-to-ready summary in milliseconds (benches with a ready probe).
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub time_to_ready_ms: Option<U64Summary>,
+
+    /// Instructions executed summary (deterministic; e.g. Callgrind `Ir`).
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub instructions: Option<U64Summary>,
+
+    /// Callgrind estimated cycles summary (weighted L1/LL/RAM accesses).
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub estimated_cycles: Option<U64Summary>,
+
+    /// L1 cache hits summary (deterministic, simulated by Callgrind).
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub l1_hits: Option<U64Summary>,
+
+    /// Last-level cache hits summary (deterministic, simulated by Callgrind).
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub ll_hits: Option<U64Summary>,
+
+    /// RAM hits summary (deterministic, simulated by Callgrind).
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub ram_hits: Option<U64Summary>,
+
+    /// Bytes allocated per operation summary.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub alloc_bytes: Option<U64Summary>,
+
+    /// Heap allocations per operation summary.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub allocs: Option<U64Summary>,
+
+    /// Tool-specific metrics with no first-class [`Metric`], keyed by name
+    /// (e.g. Google Benchmark user counters). Informational only: compare,
+    /// budgets and verdicts do not consider them.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+    pub custom: BTreeMap<String, CustomMetric>,
+}
+
+/// A named metric reported by an external benchmark tool that perfgate has
+/// no first-class [`Metric`] for. It has no direction because it is never
+/// compared or gated.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct CustomMetric {
+    /// Unit as reported by the source tool, if any.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub unit: Option<String>,
+
+    pub summary: F64Summary,
 }
 
 /// A versioned receipt from a single benchmark run (`perfgate.run.v1`).
@@ -556,11 +781,15 @@ pub struct Stats {
 ///             os: "linux".into(), arch: "x86_64".into(),
 ///             cpu_count: None, memory_bytes: None, hostname_hash: None,
 ///         },
+///         profile: None,
+///         concurrency: None,
+///         interleave: None,
+///         noise_warnings: Vec::new(),
 ///     },
 ///     bench: BenchMeta {
 ///         name: "my-bench".into(), cwd: None,
 ///         command: vec!["echo".into(), "hello".into()],
-///         repeat: 3, warmup: 0, work_units: None, timeout_ms: None,
+///         repeat: 3, warmup: 0, work_units: None, timeout_ms: None, service: None, load: None,
 ///     },
 ///     samples: vec![],
 ///     stats: Stats {
@@ -568,6 +797,12 @@ pub struct Stats {
 ///         cpu_ms: None, page_faults: None, ctx_switches: None,
 ///         max_rss_kb: None, io_read_bytes: None, io_write_bytes: None,
 ///         network_packets: None, energy_uj: None, binary_bytes: None, throughput_per_s: None,
+///         latency_p50_us: None, latency_p95_us: None, latency_p99_us: None, error_rate: None,
+///         time_to_ready_ms: None,
+///         instructions: None, estimated_cycles: None,
+///         l1_hits: None, ll_hits: None, ram_hits: None,
+///         alloc_bytes: None, allocs: None,
+///         custom: Default::default(),
 ///     },
 /// };
 ///
@@ -741,16 +976,28 @@ pub struct AggregateReceipt {
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "snake_case")]
 pub enum Metric {
+    AllocBytes,
+    Allocs,
     BinaryBytes,
     CpuMs,
     CtxSwitches,
     EnergyUj,
+    ErrorRate,
+    EstimatedCycles,
+    Instructions,
     IoReadBytes,
     IoWriteBytes,
+    L1Hits,
+    LatencyP50Us,
+    LatencyP95Us,
+    LatencyP99Us,
+    LlHits,
     MaxRssKb,
     NetworkPackets,
     PageFaults,
+    RamHits,
     ThroughputPerS,
+    TimeToReadyMs,
     WallMs,
 }
 
@@ -767,16 +1014,28 @@ impl Metric {
     /// ```
     pub fn as_str(self) -> &'static str {
         match self {
+            Metric::AllocBytes => "alloc_bytes",
+            Metric::Allocs => "allocs",
             Metric::BinaryBytes => "binary_bytes",
             Metric::CpuMs => "cpu_ms",
             Metric::CtxSwitches => "ctx_switches",
             Metric::EnergyUj => "energy_uj",
+            Metric::ErrorRate => "error_rate",
+            Metric::EstimatedCycles => "estimated_cycles",
+            Metric::Instructions => "instructions",
             Metric::IoReadBytes => "io_read_bytes",
             Metric::IoWriteBytes => "io_write_bytes",
+            Metric::L1Hits => "l1_hits",
+            Metric::LatencyP50Us => "latency_p50_us",
+            Metric::LatencyP95Us => "latency_p95_us",
+            Metric::LatencyP99Us => "latency_p99_us",
+            Metric::LlHits => "ll_hits",
             Metric::MaxRssKb => "max_rss_kb",
             Metric::NetworkPackets => "network_packets",
             Metric::PageFaults => "page_faults",
+            Metric::RamHits => "ram_hits",
             Metric::ThroughputPerS => "throughput_per_s",
+            Metric::TimeToReadyMs => "time_to_ready_ms",
             Metric::WallMs => "wall_ms",
         }
     }
@@ -794,16 +1053,28 @@ impl Metric {
     /// ```
     pub fn parse_key(key: &str) -> Option<Self> {
         match key {
+            "alloc_bytes" => Some(Metric::AllocBytes),
+            "allocs" => Some(Metric::Allocs),
             "binary_bytes" => Some(Metric::BinaryBytes),
             "cpu_ms" => Some(Metric::CpuMs),
             "ctx_switches" => Some(Metric::CtxSwitches),
             "energy_uj" => Some(Metric::EnergyUj),
+            "error_rate" => Some(Metric::ErrorRate),
+            "estimated_cycles" => Some(Metric::EstimatedCycles),
+            "instructions" => Some(Metric::Instructions),
             "io_read_bytes" => Some(Metric::IoReadBytes),
             "io_write_bytes" => Some(Metric::IoWriteBytes),
+            "l1_hits" => Some(Metric::L1Hits),
+            "latency_p50_us" => Some(Metric::LatencyP50Us),
+            "latency_p95_us" => Some(Metric::LatencyP95Us),
+            "latency_p99_us" => Some(Metric::LatencyP99Us),
+            "ll_hits" => Some(Metric::LlHits),
             "max_rss_kb" => Some(Metric::MaxRssKb),
             "network_packets" => Some(Metric::NetworkPackets),
             "page_faults" => Some(Metric::PageFaults),
+            "ram_hits" => Some(Metric::RamHits),
             "throughput_per_s" => Some(Metric::ThroughputPerS),
+            "time_to_ready_ms" => Some(Metric::TimeToReadyMs),
             "wall_ms" => Some(Metric::WallMs),
             _ => None,
         }
@@ -824,16 +1095,28 @@ impl Metric {
     /// ```
     pub fn default_direction(self) -> Direction {
         match self {
+            Metric::AllocBytes => Direction::Lower,
+            Metric::Allocs => Direction::Lower,
             Metric::BinaryBytes => Direction::Lower,
             Metric::CpuMs => Direction::Lower,
             Metric::CtxSwitches => Direction::Lower,
             Metric::EnergyUj => Direction::Lower,
+            Metric::ErrorRate => Direction::Lower,
+            Metric::EstimatedCycles => Direction::Lower,
+            Metric::Instructions => Direction::Lower,
             Metric::IoReadBytes => Direction::Lower,
             Metric::IoWriteBytes => Direction::Lower,
+            Metric::L1Hits => Direction::Lower,
+            Metric::LatencyP50Us => Direction::Lower,
+            Metric::LatencyP95Us => Direction::Lower,
+            Metric::LatencyP99Us => Direction::Lower,
+            Metric::LlHits => Direction::Lower,
             Metric::MaxRssKb => Direction::Lower,
             Metric::NetworkPackets => Direction::Lower,
             Metric::PageFaults => Direction::Lower,
+            Metric::RamHits => Direction::Lower,
             Metric::ThroughputPerS => Direction::Higher,
+            Metric::TimeToReadyMs => Direction::Lower,
             Metric::WallMs => Direction::Lower,
         }
     }
@@ -856,16 +1139,28 @@ impl Metric {
     /// ```
     pub fn display_unit(self) -> &'static str {
         match self {
+            Metric::AllocBytes => "bytes",
+            Metric::Allocs => "count",
             Metric::BinaryBytes => "bytes",
             Metric::CpuMs => "ms",
             Metric::CtxSwitches => "count",
             Metric::EnergyUj => "uj",
+            Metric::ErrorRate => "ratio",
+            Metric::EstimatedCycles => "cycles",
+            Metric::Instructions => "count",
             Metric::IoReadBytes => "bytes",
             Metric::IoWriteBytes => "bytes",
+            Metric::L1Hits => "count",
+            Metric::LatencyP50Us => "us",
+            Metric::LatencyP95Us => "us",
+            Metric::LatencyP99Us => "us",
+            Metric::LlHits => "count",
             Metric::MaxRssKb => "KB",
             Metric::NetworkPackets => "count",
             Metric::PageFaults => "count",
+            Metric::RamHits => "count",
             Metric::ThroughputPerS => "/s",
+            Metric::TimeToReadyMs => "ms",
             Metric::WallMs => "ms",
         }
     }
@@ -930,6 +1225,62 @@ pub struct Budget {
 
     /// Regression direction.
     pub direction: Direction,
+
+    /// How an `"auto"` threshold was derived; absent for fixed thresholds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_threshold: Option<AutoThresholdDerivation>,
+}
+
+/// Where the history behind an `"auto"` threshold came from.
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "snake_case")]
+pub enum AutoThresholdSource {
+    /// Run receipts under `[defaults].baseline_history_dir`.
+    HistoryDir,
+    /// Baselines recorded on the baseline server.
+    Server,
+    /// No history was available.
+    None,
+}
+
+/// Why an `"auto"` threshold fell back to the configured default.
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "snake_case")]
+pub enum AutoThresholdFallback {
+    /// Fewer historical runs than required.
+    InsufficientHistory,
+    /// The metric's historical mean was not positive.
+    NonPositiveMean,
+}
+
+/// Derivation of a budget threshold configured as `threshold = "auto"`.
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct AutoThresholdDerivation {
+    /// Where the history came from.
+    pub source: AutoThresholdSource,
+
+    /// Number of historical runs that contributed a value.
+    pub runs: u32,
+
+    /// Run-to-run coefficient of variation of the metric across history.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cv: Option<f64>,
+
+    /// Multiplier applied to `cv` (threshold = sigma * cv before bounding).
+    pub sigma: f64,
+
+    /// Lower bound applied to the derived threshold.
+    pub min: f64,
+
+    /// Upper bound applied to the derived threshold.
+    pub max: f64,
+
+    /// Why the threshold fell back to the configured default, if it did.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fallback: Option<AutoThresholdFallback>,
 }
 
 fn is_default_noise_policy(policy: &NoisePolicy) -> bool {
@@ -945,6 +1296,7 @@ impl Budget {
             noise_threshold: None,
             noise_policy: NoisePolicy::Ignore,
             direction,
+            auto_threshold: None,
         }
     }
 }
@@ -969,6 +1321,10 @@ pub struct Significance {
     pub ci_lower: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ci_upper: Option<f64>,
+    /// Benjamini–Hochberg adjusted p-value when a suite-wide false discovery
+    /// rate correction was applied; `significant` then reflects this value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub adjusted_p_value: Option<f64>,
 }
 
 /// Policy for statistical significance testing.
@@ -1076,6 +1432,10 @@ pub struct CompareRef {
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub run_id: Option<String>,
+
+    /// Config profile the referenced run was recorded under.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub profile: Option<String>,
 }
 
 #[derive(Debug, Copy, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
@@ -1152,10 +1512,10 @@ pub struct Verdict {
 ///     bench: BenchMeta {
 ///         name: "my-bench".into(), cwd: None,
 ///         command: vec!["echo".into()], repeat: 5, warmup: 0,
-///         work_units: None, timeout_ms: None,
+///         work_units: None, timeout_ms: None, service: None, load: None,
 ///     },
-///     baseline_ref: CompareRef { path: Some("base.json".into()), run_id: None },
-///     current_ref: CompareRef { path: Some("cur.json".into()), run_id: None },
+///     baseline_ref: CompareRef { path: Some("base.json".into()), run_id: None, profile: None },
+///     current_ref: CompareRef { path: Some("cur.json".into()), run_id: None, profile: None },
 ///     budgets: BTreeMap::new(),
 ///     deltas: BTreeMap::new(),
 ///     verdict: Verdict {
@@ -1304,6 +1664,107 @@ pub struct ComplexityGateResult {
     pub message: String,
 }
 
+/// How a flagged regression was re-measured before the verdict was finalized.
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "snake_case")]
+pub enum ConfirmationMode {
+    /// The bench was run again and compared against the same baseline.
+    Rerun,
+    /// The bench was run interleaved with a baseline command (wall time only).
+    Paired,
+}
+
+impl ConfirmationMode {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ConfirmationMode::Rerun => "rerun",
+            ConfirmationMode::Paired => "paired",
+        }
+    }
+}
+
+/// Status of one flagged metric before and after confirmation.
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct ConfirmationMetric {
+    /// Status from the original measurement.
+    pub original: MetricStatus,
+    /// Status from the confirmation measurement, or `None` when the
+    /// confirmation did not re-measure the metric (a paired confirmation only
+    /// measures wall time).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confirmation: Option<MetricStatus>,
+}
+
+/// Result of re-measuring a bench whose first check warned or failed.
+///
+/// A metric keeps the less severe of its original and confirmation statuses,
+/// so a regression only stands when the confirmation run reproduces it.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct Confirmation {
+    pub mode: ConfirmationMode,
+
+    /// True when the confirmation re-measured at least one flagged metric and
+    /// it still warned or failed.
+    pub confirmed: bool,
+
+    /// Verdict status of the original measurement.
+    pub original_status: VerdictStatus,
+
+    /// Flagged metrics of the original measurement.
+    pub metrics: BTreeMap<Metric, ConfirmationMetric>,
+
+    /// Confirmation artifacts, relative to the bench output directory.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub artifacts: Vec<String>,
+}
+
+/// Baseline commit chosen by merge-base aware selection (`check --merge-base`).
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct MergeBaseSelection {
+    /// Target branch the merge-base was computed against (e.g. `origin/main`).
+    pub target: String,
+
+    /// Merge-base of `HEAD` and the target branch.
+    pub merge_base: String,
+
+    /// Commit whose recorded baseline was selected.
+    pub selected_sha: String,
+
+    /// Number of first-parent commits between the merge-base and the selected commit.
+    pub distance: usize,
+}
+
+impl MergeBaseSelection {
+    /// Human-readable summary of which commit was chosen and why.
+    pub fn describe(&self) -> String {
+        let merge_base = short_sha(&self.merge_base);
+        let selected = short_sha(&self.selected_sha);
+        if self.distance == 0 {
+            format!(
+                "baseline from merge-base {} of HEAD and {}",
+                merge_base, self.target
+            )
+        } else {
+            format!(
+                "baseline from {} ({} commit{} before merge-base {} of HEAD and {})",
+                selected,
+                self.distance,
+                if self.distance == 1 { "" } else { "s" },
+                merge_base,
+                self.target
+            )
+        }
+    }
+}
+
+fn short_sha(sha: &str) -> &str {
+    sha.get(..12).unwrap_or(sha)
+}
+
 /// A performance report wrapping compare results in a cockpit-compatible envelope.
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
@@ -1332,6 +1793,18 @@ pub struct PerfgateReport {
     /// Path to a flamegraph SVG captured when regression was detected.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub profile_path: Option<String>,
+
+    /// Confirmation re-measurement of a warn/fail verdict (`check --confirm`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confirmation: Option<Confirmation>,
+
+    /// Why the bench was not run, for benches skipped by `check --changed-since`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub skip_reason: Option<String>,
+
+    /// Commit whose recorded baseline was compared against (`check --merge-base`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub baseline_selection: Option<MergeBaseSelection>,
 }
 
 // ----------------------------
@@ -1363,6 +1836,14 @@ pub struct PerfgateReport {
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Default)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ConfigFile {
+    /// Config files and bundled presets this file extends, lowest precedence
+    /// first (e.g. `"preset:standard"` or `"../shared/perfgate.base.toml"`).
+    ///
+    /// Resolved by [`config::resolve_config_file`]; see `docs/CONFIG.md` for
+    /// the merge semantics.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extends: Vec<String>,
+
     #[serde(default)]
     pub defaults: DefaultsConfig,
 
@@ -1390,9 +1871,69 @@ pub struct ConfigFile {
     /// compensating improvements are present.
     #[serde(default, rename = "tradeoff")]
     pub tradeoffs: Vec<TradeoffRule>,
+
+    /// Named profiles (`[profile.ci]`, `[profile.local]`, ...) layered over
+    /// `defaults` and benches when active.
+    ///
+    /// Applied by [`config::resolve_config_file`]; see `docs/CONFIG.md` for
+    /// how the active profile is chosen.
+    #[serde(
+        default,
+        rename = "profile",
+        skip_serializing_if = "BTreeMap::is_empty"
+    )]
+    pub profiles: BTreeMap<String, ConfigProfile>,
+}
+
+/// One `[profile.<name>]` table: overrides for `defaults` and for benches.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct ConfigProfile {
+    /// Values merged over `[defaults]`.
+    #[serde(default)]
+    pub defaults: DefaultsConfig,
+
+    /// Per-bench overrides, matched to `[[bench]]` entries by `name`.
+    #[serde(default, rename = "bench", skip_serializing_if = "Vec::is_empty")]
+    pub benches: Vec<BenchProfileOverride>,
+}
+
+/// A `[[profile.<name>.bench]]` entry.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct BenchProfileOverride {
+    /// Name of the bench being overridden (before matrix expansion).
+    pub name: String,
+
+    #[serde(flatten)]
+    pub settings: BenchOverride,
 }
 
 impl ConfigFile {
+    /// Replace every bench that has a `matrix` with its expansions, in place.
+    ///
+    /// Fails when an expansion is malformed or its name collides with another
+    /// bench.
+    pub fn expand_matrices(&mut self) -> Result<(), String> {
+        if self.benches.iter().all(|bench| bench.matrix.is_none()) {
+            return Ok(());
+        }
+        let mut expanded: Vec<BenchConfigFile> = Vec::with_capacity(self.benches.len());
+        for bench in &self.benches {
+            for bench in bench.expand_matrix()? {
+                if expanded.iter().any(|other| other.name == bench.name) {
+                    return Err(format!(
+                        "matrix expansion '{}' collides with another bench of the same name",
+                        bench.name
+                    ));
+                }
+                expanded.push(bench);
+            }
+        }
+        self.benches = expanded;
+        Ok(())
+    }
+
     /// Validate all bench names in this config. Returns an error if any name is invalid.
     ///
     /// # Examples
@@ -1415,8 +1956,84 @@ impl ConfigFile {
     /// assert!(bad.validate().is_err());
     /// ```
     pub fn validate(&self) -> Result<(), String> {
+        let mut benches = Vec::with_capacity(self.benches.len());
         for bench in &self.benches {
+            benches.extend(bench.expand_matrix()?);
+        }
+        for bench in &benches {
             validate_bench_name(&bench.name).map_err(|e| e.to_string())?;
+            if let Some(tag) = bench
+                .tags
+                .iter()
+                .find(|tag| tag.is_empty() || tag.chars().any(char::is_whitespace))
+            {
+                return Err(format!(
+                    "bench '{}' tag '{}' must be non-empty and contain no whitespace",
+                    bench.name, tag
+                ));
+            }
+            if bench.terminate_on_ready && bench.ready.is_none() {
+                return Err(format!(
+                    "bench '{}' sets terminate_on_ready without a ready probe",
+                    bench.name
+                ));
+            }
+            if let Some(load) = &bench.load {
+                if bench.ready.is_some() {
+                    return Err(format!(
+                        "bench '{}' load benches do not support a ready probe",
+                        bench.name
+                    ));
+                }
+                if !bench.command.is_empty() {
+                    return Err(format!(
+                        "bench '{}' sets both command and load; a load bench has no command",
+                        bench.name
+                    ));
+                }
+                if load.duration.is_none() && load.requests.is_none() {
+                    return Err(format!(
+                        "bench '{}' load needs a duration or a request count",
+                        bench.name
+                    ));
+                }
+                if bench.scaling.is_some() {
+                    return Err(format!(
+                        "bench '{}' load benches do not support scaling",
+                        bench.name
+                    ));
+                }
+                if load.concurrency == Some(0) || load.rate == Some(0) {
+                    return Err(format!(
+                        "bench '{}' load concurrency and rate must be positive",
+                        bench.name
+                    ));
+                }
+            }
+            for (metric, budget) in bench.budgets.iter().flatten() {
+                for (field, value) in [
+                    ("threshold_min", budget.threshold_min),
+                    ("threshold_max", budget.threshold_max),
+                ] {
+                    if value.is_some_and(|v| !v.is_finite() || v < 0.0) {
+                        return Err(format!(
+                            "bench '{}' budget {}: {} must be a non-negative finite number",
+                            bench.name,
+                            metric.as_str(),
+                            field
+                        ));
+                    }
+                }
+                if let (Some(min), Some(max)) = (budget.threshold_min, budget.threshold_max)
+                    && min > max
+                {
+                    return Err(format!(
+                        "bench '{}' budget {}: threshold_min must not exceed threshold_max",
+                        bench.name,
+                        metric.as_str()
+                    ));
+                }
+            }
         }
         if self.decision_policy.require_low_noise_for_acceptance
             && self.decision_policy.max_cv.is_none()
@@ -1447,11 +2064,7 @@ impl ConfigFile {
                     scenario.name
                 ));
             }
-            if !self
-                .benches
-                .iter()
-                .any(|bench| bench.name == scenario.bench)
-            {
+            if !benches.iter().any(|bench| bench.name == scenario.bench) {
                 return Err(format!(
                     "scenario '{}' references unknown benchmark '{}'",
                     scenario.name, scenario.bench
@@ -1634,39 +2247,544 @@ impl BaselineServerConfig {
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
-pub struct BenchConfigFile {
-    pub name: String,
+pub struct BenchConfigFile {
+    pub name: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cwd: Option<String>,
+
+    /// Work units per iteration; may be a `"{key}"` template when `matrix` is set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub work: Option<BenchWork>,
+
+    /// Duration string parseable by humantime, e.g. "2s".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<String>,
+
+    /// argv vector (no shell parsing). Omitted for HTTP load benches.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub command: Vec<String>,
+
+    /// Labels used to select groups of benches with `--tag` and
+    /// `--exclude-tag`, e.g. `["fast", "memory"]`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+
+    /// Glob patterns, relative to the repository root, of files this bench
+    /// depends on (e.g. `["crates/parser/**"]`). `check --changed-since`
+    /// skips the bench when no changed file matches.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub paths: Vec<String>,
+
+    /// Run this bench under `check --changed-since` even when none of its
+    /// `paths` changed.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub always: bool,
+
+    /// Number of measured samples (overrides defaults.repeat).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub repeat: Option<u32>,
+
+    /// Warmup samples excluded from stats (overrides defaults.warmup).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub warmup: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metrics: Option<Vec<Metric>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub budgets: Option<BTreeMap<Metric, BudgetOverride>>,
+
+    /// Optional scaling validation configuration.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub scaling: Option<ScalingConfig>,
+
+    /// Expands this entry into one bench per combination of values.
+    ///
+    /// `{key}` placeholders in `name`, `command` and `work` are replaced by
+    /// each combination's values, e.g. `name = "parse_{size}_{threads}"`.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub matrix: Option<BTreeMap<String, Vec<MatrixValue>>>,
+
+    /// Settings for individual matrix expansions, keyed by expanded bench name.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub matrix_overrides: Option<BTreeMap<String, BenchOverride>>,
+
+    /// Makes this a service bench: the server is started once and kept
+    /// running while `command` drives a client workload against it per sample.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub service: Option<ServiceConfig>,
+
+    /// Makes this an HTTP load bench: each sample drives requests at a URL
+    /// with the built-in load generator instead of running `command`.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub load: Option<HttpLoadConfig>,
+
+    /// Probe that records `time_to_ready_ms`: the time from spawning
+    /// `command` until it passes.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub ready: Option<ReadyCheck>,
+
+    /// How long to wait for the ready probe, e.g. "10s" (default 30s).
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub ready_timeout: Option<String>,
+
+    /// Stop the command with SIGTERM once the ready probe passes, for
+    /// commands such as servers that never exit on their own.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub terminate_on_ready: bool,
+}
+
+/// Long-running server of a service bench (`[bench.service]`).
+///
+/// # Examples
+///
+/// ```
+/// use perfgate_types::{ConfigFile, ReadyCheck};
+///
+/// let config: ConfigFile = toml::from_str(r#"
+/// [[bench]]
+/// name = "api"
+/// command = ["curl", "-s", "http://127.0.0.1:8080/items"]
+///
+/// [bench.service]
+/// command = ["./target/release/server", "--port", "8080"]
+/// ready = { http = "http://127.0.0.1:8080/health" }
+/// "#).unwrap();
+///
+/// let service = config.benches[0].service.as_ref().unwrap();
+/// assert_eq!(
+///     service.ready,
+///     ReadyCheck::Http("http://127.0.0.1:8080/health".to_string())
+/// );
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct ServiceConfig {
+    /// argv vector of the server (no shell parsing).
+    pub command: Vec<String>,
+
+    /// Working directory of the server (defaults to the bench's `cwd`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cwd: Option<String>,
+
+    /// How to tell that the server is ready to take the workload.
+    pub ready: ReadyCheck,
+
+    /// How long to wait for readiness, e.g. "10s" (default 30s).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ready_timeout: Option<String>,
+
+    /// How long the server may take to exit after SIGTERM before it is
+    /// killed, e.g. "2s" (default 5s).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shutdown_timeout: Option<String>,
+}
+
+/// Readiness check of a service bench's server or a bench's command.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "snake_case")]
+pub enum ReadyCheck {
+    /// A TCP connection to `host:port` succeeds.
+    Tcp(String),
+
+    /// An HTTP GET of the URL returns status 200 (plain `http://` only).
+    Http(String),
+
+    /// A line of the process's stdout matches the regex.
+    Stdout(String),
+
+    /// A line of the process's stderr matches the regex.
+    Stderr(String),
+
+    /// The file exists (relative paths resolve against the working directory).
+    /// A file left from an earlier run is removed before the process starts.
+    File(String),
+}
+
+/// Built-in HTTP load generator of a load bench (`[bench.load]`).
+///
+/// Without `rate` the load is closed-loop: `concurrency` connections send
+/// requests back to back. With `rate` it is open-loop: requests are issued on
+/// a fixed schedule and latency is measured from each scheduled send time.
+///
+/// # Examples
+///
+/// ```
+/// use perfgate_types::ConfigFile;
+///
+/// let config: ConfigFile = toml::from_str(r#"
+/// [[bench]]
+/// name = "items"
+///
+/// [bench.load]
+/// url = "http://127.0.0.1:8080/items"
+/// concurrency = 8
+/// duration = "5s"
+/// "#).unwrap();
+///
+/// let load = config.benches[0].load.as_ref().unwrap();
+/// assert_eq!(load.concurrency, Some(8));
+/// assert!(config.validate().is_ok());
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct HttpLoadConfig {
+    /// Target URL (plain `http://` only).
+    pub url: String,
+
+    /// Request method (default GET).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub method: Option<String>,
+
+    /// Request body sent with every request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+
+    /// Extra request headers.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub headers: BTreeMap<String, String>,
+
+    /// Number of concurrent connections (default 1).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub concurrency: Option<u32>,
+
+    /// How long each sample sends requests, e.g. "10s".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<String>,
+
+    /// Number of requests per sample. With `duration`, whichever is reached
+    /// first ends the sample.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requests: Option<u64>,
+
+    /// Target request rate per second; switches to open-loop load.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rate: Option<u64>,
+
+    /// Per-request timeout, e.g. "2s" (default 10s). Timed out requests
+    /// count as errors.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<String>,
+}
+
+/// Tag-based bench selection from `--tag` and `--exclude-tag`.
+///
+/// A bench is selected when it carries any `include` tag (or `include` is
+/// empty) and none of the `exclude` tags.
+///
+/// # Examples
+///
+/// ```
+/// use perfgate_types::{BenchTagFilter, ConfigFile};
+///
+/// let config: ConfigFile = toml::from_str(r#"
+/// [[bench]]
+/// name = "parse"
+/// command = ["parse"]
+/// tags = ["fast"]
+///
+/// [[bench]]
+/// name = "soak"
+/// command = ["soak"]
+/// tags = ["nightly"]
+/// "#).unwrap();
+///
+/// let filter = BenchTagFilter {
+///     include: vec![],
+///     exclude: vec!["nightly".to_string()],
+/// };
+/// let names: Vec<&str> = config
+///     .benches
+///     .iter()
+///     .filter(|bench| filter.matches(bench))
+///     .map(|bench| bench.name.as_str())
+///     .collect();
+/// assert_eq!(names, ["parse"]);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BenchTagFilter {
+    pub include: Vec<String>,
+    pub exclude: Vec<String>,
+}
+
+impl BenchTagFilter {
+    /// True when neither `--tag` nor `--exclude-tag` was given.
+    pub fn is_empty(&self) -> bool {
+        self.include.is_empty() && self.exclude.is_empty()
+    }
+
+    pub fn matches(&self, bench: &BenchConfigFile) -> bool {
+        let has = |tag: &String| bench.tags.contains(tag);
+        (self.include.is_empty() || self.include.iter().any(has)) && !self.exclude.iter().any(has)
+    }
+
+    /// Human-readable description for error messages, e.g.
+    /// `--tag fast --exclude-tag memory`.
+    pub fn describe(&self) -> String {
+        self.include
+            .iter()
+            .map(|tag| format!("--tag {}", tag))
+            .chain(
+                self.exclude
+                    .iter()
+                    .map(|tag| format!("--exclude-tag {}", tag)),
+            )
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+/// Work units per iteration of a configured bench.
+///
+/// Either a count (`work = 1000`) or, in a bench with a `matrix`, a template
+/// such as `work = "{size}"` that must expand to a count.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(untagged)]
+pub enum BenchWork {
+    Units(u64),
+    Template(String),
+}
+
+impl BenchWork {
+    /// The work unit count, or `None` for an unexpanded template.
+    pub fn units(&self) -> Option<u64> {
+        match self {
+            BenchWork::Units(units) => Some(*units),
+            BenchWork::Template(_) => None,
+        }
+    }
+}
+
+impl From<u64> for BenchWork {
+    fn from(units: u64) -> Self {
+        BenchWork::Units(units)
+    }
+}
+
+/// One value of a bench `matrix` key.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(untagged)]
+pub enum MatrixValue {
+    Integer(i64),
+    Float(f64),
+    String(String),
+}
 
-    #[serde(skip_serializing_if = "Option::is_none")]
+impl std::fmt::Display for MatrixValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MatrixValue::Integer(value) => write!(f, "{}", value),
+            MatrixValue::Float(value) => write!(f, "{}", value),
+            MatrixValue::String(value) => f.write_str(value),
+        }
+    }
+}
+
+/// Settings that replace a bench's own for one matrix expansion or config
+/// profile.
+///
+/// Matrix overrides merge budgets per metric over the bench's `budgets`;
+/// profiles merge them key by key, like `extends`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct BenchOverride {
+    #[serde(skip_serializing_if = "Option::is_none", default)]
     pub cwd: Option<String>,
 
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "Option::is_none", default)]
     pub work: Option<u64>,
 
-    /// Duration string parseable by humantime, e.g. "2s".
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "Option::is_none", default)]
     pub timeout: Option<String>,
 
-    /// argv vector (no shell parsing).
-    pub command: Vec<String>,
-
-    /// Number of measured samples (overrides defaults.repeat).
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "Option::is_none", default)]
     pub repeat: Option<u32>,
 
-    /// Warmup samples excluded from stats (overrides defaults.warmup).
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "Option::is_none", default)]
     pub warmup: Option<u32>,
 
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "Option::is_none", default)]
     pub metrics: Option<Vec<Metric>>,
 
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "Option::is_none", default)]
     pub budgets: Option<BTreeMap<Metric, BudgetOverride>>,
+}
 
-    /// Optional scaling validation configuration.
-    #[serde(skip_serializing_if = "Option::is_none", default)]
-    pub scaling: Option<ScalingConfig>,
+impl BenchConfigFile {
+    /// Expand this bench's `matrix` into concrete benches, one per combination
+    /// of values (the last key varies fastest; keys are in sorted order).
+    ///
+    /// A bench without a matrix expands to itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use perfgate_types::ConfigFile;
+    ///
+    /// let config: ConfigFile = toml::from_str(r#"
+    /// [[bench]]
+    /// name = "parse_{size}_{threads}"
+    /// command = ["parse", "--input", "data/{size}.json", "--threads", "{threads}"]
+    ///
+    /// [bench.matrix]
+    /// size = ["small", "large"]
+    /// threads = [1, 4]
+    /// "#).unwrap();
+    ///
+    /// let names: Vec<String> = config.benches[0]
+    ///     .expand_matrix()
+    ///     .unwrap()
+    ///     .into_iter()
+    ///     .map(|bench| bench.name)
+    ///     .collect();
+    /// assert_eq!(
+    ///     names,
+    ///     ["parse_small_1", "parse_small_4", "parse_large_1", "parse_large_4"]
+    /// );
+    /// ```
+    pub fn expand_matrix(&self) -> Result<Vec<BenchConfigFile>, String> {
+        let Some(matrix) = &self.matrix else {
+            if let Some(BenchWork::Template(template)) = &self.work {
+                return Err(format!(
+                    "bench '{}' work '{}' must be an integer unless it is templated by a matrix",
+                    self.name, template
+                ));
+            }
+            if self.matrix_overrides.is_some() {
+                return Err(format!(
+                    "bench '{}' has matrix_overrides but no matrix",
+                    self.name
+                ));
+            }
+            return Ok(vec![self.clone()]);
+        };
+        if matrix.is_empty() {
+            return Err(format!("bench '{}' matrix must not be empty", self.name));
+        }
+        if let Some((key, _)) = matrix.iter().find(|(_, values)| values.is_empty()) {
+            return Err(format!(
+                "bench '{}' matrix key '{}' must have at least one value",
+                self.name, key
+            ));
+        }
+
+        let mut combinations: Vec<Vec<(&str, String)>> = vec![Vec::new()];
+        for (key, values) in matrix {
+            combinations = combinations
+                .into_iter()
+                .flat_map(|combination| {
+                    values.iter().map(move |value| {
+                        let mut next = combination.clone();
+                        next.push((key.as_str(), value.to_string()));
+                        next
+                    })
+                })
+                .collect();
+        }
+
+        let mut overrides = self.matrix_overrides.clone().unwrap_or_default();
+        let mut expanded: Vec<BenchConfigFile> = Vec::with_capacity(combinations.len());
+        for combination in &combinations {
+            let name = render_matrix_template(&self.name, combination);
+            if expanded.iter().any(|bench| bench.name == name) {
+                return Err(format!(
+                    "bench '{}' matrix expands to duplicate name '{}'; use every matrix key in the name",
+                    self.name, name
+                ));
+            }
+            let work = match &self.work {
+                Some(BenchWork::Template(template)) => {
+                    let rendered = render_matrix_template(template, combination);
+                    let units = rendered.parse::<u64>().map_err(|_| {
+                        format!(
+                            "bench '{}' work '{}' expands to '{}', which is not an integer",
+                            name, template, rendered
+                        )
+                    })?;
+                    Some(BenchWork::Units(units))
+                }
+                work => work.clone(),
+            };
+            let mut bench = BenchConfigFile {
+                name,
+                command: self
+                    .command
+                    .iter()
+                    .map(|arg| render_matrix_template(arg, combination))
+                    .collect(),
+                paths: self
+                    .paths
+                    .iter()
+                    .map(|pattern| render_matrix_template(pattern, combination))
+                    .collect(),
+                work,
+                matrix: None,
+                matrix_overrides: None,
+                service: self.service.as_ref().map(|service| ServiceConfig {
+                    command: service
+                        .command
+                        .iter()
+                        .map(|arg| render_matrix_template(arg, combination))
+                        .collect(),
+                    ..service.clone()
+                }),
+                load: self.load.as_ref().map(|load| HttpLoadConfig {
+                    url: render_matrix_template(&load.url, combination),
+                    ..load.clone()
+                }),
+                ..self.clone()
+            };
+            if let Some(matrix_override) = overrides.remove(&bench.name) {
+                bench.apply_matrix_override(matrix_override);
+            }
+            expanded.push(bench);
+        }
+
+        if let Some(unused) = overrides.keys().next() {
+            return Err(format!(
+                "bench '{}' matrix_overrides entry '{}' does not match any expansion",
+                self.name, unused
+            ));
+        }
+        Ok(expanded)
+    }
+
+    fn apply_matrix_override(&mut self, matrix_override: BenchOverride) {
+        let BenchOverride {
+            cwd,
+            work,
+            timeout,
+            repeat,
+            warmup,
+            metrics,
+            budgets,
+        } = matrix_override;
+        self.cwd = cwd.or(self.cwd.take());
+        self.work = work.map(BenchWork::Units).or(self.work.take());
+        self.timeout = timeout.or(self.timeout.take());
+        self.repeat = repeat.or(self.repeat);
+        self.warmup = warmup.or(self.warmup);
+        self.metrics = metrics.or(self.metrics.take());
+        if let Some(budgets) = budgets {
+            self.budgets
+                .get_or_insert_with(BTreeMap::new)
+                .extend(budgets);
+        }
+    }
+}
+
+/// Replace `{key}` placeholders with the combination's values. Braces that do
+/// not name a matrix key are left as they are.
+fn render_matrix_template(template: &str, combination: &[(&str, String)]) -> String {
+    combination
+        .iter()
+        .fold(template.to_string(), |rendered, (key, value)| {
+            rendered.replace(&format!("{{{}}}", key), value)
+        })
 }
 
 /// Weighted scenario definition for workload-level evaluation.
@@ -1812,11 +2930,60 @@ pub struct ScalingConfig {
     pub r_squared_threshold: Option<f64>,
 }
 
+/// Fail threshold of a per-metric budget override.
+///
+/// Either a fixed fraction (`threshold = 0.15`) or `threshold = "auto"`, which
+/// derives the threshold at compare time from the bench's history.
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(untagged)]
+pub enum BudgetThreshold {
+    Fixed(f64),
+    Auto(AutoThresholdKeyword),
+}
+
+/// The literal `"auto"` accepted by [`BudgetThreshold`].
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename_all = "snake_case")]
+pub enum AutoThresholdKeyword {
+    Auto,
+}
+
+impl BudgetThreshold {
+    /// The fixed threshold, or `None` for `"auto"`.
+    pub fn fixed(self) -> Option<f64> {
+        match self {
+            BudgetThreshold::Fixed(value) => Some(value),
+            BudgetThreshold::Auto(_) => None,
+        }
+    }
+
+    pub fn is_auto(self) -> bool {
+        matches!(self, BudgetThreshold::Auto(_))
+    }
+}
+
+impl From<f64> for BudgetThreshold {
+    fn from(value: f64) -> Self {
+        BudgetThreshold::Fixed(value)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Default)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct BudgetOverride {
+    /// Fail threshold as a fraction, or `"auto"` to derive it from history.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub threshold: Option<f64>,
+    pub threshold: Option<BudgetThreshold>,
+
+    /// Lower bound for an `"auto"` threshold (default: 0.02).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub threshold_min: Option<f64>,
+
+    /// Upper bound for an `"auto"` threshold (default: 0.50).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub threshold_max: Option<f64>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub direction: Option<Direction>,
@@ -2125,7 +3292,19 @@ mod tests {
                 budgets: None,
 
                 scaling: None,
+                matrix: None,
+                matrix_overrides: None,
+                service: None,
+                tags: Vec::new(),
+                paths: Vec::new(),
+                always: false,
+                load: None,
+                ready: None,
+                ready_timeout: None,
+                terminate_on_ready: false,
             }],
+            extends: Vec::new(),
+            profiles: Default::default(),
         };
         assert!(config.validate().is_err());
     }
@@ -2218,7 +3397,19 @@ mod tests {
                 budgets: None,
 
                 scaling: None,
+                matrix: None,
+                matrix_overrides: None,
+                service: None,
+                tags: Vec::new(),
+                paths: Vec::new(),
+                always: false,
+                load: None,
+                ready: None,
+                ready_timeout: None,
+                terminate_on_ready: false,
             }],
+            extends: Vec::new(),
+            profiles: Default::default(),
         };
         assert!(config.validate().is_ok());
     }
@@ -2249,7 +3440,19 @@ mod tests {
                 metrics: None,
                 budgets: None,
                 scaling: None,
+                matrix: None,
+                matrix_overrides: None,
+                service: None,
+                tags: Vec::new(),
+                paths: Vec::new(),
+                always: false,
+                load: None,
+                ready: None,
+                ready_timeout: None,
+                terminate_on_ready: false,
             }],
+            extends: Vec::new(),
+            profiles: Default::default(),
         };
 
         assert!(config.validate().is_err());
@@ -2285,7 +3488,19 @@ mod tests {
                 metrics: None,
                 budgets: None,
                 scaling: None,
+                matrix: None,
+                matrix_overrides: None,
+                service: None,
+                tags: Vec::new(),
+                paths: Vec::new(),
+                always: false,
+                load: None,
+                ready: None,
+                ready_timeout: None,
+                terminate_on_ready: false,
             }],
+            extends: Vec::new(),
+            profiles: Default::default(),
         };
         assert!(config.validate().is_ok());
 
@@ -2360,6 +3575,75 @@ require_low_noise_for_acceptance = true
         assert!(config.validate().is_ok());
     }
 
+    #[test]
+    fn config_file_validate_rejects_invalid_load_benches() {
+        let mut config: ConfigFile = toml::from_str(
+            r#"
+[[bench]]
+name = "items"
+command = ["curl", "http://127.0.0.1:8080/items"]
+
+[bench.load]
+url = "http://127.0.0.1:8080/items"
+"#,
+        )
+        .expect("parse config");
+
+        assert!(
+            config
+                .validate()
+                .unwrap_err()
+                .contains("sets both command and load")
+        );
+
+        config.benches[0].command.clear();
+        assert!(
+            config
+                .validate()
+                .unwrap_err()
+                .contains("needs a duration or a request count")
+        );
+
+        let load = config.benches[0].load.as_mut().unwrap();
+        load.requests = Some(100);
+        load.rate = Some(0);
+        assert!(config.validate().unwrap_err().contains("must be positive"));
+
+        config.benches[0].load.as_mut().unwrap().rate = Some(50);
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn config_file_parses_and_validates_ready_probes() {
+        let mut config: ConfigFile = toml::from_str(
+            r#"
+[[bench]]
+name = "server_startup"
+command = ["./server"]
+ready = { stderr = "listening on \\d+" }
+ready_timeout = "5s"
+terminate_on_ready = true
+"#,
+        )
+        .expect("parse config");
+
+        let bench = &config.benches[0];
+        assert_eq!(
+            bench.ready,
+            Some(ReadyCheck::Stderr("listening on \\d+".to_string()))
+        );
+        assert!(bench.terminate_on_ready);
+        assert!(config.validate().is_ok());
+
+        config.benches[0].ready = None;
+        assert!(
+            config
+                .validate()
+                .unwrap_err()
+                .contains("terminate_on_ready without a ready probe")
+        );
+    }
+
     #[test]
     fn config_file_parses_decision_policy() {
         let config: ConfigFile = toml::from_str(
@@ -2381,6 +3665,232 @@ missing_noise = "accept"
         assert!(config.validate().is_ok());
     }
 
+    #[test]
+    fn config_file_parses_auto_thresholds() {
+        let mut config: ConfigFile = toml::from_str(
+            r#"
+[[bench]]
+name = "parse"
+command = ["echo"]
+
+[bench.budgets.wall_ms]
+threshold = "auto"
+threshold_min = 0.05
+threshold_max = 0.30
+
+[bench.budgets.max_rss_kb]
+threshold = 0.15
+"#,
+        )
+        .expect("parse config");
+
+        let budgets = config.benches[0].budgets.as_ref().expect("budgets");
+        let wall = &budgets[&Metric::WallMs];
+        assert_eq!(
+            wall.threshold,
+            Some(BudgetThreshold::Auto(AutoThresholdKeyword::Auto))
+        );
+        assert_eq!(wall.threshold_min, Some(0.05));
+        assert_eq!(wall.threshold_max, Some(0.30));
+        assert_eq!(
+            budgets[&Metric::MaxRssKb].threshold,
+            Some(BudgetThreshold::Fixed(0.15))
+        );
+        assert!(config.validate().is_ok());
+
+        let wall = config.benches[0]
+            .budgets
+            .as_mut()
+            .and_then(|budgets| budgets.get_mut(&Metric::WallMs))
+            .expect("wall budget");
+        wall.threshold_min = Some(0.40);
+        let err = config.validate().unwrap_err();
+        assert!(err.contains("threshold_min must not exceed threshold_max"));
+    }
+
+    #[test]
+    fn bench_tag_filter_selects_by_include_and_exclude() {
+        let config: ConfigFile = toml::from_str(
+            r#"
+[[bench]]
+name = "parse"
+command = ["parse"]
+tags = ["fast"]
+
+[[bench]]
+name = "alloc"
+command = ["alloc"]
+tags = ["fast", "memory"]
+
+[[bench]]
+name = "untagged"
+command = ["untagged"]
+"#,
+        )
+        .expect("parse config");
+        let selected = |include: &[&str], exclude: &[&str]| {
+            let filter = BenchTagFilter {
+                include: include.iter().map(|tag| tag.to_string()).collect(),
+                exclude: exclude.iter().map(|tag| tag.to_string()).collect(),
+            };
+            config
+                .benches
+                .iter()
+                .filter(|bench| filter.matches(bench))
+                .map(|bench| bench.name.as_str())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(selected(&[], &[]), ["parse", "alloc", "untagged"]);
+        assert_eq!(selected(&["fast"], &[]), ["parse", "alloc"]);
+        assert_eq!(selected(&["fast"], &["memory"]), ["parse"]);
+        assert_eq!(selected(&[], &["fast"]), ["untagged"]);
+        assert_eq!(selected(&["memory", "nightly"], &[]), ["alloc"]);
+
+        let bad: ConfigFile = toml::from_str(
+            r#"
+[[bench]]
+name = "parse"
+command = ["parse"]
+tags = ["two words"]
+"#,
+        )
+        .expect("parse config");
+        assert!(bad.validate().unwrap_err().contains("tag 'two words'"));
+    }
+
+    #[test]
+    fn bench_matrix_expands_templates_and_overrides() {
+        let mut config: ConfigFile = toml::from_str(
+            r#"
+[[bench]]
+name = "parse_{size}_{threads}"
+command = ["parse", "--bytes={size}", "--threads", "{threads}", "{unrelated}"]
+work = "{size}"
+repeat = 5
+
+[bench.matrix]
+size = [1024, 65536]
+threads = [1, 8]
+
+[bench.budgets.wall_ms]
+threshold = 0.10
+
+[bench.matrix_overrides.parse_65536_8]
+repeat = 9
+
+[bench.matrix_overrides.parse_65536_8.budgets.wall_ms]
+threshold = 0.25
+
+[[bench]]
+name = "render"
+command = ["render"]
+work = 3
+"#,
+        )
+        .expect("parse config");
+        assert!(config.validate().is_ok());
+
+        config.expand_matrices().expect("expand");
+        let names: Vec<&str> = config.benches.iter().map(|b| b.name.as_str()).collect();
+        assert_eq!(
+            names,
+            [
+                "parse_1024_1",
+                "parse_1024_8",
+                "parse_65536_1",
+                "parse_65536_8",
+                "render"
+            ]
+        );
+
+        let first = &config.benches[0];
+        assert_eq!(
+            first.command,
+            ["parse", "--bytes=1024", "--threads", "1", "{unrelated}"]
+        );
+        assert_eq!(first.work, Some(BenchWork::Units(1024)));
+        assert_eq!(first.repeat, Some(5));
+        assert!(first.matrix.is_none());
+        assert_eq!(
+            first.budgets.as_ref().expect("budgets")[&Metric::WallMs].threshold,
+            Some(BudgetThreshold::Fixed(0.10))
+        );
+
+        let overridden = &config.benches[3];
+        assert_eq!(overridden.repeat, Some(9));
+        assert_eq!(
+            overridden.budgets.as_ref().expect("budgets")[&Metric::WallMs].threshold,
+            Some(BudgetThreshold::Fixed(0.25))
+        );
+        assert_eq!(config.benches[4].work, Some(BenchWork::Units(3)));
+    }
+
+    #[test]
+    fn bench_matrix_rejects_invalid_expansions() {
+        let parse = |toml: &str| toml::from_str::<ConfigFile>(toml).expect("parse config");
+
+        let invalid_name = parse(
+            r#"
+[[bench]]
+name = "parse_{input}"
+command = ["parse"]
+matrix = { input = ["ok", "a|b"] }
+"#,
+        );
+        assert!(invalid_name.validate().is_err());
+
+        let duplicate = parse(
+            r#"
+[[bench]]
+name = "parse_{size}"
+command = ["parse", "{threads}"]
+matrix = { size = [1], threads = [1, 2] }
+"#,
+        );
+        let err = duplicate.validate().unwrap_err();
+        assert!(err.contains("duplicate name 'parse_1'"), "{err}");
+
+        let bad_work = parse(
+            r#"
+[[bench]]
+name = "parse_{size}"
+command = ["parse"]
+work = "{size}"
+matrix = { size = ["small"] }
+"#,
+        );
+        let err = bad_work.validate().unwrap_err();
+        assert!(err.contains("not an integer"), "{err}");
+
+        let unknown_override = parse(
+            r#"
+[[bench]]
+name = "parse_{size}"
+command = ["parse"]
+matrix = { size = [1] }
+matrix_overrides = { parse_2 = { repeat = 3 } }
+"#,
+        );
+        let err = unknown_override.validate().unwrap_err();
+        assert!(err.contains("does not match any expansion"), "{err}");
+
+        let mut collision = parse(
+            r#"
+[[bench]]
+name = "parse_{size}"
+command = ["parse"]
+matrix = { size = [1] }
+
+[[bench]]
+name = "parse_1"
+command = ["parse"]
+"#,
+        );
+        let err = collision.expand_matrices().unwrap_err();
+        assert!(err.contains("collides"), "{err}");
+    }
+
     #[test]
     fn config_file_parses_weighted_scenarios() {
         let config: ConfigFile = toml::from_str(
@@ -2491,6 +4001,10 @@ command = ["echo", "large"]
                     memory_bytes: Some(16_000_000_000),
                     hostname_hash: Some("cafebabe".into()),
                 },
+                profile: None,
+                concurrency: None,
+                interleave: None,
+                noise_warnings: Vec::new(),
             },
             bench: BenchMeta {
                 name: "my-bench".into(),
@@ -2500,6 +4014,8 @@ command = ["echo", "large"]
                 warmup: 1,
                 work_units: Some(1000),
                 timeout_ms: Some(30000),
+                service: None,
+                load: None,
             },
             samples: vec![
                 Sample {
@@ -2518,6 +4034,19 @@ command = ["echo", "large"]
                     binary_bytes: Some(4096),
                     stdout: Some("ok".into()),
                     stderr: None,
+                    latency_p50_us: None,
+                    latency_p95_us: None,
+                    latency_p99_us: None,
+                    error_rate: None,
+                    throughput_per_s: None,
+                    time_to_ready_ms: None,
+                    instructions: None,
+                    estimated_cycles: None,
+                    l1_hits: None,
+                    ll_hits: None,
+                    ram_hits: None,
+                    alloc_bytes: None,
+                    allocs: None,
                 },
                 Sample {
                     wall_ms: 95,
@@ -2535,6 +4064,19 @@ command = ["echo", "large"]
                     binary_bytes: None,
                     stdout: None,
                     stderr: Some("warn".into()),
+                    latency_p50_us: None,
+                    latency_p95_us: None,
+                    latency_p99_us: None,
+                    error_rate: None,
+                    throughput_per_s: None,
+                    time_to_ready_ms: None,
+                    instructions: None,
+                    estimated_cycles: None,
+                    l1_hits: None,
+                    ll_hits: None,
+                    ram_hits: None,
+                    alloc_bytes: None,
+                    allocs: None,
                 },
             ],
             stats: Stats {
@@ -2549,6 +4091,19 @@ command = ["echo", "large"]
                 energy_uj: None,
                 binary_bytes: Some(U64Summary::new(4096, 4096, 4096)),
                 throughput_per_s: Some(F64Summary::new(10.526, 10.0, 11.111)),
+                latency_p50_us: None,
+                latency_p95_us: None,
+                latency_p99_us: None,
+                error_rate: None,
+                time_to_ready_ms: None,
+                instructions: None,
+                estimated_cycles: None,
+                l1_hits: None,
+                ll_hits: None,
+                ram_hits: None,
+                alloc_bytes: None,
+                allocs: None,
+                custom: Default::default(),
             },
         };
         let json = serde_json::to_string(&receipt).unwrap();
@@ -2575,6 +4130,10 @@ command = ["echo", "large"]
                     memory_bytes: None,
                     hostname_hash: None,
                 },
+                profile: None,
+                concurrency: None,
+                interleave: None,
+                noise_warnings: Vec::new(),
             },
             bench: BenchMeta {
                 name: "b".into(),
@@ -2584,6 +4143,8 @@ command = ["echo", "large"]
                 warmup: 0,
                 work_units: None,
                 timeout_ms: None,
+                service: None,
+                load: None,
             },
             samples: vec![],
             stats: Stats {
@@ -2598,6 +4159,19 @@ command = ["echo", "large"]
                 energy_uj: None,
                 binary_bytes: None,
                 throughput_per_s: None,
+                latency_p50_us: None,
+                latency_p95_us: None,
+                latency_p99_us: None,
+                error_rate: None,
+                time_to_ready_ms: None,
+                instructions: None,
+                estimated_cycles: None,
+                l1_hits: None,
+                ll_hits: None,
+                ram_hits: None,
+                alloc_bytes: None,
+                allocs: None,
+                custom: Default::default(),
             },
         };
         let json = serde_json::to_string(&receipt).unwrap();
@@ -2624,6 +4198,10 @@ command = ["echo", "large"]
                     memory_bytes: Some(u64::MAX),
                     hostname_hash: None,
                 },
+                profile: None,
+                concurrency: None,
+                interleave: None,
+                noise_warnings: Vec::new(),
             },
             bench: BenchMeta {
                 name: "big".into(),
@@ -2633,6 +4211,8 @@ command = ["echo", "large"]
                 warmup: u32::MAX,
                 work_units: Some(u64::MAX),
                 timeout_ms: Some(u64::MAX),
+                service: None,
+                load: None,
             },
             samples: vec![Sample {
                 wall_ms: u64::MAX,
@@ -2650,6 +4230,19 @@ command = ["echo", "large"]
                 binary_bytes: Some(u64::MAX),
                 stdout: None,
                 stderr: None,
+                latency_p50_us: None,
+                latency_p95_us: None,
+                latency_p99_us: None,
+                error_rate: None,
+                throughput_per_s: None,
+                time_to_ready_ms: None,
+                instructions: None,
+                estimated_cycles: None,
+                l1_hits: None,
+                ll_hits: None,
+                ram_hits: None,
+                alloc_bytes: None,
+                allocs: None,
             }],
             stats: Stats {
                 wall_ms: U64Summary::new(u64::MAX, 0, u64::MAX),
@@ -2663,6 +4256,19 @@ command = ["echo", "large"]
                 energy_uj: None,
                 binary_bytes: None,
                 throughput_per_s: Some(F64Summary::new(f64::MAX, 0.0, f64::MAX)),
+                latency_p50_us: None,
+                latency_p95_us: None,
+                latency_p99_us: None,
+                error_rate: None,
+                time_to_ready_ms: None,
+                instructions: None,
+                estimated_cycles: None,
+                l1_hits: None,
+                ll_hits: None,
+                ram_hits: None,
+                alloc_bytes: None,
+                allocs: None,
+                custom: Default::default(),
             },
         };
         let json = serde_json::to_string(&receipt).unwrap();
@@ -2722,14 +4328,18 @@ command = ["echo", "large"]
                 warmup: 0,
                 work_units: None,
                 timeout_ms: None,
+                service: None,
+                load: None,
             },
             baseline_ref: CompareRef {
                 path: Some("base.json".into()),
                 run_id: Some("r1".into()),
+                profile: None,
             },
             current_ref: CompareRef {
                 path: Some("cur.json".into()),
                 run_id: Some("r2".into()),
+                profile: None,
             },
             budgets,
             deltas,
@@ -2765,14 +4375,18 @@ command = ["echo", "large"]
                 warmup: 0,
                 work_units: None,
                 timeout_ms: None,
+                service: None,
+                load: None,
             },
             baseline_ref: CompareRef {
                 path: None,
                 run_id: None,
+                profile: None,
             },
             current_ref: CompareRef {
                 path: None,
                 run_id: None,
+                profile: None,
             },
             budgets: BTreeMap::new(),
             deltas: BTreeMap::new(),
@@ -2830,6 +4444,9 @@ command = ["echo", "large"]
             },
             complexity: None,
             profile_path: None,
+            confirmation: None,
+            skip_reason: None,
+            baseline_selection: None,
         };
         let json = serde_json::to_string(&report).unwrap();
         let back: PerfgateReport = serde_json::from_str(&json).unwrap();
@@ -2850,6 +4467,8 @@ command = ["echo", "large"]
                 baseline_dir: Some("baselines".into()),
                 baseline_pattern: Some("baselines/{bench}.json".into()),
                 markdown_template: None,
+                baseline_history_dir: None,
+                merge_base: None,
             },
             baseline_server: BaselineServerConfig::default(),
             decision_policy: DecisionPolicyConfig::default(),
@@ -2859,7 +4478,7 @@ command = ["echo", "large"]
             benches: vec![BenchConfigFile {
                 name: "my-bench".into(),
                 cwd: Some("/home/user/project".into()),
-                work: Some(1000),
+                work: Some(BenchWork::Units(1000)),
                 timeout: Some("5s".into()),
                 command: vec!["cargo".into(), "bench".into()],
                 repeat: Some(20),
@@ -2872,7 +4491,9 @@ command = ["echo", "large"]
                         BudgetOverride {
                             noise_threshold: None,
                             noise_policy: None,
-                            threshold: Some(0.15),
+                            threshold: Some(BudgetThreshold::Fixed(0.15)),
+                            threshold_min: None,
+                            threshold_max: None,
                             direction: Some(Direction::Lower),
                             warn_factor: Some(0.85),
                             statistic: Some(MetricStatistic::P95),
@@ -2881,7 +4502,19 @@ command = ["echo", "large"]
                     m
                 }),
                 scaling: None,
+                matrix: None,
+                matrix_overrides: None,
+                service: None,
+                tags: Vec::new(),
+                paths: Vec::new(),
+                always: false,
+                load: None,
+                ready: None,
+                ready_timeout: None,
+                terminate_on_ready: false,
             }],
+            extends: Vec::new(),
+            profiles: Default::default(),
         };
         let json = serde_json::to_string(&config).unwrap();
         let back: ConfigFile = serde_json::from_str(&json).unwrap();
@@ -2898,6 +4531,8 @@ command = ["echo", "large"]
             ratchet: None,
             scenarios: Vec::new(),
             benches: vec![],
+            extends: Vec::new(),
+            profiles: Default::default(),
         };
         let json = serde_json::to_string(&config).unwrap();
         let back: ConfigFile = serde_json::from_str(&json).unwrap();
@@ -2918,6 +4553,19 @@ command = ["echo", "large"]
             energy_uj: None,
             binary_bytes: Some(U64Summary::new(1024, 1024, 1024)),
             throughput_per_s: Some(F64Summary::new(2.0, 1.111, 10.0)),
+            latency_p50_us: None,
+            latency_p95_us: None,
+            latency_p99_us: None,
+            error_rate: None,
+            time_to_ready_ms: None,
+            instructions: None,
+            estimated_cycles: None,
+            l1_hits: None,
+            ll_hits: None,
+            ram_hits: None,
+            alloc_bytes: None,
+            allocs: None,
+            custom: Default::default(),
         };
         let json = serde_json::to_string(&stats).unwrap();
         let back: Stats = serde_json::from_str(&json).unwrap();
@@ -2938,6 +4586,19 @@ command = ["echo", "large"]
             energy_uj: None,
             binary_bytes: None,
             throughput_per_s: Some(F64Summary::new(0.0, 0.0, 0.0)),
+            latency_p50_us: None,
+            latency_p95_us: None,
+            latency_p99_us: None,
+            error_rate: None,
+            time_to_ready_ms: None,
+            instructions: None,
+            estimated_cycles: None,
+            l1_hits: None,
+            ll_hits: None,
+            ram_hits: None,
+            alloc_bytes: None,
+            allocs: None,
+            custom: Default::default(),
         };
         let json = serde_json::to_string(&stats).unwrap();
         let back: Stats = serde_json::from_str(&json).unwrap();
@@ -3074,6 +4735,10 @@ command = ["echo", "large"]
                     memory_bytes: None,
                     hostname_hash: None,
                 },
+                profile: None,
+                concurrency: None,
+                interleave: None,
+                noise_warnings: Vec::new(),
             },
             bench: BenchMeta {
                 name: "minimal".into(),
@@ -3083,6 +4748,8 @@ command = ["echo", "large"]
                 warmup: 0,
                 work_units: None,
                 timeout_ms: None,
+                service: None,
+                load: None,
             },
             samples: vec![Sample {
                 wall_ms: 1,
@@ -3100,6 +4767,19 @@ command = ["echo", "large"]
                 binary_bytes: None,
                 stdout: None,
                 stderr: None,
+                latency_p50_us: None,
+                latency_p95_us: None,
+                latency_p99_us: None,
+                error_rate: None,
+                throughput_per_s: None,
+                time_to_ready_ms: None,
+                instructions: None,
+                estimated_cycles: None,
+                l1_hits: None,
+                ll_hits: None,
+                ram_hits: None,
+                alloc_bytes: None,
+                allocs: None,
             }],
             stats: Stats {
                 wall_ms: U64Summary::new(1, 1, 1),
@@ -3113,6 +4793,19 @@ command = ["echo", "large"]
                 energy_uj: None,
                 binary_bytes: None,
                 throughput_per_s: None,
+                latency_p50_us: None,
+                latency_p95_us: None,
+                latency_p99_us: None,
+                error_rate: None,
+                time_to_ready_ms: None,
+                instructions: None,
+                estimated_cycles: None,
+                l1_hits: None,
+                ll_hits: None,
+                ram_hits: None,
+                alloc_bytes: None,
+                allocs: None,
+                custom: Default::default(),
             },
         };
 
@@ -3144,14 +4837,18 @@ command = ["echo", "large"]
                 warmup: 0,
                 work_units: None,
                 timeout_ms: None,
+                service: None,
+                load: None,
             },
             baseline_ref: CompareRef {
                 path: None,
                 run_id: None,
+                profile: None,
             },
             current_ref: CompareRef {
                 path: None,
                 run_id: None,
+                profile: None,
             },
             budgets: BTreeMap::new(),
             deltas: BTreeMap::new(),
@@ -3317,6 +5014,27 @@ command = ["echo", "large"]
         let s = F64Summary::new(50.0, 40.0, 60.0);
         assert!(s.cv().is_none());
     }
+
+    #[test]
+    fn test_merge_base_selection_describe() {
+        let mut selection = MergeBaseSelection {
+            target: "origin/main".to_string(),
+            merge_base: "0123456789abcdef".to_string(),
+            selected_sha: "0123456789abcdef".to_string(),
+            distance: 0,
+        };
+        assert_eq!(
+            selection.describe(),
+            "baseline from merge-base 0123456789ab of HEAD and origin/main"
+        );
+
+        selection.selected_sha = "fedcba9876543210".to_string();
+        selection.distance = 2;
+        assert_eq!(
+            selection.describe(),
+            "baseline from fedcba987654 (2 commits before merge-base 0123456789ab of HEAD and origin/main)"
+        );
+    }
 }
 
 #[cfg(test)]
@@ -3386,6 +5104,10 @@ mod property_tests {
                 started_at,
                 ended_at,
                 host,
+                profile: None,
+                concurrency: None,
+                interleave: None,
+                noise_warnings: Vec::new(),
             })
     }
 
@@ -3409,6 +5131,8 @@ mod property_tests {
                     warmup,
                     work_units,
                     timeout_ms,
+                    service: None,
+                    load: None,
                 },
             )
     }
@@ -3459,6 +5183,19 @@ mod property_tests {
                     binary_bytes,
                     stdout,
                     stderr,
+                    latency_p50_us: None,
+                    latency_p95_us: None,
+                    latency_p99_us: None,
+                    error_rate: None,
+                    throughput_per_s: None,
+                    time_to_ready_ms: None,
+                    instructions: None,
+                    estimated_cycles: None,
+                    l1_hits: None,
+                    ll_hits: None,
+                    ram_hits: None,
+                    alloc_bytes: None,
+                    allocs: None,
                 },
             )
     }
@@ -3521,6 +5258,19 @@ mod property_tests {
                     energy_uj,
                     binary_bytes,
                     throughput_per_s,
+                    latency_p50_us: None,
+                    latency_p95_us: None,
+                    latency_p99_us: None,
+                    error_rate: None,
+                    time_to_ready_ms: None,
+                    instructions: None,
+                    estimated_cycles: None,
+                    l1_hits: None,
+                    ll_hits: None,
+                    ram_hits: None,
+                    alloc_bytes: None,
+                    allocs: None,
+                    custom: Default::default(),
                 },
             )
     }
@@ -3624,7 +5374,11 @@ mod property_tests {
             proptest::option::of(non_empty_string()),
             proptest::option::of(non_empty_string()),
         )
-            .prop_map(|(path, run_id)| CompareRef { path, run_id })
+            .prop_map(|(path, run_id)| CompareRef {
+                path,
+                run_id,
+                profile: None,
+            })
     }
 
     // Strategy for Direction
@@ -3644,6 +5398,7 @@ mod property_tests {
                     threshold,
                     warn_threshold,
                     direction,
+                    auto_threshold: None,
                 }
             },
         )
@@ -3875,7 +5630,9 @@ mod property_tests {
             .prop_map(|(threshold, direction, warn_factor)| BudgetOverride {
                 noise_threshold: None,
                 noise_policy: None,
-                threshold,
+                threshold: threshold.map(BudgetThreshold::Fixed),
+                threshold_min: None,
+                threshold_max: None,
                 direction,
                 warn_factor,
                 statistic: None,
@@ -3905,7 +5662,7 @@ mod property_tests {
                     BenchConfigFile {
                         name,
                         cwd,
-                        work,
+                        work: work.map(BenchWork::Units),
                         timeout,
                         command,
                         repeat,
@@ -3913,6 +5670,16 @@ mod property_tests {
                         metrics,
                         budgets,
                         scaling: None,
+                        matrix: None,
+                        matrix_overrides: None,
+                        service: None,
+                        tags: Vec::new(),
+                        paths: Vec::new(),
+                        always: false,
+                        load: None,
+                        ready: None,
+                        ready_timeout: None,
+                        terminate_on_ready: false,
                     }
                 },
             )
@@ -3951,6 +5718,8 @@ mod property_tests {
                     baseline_dir,
                     baseline_pattern,
                     markdown_template,
+                    baseline_history_dir: None,
+                    merge_base: None,
                 },
             )
     }
@@ -3988,6 +5757,8 @@ mod property_tests {
                 ratchet: None,
                 scenarios: Vec::new(),
                 benches,
+                extends: Vec::new(),
+                profiles: Default::default(),
             })
     }
 
@@ -4054,7 +5825,7 @@ mod property_tests {
             for (orig_bench, deser_bench) in config.benches.iter().zip(deserialized.benches.iter()) {
                 prop_assert_eq!(&orig_bench.name, &deser_bench.name);
                 prop_assert_eq!(&orig_bench.cwd, &deser_bench.cwd);
-                prop_assert_eq!(orig_bench.work, deser_bench.work);
+                prop_assert_eq!(&orig_bench.work, &deser_bench.work);
                 prop_assert_eq!(&orig_bench.timeout, &deser_bench.timeout);
                 prop_assert_eq!(&orig_bench.command, &deser_bench.command);
                 prop_assert_eq!(&orig_bench.metrics, &deser_bench.metrics);
@@ -4068,7 +5839,10 @@ mod property_tests {
                                 .expect("BudgetOverride metric should exist in deserialized");
 
                             // Compare threshold with tolerance
-                            match (orig_override.threshold, deser_override.threshold) {
+                            match (
+                                orig_override.threshold.and_then(BudgetThreshold::fixed),
+                                deser_override.threshold.and_then(BudgetThreshold::fixed),
+                            ) {
                                 (Some(orig), Some(deser)) => {
                                     prop_assert!(
                                         f64_approx_eq(orig, deser),
@@ -4166,7 +5940,7 @@ mod property_tests {
             for (orig_bench, deser_bench) in config.benches.iter().zip(deserialized.benches.iter()) {
                 prop_assert_eq!(&orig_bench.name, &deser_bench.name);
                 prop_assert_eq!(&orig_bench.cwd, &deser_bench.cwd);
-                prop_assert_eq!(orig_bench.work, deser_bench.work);
+                prop_assert_eq!(&orig_bench.work, &deser_bench.work);
                 prop_assert_eq!(&orig_bench.timeout, &deser_bench.timeout);
                 prop_assert_eq!(&orig_bench.command, &deser_bench.command);
                 prop_assert_eq!(&orig_bench.metrics, &deser_bench.metrics);
@@ -4180,7 +5954,10 @@ mod property_tests {
                                 .expect("BudgetOverride metric should exist in deserialized");
 
                             // Compare threshold with tolerance
-                            match (orig_override.threshold, deser_override.threshold) {
+                            match (
+                                orig_override.threshold.and_then(BudgetThreshold::fixed),
+                                deser_override.threshold.and_then(BudgetThreshold::fixed),
+                            ) {
                                 (Some(orig), Some(deser)) => {
                                     prop_assert!(
                                         f64_approx_eq(orig, deser),
@@ -4241,7 +6018,7 @@ mod property_tests {
 
             // Compare optional fields
             prop_assert_eq!(&bench_config.cwd, &deserialized.cwd);
-            prop_assert_eq!(bench_config.work, deserialized.work);
+            prop_assert_eq!(&bench_config.work, &deserialized.work);
             prop_assert_eq!(&bench_config.timeout, &deserialized.timeout);
             prop_assert_eq!(&bench_config.metrics, &deserialized.metrics);
 
@@ -4254,7 +6031,10 @@ mod property_tests {
                             .expect("BudgetOverride metric should exist in deserialized");
 
                         // Compare threshold with tolerance
-                        match (orig_override.threshold, deser_override.threshold) {
+                        match (
+                                orig_override.threshold.and_then(BudgetThreshold::fixed),
+                                deser_override.threshold.and_then(BudgetThreshold::fixed),
+                            ) {
                             (Some(orig), Some(deser)) => {
                                 prop_assert!(
                                     f64_approx_eq(orig, deser),
@@ -4314,7 +6094,7 @@ mod property_tests {
 
             // Compare optional fields
             prop_assert_eq!(&bench_config.cwd, &deserialized.cwd);
-            prop_assert_eq!(bench_config.work, deserialized.work);
+            prop_assert_eq!(&bench_config.work, &deserialized.work);
             prop_assert_eq!(&bench_config.timeout, &deserialized.timeout);
             prop_assert_eq!(&bench_config.metrics, &deserialized.metrics);
 
@@ -4327,7 +6107,10 @@ mod property_tests {
                             .expect("BudgetOverride metric should exist in deserialized");
 
                         // Compare threshold with tolerance
-                        match (orig_override.threshold, deser_override.threshold) {
+                        match (
+                                orig_override.threshold.and_then(BudgetThreshold::fixed),
+                                deser_override.threshold.and_then(BudgetThreshold::fixed),
+                            ) {
                             (Some(orig), Some(deser)) => {
                                 prop_assert!(
                                     f64_approx_eq(orig, deser),
@@ -4415,7 +6198,10 @@ mod property_tests {
                 .expect("JSON should deserialize back to BudgetOverride");
 
             // Compare threshold with tolerance
-            match (budget_override.threshold, deserialized.threshold) {
+            match (
+                budget_override.threshold.and_then(BudgetThreshold::fixed),
+                deserialized.threshold.and_then(BudgetThreshold::fixed),
+            ) {
                 (Some(orig), Some(deser)) => {
                     prop_assert!(
                         f64_approx_eq(orig, deser),
@@ -4631,6 +6417,9 @@ mod property_tests {
                 summary,
                 complexity: None,
                 profile_path: None,
+                confirmation: None,
+                skip_reason: None,
+                baseline_selection: None,
             })
     }
 
@@ -170,6 +170,10 @@ mod tests {
                     memory_bytes: None,
                     hostname_hash: None,
                 },
+                profile: None,
+                concurrency: None,
+                interleave: None,
+                noise_warnings: Vec::new(),
             },
             bench: PairedBenchMeta {
                 name: "bench".to_string(),
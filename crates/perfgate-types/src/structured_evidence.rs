@@ -440,6 +440,10 @@ mod tests {
                 memory_bytes: None,
                 hostname_hash: None,
             },
+            profile: None,
+            concurrency: None,
+            interleave: None,
+            noise_warnings: Vec::new(),
         }
     }
 
@@ -534,6 +538,7 @@ mod tests {
                 probe_compare_ref: Some(CompareRef {
                     path: Some("artifacts/perfgate/large-file/probe-compare.json".into()),
                     run_id: Some("probe-current".into()),
+                    profile: None,
                 }),
                 deltas: weighted_deltas.clone(),
                 probes: vec!["parser.tokenize".into()],
@@ -565,15 +570,19 @@ mod tests {
                 warmup: 0,
                 work_units: None,
                 timeout_ms: None,
+                service: None,
+                load: None,
             }),
             scenario: Some("large_file_parse".into()),
             baseline_ref: Some(CompareRef {
                 path: Some("baselines/probes.json".into()),
                 run_id: Some("baseline-run".into()),
+                profile: None,
             }),
             current_ref: Some(CompareRef {
                 path: Some("artifacts/perfgate/probes.json".into()),
                 run_id: Some("current-run".into()),
+                profile: None,
             }),
             probes: vec![ProbeCompareObservation {
                 name: "parser.tokenize".into(),
@@ -0,0 +1,138 @@
+use crate::{CompareReceipt, CompareRef, Metric, RunReceipt, ToolInfo, Verdict, VerdictStatus};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Several benchmarks' run receipts in one file (`perfgate.run_bundle.v1`).
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct RunBundle {
+    pub schema: String,
+    pub tool: ToolInfo,
+    pub receipts: Vec<RunReceipt>,
+}
+
+/// Geometric-mean change of one metric across the benchmarks of a suite.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct SuiteGeomean {
+    /// Geometric mean of per-bench `current / baseline` ratios.
+    pub ratio: f64,
+
+    /// `ratio - 1.0`, as a fraction.
+    pub pct: f64,
+
+    /// Number of benchmarks that contributed a ratio.
+    pub bench_count: u32,
+}
+
+/// Comparison outcome for one benchmark present in both suites.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct SuiteBenchCompare {
+    pub name: String,
+    pub status: VerdictStatus,
+    pub compare: CompareReceipt,
+}
+
+/// A versioned receipt comparing two suites of run receipts (`perfgate.suite_compare.v1`).
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct SuiteCompareReceipt {
+    pub schema: String,
+    pub tool: ToolInfo,
+    pub baseline_ref: CompareRef,
+    pub current_ref: CompareRef,
+
+    /// Benchmarks present in both suites, sorted by name.
+    pub benches: Vec<SuiteBenchCompare>,
+
+    /// Benchmarks only present in the current suite.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub added: Vec<String>,
+
+    /// Benchmarks only present in the baseline suite.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub removed: Vec<String>,
+
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub geomean: BTreeMap<Metric, SuiteGeomean>,
+
+    /// Suite verdict; counts are per benchmark rather than per metric.
+    pub verdict: Verdict,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{RUN_BUNDLE_SCHEMA_V1, SUITE_COMPARE_SCHEMA_V1, VerdictCounts};
+
+    fn tool() -> ToolInfo {
+        ToolInfo {
+            name: "perfgate".into(),
+            version: "0.1.0".into(),
+        }
+    }
+
+    #[test]
+    fn run_bundle_round_trips() {
+        let bundle = RunBundle {
+            schema: RUN_BUNDLE_SCHEMA_V1.into(),
+            tool: tool(),
+            receipts: Vec::new(),
+        };
+
+        let json = serde_json::to_string(&bundle).expect("serialize run bundle");
+        let parsed: RunBundle = serde_json::from_str(&json).expect("parse run bundle");
+        assert_eq!(parsed, bundle);
+    }
+
+    #[test]
+    fn suite_compare_receipt_round_trips() {
+        let receipt = SuiteCompareReceipt {
+            schema: SUITE_COMPARE_SCHEMA_V1.into(),
+            tool: tool(),
+            baseline_ref: CompareRef {
+                path: Some("baselines".into()),
+                run_id: None,
+                profile: None,
+            },
+            current_ref: CompareRef {
+                path: Some("artifacts/perfgate".into()),
+                run_id: None,
+                profile: None,
+            },
+            benches: Vec::new(),
+            added: vec!["new_bench".into()],
+            removed: Vec::new(),
+            geomean: BTreeMap::from([(
+                Metric::WallMs,
+                SuiteGeomean {
+                    ratio: 1.05,
+                    pct: 0.05,
+                    bench_count: 3,
+                },
+            )]),
+            verdict: Verdict {
+                status: VerdictStatus::Pass,
+                counts: VerdictCounts {
+                    pass: 3,
+                    warn: 0,
+                    fail: 0,
+                    skip: 0,
+                },
+                reasons: Vec::new(),
+            },
+            warnings: Vec::new(),
+        };
+
+        let json = serde_json::to_string(&receipt).expect("serialize suite compare receipt");
+        assert!(!json.contains("\"removed\""));
+        let parsed: SuiteCompareReceipt =
+            serde_json::from_str(&json).expect("parse suite compare receipt");
+        assert_eq!(parsed, receipt);
+    }
+}
@@ -12,6 +12,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         threshold: 0.20,      // 20% regression = fail
         warn_threshold: 0.10, // 10% regression = warn
         direction: Direction::Lower,
+        auto_threshold: None,
     };
 
     // Scenario 1: 5% regression → Pass
@@ -21,6 +21,19 @@ fn make_sample(wall_ms: u64) -> Sample {
         binary_bytes: None,
         stdout: None,
         stderr: None,
+        latency_p50_us: None,
+        latency_p95_us: None,
+        latency_p99_us: None,
+        error_rate: None,
+        throughput_per_s: None,
+        time_to_ready_ms: None,
+        instructions: None,
+        estimated_cycles: None,
+        l1_hits: None,
+        ll_hits: None,
+        ram_hits: None,
+        alloc_bytes: None,
+        allocs: None,
     }
 }
 
@@ -65,6 +78,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             threshold: 0.20,      // 20% regression = fail
             warn_threshold: 0.10, // 10% regression = warn
             direction: Direction::Lower,
+            auto_threshold: None,
         },
     );
 
@@ -28,6 +28,10 @@ fn create_run_receipt() -> RunReceipt {
                 memory_bytes: Some(16 * 1024 * 1024 * 1024),
                 hostname_hash: None,
             },
+            profile: None,
+            concurrency: None,
+            interleave: None,
+            noise_warnings: Vec::new(),
         },
         bench: BenchMeta {
             name: "example-bench".to_string(),
@@ -37,6 +41,8 @@ fn create_run_receipt() -> RunReceipt {
             warmup: 2,
             work_units: Some(1000),
             timeout_ms: None,
+            service: None,
+            load: None,
         },
         samples: vec![
             Sample {
@@ -55,6 +61,19 @@ fn create_run_receipt() -> RunReceipt {
                 binary_bytes: None,
                 stdout: None,
                 stderr: None,
+                latency_p50_us: None,
+                latency_p95_us: None,
+                latency_p99_us: None,
+                error_rate: None,
+                throughput_per_s: None,
+                time_to_ready_ms: None,
+                instructions: None,
+                estimated_cycles: None,
+                l1_hits: None,
+                ll_hits: None,
+                ram_hits: None,
+                alloc_bytes: None,
+                allocs: None,
             },
             Sample {
                 wall_ms: 105,
@@ -72,6 +91,19 @@ fn create_run_receipt() -> RunReceipt {
                 binary_bytes: None,
                 stdout: None,
                 stderr: None,
+                latency_p50_us: None,
+                latency_p95_us: None,
+                latency_p99_us: None,
+                error_rate: None,
+                throughput_per_s: None,
+                time_to_ready_ms: None,
+                instructions: None,
+                estimated_cycles: None,
+                l1_hits: None,
+                ll_hits: None,
+                ram_hits: None,
+                alloc_bytes: None,
+                allocs: None,
             },
         ],
         stats: Stats {
@@ -86,6 +118,19 @@ fn create_run_receipt() -> RunReceipt {
             ctx_switches: None,
             binary_bytes: None,
             throughput_per_s: None,
+            latency_p50_us: None,
+            latency_p95_us: None,
+            latency_p99_us: None,
+            error_rate: None,
+            time_to_ready_ms: None,
+            instructions: None,
+            estimated_cycles: None,
+            l1_hits: None,
+            ll_hits: None,
+            ram_hits: None,
+            alloc_bytes: None,
+            allocs: None,
+            custom: Default::default(),
         },
     }
 }
@@ -141,14 +186,18 @@ fn create_compare_receipt() -> CompareReceipt {
             warmup: 2,
             work_units: None,
             timeout_ms: None,
+            service: None,
+            load: None,
         },
         baseline_ref: CompareRef {
             path: Some("baseline.json".to_string()),
             run_id: Some("run-001".to_string()),
+            profile: None,
         },
         current_ref: CompareRef {
             path: Some("current.json".to_string()),
             run_id: Some("run-002".to_string()),
+            profile: None,
         },
         budgets,
         deltas,
@@ -48,14 +48,18 @@ fn create_compare_receipt(status: MetricStatus) -> CompareReceipt {
             warmup: 1,
             work_units: None,
             timeout_ms: None,
+            service: None,
+            load: None,
         },
         baseline_ref: CompareRef {
             path: Some("baseline.json".to_string()),
             run_id: None,
+            profile: None,
         },
         current_ref: CompareRef {
             path: Some("current.json".to_string()),
             run_id: None,
+            profile: None,
         },
         budgets,
         deltas,
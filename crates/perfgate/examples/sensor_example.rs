@@ -37,6 +37,9 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         },
         complexity: None,
         profile_path: None,
+        confirmation: None,
+        skip_reason: None,
+        baseline_selection: None,
     };
 
     let sensor_report = SensorReportBuilder::new(tool, "2024-01-15T10:30:00Z".to_string())
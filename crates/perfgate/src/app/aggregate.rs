@@ -106,6 +106,10 @@ impl AggregateUseCase {
                     memory_bytes: None,
                     hostname_hash: None,
                 },
+                profile: None,
+                concurrency: None,
+                interleave: None,
+                noise_warnings: Vec::new(),
             },
             bench,
             samples: combined_samples,
@@ -500,6 +504,10 @@ mod tests {
                     memory_bytes: Some(16 * 1024 * 1024 * 1024),
                     hostname_hash: None,
                 },
+                profile: None,
+                concurrency: None,
+                interleave: None,
+                noise_warnings: Vec::new(),
             },
             bench: BenchMeta {
                 name: "bench".to_string(),
@@ -509,6 +517,8 @@ mod tests {
                 warmup: 0,
                 work_units: None,
                 timeout_ms: None,
+                service: None,
+                load: None,
             },
             samples: vec![Sample {
                 wall_ms: 10,
@@ -526,6 +536,19 @@ mod tests {
                 binary_bytes: None,
                 stdout: None,
                 stderr: None,
+                latency_p50_us: None,
+                latency_p95_us: None,
+                latency_p99_us: None,
+                error_rate: None,
+                throughput_per_s: None,
+                time_to_ready_ms: None,
+                instructions: None,
+                estimated_cycles: None,
+                l1_hits: None,
+                ll_hits: None,
+                ram_hits: None,
+                alloc_bytes: None,
+                allocs: None,
             }],
             stats: Stats {
                 wall_ms: U64Summary::new(10, 10, 10),
@@ -539,6 +562,19 @@ mod tests {
                 energy_uj: None,
                 binary_bytes: None,
                 throughput_per_s: None,
+                latency_p50_us: None,
+                latency_p95_us: None,
+                latency_p99_us: None,
+                error_rate: None,
+                time_to_ready_ms: None,
+                instructions: None,
+                estimated_cycles: None,
+                l1_hits: None,
+                ll_hits: None,
+                ram_hits: None,
+                alloc_bytes: None,
+                allocs: None,
+                custom: Default::default(),
             },
         }
     }
@@ -569,6 +605,19 @@ mod tests {
                 binary_bytes: None,
                 stdout: None,
                 stderr: None,
+                latency_p50_us: None,
+                latency_p95_us: None,
+                latency_p99_us: None,
+                error_rate: None,
+                throughput_per_s: None,
+                time_to_ready_ms: None,
+                instructions: None,
+                estimated_cycles: None,
+                l1_hits: None,
+                ll_hits: None,
+                ram_hits: None,
+                alloc_bytes: None,
+                allocs: None,
             })
             .collect();
         receipt.bench.repeat = wall_samples.len() as u32;
@@ -853,6 +902,19 @@ mod tests {
                 binary_bytes: None,
                 stdout: None,
                 stderr: None,
+                latency_p50_us: None,
+                latency_p95_us: None,
+                latency_p99_us: None,
+                error_rate: None,
+                throughput_per_s: None,
+                time_to_ready_ms: None,
+                instructions: None,
+                estimated_cycles: None,
+                l1_hits: None,
+                ll_hits: None,
+                ram_hits: None,
+                alloc_bytes: None,
+                allocs: None,
             },
             Sample {
                 wall_ms: 10,
@@ -870,6 +932,19 @@ mod tests {
                 binary_bytes: None,
                 stdout: None,
                 stderr: None,
+                latency_p50_us: None,
+                latency_p95_us: None,
+                latency_p99_us: None,
+                error_rate: None,
+                throughput_per_s: None,
+                time_to_ready_ms: None,
+                instructions: None,
+                estimated_cycles: None,
+                l1_hits: None,
+                ll_hits: None,
+                ram_hits: None,
+                alloc_bytes: None,
+                allocs: None,
             },
         ];
 
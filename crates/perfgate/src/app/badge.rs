@@ -356,14 +356,18 @@ mod tests {
                 warmup: 0,
                 work_units: None,
                 timeout_ms: None,
+                service: None,
+                load: None,
             },
             baseline_ref: CompareRef {
                 path: None,
                 run_id: None,
+                profile: None,
             },
             current_ref: CompareRef {
                 path: None,
                 run_id: None,
+                profile: None,
             },
             budgets,
             deltas,
@@ -408,6 +412,9 @@ mod tests {
             },
             complexity: None,
             profile_path: None,
+            confirmation: None,
+            skip_reason: None,
+            baseline_selection: None,
         }
     }
 
@@ -624,6 +631,9 @@ mod tests {
             },
             complexity: None,
             profile_path: None,
+            confirmation: None,
+            skip_reason: None,
+            baseline_selection: None,
         };
         let result = metric_badge(
             &BadgeInput::Report(Box::new(report)),
@@ -685,6 +695,9 @@ mod tests {
             },
             complexity: None,
             profile_path: None,
+            confirmation: None,
+            skip_reason: None,
+            baseline_selection: None,
         };
         let badge = trend_badge(&BadgeInput::Report(Box::new(report)), BadgeStyle::Flat);
         assert_eq!(badge.message, "unknown");
@@ -3,6 +3,10 @@
 use perfgate_types::ConfigFile;
 use std::path::PathBuf;
 
+/// Number of first-parent ancestors of the merge-base searched for a
+/// recorded baseline before giving up.
+pub const DEFAULT_MERGE_BASE_SEARCH_DEPTH: usize = 100;
+
 /// Resolve the baseline path from CLI args or config defaults.
 pub fn resolve_baseline_path(
     cli_baseline: &Option<PathBuf>,
@@ -35,6 +39,59 @@ pub fn resolve_baseline_path(
     PathBuf::from("baselines").join(format!("{}.json", bench_name))
 }
 
+/// Resolve the merge-base target branch from the CLI flag or config defaults.
+pub fn resolve_merge_base_target(cli_target: Option<&str>, config: &ConfigFile) -> Option<String> {
+    cli_target
+        .map(str::to_string)
+        .or_else(|| config.defaults.merge_base.clone())
+        .filter(|target| !target.trim().is_empty())
+}
+
+/// Path of a baseline recorded for `git_sha` in a local history store.
+///
+/// History stores are laid out as `<history_dir>/<git_sha>/<bench>.json`.
+pub fn render_history_baseline_path(history_dir: &str, git_sha: &str, bench_name: &str) -> PathBuf {
+    if is_remote_storage_uri(history_dir) {
+        return PathBuf::from(format!(
+            "{}/{}/{}.json",
+            history_dir.trim_end_matches('/'),
+            git_sha,
+            bench_name
+        ));
+    }
+    PathBuf::from(history_dir)
+        .join(git_sha)
+        .join(format!("{}.json", bench_name))
+}
+
+/// Pick the nearest ancestor that has a recorded baseline.
+///
+/// `ancestors` is ordered from the merge-base outwards (the merge-base itself
+/// first). Returns the distance from the merge-base and the selected SHA.
+pub fn select_nearest_recorded_ancestor<F>(
+    ancestors: &[String],
+    mut has_baseline: F,
+) -> Option<(usize, &str)>
+where
+    F: FnMut(&str) -> bool,
+{
+    ancestors
+        .iter()
+        .enumerate()
+        .find(|(_, sha)| has_baseline(sha))
+        .map(|(distance, sha)| (distance, sha.as_str()))
+}
+
+/// Returns true if two git SHAs refer to the same commit, allowing either to be abbreviated.
+pub fn git_sha_matches(a: &str, b: &str) -> bool {
+    let a = a.trim().to_ascii_lowercase();
+    let b = b.trim().to_ascii_lowercase();
+    if a.len() < 7 || b.len() < 7 {
+        return a == b;
+    }
+    a.starts_with(&b) || b.starts_with(&a)
+}
+
 /// Render a baseline pattern by replacing {bench} placeholder.
 pub fn render_baseline_pattern(pattern: &str, bench_name: &str) -> PathBuf {
     PathBuf::from(pattern.replace("{bench}", bench_name))
@@ -65,6 +122,8 @@ mod tests {
             ratchet: None,
             scenarios: Vec::new(),
             benches: Vec::new(),
+            extends: Vec::new(),
+            profiles: Default::default(),
         };
 
         let cli = Some(PathBuf::from("cli.json"));
@@ -80,6 +139,70 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_resolve_merge_base_target_prefers_cli() {
+        let mut config = ConfigFile::default();
+        assert_eq!(resolve_merge_base_target(None, &config), None);
+
+        config.defaults.merge_base = Some("origin/main".to_string());
+        assert_eq!(
+            resolve_merge_base_target(None, &config).as_deref(),
+            Some("origin/main")
+        );
+        assert_eq!(
+            resolve_merge_base_target(Some("origin/release"), &config).as_deref(),
+            Some("origin/release")
+        );
+
+        config.defaults.merge_base = Some("  ".to_string());
+        assert_eq!(resolve_merge_base_target(None, &config), None);
+    }
+
+    #[test]
+    fn test_render_history_baseline_path() {
+        assert_eq!(
+            render_history_baseline_path("history", "abc123", "bench"),
+            PathBuf::from("history").join("abc123").join("bench.json")
+        );
+        assert_eq!(
+            render_history_baseline_path("s3://bucket/history/", "abc123", "bench"),
+            PathBuf::from("s3://bucket/history/abc123/bench.json")
+        );
+    }
+
+    #[test]
+    fn test_select_nearest_recorded_ancestor() {
+        let ancestors = vec![
+            "c3".to_string(),
+            "c2".to_string(),
+            "c1".to_string(),
+            "c0".to_string(),
+        ];
+
+        assert_eq!(
+            select_nearest_recorded_ancestor(&ancestors, |sha| sha == "c1" || sha == "c0"),
+            Some((2, "c1"))
+        );
+        assert_eq!(
+            select_nearest_recorded_ancestor(&ancestors, |sha| sha == "c3"),
+            Some((0, "c3"))
+        );
+        assert_eq!(
+            select_nearest_recorded_ancestor(&ancestors, |_| false),
+            None
+        );
+    }
+
+    #[test]
+    fn test_git_sha_matches_abbreviations() {
+        let full = "0123456789abcdef0123456789abcdef01234567";
+        assert!(git_sha_matches(full, full));
+        assert!(git_sha_matches(full, "0123456"));
+        assert!(git_sha_matches("0123456789AB", full));
+        assert!(!git_sha_matches(full, "fedcba9876"));
+        assert!(!git_sha_matches(full, "0123"));
+    }
+
     #[test]
     fn test_is_remote_storage_uri() {
         assert!(is_remote_storage_uri("s3://bucket/key"));
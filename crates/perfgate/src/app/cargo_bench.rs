@@ -1,8 +1,11 @@
-//! Cargo bench integration: parse Criterion and libtest bench output into RunReceipts.
+//! Cargo bench integration: parse Criterion, libtest, divan, and iai-callgrind
+//! bench output into RunReceipts.
 //!
 //! This module provides:
 //! - Criterion JSON parsing (`target/criterion/{bench}/new/estimates.json`)
 //! - Libtest bench output parsing (`test ... bench: NNN ns/iter (+/- NNN)`)
+//! - Divan table parsing (`name  fastest │ slowest │ median │ mean │ samples │ iters`)
+//! - iai-callgrind summary parsing (`Instructions:  1734|1734  (No change)`)
 //! - A use-case struct that runs `cargo bench` and produces a `RunReceipt`
 
 use crate::app::Clock;
@@ -21,6 +24,8 @@ pub struct ParsedBenchmark {
     pub error_ns: Option<f64>,
     /// Source format that produced this result
     pub source: BenchSource,
+    /// Callgrind event counts (iai-callgrind only)
+    pub callgrind: Option<CallgrindCounts>,
 }
 
 /// Which benchmark framework produced the result.
@@ -28,6 +33,22 @@ pub struct ParsedBenchmark {
 pub enum BenchSource {
     Criterion,
     Libtest,
+    Divan,
+    IaiCallgrind,
+}
+
+/// Event counts from an iai-callgrind summary.
+///
+/// These come from Valgrind's cache simulation rather than a clock, so they
+/// are deterministic across runs on the same binary and suit very tight budgets.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CallgrindCounts {
+    pub instructions: Option<u64>,
+    pub l1_hits: Option<u64>,
+    /// Last-level cache hits (reported as `L2 Hits` by older iai-callgrind versions)
+    pub ll_hits: Option<u64>,
+    pub ram_hits: Option<u64>,
+    pub estimated_cycles: Option<u64>,
 }
 
 /// Criterion's `estimates.json` structure (subset).
@@ -121,6 +142,7 @@ fn scan_criterion_recursive(
                 estimate_ns: parsed.estimate_ns,
                 error_ns: parsed.error_ns,
                 source: BenchSource::Criterion,
+                callgrind: None,
             });
         }
         return Ok(());
@@ -230,9 +252,201 @@ fn parse_libtest_line(line: &str) -> Option<ParsedBenchmark> {
         estimate_ns,
         error_ns,
         source: BenchSource::Libtest,
+        callgrind: None,
     })
 }
 
+// ---------------------------------------------------------------------------
+// Divan parsing
+// ---------------------------------------------------------------------------
+
+/// Parse divan's tree table output.
+///
+/// ```text
+/// example       fastest       │ slowest       │ median        │ mean          │ samples │ iters
+/// ├─ add        0.5 ns        │ 1.2 ns        │ 0.6 ns        │ 0.6 ns        │ 100     │ 409600
+/// ╰─ fibonacci                │               │               │               │         │
+///    ╰─ 10      32.2 ns       │ 40.1 ns       │ 33.5 ns       │ 34.0 ns       │ 100     │ 12800
+/// ```
+///
+/// Leaves are named by their path in the tree (`example/fibonacci/10`) and
+/// use the median time as the estimate. Counter and allocation rows under a
+/// leaf are ignored.
+pub fn parse_divan_output(output: &str) -> Vec<ParsedBenchmark> {
+    let mut results = Vec::new();
+    // Root name, then one entry per open group.
+    let mut path: Vec<String> = Vec::new();
+
+    for line in output.lines() {
+        if line.contains("fastest") && line.contains("│ slowest") {
+            path = line
+                .split_whitespace()
+                .next()
+                .filter(|root| *root != "fastest")
+                .map(|root| vec![root.to_string()])
+                .unwrap_or_default();
+            continue;
+        }
+        if path.is_empty() {
+            continue;
+        }
+
+        let rest = line.trim_start_matches(['│', '├', '╰', '─', ' ']);
+        let depth = (line.chars().count() - rest.chars().count()) / 3;
+        if depth == 0 || rest.is_empty() {
+            path.clear();
+            continue;
+        }
+
+        let columns: Vec<&str> = rest.split('│').map(str::trim).collect();
+        let tokens: Vec<&str> = columns[0].split_whitespace().collect();
+        if tokens.is_empty() || tokens.iter().any(|t| t.ends_with(':')) {
+            continue;
+        }
+
+        let fastest = match tokens.as_slice() {
+            [_, .., value, unit] => parse_divan_time(value, unit),
+            _ => None,
+        };
+        if fastest.is_none() {
+            // Group rows leave every value column empty; anything else is a
+            // counter or allocation row belonging to the leaf above.
+            if columns[1..].iter().all(|c| c.is_empty()) {
+                path.truncate(depth);
+                path.push(tokens.join(" "));
+            }
+            continue;
+        }
+
+        path.truncate(depth);
+        path.push(tokens[..tokens.len() - 2].join(" "));
+        let Some(median_ns) = columns.get(2).and_then(|c| parse_divan_cell(c)) else {
+            continue;
+        };
+
+        results.push(ParsedBenchmark {
+            name: path.join("/"),
+            estimate_ns: median_ns,
+            error_ns: None,
+            source: BenchSource::Divan,
+            callgrind: None,
+        });
+    }
+
+    results.sort_by(|a, b| a.name.cmp(&b.name));
+    results
+}
+
+fn parse_divan_cell(cell: &str) -> Option<f64> {
+    let mut tokens = cell.split_whitespace();
+    let value = tokens.next()?;
+    let unit = tokens.next()?;
+    parse_divan_time(value, unit)
+}
+
+/// Convert a divan time value to nanoseconds.
+fn parse_divan_time(value: &str, unit: &str) -> Option<f64> {
+    let scale = match unit {
+        "ps" => 1e-3,
+        "ns" => 1.0,
+        "µs" | "us" => 1e3,
+        "ms" => 1e6,
+        "s" => 1e9,
+        _ => return None,
+    };
+    value.parse::<f64>().ok().map(|v| v * scale)
+}
+
+// ---------------------------------------------------------------------------
+// iai-callgrind parsing
+// ---------------------------------------------------------------------------
+
+/// Parse iai-callgrind's terminal summary.
+///
+/// ```text
+/// lib_bench::bench_group::bench_fibonacci short:10
+///   Instructions:                1734|1734            (No change)
+///   L1 Hits:                     2359|2359            (No change)
+///   LL Hits:                        0|0               (No change)
+///   RAM Hits:                       3|3               (No change)
+///   Total read+write:            2362|2362            (No change)
+///   Estimated Cycles:            2464|2464            (No change)
+/// ```
+///
+/// Only the new value (left of `|`) is kept. The benchmark name has `::`
+/// and spaces replaced by `/`. Benchmarks have no wall-clock estimate.
+pub fn parse_iai_callgrind_output(output: &str) -> Vec<ParsedBenchmark> {
+    let mut results = Vec::new();
+    let mut current: Option<(String, CallgrindCounts)> = None;
+
+    let mut flush = |current: &mut Option<(String, CallgrindCounts)>| {
+        if let Some((name, counts)) = current.take()
+            && counts != CallgrindCounts::default()
+        {
+            results.push(ParsedBenchmark {
+                name,
+                estimate_ns: 0.0,
+                error_ns: None,
+                source: BenchSource::IaiCallgrind,
+                callgrind: Some(counts),
+            });
+        }
+    };
+
+    for line in output.lines() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        if !line.starts_with(char::is_whitespace) {
+            flush(&mut current);
+            if line.contains("::") && !line.starts_with("test ") {
+                let name = line.trim().replace("::", "/").replace(' ', "/");
+                current = Some((name, CallgrindCounts::default()));
+            }
+            continue;
+        }
+
+        let Some((_, counts)) = current.as_mut() else {
+            continue;
+        };
+        let Some((key, value)) = line.trim().split_once(':') else {
+            continue;
+        };
+        let Some(value) = parse_callgrind_value(value) else {
+            continue;
+        };
+        match key.trim() {
+            "Instructions" => counts.instructions = Some(value),
+            "L1 Hits" => counts.l1_hits = Some(value),
+            "L2 Hits" | "LL Hits" => counts.ll_hits = Some(value),
+            "RAM Hits" => counts.ram_hits = Some(value),
+            "Estimated Cycles" => counts.estimated_cycles = Some(value),
+            _ => {}
+        }
+    }
+    flush(&mut current);
+
+    results.sort_by(|a, b| a.name.cmp(&b.name));
+    results
+}
+
+/// Parse the new value of a `new|old (change)` summary cell.
+fn parse_callgrind_value(cell: &str) -> Option<u64> {
+    let first = cell.split_whitespace().next()?;
+    let new = first.split('|').next()?;
+    new.replace(',', "").parse().ok()
+}
+
+/// Parse every benchmark format that `cargo bench` prints to the terminal
+/// (libtest, divan, and iai-callgrind), sorted by name.
+pub fn parse_bench_output(output: &str) -> Vec<ParsedBenchmark> {
+    let mut results = parse_libtest_output(output);
+    results.extend(parse_divan_output(output));
+    results.extend(parse_iai_callgrind_output(output));
+    results.sort_by(|a, b| a.name.cmp(&b.name));
+    results
+}
+
 // ---------------------------------------------------------------------------
 // Detect which framework was used
 // ---------------------------------------------------------------------------
@@ -291,28 +505,7 @@ pub fn benchmarks_to_receipt(
 
     // For the receipt, we create synthetic samples from the parsed data.
     // Each benchmark's estimate_ns is converted to wall_ms.
-    let mut samples: Vec<Sample> = Vec::new();
-
-    for bench in benchmarks {
-        let wall_ms = (bench.estimate_ns / 1_000_000.0).round().max(1.0) as u64;
-        samples.push(Sample {
-            wall_ms,
-            exit_code: 0,
-            warmup: false,
-            timed_out: false,
-            cpu_ms: None,
-            page_faults: None,
-            ctx_switches: None,
-            max_rss_kb: None,
-            io_read_bytes: None,
-            io_write_bytes: None,
-            network_packets: None,
-            energy_uj: None,
-            binary_bytes: None,
-            stdout: None,
-            stderr: None,
-        });
-    }
+    let samples: Vec<Sample> = benchmarks.iter().map(bench_sample).collect();
 
     let stats = compute_stats(&samples, None)?;
     let ended_at = clock.now_rfc3339();
@@ -325,6 +518,10 @@ pub fn benchmarks_to_receipt(
             started_at,
             ended_at,
             host: host.clone(),
+            profile: None,
+            concurrency: None,
+            interleave: None,
+            noise_warnings: Vec::new(),
         },
         bench: BenchMeta {
             name: name.to_string(),
@@ -334,6 +531,8 @@ pub fn benchmarks_to_receipt(
             warmup: 0,
             work_units: None,
             timeout_ms: None,
+            service: None,
+            load: None,
         },
         samples,
         stats,
@@ -351,25 +550,7 @@ pub fn benchmarks_to_individual_receipts(
     let mut receipts = Vec::new();
 
     for bench in benchmarks {
-        let wall_ms = (bench.estimate_ns / 1_000_000.0).round().max(1.0) as u64;
-
-        let sample = Sample {
-            wall_ms,
-            exit_code: 0,
-            warmup: false,
-            timed_out: false,
-            cpu_ms: None,
-            page_faults: None,
-            ctx_switches: None,
-            max_rss_kb: None,
-            io_read_bytes: None,
-            io_write_bytes: None,
-            network_packets: None,
-            energy_uj: None,
-            binary_bytes: None,
-            stdout: None,
-            stderr: None,
-        };
+        let sample = bench_sample(bench);
 
         let run_id = uuid::Uuid::new_v4().to_string();
         let ts = clock.now_rfc3339();
@@ -383,6 +564,10 @@ pub fn benchmarks_to_individual_receipts(
                 started_at: ts.clone(),
                 ended_at: ts,
                 host: host.clone(),
+                profile: None,
+                concurrency: None,
+                interleave: None,
+                noise_warnings: Vec::new(),
             },
             bench: BenchMeta {
                 name: bench.name.clone(),
@@ -392,6 +577,8 @@ pub fn benchmarks_to_individual_receipts(
                 warmup: 0,
                 work_units: None,
                 timeout_ms: None,
+                service: None,
+                load: None,
             },
             samples: vec![sample],
             stats,
@@ -401,6 +588,50 @@ pub fn benchmarks_to_individual_receipts(
     Ok(receipts)
 }
 
+/// Build the synthetic sample for one parsed benchmark.
+///
+/// Callgrind-only benchmarks have no wall-clock estimate and record
+/// `wall_ms = 0`; everything else is rounded up to at least 1ms.
+fn bench_sample(bench: &ParsedBenchmark) -> Sample {
+    let counts = bench.callgrind.clone().unwrap_or_default();
+    let wall_ms = if bench.callgrind.is_some() && bench.estimate_ns <= 0.0 {
+        0
+    } else {
+        (bench.estimate_ns / 1_000_000.0).round().max(1.0) as u64
+    };
+
+    Sample {
+        wall_ms,
+        exit_code: 0,
+        warmup: false,
+        timed_out: false,
+        cpu_ms: None,
+        page_faults: None,
+        ctx_switches: None,
+        max_rss_kb: None,
+        io_read_bytes: None,
+        io_write_bytes: None,
+        network_packets: None,
+        energy_uj: None,
+        binary_bytes: None,
+        stdout: None,
+        stderr: None,
+        latency_p50_us: None,
+        latency_p95_us: None,
+        latency_p99_us: None,
+        error_rate: None,
+        throughput_per_s: None,
+        time_to_ready_ms: None,
+        instructions: counts.instructions,
+        estimated_cycles: counts.estimated_cycles,
+        l1_hits: counts.l1_hits,
+        ll_hits: counts.ll_hits,
+        ram_hits: counts.ram_hits,
+        alloc_bytes: None,
+        allocs: None,
+    }
+}
+
 /// Build the `cargo bench` command line.
 pub fn build_cargo_bench_command(bench_target: Option<&str>, extra_args: &[String]) -> Vec<String> {
     let mut cmd = vec!["cargo".to_string(), "bench".to_string()];
@@ -560,6 +791,109 @@ test result: ok. 0 passed; 0 failed; 0 ignored; 3 measured; 0 filtered out
         assert!(results.is_empty());
     }
 
+    // ---- Divan parsing tests ----
+
+    #[test]
+    fn parse_divan_table_names_leaves_by_tree_path() {
+        let output = "
+     Running benches/example.rs (target/release/deps/example-1a2b3c)
+Timer precision: 41 ns
+example           fastest       │ slowest       │ median        │ mean          │ samples │ iters
+├─ add            0.5 ns        │ 1.2 ns        │ 0.6 ns        │ 0.6 ns        │ 100     │ 409600
+├─ copy                         │               │               │               │         │
+│  ╰─ 1024        2.7 µs        │ 3.5 µs        │ 2.8 µs        │ 2.8 µs        │ 100     │ 800
+│                 361.6 MB/s    │ 292.5 MB/s    │ 365.7 MB/s    │ 365.7 MB/s    │         │
+│                 alloc:        │               │               │               │         │
+│                   1           │ 1             │ 1             │ 1             │         │
+╰─ fibonacci                    │               │               │               │         │
+   ├─ 1           1.1 ns        │ 2.3 ns        │ 1.2 ns        │ 1.2 ns        │ 100     │ 204800
+   ╰─ 10          32.2 ns       │ 40.1 ns       │ 33.5 ns       │ 34.0 ns       │ 100     │ 12800
+
+";
+        let results = parse_divan_output(output);
+        let names: Vec<&str> = results.iter().map(|b| b.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec![
+                "example/add",
+                "example/copy/1024",
+                "example/fibonacci/1",
+                "example/fibonacci/10"
+            ]
+        );
+        assert!((results[1].estimate_ns - 2_800.0).abs() < 1e-6);
+        assert!((results[3].estimate_ns - 33.5).abs() < 1e-9);
+        assert!(results.iter().all(|b| b.source == BenchSource::Divan));
+    }
+
+    #[test]
+    fn parse_divan_ignores_text_without_table() {
+        assert!(parse_divan_output("running 0 tests\n├─ add 1 ns").is_empty());
+    }
+
+    // ---- iai-callgrind parsing tests ----
+
+    #[test]
+    fn parse_iai_callgrind_summary_counts() {
+        let output = "
+     Running benches/iai.rs (target/release/deps/iai-4d5e6f)
+lib_bench::bench_group::bench_fibonacci short:10
+  Instructions:                1734|1734            (No change)
+  L1 Hits:                     2359|2359            (No change)
+  L2 Hits:                        0|0               (No change)
+  RAM Hits:                       3|3               (No change)
+  Total read+write:            2362|2362            (No change)
+  Estimated Cycles:            2464|2464            (No change)
+lib_bench::bench_group::bench_fibonacci long:30
+  Instructions:            26214734|N/A             (*********)
+  L1 Hits:                 35638616|N/A             (*********)
+  LL Hits:                        2|N/A             (*********)
+  RAM Hits:                       4|N/A             (*********)
+  Estimated Cycles:        35638766|N/A             (*********)
+test tests::ok ... ok
+";
+        let results = parse_iai_callgrind_output(output);
+        assert_eq!(results.len(), 2);
+        assert_eq!(
+            results[0].name,
+            "lib_bench/bench_group/bench_fibonacci/long:30"
+        );
+        assert_eq!(
+            results[1].name,
+            "lib_bench/bench_group/bench_fibonacci/short:10"
+        );
+        assert_eq!(
+            results[1].callgrind,
+            Some(CallgrindCounts {
+                instructions: Some(1734),
+                l1_hits: Some(2359),
+                ll_hits: Some(0),
+                ram_hits: Some(3),
+                estimated_cycles: Some(2464),
+            })
+        );
+        assert_eq!(
+            results[0].callgrind.as_ref().unwrap().instructions,
+            Some(26_214_734)
+        );
+        assert_eq!(results[0].source, BenchSource::IaiCallgrind);
+    }
+
+    #[test]
+    fn parse_bench_output_combines_formats() {
+        let output = "
+test bench_add ... bench:         100 ns/iter (+/- 10)
+lib_bench::bench_add
+  Instructions:                  42|42              (No change)
+";
+        let results = parse_bench_output(output);
+        let sources: Vec<BenchSource> = results.iter().map(|b| b.source).collect();
+        assert_eq!(
+            sources,
+            vec![BenchSource::Libtest, BenchSource::IaiCallgrind]
+        );
+    }
+
     // ---- Command building tests ----
 
     #[test]
@@ -653,12 +987,14 @@ test result: ok. 0 passed; 0 failed; 0 ignored; 3 measured; 0 filtered out
                 estimate_ns: 5_000_000.0, // 5ms
                 error_ns: Some(100_000.0),
                 source: BenchSource::Criterion,
+                callgrind: None,
             },
             ParsedBenchmark {
                 name: "bench_b".into(),
                 estimate_ns: 10_000_000.0, // 10ms
                 error_ns: Some(200_000.0),
                 source: BenchSource::Criterion,
+                callgrind: None,
             },
         ];
 
@@ -708,12 +1044,14 @@ test result: ok. 0 passed; 0 failed; 0 ignored; 3 measured; 0 filtered out
                 estimate_ns: 5_000_000.0,
                 error_ns: Some(100_000.0),
                 source: BenchSource::Libtest,
+                callgrind: None,
             },
             ParsedBenchmark {
                 name: "bench_b".into(),
                 estimate_ns: 10_000_000.0,
                 error_ns: None,
                 source: BenchSource::Libtest,
+                callgrind: None,
             },
         ];
 
@@ -746,6 +1084,60 @@ test result: ok. 0 passed; 0 failed; 0 ignored; 3 measured; 0 filtered out
         assert_eq!(receipts[1].samples[0].wall_ms, 10);
     }
 
+    #[test]
+    fn callgrind_counts_become_deterministic_metrics() {
+        struct FakeClock;
+        impl Clock for FakeClock {
+            fn now_rfc3339(&self) -> String {
+                "2024-01-01T00:00:00Z".to_string()
+            }
+        }
+
+        let benchmarks = vec![ParsedBenchmark {
+            name: "iai/bench".into(),
+            estimate_ns: 0.0,
+            error_ns: None,
+            source: BenchSource::IaiCallgrind,
+            callgrind: Some(CallgrindCounts {
+                instructions: Some(1734),
+                l1_hits: Some(2359),
+                ll_hits: Some(0),
+                ram_hits: Some(3),
+                estimated_cycles: Some(2464),
+            }),
+        }];
+
+        let tool = ToolInfo {
+            name: "perfgate".into(),
+            version: "0.1.0".into(),
+        };
+        let host = HostInfo {
+            os: "linux".into(),
+            arch: "x86_64".into(),
+            cpu_count: None,
+            memory_bytes: None,
+            hostname_hash: None,
+        };
+
+        let receipts = benchmarks_to_individual_receipts(
+            &benchmarks,
+            &tool,
+            &host,
+            &FakeClock,
+            &["cargo".into(), "bench".into()],
+        )
+        .unwrap();
+
+        let receipt = &receipts[0];
+        assert_eq!(receipt.samples[0].wall_ms, 0);
+        assert_eq!(receipt.stats.instructions.as_ref().unwrap().median, 1734);
+        assert_eq!(
+            receipt.stats.estimated_cycles.as_ref().unwrap().median,
+            2464
+        );
+        assert_eq!(receipt.stats.ram_hits.as_ref().unwrap().median, 3);
+    }
+
     // ---- Criterion file system tests ----
 
     #[test]
@@ -873,6 +1265,7 @@ test result: ok. 0 passed; 0 failed; 0 ignored; 3 measured; 0 filtered out
             estimate_ns: 500.0, // 0.0005 ms
             error_ns: None,
             source: BenchSource::Libtest,
+            callgrind: None,
         }];
 
         let tool = ToolInfo {
@@ -0,0 +1,231 @@
+//! Change-aware bench selection for `check --changed-since`.
+//!
+//! Benches declare the files they depend on with `paths` globs. Given the
+//! files changed since a git ref, [`decide_bench_for_changes`] decides whether
+//! a bench still needs to run; skipped benches get a report from
+//! [`build_skipped_report`] so they stay visible in the output.
+
+use glob::{MatchOptions, Pattern};
+use perfgate_types::{
+    BenchConfigFile, PerfgateReport, REPORT_SCHEMA_V1, ReportSummary, VERDICT_REASON_UNCHANGED,
+    Verdict, VerdictCounts, VerdictStatus,
+};
+
+/// `*` and `?` stay within one path component; `**` crosses directories.
+const PATH_MATCH_OPTIONS: MatchOptions = MatchOptions {
+    case_sensitive: true,
+    require_literal_separator: true,
+    require_literal_leading_dot: false,
+};
+
+/// Whether a bench runs under `check --changed-since`, with the reason.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChangeDecision {
+    /// The bench runs.
+    Run(String),
+    /// The bench is skipped.
+    Skip(String),
+}
+
+/// Decide whether `bench` runs given the files changed since `since`.
+///
+/// Benches marked `always = true` and benches without `paths` always run.
+/// Changed files are repository-relative paths as printed by `git diff
+/// --name-only`.
+///
+/// # Examples
+///
+/// ```
+/// use perfgate::app::changed_paths::{ChangeDecision, decide_bench_for_changes};
+/// use perfgate_types::BenchConfigFile;
+///
+/// let bench: BenchConfigFile = toml::from_str(
+///     "name = \"parse\"\ncommand = [\"true\"]\npaths = [\"crates/parser/**\"]\n",
+/// )
+/// .unwrap();
+///
+/// let changed = vec!["README.md".to_string()];
+/// assert!(matches!(
+///     decide_bench_for_changes(&bench, &changed, "origin/main").unwrap(),
+///     ChangeDecision::Skip(_)
+/// ));
+///
+/// let changed = vec!["crates/parser/src/lib.rs".to_string()];
+/// assert!(matches!(
+///     decide_bench_for_changes(&bench, &changed, "origin/main").unwrap(),
+///     ChangeDecision::Run(_)
+/// ));
+/// ```
+pub fn decide_bench_for_changes(
+    bench: &BenchConfigFile,
+    changed_files: &[String],
+    since: &str,
+) -> anyhow::Result<ChangeDecision> {
+    if bench.always {
+        return Ok(ChangeDecision::Run("always = true".to_string()));
+    }
+    if bench.paths.is_empty() {
+        return Ok(ChangeDecision::Run("no paths configured".to_string()));
+    }
+
+    for raw in &bench.paths {
+        let pattern = Pattern::new(raw.trim_start_matches("./")).map_err(|err| {
+            anyhow::anyhow!(
+                "bench '{}' paths pattern '{}' is invalid: {}",
+                bench.name,
+                raw,
+                err
+            )
+        })?;
+        if let Some(file) = changed_files
+            .iter()
+            .find(|file| pattern.matches_with(file, PATH_MATCH_OPTIONS))
+        {
+            return Ok(ChangeDecision::Run(format!("'{}' matches '{}'", file, raw)));
+        }
+    }
+
+    Ok(ChangeDecision::Skip(format!(
+        "no files changed since {} match its paths",
+        since
+    )))
+}
+
+/// Report for a bench skipped by `check --changed-since`.
+pub fn build_skipped_report(reason: &str) -> PerfgateReport {
+    PerfgateReport {
+        report_type: REPORT_SCHEMA_V1.to_string(),
+        verdict: Verdict {
+            status: VerdictStatus::Skip,
+            counts: VerdictCounts {
+                pass: 0,
+                warn: 0,
+                fail: 0,
+                skip: 1,
+            },
+            reasons: vec![VERDICT_REASON_UNCHANGED.to_string()],
+        },
+        compare: None,
+        findings: Vec::new(),
+        summary: ReportSummary {
+            pass_count: 0,
+            warn_count: 0,
+            fail_count: 0,
+            skip_count: 1,
+            total_count: 1,
+        },
+        complexity: None,
+        profile_path: None,
+        confirmation: None,
+        skip_reason: Some(reason.to_string()),
+        baseline_selection: None,
+    }
+}
+
+/// Markdown comment for a bench skipped by `check --changed-since`.
+pub fn render_skipped_markdown(bench_name: &str, reason: &str) -> String {
+    format!(
+        "## perfgate: skipped\n\n**Bench:** `{}`\n\nNot run: {}.\n",
+        bench_name, reason
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bench(paths: &[&str], always: bool) -> BenchConfigFile {
+        BenchConfigFile {
+            name: "parse".to_string(),
+            cwd: None,
+            work: None,
+            timeout: None,
+            command: vec!["true".to_string()],
+            tags: Vec::new(),
+            paths: paths.iter().map(|path| path.to_string()).collect(),
+            always,
+            repeat: None,
+            warmup: None,
+            metrics: None,
+            budgets: None,
+            scaling: None,
+            matrix: None,
+            matrix_overrides: None,
+            service: None,
+            load: None,
+            ready: None,
+            ready_timeout: None,
+            terminate_on_ready: false,
+        }
+    }
+
+    fn changed(files: &[&str]) -> Vec<String> {
+        files.iter().map(|file| file.to_string()).collect()
+    }
+
+    #[test]
+    fn paths_select_benches_by_changed_files() {
+        let parser = bench(&["crates/parser/**", "./benches/*.rs"], false);
+
+        assert_eq!(
+            decide_bench_for_changes(&parser, &changed(&["crates/parser/src/lib.rs"]), "main")
+                .unwrap(),
+            ChangeDecision::Run("'crates/parser/src/lib.rs' matches 'crates/parser/**'".into())
+        );
+        assert!(matches!(
+            decide_bench_for_changes(&parser, &changed(&["benches/parse.rs"]), "main").unwrap(),
+            ChangeDecision::Run(_)
+        ));
+        assert_eq!(
+            decide_bench_for_changes(
+                &parser,
+                &changed(&[
+                    "README.md",
+                    "benches/nested/parse.rs",
+                    "crates/parser-extra/a.rs"
+                ]),
+                "main"
+            )
+            .unwrap(),
+            ChangeDecision::Skip("no files changed since main match its paths".into())
+        );
+    }
+
+    #[test]
+    fn always_and_unscoped_benches_run() {
+        let always = bench(&["crates/parser/**"], true);
+        assert_eq!(
+            decide_bench_for_changes(&always, &changed(&["README.md"]), "main").unwrap(),
+            ChangeDecision::Run("always = true".into())
+        );
+
+        let unscoped = bench(&[], false);
+        assert_eq!(
+            decide_bench_for_changes(&unscoped, &[], "main").unwrap(),
+            ChangeDecision::Run("no paths configured".into())
+        );
+    }
+
+    #[test]
+    fn invalid_patterns_are_reported() {
+        let invalid = bench(&["crates/[parser"], false);
+        let err = decide_bench_for_changes(&invalid, &changed(&["a.rs"]), "main").unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("bench 'parse' paths pattern 'crates/[parser' is invalid"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn skipped_report_records_reason() {
+        let report = build_skipped_report("no files changed since main match its paths");
+        assert_eq!(report.verdict.status, VerdictStatus::Skip);
+        assert_eq!(report.verdict.reasons, vec!["unchanged".to_string()]);
+        assert_eq!(report.summary.skip_count, 1);
+        assert_eq!(
+            report.skip_reason.as_deref(),
+            Some("no files changed since main match its paths")
+        );
+    }
+}
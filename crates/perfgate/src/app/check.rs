@@ -8,29 +8,42 @@
 //! 5. Compares results
 //! 6. Generates all artifacts (run.json, compare.json, report.json, comment.md)
 
-use crate::app::runtime::{CommandSpec, HostProbe, ProcessRunner};
+use crate::app::runtime::{
+    CommandSpec, HostProbe, HttpLoadRunner, HttpLoadSpec, ProcessRunner, ReadyProbe,
+    RunningService, ServiceRunner, ServiceSpec,
+};
 use crate::app::{
     Clock, CompareRequest, CompareUseCase, RunBenchRequest, RunBenchUseCase, format_metric,
     format_pct,
 };
-use crate::domain::SignificancePolicy;
+use crate::domain::budget::{
+    AUTO_THRESHOLD_DEFAULT_MAX, AUTO_THRESHOLD_DEFAULT_MIN, derive_auto_threshold,
+};
 use crate::domain::scaling::{
     SizeMeasurement, classify_complexity, is_complexity_degraded, parse_complexity,
 };
+use crate::domain::{
+    SignificancePolicy, apply_confirmation, apply_false_discovery_rate, compare_paired_stats,
+    evaluate_budget, metric_value_from_run,
+};
 use anyhow::Context;
 use perfgate_types::{
-    BenchConfigFile, Budget, CHECK_ID_BASELINE, CHECK_ID_BUDGET, CHECK_ID_COMPLEXITY,
-    CompareReceipt, CompareRef, ComplexityGateResult, ComplexityGateStatus, ConfigFile,
-    ConfigValidationError, FINDING_CODE_BASELINE_MISSING, FINDING_CODE_COMPLEXITY_FAIL,
+    AutoThresholdSource, BenchConfigFile, BenchWork, Budget, BudgetThreshold, CHECK_ID_BASELINE,
+    CHECK_ID_BUDGET, CHECK_ID_COMPLEXITY, CompareReceipt, CompareRef, ComplexityGateResult,
+    ComplexityGateStatus, ConfigFile, ConfigValidationError, Confirmation, ConfirmationMode,
+    FINDING_CODE_BASELINE_MISSING, FINDING_CODE_COMPLEXITY_FAIL,
     FINDING_CODE_COMPLEXITY_INCONCLUSIVE, FINDING_CODE_METRIC_FAIL, FINDING_CODE_METRIC_WARN,
-    FindingData, HostMismatchPolicy, Metric, MetricStatistic, MetricStatus, PerfgateError,
-    PerfgateReport, REPORT_SCHEMA_V1, ReportFinding, ReportSummary, RunReceipt, ScalingConfig,
-    Severity, ToolInfo, VERDICT_REASON_COMPLEXITY_EXPECTED_EXCEEDED,
-    VERDICT_REASON_COMPLEXITY_FIT_LOW_CONFIDENCE, VERDICT_REASON_COMPLEXITY_MEASUREMENT_INCOMPLETE,
-    VERDICT_REASON_NO_BASELINE, Verdict, VerdictCounts, VerdictStatus,
+    FindingData, HostMismatchPolicy, HttpLoadConfig, HttpLoadMeta, MergeBaseSelection, Metric,
+    MetricStatistic, MetricStatus, PairedRunReceipt, PerfgateError, PerfgateReport,
+    REPORT_SCHEMA_V1, ReportFinding, ReportSummary, RunConcurrency, RunReceipt, SampleInterleave,
+    ScalingConfig, ServiceConfig, ServiceMeta, Severity, ToolInfo,
+    VERDICT_REASON_COMPLEXITY_EXPECTED_EXCEEDED, VERDICT_REASON_COMPLEXITY_FIT_LOW_CONFIDENCE,
+    VERDICT_REASON_COMPLEXITY_MEASUREMENT_INCOMPLETE, VERDICT_REASON_NO_BASELINE, Verdict,
+    VerdictCounts, VerdictStatus,
 };
 use std::collections::BTreeMap;
 use std::path::PathBuf;
+use std::time::Duration;
 
 /// Request for the check use case.
 #[derive(Debug, Clone)]
@@ -38,6 +51,9 @@ pub struct CheckRequest {
     /// The loaded configuration file.
     pub config: ConfigFile,
 
+    /// Config profile applied to `config`, recorded in the run receipt.
+    pub profile: Option<String>,
+
     /// Name of the bench to run.
     pub bench_name: String,
 
@@ -50,6 +66,10 @@ pub struct CheckRequest {
     /// Path to the baseline file (for reference in compare receipt).
     pub baseline_path: Option<PathBuf>,
 
+    /// Commit chosen by merge-base aware baseline selection, recorded in the
+    /// report.
+    pub baseline_selection: Option<MergeBaseSelection>,
+
     /// If true, fail if baseline is missing.
     pub require_baseline: bool,
 
@@ -85,6 +105,23 @@ pub struct CheckRequest {
 
     /// Require significance to escalate warn/fail statuses.
     pub require_significance: bool,
+
+    /// Historical run receipts of this bench, used by `threshold = "auto"` budgets.
+    pub threshold_history: Option<ThresholdHistory>,
+
+    /// Parallel execution slot (`check --jobs`): the bench is pinned to its
+    /// CPUs and the settings are recorded in the run receipt.
+    pub concurrency: Option<RunConcurrency>,
+
+    /// Sample interleaving (`check --interleave`), recorded in the run receipt.
+    pub interleave: Option<SampleInterleave>,
+}
+
+/// Historical run receipts of one bench, with where they came from.
+#[derive(Debug, Clone)]
+pub struct ThresholdHistory {
+    pub source: AutoThresholdSource,
+    pub receipts: Vec<RunReceipt>,
 }
 
 /// Outcome of the check use case.
@@ -148,35 +185,68 @@ impl<R: ProcessRunner + Clone, H: HostProbe + Clone, C: Clock + Clone> CheckUseC
         let mut warnings = Vec::new();
 
         // 1. Find the bench config by name
-        let bench_config = req
-            .config
-            .benches
-            .iter()
-            .find(|b| b.name == req.bench_name)
-            .ok_or_else(|| {
-                ConfigValidationError::BenchName(format!(
-                    "bench '{}' not found in config",
-                    req.bench_name
-                ))
-            })?;
+        let bench_config = find_bench(&req)?;
 
         // 2. Build run request from config
         let run_request = self.build_run_request(bench_config, &req)?;
 
-        // 3. Run the benchmark
-        let run_usecase = RunBenchUseCase::new(
-            self.runner.clone(),
-            self.host_probe.clone(),
-            self.clock.clone(),
-            req.tool.clone(),
-        );
-        let run_outcome = run_usecase.execute(run_request)?;
-        let run_receipt = run_outcome.receipt;
-        let complexity = bench_config
-            .scaling
+        // 3. Run the benchmark, with its server up for service benches
+        let service = bench_config
+            .service
+            .as_ref()
+            .map(|service| self.start_service(bench_config, service, &req))
+            .transpose()?;
+        let load = bench_config
+            .load
             .as_ref()
-            .map(|scaling| self.evaluate_complexity_gate(bench_config, scaling, &req))
+            .map(|load| load_spec(bench_config, load))
             .transpose()?;
+        let load_runner = load
+            .clone()
+            .map(HttpLoadRunner::new)
+            .transpose()
+            .with_context(|| format!("invalid load for bench '{}'", bench_config.name))?;
+        let (mut run_receipt, complexity) = match (&service, load_runner) {
+            (Some(service), Some(load_runner)) => self.measure(
+                ServiceRunner::new(load_runner, service),
+                bench_config,
+                run_request,
+                &req,
+            )?,
+            (Some(service), None) => self.measure(
+                ServiceRunner::new(self.runner.clone(), service),
+                bench_config,
+                run_request,
+                &req,
+            )?,
+            (None, Some(load_runner)) => {
+                self.measure(load_runner, bench_config, run_request, &req)?
+            }
+            (None, None) => self.measure(self.runner.clone(), bench_config, run_request, &req)?,
+        };
+        run_receipt.bench.load = load.map(|load| HttpLoadMeta {
+            url: load.url,
+            method: load.method,
+            concurrency: load.concurrency,
+            duration_ms: load.duration.map(|duration| duration.as_millis() as u64),
+            requests: load.requests,
+            rate: load.rate,
+        });
+        run_receipt.run.profile = req.profile.clone();
+        run_receipt.run.concurrency = req.concurrency.clone();
+        run_receipt.run.interleave = req.interleave.clone();
+        if let (Some(service), Some(config)) = (service, &bench_config.service) {
+            run_receipt.bench.service = Some(ServiceMeta {
+                command: config.command.clone(),
+                ready_ms: service.ready_ms(),
+            });
+            if !service.stop()? {
+                warnings.push(format!(
+                    "service for bench '{}' did not exit within its shutdown timeout and was killed",
+                    req.bench_name
+                ));
+            }
+        }
 
         // 4. Write run receipt
         let run_path = req.out_dir.join("run.json");
@@ -192,6 +262,7 @@ impl<R: ProcessRunner + Clone, H: HostProbe + Clone, C: Clock + Clone> CheckUseC
                 &run_receipt,
                 req.noise_threshold,
                 req.noise_policy,
+                req.threshold_history.as_ref(),
             )?;
 
             // Compare
@@ -214,10 +285,12 @@ impl<R: ProcessRunner + Clone, H: HostProbe + Clone, C: Clock + Clone> CheckUseC
                 baseline_ref: CompareRef {
                     path: req.baseline_path.as_ref().map(|p| p.display().to_string()),
                     run_id: Some(baseline.run.id.clone()),
+                    profile: None,
                 },
                 current_ref: CompareRef {
                     path: Some(run_path.display().to_string()),
                     run_id: Some(run_receipt.run.id.clone()),
+                    profile: None,
                 },
                 tool: req.tool.clone(),
                 host_mismatch_policy: req.host_mismatch_policy,
@@ -231,6 +304,15 @@ impl<R: ProcessRunner + Clone, H: HostProbe + Clone, C: Clock + Clone> CheckUseC
                     warnings.push(format!("host mismatch: {}", reason));
                 }
             }
+            if let Some(mismatch) = &compare_result.profile_mismatch {
+                warnings.push(format!("profile mismatch: {}", mismatch));
+            }
+            if let Some(mismatch) = &compare_result.concurrency_mismatch {
+                warnings.push(format!("concurrency mismatch: {}", mismatch));
+            }
+            for warning in &baseline.run.noise_warnings {
+                warnings.push(format!("baseline noise: {}", warning));
+            }
 
             // Build report
             let report = build_report(&compare_result.receipt);
@@ -258,7 +340,11 @@ impl<R: ProcessRunner + Clone, H: HostProbe + Clone, C: Clock + Clone> CheckUseC
             (None, None, report)
         };
 
-        let (compare_receipt, report) = apply_complexity_gate(compare_receipt, report, complexity);
+        let (compare_receipt, mut report) =
+            apply_complexity_gate(compare_receipt, report, complexity);
+        if compare_receipt.is_some() {
+            report.baseline_selection = req.baseline_selection.clone();
+        }
 
         // 6. Generate markdown
         let mut markdown = if let Some(compare) = &compare_receipt {
@@ -266,6 +352,9 @@ impl<R: ProcessRunner + Clone, H: HostProbe + Clone, C: Clock + Clone> CheckUseC
         } else {
             render_no_baseline_markdown(&run_receipt, &warnings)
         };
+        if let Some(selection) = &report.baseline_selection {
+            markdown.push_str(&crate::app::render_baseline_selection_section(selection));
+        }
         if let Some(complexity) = &report.complexity {
             markdown.push_str(&crate::app::render_complexity_section(complexity));
         }
@@ -274,17 +363,7 @@ impl<R: ProcessRunner + Clone, H: HostProbe + Clone, C: Clock + Clone> CheckUseC
 
         // 7. Determine exit code
         let (failed, exit_code) = if let Some(compare) = &compare_receipt {
-            match compare.verdict.status {
-                VerdictStatus::Pass | VerdictStatus::Skip => (false, 0),
-                VerdictStatus::Warn => {
-                    if req.fail_on_warn {
-                        (true, 3)
-                    } else {
-                        (false, 0)
-                    }
-                }
-                VerdictStatus::Fail => (true, 2),
-            }
+            exit_code_for_verdict(compare.verdict.status, req.fail_on_warn)
         } else {
             // No baseline - pass by default (unless require_baseline was set, which already bailed)
             (false, 0)
@@ -315,6 +394,81 @@ impl<R: ProcessRunner + Clone, H: HostProbe + Clone, C: Clock + Clone> CheckUseC
         })
     }
 
+    /// Run the bench's samples and its complexity gate with `runner`.
+    fn measure<P: ProcessRunner + Clone>(
+        &self,
+        runner: P,
+        bench: &BenchConfigFile,
+        run_request: RunBenchRequest,
+        req: &CheckRequest,
+    ) -> anyhow::Result<(RunReceipt, Option<ComplexityGateResult>)> {
+        let run_usecase = RunBenchUseCase::new(
+            runner.clone(),
+            self.host_probe.clone(),
+            self.clock.clone(),
+            req.tool.clone(),
+        );
+        let run_receipt = run_usecase.execute(run_request)?.receipt;
+        let complexity = bench
+            .scaling
+            .as_ref()
+            .map(|scaling| self.evaluate_complexity_gate(&runner, bench, scaling, req))
+            .transpose()?;
+        Ok((run_receipt, complexity))
+    }
+
+    /// Start the server of a service bench and wait until it is ready.
+    fn start_service(
+        &self,
+        bench: &BenchConfigFile,
+        service: &ServiceConfig,
+        req: &CheckRequest,
+    ) -> anyhow::Result<RunningService> {
+        let parse_timeout = |value: Option<&str>, default: Duration, field: &str| {
+            value.map_or(Ok(default), |s| {
+                humantime::parse_duration(s).with_context(|| {
+                    format!(
+                        "invalid service {} '{}' for bench '{}'",
+                        field, s, bench.name
+                    )
+                })
+            })
+        };
+        let spec = ServiceSpec {
+            argv: service.command.clone(),
+            cwd: service
+                .cwd
+                .as_ref()
+                .or(bench.cwd.as_ref())
+                .map(PathBuf::from),
+            env: req.env.clone(),
+            ready: service.ready.clone(),
+            ready_timeout: parse_timeout(
+                service.ready_timeout.as_deref(),
+                Duration::from_secs(30),
+                "ready_timeout",
+            )?,
+            shutdown_timeout: parse_timeout(
+                service.shutdown_timeout.as_deref(),
+                Duration::from_secs(5),
+                "shutdown_timeout",
+            )?,
+            cpu_affinity: req
+                .concurrency
+                .as_ref()
+                .map(|concurrency| concurrency.cpus.clone())
+                .unwrap_or_default(),
+        };
+        RunningService::start(&spec)
+            .with_context(|| format!("failed to start the service of bench '{}'", bench.name))
+    }
+
+    /// The run request `execute` measures the bench with, so callers can
+    /// schedule its samples themselves (`check --interleave`).
+    pub fn run_request(&self, req: &CheckRequest) -> anyhow::Result<RunBenchRequest> {
+        self.build_run_request(find_bench(req)?, req)
+    }
+
     fn build_run_request(
         &self,
         bench: &BenchConfigFile,
@@ -341,21 +495,50 @@ impl<R: ProcessRunner + Clone, H: HostProbe + Clone, C: Clock + Clone> CheckUseC
         // Resolve cwd
         let cwd = bench.cwd.as_ref().map(PathBuf::from);
 
+        // Ready probe: records time_to_ready_ms, waiting up to 30s by default
+        let ready = bench
+            .ready
+            .as_ref()
+            .map(|check| {
+                let timeout =
+                    bench
+                        .ready_timeout
+                        .as_deref()
+                        .map_or(Ok(Duration::from_secs(30)), |s| {
+                            humantime::parse_duration(s).with_context(|| {
+                                format!("invalid ready_timeout '{}' for bench '{}'", s, bench.name)
+                            })
+                        })?;
+                Ok::<_, anyhow::Error>(ReadyProbe {
+                    check: check.clone(),
+                    timeout,
+                    terminate: bench.terminate_on_ready,
+                })
+            })
+            .transpose()?;
+
         Ok(RunBenchRequest {
             name: bench.name.clone(),
             cwd,
             command: bench.command.clone(),
             repeat,
             warmup,
-            work_units: bench.work,
+            work_units: bench.work.as_ref().and_then(BenchWork::units),
             timeout,
             env: req.env.clone(),
             output_cap_bytes: req.output_cap_bytes,
             allow_nonzero: req.allow_nonzero,
             include_hostname_hash: false,
+            cpu_affinity: req
+                .concurrency
+                .as_ref()
+                .map(|concurrency| concurrency.cpus.clone())
+                .unwrap_or_default(),
+            ready,
         })
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn build_budgets(
         &self,
         bench: &BenchConfigFile,
@@ -364,6 +547,7 @@ impl<R: ProcessRunner + Clone, H: HostProbe + Clone, C: Clock + Clone> CheckUseC
         current: &RunReceipt,
         cli_noise_threshold: Option<f64>,
         cli_noise_policy: Option<perfgate_types::NoisePolicy>,
+        history: Option<&ThresholdHistory>,
     ) -> anyhow::Result<(BTreeMap<Metric, Budget>, BTreeMap<Metric, MetricStatistic>)> {
         let defaults = &config.defaults;
 
@@ -392,6 +576,42 @@ impl<R: ProcessRunner + Clone, H: HostProbe + Clone, C: Clock + Clone> CheckUseC
         if baseline.stats.throughput_per_s.is_some() && current.stats.throughput_per_s.is_some() {
             candidates.push(Metric::ThroughputPerS);
         }
+        if baseline.stats.latency_p50_us.is_some() && current.stats.latency_p50_us.is_some() {
+            candidates.push(Metric::LatencyP50Us);
+        }
+        if baseline.stats.latency_p95_us.is_some() && current.stats.latency_p95_us.is_some() {
+            candidates.push(Metric::LatencyP95Us);
+        }
+        if baseline.stats.latency_p99_us.is_some() && current.stats.latency_p99_us.is_some() {
+            candidates.push(Metric::LatencyP99Us);
+        }
+        if baseline.stats.error_rate.is_some() && current.stats.error_rate.is_some() {
+            candidates.push(Metric::ErrorRate);
+        }
+        if baseline.stats.time_to_ready_ms.is_some() && current.stats.time_to_ready_ms.is_some() {
+            candidates.push(Metric::TimeToReadyMs);
+        }
+        if baseline.stats.instructions.is_some() && current.stats.instructions.is_some() {
+            candidates.push(Metric::Instructions);
+        }
+        if baseline.stats.estimated_cycles.is_some() && current.stats.estimated_cycles.is_some() {
+            candidates.push(Metric::EstimatedCycles);
+        }
+        if baseline.stats.l1_hits.is_some() && current.stats.l1_hits.is_some() {
+            candidates.push(Metric::L1Hits);
+        }
+        if baseline.stats.ll_hits.is_some() && current.stats.ll_hits.is_some() {
+            candidates.push(Metric::LlHits);
+        }
+        if baseline.stats.ram_hits.is_some() && current.stats.ram_hits.is_some() {
+            candidates.push(Metric::RamHits);
+        }
+        if baseline.stats.alloc_bytes.is_some() && current.stats.alloc_bytes.is_some() {
+            candidates.push(Metric::AllocBytes);
+        }
+        if baseline.stats.allocs.is_some() && current.stats.allocs.is_some() {
+            candidates.push(Metric::Allocs);
+        }
 
         let mut budgets = BTreeMap::new();
         let mut metric_statistics = BTreeMap::new();
@@ -400,10 +620,42 @@ impl<R: ProcessRunner + Clone, H: HostProbe + Clone, C: Clock + Clone> CheckUseC
             // Check for per-bench budget override
             let override_opt = bench.budgets.as_ref().and_then(|b| b.get(&metric).cloned());
 
-            let threshold = override_opt
+            let statistic = override_opt
                 .as_ref()
-                .and_then(|o| o.threshold)
-                .unwrap_or(global_threshold);
+                .and_then(|o| o.statistic)
+                .unwrap_or(MetricStatistic::Median);
+
+            let (threshold, auto_threshold) = match override_opt.as_ref() {
+                Some(o) if o.threshold.is_some_and(BudgetThreshold::is_auto) => {
+                    let (source, values) = match history {
+                        Some(history) => (
+                            history.source,
+                            history
+                                .receipts
+                                .iter()
+                                .filter_map(|run| metric_value_from_run(run, metric, statistic))
+                                .collect(),
+                        ),
+                        None => (AutoThresholdSource::None, Vec::new()),
+                    };
+                    let (threshold, derivation) = derive_auto_threshold(
+                        &values,
+                        source,
+                        o.threshold_min.unwrap_or(AUTO_THRESHOLD_DEFAULT_MIN),
+                        o.threshold_max.unwrap_or(AUTO_THRESHOLD_DEFAULT_MAX),
+                        global_threshold,
+                    );
+                    (threshold, Some(derivation))
+                }
+                _ => (
+                    override_opt
+                        .as_ref()
+                        .and_then(|o| o.threshold)
+                        .and_then(BudgetThreshold::fixed)
+                        .unwrap_or(global_threshold),
+                    None,
+                ),
+            };
 
             let warn_factor = override_opt
                 .as_ref()
@@ -430,11 +682,6 @@ impl<R: ProcessRunner + Clone, H: HostProbe + Clone, C: Clock + Clone> CheckUseC
                 .and_then(|o| o.direction)
                 .unwrap_or_else(|| metric.default_direction());
 
-            let statistic = override_opt
-                .as_ref()
-                .and_then(|o| o.statistic)
-                .unwrap_or(MetricStatistic::Median);
-
             budgets.insert(
                 metric,
                 Budget {
@@ -443,6 +690,7 @@ impl<R: ProcessRunner + Clone, H: HostProbe + Clone, C: Clock + Clone> CheckUseC
                     noise_threshold,
                     noise_policy,
                     direction,
+                    auto_threshold,
                 },
             );
 
@@ -454,6 +702,7 @@ impl<R: ProcessRunner + Clone, H: HostProbe + Clone, C: Clock + Clone> CheckUseC
 
     fn evaluate_complexity_gate(
         &self,
+        runner: &impl ProcessRunner,
         bench: &BenchConfigFile,
         scaling: &ScalingConfig,
         req: &CheckRequest,
@@ -496,8 +745,14 @@ impl<R: ProcessRunner + Clone, H: HostProbe + Clone, C: Clock + Clone> CheckUseC
                         .map(humantime::parse_duration)
                         .transpose()?,
                     output_cap_bytes: req.output_cap_bytes,
+                    cpu_affinity: req
+                        .concurrency
+                        .as_ref()
+                        .map(|concurrency| concurrency.cpus.clone())
+                        .unwrap_or_default(),
+                    ready: None,
                 };
-                let run = match self.runner.run(&spec) {
+                let run = match runner.run(&spec) {
                     Ok(run) if run.exit_code == 0 || req.allow_nonzero => run,
                     _ => {
                         return Ok(ComplexityGateResult {
@@ -598,6 +853,191 @@ impl<R: ProcessRunner + Clone, H: HostProbe + Clone, C: Clock + Clone> CheckUseC
     }
 }
 
+/// The `[[bench]]` entry named by the request.
+fn find_bench(req: &CheckRequest) -> Result<&BenchConfigFile, ConfigValidationError> {
+    req.config
+        .benches
+        .iter()
+        .find(|b| b.name == req.bench_name)
+        .ok_or_else(|| {
+            ConfigValidationError::BenchName(format!(
+                "bench '{}' not found in config",
+                req.bench_name
+            ))
+        })
+}
+
+/// Map a compare verdict to `(failed, exit_code)`.
+fn exit_code_for_verdict(status: VerdictStatus, fail_on_warn: bool) -> (bool, i32) {
+    match status {
+        VerdictStatus::Pass | VerdictStatus::Skip => (false, 0),
+        VerdictStatus::Warn => {
+            if fail_on_warn {
+                (true, 3)
+            } else {
+                (false, 0)
+            }
+        }
+        VerdictStatus::Fail => (true, 2),
+    }
+}
+
+/// Apply a Benjamini–Hochberg correction across every significance test of a
+/// multi-bench check (`check --all`), before artifacts are written.
+///
+/// Each outcome with a compare receipt gets its report, markdown, and exit
+/// code re-derived from the corrected receipt.
+pub fn apply_check_false_discovery_rate<'a>(
+    outcomes: impl IntoIterator<Item = &'a mut CheckOutcome>,
+    require_significance: bool,
+    fail_on_warn: bool,
+) {
+    let mut outcomes: Vec<&mut CheckOutcome> = outcomes.into_iter().collect();
+    let mut receipts: Vec<&mut CompareReceipt> = outcomes
+        .iter_mut()
+        .filter_map(|outcome| outcome.compare_receipt.as_mut())
+        .collect();
+    apply_false_discovery_rate(&mut receipts, require_significance);
+
+    for outcome in outcomes {
+        rebuild_from_compare(outcome, fail_on_warn);
+    }
+}
+
+/// Evidence from re-measuring a bench whose check warned or failed.
+#[derive(Debug, Clone, Copy)]
+pub enum ConfirmationEvidence<'a> {
+    /// Compare receipt of a second check run against the same baseline.
+    Rerun(&'a CompareReceipt),
+    /// Paired run of a baseline command interleaved with the bench.
+    Paired(&'a PairedRunReceipt),
+}
+
+/// Fold a confirmation measurement into a warn/fail check outcome
+/// (`check --confirm`), before artifacts are written.
+///
+/// Each flagged metric keeps the less severe of its original and confirmation
+/// statuses. A paired confirmation only re-measures wall time; it counts as a
+/// regression when the paired difference is significant and exceeds the wall
+/// time budget, and other flagged metrics keep their status without counting
+/// toward [`Confirmation::confirmed`]. The report records a [`Confirmation`]
+/// block and the report, markdown, and exit code are re-derived from the
+/// confirmed receipt.
+pub fn apply_check_confirmation(
+    outcome: &mut CheckOutcome,
+    evidence: ConfirmationEvidence<'_>,
+    artifacts: Vec<String>,
+    fail_on_warn: bool,
+) {
+    let Some(compare) = outcome.compare_receipt.as_mut() else {
+        return;
+    };
+
+    let (mode, statuses) = match evidence {
+        ConfirmationEvidence::Rerun(rerun) => (
+            ConfirmationMode::Rerun,
+            rerun
+                .deltas
+                .iter()
+                .map(|(metric, delta)| (*metric, delta.status))
+                .collect(),
+        ),
+        ConfirmationEvidence::Paired(paired) => {
+            let mut statuses = BTreeMap::new();
+            if let Some(budget) = compare.budgets.get(&Metric::WallMs) {
+                let comparison = compare_paired_stats(&paired.stats);
+                let status = if comparison.is_significant {
+                    evaluate_budget(
+                        paired.stats.baseline_wall_ms.median as f64,
+                        paired.stats.current_wall_ms.median as f64,
+                        budget,
+                        None,
+                    )
+                    .map(|result| result.status)
+                    .ok()
+                } else {
+                    Some(MetricStatus::Pass)
+                };
+                if let Some(status) = status {
+                    statuses.insert(Metric::WallMs, status);
+                }
+            }
+            (ConfirmationMode::Paired, statuses)
+        }
+    };
+
+    let original_status = compare.verdict.status;
+    let metrics = apply_confirmation(compare, &statuses);
+    let confirmed = metrics.values().any(|metric| {
+        matches!(
+            metric.confirmation,
+            Some(MetricStatus::Warn | MetricStatus::Fail)
+        )
+    });
+
+    rebuild_from_compare(outcome, fail_on_warn);
+    if let Some(compare) = &outcome.compare_receipt
+        && is_regression_status(original_status)
+        && !is_regression_status(compare.verdict.status)
+    {
+        outcome.warnings.push(format!(
+            "{} regression was not confirmed by a {} re-measurement",
+            original_status.as_str(),
+            mode.as_str()
+        ));
+    }
+    let confirmation = Confirmation {
+        mode,
+        confirmed,
+        original_status,
+        metrics,
+        artifacts,
+    };
+    outcome
+        .markdown
+        .push_str(&crate::app::render_confirmation_section(&confirmation));
+    outcome.report.confirmation = Some(confirmation);
+}
+
+fn is_regression_status(status: VerdictStatus) -> bool {
+    matches!(status, VerdictStatus::Warn | VerdictStatus::Fail)
+}
+
+/// Re-derive the report, markdown, and exit code of an outcome from its
+/// (adjusted) compare receipt, keeping complexity, profile, confirmation, and
+/// baseline selection results.
+fn rebuild_from_compare(outcome: &mut CheckOutcome, fail_on_warn: bool) {
+    let Some(compare) = &outcome.compare_receipt else {
+        return;
+    };
+
+    let mut report = build_report(compare);
+    report.findings.extend(
+        outcome
+            .report
+            .findings
+            .iter()
+            .filter(|finding| finding.check_id == CHECK_ID_COMPLEXITY)
+            .cloned(),
+    );
+    report.complexity = outcome.report.complexity.take();
+    report.profile_path = outcome.report.profile_path.take();
+    report.confirmation = outcome.report.confirmation.take();
+    report.baseline_selection = outcome.report.baseline_selection.take();
+    outcome.report = report;
+
+    let mut markdown = crate::app::render_markdown(compare);
+    if let Some(selection) = &outcome.report.baseline_selection {
+        markdown.push_str(&crate::app::render_baseline_selection_section(selection));
+    }
+    if let Some(complexity) = &outcome.report.complexity {
+        markdown.push_str(&crate::app::render_complexity_section(complexity));
+    }
+    outcome.markdown = markdown;
+    (outcome.failed, outcome.exit_code) =
+        exit_code_for_verdict(compare.verdict.status, fail_on_warn);
+}
+
 fn median(values: &mut [f64]) -> Option<f64> {
     if values.is_empty() {
         return None;
@@ -754,6 +1194,9 @@ fn build_report(compare: &CompareReceipt) -> PerfgateReport {
         summary,
         complexity: None,
         profile_path: None,
+        confirmation: None,
+        skip_reason: None,
+        baseline_selection: None,
     }
 }
 
@@ -802,6 +1245,9 @@ fn build_no_baseline_report(run: &RunReceipt) -> PerfgateReport {
         },
         complexity: None,
         profile_path: None,
+        confirmation: None,
+        skip_reason: None,
+        baseline_selection: None,
     }
 }
 
@@ -813,6 +1259,35 @@ fn detect_high_cv(run: &RunReceipt) -> bool {
     run.stats.wall_ms.cv().map(|cv| cv > 0.30).unwrap_or(false)
 }
 
+/// The load generator settings of an HTTP load bench, with defaults applied.
+fn load_spec(bench: &BenchConfigFile, load: &HttpLoadConfig) -> anyhow::Result<HttpLoadSpec> {
+    let parse_duration = |value: Option<&str>, field: &str| {
+        value
+            .map(|s| {
+                humantime::parse_duration(s).with_context(|| {
+                    format!("invalid load {} '{}' for bench '{}'", field, s, bench.name)
+                })
+            })
+            .transpose()
+    };
+    Ok(HttpLoadSpec {
+        url: load.url.clone(),
+        method: load.method.clone().unwrap_or_else(|| "GET".to_string()),
+        body: load.body.clone(),
+        headers: load
+            .headers
+            .iter()
+            .map(|(name, value)| (name.clone(), value.clone()))
+            .collect(),
+        concurrency: load.concurrency.unwrap_or(1),
+        duration: parse_duration(load.duration.as_deref(), "duration")?,
+        requests: load.requests,
+        rate: load.rate,
+        timeout: parse_duration(load.timeout.as_deref(), "timeout")?
+            .unwrap_or(Duration::from_secs(10)),
+    })
+}
+
 /// Render markdown for the case when there is no baseline.
 fn render_no_baseline_markdown(run: &RunReceipt, warnings: &[String]) -> String {
     let mut out = String::new();
@@ -865,6 +1340,53 @@ fn render_no_baseline_markdown(run: &RunReceipt, warnings: &[String]) -> String
         ));
     }
 
+    for (key, latency) in [
+        ("latency_p50_us", &run.stats.latency_p50_us),
+        ("latency_p95_us", &run.stats.latency_p95_us),
+        ("latency_p99_us", &run.stats.latency_p99_us),
+    ] {
+        if let Some(latency) = latency {
+            out.push_str(&format!("| `{}` | {} us |\n", key, latency.median));
+        }
+    }
+
+    if let Some(error_rate) = &run.stats.error_rate {
+        out.push_str(&format!(
+            "| `error_rate` | {:.3} ratio |\n",
+            error_rate.median
+        ));
+    }
+
+    if let Some(time_to_ready) = &run.stats.time_to_ready_ms {
+        out.push_str(&format!(
+            "| `time_to_ready_ms` | {} ms |\n",
+            time_to_ready.median
+        ));
+    }
+
+    for (key, counter) in [
+        ("instructions", &run.stats.instructions),
+        ("estimated_cycles", &run.stats.estimated_cycles),
+        ("l1_hits", &run.stats.l1_hits),
+        ("ll_hits", &run.stats.ll_hits),
+        ("ram_hits", &run.stats.ram_hits),
+    ] {
+        if let Some(counter) = counter {
+            out.push_str(&format!("| `{}` | {} |\n", key, counter.median));
+        }
+    }
+
+    if let Some(alloc_bytes) = &run.stats.alloc_bytes {
+        out.push_str(&format!(
+            "| `alloc_bytes` | {} bytes/op |\n",
+            alloc_bytes.median
+        ));
+    }
+
+    if let Some(allocs) = &run.stats.allocs {
+        out.push_str(&format!("| `allocs` | {} /op |\n", allocs.median));
+    }
+
     if !warnings.is_empty() {
         out.push_str("\n**Warnings:**\n");
         for w in warnings {
@@ -880,9 +1402,10 @@ mod tests {
     use super::*;
     use crate::app::runtime::{AdapterError, CommandSpec, HostProbeOptions, RunResult};
     use perfgate_types::{
-        BaselineServerConfig, BenchConfigFile, BenchMeta, BudgetOverride, COMPARE_SCHEMA_V1,
-        CompareReceipt, DefaultsConfig, Delta, Direction, HostInfo, Metric, RunMeta, Sample,
-        ScalingConfig, Stats, U64Summary, Verdict, VerdictCounts,
+        AutoThresholdFallback, AutoThresholdKeyword, BaselineServerConfig, BenchConfigFile,
+        BenchMeta, BudgetOverride, COMPARE_SCHEMA_V1, CompareReceipt, DefaultsConfig, Delta,
+        Direction, HostInfo, Metric, RunMeta, Sample, ScalingConfig, Stats, U64Summary, Verdict,
+        VerdictCounts,
     };
     use std::sync::{Arc, Mutex};
     use std::time::Duration;
@@ -905,6 +1428,10 @@ mod tests {
                     memory_bytes: None,
                     hostname_hash: None,
                 },
+                profile: None,
+                concurrency: None,
+                interleave: None,
+                noise_warnings: Vec::new(),
             },
             bench: BenchMeta {
                 name: "test-bench".to_string(),
@@ -914,6 +1441,8 @@ mod tests {
                 warmup: 0,
                 work_units: None,
                 timeout_ms: None,
+                service: None,
+                load: None,
             },
             samples: vec![Sample {
                 wall_ms: wall_ms_median,
@@ -931,6 +1460,19 @@ mod tests {
                 binary_bytes: None,
                 stdout: None,
                 stderr: None,
+                latency_p50_us: None,
+                latency_p95_us: None,
+                latency_p99_us: None,
+                error_rate: None,
+                throughput_per_s: None,
+                time_to_ready_ms: None,
+                instructions: None,
+                estimated_cycles: None,
+                l1_hits: None,
+                ll_hits: None,
+                ram_hits: None,
+                alloc_bytes: None,
+                allocs: None,
             }],
             stats: Stats {
                 wall_ms: U64Summary::new(
@@ -948,6 +1490,19 @@ mod tests {
                 energy_uj: None,
                 binary_bytes: None,
                 throughput_per_s: None,
+                latency_p50_us: None,
+                latency_p95_us: None,
+                latency_p99_us: None,
+                error_rate: None,
+                time_to_ready_ms: None,
+                instructions: None,
+                estimated_cycles: None,
+                l1_hits: None,
+                ll_hits: None,
+                ram_hits: None,
+                alloc_bytes: None,
+                allocs: None,
+                custom: Default::default(),
             },
         }
     }
@@ -1027,6 +1582,12 @@ mod tests {
             binary_bytes: None,
             stdout: Vec::new(),
             stderr: Vec::new(),
+            latency_p50_us: None,
+            latency_p95_us: None,
+            latency_p99_us: None,
+            error_rate: None,
+            throughput_per_s: None,
+            time_to_ready_ms: None,
         }
     }
 
@@ -1042,6 +1603,10 @@ mod tests {
                 started_at: "2024-01-01T00:00:00Z".to_string(),
                 ended_at: "2024-01-01T00:00:01Z".to_string(),
                 host,
+                profile: None,
+                concurrency: None,
+                interleave: None,
+                noise_warnings: Vec::new(),
             },
             bench: BenchMeta {
                 name: "bench".to_string(),
@@ -1051,6 +1616,8 @@ mod tests {
                 warmup: 0,
                 work_units: None,
                 timeout_ms: None,
+                service: None,
+                load: None,
             },
             samples: Vec::new(),
             stats: Stats {
@@ -1065,6 +1632,19 @@ mod tests {
                 energy_uj: None,
                 binary_bytes: None,
                 throughput_per_s: None,
+                latency_p50_us: None,
+                latency_p95_us: None,
+                latency_p99_us: None,
+                error_rate: None,
+                time_to_ready_ms: None,
+                instructions: None,
+                estimated_cycles: None,
+                l1_hits: None,
+                ll_hits: None,
+                ram_hits: None,
+                alloc_bytes: None,
+                allocs: None,
+                custom: Default::default(),
             },
         }
     }
@@ -1083,6 +1663,7 @@ mod tests {
             out_dir: PathBuf::from("out"),
             baseline,
             baseline_path: None,
+            baseline_selection: None,
             require_baseline: false,
             fail_on_warn,
             tool: ToolInfo {
@@ -1096,6 +1677,10 @@ mod tests {
             significance_alpha: None,
             significance_min_samples: 8,
             require_significance: false,
+            threshold_history: None,
+            concurrency: None,
+            interleave: None,
+            profile: None,
         }
     }
 
@@ -1135,14 +1720,18 @@ mod tests {
                 warmup: 0,
                 work_units: None,
                 timeout_ms: None,
+                service: None,
+                load: None,
             },
             baseline_ref: CompareRef {
                 path: Some("baseline.json".to_string()),
                 run_id: Some("baseline-id".to_string()),
+                profile: None,
             },
             current_ref: CompareRef {
                 path: Some("current.json".to_string()),
                 run_id: Some("current-id".to_string()),
+                profile: None,
             },
             budgets,
             deltas,
@@ -1169,6 +1758,225 @@ mod tests {
         assert_eq!(report.summary.total_count, 1);
     }
 
+    fn fail_outcome_with_p_value(p_value: f64) -> CheckOutcome {
+        let mut budgets = BTreeMap::new();
+        budgets.insert(Metric::WallMs, Budget::new(0.20, 0.18, Direction::Lower));
+        let mut deltas = BTreeMap::new();
+        deltas.insert(
+            Metric::WallMs,
+            Delta {
+                baseline: 1000.0,
+                current: 1250.0,
+                ratio: 1.25,
+                pct: 0.25,
+                regression: 0.25,
+                cv: None,
+                noise_threshold: None,
+                statistic: MetricStatistic::Median,
+                significance: Some(perfgate_types::Significance {
+                    test: perfgate_types::SignificanceTest::WelchT,
+                    p_value: Some(p_value),
+                    alpha: 0.05,
+                    significant: p_value <= 0.05,
+                    baseline_samples: 8,
+                    current_samples: 8,
+                    ci_lower: None,
+                    ci_upper: None,
+                    adjusted_p_value: None,
+                }),
+                status: MetricStatus::Fail,
+            },
+        );
+        let compare = CompareReceipt {
+            schema: COMPARE_SCHEMA_V1.to_string(),
+            tool: ToolInfo {
+                name: "perfgate".to_string(),
+                version: "0.1.0".to_string(),
+            },
+            bench: BenchMeta {
+                name: "test-bench".to_string(),
+                cwd: None,
+                command: vec!["echo".to_string()],
+                repeat: 8,
+                warmup: 0,
+                work_units: None,
+                timeout_ms: None,
+                service: None,
+                load: None,
+            },
+            baseline_ref: CompareRef {
+                path: None,
+                run_id: None,
+                profile: None,
+            },
+            current_ref: CompareRef {
+                path: None,
+                run_id: None,
+                profile: None,
+            },
+            budgets,
+            deltas,
+            verdict: Verdict {
+                status: VerdictStatus::Fail,
+                counts: VerdictCounts {
+                    pass: 0,
+                    warn: 0,
+                    fail: 1,
+                    skip: 0,
+                },
+                reasons: vec!["wall_ms_fail".to_string()],
+            },
+        };
+
+        CheckOutcome {
+            run_receipt: make_run_receipt(1250),
+            run_path: PathBuf::from("out/run.json"),
+            report: build_report(&compare),
+            markdown: crate::app::render_markdown(&compare),
+            compare_receipt: Some(compare),
+            compare_path: Some(PathBuf::from("out/compare.json")),
+            report_path: PathBuf::from("out/report.json"),
+            markdown_path: PathBuf::from("out/comment.md"),
+            warnings: Vec::new(),
+            failed: true,
+            exit_code: 2,
+            suggest_paired: false,
+        }
+    }
+
+    #[test]
+    fn false_discovery_rate_rederives_check_outcomes() {
+        let mut outcomes = vec![
+            fail_outcome_with_p_value(0.001),
+            fail_outcome_with_p_value(0.04),
+            fail_outcome_with_p_value(0.9),
+        ];
+
+        apply_check_false_discovery_rate(&mut outcomes, true, false);
+
+        // Adjusted: 0.003, 0.06, 0.9 -> only the first bench still fails.
+        assert_eq!(outcomes[0].exit_code, 2);
+        assert_eq!(outcomes[0].report.verdict.status, VerdictStatus::Fail);
+        for outcome in &outcomes[1..] {
+            assert_eq!(outcome.exit_code, 0);
+            assert!(!outcome.failed);
+            assert_eq!(outcome.report.verdict.status, VerdictStatus::Pass);
+            assert!(outcome.report.findings.is_empty());
+        }
+        let compare = outcomes[1].report.compare.as_ref().unwrap();
+        let sig = compare.deltas[&Metric::WallMs]
+            .significance
+            .as_ref()
+            .unwrap();
+        assert!((sig.adjusted_p_value.unwrap() - 0.06).abs() < 1e-12);
+    }
+
+    #[test]
+    fn confirmation_rerun_settles_check_outcome() {
+        let mut cleared = fail_outcome_with_p_value(0.001);
+        let mut rerun = cleared.compare_receipt.clone().unwrap();
+        rerun.deltas.get_mut(&Metric::WallMs).unwrap().status = MetricStatus::Pass;
+
+        apply_check_confirmation(
+            &mut cleared,
+            ConfirmationEvidence::Rerun(&rerun),
+            vec!["confirm/compare.json".to_string()],
+            false,
+        );
+
+        assert_eq!(cleared.exit_code, 0);
+        assert_eq!(cleared.report.verdict.status, VerdictStatus::Pass);
+        let confirmation = cleared.report.confirmation.as_ref().unwrap();
+        assert!(!confirmation.confirmed);
+        assert_eq!(confirmation.mode, ConfirmationMode::Rerun);
+        assert_eq!(confirmation.original_status, VerdictStatus::Fail);
+        assert_eq!(
+            confirmation.metrics[&Metric::WallMs].confirmation,
+            Some(MetricStatus::Pass)
+        );
+        assert!(cleared.markdown.contains("regression not confirmed"));
+        assert!(cleared.warnings[0].contains("not confirmed"));
+
+        let mut reproduced = fail_outcome_with_p_value(0.001);
+        let rerun = reproduced.compare_receipt.clone().unwrap();
+        apply_check_confirmation(
+            &mut reproduced,
+            ConfirmationEvidence::Rerun(&rerun),
+            Vec::new(),
+            false,
+        );
+        assert_eq!(reproduced.exit_code, 2);
+        assert!(reproduced.report.confirmation.as_ref().unwrap().confirmed);
+        assert!(reproduced.warnings.is_empty());
+    }
+
+    #[test]
+    fn confirmation_paired_ignores_metrics_it_did_not_measure() {
+        let mut outcome = fail_outcome_with_p_value(0.001);
+        let compare = outcome.compare_receipt.as_mut().unwrap();
+        let mut rss = compare.deltas[&Metric::WallMs].clone();
+        rss.significance = None;
+        compare.deltas.insert(Metric::MaxRssKb, rss);
+        compare
+            .budgets
+            .insert(Metric::MaxRssKb, Budget::new(0.20, 0.18, Direction::Lower));
+        compare.verdict.counts.fail = 2;
+        let paired = PairedRunReceipt {
+            schema: perfgate_types::PAIRED_SCHEMA_V1.to_string(),
+            tool: compare.tool.clone(),
+            run: make_run_receipt(1250).run,
+            bench: perfgate_types::PairedBenchMeta {
+                name: "test-bench".to_string(),
+                cwd: None,
+                baseline_command: vec!["echo".to_string()],
+                current_command: vec!["echo".to_string()],
+                repeat: 8,
+                warmup: 0,
+                work_units: None,
+                timeout_ms: None,
+            },
+            samples: Vec::new(),
+            stats: perfgate_types::PairedStats {
+                baseline_wall_ms: U64Summary::new(1000, 990, 1010),
+                current_wall_ms: U64Summary::new(1002, 990, 1015),
+                wall_diff_ms: perfgate_types::PairedDiffSummary {
+                    mean: 2.0,
+                    median: 2.0,
+                    std_dev: 20.0,
+                    min: -30.0,
+                    max: 30.0,
+                    count: 8,
+                    significance: None,
+                },
+                baseline_max_rss_kb: None,
+                current_max_rss_kb: None,
+                rss_diff_kb: None,
+                baseline_throughput_per_s: None,
+                current_throughput_per_s: None,
+                throughput_diff_per_s: None,
+            },
+            noise_diagnostics: None,
+        };
+
+        apply_check_confirmation(
+            &mut outcome,
+            ConfirmationEvidence::Paired(&paired),
+            Vec::new(),
+            false,
+        );
+
+        // max_rss_kb still fails, but the paired run never measured it.
+        assert_eq!(outcome.report.verdict.status, VerdictStatus::Fail);
+        let confirmation = outcome.report.confirmation.as_ref().unwrap();
+        assert!(!confirmation.confirmed);
+        assert_eq!(
+            confirmation.metrics[&Metric::WallMs].confirmation,
+            Some(MetricStatus::Pass)
+        );
+        assert_eq!(confirmation.metrics[&Metric::MaxRssKb].confirmation, None);
+        assert!(outcome.markdown.contains("not re-measured"));
+    }
+
     #[test]
     fn build_report_normalizes_higher_is_better_regression() {
         let mut budgets = BTreeMap::new();
@@ -1208,14 +2016,18 @@ mod tests {
                 warmup: 0,
                 work_units: None,
                 timeout_ms: None,
+                service: None,
+                load: None,
             },
             baseline_ref: CompareRef {
                 path: Some("baseline.json".to_string()),
                 run_id: Some("baseline-id".to_string()),
+                profile: None,
             },
             current_ref: CompareRef {
                 path: Some("current.json".to_string()),
                 run_id: Some("current-id".to_string()),
+                profile: None,
             },
             budgets,
             deltas,
@@ -1301,7 +2113,7 @@ mod tests {
         let bench = BenchConfigFile {
             name: "bench".to_string(),
             cwd: Some("some/dir".to_string()),
-            work: Some(42),
+            work: Some(BenchWork::Units(42)),
             timeout: Some("2s".to_string()),
             command: vec!["echo".to_string(), "ok".to_string()],
             repeat: None,
@@ -1310,6 +2122,16 @@ mod tests {
             budgets: None,
 
             scaling: None,
+            matrix: None,
+            matrix_overrides: None,
+            service: None,
+            tags: Vec::new(),
+            paths: Vec::new(),
+            always: false,
+            load: None,
+            ready: None,
+            ready_timeout: None,
+            terminate_on_ready: false,
         };
 
         let config = ConfigFile {
@@ -1324,6 +2146,8 @@ mod tests {
                 baseline_dir: None,
                 baseline_pattern: None,
                 markdown_template: None,
+                baseline_history_dir: None,
+                merge_base: None,
             },
             baseline_server: BaselineServerConfig::default(),
             decision_policy: perfgate_types::DecisionPolicyConfig::default(),
@@ -1331,6 +2155,8 @@ mod tests {
             ratchet: None,
             scenarios: Vec::new(),
             benches: vec![bench.clone()],
+            extends: Vec::new(),
+            profiles: Default::default(),
         };
 
         let req = CheckRequest {
@@ -1341,6 +2167,7 @@ mod tests {
             out_dir: PathBuf::from("out"),
             baseline: None,
             baseline_path: None,
+            baseline_selection: None,
             require_baseline: false,
             fail_on_warn: false,
             tool: ToolInfo {
@@ -1354,6 +2181,10 @@ mod tests {
             significance_alpha: None,
             significance_min_samples: 8,
             require_significance: false,
+            threshold_history: None,
+            concurrency: None,
+            interleave: None,
+            profile: None,
         };
 
         let usecase = CheckUseCase::new(
@@ -1393,6 +2224,16 @@ mod tests {
             budgets: None,
 
             scaling: None,
+            matrix: None,
+            matrix_overrides: None,
+            service: None,
+            tags: Vec::new(),
+            paths: Vec::new(),
+            always: false,
+            load: None,
+            ready: None,
+            ready_timeout: None,
+            terminate_on_ready: false,
         };
         let config = ConfigFile::default();
         let req = make_check_request(config, None, HostMismatchPolicy::Warn, false);
@@ -1425,7 +2266,9 @@ mod tests {
             BudgetOverride {
                 noise_threshold: None,
                 noise_policy: None,
-                threshold: Some(0.3),
+                threshold: Some(BudgetThreshold::Fixed(0.3)),
+                threshold_min: None,
+                threshold_max: None,
                 direction: Some(Direction::Higher),
                 warn_factor: Some(0.8),
                 statistic: Some(MetricStatistic::P95),
@@ -1444,6 +2287,16 @@ mod tests {
             budgets: Some(overrides),
 
             scaling: None,
+            matrix: None,
+            matrix_overrides: None,
+            service: None,
+            tags: Vec::new(),
+            paths: Vec::new(),
+            always: false,
+            load: None,
+            ready: None,
+            ready_timeout: None,
+            terminate_on_ready: false,
         };
 
         let config = ConfigFile {
@@ -1458,6 +2311,8 @@ mod tests {
                 baseline_dir: None,
                 baseline_pattern: None,
                 markdown_template: None,
+                baseline_history_dir: None,
+                merge_base: None,
             },
             baseline_server: BaselineServerConfig::default(),
             decision_policy: perfgate_types::DecisionPolicyConfig::default(),
@@ -1465,6 +2320,8 @@ mod tests {
             ratchet: None,
             scenarios: Vec::new(),
             benches: vec![bench.clone()],
+            extends: Vec::new(),
+            profiles: Default::default(),
         };
 
         let baseline = make_baseline_receipt(
@@ -1503,7 +2360,7 @@ mod tests {
         );
 
         let (budgets, statistics) = usecase
-            .build_budgets(&bench, &config, &baseline, &current, None, None)
+            .build_budgets(&bench, &config, &baseline, &current, None, None, None)
             .expect("build budgets");
 
         let wall = budgets.get(&Metric::WallMs).expect("wall budget");
@@ -1523,6 +2380,110 @@ mod tests {
         );
     }
 
+    #[test]
+    fn build_budgets_derives_auto_threshold_from_history() {
+        let auto = |threshold_max: Option<f64>| BudgetOverride {
+            noise_threshold: None,
+            noise_policy: None,
+            threshold: Some(BudgetThreshold::Auto(AutoThresholdKeyword::Auto)),
+            threshold_min: None,
+            threshold_max,
+            direction: None,
+            warn_factor: None,
+            statistic: None,
+        };
+        let mut overrides = BTreeMap::new();
+        overrides.insert(Metric::WallMs, auto(None));
+        overrides.insert(Metric::MaxRssKb, auto(Some(0.01)));
+
+        let bench = BenchConfigFile {
+            name: "bench".to_string(),
+            cwd: None,
+            work: None,
+            timeout: None,
+            command: vec!["echo".to_string()],
+            repeat: None,
+            warmup: None,
+            metrics: None,
+            budgets: Some(overrides),
+
+            scaling: None,
+            matrix: None,
+            matrix_overrides: None,
+            service: None,
+            tags: Vec::new(),
+            paths: Vec::new(),
+            always: false,
+            load: None,
+            ready: None,
+            ready_timeout: None,
+            terminate_on_ready: false,
+        };
+        let config = ConfigFile {
+            benches: vec![bench.clone()],
+            ..ConfigFile::default()
+        };
+
+        let host = HostInfo {
+            os: "linux".to_string(),
+            arch: "x86_64".to_string(),
+            cpu_count: None,
+            memory_bytes: None,
+            hostname_hash: None,
+        };
+        let baseline = make_baseline_receipt(100, host.clone(), Some(1024));
+        let current = make_baseline_receipt(110, host.clone(), Some(1024));
+        let history = ThresholdHistory {
+            source: AutoThresholdSource::HistoryDir,
+            receipts: [90, 110, 95, 105, 100]
+                .into_iter()
+                .map(|wall| make_baseline_receipt(wall, host.clone(), None))
+                .collect(),
+        };
+
+        let usecase = CheckUseCase::new(
+            TestRunner::new(Vec::new()),
+            TestHostProbe::new(host),
+            TestClock::new("2024-01-01T00:00:00Z"),
+        );
+
+        let (budgets, _) = usecase
+            .build_budgets(
+                &bench,
+                &config,
+                &baseline,
+                &current,
+                None,
+                None,
+                Some(&history),
+            )
+            .expect("build budgets");
+
+        let wall = budgets.get(&Metric::WallMs).expect("wall budget");
+        let derivation = wall.auto_threshold.expect("auto derivation");
+        assert_eq!(derivation.source, AutoThresholdSource::HistoryDir);
+        assert_eq!(derivation.runs, 5);
+        assert_eq!(derivation.fallback, None);
+        let cv = derivation.cv.expect("cv");
+        assert!((wall.threshold - 3.0 * cv).abs() < 1e-12);
+        assert!(wall.threshold > 0.2 && wall.threshold < 0.3);
+
+        let rss = budgets.get(&Metric::MaxRssKb).expect("max_rss budget");
+        assert!((rss.threshold - 0.01).abs() < f64::EPSILON);
+
+        let (budgets, _) = usecase
+            .build_budgets(&bench, &config, &baseline, &current, None, None, None)
+            .expect("build budgets");
+        let wall = budgets.get(&Metric::WallMs).expect("wall budget");
+        let derivation = wall.auto_threshold.expect("auto derivation");
+        assert_eq!(derivation.source, AutoThresholdSource::None);
+        assert_eq!(
+            derivation.fallback,
+            Some(AutoThresholdFallback::InsufficientHistory)
+        );
+        assert!((wall.threshold - 0.2).abs() < f64::EPSILON);
+    }
+
     #[test]
     fn execute_no_baseline_builds_warn_report() {
         let bench = BenchConfigFile {
@@ -1537,6 +2498,16 @@ mod tests {
             budgets: None,
 
             scaling: None,
+            matrix: None,
+            matrix_overrides: None,
+            service: None,
+            tags: Vec::new(),
+            paths: Vec::new(),
+            always: false,
+            load: None,
+            ready: None,
+            ready_timeout: None,
+            terminate_on_ready: false,
         };
         let config = ConfigFile {
             defaults: DefaultsConfig::default(),
@@ -1546,6 +2517,8 @@ mod tests {
             ratchet: None,
             scenarios: Vec::new(),
             benches: vec![bench],
+            extends: Vec::new(),
+            profiles: Default::default(),
         };
 
         let runner = TestRunner::new(vec![run_result(100, 0, false)]);
@@ -1582,7 +2555,7 @@ mod tests {
     }
 
     #[test]
-    fn execute_with_baseline_emits_host_mismatch_warning() {
+    fn execute_with_baseline_emits_host_mismatch_and_noise_warnings() {
         let bench = BenchConfigFile {
             name: "bench".to_string(),
             cwd: None,
@@ -1595,6 +2568,16 @@ mod tests {
             budgets: None,
 
             scaling: None,
+            matrix: None,
+            matrix_overrides: None,
+            service: None,
+            tags: Vec::new(),
+            paths: Vec::new(),
+            always: false,
+            load: None,
+            ready: None,
+            ready_timeout: None,
+            terminate_on_ready: false,
         };
         let config = ConfigFile {
             defaults: DefaultsConfig::default(),
@@ -1604,9 +2587,11 @@ mod tests {
             ratchet: None,
             scenarios: Vec::new(),
             benches: vec![bench],
+            extends: Vec::new(),
+            profiles: Default::default(),
         };
 
-        let baseline = make_baseline_receipt(
+        let mut baseline = make_baseline_receipt(
             100,
             HostInfo {
                 os: "linux".to_string(),
@@ -1617,6 +2602,7 @@ mod tests {
             },
             None,
         );
+        baseline.run.noise_warnings = vec!["cycles was counted 50.00% of the time".to_string()];
 
         let runner = TestRunner::new(vec![run_result(100, 0, false)]);
         let host_probe = TestHostProbe::new(HostInfo {
@@ -1643,6 +2629,11 @@ mod tests {
             outcome.warnings.iter().any(|w| w.contains("host mismatch")),
             "expected host mismatch warning"
         );
+        assert!(
+            outcome
+                .warnings
+                .contains(&"baseline noise: cycles was counted 50.00% of the time".to_string())
+        );
     }
 
     #[test]
@@ -1659,6 +2650,16 @@ mod tests {
             budgets: None,
 
             scaling: None,
+            matrix: None,
+            matrix_overrides: None,
+            service: None,
+            tags: Vec::new(),
+            paths: Vec::new(),
+            always: false,
+            load: None,
+            ready: None,
+            ready_timeout: None,
+            terminate_on_ready: false,
         };
         let config = ConfigFile {
             defaults: DefaultsConfig {
@@ -1672,6 +2673,8 @@ mod tests {
                 baseline_dir: None,
                 baseline_pattern: None,
                 markdown_template: None,
+                baseline_history_dir: None,
+                merge_base: None,
             },
             baseline_server: BaselineServerConfig::default(),
             decision_policy: perfgate_types::DecisionPolicyConfig::default(),
@@ -1679,6 +2682,8 @@ mod tests {
             ratchet: None,
             scenarios: Vec::new(),
             benches: vec![bench],
+            extends: Vec::new(),
+            profiles: Default::default(),
         };
 
         let baseline = make_baseline_receipt(
@@ -1731,6 +2736,16 @@ mod tests {
             budgets: None,
 
             scaling: None,
+            matrix: None,
+            matrix_overrides: None,
+            service: None,
+            tags: Vec::new(),
+            paths: Vec::new(),
+            always: false,
+            load: None,
+            ready: None,
+            ready_timeout: None,
+            terminate_on_ready: false,
         };
         let config = ConfigFile {
             defaults: DefaultsConfig::default(),
@@ -1740,6 +2755,8 @@ mod tests {
             ratchet: None,
             scenarios: Vec::new(),
             benches: vec![bench],
+            extends: Vec::new(),
+            profiles: Default::default(),
         };
 
         let runner = TestRunner::new(vec![run_result(100, 0, false)]);
@@ -1774,6 +2791,8 @@ mod tests {
             ratchet: None,
             scenarios: Vec::new(),
             benches: vec![],
+            extends: Vec::new(),
+            profiles: Default::default(),
         };
 
         let runner = TestRunner::new(vec![]);
@@ -1810,6 +2829,16 @@ mod tests {
             budgets: None,
 
             scaling: None,
+            matrix: None,
+            matrix_overrides: None,
+            service: None,
+            tags: Vec::new(),
+            paths: Vec::new(),
+            always: false,
+            load: None,
+            ready: None,
+            ready_timeout: None,
+            terminate_on_ready: false,
         };
         let config = ConfigFile {
             defaults: DefaultsConfig {
@@ -1823,6 +2852,8 @@ mod tests {
                 baseline_dir: None,
                 baseline_pattern: None,
                 markdown_template: None,
+                baseline_history_dir: None,
+                merge_base: None,
             },
             baseline_server: BaselineServerConfig::default(),
             decision_policy: perfgate_types::DecisionPolicyConfig::default(),
@@ -1830,6 +2861,8 @@ mod tests {
             ratchet: None,
             scenarios: Vec::new(),
             benches: vec![bench],
+            extends: Vec::new(),
+            profiles: Default::default(),
         };
 
         let baseline = make_baseline_receipt(
@@ -1892,6 +2925,16 @@ mod tests {
                 repeat: Some(1),
                 r_squared_threshold: Some(0.95),
             }),
+            matrix: None,
+            matrix_overrides: None,
+            service: None,
+            tags: Vec::new(),
+            paths: Vec::new(),
+            always: false,
+            load: None,
+            ready: None,
+            ready_timeout: None,
+            terminate_on_ready: false,
         };
         let config = ConfigFile {
             defaults: DefaultsConfig::default(),
@@ -1901,6 +2944,8 @@ mod tests {
             ratchet: None,
             scenarios: Vec::new(),
             benches: vec![bench],
+            extends: Vec::new(),
+            profiles: Default::default(),
         };
 
         let runner = TestRunner::new(vec![
@@ -1953,6 +2998,16 @@ mod tests {
                 repeat: Some(1),
                 r_squared_threshold: Some(0.95),
             }),
+            matrix: None,
+            matrix_overrides: None,
+            service: None,
+            tags: Vec::new(),
+            paths: Vec::new(),
+            always: false,
+            load: None,
+            ready: None,
+            ready_timeout: None,
+            terminate_on_ready: false,
         };
         let config = ConfigFile {
             defaults: DefaultsConfig::default(),
@@ -1962,6 +3017,8 @@ mod tests {
             ratchet: None,
             scenarios: Vec::new(),
             benches: vec![bench],
+            extends: Vec::new(),
+            profiles: Default::default(),
         };
 
         let baseline = make_baseline_receipt(
@@ -2012,4 +3069,170 @@ mod tests {
             Some(VERDICT_REASON_COMPLEXITY_EXPECTED_EXCEEDED.to_string())
         );
     }
+
+    #[cfg(unix)]
+    #[test]
+    fn check_service_bench_runs_workload_against_ready_server() {
+        let bench = BenchConfigFile {
+            name: "api".to_string(),
+            cwd: None,
+            work: None,
+            timeout: None,
+            command: vec!["client".to_string()],
+            repeat: Some(2),
+            warmup: Some(0),
+            metrics: None,
+            budgets: None,
+            scaling: None,
+            matrix: None,
+            matrix_overrides: None,
+            service: Some(ServiceConfig {
+                command: vec![
+                    "sh".to_string(),
+                    "-c".to_string(),
+                    "echo ready; exec sleep 30".to_string(),
+                ],
+                cwd: None,
+                ready: perfgate_types::ReadyCheck::Stdout("^ready".to_string()),
+                ready_timeout: Some("10s".to_string()),
+                shutdown_timeout: None,
+            }),
+            tags: Vec::new(),
+            paths: Vec::new(),
+            always: false,
+            load: None,
+            ready: None,
+            ready_timeout: None,
+            terminate_on_ready: false,
+        };
+        let config = ConfigFile {
+            defaults: DefaultsConfig::default(),
+            baseline_server: BaselineServerConfig::default(),
+            decision_policy: perfgate_types::DecisionPolicyConfig::default(),
+            tradeoffs: Vec::new(),
+            ratchet: None,
+            scenarios: Vec::new(),
+            benches: vec![bench],
+            extends: Vec::new(),
+            profiles: Default::default(),
+        };
+
+        let runner = TestRunner::new(vec![run_result(40, 0, false), run_result(50, 0, false)]);
+        let host_probe = TestHostProbe::new(HostInfo {
+            os: "linux".to_string(),
+            arch: "x86_64".to_string(),
+            cpu_count: None,
+            memory_bytes: None,
+            hostname_hash: None,
+        });
+        let clock = TestClock::new("2024-01-01T00:00:00Z");
+        let usecase = CheckUseCase::new(runner, host_probe, clock);
+
+        let mut request = make_check_request(config, None, HostMismatchPolicy::Warn, false);
+        request.bench_name = "api".to_string();
+        let outcome = usecase.execute(request).expect("check should succeed");
+
+        let service = outcome.run_receipt.bench.service.expect("service recorded");
+        assert_eq!(service.command[0], "sh");
+        assert_eq!(outcome.run_receipt.samples.len(), 2);
+        assert_eq!(outcome.run_receipt.samples[1].wall_ms, 50);
+        assert!(!outcome.warnings.iter().any(|w| w.contains("killed")));
+    }
+
+    #[test]
+    fn check_load_bench_records_latency_and_error_rate() {
+        use std::io::{BufRead, BufReader, Write};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(stream) = stream else { continue };
+                std::thread::spawn(move || {
+                    let mut writer = stream.try_clone().unwrap();
+                    let mut reader = BufReader::new(stream);
+                    let mut line = String::new();
+                    while reader.read_line(&mut line).unwrap_or(0) > 0 {
+                        if line == "\r\n"
+                            && writer
+                                .write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nok")
+                                .is_err()
+                        {
+                            break;
+                        }
+                        line.clear();
+                    }
+                });
+            }
+        });
+
+        let bench = BenchConfigFile {
+            name: "items".to_string(),
+            cwd: None,
+            work: None,
+            timeout: None,
+            command: Vec::new(),
+            repeat: Some(2),
+            warmup: Some(0),
+            metrics: None,
+            budgets: None,
+            scaling: None,
+            matrix: None,
+            matrix_overrides: None,
+            service: None,
+            tags: Vec::new(),
+            paths: Vec::new(),
+            always: false,
+            load: Some(HttpLoadConfig {
+                url: format!("http://{addr}/items"),
+                method: None,
+                body: None,
+                headers: BTreeMap::new(),
+                concurrency: Some(2),
+                duration: None,
+                requests: Some(20),
+                rate: None,
+                timeout: Some("5s".to_string()),
+            }),
+            ready: None,
+            ready_timeout: None,
+            terminate_on_ready: false,
+        };
+        let config = ConfigFile {
+            defaults: DefaultsConfig::default(),
+            baseline_server: BaselineServerConfig::default(),
+            decision_policy: perfgate_types::DecisionPolicyConfig::default(),
+            tradeoffs: Vec::new(),
+            ratchet: None,
+            scenarios: Vec::new(),
+            benches: vec![bench],
+            extends: Vec::new(),
+            profiles: Default::default(),
+        };
+
+        // The load generator replaces the process runner, so no results are queued.
+        let runner = TestRunner::new(Vec::new());
+        let host_probe = TestHostProbe::new(HostInfo {
+            os: "linux".to_string(),
+            arch: "x86_64".to_string(),
+            cpu_count: None,
+            memory_bytes: None,
+            hostname_hash: None,
+        });
+        let clock = TestClock::new("2024-01-01T00:00:00Z");
+        let usecase = CheckUseCase::new(runner, host_probe, clock);
+
+        let mut request = make_check_request(config, None, HostMismatchPolicy::Warn, false);
+        request.bench_name = "items".to_string();
+        let outcome = usecase.execute(request).expect("check should succeed");
+
+        let load = outcome.run_receipt.bench.load.expect("load recorded");
+        assert_eq!(load.method, "GET");
+        assert_eq!(load.concurrency, 2);
+        let stats = &outcome.run_receipt.stats;
+        assert!(stats.latency_p99_us.is_some());
+        assert_eq!(stats.error_rate.as_ref().map(|e| e.median), Some(0.0));
+        assert!(stats.throughput_per_s.is_some());
+        assert!(outcome.markdown.contains("`latency_p95_us`"));
+    }
 }
@@ -37,6 +37,42 @@ pub fn build_budgets(
     if baseline.stats.throughput_per_s.is_some() && current.stats.throughput_per_s.is_some() {
         candidates.push(Metric::ThroughputPerS);
     }
+    if baseline.stats.latency_p50_us.is_some() && current.stats.latency_p50_us.is_some() {
+        candidates.push(Metric::LatencyP50Us);
+    }
+    if baseline.stats.latency_p95_us.is_some() && current.stats.latency_p95_us.is_some() {
+        candidates.push(Metric::LatencyP95Us);
+    }
+    if baseline.stats.latency_p99_us.is_some() && current.stats.latency_p99_us.is_some() {
+        candidates.push(Metric::LatencyP99Us);
+    }
+    if baseline.stats.error_rate.is_some() && current.stats.error_rate.is_some() {
+        candidates.push(Metric::ErrorRate);
+    }
+    if baseline.stats.time_to_ready_ms.is_some() && current.stats.time_to_ready_ms.is_some() {
+        candidates.push(Metric::TimeToReadyMs);
+    }
+    if baseline.stats.instructions.is_some() && current.stats.instructions.is_some() {
+        candidates.push(Metric::Instructions);
+    }
+    if baseline.stats.estimated_cycles.is_some() && current.stats.estimated_cycles.is_some() {
+        candidates.push(Metric::EstimatedCycles);
+    }
+    if baseline.stats.l1_hits.is_some() && current.stats.l1_hits.is_some() {
+        candidates.push(Metric::L1Hits);
+    }
+    if baseline.stats.ll_hits.is_some() && current.stats.ll_hits.is_some() {
+        candidates.push(Metric::LlHits);
+    }
+    if baseline.stats.ram_hits.is_some() && current.stats.ram_hits.is_some() {
+        candidates.push(Metric::RamHits);
+    }
+    if baseline.stats.alloc_bytes.is_some() && current.stats.alloc_bytes.is_some() {
+        candidates.push(Metric::AllocBytes);
+    }
+    if baseline.stats.allocs.is_some() && current.stats.allocs.is_some() {
+        candidates.push(Metric::Allocs);
+    }
 
     let mut thresholds: BTreeMap<String, f64> = metric_thresholds.into_iter().collect();
     let mut noise_limits: BTreeMap<String, f64> = noise_thresholds.into_iter().collect();
@@ -68,6 +104,7 @@ pub fn build_budgets(
                 noise_threshold,
                 noise_policy,
                 direction: dir,
+                auto_threshold: None,
             },
         );
     }
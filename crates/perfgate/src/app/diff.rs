@@ -12,8 +12,8 @@ use crate::app::runtime::{HostProbe, ProcessRunner};
 use crate::app::{Clock, CompareRequest, CompareUseCase, RunBenchRequest, RunBenchUseCase};
 use anyhow::Context;
 use perfgate_types::{
-    BenchConfigFile, CompareReceipt, CompareRef, ConfigFile, HostMismatchPolicy, Metric,
-    MetricStatistic, RunReceipt, ToolInfo, VerdictStatus,
+    BenchConfigFile, BenchTagFilter, BenchWork, BudgetThreshold, CompareReceipt, CompareRef,
+    ConfigFile, HostMismatchPolicy, Metric, MetricStatistic, RunReceipt, ToolInfo, VerdictStatus,
 };
 use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
@@ -27,6 +27,9 @@ pub struct DiffRequest {
     /// Filter to a single benchmark by name.
     pub bench_filter: Option<String>,
 
+    /// Select benchmarks by tag when no `bench_filter` is given.
+    pub tags: BenchTagFilter,
+
     /// Git ref to compare against (reserved for future use).
     pub against: Option<String>,
 
@@ -57,6 +60,9 @@ pub struct BenchDiffOutcome {
 
     /// True if no baseline was found.
     pub no_baseline: bool,
+
+    /// Budget settings diff could not honor, for the user to see.
+    pub warnings: Vec<String>,
 }
 
 /// Overall outcome of the diff command.
@@ -166,7 +172,20 @@ impl<R: ProcessRunner + Clone, H: HostProbe + Clone, C: Clock + Clone> DiffUseCa
             if config.benches.is_empty() {
                 anyhow::bail!("no benchmarks defined in {}", config_path.display());
             }
-            config.benches.iter().map(|b| b.name.clone()).collect()
+            let names: Vec<String> = config
+                .benches
+                .iter()
+                .filter(|b| req.tags.matches(b))
+                .map(|b| b.name.clone())
+                .collect();
+            if names.is_empty() {
+                anyhow::bail!(
+                    "{} did not match any benchmarks in {}",
+                    req.tags.describe(),
+                    config_path.display()
+                );
+            }
+            names
         };
 
         // 4. Run each benchmark and compare
@@ -238,12 +257,14 @@ impl<R: ProcessRunner + Clone, H: HostProbe + Clone, C: Clock + Clone> DiffUseCa
             command: bench.command.clone(),
             repeat,
             warmup,
-            work_units: bench.work,
+            work_units: bench.work.as_ref().and_then(BenchWork::units),
             timeout,
             env: Vec::new(),
             output_cap_bytes: 8192,
             allow_nonzero: false,
             include_hostname_hash: false,
+            cpu_affinity: Vec::new(),
+            ready: None,
         };
 
         // Run the benchmark
@@ -269,9 +290,10 @@ impl<R: ProcessRunner + Clone, H: HostProbe + Clone, C: Clock + Clone> DiffUseCa
         };
 
         // Compare if baseline exists
+        let mut warnings = Vec::new();
         let compare_receipt = if let Some(baseline) = &baseline_receipt {
             let (budgets, metric_statistics) =
-                build_diff_budgets(bench, config, baseline, &run_receipt)?;
+                build_diff_budgets(bench, config, baseline, &run_receipt, &mut warnings)?;
 
             let compare_req = CompareRequest {
                 baseline: baseline.clone(),
@@ -283,10 +305,12 @@ impl<R: ProcessRunner + Clone, H: HostProbe + Clone, C: Clock + Clone> DiffUseCa
                 baseline_ref: CompareRef {
                     path: Some(baseline_path.display().to_string()),
                     run_id: Some(baseline.run.id.clone()),
+                    profile: None,
                 },
                 current_ref: CompareRef {
                     path: None,
                     run_id: Some(run_receipt.run.id.clone()),
+                    profile: None,
                 },
                 tool: req.tool.clone(),
                 host_mismatch_policy: HostMismatchPolicy::Warn,
@@ -303,16 +327,21 @@ impl<R: ProcessRunner + Clone, H: HostProbe + Clone, C: Clock + Clone> DiffUseCa
             compare_receipt,
             baseline_path: Some(baseline_path),
             no_baseline: baseline_receipt.is_none(),
+            warnings,
         })
     }
 }
 
 /// Build budgets for the diff comparison (simplified from CheckUseCase).
+///
+/// `threshold = "auto"` needs bench history, which a local diff does not read;
+/// those metrics use the global threshold and a warning is pushed to `warnings`.
 fn build_diff_budgets(
     bench: &BenchConfigFile,
     config: &ConfigFile,
     baseline: &RunReceipt,
     current: &RunReceipt,
+    warnings: &mut Vec<String>,
 ) -> anyhow::Result<(
     BTreeMap<Metric, perfgate_types::Budget>,
     BTreeMap<Metric, MetricStatistic>,
@@ -341,6 +370,42 @@ fn build_diff_budgets(
     if baseline.stats.throughput_per_s.is_some() && current.stats.throughput_per_s.is_some() {
         candidates.push(Metric::ThroughputPerS);
     }
+    if baseline.stats.latency_p50_us.is_some() && current.stats.latency_p50_us.is_some() {
+        candidates.push(Metric::LatencyP50Us);
+    }
+    if baseline.stats.latency_p95_us.is_some() && current.stats.latency_p95_us.is_some() {
+        candidates.push(Metric::LatencyP95Us);
+    }
+    if baseline.stats.latency_p99_us.is_some() && current.stats.latency_p99_us.is_some() {
+        candidates.push(Metric::LatencyP99Us);
+    }
+    if baseline.stats.error_rate.is_some() && current.stats.error_rate.is_some() {
+        candidates.push(Metric::ErrorRate);
+    }
+    if baseline.stats.time_to_ready_ms.is_some() && current.stats.time_to_ready_ms.is_some() {
+        candidates.push(Metric::TimeToReadyMs);
+    }
+    if baseline.stats.instructions.is_some() && current.stats.instructions.is_some() {
+        candidates.push(Metric::Instructions);
+    }
+    if baseline.stats.estimated_cycles.is_some() && current.stats.estimated_cycles.is_some() {
+        candidates.push(Metric::EstimatedCycles);
+    }
+    if baseline.stats.l1_hits.is_some() && current.stats.l1_hits.is_some() {
+        candidates.push(Metric::L1Hits);
+    }
+    if baseline.stats.ll_hits.is_some() && current.stats.ll_hits.is_some() {
+        candidates.push(Metric::LlHits);
+    }
+    if baseline.stats.ram_hits.is_some() && current.stats.ram_hits.is_some() {
+        candidates.push(Metric::RamHits);
+    }
+    if baseline.stats.alloc_bytes.is_some() && current.stats.alloc_bytes.is_some() {
+        candidates.push(Metric::AllocBytes);
+    }
+    if baseline.stats.allocs.is_some() && current.stats.allocs.is_some() {
+        candidates.push(Metric::Allocs);
+    }
 
     let mut budgets = BTreeMap::new();
     let mut metric_statistics = BTreeMap::new();
@@ -348,9 +413,17 @@ fn build_diff_budgets(
     for metric in candidates {
         let override_opt = bench.budgets.as_ref().and_then(|b| b.get(&metric).cloned());
 
-        let threshold = override_opt
-            .as_ref()
-            .and_then(|o| o.threshold)
+        let configured = override_opt.as_ref().and_then(|o| o.threshold);
+        if configured.is_some_and(BudgetThreshold::is_auto) {
+            warnings.push(format!(
+                "bench '{}': threshold = \"auto\" for {} is not derived by diff; using the global threshold {}",
+                bench.name,
+                metric.as_str(),
+                global_threshold
+            ));
+        }
+        let threshold = configured
+            .and_then(BudgetThreshold::fixed)
             .unwrap_or(global_threshold);
 
         let warn_factor = override_opt
@@ -389,6 +462,7 @@ fn build_diff_budgets(
                 noise_threshold,
                 noise_policy,
                 direction,
+                auto_threshold: None,
             },
         );
 
@@ -461,6 +535,7 @@ pub fn render_json_diff(outcome: &DiffOutcome) -> anyhow::Result<String> {
             "bench": bench_outcome.bench_name,
             "no_baseline": bench_outcome.no_baseline,
             "compare": bench_outcome.compare_receipt,
+            "warnings": bench_outcome.warnings,
         });
         entries.push(entry);
     }
@@ -541,6 +616,7 @@ mod tests {
                 compare_receipt: None,
                 baseline_path: None,
                 no_baseline: true,
+                warnings: Vec::new(),
             }],
             exit_code: 0,
         };
@@ -588,14 +664,18 @@ mod tests {
                 warmup: 0,
                 work_units: None,
                 timeout_ms: None,
+                service: None,
+                load: None,
             },
             baseline_ref: CompareRef {
                 path: None,
                 run_id: None,
+                profile: None,
             },
             current_ref: CompareRef {
                 path: None,
                 run_id: None,
+                profile: None,
             },
             budgets,
             deltas,
@@ -619,6 +699,7 @@ mod tests {
                 compare_receipt: Some(compare),
                 baseline_path: Some(PathBuf::from("baselines/my-bench.json")),
                 no_baseline: false,
+                warnings: Vec::new(),
             }],
             exit_code: 0,
         };
@@ -640,6 +721,7 @@ mod tests {
                 compare_receipt: None,
                 baseline_path: None,
                 no_baseline: true,
+                warnings: Vec::new(),
             }],
             exit_code: 0,
         };
@@ -667,6 +749,7 @@ mod tests {
                     compare_receipt: Some(pass_compare),
                     baseline_path: None,
                     no_baseline: false,
+                    warnings: Vec::new(),
                 },
                 BenchDiffOutcome {
                     bench_name: "b".to_string(),
@@ -674,6 +757,7 @@ mod tests {
                     compare_receipt: Some(fail_compare),
                     baseline_path: None,
                     no_baseline: false,
+                    warnings: Vec::new(),
                 },
             ],
             exit_code: 2,
@@ -692,6 +776,7 @@ mod tests {
                 compare_receipt: None,
                 baseline_path: None,
                 no_baseline: true,
+                warnings: Vec::new(),
             }],
             exit_code: 0,
         };
@@ -699,6 +784,45 @@ mod tests {
         assert_eq!(outcome.worst_verdict(), VerdictStatus::Pass);
     }
 
+    #[test]
+    fn build_diff_budgets_warns_that_auto_threshold_falls_back() {
+        let config: ConfigFile = toml::from_str(
+            r#"
+[defaults]
+threshold = 0.15
+
+[[bench]]
+name = "parse"
+command = ["echo"]
+
+[bench.budgets.wall_ms]
+threshold = "auto"
+"#,
+        )
+        .unwrap();
+        let receipt = make_dummy_run_receipt();
+        let mut warnings = Vec::new();
+
+        let (budgets, _) = build_diff_budgets(
+            &config.benches[0],
+            &config,
+            &receipt,
+            &receipt,
+            &mut warnings,
+        )
+        .unwrap();
+
+        assert_eq!(budgets[&Metric::WallMs].threshold, 0.15);
+        assert_eq!(
+            warnings,
+            vec![
+                "bench 'parse': threshold = \"auto\" for wall_ms is not derived by diff; \
+                 using the global threshold 0.15"
+                    .to_string()
+            ]
+        );
+    }
+
     fn make_dummy_run_receipt() -> RunReceipt {
         use perfgate_types::*;
 
@@ -716,6 +840,8 @@ mod tests {
                 warmup: 0,
                 work_units: None,
                 timeout_ms: None,
+                service: None,
+                load: None,
             },
             run: RunMeta {
                 id: "test-id".into(),
@@ -728,6 +854,10 @@ mod tests {
                     memory_bytes: None,
                     hostname_hash: None,
                 },
+                profile: None,
+                concurrency: None,
+                interleave: None,
+                noise_warnings: Vec::new(),
             },
             samples: vec![],
             stats: Stats {
@@ -742,6 +872,19 @@ mod tests {
                 io_write_bytes: None,
                 energy_uj: None,
                 network_packets: None,
+                latency_p50_us: None,
+                latency_p95_us: None,
+                latency_p99_us: None,
+                error_rate: None,
+                time_to_ready_ms: None,
+                instructions: None,
+                estimated_cycles: None,
+                l1_hits: None,
+                ll_hits: None,
+                ram_hits: None,
+                alloc_bytes: None,
+                allocs: None,
+                custom: Default::default(),
             },
         }
     }
@@ -763,14 +906,18 @@ mod tests {
                 warmup: 0,
                 work_units: None,
                 timeout_ms: None,
+                service: None,
+                load: None,
             },
             baseline_ref: CompareRef {
                 path: None,
                 run_id: None,
+                profile: None,
             },
             current_ref: CompareRef {
                 path: None,
                 run_id: None,
+                profile: None,
             },
             budgets: BTreeMap::new(),
             deltas: BTreeMap::new(),
@@ -13,7 +13,7 @@ use std::path::Path;
 pub struct DiscoveredBenchmark {
     /// Human-readable name for the benchmark.
     pub name: String,
-    /// Framework that was detected (e.g. "criterion", "go-bench", "pytest-benchmark").
+    /// Framework that was detected (e.g. "criterion", "go-bench", "pytest-benchmark", "vitest-bench").
     pub framework: String,
     /// Suggested command to run this benchmark.
     pub command: String,
@@ -23,6 +23,10 @@ pub struct DiscoveredBenchmark {
     pub language: String,
     /// Confidence level: "high", "medium", or "low".
     pub confidence: String,
+    /// Set when the framework has no JSON reporter: what the benchmark must
+    /// add before `command`'s output can be ingested.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub manual_export: Option<String>,
 }
 
 /// Orchestrates all framework-specific scanners and returns the combined results.
@@ -32,6 +36,7 @@ pub fn discover_all(root: &Path) -> Vec<DiscoveredBenchmark> {
     results.extend(scan_go_benchmarks(root));
     results.extend(scan_python_pytest_benchmark(root));
     results.extend(scan_javascript_benchmark(root));
+    results.extend(scan_javascript_tinybench(root));
     results.extend(scan_custom_directories(root));
     results.sort_by(|a, b| a.name.cmp(&b.name));
     results
@@ -113,6 +118,7 @@ fn parse_cargo_bench_targets(content: &str, root: &Path) -> Vec<DiscoveredBenchm
                     path: rel_path,
                     language: "rust".to_string(),
                     confidence: confidence.to_string(),
+                    manual_export: None,
                 });
             }
             i = j;
@@ -168,6 +174,7 @@ fn scan_dir_for_criterion(dir: &Path, root: &Path) -> Vec<DiscoveredBenchmark> {
                 path: rel_path,
                 language: "rust".to_string(),
                 confidence: "high".to_string(),
+                manual_export: None,
             });
         }
     }
@@ -213,6 +220,7 @@ fn scan_go_benchmarks(root: &Path) -> Vec<DiscoveredBenchmark> {
                             path: rel_path,
                             language: "go".to_string(),
                             confidence: "high".to_string(),
+                            manual_export: None,
                         });
                     }
                 }
@@ -257,6 +265,7 @@ fn scan_python_pytest_benchmark(root: &Path) -> Vec<DiscoveredBenchmark> {
                         path: rel_path,
                         language: "python".to_string(),
                         confidence: "high".to_string(),
+                        manual_export: None,
                     });
                 }
             }
@@ -278,6 +287,7 @@ fn scan_javascript_benchmark(root: &Path) -> Vec<DiscoveredBenchmark> {
             && (name.contains("bench") || name.contains("perf"))
             && let Ok(content) = fs::read_to_string(path)
             && (content.contains("suite.add") || content.contains("Suite"))
+            && !uses_tinybench(&content)
         {
             let rel_path = path
                 .strip_prefix(root)
@@ -295,6 +305,95 @@ fn scan_javascript_benchmark(root: &Path) -> Vec<DiscoveredBenchmark> {
                 path: rel_path,
                 language: "javascript".to_string(),
                 confidence: "medium".to_string(),
+                manual_export: Some(
+                    "Benchmark.js has no JSON reporter: write `JSON.stringify(Array.from(this))` \
+                     from the suite's `complete` handler, then `perfgate ingest --format benchmark-js`"
+                        .to_string(),
+                ),
+            });
+        }
+    });
+    results
+}
+
+// ---------------------------------------------------------------------------
+// JavaScript / TypeScript: vitest bench and tinybench
+// ---------------------------------------------------------------------------
+
+const JS_EXTENSIONS: &[&str] = &["js", "mjs", "cjs", "ts", "mts", "cts"];
+
+/// vitest bench and plain tinybench scripts share tinybench's result model.
+fn uses_tinybench(content: &str) -> bool {
+    content.contains("vitest") || content.contains("tinybench")
+}
+
+/// Scan for vitest bench files (`*.bench.ts` and friends calling `bench(`)
+/// and tinybench scripts (`new Bench(` from `tinybench`).
+///
+/// vitest's suggested command writes JSON that `perfgate ingest --format
+/// tinybench` reads; a plain tinybench script has to print it itself, so
+/// those discoveries carry a `manual_export` hint.
+fn scan_javascript_tinybench(root: &Path) -> Vec<DiscoveredBenchmark> {
+    let mut results = Vec::new();
+    walk_files(root, &mut |path| {
+        let Some(ext) = path.extension().map(|e| e.to_string_lossy().to_string()) else {
+            return;
+        };
+        if !JS_EXTENSIONS.contains(&ext.as_str()) {
+            return;
+        }
+        let Ok(content) = fs::read_to_string(path) else {
+            return;
+        };
+        let rel_path = path
+            .strip_prefix(root)
+            .unwrap_or(path)
+            .to_string_lossy()
+            .replace('\\', "/");
+        let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+        let language = if ext.ends_with("ts") {
+            "typescript"
+        } else {
+            "javascript"
+        };
+        let suffix = format!(".bench.{ext}");
+
+        if let Some(stem) = file_name.strip_suffix(&suffix)
+            && content.contains("bench(")
+        {
+            results.push(DiscoveredBenchmark {
+                name: stem.to_string(),
+                framework: "vitest-bench".to_string(),
+                command: format!(
+                    "npx vitest bench --run --outputJson vitest-bench.json {rel_path}"
+                ),
+                path: rel_path,
+                language: language.to_string(),
+                confidence: "high".to_string(),
+                manual_export: None,
+            });
+        } else if content.contains("tinybench") && content.contains("new Bench(") {
+            let runner = if language == "typescript" {
+                "npx tsx"
+            } else {
+                "node"
+            };
+            results.push(DiscoveredBenchmark {
+                name: path
+                    .file_stem()
+                    .map(|s| s.to_string_lossy().to_string())
+                    .unwrap_or_else(|| "unknown".to_string()),
+                framework: "tinybench".to_string(),
+                command: format!("{runner} {rel_path}"),
+                path: rel_path,
+                language: language.to_string(),
+                confidence: "medium".to_string(),
+                manual_export: Some(
+                    "tinybench prints a table: after `bench.run()`, print \
+                     `JSON.stringify(bench.tasks.map(({ name, result }) => ({ name, result })))` \
+                     and ingest the output with `perfgate ingest --format tinybench`"
+                        .to_string(),
+                ),
             });
         }
     });
@@ -335,6 +434,7 @@ fn scan_custom_directories(root: &Path) -> Vec<DiscoveredBenchmark> {
                         path: rel_path,
                         language: "unknown".to_string(),
                         confidence: "low".to_string(),
+                        manual_export: None,
                     });
                 }
             }
@@ -612,6 +712,61 @@ suite.add('sort', function() {
         assert_eq!(results[0].framework, "benchmark.js");
         assert_eq!(results[0].language, "javascript");
         assert_eq!(results[0].confidence, "medium");
+        assert!(
+            results[0]
+                .manual_export
+                .as_deref()
+                .is_some_and(|hint| hint.contains("--format benchmark-js"))
+        );
+    }
+
+    #[test]
+    fn test_scan_javascript_tinybench() {
+        let tmp = setup_temp_dir();
+        fs::write(
+            tmp.path().join("sort.bench.ts"),
+            r#"
+import { bench, describe } from 'vitest';
+
+describe('sorting', () => {
+    bench('native', () => {
+        [3, 1, 2].sort();
+    });
+});
+"#,
+        )
+        .unwrap();
+        fs::write(
+            tmp.path().join("parse-perf.mjs"),
+            r#"
+import { Bench } from 'tinybench';
+const bench = new Bench({ time: 100 });
+bench.add('parse', () => JSON.parse('{}'));
+await bench.run();
+"#,
+        )
+        .unwrap();
+
+        let mut results = scan_javascript_tinybench(tmp.path());
+        results.sort_by(|a, b| a.name.cmp(&b.name));
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].name, "parse-perf");
+        assert_eq!(results[0].framework, "tinybench");
+        assert_eq!(results[0].command, "node parse-perf.mjs");
+        assert!(
+            results[0]
+                .manual_export
+                .as_deref()
+                .is_some_and(|hint| hint.contains("--format tinybench"))
+        );
+        assert_eq!(results[1].name, "sort");
+        assert_eq!(results[1].framework, "vitest-bench");
+        assert_eq!(results[1].language, "typescript");
+        assert!(results[1].command.contains("--outputJson"));
+        assert_eq!(results[1].manual_export, None);
+
+        // The Benchmark.js scanner leaves tinybench scripts alone.
+        assert!(scan_javascript_benchmark(tmp.path()).is_empty());
     }
 
     #[test]
@@ -154,6 +154,11 @@ impl ExplainUseCase {
                 playbook
             },
             Metric::ThroughputPerS => "### Throughput Playbook\n- **Bottlenecks**: A drop in throughput usually indicates a bottleneck in CPU or I/O. Consult the Wall Time and CPU playbooks.\n- **Concurrency Limit**: Check if a semaphore or connection pool is artificially limiting concurrent work units.".to_string(),
+            Metric::LatencyP50Us | Metric::LatencyP95Us | Metric::LatencyP99Us => "### Request Latency Playbook\n- **Tail Latency**: A p95/p99 regression with a steady p50 points at occasional stalls: lock contention, GC or allocator pauses, or queueing behind slow requests.\n- **Per-request Work**: A p50 regression means every request got slower. Profile the handler for new work on the hot path.\n- **Saturation**: Compare with the server's CPU time; a saturated worker pool turns small slowdowns into queueing delay.".to_string(),
+            Metric::ErrorRate => "### Error Rate Playbook\n- **Timeouts**: Did requests start exceeding the load generator's request timeout?\n- **Connection Limits**: Check accept backlogs, connection pool sizes and file descriptor limits under the configured concurrency.\n- **Status Codes**: Reproduce with the same load and inspect which responses return 4xx/5xx.".to_string(),
+            Metric::TimeToReadyMs => "### Startup Playbook\n- **Eager Initialization**: Defer work that is not needed before the ready signal (caches, connection pools, config validation of unused features).\n- **Dynamic Linking and I/O**: Check for new shared libraries, large config or asset loads, or blocking network calls during startup.\n- **Ordering**: Make sure the ready line is printed or the port opened as soon as the process can serve, not after background warm-up.".to_string(),
+            Metric::Instructions | Metric::EstimatedCycles | Metric::L1Hits | Metric::LlHits | Metric::RamHits => "### Instruction Count Playbook\n- **Deterministic Signal**: Callgrind counters do not depend on machine load, so any change is caused by the code or the toolchain. Diff the callgrind output (`callgrind_annotate`) against the baseline to find the functions that grew.\n- **Cache Behaviour**: Rising LL or RAM hits with steady instructions point at a larger working set or worse data locality, not more work.\n- **Toolchain Drift**: Compiler or dependency upgrades change codegen; pin the toolchain when comparing across commits.".to_string(),
+            Metric::AllocBytes | Metric::Allocs => "### Allocation Playbook\n- **Hot-path Allocations**: Look for new `make`/`Vec::new`/boxing or string formatting inside the measured loop; reuse buffers instead.\n- **Escaping Values**: Values that escape to the heap (captured by closures, stored behind interfaces or trait objects) allocate per call.\n- **Capacity Hints**: Pre-size collections so they do not grow and reallocate while the benchmark runs.".to_string(),
             Metric::EnergyUj => "### Energy Efficiency Playbook\n- **Busy Waiting**: Are you using `spin` loops? Use OS-backed blocking primitives instead.\n- **High CPU Utilization**: Energy correlates strongly with CPU time. Optimize your algorithms to do less work.\n- **Polling**: Switch from polling models to event-driven (interrupt-based) architectures.".to_string(),
         }
     }
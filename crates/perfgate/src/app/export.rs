@@ -29,23 +29,38 @@
 //!         ended_at: "2024-01-01T00:00:01Z".into(),
 //!         host: HostInfo { os: "linux".into(), arch: "x86_64".into(),
 //!             cpu_count: None, memory_bytes: None, hostname_hash: None },
+//!         profile: None,
+//!         concurrency: None,
+//!         interleave: None,
+//!         noise_warnings: Vec::new(),
 //!     },
 //!     bench: BenchMeta {
 //!         name: "bench".into(), cwd: None,
 //!         command: vec!["echo".into()], repeat: 1, warmup: 0,
-//!         work_units: None, timeout_ms: None,
+//!         work_units: None, timeout_ms: None, service: None, load: None,
 //!     },
 //!     samples: vec![Sample {
 //!         wall_ms: 42, exit_code: 0, warmup: false, timed_out: false,
 //!         cpu_ms: None, page_faults: None, ctx_switches: None,
 //!         max_rss_kb: None, io_read_bytes: None, io_write_bytes: None,
 //!         network_packets: None, energy_uj: None, binary_bytes: None, stdout: None, stderr: None,
+//!         latency_p50_us: None, latency_p95_us: None, latency_p99_us: None, error_rate: None, throughput_per_s: None,
+//!         time_to_ready_ms: None,
+//!         instructions: None, estimated_cycles: None,
+//!         l1_hits: None, ll_hits: None, ram_hits: None,
+//!         alloc_bytes: None, allocs: None,
 //!     }],
 //!     stats: Stats {
 //!         wall_ms: U64Summary::new(42, 42, 42 ),
 //!         cpu_ms: None, page_faults: None, ctx_switches: None,
 //!         max_rss_kb: None, io_read_bytes: None, io_write_bytes: None,
 //!         network_packets: None, energy_uj: None, binary_bytes: None, throughput_per_s: None,
+//!         latency_p50_us: None, latency_p95_us: None, latency_p99_us: None, error_rate: None,
+//!         time_to_ready_ms: None,
+//!         instructions: None, estimated_cycles: None,
+//!         l1_hits: None, ll_hits: None, ram_hits: None,
+//!         alloc_bytes: None, allocs: None,
+//!         custom: Default::default(),
 //!     },
 //! };
 //!
@@ -88,23 +103,39 @@ impl ExportUseCase {
     ///         ended_at: "2024-01-01T00:00:01Z".into(),
     ///         host: HostInfo { os: "linux".into(), arch: "x86_64".into(),
     ///             cpu_count: None, memory_bytes: None, hostname_hash: None },
+    ///         profile: None,
+    ///         concurrency: None,
+    ///         interleave: None,
+    ///         noise_warnings: Vec::new(),
     ///     },
     ///     bench: BenchMeta {
     ///         name: "bench".into(), cwd: None,
     ///         command: vec!["echo".into()], repeat: 1, warmup: 0,
-    ///         work_units: None, timeout_ms: None,
+    ///         work_units: None, timeout_ms: None, service: None,
+    ///         load: None,
     ///     },
     ///     samples: vec![Sample {
     ///         wall_ms: 42, exit_code: 0, warmup: false, timed_out: false,
     ///         cpu_ms: None, page_faults: None, ctx_switches: None,
     ///         max_rss_kb: None, io_read_bytes: None, io_write_bytes: None,
     ///         network_packets: None, energy_uj: None, binary_bytes: None, stdout: None, stderr: None,
+    ///         latency_p50_us: None, latency_p95_us: None, latency_p99_us: None, error_rate: None, throughput_per_s: None,
+    ///         time_to_ready_ms: None,
+    ///         instructions: None, estimated_cycles: None,
+    ///         l1_hits: None, ll_hits: None, ram_hits: None,
+    ///         alloc_bytes: None, allocs: None,
     ///     }],
     ///     stats: Stats {
     ///         wall_ms: U64Summary::new(42, 42, 42 ),
     ///         cpu_ms: None, page_faults: None, ctx_switches: None,
     ///         max_rss_kb: None, io_read_bytes: None, io_write_bytes: None,
     ///         network_packets: None, energy_uj: None, binary_bytes: None, throughput_per_s: None,
+    ///         latency_p50_us: None, latency_p95_us: None, latency_p99_us: None, error_rate: None,
+    ///         time_to_ready_ms: None,
+    ///         instructions: None, estimated_cycles: None,
+    ///         l1_hits: None, ll_hits: None, ram_hits: None,
+    ///         alloc_bytes: None, allocs: None,
+    ///         custom: Default::default(),
     ///     },
     /// };
     /// let csv = ExportUseCase::export_run(&receipt, ExportFormat::Csv).unwrap();
@@ -135,10 +166,11 @@ impl ExportUseCase {
     ///     bench: BenchMeta {
     ///         name: "bench".into(), cwd: None,
     ///         command: vec!["echo".into()], repeat: 1, warmup: 0,
-    ///         work_units: None, timeout_ms: None,
+    ///         work_units: None, timeout_ms: None, service: None,
+    ///         load: None,
     ///     },
-    ///     baseline_ref: CompareRef { path: None, run_id: None },
-    ///     current_ref: CompareRef { path: None, run_id: None },
+    ///     baseline_ref: CompareRef { path: None, run_id: None, profile: None },
+    ///     current_ref: CompareRef { path: None, run_id: None, profile: None },
     ///     budgets: BTreeMap::new(),
     ///     deltas: BTreeMap::from([(Metric::WallMs, Delta {
     ///         baseline: 100.0, current: 110.0, ratio: 1.1, pct: 0.1, regression: 0.1,
@@ -199,6 +231,10 @@ mod tests {
                     memory_bytes: None,
                     hostname_hash: None,
                 },
+                profile: None,
+                concurrency: None,
+                interleave: None,
+                noise_warnings: Vec::new(),
             },
             bench: BenchMeta {
                 name: "test-benchmark".to_string(),
@@ -208,6 +244,8 @@ mod tests {
                 warmup: 0,
                 work_units: None,
                 timeout_ms: None,
+                service: None,
+                load: None,
             },
             samples: vec![
                 Sample {
@@ -226,6 +264,19 @@ mod tests {
                     binary_bytes: None,
                     stdout: None,
                     stderr: None,
+                    latency_p50_us: None,
+                    latency_p95_us: None,
+                    latency_p99_us: None,
+                    error_rate: None,
+                    throughput_per_s: None,
+                    time_to_ready_ms: None,
+                    instructions: None,
+                    estimated_cycles: None,
+                    l1_hits: None,
+                    ll_hits: None,
+                    ram_hits: None,
+                    alloc_bytes: None,
+                    allocs: None,
                 },
                 Sample {
                     wall_ms: 102,
@@ -243,6 +294,19 @@ mod tests {
                     binary_bytes: None,
                     stdout: None,
                     stderr: None,
+                    latency_p50_us: None,
+                    latency_p95_us: None,
+                    latency_p99_us: None,
+                    error_rate: None,
+                    throughput_per_s: None,
+                    time_to_ready_ms: None,
+                    instructions: None,
+                    estimated_cycles: None,
+                    l1_hits: None,
+                    ll_hits: None,
+                    ram_hits: None,
+                    alloc_bytes: None,
+                    allocs: None,
                 },
             ],
             stats: Stats {
@@ -257,6 +321,19 @@ mod tests {
                 energy_uj: None,
                 binary_bytes: None,
                 throughput_per_s: None,
+                latency_p50_us: None,
+                latency_p95_us: None,
+                latency_p99_us: None,
+                error_rate: None,
+                time_to_ready_ms: None,
+                instructions: None,
+                estimated_cycles: None,
+                l1_hits: None,
+                ll_hits: None,
+                ram_hits: None,
+                alloc_bytes: None,
+                allocs: None,
+                custom: Default::default(),
             },
         }
     }
@@ -312,14 +389,18 @@ mod tests {
                 warmup: 0,
                 work_units: None,
                 timeout_ms: None,
+                service: None,
+                load: None,
             },
             baseline_ref: CompareRef {
                 path: Some("baseline.json".to_string()),
                 run_id: Some("baseline-001".to_string()),
+                profile: None,
             },
             current_ref: CompareRef {
                 path: Some("current.json".to_string()),
                 run_id: Some("current-001".to_string()),
+                profile: None,
             },
             budgets,
             deltas,
@@ -533,6 +614,10 @@ mod tests {
                         memory_bytes: None,
                         hostname_hash: None,
                     },
+                    profile: None,
+                    concurrency: None,
+                    interleave: None,
+                    noise_warnings: Vec::new(),
                 },
                 bench: BenchMeta {
                     name: "empty-bench".to_string(),
@@ -542,6 +627,8 @@ mod tests {
                     warmup: 0,
                     work_units: None,
                     timeout_ms: None,
+                    service: None,
+                    load: None,
                 },
                 samples: vec![],
                 stats: Stats {
@@ -556,6 +643,19 @@ mod tests {
                     energy_uj: None,
                     binary_bytes: None,
                     throughput_per_s: None,
+                    latency_p50_us: None,
+                    latency_p95_us: None,
+                    latency_p99_us: None,
+                    error_rate: None,
+                    time_to_ready_ms: None,
+                    instructions: None,
+                    estimated_cycles: None,
+                    l1_hits: None,
+                    ll_hits: None,
+                    ram_hits: None,
+                    alloc_bytes: None,
+                    allocs: None,
+                    custom: Default::default(),
                 },
             }
         }
@@ -575,14 +675,18 @@ mod tests {
                     warmup: 0,
                     work_units: None,
                     timeout_ms: None,
+                    service: None,
+                    load: None,
                 },
                 baseline_ref: CompareRef {
                     path: None,
                     run_id: None,
+                    profile: None,
                 },
                 current_ref: CompareRef {
                     path: None,
                     run_id: None,
+                    profile: None,
                 },
                 budgets: BTreeMap::new(),
                 deltas: BTreeMap::new(),
@@ -618,6 +722,19 @@ mod tests {
                 binary_bytes: None,
                 stdout: None,
                 stderr: None,
+                latency_p50_us: None,
+                latency_p95_us: None,
+                latency_p99_us: None,
+                error_rate: None,
+                throughput_per_s: None,
+                time_to_ready_ms: None,
+                instructions: None,
+                estimated_cycles: None,
+                l1_hits: None,
+                ll_hits: None,
+                ram_hits: None,
+                alloc_bytes: None,
+                allocs: None,
             });
             receipt.stats.wall_ms = U64Summary::new(42, 42, 42);
             receipt
@@ -927,6 +1044,19 @@ mod tests {
                     binary_bytes: None,
                     stdout: None,
                     stderr: None,
+                    latency_p50_us: None,
+                    latency_p95_us: None,
+                    latency_p99_us: None,
+                    error_rate: None,
+                    throughput_per_s: None,
+                    time_to_ready_ms: None,
+                    instructions: None,
+                    estimated_cycles: None,
+                    l1_hits: None,
+                    ll_hits: None,
+                    ram_hits: None,
+                    alloc_bytes: None,
+                    allocs: None,
                 },
                 Sample {
                     wall_ms: 11,
@@ -944,6 +1074,19 @@ mod tests {
                     binary_bytes: None,
                     stdout: None,
                     stderr: None,
+                    latency_p50_us: None,
+                    latency_p95_us: None,
+                    latency_p99_us: None,
+                    error_rate: None,
+                    throughput_per_s: None,
+                    time_to_ready_ms: None,
+                    instructions: None,
+                    estimated_cycles: None,
+                    l1_hits: None,
+                    ll_hits: None,
+                    ram_hits: None,
+                    alloc_bytes: None,
+                    allocs: None,
                 },
             ];
 
@@ -1403,6 +1546,10 @@ mod property_tests {
                 started_at,
                 ended_at,
                 host,
+                profile: None,
+                concurrency: None,
+                interleave: None,
+                noise_warnings: Vec::new(),
             })
     }
 
@@ -1425,6 +1572,8 @@ mod property_tests {
                     warmup,
                     work_units,
                     timeout_ms,
+                    service: None,
+                    load: None,
                 },
             )
     }
@@ -1473,6 +1622,19 @@ mod property_tests {
                     binary_bytes,
                     stdout: None,
                     stderr: None,
+                    latency_p50_us: None,
+                    latency_p95_us: None,
+                    latency_p99_us: None,
+                    error_rate: None,
+                    throughput_per_s: None,
+                    time_to_ready_ms: None,
+                    instructions: None,
+                    estimated_cycles: None,
+                    l1_hits: None,
+                    ll_hits: None,
+                    ram_hits: None,
+                    alloc_bytes: None,
+                    allocs: None,
                 },
             )
     }
@@ -1529,6 +1691,19 @@ mod property_tests {
                     energy_uj,
                     binary_bytes,
                     throughput_per_s,
+                    latency_p50_us: None,
+                    latency_p95_us: None,
+                    latency_p99_us: None,
+                    error_rate: None,
+                    time_to_ready_ms: None,
+                    instructions: None,
+                    estimated_cycles: None,
+                    l1_hits: None,
+                    ll_hits: None,
+                    ram_hits: None,
+                    alloc_bytes: None,
+                    allocs: None,
+                    custom: Default::default(),
                 },
             )
     }
@@ -1565,6 +1740,7 @@ mod property_tests {
                     threshold,
                     warn_threshold,
                     direction,
+                    auto_threshold: None,
                 }
             },
         )
@@ -1662,7 +1838,11 @@ mod property_tests {
             proptest::option::of(non_empty_string()),
             proptest::option::of(non_empty_string()),
         )
-            .prop_map(|(path, run_id)| CompareRef { path, run_id })
+            .prop_map(|(path, run_id)| CompareRef {
+                path,
+                run_id,
+                profile: None,
+            })
     }
 
     fn compare_receipt_strategy() -> impl Strategy<Value = CompareReceipt> {
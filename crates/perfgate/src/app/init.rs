@@ -364,6 +364,16 @@ pub fn generate_config(benchmarks: &[DiscoveredBench], preset: Preset) -> Config
             metrics: None,
             budgets: None,
             scaling: None,
+            matrix: None,
+            matrix_overrides: None,
+            service: None,
+            tags: Vec::new(),
+            paths: Vec::new(),
+            always: false,
+            load: None,
+            ready: None,
+            ready_timeout: None,
+            terminate_on_ready: false,
         })
         .collect();
 
@@ -0,0 +1,160 @@
+//! Interleaved sample execution for `check --interleave`.
+//!
+//! Every sample of the selected benches is run up front in a seeded shuffled
+//! order (see [`interleaved_sample_order`]). Each bench's check then measures
+//! through a [`ReplayRunner`] that hands back its recorded samples in order, so
+//! the rest of the check workflow is unchanged.
+
+use crate::app::RunBenchRequest;
+use crate::app::runtime::{AdapterError, CommandSpec, ProcessRunner, RunResult};
+use crate::domain::interleaved_sample_order;
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+/// Samples recorded for one bench, in the bench's own sample order.
+pub type RecordedSamples = VecDeque<Result<RunResult, AdapterError>>;
+
+/// Run the warmup and measured samples of `requests` in the interleaved order
+/// given by `seed`, returning each bench's samples.
+///
+/// A bench stops at its first sample that fails to run, as it would when run
+/// alone; its remaining slots are skipped.
+pub fn run_interleaved_samples<R: ProcessRunner>(
+    runner: &R,
+    requests: &[RunBenchRequest],
+    seed: u64,
+) -> Vec<RecordedSamples> {
+    let counts: Vec<u32> = requests.iter().map(|r| r.warmup + r.repeat).collect();
+    let specs: Vec<_> = requests.iter().map(RunBenchRequest::command_spec).collect();
+    let mut recorded: Vec<RecordedSamples> = vec![VecDeque::new(); requests.len()];
+
+    for bench in interleaved_sample_order(&counts, seed) {
+        if recorded[bench].back().is_some_and(Result::is_err) {
+            continue;
+        }
+        recorded[bench].push_back(runner.run(&specs[bench]));
+    }
+    recorded
+}
+
+/// Process runner that replays recorded samples for one command and runs
+/// anything else (or anything past the recording) with the inner runner.
+#[derive(Clone)]
+pub struct ReplayRunner<R> {
+    inner: R,
+    argv: Vec<String>,
+    recorded: Arc<Mutex<RecordedSamples>>,
+}
+
+impl<R> ReplayRunner<R> {
+    /// Replay `recorded` for runs of `argv`.
+    pub fn new(inner: R, argv: Vec<String>, recorded: RecordedSamples) -> Self {
+        Self {
+            inner,
+            argv,
+            recorded: Arc::new(Mutex::new(recorded)),
+        }
+    }
+}
+
+impl<R: ProcessRunner> ProcessRunner for ReplayRunner<R> {
+    fn run(&self, spec: &CommandSpec) -> Result<RunResult, AdapterError> {
+        if spec.argv == self.argv {
+            let next = self
+                .recorded
+                .lock()
+                .map_err(|_| AdapterError::Other("recorded samples poisoned".to_string()))?
+                .pop_front();
+            if let Some(result) = next {
+                return result;
+            }
+        }
+        self.inner.run(spec)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+
+    /// Runner that logs the command of every run and reports its position.
+    #[derive(Default)]
+    struct LoggingRunner {
+        log: RefCell<Vec<String>>,
+    }
+
+    impl ProcessRunner for LoggingRunner {
+        fn run(&self, spec: &CommandSpec) -> Result<RunResult, AdapterError> {
+            if spec.argv[0] == "missing" {
+                return Err(AdapterError::RunCommand {
+                    command: "missing".to_string(),
+                    reason: "not found".to_string(),
+                });
+            }
+            let mut log = self.log.borrow_mut();
+            log.push(spec.argv[0].clone());
+            Ok(RunResult {
+                wall_ms: log.len() as u64,
+                ..RunResult::default()
+            })
+        }
+    }
+
+    fn request(command: &str, warmup: u32, repeat: u32) -> RunBenchRequest {
+        RunBenchRequest {
+            name: command.to_string(),
+            cwd: None,
+            command: vec![command.to_string()],
+            repeat,
+            warmup,
+            work_units: None,
+            timeout: None,
+            env: Vec::new(),
+            output_cap_bytes: 1024,
+            allow_nonzero: false,
+            include_hostname_hash: false,
+            cpu_affinity: Vec::new(),
+            ready: None,
+        }
+    }
+
+    #[test]
+    fn samples_run_in_seeded_order_and_replay_per_bench() {
+        let runner = LoggingRunner::default();
+        let requests = [request("a", 1, 4), request("b", 0, 5)];
+        let recorded = run_interleaved_samples(&runner, &requests, 11);
+
+        let order: Vec<String> = interleaved_sample_order(&[5, 5], 11)
+            .into_iter()
+            .map(|bench| ["a", "b"][bench].to_string())
+            .collect();
+        assert_eq!(*runner.log.borrow(), order);
+        assert_eq!(recorded[0].len(), 5);
+        assert_eq!(recorded[1].len(), 5);
+
+        // Replayed samples come back in the bench's own order.
+        let replay = ReplayRunner::new(
+            LoggingRunner::default(),
+            vec!["a".into()],
+            recorded[0].clone(),
+        );
+        let spec = requests[0].command_spec();
+        let positions: Vec<u64> = (0..5).map(|_| replay.run(&spec).unwrap().wall_ms).collect();
+        assert!(positions.windows(2).all(|w| w[0] < w[1]), "{positions:?}");
+
+        // Past the recording, the inner runner takes over.
+        assert_eq!(replay.run(&spec).unwrap().wall_ms, 1);
+    }
+
+    #[test]
+    fn bench_stops_at_first_failed_sample() {
+        let runner = LoggingRunner::default();
+        let requests = [request("missing", 0, 3), request("b", 0, 2)];
+        let recorded = run_interleaved_samples(&runner, &requests, 5);
+
+        assert_eq!(recorded[0].len(), 1);
+        assert!(recorded[0][0].is_err());
+        assert_eq!(recorded[1].len(), 2);
+    }
+}
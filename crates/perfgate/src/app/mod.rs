@@ -12,6 +12,7 @@ pub mod baseline_resolve;
 pub mod bisect;
 pub mod blame;
 pub mod cargo_bench;
+pub mod changed_paths;
 mod check;
 pub mod comparison_logic;
 mod diff;
@@ -19,6 +20,7 @@ pub mod discover;
 mod explain;
 pub mod export;
 pub mod init;
+pub mod interleave;
 mod paired;
 mod probe;
 mod promote;
@@ -30,6 +32,7 @@ pub mod runtime;
 mod scenario;
 pub mod sensor;
 mod sensor_report;
+mod suite;
 mod tradeoff;
 mod trend;
 pub mod watch;
@@ -40,7 +43,10 @@ pub use badge::{
 };
 pub use bisect::{BisectRequest, BisectUseCase};
 pub use blame::{BlameOutcome, BlameRequest, BlameUseCase};
-pub use check::{CheckOutcome, CheckRequest, CheckUseCase};
+pub use check::{
+    CheckOutcome, CheckRequest, CheckUseCase, ConfirmationEvidence, ThresholdHistory,
+    apply_check_confirmation, apply_check_false_discovery_rate,
+};
 pub use diff::{
     BenchDiffOutcome, DiffOutcome, DiffRequest, DiffUseCase, discover_config, render_json_diff,
     render_terminal_diff,
@@ -59,6 +65,7 @@ pub use sensor_report::{
     BenchOutcome, SensorCheckOptions, SensorReportBuilder, classify_error,
     default_engine_capability, run_sensor_check, sensor_fingerprint,
 };
+pub use suite::{SuiteCompareOutcome, SuiteCompareRequest, SuiteCompareUseCase};
 pub use tradeoff::{TradeoffEvaluateOutcome, TradeoffEvaluateRequest, TradeoffUseCase};
 pub use trend::{
     TrendOutcome, TrendRequest, TrendUseCase, format_trend_chart, format_trend_output,
@@ -68,14 +75,17 @@ pub use trend::{
 pub use render::{
     direction_str, format_metric, format_metric_with_statistic, format_pct, format_value,
     github_annotations, markdown_template_context, metric_status_icon, metric_status_str,
-    parse_reason_token, render_complexity_section, render_markdown, render_markdown_template,
-    render_reason_line, render_tradeoff_markdown,
+    parse_reason_token, render_baseline_selection_section, render_complexity_section,
+    render_confirmation_section, render_markdown, render_markdown_template, render_reason_line,
+    render_suite_markdown, render_tradeoff_markdown,
 };
 
 // Re-export export functionality from the app-owned presentation module for backward compatibility.
 pub use export::{CompareExportRow, ExportFormat, ExportUseCase, RunExportRow};
 
-use self::runtime::{CommandSpec, HostProbe, HostProbeOptions, ProcessRunner, RunResult};
+use self::runtime::{
+    CommandSpec, HostProbe, HostProbeOptions, ProcessRunner, ReadyProbe, RunResult,
+};
 use crate::domain::{
     Comparison, SignificancePolicy, compare_runs_with_tradeoffs, compute_stats,
     detect_host_mismatch,
@@ -123,6 +133,28 @@ pub struct RunBenchRequest {
     /// If true, include a hashed hostname in the host fingerprint.
     /// This is opt-in for privacy reasons.
     pub include_hostname_hash: bool,
+
+    /// CPUs to pin every sample to. Empty leaves the affinity unchanged.
+    pub cpu_affinity: Vec<u32>,
+
+    /// Probe that records each sample's `time_to_ready_ms`.
+    pub ready: Option<ReadyProbe>,
+}
+
+impl RunBenchRequest {
+    /// The command run for each warmup and measured sample.
+    pub fn command_spec(&self) -> CommandSpec {
+        CommandSpec {
+            name: self.name.clone(),
+            argv: self.command.clone(),
+            cwd: self.cwd.clone(),
+            env: self.env.clone(),
+            timeout: self.timeout,
+            output_cap_bytes: self.output_cap_bytes,
+            cpu_affinity: self.cpu_affinity.clone(),
+            ready: self.ready.clone(),
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -170,25 +202,19 @@ impl<R: ProcessRunner, H: HostProbe, C: Clock> RunBenchUseCase<R, H, C> {
             warmup: req.warmup,
             work_units: req.work_units,
             timeout_ms: req.timeout.map(|d| d.as_millis() as u64),
+            service: None,
+            load: None,
         };
 
         let mut samples: Vec<Sample> = Vec::new();
         let mut reasons: Vec<String> = Vec::new();
 
         let total = req.warmup + req.repeat;
+        let spec = req.command_spec();
 
         for i in 0..total {
             let is_warmup = i < req.warmup;
 
-            let spec = CommandSpec {
-                name: req.name.clone(),
-                argv: req.command.clone(),
-                cwd: req.cwd.clone(),
-                env: req.env.clone(),
-                timeout: req.timeout,
-                output_cap_bytes: req.output_cap_bytes,
-            };
-
             let run = self.runner.run(&spec).map_err(|e| match e {
                 runtime::AdapterError::RunCommand { command, reason } => {
                     anyhow::anyhow!("failed to run iteration {}: {}: {}", i + 1, command, reason)
@@ -221,6 +247,10 @@ impl<R: ProcessRunner, H: HostProbe, C: Clock> RunBenchUseCase<R, H, C> {
                 started_at,
                 ended_at,
                 host,
+                profile: None,
+                concurrency: None,
+                interleave: None,
+                noise_warnings: Vec::new(),
             },
             bench,
             samples,
@@ -257,6 +287,12 @@ fn sample_from_run(run: RunResult, warmup: bool) -> Sample {
         network_packets: run.network_packets,
         energy_uj: run.energy_uj,
         binary_bytes: run.binary_bytes,
+        latency_p50_us: run.latency_p50_us,
+        latency_p95_us: run.latency_p95_us,
+        latency_p99_us: run.latency_p99_us,
+        error_rate: run.error_rate,
+        throughput_per_s: run.throughput_per_s,
+        time_to_ready_ms: run.time_to_ready_ms,
         stdout: if run.stdout.is_empty() {
             None
         } else {
@@ -267,6 +303,13 @@ fn sample_from_run(run: RunResult, warmup: bool) -> Sample {
         } else {
             Some(String::from_utf8_lossy(&run.stderr).to_string())
         },
+        instructions: None,
+        estimated_cycles: None,
+        l1_hits: None,
+        ll_hits: None,
+        ram_hits: None,
+        alloc_bytes: None,
+        allocs: None,
     }
 }
 
@@ -291,6 +334,12 @@ pub struct CompareResult {
     pub receipt: CompareReceipt,
     /// Host mismatch info if detected (only populated when policy is not Ignore).
     pub host_mismatch: Option<HostMismatchInfo>,
+    /// Description of a config profile mismatch between the two runs, subject
+    /// to the same policy as host mismatches.
+    pub profile_mismatch: Option<String>,
+    /// Description of a difference in parallel execution (`check --jobs`)
+    /// between the two runs, subject to the same policy as host mismatches.
+    pub concurrency_mismatch: Option<String>,
 }
 
 pub struct CompareUseCase;
@@ -314,6 +363,34 @@ impl CompareUseCase {
             );
         }
 
+        let profile_mismatch = if req.host_mismatch_policy != HostMismatchPolicy::Ignore {
+            detect_profile_mismatch(&req.baseline, &req.current)
+        } else {
+            None
+        };
+        if req.host_mismatch_policy == HostMismatchPolicy::Error
+            && let Some(mismatch) = &profile_mismatch
+        {
+            anyhow::bail!(
+                "profile mismatch detected (--host-mismatch=error): {}",
+                mismatch
+            );
+        }
+
+        let concurrency_mismatch = if req.host_mismatch_policy != HostMismatchPolicy::Ignore {
+            detect_concurrency_mismatch(&req.baseline, &req.current)
+        } else {
+            None
+        };
+        if req.host_mismatch_policy == HostMismatchPolicy::Error
+            && let Some(mismatch) = &concurrency_mismatch
+        {
+            anyhow::bail!(
+                "concurrency mismatch detected (--host-mismatch=error): {}",
+                mismatch
+            );
+        }
+
         let Comparison { deltas, verdict } = compare_runs_with_tradeoffs(
             &req.baseline,
             &req.current,
@@ -323,12 +400,17 @@ impl CompareUseCase {
             &req.tradeoffs,
         )?;
 
+        let mut baseline_ref = req.baseline_ref;
+        baseline_ref.profile = baseline_ref.profile.or(req.baseline.run.profile);
+        let mut current_ref = req.current_ref;
+        current_ref.profile = current_ref.profile.or(req.current.run.profile);
+
         let receipt = CompareReceipt {
             schema: perfgate_types::COMPARE_SCHEMA_V1.to_string(),
             tool: req.tool,
             bench: req.current.bench,
-            baseline_ref: req.baseline_ref,
-            current_ref: req.current_ref,
+            baseline_ref,
+            current_ref,
             budgets: req.budgets,
             deltas,
             verdict,
@@ -337,10 +419,46 @@ impl CompareUseCase {
         Ok(CompareResult {
             receipt,
             host_mismatch,
+            profile_mismatch,
+            concurrency_mismatch,
         })
     }
 }
 
+/// Describe a difference between the config profiles two runs were recorded
+/// under. Runs that predate profiles (no profile recorded) are not flagged.
+fn detect_profile_mismatch(baseline: &RunReceipt, current: &RunReceipt) -> Option<String> {
+    match (&baseline.run.profile, &current.run.profile) {
+        (Some(baseline), Some(current)) if baseline != current => Some(format!(
+            "baseline was recorded with profile '{}', current with profile '{}'",
+            baseline, current
+        )),
+        _ => None,
+    }
+}
+
+/// Describe a difference between how many benches ran alongside each run.
+/// Runs without recorded concurrency ran serially.
+fn detect_concurrency_mismatch(baseline: &RunReceipt, current: &RunReceipt) -> Option<String> {
+    let jobs = |receipt: &RunReceipt| receipt.run.concurrency.as_ref().map_or(1, |c| c.jobs);
+    let describe = |jobs: u32| {
+        if jobs <= 1 {
+            "serially".to_string()
+        } else {
+            format!("with {} concurrent jobs", jobs)
+        }
+    };
+    let (baseline_jobs, current_jobs) = (jobs(baseline), jobs(current));
+    if baseline_jobs.max(1) == current_jobs.max(1) {
+        return None;
+    }
+    Some(format!(
+        "baseline was recorded {}, current {}",
+        describe(baseline_jobs),
+        describe(current_jobs)
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -360,6 +478,7 @@ mod tests {
                 noise_threshold: None,
                 noise_policy: perfgate_types::NoisePolicy::Ignore,
                 direction: Direction::Lower,
+                auto_threshold: None,
             },
         );
 
@@ -394,14 +513,18 @@ mod tests {
                 warmup: 0,
                 work_units: None,
                 timeout_ms: None,
+                service: None,
+                load: None,
             },
             baseline_ref: CompareRef {
                 path: None,
                 run_id: None,
+                profile: None,
             },
             current_ref: CompareRef {
                 path: None,
                 run_id: None,
+                profile: None,
             },
             budgets,
             deltas,
@@ -430,6 +553,10 @@ mod tests {
                 started_at: "2024-01-01T00:00:00Z".to_string(),
                 ended_at: "2024-01-01T00:00:01Z".to_string(),
                 host,
+                profile: None,
+                concurrency: None,
+                interleave: None,
+                noise_warnings: Vec::new(),
             },
             bench: BenchMeta {
                 name: "bench".to_string(),
@@ -439,6 +566,8 @@ mod tests {
                 warmup: 0,
                 work_units: None,
                 timeout_ms: None,
+                service: None,
+                load: None,
             },
             samples: Vec::new(),
             stats: Stats {
@@ -453,6 +582,19 @@ mod tests {
                 energy_uj: None,
                 binary_bytes: None,
                 throughput_per_s: None,
+                latency_p50_us: None,
+                latency_p95_us: None,
+                latency_p99_us: None,
+                error_rate: None,
+                time_to_ready_ms: None,
+                instructions: None,
+                estimated_cycles: None,
+                l1_hits: None,
+                ll_hits: None,
+                ram_hits: None,
+                alloc_bytes: None,
+                allocs: None,
+                custom: Default::default(),
             },
         }
     }
@@ -468,6 +610,7 @@ mod tests {
                 noise_threshold: None,
                 noise_policy: perfgate_types::NoisePolicy::Ignore,
                 direction: Direction::Lower,
+                auto_threshold: None,
             },
         );
 
@@ -502,14 +645,18 @@ mod tests {
                 warmup: 0,
                 work_units: None,
                 timeout_ms: None,
+                service: None,
+                load: None,
             },
             baseline_ref: CompareRef {
                 path: None,
                 run_id: None,
+                profile: None,
             },
             current_ref: CompareRef {
                 path: None,
                 run_id: None,
+                profile: None,
             },
             budgets,
             deltas,
@@ -648,6 +795,12 @@ mod tests {
             binary_bytes: None,
             stdout: b"ok".to_vec(),
             stderr: vec![],
+            latency_p50_us: None,
+            latency_p95_us: None,
+            latency_p99_us: None,
+            error_rate: None,
+            throughput_per_s: None,
+            time_to_ready_ms: None,
         };
 
         let sample = sample_from_run(run, false);
@@ -687,6 +840,7 @@ mod tests {
                 noise_threshold: None,
                 noise_policy: perfgate_types::NoisePolicy::Ignore,
                 direction: Direction::Lower,
+                auto_threshold: None,
             },
         );
 
@@ -700,10 +854,12 @@ mod tests {
             baseline_ref: CompareRef {
                 path: None,
                 run_id: None,
+                profile: None,
             },
             current_ref: CompareRef {
                 path: None,
                 run_id: None,
+                profile: None,
             },
             tool: ToolInfo {
                 name: "perfgate".to_string(),
@@ -724,10 +880,12 @@ mod tests {
             baseline_ref: CompareRef {
                 path: None,
                 run_id: None,
+                profile: None,
             },
             current_ref: CompareRef {
                 path: None,
                 run_id: None,
+                profile: None,
             },
             tool: ToolInfo {
                 name: "perfgate".to_string(),
@@ -748,10 +906,12 @@ mod tests {
             baseline_ref: CompareRef {
                 path: None,
                 run_id: None,
+                profile: None,
             },
             current_ref: CompareRef {
                 path: None,
                 run_id: None,
+                profile: None,
             },
             tool: ToolInfo {
                 name: "perfgate".to_string(),
@@ -763,6 +923,130 @@ mod tests {
 
         assert!(ignore.host_mismatch.is_none());
     }
+
+    #[test]
+    fn compare_use_case_records_and_flags_profiles() {
+        let host = HostInfo {
+            os: "linux".to_string(),
+            arch: "x86_64".to_string(),
+            cpu_count: None,
+            memory_bytes: None,
+            hostname_hash: None,
+        };
+        let mut baseline = make_run_receipt_with_host(host.clone(), 100);
+        baseline.run.profile = Some("local".to_string());
+        let mut current = make_run_receipt_with_host(host, 100);
+        current.run.profile = Some("ci".to_string());
+
+        let request = |policy| CompareRequest {
+            baseline: baseline.clone(),
+            current: current.clone(),
+            budgets: BTreeMap::new(),
+            metric_statistics: BTreeMap::new(),
+            significance: None,
+            tradeoffs: Vec::new(),
+            baseline_ref: CompareRef {
+                path: None,
+                run_id: None,
+                profile: None,
+            },
+            current_ref: CompareRef {
+                path: None,
+                run_id: None,
+                profile: None,
+            },
+            tool: ToolInfo {
+                name: "perfgate".to_string(),
+                version: "0.1.0".to_string(),
+            },
+            host_mismatch_policy: policy,
+        };
+
+        let warned = CompareUseCase::execute(request(HostMismatchPolicy::Warn)).expect("warn");
+        assert_eq!(
+            warned.receipt.baseline_ref.profile.as_deref(),
+            Some("local")
+        );
+        assert_eq!(warned.receipt.current_ref.profile.as_deref(), Some("ci"));
+        assert_eq!(
+            warned.profile_mismatch.as_deref(),
+            Some("baseline was recorded with profile 'local', current with profile 'ci'")
+        );
+
+        let err = CompareUseCase::execute(request(HostMismatchPolicy::Error)).unwrap_err();
+        assert!(err.to_string().contains("profile mismatch"), "{err}");
+
+        let ignored = CompareUseCase::execute(request(HostMismatchPolicy::Ignore)).expect("ignore");
+        assert!(ignored.profile_mismatch.is_none());
+        assert_eq!(ignored.receipt.current_ref.profile.as_deref(), Some("ci"));
+    }
+
+    #[test]
+    fn compare_use_case_flags_serial_vs_parallel_runs() {
+        let host = HostInfo {
+            os: "linux".to_string(),
+            arch: "x86_64".to_string(),
+            cpu_count: None,
+            memory_bytes: None,
+            hostname_hash: None,
+        };
+        let baseline = make_run_receipt_with_host(host.clone(), 100);
+        let mut current = make_run_receipt_with_host(host, 100);
+        current.run.concurrency = Some(perfgate_types::RunConcurrency {
+            jobs: 4,
+            cpus: vec![2, 3],
+        });
+
+        let request = |baseline: &RunReceipt, current: &RunReceipt, policy| CompareRequest {
+            baseline: baseline.clone(),
+            current: current.clone(),
+            budgets: BTreeMap::new(),
+            metric_statistics: BTreeMap::new(),
+            significance: None,
+            tradeoffs: Vec::new(),
+            baseline_ref: CompareRef {
+                path: None,
+                run_id: None,
+                profile: None,
+            },
+            current_ref: CompareRef {
+                path: None,
+                run_id: None,
+                profile: None,
+            },
+            tool: ToolInfo {
+                name: "perfgate".to_string(),
+                version: "0.1.0".to_string(),
+            },
+            host_mismatch_policy: policy,
+        };
+
+        let warned =
+            CompareUseCase::execute(request(&baseline, &current, HostMismatchPolicy::Warn))
+                .expect("warn");
+        assert_eq!(
+            warned.concurrency_mismatch.as_deref(),
+            Some("baseline was recorded serially, current with 4 concurrent jobs")
+        );
+
+        let err = CompareUseCase::execute(request(&baseline, &current, HostMismatchPolicy::Error))
+            .unwrap_err();
+        assert!(err.to_string().contains("concurrency mismatch"), "{err}");
+
+        // Same concurrency level on different cores is comparable.
+        let mut parallel_baseline = baseline.clone();
+        parallel_baseline.run.concurrency = Some(perfgate_types::RunConcurrency {
+            jobs: 4,
+            cpus: vec![0, 1],
+        });
+        let same = CompareUseCase::execute(request(
+            &parallel_baseline,
+            &current,
+            HostMismatchPolicy::Warn,
+        ))
+        .expect("same jobs");
+        assert!(same.concurrency_mismatch.is_none());
+    }
 }
 
 #[cfg(test)]
@@ -806,6 +1090,8 @@ mod property_tests {
                     warmup,
                     work_units,
                     timeout_ms,
+                    service: None,
+                    load: None,
                 },
             )
     }
@@ -816,7 +1102,11 @@ mod property_tests {
             proptest::option::of(non_empty_string()),
             proptest::option::of(non_empty_string()),
         )
-            .prop_map(|(path, run_id)| CompareRef { path, run_id })
+            .prop_map(|(path, run_id)| CompareRef {
+                path,
+                run_id,
+                profile: None,
+            })
     }
 
     // Strategy for Direction
@@ -836,6 +1126,7 @@ mod property_tests {
                     threshold,
                     warn_threshold,
                     direction,
+                    auto_threshold: None,
                 }
             },
         )
@@ -36,6 +36,8 @@ pub struct PairedRunRequest {
     /// the wall-time differences exceeds this value, retries are aborted because
     /// the benchmark is too noisy for significance to be achievable.
     pub cv_threshold: Option<f64>,
+    /// CPUs to pin both commands to. Empty leaves the affinity unchanged.
+    pub cpu_affinity: Vec<u32>,
 }
 
 #[derive(Debug, Clone)]
@@ -180,6 +182,10 @@ impl<R: ProcessRunner, H: HostProbe, C: Clock> PairedRunUseCase<R, H, C> {
                 started_at,
                 ended_at,
                 host,
+                profile: None,
+                concurrency: None,
+                interleave: None,
+                noise_warnings: Vec::new(),
             },
             bench,
             samples,
@@ -222,6 +228,8 @@ impl<R: ProcessRunner, H: HostProbe, C: Clock> PairedRunUseCase<R, H, C> {
             env: req.env.clone(),
             timeout: req.timeout,
             output_cap_bytes: req.output_cap_bytes,
+            cpu_affinity: req.cpu_affinity.clone(),
+            ready: None,
         };
         let baseline_run = self.runner.run(&baseline_spec).map_err(|e| match e {
             AdapterError::RunCommand { command, reason } => {
@@ -242,6 +250,8 @@ impl<R: ProcessRunner, H: HostProbe, C: Clock> PairedRunUseCase<R, H, C> {
             env: req.env.clone(),
             timeout: req.timeout,
             output_cap_bytes: req.output_cap_bytes,
+            cpu_affinity: req.cpu_affinity.clone(),
+            ready: None,
         };
         let current_run = self.runner.run(&current_spec).map_err(|e| match e {
             AdapterError::RunCommand { command, reason } => {
@@ -328,18 +338,24 @@ mod tests {
     #[derive(Clone)]
     struct TestRunner {
         runs: Arc<Mutex<Vec<RunResult>>>,
+        seen_affinity: Arc<Mutex<Vec<Vec<u32>>>>,
     }
 
     impl TestRunner {
         fn new(runs: Vec<RunResult>) -> Self {
             Self {
                 runs: Arc::new(Mutex::new(runs)),
+                seen_affinity: Arc::new(Mutex::new(Vec::new())),
             }
         }
     }
 
     impl ProcessRunner for TestRunner {
-        fn run(&self, _spec: &CommandSpec) -> Result<RunResult, AdapterError> {
+        fn run(&self, spec: &CommandSpec) -> Result<RunResult, AdapterError> {
+            self.seen_affinity
+                .lock()
+                .expect("lock seen affinity")
+                .push(spec.cpu_affinity.clone());
             let mut runs = self.runs.lock().expect("lock runs");
             if runs.is_empty() {
                 return Err(AdapterError::Other("no more queued runs".to_string()));
@@ -415,6 +431,12 @@ mod tests {
             binary_bytes: None,
             stdout: stdout.to_vec(),
             stderr: stderr.to_vec(),
+            latency_p50_us: None,
+            latency_p95_us: None,
+            latency_p99_us: None,
+            error_rate: None,
+            throughput_per_s: None,
+            time_to_ready_ms: None,
         }
     }
 
@@ -443,6 +465,7 @@ mod tests {
         ];
 
         let runner = TestRunner::new(runs);
+        let seen_affinity = runner.seen_affinity.clone();
         let host = HostInfo {
             os: "linux".to_string(),
             arch: "x86_64".to_string(),
@@ -483,6 +506,7 @@ mod tests {
                 max_retries: 0,
                 fail_on_regression: None,
                 cv_threshold: None,
+                cpu_affinity: vec![2, 3],
             })
             .expect("paired run should succeed");
 
@@ -518,6 +542,10 @@ mod tests {
         let seen = host_probe.seen_include_hash.lock().expect("lock seen");
         assert_eq!(seen.as_slice(), &[true]);
         assert_eq!(outcome.receipt.run.host, host);
+
+        let seen_affinity = seen_affinity.lock().expect("lock seen affinity");
+        assert_eq!(seen_affinity.len(), 4);
+        assert!(seen_affinity.iter().all(|cpus| cpus == &[2, 3]));
     }
 
     #[test]
@@ -571,6 +599,7 @@ mod tests {
                 max_retries: 0,
                 fail_on_regression: None,
                 cv_threshold: None,
+                cpu_affinity: Vec::new(),
             })
             .expect("paired run should succeed");
 
@@ -624,6 +653,7 @@ mod tests {
                 max_retries: 0,
                 fail_on_regression: None,
                 cv_threshold: None,
+                cpu_affinity: Vec::new(),
             })
             .unwrap_err();
 
@@ -684,6 +714,7 @@ mod tests {
                 max_retries: 0,
                 fail_on_regression: None,
                 cv_threshold: None,
+                cpu_affinity: Vec::new(),
             })
             .expect("paired run should succeed");
 
@@ -776,6 +807,7 @@ mod tests {
                 max_retries: 5, // Allow up to 5 retries
                 fail_on_regression: None,
                 cv_threshold: None,
+                cpu_affinity: Vec::new(),
             })
             .expect("paired run should succeed");
 
@@ -855,6 +887,7 @@ mod tests {
                 max_retries: 5,
                 fail_on_regression: None,
                 cv_threshold: Some(0.5),
+                cpu_affinity: Vec::new(),
             })
             .expect("paired run should succeed");
 
@@ -926,6 +959,7 @@ mod tests {
                 max_retries: 0,
                 fail_on_regression: None,
                 cv_threshold: None,
+                cpu_affinity: Vec::new(),
             })
             .expect("paired run should succeed");
 
@@ -420,6 +420,10 @@ fn make_run_meta() -> RunMeta {
             memory_bytes: None,
             hostname_hash: None,
         },
+        profile: None,
+        concurrency: None,
+        interleave: None,
+        noise_warnings: Vec::new(),
     }
 }
 
@@ -446,6 +450,10 @@ mod tests {
                     memory_bytes: None,
                     hostname_hash: None,
                 },
+                profile: None,
+                concurrency: None,
+                interleave: None,
+                noise_warnings: Vec::new(),
             },
             bench: None,
             scenario: Some("large_file_parse".to_string()),
@@ -491,10 +499,12 @@ mod tests {
             baseline_ref: CompareRef {
                 path: Some("baselines/probes.json".to_string()),
                 run_id: Some("base".to_string()),
+                profile: None,
             },
             current_ref: CompareRef {
                 path: Some("artifacts/probes.json".to_string()),
                 run_id: Some("current".to_string()),
+                profile: None,
             },
             tool: ToolInfo {
                 name: "perfgate".to_string(),
@@ -521,10 +531,12 @@ mod tests {
             baseline_ref: CompareRef {
                 path: Some("baselines/probes.json".to_string()),
                 run_id: Some("base".to_string()),
+                profile: None,
             },
             current_ref: CompareRef {
                 path: Some("artifacts/probes.json".to_string()),
                 run_id: Some("current".to_string()),
+                profile: None,
             },
             tool: ToolInfo {
                 name: "perfgate".to_string(),
@@ -550,10 +562,12 @@ mod tests {
             baseline_ref: CompareRef {
                 path: None,
                 run_id: None,
+                profile: None,
             },
             current_ref: CompareRef {
                 path: None,
                 run_id: None,
+                profile: None,
             },
             tool: ToolInfo {
                 name: "perfgate".to_string(),
@@ -585,10 +599,12 @@ mod tests {
             baseline_ref: CompareRef {
                 path: None,
                 run_id: None,
+                profile: None,
             },
             current_ref: CompareRef {
                 path: None,
                 run_id: None,
+                profile: None,
             },
             tool: ToolInfo {
                 name: "perfgate".to_string(),
@@ -623,10 +639,12 @@ mod tests {
             baseline_ref: CompareRef {
                 path: None,
                 run_id: None,
+                profile: None,
             },
             current_ref: CompareRef {
                 path: None,
                 run_id: None,
+                profile: None,
             },
             tool: ToolInfo {
                 name: "perfgate".to_string(),
@@ -657,10 +675,12 @@ mod tests {
             baseline_ref: CompareRef {
                 path: None,
                 run_id: None,
+                profile: None,
             },
             current_ref: CompareRef {
                 path: None,
                 run_id: None,
+                profile: None,
             },
             tool: ToolInfo {
                 name: "perfgate".to_string(),
@@ -56,6 +56,10 @@ impl PromoteUseCase {
                 memory_bytes: receipt.run.host.memory_bytes,
                 hostname_hash: receipt.run.host.hostname_hash,
             },
+            profile: None,
+            concurrency: None,
+            interleave: None,
+            noise_warnings: Vec::new(),
         };
         receipt
     }
@@ -84,6 +88,10 @@ mod tests {
                     memory_bytes: Some(8_000_000_000),
                     hostname_hash: Some("testhash123".to_string()),
                 },
+                profile: None,
+                concurrency: None,
+                interleave: None,
+                noise_warnings: Vec::new(),
             },
             bench: BenchMeta {
                 name: "test-benchmark".to_string(),
@@ -93,6 +101,8 @@ mod tests {
                 warmup: 0,
                 work_units: None,
                 timeout_ms: None,
+                service: None,
+                load: None,
             },
             samples: vec![Sample {
                 wall_ms: 100,
@@ -110,6 +120,19 @@ mod tests {
                 binary_bytes: None,
                 stdout: None,
                 stderr: None,
+                latency_p50_us: None,
+                latency_p95_us: None,
+                latency_p99_us: None,
+                error_rate: None,
+                throughput_per_s: None,
+                time_to_ready_ms: None,
+                instructions: None,
+                estimated_cycles: None,
+                l1_hits: None,
+                ll_hits: None,
+                ram_hits: None,
+                alloc_bytes: None,
+                allocs: None,
             }],
             stats: Stats {
                 wall_ms: U64Summary::new(100, 100, 100),
@@ -123,6 +146,19 @@ mod tests {
                 energy_uj: None,
                 binary_bytes: None,
                 throughput_per_s: None,
+                latency_p50_us: None,
+                latency_p95_us: None,
+                latency_p99_us: None,
+                error_rate: None,
+                time_to_ready_ms: None,
+                instructions: None,
+                estimated_cycles: None,
+                l1_hits: None,
+                ll_hits: None,
+                ram_hits: None,
+                alloc_bytes: None,
+                allocs: None,
+                custom: Default::default(),
             },
         }
     }
@@ -163,6 +163,7 @@ mod tests {
                     current_samples: 12,
                     ci_lower: None,
                     ci_upper: None,
+                    adjusted_p_value: None,
                 }),
                 status: MetricStatus::Pass,
             },
@@ -182,14 +183,18 @@ mod tests {
                 warmup: 0,
                 work_units: None,
                 timeout_ms: None,
+                service: None,
+                load: None,
             },
             baseline_ref: CompareRef {
                 path: None,
                 run_id: None,
+                profile: None,
             },
             current_ref: CompareRef {
                 path: None,
                 run_id: None,
+                profile: None,
             },
             budgets,
             deltas,
@@ -9,9 +9,10 @@ pub mod summary;
 
 use anyhow::Context;
 use perfgate_types::{
-    CompareReceipt, ComplexityGateResult, ComplexityGateStatus, Delta, Direction, Metric,
-    MetricStatistic, MetricStatus, TradeoffAllowanceOutcome, TradeoffDecisionStatus,
-    TradeoffReceipt, TradeoffRequirementOutcome,
+    CompareReceipt, ComplexityGateResult, ComplexityGateStatus, Confirmation, Delta, Direction,
+    MergeBaseSelection, Metric, MetricStatistic, MetricStatus, SuiteCompareReceipt,
+    TradeoffAllowanceOutcome, TradeoffDecisionStatus, TradeoffReceipt, TradeoffRequirementOutcome,
+    VerdictStatus,
 };
 use serde_json::json;
 
@@ -276,6 +277,101 @@ pub fn render_tradeoff_markdown(tradeoff: &TradeoffReceipt) -> String {
     out
 }
 
+/// Render a [`SuiteCompareReceipt`] as Markdown: one row per bench plus the
+/// suite verdict, geometric-mean change, and added/removed benches.
+pub fn render_suite_markdown(suite: &SuiteCompareReceipt) -> String {
+    let mut out = String::new();
+
+    out.push_str(match suite.verdict.status {
+        VerdictStatus::Pass => "✅ perfgate suite: pass",
+        VerdictStatus::Warn => "⚠️ perfgate suite: warn",
+        VerdictStatus::Fail => "❌ perfgate suite: fail",
+        VerdictStatus::Skip => "⏭️ perfgate suite: skip",
+    });
+    out.push_str("\n\n");
+
+    out.push_str(&format!(
+        "**Benches:** {} compared, {} added, {} removed\n\n",
+        suite.benches.len(),
+        suite.added.len(),
+        suite.removed.len()
+    ));
+
+    if !suite.geomean.is_empty() {
+        out.push_str("| metric | geometric mean change | benches |\n");
+        out.push_str("|---|---:|---:|\n");
+        for (metric, geomean) in &suite.geomean {
+            out.push_str(&format!(
+                "| `{}` | {} | {} |\n",
+                format_metric(*metric),
+                format_pct(geomean.pct),
+                geomean.bench_count
+            ));
+        }
+        out.push('\n');
+    }
+
+    if !suite.benches.is_empty() {
+        out.push_str("| bench | changes | status |\n");
+        out.push_str("|---|---|---|\n");
+        for bench in &suite.benches {
+            let changes = bench
+                .compare
+                .deltas
+                .iter()
+                .map(|(metric, delta)| {
+                    format!(
+                        "`{}` {} {}",
+                        format_metric(*metric),
+                        format_pct(delta.pct),
+                        metric_status_icon(delta.status)
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+            out.push_str(&format!(
+                "| `{}` | {} | {} |\n",
+                bench.name,
+                changes,
+                match bench.status {
+                    VerdictStatus::Pass => metric_status_icon(MetricStatus::Pass),
+                    VerdictStatus::Warn => metric_status_icon(MetricStatus::Warn),
+                    VerdictStatus::Fail => metric_status_icon(MetricStatus::Fail),
+                    VerdictStatus::Skip => metric_status_icon(MetricStatus::Skip),
+                }
+            ));
+        }
+    }
+
+    if !suite.added.is_empty() {
+        out.push_str("\n**Added (no baseline):** ");
+        out.push_str(&render_bench_list(&suite.added));
+        out.push('\n');
+    }
+    if !suite.removed.is_empty() {
+        out.push_str("\n**Removed (no current run):** ");
+        out.push_str(&render_bench_list(&suite.removed));
+        out.push('\n');
+    }
+
+    if !suite.verdict.reasons.is_empty() {
+        out.push_str("\n**Notes:**\n");
+        for reason in &suite.verdict.reasons {
+            out.push_str(&format!("- {reason}\n"));
+        }
+    }
+
+    out
+}
+
+fn render_bench_list(names: &[String]) -> String {
+    names
+        .iter()
+        .map(|name| format!("`{name}`"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
 fn render_tradeoff_requirement(requirement: &TradeoffRequirementOutcome) -> String {
     let observed = requirement
         .observed_change
@@ -369,6 +465,53 @@ pub fn render_complexity_section(complexity: &ComplexityGateResult) -> String {
     out
 }
 
+/// Render the merge-base baseline selection for markdown reports.
+pub fn render_baseline_selection_section(selection: &MergeBaseSelection) -> String {
+    let mut out = String::new();
+    out.push_str("\n### Baseline\n\n");
+    out.push_str(&format!(
+        "Compared against the {}.\n\n",
+        selection.describe()
+    ));
+    out.push_str(&format!(
+        "* Selected commit: `{}`\n",
+        selection.selected_sha
+    ));
+    out.push_str(&format!("* Merge-base: `{}`\n", selection.merge_base));
+    out
+}
+
+/// Render a confirmation section for markdown reports.
+pub fn render_confirmation_section(confirmation: &Confirmation) -> String {
+    let mut out = String::new();
+    out.push_str("\n### Confirmation\n\n");
+    let status = if confirmation.confirmed {
+        "regression confirmed"
+    } else {
+        "regression not confirmed"
+    };
+    out.push_str(&format!(
+        "**{}** by a {} re-measurement (original verdict: `{}`)\n\n",
+        status,
+        confirmation.mode.as_str(),
+        confirmation.original_status.as_str()
+    ));
+    for (metric, statuses) in &confirmation.metrics {
+        out.push_str(&format!(
+            "* {}: {} → {}\n",
+            format_metric(*metric),
+            statuses.original.as_str(),
+            statuses
+                .confirmation
+                .map_or("not re-measured", MetricStatus::as_str)
+        ));
+    }
+    for artifact in &confirmation.artifacts {
+        out.push_str(&format!("* Evidence: `{artifact}`\n"));
+    }
+    out
+}
+
 /// Render a [`CompareReceipt`] using a custom [Handlebars](https://docs.rs/handlebars) template.
 pub fn render_markdown_template(
     compare: &CompareReceipt,
@@ -571,17 +714,28 @@ pub fn render_reason_line(compare: &CompareReceipt, token: &str) -> String {
 /// Format a metric value for display.
 pub fn format_value(metric: Metric, v: f64) -> String {
     match metric {
-        Metric::BinaryBytes
+        Metric::AllocBytes
+        | Metric::Allocs
+        | Metric::BinaryBytes
         | Metric::CpuMs
         | Metric::CtxSwitches
         | Metric::EnergyUj
+        | Metric::EstimatedCycles
+        | Metric::Instructions
         | Metric::IoReadBytes
         | Metric::IoWriteBytes
+        | Metric::L1Hits
+        | Metric::LatencyP50Us
+        | Metric::LatencyP95Us
+        | Metric::LatencyP99Us
+        | Metric::LlHits
         | Metric::MaxRssKb
         | Metric::NetworkPackets
         | Metric::PageFaults
+        | Metric::RamHits
+        | Metric::TimeToReadyMs
         | Metric::WallMs => format!("{:.0}", v),
-        Metric::ThroughputPerS => format!("{:.3}", v),
+        Metric::ErrorRate | Metric::ThroughputPerS => format!("{:.3}", v),
     }
 }
 
@@ -679,14 +833,18 @@ mod tests {
                 warmup: 0,
                 work_units: None,
                 timeout_ms: None,
+                service: None,
+                load: None,
             },
             baseline_ref: CompareRef {
                 path: None,
                 run_id: None,
+                profile: None,
             },
             current_ref: CompareRef {
                 path: None,
                 run_id: None,
+                profile: None,
             },
             budgets,
             deltas,
@@ -753,6 +911,10 @@ mod tests {
                     memory_bytes: None,
                     hostname_hash: None,
                 },
+                profile: None,
+                concurrency: None,
+                interleave: None,
+                noise_warnings: Vec::new(),
             },
             scenario: Some("release_workload".to_string()),
             baseline_ref: None,
@@ -851,6 +1013,65 @@ mod tests {
         }
     }
 
+    #[test]
+    fn render_suite_markdown_lists_benches_and_geomean() {
+        let suite = SuiteCompareReceipt {
+            schema: perfgate_types::SUITE_COMPARE_SCHEMA_V1.to_string(),
+            tool: ToolInfo {
+                name: "perfgate".into(),
+                version: "0.1.0".into(),
+            },
+            baseline_ref: CompareRef {
+                path: Some("baselines".into()),
+                run_id: None,
+                profile: None,
+            },
+            current_ref: CompareRef {
+                path: Some("artifacts/perfgate".into()),
+                run_id: None,
+                profile: None,
+            },
+            benches: vec![perfgate_types::SuiteBenchCompare {
+                name: "bench".into(),
+                status: VerdictStatus::Warn,
+                compare: make_compare_receipt(MetricStatus::Warn),
+            }],
+            added: vec!["fresh".into()],
+            removed: vec!["retired".into()],
+            geomean: BTreeMap::from([(
+                Metric::WallMs,
+                perfgate_types::SuiteGeomean {
+                    ratio: 1.15,
+                    pct: 0.15,
+                    bench_count: 1,
+                },
+            )]),
+            verdict: Verdict {
+                status: VerdictStatus::Warn,
+                counts: VerdictCounts {
+                    pass: 0,
+                    warn: 1,
+                    fail: 0,
+                    skip: 0,
+                },
+                reasons: vec![
+                    "bench:wall_ms_warn".into(),
+                    "retired:suite_bench_removed".into(),
+                ],
+            },
+            warnings: Vec::new(),
+        };
+
+        let md = render_suite_markdown(&suite);
+        assert!(md.starts_with("⚠️ perfgate suite: warn"));
+        assert!(md.contains("**Benches:** 1 compared, 1 added, 1 removed"));
+        assert!(md.contains("| `wall_ms` | +15.00% | 1 |"));
+        assert!(md.contains("| `bench` | `wall_ms` +15.00% ⚠️ | ⚠️ |"));
+        assert!(md.contains("**Added (no baseline):** `fresh`"));
+        assert!(md.contains("**Removed (no current run):** `retired`"));
+        assert!(md.contains("- retired:suite_bench_removed"));
+    }
+
     #[test]
     fn markdown_renders_table() {
         let receipt = make_compare_receipt(MetricStatus::Pass);
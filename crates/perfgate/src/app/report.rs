@@ -112,6 +112,9 @@ impl ReportUseCase {
             summary,
             complexity: None,
             profile_path: None,
+            confirmation: None,
+            skip_reason: None,
+            baseline_selection: None,
         };
 
         ReportResult { report }
@@ -167,14 +170,18 @@ mod tests {
                 warmup: 0,
                 work_units: None,
                 timeout_ms: None,
+                service: None,
+                load: None,
             },
             baseline_ref: CompareRef {
                 path: Some("baseline.json".to_string()),
                 run_id: Some("baseline-001".to_string()),
+                profile: None,
             },
             current_ref: CompareRef {
                 path: Some("current.json".to_string()),
                 run_id: Some("current-001".to_string()),
+                profile: None,
             },
             budgets,
             deltas,
@@ -226,14 +233,18 @@ mod tests {
                 warmup: 0,
                 work_units: None,
                 timeout_ms: None,
+                service: None,
+                load: None,
             },
             baseline_ref: CompareRef {
                 path: Some("baseline.json".to_string()),
                 run_id: Some("baseline-001".to_string()),
+                profile: None,
             },
             current_ref: CompareRef {
                 path: Some("current.json".to_string()),
                 run_id: Some("current-001".to_string()),
+                profile: None,
             },
             budgets,
             deltas,
@@ -285,14 +296,18 @@ mod tests {
                 warmup: 0,
                 work_units: None,
                 timeout_ms: None,
+                service: None,
+                load: None,
             },
             baseline_ref: CompareRef {
                 path: Some("baseline.json".to_string()),
                 run_id: Some("baseline-001".to_string()),
+                profile: None,
             },
             current_ref: CompareRef {
                 path: Some("current.json".to_string()),
                 run_id: Some("current-001".to_string()),
+                profile: None,
             },
             budgets,
             deltas,
@@ -347,14 +362,18 @@ mod tests {
                 warmup: 0,
                 work_units: None,
                 timeout_ms: None,
+                service: None,
+                load: None,
             },
             baseline_ref: CompareRef {
                 path: Some("baseline.json".to_string()),
                 run_id: Some("baseline-001".to_string()),
+                profile: None,
             },
             current_ref: CompareRef {
                 path: Some("current.json".to_string()),
                 run_id: Some("current-001".to_string()),
+                profile: None,
             },
             budgets,
             deltas,
@@ -530,14 +549,18 @@ mod tests {
                 warmup: 2,
                 work_units: None,
                 timeout_ms: None,
+                service: None,
+                load: None,
             },
             baseline_ref: CompareRef {
                 path: Some("baseline.json".to_string()),
                 run_id: Some("base-001".to_string()),
+                profile: None,
             },
             current_ref: CompareRef {
                 path: Some("current.json".to_string()),
                 run_id: Some("cur-001".to_string()),
+                profile: None,
             },
             budgets,
             deltas,
@@ -629,14 +652,18 @@ mod tests {
                 warmup: 0,
                 work_units: None,
                 timeout_ms: None,
+                service: None,
+                load: None,
             },
             baseline_ref: CompareRef {
                 path: None,
                 run_id: None,
+                profile: None,
             },
             current_ref: CompareRef {
                 path: None,
                 run_id: None,
+                profile: None,
             },
             budgets,
             deltas,
@@ -703,6 +730,8 @@ mod property_tests {
                     warmup,
                     work_units,
                     timeout_ms,
+                    service: None,
+                    load: None,
                 },
             )
     }
@@ -712,7 +741,11 @@ mod property_tests {
             proptest::option::of(non_empty_string()),
             proptest::option::of(non_empty_string()),
         )
-            .prop_map(|(path, run_id)| CompareRef { path, run_id })
+            .prop_map(|(path, run_id)| CompareRef {
+                path,
+                run_id,
+                profile: None,
+            })
     }
 
     fn direction_strategy() -> impl Strategy<Value = Direction> {
@@ -729,6 +762,7 @@ mod property_tests {
                     threshold,
                     warn_threshold,
                     direction,
+                    auto_threshold: None,
                 }
             },
         )
@@ -22,17 +22,28 @@
 //! ```
 
 mod fake;
+mod http_load;
+mod ready;
+mod service;
 
 pub use fake::FakeProcessRunner;
+pub use http_load::{HttpLoadRunner, HttpLoadSpec};
+pub use ready::ReadyProbe;
+pub use service::{RunningService, ServiceRunner, ServiceSpec};
 
 pub use perfgate_types::error::AdapterError;
 use perfgate_types::fingerprint::sha256_hex;
+#[cfg(unix)]
+use ready::{OutputStream, ReadyPoller};
 use std::path::{Path, PathBuf};
 use std::time::{Duration, Instant};
 
 #[cfg(windows)]
 use std::os::windows::io::AsRawHandle;
 
+#[cfg(unix)]
+const READY_POLL_INTERVAL: Duration = Duration::from_millis(5);
+
 /// Command to execute.
 #[derive(Debug, Clone, Default)]
 pub struct CommandSpec {
@@ -42,6 +53,11 @@ pub struct CommandSpec {
     pub env: Vec<(String, String)>,
     pub timeout: Option<Duration>,
     pub output_cap_bytes: usize,
+    /// CPUs to pin the child to. Empty leaves the affinity unchanged; pinning
+    /// is applied on Linux only.
+    pub cpu_affinity: Vec<u32>,
+    /// Probe that records `time_to_ready_ms` (Unix only).
+    pub ready: Option<ReadyProbe>,
 }
 
 /// Result of a single execution.
@@ -71,6 +87,16 @@ pub struct RunResult {
     pub energy_uj: Option<u64>,
     /// Size of executed binary in bytes (best-effort).
     pub binary_bytes: Option<u64>,
+    /// Request latency percentiles in microseconds (HTTP load runs only).
+    pub latency_p50_us: Option<u64>,
+    pub latency_p95_us: Option<u64>,
+    pub latency_p99_us: Option<u64>,
+    /// Fraction of failed requests (HTTP load runs only).
+    pub error_rate: Option<f64>,
+    /// Successful requests per second (HTTP load runs only).
+    pub throughput_per_s: Option<f64>,
+    /// Time from spawning until the ready probe passed.
+    pub time_to_ready_ms: Option<u64>,
     pub stdout: Vec<u8>,
     pub stderr: Vec<u8>,
 }
@@ -129,6 +155,12 @@ fn run_portable(spec: &CommandSpec) -> Result<RunResult, AdapterError> {
         binary_bytes,
         stdout: truncate(out.stdout, spec.output_cap_bytes),
         stderr: truncate(out.stderr, spec.output_cap_bytes),
+        latency_p50_us: None,
+        latency_p95_us: None,
+        latency_p99_us: None,
+        error_rate: None,
+        throughput_per_s: None,
+        time_to_ready_ms: None,
     })
 }
 
@@ -213,6 +245,12 @@ fn run_windows(spec: &CommandSpec) -> Result<RunResult, AdapterError> {
         binary_bytes,
         stdout: truncate(stdout_buf, spec.output_cap_bytes),
         stderr: truncate(stderr_buf, spec.output_cap_bytes),
+        latency_p50_us: None,
+        latency_p95_us: None,
+        latency_p99_us: None,
+        error_rate: None,
+        throughput_per_s: None,
+        time_to_ready_ms: None,
     })
 }
 
@@ -264,7 +302,6 @@ fn get_io_counters_windows(handle: std::os::windows::io::RawHandle) -> (Option<u
 #[cfg(unix)]
 #[allow(unsafe_code)]
 fn run_unix(spec: &CommandSpec) -> Result<RunResult, AdapterError> {
-    use std::os::unix::process::ExitStatusExt;
     use std::process::{Command, Stdio};
 
     let binary_bytes = binary_bytes_for_command(spec);
@@ -281,90 +318,273 @@ fn run_unix(spec: &CommandSpec) -> Result<RunResult, AdapterError> {
         cmd.env(k, v);
     }
 
+    #[cfg(target_os = "linux")]
+    pin_to_cpus(&mut cmd, &spec.cpu_affinity);
+
     cmd.stdout(Stdio::piped());
     cmd.stderr(Stdio::piped());
 
-    let mut usage_before = unsafe { std::mem::zeroed::<libc::rusage>() };
-    let _ = unsafe { libc::getrusage(libc::RUSAGE_CHILDREN, &mut usage_before) };
+    let (poller, output_watch) = match &spec.ready {
+        Some(probe) => {
+            let (poller, watch) = ReadyPoller::new(&probe.check, spec.cwd.as_deref())?;
+            (Some(poller), watch)
+        }
+        None => (None, None),
+    };
 
     let start = Instant::now();
 
-    let out = if let Some(timeout) = spec.timeout {
-        let mut child = cmd.spawn().map_err(|e| AdapterError::RunCommand {
-            command: spec.argv.join(" "),
-            reason: e.to_string(),
-        })?;
-
-        match child
-            .wait_timeout(timeout)
-            .map_err(|e| AdapterError::Other(e.to_string()))?
-        {
-            Some(_) => child
-                .wait_with_output()
-                .map_err(|e| AdapterError::RunCommand {
-                    command: spec.argv.join(" "),
-                    reason: e.to_string(),
-                })?,
-            None => {
-                child.kill().ok();
-                return Err(AdapterError::Timeout);
-            }
+    let mut child = cmd.spawn().map_err(|e| AdapterError::RunCommand {
+        command: spec.argv.join(" "),
+        reason: e.to_string(),
+    })?;
+    let (stdout_watch, stderr_watch) = match output_watch {
+        Some(watch) if watch.stream == OutputStream::Stdout => (Some(watch), None),
+        watch => (None, watch),
+    };
+    let stdout = ready::drain_pipe(child.stdout.take(), stdout_watch, true);
+    let stderr = ready::drain_pipe(child.stderr.take(), stderr_watch, true);
+
+    // Reap the child with wait4 so resource usage is this child's alone, even
+    // when other benches run concurrently in the same process.
+    let pid = child.id() as libc::pid_t;
+    let mut ready_at = None;
+    let waited = match (&spec.ready, &poller) {
+        (Some(probe), Some(poller)) => {
+            wait_child_until_ready(pid, spec.timeout, start, probe, poller, &mut ready_at)
+        }
+        _ => wait_child_with_usage(pid, spec.timeout),
+    };
+    let (status, usage, exited_at) = match waited {
+        Ok(Some(reaped)) => reaped,
+        Ok(None) => return Err(AdapterError::Timeout),
+        Err(err) => {
+            child.kill().ok();
+            let _ = reap_child(pid);
+            return Err(err);
         }
-    } else {
-        cmd.output().map_err(|e| AdapterError::RunCommand {
-            command: spec.argv.join(" "),
-            reason: e.to_string(),
-        })?
     };
 
-    let wall_ms = start.elapsed().as_millis() as u64;
-    let exit_code = out
-        .status
-        .code()
-        .or_else(|| out.status.signal())
-        .unwrap_or(-1);
-
-    let mut cpu_ms = None;
-    let mut max_rss_kb = None;
-    let mut page_faults = None;
-    let mut ctx_switches = None;
-
-    let mut usage_after = unsafe { std::mem::zeroed::<libc::rusage>() };
-    if unsafe { libc::getrusage(libc::RUSAGE_CHILDREN, &mut usage_after) } == 0 {
-        let user_ms = diff_timeval_ms(usage_after.ru_utime, usage_before.ru_utime);
-        let sys_ms = diff_timeval_ms(usage_after.ru_stime, usage_before.ru_stime);
-
-        cpu_ms = Some(user_ms.saturating_add(sys_ms));
-        max_rss_kb = Some(usage_after.ru_maxrss as u64);
-        page_faults =
-            Some((usage_after.ru_majflt as u64).saturating_sub(usage_before.ru_majflt as u64));
-        ctx_switches = Some(
-            (usage_after.ru_nvcsw as u64)
-                .saturating_sub(usage_before.ru_nvcsw as u64)
-                .saturating_add(
-                    (usage_after.ru_nivcsw as u64).saturating_sub(usage_before.ru_nivcsw as u64),
-                ),
-        );
+    let wall_ms = exited_at.duration_since(start).as_millis() as u64;
+    let stdout = truncate(stdout.join().unwrap_or_default(), spec.output_cap_bytes);
+    let stderr = truncate(stderr.join().unwrap_or_default(), spec.output_cap_bytes);
+    // An output line read just before exit may only be seen now.
+    let ready_at = ready_at.or_else(|| poller.as_ref().and_then(ReadyPoller::poll));
+    let time_to_ready_ms = ready_at.map(|at| at.duration_since(start).as_millis() as u64);
+    if spec.ready.is_some() && time_to_ready_ms.is_none() {
+        return Err(AdapterError::Other(format!(
+            "command {:?} exited before its ready probe passed",
+            spec.argv.join(" ")
+        )));
     }
+    let terminated = spec.ready.as_ref().is_some_and(|probe| probe.terminate)
+        && libc::WIFSIGNALED(status)
+        && libc::WTERMSIG(status) == libc::SIGTERM;
+
+    let exit_code = if terminated {
+        // Stopped by us once ready, not a failure.
+        0
+    } else if libc::WIFEXITED(status) {
+        libc::WEXITSTATUS(status)
+    } else if libc::WIFSIGNALED(status) {
+        libc::WTERMSIG(status)
+    } else {
+        -1
+    };
+
+    let user_ms = timeval_ms(usage.ru_utime);
+    let sys_ms = timeval_ms(usage.ru_stime);
 
     Ok(RunResult {
         wall_ms,
         exit_code,
         timed_out: false,
-        cpu_ms,
-        page_faults,
-        ctx_switches,
-        max_rss_kb,
+        cpu_ms: Some(user_ms.saturating_add(sys_ms)),
+        page_faults: Some(usage.ru_majflt as u64),
+        ctx_switches: Some((usage.ru_nvcsw as u64).saturating_add(usage.ru_nivcsw as u64)),
+        max_rss_kb: Some(usage.ru_maxrss as u64),
         io_read_bytes: None,
         io_write_bytes: None,
         network_packets: None,
         energy_uj: None,
         binary_bytes,
-        stdout: truncate(out.stdout, spec.output_cap_bytes),
-        stderr: truncate(out.stderr, spec.output_cap_bytes),
+        stdout,
+        stderr,
+        latency_p50_us: None,
+        latency_p95_us: None,
+        latency_p99_us: None,
+        error_rate: None,
+        throughput_per_s: None,
+        time_to_ready_ms,
     })
 }
 
+/// Pin the command's process to `cpus`; empty leaves the affinity unchanged.
+#[cfg(target_os = "linux")]
+#[allow(unsafe_code)]
+fn pin_to_cpus(cmd: &mut std::process::Command, cpus: &[u32]) {
+    use std::os::unix::process::CommandExt;
+
+    if cpus.is_empty() {
+        return;
+    }
+    let mut set = unsafe { std::mem::zeroed::<libc::cpu_set_t>() };
+    for cpu in cpus {
+        unsafe { libc::CPU_SET(*cpu as usize, &mut set) };
+    }
+    // SAFETY: sched_setaffinity is async-signal-safe and `set` is fully
+    // built before fork.
+    unsafe {
+        cmd.pre_exec(move || {
+            if libc::sched_setaffinity(0, std::mem::size_of::<libc::cpu_set_t>(), &set) != 0 {
+                return Err(std::io::Error::last_os_error());
+            }
+            Ok(())
+        });
+    }
+}
+
+/// Exit status and resource usage of a reaped child, and when it exited.
+#[cfg(unix)]
+type ReapedChild = (libc::c_int, libc::rusage, Instant);
+
+/// Wait for `pid` to exit and reap it with `wait4`, returning its exit status,
+/// resource usage and exit time, or `None` if it was killed after `timeout`.
+///
+/// The wait blocks so the exit time is taken as soon as the child exits; the
+/// timeout is enforced by a separate thread that kills the child.
+#[cfg(unix)]
+#[allow(unsafe_code)]
+fn wait_child_with_usage(
+    pid: libc::pid_t,
+    timeout: Option<Duration>,
+) -> Result<Option<ReapedChild>, AdapterError> {
+    use std::sync::mpsc;
+
+    let (exited, exit_seen) = mpsc::channel::<()>();
+    let killer = timeout.map(|timeout| {
+        std::thread::spawn(move || {
+            let expired = matches!(
+                exit_seen.recv_timeout(timeout),
+                Err(mpsc::RecvTimeoutError::Timeout)
+            );
+            if expired {
+                // The child is only reaped after this thread is joined, so
+                // `pid` cannot have been reused yet.
+                unsafe { libc::kill(pid, libc::SIGKILL) };
+            }
+            expired
+        })
+    });
+
+    // Wait without reaping (WNOWAIT) so the killer never signals a reused pid.
+    let waited = loop {
+        let mut info = unsafe { std::mem::zeroed::<libc::siginfo_t>() };
+        if unsafe {
+            libc::waitid(
+                libc::P_PID,
+                pid as libc::id_t,
+                &mut info,
+                libc::WEXITED | libc::WNOWAIT,
+            )
+        } == 0
+        {
+            break Ok(Instant::now());
+        }
+        let err = std::io::Error::last_os_error();
+        if err.kind() != std::io::ErrorKind::Interrupted {
+            break Err(AdapterError::Other(err.to_string()));
+        }
+    };
+    drop(exited);
+    let timed_out = killer.is_some_and(|killer| killer.join().unwrap_or(false));
+    let exited_at = waited?;
+
+    let (status, usage) = reap_child(pid)?;
+    if timed_out {
+        return Ok(None);
+    }
+    Ok(Some((status, usage, exited_at)))
+}
+
+/// Reap `pid` with a blocking `wait4`, returning its exit status and resource
+/// usage.
+#[cfg(unix)]
+#[allow(unsafe_code)]
+fn reap_child(pid: libc::pid_t) -> Result<(libc::c_int, libc::rusage), AdapterError> {
+    loop {
+        match try_reap_child(pid, 0)? {
+            Some((status, usage, _)) => return Ok((status, usage)),
+            None => continue,
+        }
+    }
+}
+
+/// Reap `pid` with `wait4` and `flags`, returning `None` when `WNOHANG` is set
+/// and the child is still running or the wait was interrupted.
+#[cfg(unix)]
+#[allow(unsafe_code)]
+fn try_reap_child(
+    pid: libc::pid_t,
+    flags: libc::c_int,
+) -> Result<Option<ReapedChild>, AdapterError> {
+    let mut status: libc::c_int = 0;
+    let mut usage = unsafe { std::mem::zeroed::<libc::rusage>() };
+    let reaped = unsafe { libc::wait4(pid, &mut status, flags, &mut usage) };
+    if reaped == pid {
+        return Ok(Some((status, usage, Instant::now())));
+    }
+    if reaped < 0 {
+        let err = std::io::Error::last_os_error();
+        if err.kind() != std::io::ErrorKind::Interrupted {
+            return Err(AdapterError::Other(err.to_string()));
+        }
+    }
+    Ok(None)
+}
+
+/// Like [`wait_child_with_usage`], polling `poller` until the probe passes
+/// and recording when in `ready_at`. With `probe.terminate` the child is sent
+/// SIGTERM once ready. Fails if the probe does not pass within its timeout.
+/// Once ready, the remaining wait blocks like [`wait_child_with_usage`].
+#[cfg(unix)]
+#[allow(unsafe_code)]
+fn wait_child_until_ready(
+    pid: libc::pid_t,
+    timeout: Option<Duration>,
+    start: Instant,
+    probe: &ReadyProbe,
+    poller: &ReadyPoller,
+    ready_at: &mut Option<Instant>,
+) -> Result<Option<ReapedChild>, AdapterError> {
+    loop {
+        if let Some(reaped) = try_reap_child(pid, libc::WNOHANG)? {
+            return Ok(Some(reaped));
+        }
+        *ready_at = poller.poll();
+        if ready_at.is_some() {
+            if probe.terminate {
+                unsafe { libc::kill(pid, libc::SIGTERM) };
+            }
+            return wait_child_with_usage(pid, timeout.map(|t| t.saturating_sub(start.elapsed())));
+        }
+        if start.elapsed() >= probe.timeout {
+            return Err(AdapterError::Other(format!(
+                "ready probe did not pass within {}",
+                humantime::format_duration(probe.timeout)
+            )));
+        }
+        if let Some(timeout) = timeout
+            && start.elapsed() >= timeout
+        {
+            unsafe { libc::kill(pid, libc::SIGKILL) };
+            reap_child(pid)?;
+            return Ok(None);
+        }
+        std::thread::sleep(READY_POLL_INTERVAL);
+    }
+}
+
 /// Standard process runner using std::process::Command.
 #[derive(Clone, Debug, Default)]
 pub struct StdProcessRunner;
@@ -374,6 +594,11 @@ impl ProcessRunner for StdProcessRunner {
         if spec.argv.is_empty() {
             return Err(AdapterError::EmptyArgv);
         }
+        if spec.ready.is_some() && !cfg!(unix) {
+            return Err(AdapterError::Other(
+                "ready probes are only supported on Unix".to_string(),
+            ));
+        }
 
         #[cfg(windows)]
         {
@@ -390,6 +615,59 @@ impl ProcessRunner for StdProcessRunner {
     }
 }
 
+/// Whether [`CommandSpec::cpu_affinity`] is honored on this platform.
+pub const CPU_PINNING_SUPPORTED: bool = cfg!(target_os = "linux");
+
+/// CPUs this process may run on: its affinity mask on Linux, otherwise every
+/// logical CPU.
+#[allow(unsafe_code)]
+pub fn available_cpus() -> Vec<u32> {
+    #[cfg(target_os = "linux")]
+    {
+        let mut set = unsafe { std::mem::zeroed::<libc::cpu_set_t>() };
+        if unsafe { libc::sched_getaffinity(0, std::mem::size_of::<libc::cpu_set_t>(), &mut set) }
+            == 0
+        {
+            let cpus: Vec<u32> = (0..libc::CPU_SETSIZE as usize)
+                .filter(|cpu| unsafe { libc::CPU_ISSET(*cpu, &set) })
+                .map(|cpu| cpu as u32)
+                .collect();
+            if !cpus.is_empty() {
+                return cpus;
+            }
+        }
+    }
+    (0..num_cpus::get() as u32).collect()
+}
+
+/// Split `cpus` into `jobs` disjoint, contiguous sets of near-equal size.
+///
+/// Returns fewer sets than `jobs` when there are fewer CPUs than jobs, so
+/// every set has at least one CPU.
+///
+/// # Examples
+///
+/// ```
+/// use perfgate::app::runtime::partition_cpus;
+///
+/// let sets = partition_cpus(&[0, 1, 2, 3, 4], 2);
+/// assert_eq!(sets, vec![vec![0, 1, 2], vec![3, 4]]);
+/// assert_eq!(partition_cpus(&[0, 1], 4).len(), 2);
+/// ```
+pub fn partition_cpus(cpus: &[u32], jobs: usize) -> Vec<Vec<u32>> {
+    let jobs = jobs.min(cpus.len()).max(1);
+    let base = cpus.len() / jobs;
+    let extra = cpus.len() % jobs;
+    let mut sets = Vec::with_capacity(jobs);
+    let mut start = 0;
+    for job in 0..jobs {
+        let len = base + usize::from(job < extra);
+        sets.push(cpus[start..start + len].to_vec());
+        start += len;
+    }
+    sets
+}
+
 /// Host fingerprinting and metadata collection.
 pub trait HostProbe {
     fn probe(&self, options: &HostProbeOptions) -> perfgate_types::HostInfo;
@@ -465,7 +743,8 @@ fn binary_bytes_for_command(spec: &CommandSpec) -> Option<u64> {
     })
 }
 
-// Extension trait for Command to support timeout on Windows/Unix
+// Extension trait for Command to support timeout on Windows
+#[cfg(windows)]
 trait CommandTimeoutExt {
     fn wait_timeout(
         &mut self,
@@ -473,7 +752,7 @@ trait CommandTimeoutExt {
     ) -> std::io::Result<Option<std::process::ExitStatus>>;
 }
 
-#[cfg(any(unix, windows))]
+#[cfg(windows)]
 impl CommandTimeoutExt for std::process::Child {
     fn wait_timeout(
         &mut self,
@@ -491,28 +770,188 @@ impl CommandTimeoutExt for std::process::Child {
 }
 
 #[cfg(unix)]
-fn diff_timeval_ms(after: libc::timeval, before: libc::timeval) -> u64 {
+fn timeval_ms(tv: libc::timeval) -> u64 {
     #[allow(clippy::unnecessary_cast)]
-    let mut sec = after.tv_sec as i64 - before.tv_sec as i64;
+    let sec = (tv.tv_sec as i64).max(0) as u64;
     #[allow(clippy::unnecessary_cast)]
-    let mut usec = after.tv_usec as i64 - before.tv_usec as i64;
+    let usec = (tv.tv_usec as i64).max(0) as u64;
+    sec * 1000 + usec / 1000
+}
 
-    if usec < 0 {
-        sec -= 1;
-        usec += 1_000_000;
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn partition_cpus_splits_evenly_and_disjointly() {
+        let cpus: Vec<u32> = (0..8).collect();
+        assert_eq!(
+            partition_cpus(&cpus, 3),
+            vec![vec![0, 1, 2], vec![3, 4, 5], vec![6, 7]]
+        );
+        assert_eq!(partition_cpus(&cpus, 1), vec![cpus.clone()]);
+        assert_eq!(partition_cpus(&[4, 9], 3), vec![vec![4], vec![9]]);
+        assert_eq!(partition_cpus(&[], 2), vec![Vec::<u32>::new()]);
     }
 
-    // Ensure we don't underflow if rusage somehow goes backwards (unlikely)
-    if sec < 0 {
-        return 0;
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn pinned_child_runs_on_assigned_cpu() {
+        let cpu = available_cpus()[0];
+        let spec = CommandSpec {
+            name: "affinity".into(),
+            argv: vec![
+                "sh".into(),
+                "-c".into(),
+                "grep Cpus_allowed_list /proc/self/status".into(),
+            ],
+            output_cap_bytes: 4096,
+            cpu_affinity: vec![cpu],
+            ..Default::default()
+        };
+        let result = StdProcessRunner.run(&spec).expect("run pinned child");
+        let stdout = String::from_utf8_lossy(&result.stdout);
+        assert!(
+            stdout.trim().ends_with(&format!("\t{cpu}")),
+            "unexpected affinity: {stdout}"
+        );
     }
 
-    (sec as u64) * 1000 + (usec as u64) / 1000
-}
+    #[cfg(unix)]
+    fn ready_spec(script: &str, check: perfgate_types::ReadyCheck, terminate: bool) -> CommandSpec {
+        CommandSpec {
+            name: "ready".into(),
+            argv: vec!["sh".into(), "-c".into(), script.into()],
+            output_cap_bytes: 4096,
+            timeout: Some(Duration::from_secs(10)),
+            ready: Some(ReadyProbe {
+                check,
+                timeout: Duration::from_secs(5),
+                terminate,
+            }),
+            ..Default::default()
+        }
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[cfg(unix)]
+    #[test]
+    fn ready_probe_records_time_to_ready_before_exit() {
+        use perfgate_types::ReadyCheck;
+
+        let result = StdProcessRunner
+            .run(&ready_spec(
+                "sleep 0.1; echo 'listening on 8080'; sleep 0.2",
+                ReadyCheck::Stdout("listening on \\d+".into()),
+                false,
+            ))
+            .expect("run with stdout probe");
+        let ready = result.time_to_ready_ms.expect("time to ready");
+        assert!(
+            (100..result.wall_ms).contains(&ready),
+            "{ready} vs {result:?}"
+        );
+        assert!(String::from_utf8_lossy(&result.stdout).contains("listening on 8080"));
+
+        let result = StdProcessRunner
+            .run(&ready_spec(
+                "echo booting >&2; sleep 0.05; echo ready >&2",
+                ReadyCheck::Stderr("^ready".into()),
+                false,
+            ))
+            .expect("run with stderr probe");
+        assert!(result.time_to_ready_ms.is_some_and(|ms| ms >= 50));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn ready_probe_terminates_long_running_command_once_ready() {
+        use perfgate_types::ReadyCheck;
+
+        let dir = tempfile::tempdir().unwrap();
+        let marker = dir.path().join("ready.marker");
+        let script = format!("sleep 0.05; touch {}; exec sleep 30", marker.display());
+        let result = StdProcessRunner
+            .run(&ready_spec(
+                &script,
+                ReadyCheck::File(marker.display().to_string()),
+                true,
+            ))
+            .expect("run terminated once ready");
+        assert_eq!(result.exit_code, 0);
+        assert!(result.time_to_ready_ms.is_some_and(|ms| ms >= 50));
+        assert!(result.wall_ms < 5_000, "{result:?}");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn ready_probe_file_marker_is_not_reused_by_the_next_sample() {
+        use perfgate_types::ReadyCheck;
+
+        let dir = tempfile::tempdir().unwrap();
+        let marker = dir.path().join("ready.marker");
+        let script = format!("sleep 0.1; touch {}; exec sleep 30", marker.display());
+        let spec = ready_spec(
+            &script,
+            ReadyCheck::File(marker.display().to_string()),
+            true,
+        );
+        for sample in 0..2 {
+            let result = StdProcessRunner
+                .run(&spec)
+                .expect("run terminated once ready");
+            assert!(
+                result.time_to_ready_ms.is_some_and(|ms| ms >= 100),
+                "sample {sample}: {result:?}"
+            );
+            assert!(marker.exists());
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn ready_probe_fails_when_never_ready() {
+        use perfgate_types::ReadyCheck;
+
+        let err = StdProcessRunner
+            .run(&ready_spec(
+                "echo booting",
+                ReadyCheck::Stdout("^ready".into()),
+                false,
+            ))
+            .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("exited before its ready probe passed"),
+            "{err}"
+        );
+
+        let mut spec = ready_spec("exec sleep 30", ReadyCheck::Stdout("^ready".into()), true);
+        spec.ready.as_mut().unwrap().timeout = Duration::from_millis(100);
+        let err = StdProcessRunner.run(&spec).unwrap_err();
+        assert!(err.to_string().contains("did not pass within"), "{err}");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn timeout_kills_child_and_wall_time_stops_at_exit() {
+        let mut spec = CommandSpec {
+            name: "timeout".into(),
+            argv: vec!["sleep".into(), "30".into()],
+            output_cap_bytes: 4096,
+            timeout: Some(Duration::from_millis(100)),
+            ..Default::default()
+        };
+        let started = Instant::now();
+        let err = StdProcessRunner.run(&spec).unwrap_err();
+        assert!(matches!(err, AdapterError::Timeout), "{err}");
+        assert!(started.elapsed() < Duration::from_secs(5));
+
+        spec.argv = vec!["sleep".into(), "0.05".into()];
+        spec.timeout = Some(Duration::from_secs(10));
+        let result = StdProcessRunner.run(&spec).expect("run before timeout");
+        assert_eq!(result.exit_code, 0);
+        assert!((50..1_000).contains(&result.wall_ms), "{result:?}");
+    }
 
     #[test]
     fn truncate_works() {
@@ -0,0 +1,432 @@
+//! Built-in HTTP load generator of load benches.
+//!
+//! Each sample of a load bench is one [`HttpLoadRunner`] run: `concurrency`
+//! workers send requests over keep-alive HTTP/1.1 connections until the
+//! sample's duration or request count is reached. Closed-loop workers send
+//! back to back; open-loop workers follow a fixed `rate` schedule and measure
+//! latency from each scheduled send time, so a stalled server shows up as
+//! queueing delay instead of fewer requests.
+
+use super::ready::HttpTarget;
+use super::{AdapterError, CommandSpec, ProcessRunner, RunResult};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+/// Load to drive against one URL per sample.
+#[derive(Debug, Clone)]
+pub struct HttpLoadSpec {
+    pub url: String,
+    pub method: String,
+    pub body: Option<String>,
+    pub headers: Vec<(String, String)>,
+    pub concurrency: u32,
+    pub duration: Option<Duration>,
+    pub requests: Option<u64>,
+    /// Target requests per second of open-loop load; `None` is closed-loop.
+    pub rate: Option<u64>,
+    /// Per-request timeout; timed out requests count as errors.
+    pub timeout: Duration,
+}
+
+/// [`ProcessRunner`] that ignores the command and runs an HTTP load sample.
+///
+/// The result carries latency percentiles of successful requests, the error
+/// rate (connection failures, timeouts and status >= 400) and successful
+/// requests per second.
+#[derive(Clone)]
+pub struct HttpLoadRunner {
+    spec: HttpLoadSpec,
+    target: HttpTarget,
+    request: Vec<u8>,
+}
+
+impl HttpLoadRunner {
+    pub fn new(spec: HttpLoadSpec) -> Result<Self, AdapterError> {
+        if spec.duration.is_none() && spec.requests.is_none() {
+            return Err(AdapterError::Other(format!(
+                "HTTP load against {:?} needs a duration or a request count",
+                spec.url
+            )));
+        }
+        let target = HttpTarget::parse(&spec.url)?;
+        let request = build_request(&spec, &target);
+        Ok(Self {
+            spec,
+            target,
+            request,
+        })
+    }
+}
+
+impl ProcessRunner for HttpLoadRunner {
+    fn run(&self, _spec: &CommandSpec) -> Result<RunResult, AdapterError> {
+        let issued = AtomicU64::new(0);
+        let start = Instant::now();
+        let outcomes: Vec<WorkerOutcome> = std::thread::scope(|scope| {
+            let workers: Vec<_> = (0..self.spec.concurrency.max(1))
+                .map(|_| scope.spawn(|| self.worker(&issued, start)))
+                .collect();
+            workers
+                .into_iter()
+                .map(|worker| worker.join().unwrap_or_default())
+                .collect()
+        });
+        let elapsed = start.elapsed();
+
+        let mut latencies_us: Vec<u64> = Vec::new();
+        let mut errors = 0u64;
+        for outcome in outcomes {
+            latencies_us.extend(outcome.latencies_us);
+            errors += outcome.errors;
+        }
+        if latencies_us.is_empty() {
+            return Err(AdapterError::Other(format!(
+                "HTTP load against {:?} had no successful requests ({errors} failed)",
+                self.spec.url
+            )));
+        }
+        latencies_us.sort_unstable();
+
+        let total = latencies_us.len() as u64 + errors;
+        Ok(RunResult {
+            wall_ms: elapsed.as_millis() as u64,
+            latency_p50_us: Some(nearest_rank(&latencies_us, 0.50)),
+            latency_p95_us: Some(nearest_rank(&latencies_us, 0.95)),
+            latency_p99_us: Some(nearest_rank(&latencies_us, 0.99)),
+            error_rate: Some(errors as f64 / total as f64),
+            throughput_per_s: Some(latencies_us.len() as f64 / elapsed.as_secs_f64()),
+            ..Default::default()
+        })
+    }
+}
+
+#[derive(Debug, Default)]
+struct WorkerOutcome {
+    latencies_us: Vec<u64>,
+    errors: u64,
+}
+
+impl HttpLoadRunner {
+    fn worker(&self, issued: &AtomicU64, start: Instant) -> WorkerOutcome {
+        let mut outcome = WorkerOutcome::default();
+        let mut conn: Option<BufReader<TcpStream>> = None;
+        let deadline = self.spec.duration.map(|duration| start + duration);
+        loop {
+            let index = issued.fetch_add(1, Ordering::Relaxed);
+            if self.spec.requests.is_some_and(|requests| index >= requests) {
+                break;
+            }
+            let scheduled = match self.spec.rate {
+                Some(rate) => start + Duration::from_nanos(index * 1_000_000_000 / rate),
+                None => Instant::now(),
+            };
+            if deadline.is_some_and(|deadline| scheduled >= deadline) {
+                break;
+            }
+            if let Some(wait) = scheduled.checked_duration_since(Instant::now()) {
+                std::thread::sleep(wait);
+            }
+
+            match self.send(&mut conn) {
+                Ok(status) if status < 400 => outcome
+                    .latencies_us
+                    .push(scheduled.elapsed().as_micros() as u64),
+                Ok(_) => outcome.errors += 1,
+                Err(_) => {
+                    conn = None;
+                    outcome.errors += 1;
+                }
+            }
+        }
+        outcome
+    }
+
+    /// Send one request, (re)connecting as needed, and return its status.
+    fn send(&self, conn: &mut Option<BufReader<TcpStream>>) -> std::io::Result<u16> {
+        if conn.is_none() {
+            *conn = Some(BufReader::new(self.connect()?));
+        }
+        let reader = conn.as_mut().expect("connection was just opened");
+        reader.get_mut().write_all(&self.request)?;
+        let response = read_response(reader, self.spec.method.eq_ignore_ascii_case("HEAD"))?;
+        if !response.keep_alive {
+            *conn = None;
+        }
+        Ok(response.status)
+    }
+
+    fn connect(&self) -> std::io::Result<TcpStream> {
+        let mut last_err = None;
+        for addr in self.target.addr.to_socket_addrs()? {
+            match TcpStream::connect_timeout(&addr, self.spec.timeout) {
+                Ok(stream) => {
+                    stream.set_read_timeout(Some(self.spec.timeout))?;
+                    stream.set_write_timeout(Some(self.spec.timeout))?;
+                    stream.set_nodelay(true)?;
+                    return Ok(stream);
+                }
+                Err(e) => last_err = Some(e),
+            }
+        }
+        Err(last_err.unwrap_or_else(|| {
+            std::io::Error::new(std::io::ErrorKind::NotFound, "address did not resolve")
+        }))
+    }
+}
+
+fn build_request(spec: &HttpLoadSpec, target: &HttpTarget) -> Vec<u8> {
+    let mut request = format!(
+        "{} {} HTTP/1.1\r\nHost: {}\r\n",
+        spec.method.to_ascii_uppercase(),
+        target.path,
+        target.host
+    );
+    for (name, value) in &spec.headers {
+        request.push_str(&format!("{name}: {value}\r\n"));
+    }
+    let has_length = spec
+        .headers
+        .iter()
+        .any(|(name, _)| name.eq_ignore_ascii_case("content-length"));
+    if let Some(body) = &spec.body
+        && !has_length
+    {
+        request.push_str(&format!("Content-Length: {}\r\n", body.len()));
+    }
+    request.push_str("\r\n");
+    if let Some(body) = &spec.body {
+        request.push_str(body);
+    }
+    request.into_bytes()
+}
+
+struct Response {
+    status: u16,
+    keep_alive: bool,
+}
+
+/// Read one response, discarding its body.
+fn read_response(reader: &mut BufReader<TcpStream>, head_only: bool) -> std::io::Result<Response> {
+    let invalid =
+        |what: &str| std::io::Error::new(std::io::ErrorKind::InvalidData, what.to_string());
+
+    let mut line = String::new();
+    if reader.read_line(&mut line)? == 0 {
+        return Err(invalid("connection closed before the status line"));
+    }
+    let mut parts = line.split_whitespace();
+    let version = parts.next().unwrap_or_default();
+    let status: u16 = parts
+        .next()
+        .and_then(|status| status.parse().ok())
+        .ok_or_else(|| invalid("malformed status line"))?;
+    let mut keep_alive = version != "HTTP/1.0";
+
+    let mut content_length = None;
+    let mut chunked = false;
+    loop {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 {
+            return Err(invalid("connection closed in the response headers"));
+        }
+        let header = line.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        let Some((name, value)) = header.split_once(':') else {
+            continue;
+        };
+        let value = value.trim();
+        if name.eq_ignore_ascii_case("content-length") {
+            content_length = value.parse::<u64>().ok();
+        } else if name.eq_ignore_ascii_case("transfer-encoding") {
+            chunked = value.to_ascii_lowercase().contains("chunked");
+        } else if name.eq_ignore_ascii_case("connection") {
+            keep_alive = !value.eq_ignore_ascii_case("close");
+        }
+    }
+
+    if head_only || status == 204 || status == 304 || (100..200).contains(&status) {
+        // No body.
+    } else if chunked {
+        loop {
+            line.clear();
+            reader.read_line(&mut line)?;
+            let size = line.trim_end().split(';').next().unwrap_or_default();
+            let size = u64::from_str_radix(size.trim(), 16)
+                .map_err(|_| invalid("malformed chunk size"))?;
+            if size == 0 {
+                // Trailers end with an empty line.
+                loop {
+                    line.clear();
+                    if reader.read_line(&mut line)? == 0 || line.trim_end().is_empty() {
+                        break;
+                    }
+                }
+                break;
+            }
+            std::io::copy(&mut reader.by_ref().take(size + 2), &mut std::io::sink())?;
+        }
+    } else if let Some(length) = content_length {
+        let copied = std::io::copy(&mut reader.by_ref().take(length), &mut std::io::sink())?;
+        if copied < length {
+            return Err(invalid("connection closed in the response body"));
+        }
+    } else {
+        // Body delimited by the end of the connection.
+        std::io::copy(reader, &mut std::io::sink())?;
+        keep_alive = false;
+    }
+
+    Ok(Response { status, keep_alive })
+}
+
+/// Nearest-rank percentile of sorted, non-empty values.
+fn nearest_rank(sorted: &[u64], q: f64) -> u64 {
+    let rank = (q * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::TcpListener;
+
+    /// Serve keep-alive responses; every `fail_every`th request gets a 500.
+    fn serve(fail_every: Option<usize>) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let served = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(stream) = stream else { continue };
+                let served = served.clone();
+                std::thread::spawn(move || {
+                    let mut writer = stream.try_clone().unwrap();
+                    let mut reader = BufReader::new(stream);
+                    let mut line = String::new();
+                    loop {
+                        let mut content_length = 0;
+                        loop {
+                            line.clear();
+                            if reader.read_line(&mut line).unwrap_or(0) == 0 {
+                                return;
+                            }
+                            if line.trim_end().is_empty() {
+                                break;
+                            }
+                            if let Some(value) =
+                                line.to_ascii_lowercase().strip_prefix("content-length:")
+                            {
+                                content_length = value.trim().parse().unwrap();
+                            }
+                        }
+                        let mut body = vec![0; content_length];
+                        reader.read_exact(&mut body).unwrap();
+                        let n = served.fetch_add(1, Ordering::SeqCst) + 1;
+                        let response: &[u8] = if fail_every
+                            .is_some_and(|every| n.is_multiple_of(every))
+                        {
+                            b"HTTP/1.1 500 Internal Server Error\r\nContent-Length: 4\r\n\r\noops"
+                        } else if n.is_multiple_of(2) {
+                            b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n2\r\nok\r\n0\r\n\r\n"
+                        } else {
+                            b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nok"
+                        };
+                        if writer.write_all(response).is_err() {
+                            return;
+                        }
+                    }
+                });
+            }
+        });
+        format!("http://{addr}/items")
+    }
+
+    fn spec(url: String) -> HttpLoadSpec {
+        HttpLoadSpec {
+            url,
+            method: "GET".into(),
+            body: None,
+            headers: Vec::new(),
+            concurrency: 2,
+            duration: None,
+            requests: Some(40),
+            rate: None,
+            timeout: Duration::from_secs(5),
+        }
+    }
+
+    #[test]
+    fn closed_loop_reports_latency_percentiles_and_throughput() {
+        let runner = HttpLoadRunner::new(spec(serve(None))).unwrap();
+        let result = runner.run(&CommandSpec::default()).expect("load runs");
+
+        let p50 = result.latency_p50_us.unwrap();
+        let p95 = result.latency_p95_us.unwrap();
+        let p99 = result.latency_p99_us.unwrap();
+        assert!(p50 <= p95 && p95 <= p99);
+        assert_eq!(result.error_rate, Some(0.0));
+        assert!(result.throughput_per_s.unwrap() > 0.0);
+        assert_eq!(result.exit_code, 0);
+    }
+
+    #[test]
+    fn error_statuses_count_toward_error_rate() {
+        let mut spec = spec(serve(Some(4)));
+        spec.method = "POST".into();
+        spec.body = Some("{\"id\":1}".into());
+        let result = HttpLoadRunner::new(spec)
+            .unwrap()
+            .run(&CommandSpec::default())
+            .expect("load runs");
+        assert_eq!(result.error_rate, Some(0.25));
+    }
+
+    #[test]
+    fn open_loop_follows_the_rate_for_the_duration() {
+        let mut spec = spec(serve(None));
+        spec.requests = None;
+        spec.duration = Some(Duration::from_millis(300));
+        spec.rate = Some(100);
+        let result = HttpLoadRunner::new(spec)
+            .unwrap()
+            .run(&CommandSpec::default())
+            .expect("load runs");
+
+        // 300ms at 100/s schedules 30 requests.
+        let throughput = result.throughput_per_s.unwrap();
+        assert!((50.0..=150.0).contains(&throughput), "got {throughput}");
+        assert!(result.wall_ms >= 290);
+    }
+
+    #[test]
+    fn unreachable_target_fails_the_sample() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/", listener.local_addr().unwrap());
+        drop(listener);
+        let err = HttpLoadRunner::new(spec(url))
+            .unwrap()
+            .run(&CommandSpec::default())
+            .unwrap_err();
+        assert!(err.to_string().contains("no successful requests"), "{err}");
+    }
+
+    #[test]
+    fn load_needs_a_stop_condition_and_an_http_url() {
+        let mut no_stop = spec("http://127.0.0.1:1/".into());
+        no_stop.requests = None;
+        assert!(HttpLoadRunner::new(no_stop).is_err());
+        assert!(HttpLoadRunner::new(spec("https://example.com/".into())).is_err());
+    }
+
+    #[test]
+    fn nearest_rank_picks_covering_sample() {
+        let values: Vec<u64> = (1..=100).collect();
+        assert_eq!(nearest_rank(&values, 0.50), 50);
+        assert_eq!(nearest_rank(&values, 0.99), 99);
+        assert_eq!(nearest_rank(&[7], 0.95), 7);
+    }
+}
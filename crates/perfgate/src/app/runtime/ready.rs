@@ -0,0 +1,246 @@
+//! Readiness probes shared by service bench servers and bench commands.
+//!
+//! A [`ReadyPoller`] answers "is the process ready yet?" for a
+//! [`ReadyCheck`]. Output checks are fed by [`drain_pipe`], which timestamps
+//! the first matching line so time-to-ready is not rounded up to the polling
+//! interval.
+
+use super::AdapterError;
+use perfgate_types::ReadyCheck;
+use regex::Regex;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Ready probe of a bench command: `time_to_ready_ms` is the time from
+/// spawning the command until `check` passes.
+#[derive(Debug, Clone)]
+pub struct ReadyProbe {
+    pub check: ReadyCheck,
+    /// How long to wait for the probe before the sample fails.
+    pub timeout: Duration,
+    /// Send SIGTERM to the command once the probe passes.
+    pub terminate: bool,
+}
+
+/// Output stream an output check watches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum OutputStream {
+    Stdout,
+    Stderr,
+}
+
+/// Pattern to watch for in one output stream; hand it to [`drain_pipe`].
+pub(super) struct OutputWatch {
+    pub(super) stream: OutputStream,
+    pattern: Regex,
+    matched: mpsc::Sender<Instant>,
+}
+
+/// Polls one readiness check.
+pub(super) struct ReadyPoller {
+    check: PollCheck,
+}
+
+enum PollCheck {
+    Tcp(String),
+    Http(HttpTarget),
+    File(PathBuf),
+    Output(mpsc::Receiver<Instant>),
+}
+
+impl ReadyPoller {
+    /// Build a poller for `check`. File paths resolve against `cwd`, and a
+    /// marker left by an earlier sample is removed so only the process about
+    /// to be spawned can create it. Output checks also return the
+    /// [`OutputWatch`] to attach to the process's pipe.
+    pub(super) fn new(
+        check: &ReadyCheck,
+        cwd: Option<&Path>,
+    ) -> Result<(Self, Option<OutputWatch>), AdapterError> {
+        let output = |stream: OutputStream, pattern: &str| {
+            let pattern = Regex::new(pattern).map_err(|e| {
+                AdapterError::Other(format!("invalid ready pattern {pattern:?}: {e}"))
+            })?;
+            let (matched, rx) = mpsc::channel();
+            Ok::<_, AdapterError>((
+                PollCheck::Output(rx),
+                Some(OutputWatch {
+                    stream,
+                    pattern,
+                    matched,
+                }),
+            ))
+        };
+        let (check, watch) = match check {
+            ReadyCheck::Tcp(addr) => (PollCheck::Tcp(addr.clone()), None),
+            ReadyCheck::Http(url) => (PollCheck::Http(HttpTarget::parse(url)?), None),
+            ReadyCheck::File(path) => {
+                let path = match cwd {
+                    Some(cwd) => cwd.join(path),
+                    None => PathBuf::from(path),
+                };
+                match std::fs::remove_file(&path) {
+                    Err(e) if e.kind() != std::io::ErrorKind::NotFound => {
+                        return Err(AdapterError::Other(format!(
+                            "could not remove stale ready marker {}: {e}",
+                            path.display()
+                        )));
+                    }
+                    _ => {}
+                }
+                (PollCheck::File(path), None)
+            }
+            ReadyCheck::Stdout(pattern) => output(OutputStream::Stdout, pattern)?,
+            ReadyCheck::Stderr(pattern) => output(OutputStream::Stderr, pattern)?,
+        };
+        Ok((Self { check }, watch))
+    }
+
+    /// When the check passed, or `None` if it has not passed yet. Output
+    /// checks report the time the matching line was read.
+    pub(super) fn poll(&self) -> Option<Instant> {
+        let ready = match &self.check {
+            PollCheck::Tcp(addr) => connect(addr).is_some(),
+            PollCheck::Http(target) => target.returns_ok(),
+            PollCheck::File(path) => path.exists(),
+            PollCheck::Output(rx) => return rx.try_recv().ok(),
+        };
+        ready.then(Instant::now)
+    }
+}
+
+/// Drain a child pipe on a separate thread so the child never blocks on a
+/// full pipe, signalling the first line that matches `watch`. The captured
+/// bytes are returned when `capture` is set.
+pub(super) fn drain_pipe<R: Read + Send + 'static>(
+    pipe: Option<R>,
+    watch: Option<OutputWatch>,
+    capture: bool,
+) -> JoinHandle<Vec<u8>> {
+    std::thread::spawn(move || {
+        let mut bytes = Vec::new();
+        let Some(pipe) = pipe else {
+            return bytes;
+        };
+        let mut reader = BufReader::new(pipe);
+        let mut watch = watch;
+        let mut line = Vec::new();
+        while reader.read_until(b'\n', &mut line).is_ok_and(|n| n > 0) {
+            if watch
+                .as_ref()
+                .is_some_and(|w| w.pattern.is_match(&String::from_utf8_lossy(&line)))
+            {
+                let _ = watch.take().map(|w| w.matched.send(Instant::now()));
+            }
+            if capture {
+                bytes.append(&mut line);
+            } else {
+                line.clear();
+            }
+        }
+        bytes
+    })
+}
+
+fn connect(addr: &str) -> Option<TcpStream> {
+    addr.to_socket_addrs()
+        .ok()?
+        .find_map(|addr| TcpStream::connect_timeout(&addr, CONNECT_TIMEOUT).ok())
+}
+
+/// Endpoint of an HTTP readiness check or load bench.
+#[derive(Clone)]
+pub(super) struct HttpTarget {
+    pub(super) addr: String,
+    pub(super) host: String,
+    pub(super) path: String,
+}
+
+impl HttpTarget {
+    pub(super) fn parse(url: &str) -> Result<Self, AdapterError> {
+        let rest = url.strip_prefix("http://").ok_or_else(|| {
+            AdapterError::Other(format!("HTTP URL {url:?} must be a plain http:// URL"))
+        })?;
+        let (host, path) = match rest.find('/') {
+            Some(slash) => (&rest[..slash], &rest[slash..]),
+            None => (rest, "/"),
+        };
+        if host.is_empty() {
+            return Err(AdapterError::Other(format!("HTTP URL {url:?} has no host")));
+        }
+        let addr = if host.contains(':') {
+            host.to_string()
+        } else {
+            format!("{host}:80")
+        };
+        Ok(Self {
+            addr,
+            host: host.to_string(),
+            path: path.to_string(),
+        })
+    }
+
+    fn returns_ok(&self) -> bool {
+        let Some(mut stream) = connect(&self.addr) else {
+            return false;
+        };
+        let _ = stream.set_read_timeout(Some(CONNECT_TIMEOUT));
+        let request = format!(
+            "GET {} HTTP/1.1\r\nHost: {}\r\nConnection: close\r\n\r\n",
+            self.path, self.host
+        );
+        if stream.write_all(request.as_bytes()).is_err() {
+            return false;
+        }
+        let mut status_line = String::new();
+        BufReader::new(stream).read_line(&mut status_line).is_ok()
+            && status_line.split_whitespace().nth(1) == Some("200")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn http_target_requires_plain_http() {
+        assert!(HttpTarget::parse("https://example.com/").is_err());
+        let target = HttpTarget::parse("http://localhost:8080").unwrap();
+        assert_eq!(target.addr, "localhost:8080");
+        assert_eq!(target.path, "/");
+        assert_eq!(HttpTarget::parse("http://svc/ok").unwrap().addr, "svc:80");
+    }
+
+    #[test]
+    fn file_check_resolves_against_cwd() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("ready.marker"), b"stale").unwrap();
+        let (poller, watch) =
+            ReadyPoller::new(&ReadyCheck::File("ready.marker".into()), Some(dir.path())).unwrap();
+        assert!(watch.is_none());
+        assert!(poller.poll().is_none());
+        std::fs::write(dir.path().join("ready.marker"), b"").unwrap();
+        assert!(poller.poll().is_some());
+    }
+
+    #[test]
+    fn output_check_reports_first_matching_line() {
+        let (poller, watch) = ReadyPoller::new(&ReadyCheck::Stderr("^ready".into()), None).unwrap();
+        let watch = watch.expect("output watch");
+        assert_eq!(watch.stream, OutputStream::Stderr);
+
+        let output: &[u8] = b"booting\nready on 8080\nready again\n";
+        let captured = drain_pipe(Some(output), Some(watch), true).join().unwrap();
+        assert_eq!(captured, output);
+        assert!(poller.poll().is_some());
+        assert!(poller.poll().is_none());
+
+        assert!(ReadyPoller::new(&ReadyCheck::Stdout("(".into()), None).is_err());
+    }
+}
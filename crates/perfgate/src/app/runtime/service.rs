@@ -0,0 +1,370 @@
+//! Long-running servers of service benches.
+//!
+//! A [`RunningService`] is started once per bench and polled until its
+//! readiness check passes. Each sample then runs the bench's client workload
+//! through a [`ServiceRunner`], which reports the server's CPU time and peak
+//! RSS in place of the client's. Afterwards the server is sent SIGTERM and
+//! killed if it outlives its shutdown timeout.
+
+use super::ready::{OutputStream, ReadyPoller, drain_pipe};
+use super::{AdapterError, CommandSpec, ProcessRunner, RunResult};
+use perfgate_types::ReadyCheck;
+use std::path::PathBuf;
+use std::process::{Child, Command, ExitStatus, Stdio};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, Instant};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+/// Server to start for a service bench.
+#[derive(Debug, Clone)]
+pub struct ServiceSpec {
+    pub argv: Vec<String>,
+    pub cwd: Option<PathBuf>,
+    pub env: Vec<(String, String)>,
+    pub ready: ReadyCheck,
+    pub ready_timeout: Duration,
+    pub shutdown_timeout: Duration,
+    /// CPUs to pin the server to. Empty leaves the affinity unchanged.
+    pub cpu_affinity: Vec<u32>,
+}
+
+/// A started, ready server. Dropping it without [`RunningService::stop`]
+/// kills the server.
+pub struct RunningService {
+    command: String,
+    child: Mutex<Child>,
+    ready_ms: u64,
+    #[cfg_attr(not(unix), allow(dead_code))]
+    shutdown_timeout: Duration,
+    stopped: bool,
+}
+
+impl RunningService {
+    /// Start the server and wait until its readiness check passes.
+    pub fn start(spec: &ServiceSpec) -> Result<Self, AdapterError> {
+        if spec.argv.is_empty() {
+            return Err(AdapterError::EmptyArgv);
+        }
+        let command = spec.argv.join(" ");
+        let (poller, watch) = ReadyPoller::new(&spec.ready, spec.cwd.as_deref())?;
+        let watched = watch.as_ref().map(|watch| watch.stream);
+        let piped_if = |stream: OutputStream| {
+            if watched == Some(stream) {
+                Stdio::piped()
+            } else {
+                Stdio::null()
+            }
+        };
+
+        let mut cmd = Command::new(&spec.argv[0]);
+        cmd.args(&spec.argv[1..]);
+        if let Some(cwd) = &spec.cwd {
+            cmd.current_dir(cwd);
+        }
+        for (k, v) in &spec.env {
+            cmd.env(k, v);
+        }
+        #[cfg(target_os = "linux")]
+        super::pin_to_cpus(&mut cmd, &spec.cpu_affinity);
+        cmd.stdin(Stdio::null());
+        cmd.stdout(piped_if(OutputStream::Stdout));
+        cmd.stderr(piped_if(OutputStream::Stderr));
+
+        let start = Instant::now();
+        let child = cmd.spawn().map_err(|e| AdapterError::RunCommand {
+            command: command.clone(),
+            reason: e.to_string(),
+        })?;
+        let mut service = Self {
+            command,
+            child: Mutex::new(child),
+            ready_ms: 0,
+            shutdown_timeout: spec.shutdown_timeout,
+            stopped: false,
+        };
+        if let Some(watch) = watch {
+            // The pipe is drained for the server's whole life so it never
+            // blocks on a full pipe.
+            let child = service
+                .child
+                .get_mut()
+                .unwrap_or_else(PoisonError::into_inner);
+            match watch.stream {
+                OutputStream::Stdout => drain_pipe(child.stdout.take(), Some(watch), false),
+                OutputStream::Stderr => drain_pipe(child.stderr.take(), Some(watch), false),
+            };
+        }
+
+        loop {
+            if poller.poll().is_some() {
+                break;
+            }
+            if let Some(status) = service.exit_status()? {
+                return Err(AdapterError::Other(format!(
+                    "service {:?} exited before it was ready ({status})",
+                    service.command
+                )));
+            }
+            if start.elapsed() >= spec.ready_timeout {
+                return Err(AdapterError::Other(format!(
+                    "service {:?} was not ready within {}",
+                    service.command,
+                    humantime::format_duration(spec.ready_timeout)
+                )));
+            }
+            std::thread::sleep(POLL_INTERVAL);
+        }
+
+        service.ready_ms = start.elapsed().as_millis() as u64;
+        Ok(service)
+    }
+
+    /// Time from starting the server until its readiness check passed.
+    pub fn ready_ms(&self) -> u64 {
+        self.ready_ms
+    }
+
+    /// Stop the server: SIGTERM, then a kill once the shutdown timeout has
+    /// passed. Returns false when the server had to be killed.
+    pub fn stop(mut self) -> Result<bool, AdapterError> {
+        self.stopped = true;
+        let mut child = self.child();
+        if child.try_wait().map_err(other)?.is_some() {
+            return Ok(true);
+        }
+
+        #[cfg(unix)]
+        #[allow(unsafe_code)]
+        {
+            unsafe { libc::kill(child.id() as libc::pid_t, libc::SIGTERM) };
+            let start = Instant::now();
+            while start.elapsed() < self.shutdown_timeout {
+                if child.try_wait().map_err(other)?.is_some() {
+                    return Ok(true);
+                }
+                std::thread::sleep(POLL_INTERVAL);
+            }
+        }
+
+        // Without signals (Windows) the only way to stop the server is a kill.
+        child.kill().map_err(other)?;
+        child.wait().map_err(other)?;
+        Ok(!cfg!(unix))
+    }
+
+    fn child(&self) -> MutexGuard<'_, Child> {
+        self.child.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn exit_status(&self) -> Result<Option<ExitStatus>, AdapterError> {
+        self.child().try_wait().map_err(other)
+    }
+
+    fn usage(&self) -> ServiceUsage {
+        process_usage(self.child().id())
+    }
+}
+
+impl Drop for RunningService {
+    fn drop(&mut self) {
+        if !self.stopped {
+            let mut child = self.child();
+            let _ = child.kill();
+            let _ = child.wait();
+        }
+    }
+}
+
+/// Process runner for the client workload of a service bench.
+///
+/// Each sample runs with the inner runner; its `cpu_ms` is replaced by the
+/// server's CPU time during the sample and its `max_rss_kb` by the server's
+/// peak RSS so far (Linux only; `None` elsewhere). A sample fails if the
+/// server exits during it.
+#[derive(Clone)]
+pub struct ServiceRunner<'a, R> {
+    inner: R,
+    service: &'a RunningService,
+}
+
+impl<'a, R> ServiceRunner<'a, R> {
+    pub fn new(inner: R, service: &'a RunningService) -> Self {
+        Self { inner, service }
+    }
+}
+
+impl<R: ProcessRunner> ProcessRunner for ServiceRunner<'_, R> {
+    fn run(&self, spec: &CommandSpec) -> Result<RunResult, AdapterError> {
+        let before = self.service.usage();
+        let mut result = self.inner.run(spec)?;
+        if let Some(status) = self.service.exit_status()? {
+            return Err(AdapterError::Other(format!(
+                "service {:?} exited during the workload ({status})",
+                self.service.command
+            )));
+        }
+        let after = self.service.usage();
+
+        result.cpu_ms = before
+            .cpu_ms
+            .zip(after.cpu_ms)
+            .map(|(before, after)| after.saturating_sub(before));
+        result.max_rss_kb = after.max_rss_kb;
+        Ok(result)
+    }
+}
+
+/// Server resource usage since it started.
+#[derive(Debug, Default)]
+struct ServiceUsage {
+    cpu_ms: Option<u64>,
+    max_rss_kb: Option<u64>,
+}
+
+#[cfg(target_os = "linux")]
+#[allow(unsafe_code)]
+fn process_usage(pid: u32) -> ServiceUsage {
+    let cpu_ms = std::fs::read_to_string(format!("/proc/{pid}/stat"))
+        .ok()
+        .and_then(|stat| {
+            // utime and stime are the 12th and 13th fields after the
+            // parenthesised command name.
+            let fields: Vec<&str> = stat.rsplit_once(')')?.1.split_whitespace().collect();
+            let ticks =
+                fields.get(11)?.parse::<u64>().ok()? + fields.get(12)?.parse::<u64>().ok()?;
+            let ticks_per_sec = unsafe { libc::sysconf(libc::_SC_CLK_TCK) };
+            (ticks_per_sec > 0).then(|| ticks * 1000 / ticks_per_sec as u64)
+        });
+    let max_rss_kb = std::fs::read_to_string(format!("/proc/{pid}/status"))
+        .ok()
+        .and_then(|status| {
+            status
+                .lines()
+                .find_map(|line| line.strip_prefix("VmHWM:"))?
+                .trim()
+                .trim_end_matches("kB")
+                .trim()
+                .parse()
+                .ok()
+        });
+    ServiceUsage { cpu_ms, max_rss_kb }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn process_usage(_pid: u32) -> ServiceUsage {
+    ServiceUsage::default()
+}
+
+fn other(err: std::io::Error) -> AdapterError {
+    AdapterError::Other(err.to_string())
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use crate::app::runtime::StdProcessRunner;
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+
+    fn spec(script: &str, ready: ReadyCheck) -> ServiceSpec {
+        ServiceSpec {
+            argv: vec!["sh".into(), "-c".into(), script.into()],
+            cwd: None,
+            env: Vec::new(),
+            ready,
+            ready_timeout: Duration::from_secs(10),
+            shutdown_timeout: Duration::from_secs(5),
+            cpu_affinity: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn stdout_ready_service_serves_workload_and_stops_on_sigterm() {
+        let service = RunningService::start(&spec(
+            "echo booting; echo listening on 8080; exec sleep 30",
+            ReadyCheck::Stdout("listening on \\d+".into()),
+        ))
+        .expect("service becomes ready");
+
+        let runner = ServiceRunner::new(StdProcessRunner, &service);
+        let result = runner
+            .run(&CommandSpec {
+                name: "client".into(),
+                argv: vec!["true".into()],
+                ..Default::default()
+            })
+            .expect("client runs");
+        assert_eq!(result.exit_code, 0);
+        if cfg!(target_os = "linux") {
+            assert!(result.cpu_ms.is_some());
+            assert!(result.max_rss_kb.is_some_and(|kb| kb > 0));
+        }
+
+        assert!(service.stop().expect("stop service"));
+    }
+
+    #[test]
+    fn tcp_and_http_ready_checks_poll_the_server() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut buf = [0u8; 1024];
+                let _ = stream.read(&mut buf);
+                let _ = stream.write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 0\r\n\r\n");
+            }
+        });
+
+        let tcp = RunningService::start(&spec("exec sleep 30", ReadyCheck::Tcp(addr.to_string())))
+            .expect("tcp ready");
+        assert!(tcp.stop().unwrap());
+
+        let http = RunningService::start(&spec(
+            "exec sleep 30",
+            ReadyCheck::Http(format!("http://{addr}/health")),
+        ))
+        .expect("http ready");
+        assert!(http.stop().unwrap());
+    }
+
+    #[test]
+    fn service_that_never_becomes_ready_times_out() {
+        let closed = TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap();
+        let mut spec = spec("exec sleep 30", ReadyCheck::Tcp(closed.to_string()));
+        spec.ready_timeout = Duration::from_millis(100);
+
+        let err = RunningService::start(&spec).err().expect("not ready");
+        assert!(
+            err.to_string().contains("was not ready within 100ms"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn service_that_exits_early_is_reported() {
+        let err = RunningService::start(&spec("exit 3", ReadyCheck::Stdout("ready".into())))
+            .err()
+            .expect("exited");
+        assert!(
+            err.to_string().contains("exited before it was ready"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn service_ignoring_sigterm_is_killed() {
+        let mut spec = spec(
+            "trap '' TERM; echo up; exec sleep 30",
+            ReadyCheck::Stdout("up".into()),
+        );
+        spec.shutdown_timeout = Duration::from_millis(100);
+
+        let service = RunningService::start(&spec).expect("ready");
+        assert!(!service.stop().expect("killed"));
+    }
+}
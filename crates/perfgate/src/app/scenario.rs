@@ -286,6 +286,10 @@ fn make_run_meta() -> RunMeta {
             memory_bytes: None,
             hostname_hash: None,
         },
+        profile: None,
+        concurrency: None,
+        interleave: None,
+        noise_warnings: Vec::new(),
     }
 }
 
@@ -317,14 +321,18 @@ mod tests {
                 warmup: 0,
                 work_units: None,
                 timeout_ms: None,
+                service: None,
+                load: None,
             },
             baseline_ref: CompareRef {
                 path: Some(format!("baselines/{bench}.json")),
                 run_id: Some(format!("{bench}-base")),
+                profile: None,
             },
             current_ref: CompareRef {
                 path: Some(format!("artifacts/{bench}/run.json")),
                 run_id: Some(format!("{bench}-current")),
+                profile: None,
             },
             budgets: BTreeMap::new(),
             deltas: BTreeMap::from([(
@@ -382,6 +390,10 @@ mod tests {
                     memory_bytes: None,
                     hostname_hash: None,
                 },
+                profile: None,
+                concurrency: None,
+                interleave: None,
+                noise_warnings: Vec::new(),
             },
             bench: None,
             scenario: Some("large_file_parse".to_string()),
@@ -434,6 +446,7 @@ mod tests {
             compare_ref: CompareRef {
                 path: Some(format!("artifacts/{bench}/compare.json")),
                 run_id: compare.current_ref.run_id.clone(),
+                profile: None,
             },
             compare,
             probe_compare_ref: None,
@@ -517,6 +530,7 @@ mod tests {
         input.probe_compare_ref = Some(CompareRef {
             path: Some("artifacts/perfgate/large-file/probe-compare.json".into()),
             run_id: Some("probe-compare-run".into()),
+            profile: None,
         });
         input.probe_compare = Some(probe_compare_receipt(&[
             "parser.tokenize",
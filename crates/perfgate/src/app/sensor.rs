@@ -280,6 +280,28 @@ impl SensorReportBuilder {
                         });
                     }
 
+                    if let Some(reason) = &report.skip_reason {
+                        findings.push(SensorFinding {
+                            check_id: perfgate_types::CHECK_ID_SELECTION.to_string(),
+                            code: perfgate_types::FINDING_CODE_BENCH_SKIPPED.to_string(),
+                            severity: SensorSeverity::Info,
+                            message: format!("[{}] skipped: {}", bench_name, reason),
+                            fingerprint: Some(sha256_hex(
+                                format!(
+                                    "{}:{}:{}",
+                                    bench_name,
+                                    perfgate_types::CHECK_ID_SELECTION,
+                                    perfgate_types::FINDING_CODE_BENCH_SKIPPED
+                                )
+                                .as_bytes(),
+                            )),
+                            data: Some(serde_json::json!({
+                                "bench_name": bench_name,
+                                "reason": reason,
+                            })),
+                        });
+                    }
+
                     if let Some(prefix) = extras_prefix {
                         artifacts.push(SensorArtifact {
                             path: format!("{}/perfgate.run.v1.json", prefix),
@@ -490,6 +512,9 @@ mod tests {
             },
             complexity: None,
             profile_path: None,
+            confirmation: None,
+            skip_reason: None,
+            baseline_selection: None,
         }
     }
 
@@ -533,6 +558,9 @@ mod tests {
             },
             complexity: None,
             profile_path: None,
+            confirmation: None,
+            skip_reason: None,
+            baseline_selection: None,
         }
     }
 
@@ -567,6 +595,9 @@ mod tests {
             },
             complexity: None,
             profile_path: None,
+            confirmation: None,
+            skip_reason: None,
+            baseline_selection: None,
         }
     }
 
@@ -675,6 +706,45 @@ mod tests {
         assert_eq!(agg_report.verdict.counts.info, 3);
         assert_eq!(agg_report.findings.len(), 1); // Only the error finding
     }
+
+    #[test]
+    fn test_build_aggregated_records_skipped_benches() {
+        let outcome_a = BenchOutcome::Success {
+            bench_name: "bench-a".to_string(),
+            report: Box::new(make_pass_report()),
+            markdown: "md-a".to_string(),
+            extras_prefix: None,
+        };
+        let outcome_b = BenchOutcome::Success {
+            bench_name: "bench-b".to_string(),
+            report: Box::new(crate::app::changed_paths::build_skipped_report(
+                "no files changed since main match its paths",
+            )),
+            markdown: "md-b".to_string(),
+            extras_prefix: None,
+        };
+
+        let builder =
+            SensorReportBuilder::new(make_tool_info(), "2024-01-01T00:00:00Z".to_string());
+        let (agg_report, _) = builder.build_aggregated(&[outcome_a, outcome_b]);
+
+        assert_eq!(agg_report.verdict.status, SensorVerdictStatus::Pass);
+        assert!(
+            agg_report
+                .verdict
+                .reasons
+                .contains(&"unchanged".to_string())
+        );
+        assert_eq!(agg_report.findings.len(), 1);
+        let finding = &agg_report.findings[0];
+        assert_eq!(finding.check_id, perfgate_types::CHECK_ID_SELECTION);
+        assert_eq!(finding.code, perfgate_types::FINDING_CODE_BENCH_SKIPPED);
+        assert_eq!(finding.severity, SensorSeverity::Info);
+        assert_eq!(
+            finding.data.as_ref().unwrap()["reason"],
+            "no files changed since main match its paths"
+        );
+    }
 }
 
 #[cfg(test)]
@@ -777,6 +847,9 @@ mod snapshot_tests {
             },
             complexity: None,
             profile_path: None,
+            confirmation: None,
+            skip_reason: None,
+            baseline_selection: None,
         };
 
         let sensor_report =
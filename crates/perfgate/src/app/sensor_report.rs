@@ -103,6 +103,7 @@ where
             out_dir: std::path::PathBuf::from("."),
             baseline: baseline.cloned(),
             baseline_path: None,
+            baseline_selection: None,
             require_baseline: options.require_baseline,
             fail_on_warn: options.fail_on_warn,
             tool: tool.clone(),
@@ -113,6 +114,10 @@ where
             significance_alpha: None,
             significance_min_samples: 8,
             require_significance: false,
+            threshold_history: None,
+            concurrency: None,
+            interleave: None,
+            profile: None,
         },
     );
 
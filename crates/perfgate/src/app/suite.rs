@@ -0,0 +1,676 @@
+//! Suite-level comparison of whole directories (or bundles) of run receipts.
+//!
+//! Receipts are matched by bench name. Every matched pair goes through the
+//! regular [`CompareUseCase`]; the per-bench verdicts are then folded into one
+//! suite verdict together with a geometric-mean change per metric.
+
+use crate::app::comparison_logic::build_budgets;
+use crate::app::{CompareRequest, CompareUseCase, ReportRequest, ReportUseCase};
+use crate::domain::{SignificancePolicy, apply_false_discovery_rate, geometric_mean};
+use anyhow::Context;
+use glob::glob;
+use perfgate_types::{
+    CHECK_ID_SUITE, CompareRef, FINDING_CODE_BENCH_REMOVED, HostMismatchPolicy, Metric,
+    NoisePolicy, PerfgateReport, REPORT_SCHEMA_V1, RUN_BUNDLE_SCHEMA_V1, RUN_SCHEMA_V1,
+    ReportFinding, ReportSummary, RunBundle, RunReceipt, SUITE_COMPARE_SCHEMA_V1, Severity,
+    SuiteBenchCompare, SuiteCompareReceipt, SuiteGeomean, ToolInfo,
+    VERDICT_REASON_SUITE_BENCH_REMOVED, Verdict, VerdictCounts, VerdictStatus,
+};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// Request for comparing two suites of run receipts.
+#[derive(Debug, Clone)]
+pub struct SuiteCompareRequest {
+    /// Baseline directory of run receipts, or a `perfgate.run_bundle.v1` file.
+    pub baseline: PathBuf,
+    /// Current directory of run receipts, or a `perfgate.run_bundle.v1` file.
+    pub current: PathBuf,
+    pub threshold: f64,
+    pub warn_factor: f64,
+    pub noise_threshold: Option<f64>,
+    pub noise_policy: Option<NoisePolicy>,
+    pub metric_thresholds: Vec<(String, f64)>,
+    pub direction_overrides: Vec<(String, String)>,
+    pub significance: Option<SignificancePolicy>,
+    /// Apply a Benjamini–Hochberg correction across every significance test in the suite.
+    pub false_discovery_rate: bool,
+    pub host_mismatch_policy: HostMismatchPolicy,
+    pub tool: ToolInfo,
+}
+
+/// Result of a suite comparison.
+#[derive(Debug, Clone)]
+pub struct SuiteCompareOutcome {
+    pub receipt: SuiteCompareReceipt,
+    /// Suite-level `perfgate.report.v1` envelope with findings from every bench.
+    pub report: PerfgateReport,
+}
+
+/// Run receipt loaded from a suite, with a label describing where it came from.
+struct SuiteEntry {
+    source: String,
+    receipt: RunReceipt,
+}
+
+pub struct SuiteCompareUseCase;
+
+impl SuiteCompareUseCase {
+    pub fn execute(req: SuiteCompareRequest) -> anyhow::Result<SuiteCompareOutcome> {
+        let baseline = load_suite(&req.baseline)
+            .with_context(|| format!("load baseline suite {}", req.baseline.display()))?;
+        let current = load_suite(&req.current)
+            .with_context(|| format!("load current suite {}", req.current.display()))?;
+
+        let mut benches = Vec::new();
+        let mut added = Vec::new();
+        let mut warnings = Vec::new();
+
+        for (name, current_entry) in &current {
+            let Some(baseline_entry) = baseline.get(name) else {
+                added.push(name.clone());
+                continue;
+            };
+
+            let budgets = build_budgets(
+                &baseline_entry.receipt,
+                &current_entry.receipt,
+                req.threshold,
+                req.warn_factor,
+                req.noise_threshold,
+                req.noise_policy,
+                req.metric_thresholds.clone(),
+                Vec::new(),
+                req.direction_overrides.clone(),
+            )?;
+
+            let result = CompareUseCase::execute(CompareRequest {
+                baseline: baseline_entry.receipt.clone(),
+                current: current_entry.receipt.clone(),
+                budgets,
+                metric_statistics: BTreeMap::new(),
+                significance: req.significance,
+                tradeoffs: Vec::new(),
+                baseline_ref: CompareRef {
+                    path: Some(baseline_entry.source.clone()),
+                    run_id: Some(baseline_entry.receipt.run.id.clone()),
+                    profile: None,
+                },
+                current_ref: CompareRef {
+                    path: Some(current_entry.source.clone()),
+                    run_id: Some(current_entry.receipt.run.id.clone()),
+                    profile: None,
+                },
+                tool: req.tool.clone(),
+                host_mismatch_policy: req.host_mismatch_policy,
+            })
+            .with_context(|| format!("compare bench {name}"))?;
+
+            if let Some(mismatch) = &result.host_mismatch {
+                warnings.push(format!(
+                    "{name}: host mismatch: {}",
+                    mismatch.reasons.join("; ")
+                ));
+            }
+            if let Some(mismatch) = &result.profile_mismatch {
+                warnings.push(format!("{name}: profile mismatch: {mismatch}"));
+            }
+            if let Some(mismatch) = &result.concurrency_mismatch {
+                warnings.push(format!("{name}: concurrency mismatch: {mismatch}"));
+            }
+
+            benches.push(SuiteBenchCompare {
+                name: name.clone(),
+                status: result.receipt.verdict.status,
+                compare: result.receipt,
+            });
+        }
+
+        if req.false_discovery_rate
+            && let Some(policy) = req.significance
+        {
+            let mut receipts: Vec<_> = benches.iter_mut().map(|bench| &mut bench.compare).collect();
+            apply_false_discovery_rate(&mut receipts, policy.require_significance);
+            for bench in &mut benches {
+                bench.status = bench.compare.verdict.status;
+            }
+        }
+
+        let removed: Vec<String> = baseline
+            .keys()
+            .filter(|name| !current.contains_key(*name))
+            .cloned()
+            .collect();
+        for name in &removed {
+            warnings.push(format!(
+                "{name}: present in baseline suite but not in current suite"
+            ));
+        }
+
+        let receipt = SuiteCompareReceipt {
+            schema: SUITE_COMPARE_SCHEMA_V1.to_string(),
+            tool: req.tool,
+            baseline_ref: CompareRef {
+                path: Some(req.baseline.display().to_string()),
+                run_id: None,
+                profile: None,
+            },
+            current_ref: CompareRef {
+                path: Some(req.current.display().to_string()),
+                run_id: None,
+                profile: None,
+            },
+            geomean: suite_geomean(&benches),
+            verdict: suite_verdict(&benches, &removed),
+            benches,
+            added,
+            removed,
+            warnings,
+        };
+        let report = build_suite_report(&receipt);
+
+        Ok(SuiteCompareOutcome { receipt, report })
+    }
+}
+
+/// Load every run receipt of a suite, keyed by bench name.
+///
+/// Directories are searched recursively for `perfgate.run.v1` receipts and
+/// `perfgate.run_bundle.v1` bundles; other JSON artifacts (compare receipts,
+/// reports) are ignored. A file path must be a bundle or a single run receipt.
+fn load_suite(path: &Path) -> anyhow::Result<BTreeMap<String, SuiteEntry>> {
+    let is_dir = path.is_dir();
+    let files = if is_dir {
+        let pattern = path.join("**").join("*.json");
+        let pattern = pattern.to_string_lossy();
+        let mut files = Vec::new();
+        for entry in glob(&pattern).with_context(|| format!("invalid glob pattern: {pattern}"))? {
+            files.push(entry?);
+        }
+        files.sort();
+        files
+    } else {
+        vec![path.to_path_buf()]
+    };
+
+    let mut suite: BTreeMap<String, SuiteEntry> = BTreeMap::new();
+    let mut insert = |source: String, receipt: RunReceipt| -> anyhow::Result<()> {
+        if let Some(existing) = suite.get(&receipt.bench.name) {
+            anyhow::bail!(
+                "duplicate receipts for bench '{}': {} and {}",
+                receipt.bench.name,
+                existing.source,
+                source
+            );
+        }
+        suite.insert(receipt.bench.name.clone(), SuiteEntry { source, receipt });
+        Ok(())
+    };
+
+    for file in files {
+        let value: serde_json::Value = perfgate_types::read_json_file(&file)?;
+        match value.get("schema").and_then(serde_json::Value::as_str) {
+            Some(RUN_SCHEMA_V1) => {
+                let receipt: RunReceipt = serde_json::from_value(value)
+                    .with_context(|| format!("parse run receipt {}", file.display()))?;
+                insert(file.display().to_string(), receipt)?;
+            }
+            Some(RUN_BUNDLE_SCHEMA_V1) => {
+                let bundle: RunBundle = serde_json::from_value(value)
+                    .with_context(|| format!("parse run bundle {}", file.display()))?;
+                for receipt in bundle.receipts {
+                    let source = format!("{}#{}", file.display(), receipt.bench.name);
+                    insert(source, receipt)?;
+                }
+            }
+            _ if is_dir => {}
+            _ => anyhow::bail!(
+                "{} is neither a {} receipt nor a {} bundle",
+                file.display(),
+                RUN_SCHEMA_V1,
+                RUN_BUNDLE_SCHEMA_V1
+            ),
+        }
+    }
+
+    if suite.is_empty() {
+        anyhow::bail!("no run receipts found in {}", path.display());
+    }
+
+    Ok(suite)
+}
+
+/// Geometric mean of per-bench `current / baseline` ratios for each metric.
+fn suite_geomean(benches: &[SuiteBenchCompare]) -> BTreeMap<Metric, SuiteGeomean> {
+    let mut ratios: BTreeMap<Metric, Vec<f64>> = BTreeMap::new();
+    for bench in benches {
+        for (metric, delta) in &bench.compare.deltas {
+            ratios.entry(*metric).or_default().push(delta.ratio);
+        }
+    }
+
+    ratios
+        .into_iter()
+        .filter_map(|(metric, values)| {
+            let ratio = geometric_mean(&values)?;
+            Some((
+                metric,
+                SuiteGeomean {
+                    ratio,
+                    pct: ratio - 1.0,
+                    bench_count: values.len() as u32,
+                },
+            ))
+        })
+        .collect()
+}
+
+/// Fold per-bench verdicts into a suite verdict.
+///
+/// Counts are per benchmark. Reasons are prefixed with the bench name. A
+/// benchmark that disappeared from the current suite downgrades a passing
+/// suite to `warn`, since its coverage was silently lost.
+fn suite_verdict(benches: &[SuiteBenchCompare], removed: &[String]) -> Verdict {
+    let mut counts = VerdictCounts {
+        pass: 0,
+        warn: 0,
+        fail: 0,
+        skip: 0,
+    };
+    let mut reasons = Vec::new();
+
+    for bench in benches {
+        match bench.status {
+            VerdictStatus::Pass => counts.pass += 1,
+            VerdictStatus::Warn => counts.warn += 1,
+            VerdictStatus::Fail => counts.fail += 1,
+            VerdictStatus::Skip => counts.skip += 1,
+        }
+        if bench.status != VerdictStatus::Pass {
+            reasons.extend(
+                bench
+                    .compare
+                    .verdict
+                    .reasons
+                    .iter()
+                    .map(|reason| format!("{}:{}", bench.name, reason)),
+            );
+        }
+    }
+    reasons.extend(
+        removed
+            .iter()
+            .map(|name| format!("{}:{}", name, VERDICT_REASON_SUITE_BENCH_REMOVED)),
+    );
+
+    let status = if counts.fail > 0 {
+        VerdictStatus::Fail
+    } else if counts.warn > 0 || !removed.is_empty() {
+        VerdictStatus::Warn
+    } else if counts.pass > 0 {
+        VerdictStatus::Pass
+    } else {
+        VerdictStatus::Skip
+    };
+
+    Verdict {
+        status,
+        counts,
+        reasons,
+    }
+}
+
+/// Build one `perfgate.report.v1` envelope covering every bench of the suite.
+fn build_suite_report(receipt: &SuiteCompareReceipt) -> PerfgateReport {
+    let mut findings = Vec::new();
+    let mut summary = ReportSummary {
+        pass_count: 0,
+        warn_count: 0,
+        fail_count: 0,
+        skip_count: 0,
+        total_count: 0,
+    };
+
+    for bench in &receipt.benches {
+        let report = ReportUseCase::execute(ReportRequest {
+            compare: bench.compare.clone(),
+        })
+        .report;
+        summary.pass_count += report.summary.pass_count;
+        summary.warn_count += report.summary.warn_count;
+        summary.fail_count += report.summary.fail_count;
+        summary.skip_count += report.summary.skip_count;
+        summary.total_count += report.summary.total_count;
+        findings.extend(report.findings.into_iter().map(|mut finding| {
+            finding.message = format!("[{}] {}", bench.name, finding.message);
+            finding
+        }));
+    }
+
+    findings.extend(receipt.removed.iter().map(|name| ReportFinding {
+        check_id: CHECK_ID_SUITE.to_string(),
+        code: FINDING_CODE_BENCH_REMOVED.to_string(),
+        severity: Severity::Warn,
+        message: format!(
+            "[{name}] Benchmark present in baseline suite is missing from current suite"
+        ),
+        data: None,
+    }));
+
+    PerfgateReport {
+        report_type: REPORT_SCHEMA_V1.to_string(),
+        verdict: receipt.verdict.clone(),
+        compare: None,
+        findings,
+        summary,
+        complexity: None,
+        profile_path: None,
+        confirmation: None,
+        skip_reason: None,
+        baseline_selection: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use perfgate_types::{BenchMeta, HostInfo, RunMeta, Sample, Stats, U64Summary};
+    use tempfile::tempdir;
+
+    fn receipt(name: &str, wall_ms: u64) -> RunReceipt {
+        RunReceipt {
+            schema: RUN_SCHEMA_V1.to_string(),
+            tool: tool(),
+            run: RunMeta {
+                id: format!("{name}-{wall_ms}"),
+                started_at: "2024-01-01T00:00:00Z".to_string(),
+                ended_at: "2024-01-01T00:00:01Z".to_string(),
+                host: HostInfo {
+                    os: "linux".to_string(),
+                    arch: "x86_64".to_string(),
+                    cpu_count: None,
+                    memory_bytes: None,
+                    hostname_hash: None,
+                },
+                profile: None,
+                concurrency: None,
+                interleave: None,
+                noise_warnings: Vec::new(),
+            },
+            bench: BenchMeta {
+                name: name.to_string(),
+                cwd: None,
+                command: vec!["true".to_string()],
+                repeat: 1,
+                warmup: 0,
+                work_units: None,
+                timeout_ms: None,
+                service: None,
+                load: None,
+            },
+            samples: vec![Sample {
+                wall_ms,
+                exit_code: 0,
+                warmup: false,
+                timed_out: false,
+                cpu_ms: None,
+                page_faults: None,
+                ctx_switches: None,
+                max_rss_kb: None,
+                io_read_bytes: None,
+                io_write_bytes: None,
+                network_packets: None,
+                energy_uj: None,
+                binary_bytes: None,
+                stdout: None,
+                stderr: None,
+                latency_p50_us: None,
+                latency_p95_us: None,
+                latency_p99_us: None,
+                error_rate: None,
+                throughput_per_s: None,
+                time_to_ready_ms: None,
+                instructions: None,
+                estimated_cycles: None,
+                l1_hits: None,
+                ll_hits: None,
+                ram_hits: None,
+                alloc_bytes: None,
+                allocs: None,
+            }],
+            stats: Stats {
+                wall_ms: U64Summary::new(wall_ms, wall_ms, wall_ms),
+                cpu_ms: None,
+                page_faults: None,
+                ctx_switches: None,
+                max_rss_kb: None,
+                io_read_bytes: None,
+                io_write_bytes: None,
+                network_packets: None,
+                energy_uj: None,
+                binary_bytes: None,
+                throughput_per_s: None,
+                latency_p50_us: None,
+                latency_p95_us: None,
+                latency_p99_us: None,
+                error_rate: None,
+                time_to_ready_ms: None,
+                instructions: None,
+                estimated_cycles: None,
+                l1_hits: None,
+                ll_hits: None,
+                ram_hits: None,
+                alloc_bytes: None,
+                allocs: None,
+                custom: Default::default(),
+            },
+        }
+    }
+
+    fn tool() -> ToolInfo {
+        ToolInfo {
+            name: "perfgate".to_string(),
+            version: "test".to_string(),
+        }
+    }
+
+    fn write(path: &Path, value: &impl serde::Serialize) {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, serde_json::to_string(value).unwrap()).unwrap();
+    }
+
+    fn request(baseline: &Path, current: &Path) -> SuiteCompareRequest {
+        SuiteCompareRequest {
+            baseline: baseline.to_path_buf(),
+            current: current.to_path_buf(),
+            threshold: 0.20,
+            warn_factor: 0.50,
+            noise_threshold: None,
+            noise_policy: None,
+            metric_thresholds: Vec::new(),
+            direction_overrides: Vec::new(),
+            significance: None,
+            false_discovery_rate: false,
+            host_mismatch_policy: HostMismatchPolicy::Ignore,
+            tool: tool(),
+        }
+    }
+
+    fn sampled_receipt(name: &str, walls: &[u64]) -> RunReceipt {
+        let mut receipt = receipt(name, walls[0]);
+        let template = receipt.samples[0].clone();
+        receipt.samples = walls
+            .iter()
+            .map(|&wall_ms| Sample {
+                wall_ms,
+                ..template.clone()
+            })
+            .collect();
+        let mut sorted = walls.to_vec();
+        sorted.sort_unstable();
+        receipt.stats.wall_ms = U64Summary::new(
+            crate::domain::median_u64_sorted(&sorted),
+            sorted[0],
+            sorted[sorted.len() - 1],
+        );
+        receipt
+    }
+
+    #[test]
+    fn suite_compare_false_discovery_rate_demotes_marginal_regressions() {
+        let dir = tempdir().unwrap();
+        let base = dir.path().join("base");
+        let cur = dir.path().join("cur");
+        let noisy = [75, 125, 80, 120, 85, 115, 90, 110];
+        let shifted: Vec<u64> = noisy.iter().map(|v| v + 25).collect();
+        write(
+            &base.join("marginal.json"),
+            &sampled_receipt("marginal", &noisy),
+        );
+        write(
+            &cur.join("marginal.json"),
+            &sampled_receipt("marginal", &shifted),
+        );
+        for name in ["steady-a", "steady-b"] {
+            write(
+                &base.join(format!("{name}.json")),
+                &sampled_receipt(name, &noisy),
+            );
+            write(
+                &cur.join(format!("{name}.json")),
+                &sampled_receipt(name, &noisy),
+            );
+        }
+
+        let mut req = request(&base, &cur);
+        req.significance = Some(SignificancePolicy::new(0.05, 8, true).unwrap());
+        let uncorrected = SuiteCompareUseCase::execute(req.clone()).unwrap().receipt;
+        assert_eq!(uncorrected.verdict.status, VerdictStatus::Fail);
+
+        req.false_discovery_rate = true;
+        let corrected = SuiteCompareUseCase::execute(req).unwrap().receipt;
+        let marginal = &corrected.benches[0];
+        assert_eq!(marginal.name, "marginal");
+        assert_eq!(marginal.status, VerdictStatus::Pass);
+        let sig = marginal.compare.deltas[&Metric::WallMs]
+            .significance
+            .as_ref()
+            .unwrap();
+        let adjusted = sig.adjusted_p_value.unwrap();
+        assert!(sig.p_value.unwrap() <= 0.05);
+        assert!(adjusted > 0.05 && !sig.significant);
+        assert_eq!(corrected.verdict.status, VerdictStatus::Pass);
+    }
+
+    #[test]
+    fn suite_compare_matches_benches_by_name() {
+        let dir = tempdir().unwrap();
+        let base = dir.path().join("base");
+        let cur = dir.path().join("cur");
+        write(&base.join("a.json"), &receipt("a", 100));
+        write(&base.join("b.json"), &receipt("b", 100));
+        write(&base.join("gone.json"), &receipt("gone", 100));
+        write(&cur.join("a").join("run.json"), &receipt("a", 100));
+        write(&cur.join("b").join("run.json"), &receipt("b", 400));
+        write(&cur.join("new").join("run.json"), &receipt("new", 100));
+        write(
+            &cur.join("a").join("report.json"),
+            &serde_json::json!({"report_type": REPORT_SCHEMA_V1}),
+        );
+
+        let outcome = SuiteCompareUseCase::execute(request(&base, &cur)).unwrap();
+        let receipt = outcome.receipt;
+
+        assert_eq!(receipt.schema, SUITE_COMPARE_SCHEMA_V1);
+        let names: Vec<&str> = receipt.benches.iter().map(|b| b.name.as_str()).collect();
+        assert_eq!(names, vec!["a", "b"]);
+        assert_eq!(receipt.added, vec!["new".to_string()]);
+        assert_eq!(receipt.removed, vec!["gone".to_string()]);
+        assert_eq!(receipt.verdict.status, VerdictStatus::Fail);
+        assert_eq!(receipt.verdict.counts.pass, 1);
+        assert_eq!(receipt.verdict.counts.fail, 1);
+        assert!(
+            receipt
+                .verdict
+                .reasons
+                .contains(&"b:wall_ms_fail".to_string())
+        );
+        assert!(
+            receipt
+                .verdict
+                .reasons
+                .contains(&"gone:suite_bench_removed".to_string())
+        );
+
+        // sqrt(1.0 * 4.0) == 2.0
+        let geomean = &receipt.geomean[&Metric::WallMs];
+        assert!((geomean.ratio - 2.0).abs() < 1e-9);
+        assert!((geomean.pct - 1.0).abs() < 1e-9);
+        assert_eq!(geomean.bench_count, 2);
+
+        let report = outcome.report;
+        assert_eq!(report.verdict.status, VerdictStatus::Fail);
+        assert!(report.compare.is_none());
+        assert!(
+            report
+                .findings
+                .iter()
+                .any(|f| f.message.starts_with("[b] "))
+        );
+        assert!(
+            report
+                .findings
+                .iter()
+                .any(|f| f.code == FINDING_CODE_BENCH_REMOVED)
+        );
+    }
+
+    #[test]
+    fn suite_compare_reads_bundles() {
+        let dir = tempdir().unwrap();
+        let bundle = |receipts: Vec<RunReceipt>| RunBundle {
+            schema: RUN_BUNDLE_SCHEMA_V1.to_string(),
+            tool: tool(),
+            receipts,
+        };
+        let base = dir.path().join("base.json");
+        let cur = dir.path().join("cur.json");
+        write(&base, &bundle(vec![receipt("a", 100), receipt("b", 100)]));
+        write(&cur, &bundle(vec![receipt("a", 105), receipt("b", 100)]));
+
+        let receipt = SuiteCompareUseCase::execute(request(&base, &cur))
+            .unwrap()
+            .receipt;
+
+        assert_eq!(receipt.verdict.status, VerdictStatus::Pass);
+        assert_eq!(receipt.verdict.counts.pass, 2);
+        assert!(receipt.added.is_empty() && receipt.removed.is_empty());
+        assert!(
+            receipt.benches[0]
+                .compare
+                .baseline_ref
+                .path
+                .as_deref()
+                .unwrap()
+                .ends_with("base.json#a")
+        );
+    }
+
+    #[test]
+    fn suite_compare_rejects_duplicate_bench_names() {
+        let dir = tempdir().unwrap();
+        let base = dir.path().join("base");
+        write(&base.join("one.json"), &receipt("a", 100));
+        write(&base.join("two.json"), &receipt("a", 100));
+
+        let err = SuiteCompareUseCase::execute(request(&base, &base)).unwrap_err();
+        assert!(format!("{err:#}").contains("duplicate receipts for bench 'a'"));
+    }
+
+    #[test]
+    fn suite_compare_rejects_empty_suite() {
+        let dir = tempdir().unwrap();
+        let err = SuiteCompareUseCase::execute(request(dir.path(), dir.path())).unwrap_err();
+        assert!(format!("{err:#}").contains("no run receipts found"));
+    }
+}
@@ -642,6 +642,10 @@ fn make_run_meta() -> RunMeta {
             memory_bytes: None,
             hostname_hash: None,
         },
+        profile: None,
+        concurrency: None,
+        interleave: None,
+        noise_warnings: Vec::new(),
     }
 }
 
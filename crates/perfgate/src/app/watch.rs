@@ -49,6 +49,9 @@ pub struct WatchRunRequest {
 
     /// Policy for handling host mismatches.
     pub host_mismatch_policy: HostMismatchPolicy,
+
+    /// Config profile applied to `config`, recorded in run receipts.
+    pub profile: Option<String>,
 }
 
 /// Result of a single watch iteration.
@@ -205,6 +208,7 @@ pub fn execute_watch_run<R: ProcessRunner + Clone, H: HostProbe + Clone, C: Cloc
         out_dir: request.out_dir.clone(),
         baseline: request.baseline.clone(),
         baseline_path: request.baseline_path.clone(),
+        baseline_selection: None,
         require_baseline: false,
         fail_on_warn: false,
         noise_threshold: None,
@@ -217,6 +221,10 @@ pub fn execute_watch_run<R: ProcessRunner + Clone, H: HostProbe + Clone, C: Cloc
         significance_alpha: None,
         significance_min_samples: 8,
         require_significance: false,
+        threshold_history: None,
+        concurrency: None,
+        interleave: None,
+        profile: request.profile.clone(),
     })?;
 
     let elapsed = start.elapsed();
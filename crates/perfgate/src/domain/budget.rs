@@ -27,6 +27,7 @@
 //!     threshold: 0.20,       // 20% regression fails
 //!     warn_threshold: 0.10,  // 10% regression warns
 //!     direction: Direction::Lower,
+//!     auto_threshold: None,
 //! };
 //!
 //! // Evaluate baseline vs current
@@ -40,7 +41,8 @@
 //! ```
 
 use perfgate_types::{
-    Budget, Direction, Metric, MetricStatus, Verdict, VerdictCounts, VerdictStatus,
+    AutoThresholdDerivation, AutoThresholdFallback, AutoThresholdSource, Budget, Direction, Metric,
+    MetricStatus, Verdict, VerdictCounts, VerdictStatus,
 };
 use std::collections::BTreeMap;
 use thiserror::Error;
@@ -59,6 +61,7 @@ use thiserror::Error;
 ///     threshold: 0.20,
 ///     warn_threshold: 0.10,
 ///     direction: Direction::Lower,
+///     auto_threshold: None,
 /// };
 ///
 /// // A zero baseline results in InvalidBaseline error
@@ -88,6 +91,7 @@ pub enum BudgetError {
 ///     threshold: 0.20,
 ///     warn_threshold: 0.10,
 ///     direction: Direction::Lower,
+///     auto_threshold: None,
 /// };
 ///
 /// let result = evaluate_budget(100.0, 110.0, &budget, None).unwrap();
@@ -404,10 +408,119 @@ where
     Ok((deltas, verdict))
 }
 
+/// Multiplier applied to the historical coefficient of variation.
+pub const AUTO_THRESHOLD_SIGMA: f64 = 3.0;
+
+/// Fewest historical runs from which an `"auto"` threshold is derived.
+pub const AUTO_THRESHOLD_MIN_RUNS: usize = 5;
+
+/// Default lower bound for an `"auto"` threshold.
+pub const AUTO_THRESHOLD_DEFAULT_MIN: f64 = 0.02;
+
+/// Default upper bound for an `"auto"` threshold.
+pub const AUTO_THRESHOLD_DEFAULT_MAX: f64 = 0.50;
+
+/// Derive a fail threshold from a metric's historical run values.
+///
+/// The threshold is `AUTO_THRESHOLD_SIGMA * cv`, where `cv` is the run-to-run
+/// coefficient of variation of `history`, bounded to `[min, max]`. With fewer
+/// than [`AUTO_THRESHOLD_MIN_RUNS`] values (or a non-positive mean) the bounded
+/// `fallback` threshold is used instead and the reason is recorded.
+///
+/// # Examples
+///
+/// ```
+/// use perfgate::domain::budget::derive_auto_threshold;
+/// use perfgate_types::AutoThresholdSource;
+///
+/// let history = [100.0, 102.0, 98.0, 101.0, 99.0];
+/// let (threshold, derivation) =
+///     derive_auto_threshold(&history, AutoThresholdSource::HistoryDir, 0.02, 0.50, 0.20);
+/// assert!((threshold - 0.0474).abs() < 1e-3);
+/// assert_eq!(derivation.runs, 5);
+/// assert!(derivation.fallback.is_none());
+/// ```
+#[must_use = "pure computation; call site should use the derived threshold"]
+pub fn derive_auto_threshold(
+    history: &[f64],
+    source: AutoThresholdSource,
+    min: f64,
+    max: f64,
+    fallback: f64,
+) -> (f64, AutoThresholdDerivation) {
+    let mut derivation = AutoThresholdDerivation {
+        source,
+        runs: history.len() as u32,
+        cv: None,
+        sigma: AUTO_THRESHOLD_SIGMA,
+        min,
+        max,
+        fallback: None,
+    };
+
+    let bound = |value: f64| value.max(min).min(max);
+
+    if history.len() < AUTO_THRESHOLD_MIN_RUNS {
+        derivation.fallback = Some(AutoThresholdFallback::InsufficientHistory);
+        return (bound(fallback), derivation);
+    }
+
+    match crate::domain::mean_and_variance(history) {
+        Some((mean, variance)) if mean > 0.0 => {
+            let cv = variance.sqrt() / mean;
+            derivation.cv = Some(cv);
+            (bound(AUTO_THRESHOLD_SIGMA * cv), derivation)
+        }
+        _ => {
+            derivation.fallback = Some(AutoThresholdFallback::NonPositiveMean);
+            (bound(fallback), derivation)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn derive_auto_threshold_bounds_derived_value() {
+        let steady = [100.0; 6];
+        let (threshold, derivation) =
+            derive_auto_threshold(&steady, AutoThresholdSource::Server, 0.05, 0.30, 0.20);
+        assert_eq!(threshold, 0.05);
+        assert_eq!(derivation.cv, Some(0.0));
+
+        let noisy = [50.0, 150.0, 60.0, 140.0, 100.0];
+        let (threshold, _) =
+            derive_auto_threshold(&noisy, AutoThresholdSource::Server, 0.05, 0.30, 0.20);
+        assert_eq!(threshold, 0.30);
+    }
+
+    #[test]
+    fn derive_auto_threshold_falls_back_without_enough_history() {
+        let (threshold, derivation) = derive_auto_threshold(
+            &[100.0, 101.0],
+            AutoThresholdSource::HistoryDir,
+            0.02,
+            0.50,
+            0.20,
+        );
+        assert_eq!(threshold, 0.20);
+        assert_eq!(
+            derivation.fallback,
+            Some(AutoThresholdFallback::InsufficientHistory)
+        );
+        assert_eq!(derivation.runs, 2);
+
+        let (threshold, derivation) =
+            derive_auto_threshold(&[0.0; 5], AutoThresholdSource::HistoryDir, 0.02, 0.50, 0.90);
+        assert_eq!(threshold, 0.50);
+        assert_eq!(
+            derivation.fallback,
+            Some(AutoThresholdFallback::NonPositiveMean)
+        );
+    }
+
     fn test_budget() -> Budget {
         Budget::new(0.20, 0.10, Direction::Lower)
     }
@@ -588,6 +701,7 @@ mod property_tests {
                 threshold,
                 warn_threshold,
                 direction: Direction::Lower,
+                auto_threshold: None,
             }
         })
     }
@@ -1,14 +1,15 @@
 use std::collections::BTreeMap;
 
 use perfgate_types::{
-    Budget, Delta, Metric, MetricStatistic, MetricStatus, RunReceipt, Stats, TradeoffDowngrade,
-    TradeoffRule, VERDICT_REASON_TRADEOFF_MISSING_REQUIRED_METRIC,
-    VERDICT_REASON_TRADEOFF_RULE_NOT_SATISFIED, Verdict, VerdictCounts, VerdictStatus,
+    Budget, CompareReceipt, ConfirmationMetric, Delta, Metric, MetricStatistic, MetricStatus,
+    RunReceipt, Stats, TradeoffDowngrade, TradeoffRule,
+    VERDICT_REASON_TRADEOFF_MISSING_REQUIRED_METRIC, VERDICT_REASON_TRADEOFF_RULE_NOT_SATISFIED,
+    Verdict, VerdictCounts, VerdictStatus,
 };
 
 use super::{
-    DomainError, compute_significance, evaluate_budget, improvement_ratio, metric_cv,
-    metric_series_from_run, metric_value, metric_value_from_run, reason_token,
+    DomainError, benjamini_hochberg, compute_significance, evaluate_budget, improvement_ratio,
+    metric_cv, metric_series_from_run, metric_value, metric_value_from_run, reason_token,
 };
 
 #[derive(Debug, Clone, PartialEq)]
@@ -77,6 +78,12 @@ fn aggregate_verdict_from_counts(counts: VerdictCounts, reasons: Vec<String>) ->
 ///     io_read_bytes: None, io_write_bytes: None, network_packets: None,
 ///     energy_uj: None,
 ///     binary_bytes: None, throughput_per_s: None,
+///     latency_p50_us: None, latency_p95_us: None, latency_p99_us: None, error_rate: None,
+///     time_to_ready_ms: None,
+///     instructions: None, estimated_cycles: None,
+///     l1_hits: None, ll_hits: None, ram_hits: None,
+///     alloc_bytes: None, allocs: None,
+///     custom: Default::default(),
 /// };
 /// let current = Stats {
 ///     wall_ms: U64Summary::new(105, 95, 115 ),
@@ -85,6 +92,12 @@ fn aggregate_verdict_from_counts(counts: VerdictCounts, reasons: Vec<String>) ->
 ///     io_read_bytes: None, io_write_bytes: None, network_packets: None,
 ///     energy_uj: None,
 ///     binary_bytes: None, throughput_per_s: None,
+///     latency_p50_us: None, latency_p95_us: None, latency_p99_us: None, error_rate: None,
+///     time_to_ready_ms: None,
+///     instructions: None, estimated_cycles: None,
+///     l1_hits: None, ll_hits: None, ram_hits: None,
+///     alloc_bytes: None, allocs: None,
+///     custom: Default::default(),
 /// };
 ///
 /// let mut budgets = BTreeMap::new();
@@ -92,6 +105,7 @@ fn aggregate_verdict_from_counts(counts: VerdictCounts, reasons: Vec<String>) ->
 ///     noise_threshold: None,
 ///     noise_policy: perfgate_types::NoisePolicy::Ignore,
 ///     threshold: 0.20, warn_threshold: 0.10, direction: Direction::Lower,
+///     auto_threshold: None,
 /// });
 ///
 /// let cmp = compare_stats(&baseline, &current, &budgets).unwrap();
@@ -338,6 +352,147 @@ pub fn compare_runs_with_tradeoffs(
     Ok(Comparison { deltas, verdict })
 }
 
+/// Apply a Benjamini–Hochberg false discovery rate correction across every
+/// significance test in `receipts`.
+///
+/// Each tested delta records its `adjusted_p_value`, and `significant` is
+/// recomputed against the adjusted value. With `require_significance`, warn/fail
+/// deltas that are no longer significant are demoted to pass and the verdict is
+/// recomputed. Returns the demoted metrics of each receipt, in input order.
+pub fn apply_false_discovery_rate(
+    receipts: &mut [&mut CompareReceipt],
+    require_significance: bool,
+) -> Vec<Vec<Metric>> {
+    let tested: Vec<(usize, Metric, f64)> = receipts
+        .iter()
+        .enumerate()
+        .flat_map(|(index, receipt)| {
+            receipt.deltas.iter().filter_map(move |(metric, delta)| {
+                let p_value = delta.significance.as_ref()?.p_value?;
+                Some((index, *metric, p_value))
+            })
+        })
+        .collect();
+    let p_values: Vec<f64> = tested.iter().map(|(_, _, p)| *p).collect();
+    let adjusted = benjamini_hochberg(&p_values);
+
+    let mut demoted = vec![Vec::new(); receipts.len()];
+    for ((index, metric, _), adjusted_p) in tested.into_iter().zip(adjusted) {
+        let receipt = &mut *receipts[index];
+        let Some(delta) = receipt.deltas.get_mut(&metric) else {
+            continue;
+        };
+        let Some(significance) = delta.significance.as_mut() else {
+            continue;
+        };
+        significance.adjusted_p_value = Some(adjusted_p);
+        significance.significant = adjusted_p <= significance.alpha;
+
+        if require_significance
+            && !significance.significant
+            && matches!(delta.status, MetricStatus::Warn | MetricStatus::Fail)
+        {
+            let counts = &mut receipt.verdict.counts;
+            match delta.status {
+                MetricStatus::Warn => counts.warn = counts.warn.saturating_sub(1),
+                MetricStatus::Fail => counts.fail = counts.fail.saturating_sub(1),
+                MetricStatus::Pass | MetricStatus::Skip => {}
+            }
+            counts.pass += 1;
+            remove_reason(
+                &mut receipt.verdict.reasons,
+                &reason_token(metric, delta.status),
+            );
+            delta.status = MetricStatus::Pass;
+            demoted[index].push(metric);
+        }
+    }
+
+    for (receipt, demoted) in receipts.iter_mut().zip(&demoted) {
+        if !demoted.is_empty() {
+            let verdict = &mut receipt.verdict;
+            verdict.status =
+                aggregate_verdict_from_counts(verdict.counts.clone(), Vec::new()).status;
+        }
+    }
+
+    demoted
+}
+
+/// Fold a confirmation measurement into `receipt`.
+///
+/// Every warn/fail delta keeps the less severe of its original status and the
+/// status in `confirmation`; metrics missing from `confirmation` were not
+/// re-measured and keep their original status. Counts, reasons, and the verdict
+/// are updated to match. Returns the original and confirmation status of each
+/// flagged metric, with no confirmation status for metrics that were not
+/// re-measured.
+pub fn apply_confirmation(
+    receipt: &mut CompareReceipt,
+    confirmation: &BTreeMap<Metric, MetricStatus>,
+) -> BTreeMap<Metric, ConfirmationMetric> {
+    let severity = |status: MetricStatus| match status {
+        MetricStatus::Pass | MetricStatus::Skip => 0,
+        MetricStatus::Warn => 1,
+        MetricStatus::Fail => 2,
+    };
+
+    let mut metrics = BTreeMap::new();
+    let mut changed = false;
+    for (metric, delta) in receipt.deltas.iter_mut() {
+        let original = delta.status;
+        if !matches!(original, MetricStatus::Warn | MetricStatus::Fail) {
+            continue;
+        }
+        let confirmed = confirmation.get(metric).copied();
+        metrics.insert(
+            *metric,
+            ConfirmationMetric {
+                original,
+                confirmation: confirmed,
+            },
+        );
+        let Some(confirmed) = confirmed else {
+            continue;
+        };
+        if severity(confirmed) >= severity(original) {
+            continue;
+        }
+
+        let settled = match confirmed {
+            MetricStatus::Warn => MetricStatus::Warn,
+            _ => MetricStatus::Pass,
+        };
+        let counts = &mut receipt.verdict.counts;
+        match original {
+            MetricStatus::Warn => counts.warn = counts.warn.saturating_sub(1),
+            _ => counts.fail = counts.fail.saturating_sub(1),
+        }
+        remove_reason(
+            &mut receipt.verdict.reasons,
+            &reason_token(*metric, original),
+        );
+        match settled {
+            MetricStatus::Warn => {
+                counts.warn += 1;
+                push_unique_reason(
+                    &mut receipt.verdict.reasons,
+                    reason_token(*metric, MetricStatus::Warn),
+                );
+            }
+            _ => counts.pass += 1,
+        }
+        delta.status = settled;
+        changed = true;
+    }
+
+    if changed {
+        let verdict = &mut receipt.verdict;
+        verdict.status = aggregate_verdict_from_counts(verdict.counts.clone(), Vec::new()).status;
+    }
+    metrics
+}
+
 fn push_unique_reason(reasons: &mut Vec<String>, token: String) {
     if !reasons.contains(&token) {
         reasons.push(token);
@@ -489,4 +644,152 @@ mod tests {
 
         assert_eq!(observed, Some(0.8));
     }
+
+    fn tested_receipt(p_value: f64, status: MetricStatus) -> CompareReceipt {
+        use perfgate_types::{
+            BenchMeta, COMPARE_SCHEMA_V1, CompareRef, Significance, SignificanceTest, ToolInfo,
+        };
+
+        let mut tested = delta(100.0, 120.0);
+        tested.status = status;
+        tested.significance = Some(Significance {
+            test: SignificanceTest::WelchT,
+            p_value: Some(p_value),
+            alpha: 0.05,
+            significant: p_value <= 0.05,
+            baseline_samples: 10,
+            current_samples: 10,
+            ci_lower: None,
+            ci_upper: None,
+            adjusted_p_value: None,
+        });
+        let counts = VerdictCounts {
+            pass: u32::from(status == MetricStatus::Pass),
+            warn: u32::from(status == MetricStatus::Warn),
+            fail: u32::from(status == MetricStatus::Fail),
+            skip: 0,
+        };
+        let reasons = match status {
+            MetricStatus::Pass => Vec::new(),
+            other => vec![reason_token(Metric::WallMs, other)],
+        };
+
+        CompareReceipt {
+            schema: COMPARE_SCHEMA_V1.to_string(),
+            tool: ToolInfo {
+                name: "perfgate".to_string(),
+                version: "0.1.0".to_string(),
+            },
+            bench: BenchMeta {
+                name: "bench".to_string(),
+                cwd: None,
+                command: vec!["true".to_string()],
+                repeat: 10,
+                warmup: 0,
+                work_units: None,
+                timeout_ms: None,
+                service: None,
+                load: None,
+            },
+            baseline_ref: CompareRef {
+                path: None,
+                run_id: None,
+                profile: None,
+            },
+            current_ref: CompareRef {
+                path: None,
+                run_id: None,
+                profile: None,
+            },
+            budgets: BTreeMap::new(),
+            deltas: BTreeMap::from([(Metric::WallMs, tested)]),
+            verdict: aggregate_verdict_from_counts(counts, reasons),
+        }
+    }
+
+    #[test]
+    fn false_discovery_rate_records_adjusted_p_values_without_demoting() {
+        let mut first = tested_receipt(0.01, MetricStatus::Fail);
+        let mut second = tested_receipt(0.04, MetricStatus::Warn);
+
+        let demoted = apply_false_discovery_rate(&mut [&mut first, &mut second], false);
+
+        assert_eq!(demoted, vec![Vec::<Metric>::new(), Vec::new()]);
+        let sig = first.deltas[&Metric::WallMs].significance.as_ref().unwrap();
+        assert_eq!(sig.adjusted_p_value, Some(0.02));
+        assert!(sig.significant);
+        let sig = second.deltas[&Metric::WallMs]
+            .significance
+            .as_ref()
+            .unwrap();
+        assert_eq!(sig.adjusted_p_value, Some(0.04));
+        assert_eq!(second.verdict.status, VerdictStatus::Warn);
+    }
+
+    #[test]
+    fn false_discovery_rate_demotes_insignificant_regressions_when_required() {
+        let mut receipts: Vec<CompareReceipt> = [0.001, 0.03, 0.04, 0.5]
+            .into_iter()
+            .map(|p| tested_receipt(p, MetricStatus::Fail))
+            .collect();
+        let mut refs: Vec<&mut CompareReceipt> = receipts.iter_mut().collect();
+
+        let demoted = apply_false_discovery_rate(&mut refs, true);
+
+        // Adjusted: 0.004, 0.0533, 0.0533, 0.5 -> only the first stays significant.
+        assert!(demoted[0].is_empty());
+        assert_eq!(demoted[1], vec![Metric::WallMs]);
+        assert_eq!(receipts[0].verdict.status, VerdictStatus::Fail);
+        let second = &receipts[1];
+        assert_eq!(second.verdict.status, VerdictStatus::Pass);
+        assert_eq!(second.verdict.counts.fail, 0);
+        assert_eq!(second.verdict.counts.pass, 1);
+        assert!(second.verdict.reasons.is_empty());
+        assert_eq!(second.deltas[&Metric::WallMs].status, MetricStatus::Pass);
+    }
+
+    #[test]
+    fn confirmation_keeps_less_severe_status() {
+        let mut downgraded = tested_receipt(0.01, MetricStatus::Fail);
+        let metrics = apply_confirmation(
+            &mut downgraded,
+            &BTreeMap::from([(Metric::WallMs, MetricStatus::Warn)]),
+        );
+        assert_eq!(metrics[&Metric::WallMs].original, MetricStatus::Fail);
+        assert_eq!(
+            metrics[&Metric::WallMs].confirmation,
+            Some(MetricStatus::Warn)
+        );
+        assert_eq!(
+            downgraded.deltas[&Metric::WallMs].status,
+            MetricStatus::Warn
+        );
+        assert_eq!(downgraded.verdict.status, VerdictStatus::Warn);
+        assert_eq!(downgraded.verdict.counts.fail, 0);
+        assert_eq!(downgraded.verdict.counts.warn, 1);
+        assert_eq!(
+            downgraded.verdict.reasons,
+            vec![reason_token(Metric::WallMs, MetricStatus::Warn)]
+        );
+
+        let mut cleared = tested_receipt(0.01, MetricStatus::Warn);
+        apply_confirmation(
+            &mut cleared,
+            &BTreeMap::from([(Metric::WallMs, MetricStatus::Pass)]),
+        );
+        assert_eq!(cleared.verdict.status, VerdictStatus::Pass);
+        assert!(cleared.verdict.reasons.is_empty());
+
+        // A worse confirmation never escalates the original verdict, and a
+        // metric that was not re-measured keeps its status.
+        for confirmation in [
+            BTreeMap::from([(Metric::WallMs, MetricStatus::Fail)]),
+            BTreeMap::new(),
+        ] {
+            let mut kept = tested_receipt(0.01, MetricStatus::Warn);
+            apply_confirmation(&mut kept, &confirmation);
+            assert_eq!(kept.verdict.status, VerdictStatus::Warn);
+            assert_eq!(kept.deltas[&Metric::WallMs].status, MetricStatus::Warn);
+        }
+    }
 }
@@ -0,0 +1,91 @@
+//! Seeded sample ordering for `check --interleave`.
+//!
+//! Running every sample of one bench before the next lets slow drift across a
+//! job (thermal throttling, background load) bias the later benches. The
+//! interleaved order spreads each bench's samples across the whole job in a
+//! shuffle that is fully determined by a seed, so a run can be replayed.
+
+/// Order in which to run the samples of several benches.
+///
+/// `counts[i]` is the number of samples (warmups included) of bench `i`. The
+/// result lists bench indices, one entry per sample; each bench's samples keep
+/// their own order (warmups first), only their position in the job is
+/// shuffled. The same `counts` and `seed` always give the same order.
+///
+/// # Examples
+///
+/// ```
+/// use perfgate::domain::interleaved_sample_order;
+///
+/// let order = interleaved_sample_order(&[2, 3], 7);
+/// assert_eq!(order.len(), 5);
+/// assert_eq!(order.iter().filter(|&&bench| bench == 0).count(), 2);
+/// assert_eq!(order, interleaved_sample_order(&[2, 3], 7));
+/// ```
+pub fn interleaved_sample_order(counts: &[u32], seed: u64) -> Vec<usize> {
+    let mut order: Vec<usize> = counts
+        .iter()
+        .enumerate()
+        .flat_map(|(bench, &count)| std::iter::repeat_n(bench, count as usize))
+        .collect();
+
+    // Fisher–Yates shuffle driven by SplitMix64.
+    let mut rng = SplitMix64(seed);
+    for i in (1..order.len()).rev() {
+        let j = (rng.next() % (i as u64 + 1)) as usize;
+        order.swap(i, j);
+    }
+    order
+}
+
+/// Small, portable PRNG; the sample order must not change across platforms or
+/// releases for a given seed.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn order_is_a_permutation_of_every_sample() {
+        let order = interleaved_sample_order(&[3, 0, 5, 1], 42);
+        let mut counts = [0u32; 4];
+        for bench in &order {
+            counts[*bench] += 1;
+        }
+        assert_eq!(counts, [3, 0, 5, 1]);
+    }
+
+    #[test]
+    fn order_is_pinned_by_seed() {
+        // Changing this order breaks replay of recorded seeds.
+        assert_eq!(interleaved_sample_order(&[3, 3], 1), vec![0, 0, 1, 0, 1, 1]);
+        assert_ne!(
+            interleaved_sample_order(&[10, 10], 1),
+            interleaved_sample_order(&[10, 10], 2)
+        );
+    }
+
+    #[test]
+    fn order_interleaves_benches() {
+        let order = interleaved_sample_order(&[20, 20], 9);
+        let first_half_a = order[..20].iter().filter(|&&bench| bench == 0).count();
+        assert!(first_half_a > 0 && first_half_a < 20, "{order:?}");
+    }
+
+    #[test]
+    fn empty_counts_give_empty_order() {
+        assert!(interleaved_sample_order(&[], 3).is_empty());
+        assert!(interleaved_sample_order(&[0, 0], 3).is_empty());
+    }
+}
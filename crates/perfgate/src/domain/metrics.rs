@@ -2,16 +2,28 @@ use perfgate_types::{Metric, MetricStatistic, RunReceipt, Stats};
 
 pub(crate) fn metric_cv(stats: &Stats, metric: Metric) -> Option<f64> {
     match metric {
+        Metric::AllocBytes => stats.alloc_bytes.as_ref().and_then(|s| s.cv()),
+        Metric::Allocs => stats.allocs.as_ref().and_then(|s| s.cv()),
         Metric::BinaryBytes => stats.binary_bytes.as_ref().and_then(|s| s.cv()),
         Metric::CpuMs => stats.cpu_ms.as_ref().and_then(|s| s.cv()),
         Metric::CtxSwitches => stats.ctx_switches.as_ref().and_then(|s| s.cv()),
         Metric::EnergyUj => stats.energy_uj.as_ref().and_then(|s| s.cv()),
+        Metric::ErrorRate => stats.error_rate.as_ref().and_then(|s| s.cv()),
+        Metric::EstimatedCycles => stats.estimated_cycles.as_ref().and_then(|s| s.cv()),
+        Metric::Instructions => stats.instructions.as_ref().and_then(|s| s.cv()),
         Metric::IoReadBytes => stats.io_read_bytes.as_ref().and_then(|s| s.cv()),
         Metric::IoWriteBytes => stats.io_write_bytes.as_ref().and_then(|s| s.cv()),
+        Metric::L1Hits => stats.l1_hits.as_ref().and_then(|s| s.cv()),
+        Metric::LatencyP50Us => stats.latency_p50_us.as_ref().and_then(|s| s.cv()),
+        Metric::LatencyP95Us => stats.latency_p95_us.as_ref().and_then(|s| s.cv()),
+        Metric::LatencyP99Us => stats.latency_p99_us.as_ref().and_then(|s| s.cv()),
+        Metric::LlHits => stats.ll_hits.as_ref().and_then(|s| s.cv()),
         Metric::MaxRssKb => stats.max_rss_kb.as_ref().and_then(|s| s.cv()),
         Metric::NetworkPackets => stats.network_packets.as_ref().and_then(|s| s.cv()),
         Metric::PageFaults => stats.page_faults.as_ref().and_then(|s| s.cv()),
+        Metric::RamHits => stats.ram_hits.as_ref().and_then(|s| s.cv()),
         Metric::ThroughputPerS => stats.throughput_per_s.as_ref().and_then(|s| s.cv()),
+        Metric::TimeToReadyMs => stats.time_to_ready_ms.as_ref().and_then(|s| s.cv()),
         Metric::WallMs => stats.wall_ms.cv(),
     }
 }
@@ -24,16 +36,28 @@ pub(crate) fn metric_to_string(metric: Metric) -> String {
 #[must_use = "pure computation; call site should use the returned value"]
 pub fn metric_value(stats: &Stats, metric: Metric) -> Option<f64> {
     match metric {
+        Metric::AllocBytes => stats.alloc_bytes.as_ref().map(|s| s.median as f64),
+        Metric::Allocs => stats.allocs.as_ref().map(|s| s.median as f64),
         Metric::BinaryBytes => stats.binary_bytes.as_ref().map(|s| s.median as f64),
         Metric::CpuMs => stats.cpu_ms.as_ref().map(|s| s.median as f64),
         Metric::CtxSwitches => stats.ctx_switches.as_ref().map(|s| s.median as f64),
         Metric::EnergyUj => stats.energy_uj.as_ref().map(|s| s.median as f64),
+        Metric::ErrorRate => stats.error_rate.as_ref().map(|s| s.median),
+        Metric::EstimatedCycles => stats.estimated_cycles.as_ref().map(|s| s.median as f64),
+        Metric::Instructions => stats.instructions.as_ref().map(|s| s.median as f64),
         Metric::IoReadBytes => stats.io_read_bytes.as_ref().map(|s| s.median as f64),
         Metric::IoWriteBytes => stats.io_write_bytes.as_ref().map(|s| s.median as f64),
+        Metric::L1Hits => stats.l1_hits.as_ref().map(|s| s.median as f64),
+        Metric::LatencyP50Us => stats.latency_p50_us.as_ref().map(|s| s.median as f64),
+        Metric::LatencyP95Us => stats.latency_p95_us.as_ref().map(|s| s.median as f64),
+        Metric::LatencyP99Us => stats.latency_p99_us.as_ref().map(|s| s.median as f64),
+        Metric::LlHits => stats.ll_hits.as_ref().map(|s| s.median as f64),
         Metric::MaxRssKb => stats.max_rss_kb.as_ref().map(|s| s.median as f64),
         Metric::NetworkPackets => stats.network_packets.as_ref().map(|s| s.median as f64),
         Metric::PageFaults => stats.page_faults.as_ref().map(|s| s.median as f64),
+        Metric::RamHits => stats.ram_hits.as_ref().map(|s| s.median as f64),
         Metric::ThroughputPerS => stats.throughput_per_s.as_ref().map(|s| s.median),
+        Metric::TimeToReadyMs => stats.time_to_ready_ms.as_ref().map(|s| s.median as f64),
         Metric::WallMs => Some(stats.wall_ms.median as f64),
     }
 }
@@ -60,6 +84,12 @@ pub(crate) fn metric_series_from_run(run: &RunReceipt, metric: Metric) -> Vec<f6
     let measured = run.samples.iter().filter(|s| !s.warmup);
 
     match metric {
+        Metric::AllocBytes => measured
+            .filter_map(|s| s.alloc_bytes.map(|v| v as f64))
+            .collect(),
+        Metric::Allocs => measured
+            .filter_map(|s| s.allocs.map(|v| v as f64))
+            .collect(),
         Metric::BinaryBytes => measured
             .filter_map(|s| s.binary_bytes.map(|v| v as f64))
             .collect(),
@@ -72,12 +102,34 @@ pub(crate) fn metric_series_from_run(run: &RunReceipt, metric: Metric) -> Vec<f6
         Metric::EnergyUj => measured
             .filter_map(|s| s.energy_uj.map(|v| v as f64))
             .collect(),
+        Metric::ErrorRate => measured.filter_map(|s| s.error_rate).collect(),
+        Metric::EstimatedCycles => measured
+            .filter_map(|s| s.estimated_cycles.map(|v| v as f64))
+            .collect(),
+        Metric::Instructions => measured
+            .filter_map(|s| s.instructions.map(|v| v as f64))
+            .collect(),
         Metric::IoReadBytes => measured
             .filter_map(|s| s.io_read_bytes.map(|v| v as f64))
             .collect(),
         Metric::IoWriteBytes => measured
             .filter_map(|s| s.io_write_bytes.map(|v| v as f64))
             .collect(),
+        Metric::L1Hits => measured
+            .filter_map(|s| s.l1_hits.map(|v| v as f64))
+            .collect(),
+        Metric::LatencyP50Us => measured
+            .filter_map(|s| s.latency_p50_us.map(|v| v as f64))
+            .collect(),
+        Metric::LatencyP95Us => measured
+            .filter_map(|s| s.latency_p95_us.map(|v| v as f64))
+            .collect(),
+        Metric::LatencyP99Us => measured
+            .filter_map(|s| s.latency_p99_us.map(|v| v as f64))
+            .collect(),
+        Metric::LlHits => measured
+            .filter_map(|s| s.ll_hits.map(|v| v as f64))
+            .collect(),
         Metric::MaxRssKb => measured
             .filter_map(|s| s.max_rss_kb.map(|v| v as f64))
             .collect(),
@@ -87,11 +139,19 @@ pub(crate) fn metric_series_from_run(run: &RunReceipt, metric: Metric) -> Vec<f6
         Metric::PageFaults => measured
             .filter_map(|s| s.page_faults.map(|v| v as f64))
             .collect(),
+        Metric::RamHits => measured
+            .filter_map(|s| s.ram_hits.map(|v| v as f64))
+            .collect(),
         Metric::ThroughputPerS => {
+            let measured: Vec<_> = measured.collect();
+            if measured.iter().any(|s| s.throughput_per_s.is_some()) {
+                return measured.iter().filter_map(|s| s.throughput_per_s).collect();
+            }
             let Some(work) = run.bench.work_units else {
                 return Vec::new();
             };
             measured
+                .iter()
                 .map(|s| {
                     let secs = (s.wall_ms as f64) / 1000.0;
                     if secs <= 0.0 {
@@ -102,6 +162,9 @@ pub(crate) fn metric_series_from_run(run: &RunReceipt, metric: Metric) -> Vec<f6
                 })
                 .collect()
         }
+        Metric::TimeToReadyMs => measured
+            .filter_map(|s| s.time_to_ready_ms.map(|v| v as f64))
+            .collect(),
         Metric::WallMs => measured.map(|s| s.wall_ms as f64).collect(),
     }
 }
@@ -10,6 +10,7 @@ mod blame;
 pub mod budget;
 mod comparison;
 pub mod host;
+mod interleave;
 mod metrics;
 pub mod movement;
 pub mod paired;
@@ -27,10 +28,11 @@ pub use paired::{
 };
 
 pub use comparison::{
-    Comparison, SignificancePolicy, compare_runs, compare_runs_with_tradeoffs, compare_stats,
-    compare_stats_with_tradeoffs,
+    Comparison, SignificancePolicy, apply_confirmation, apply_false_discovery_rate, compare_runs,
+    compare_runs_with_tradeoffs, compare_stats, compare_stats_with_tradeoffs,
 };
 pub use host::detect_host_mismatch;
+pub use interleave::interleaved_sample_order;
 pub use metrics::metric_value;
 pub(crate) use metrics::{
     metric_cv, metric_series_from_run, metric_to_string, metric_value_from_run,
@@ -43,15 +45,15 @@ pub use report::{Finding, FindingData, Report, derive_report};
 pub use stats_compute::compute_stats;
 
 pub use budget::{
-    BudgetError, BudgetResult, aggregate_verdict, calculate_regression, determine_status,
-    evaluate_budget, evaluate_budgets, reason_token,
+    BudgetError, BudgetResult, aggregate_verdict, calculate_regression, derive_auto_threshold,
+    determine_status, evaluate_budget, evaluate_budgets, reason_token,
 };
 
-pub use significance::{compute_significance, mean_and_variance};
+pub use significance::{benjamini_hochberg, compute_significance, mean_and_variance};
 pub use stats::{
     DriftClass, TrendAnalysis, TrendConfig, analyze_trend, classify_drift, compute_headroom_pct,
-    linear_regression, median_f64_sorted, median_u64_sorted, predict_breach_run, spark_chart,
-    summarize_f64, summarize_u64,
+    geometric_mean, linear_regression, median_f64_sorted, median_u64_sorted, predict_breach_run,
+    spark_chart, summarize_f64, summarize_u64,
 };
 
 pub use perfgate_types::error::StatsError;
@@ -101,6 +103,19 @@ mod advanced_analytics_tests {
                 binary_bytes: None,
                 stdout: None,
                 stderr: None,
+                latency_p50_us: None,
+                latency_p95_us: None,
+                latency_p99_us: None,
+                error_rate: None,
+                throughput_per_s: None,
+                time_to_ready_ms: None,
+                instructions: None,
+                estimated_cycles: None,
+                l1_hits: None,
+                ll_hits: None,
+                ram_hits: None,
+                alloc_bytes: None,
+                allocs: None,
             })
             .collect();
 
@@ -123,6 +138,10 @@ mod advanced_analytics_tests {
                     memory_bytes: None,
                     hostname_hash: None,
                 },
+                profile: None,
+                concurrency: None,
+                interleave: None,
+                noise_warnings: Vec::new(),
             },
             bench: BenchMeta {
                 name: name.to_string(),
@@ -132,6 +151,8 @@ mod advanced_analytics_tests {
                 warmup: 0,
                 work_units: None,
                 timeout_ms: None,
+                service: None,
+                load: None,
             },
             samples,
             stats,
@@ -148,6 +169,7 @@ mod advanced_analytics_tests {
                 threshold,
                 warn_threshold: threshold * 0.9,
                 direction: Direction::Lower,
+                auto_threshold: None,
             },
         );
         budgets
@@ -243,6 +265,19 @@ mod tradeoff_tests {
                 throughput as f64,
                 throughput as f64,
             )),
+            latency_p50_us: None,
+            latency_p95_us: None,
+            latency_p99_us: None,
+            error_rate: None,
+            time_to_ready_ms: None,
+            instructions: None,
+            estimated_cycles: None,
+            l1_hits: None,
+            ll_hits: None,
+            ram_hits: None,
+            alloc_bytes: None,
+            allocs: None,
+            custom: Default::default(),
         }
     }
 
@@ -398,6 +433,19 @@ mod tradeoff_tests {
             energy_uj: None,
             binary_bytes: None,
             throughput_per_s: None,
+            latency_p50_us: None,
+            latency_p95_us: None,
+            latency_p99_us: None,
+            error_rate: None,
+            time_to_ready_ms: None,
+            instructions: None,
+            estimated_cycles: None,
+            l1_hits: None,
+            ll_hits: None,
+            ram_hits: None,
+            alloc_bytes: None,
+            allocs: None,
+            custom: Default::default(),
         };
         let current = Stats {
             wall_ms: U64Summary::new(100, 100, 100),
@@ -411,6 +459,19 @@ mod tradeoff_tests {
             energy_uj: None,
             binary_bytes: None,
             throughput_per_s: None,
+            latency_p50_us: None,
+            latency_p95_us: None,
+            latency_p99_us: None,
+            error_rate: None,
+            time_to_ready_ms: None,
+            instructions: None,
+            estimated_cycles: None,
+            l1_hits: None,
+            ll_hits: None,
+            ram_hits: None,
+            alloc_bytes: None,
+            allocs: None,
+            custom: Default::default(),
         };
 
         let comparison = compare_stats_with_tradeoffs(
@@ -1193,6 +1254,19 @@ mod tests {
                 binary_bytes: None,
                 stdout: None,
                 stderr: None,
+                latency_p50_us: None,
+                latency_p95_us: None,
+                latency_p99_us: None,
+                error_rate: None,
+                throughput_per_s: None,
+                time_to_ready_ms: None,
+                instructions: None,
+                estimated_cycles: None,
+                l1_hits: None,
+                ll_hits: None,
+                ram_hits: None,
+                alloc_bytes: None,
+                allocs: None,
             }
         }
 
@@ -1214,6 +1288,19 @@ mod tests {
                 binary_bytes: None,
                 stdout: None,
                 stderr: None,
+                latency_p50_us: None,
+                latency_p95_us: None,
+                latency_p99_us: None,
+                error_rate: None,
+                throughput_per_s: None,
+                time_to_ready_ms: None,
+                instructions: None,
+                estimated_cycles: None,
+                l1_hits: None,
+                ll_hits: None,
+                ram_hits: None,
+                alloc_bytes: None,
+                allocs: None,
             }
         }
 
@@ -1436,6 +1523,19 @@ mod tests {
                     energy_uj: None,
                     binary_bytes: None,
                     throughput_per_s: None,
+                    latency_p50_us: None,
+                    latency_p95_us: None,
+                    latency_p99_us: None,
+                    error_rate: None,
+                    time_to_ready_ms: None,
+                    instructions: None,
+                    estimated_cycles: None,
+                    l1_hits: None,
+                    ll_hits: None,
+                    ram_hits: None,
+                    alloc_bytes: None,
+                    allocs: None,
+                    custom: Default::default(),
                 };
 
                 let current_stats = Stats {
@@ -1450,6 +1550,19 @@ mod tests {
                     energy_uj: None,
                     binary_bytes: None,
                     throughput_per_s: None,
+                    latency_p50_us: None,
+                    latency_p95_us: None,
+                    latency_p99_us: None,
+                    error_rate: None,
+                    time_to_ready_ms: None,
+                    instructions: None,
+                    estimated_cycles: None,
+                    l1_hits: None,
+                    ll_hits: None,
+                    ram_hits: None,
+                    alloc_bytes: None,
+                    allocs: None,
+                    custom: Default::default(),
                 };
 
                 // Create budget with the generated thresholds
@@ -1462,6 +1575,7 @@ mod tests {
                         threshold,
                         warn_threshold,
                         direction,
+                        auto_threshold: None,
                     },
                 );
 
@@ -1515,7 +1629,21 @@ mod tests {
                     network_packets: None,
                     energy_uj: None,
                     binary_bytes: None,
-                    throughput_per_s: Some(F64Summary::new(baseline, baseline, baseline)),                };
+                    throughput_per_s: Some(F64Summary::new(baseline, baseline, baseline)),
+                    latency_p50_us: None,
+                    latency_p95_us: None,
+                    latency_p99_us: None,
+                    error_rate: None,
+                    time_to_ready_ms: None,
+                    instructions: None,
+                    estimated_cycles: None,
+                    l1_hits: None,
+                    ll_hits: None,
+                    ram_hits: None,
+                    alloc_bytes: None,
+                    allocs: None,
+                    custom: Default::default(),
+                };
 
                 let current_stats = Stats {
                     wall_ms: U64Summary::new(1000, 1000, 1000),
@@ -1528,7 +1656,21 @@ mod tests {
                     network_packets: None,
                     energy_uj: None,
                     binary_bytes: None,
-                    throughput_per_s: Some(F64Summary::new(current, current, current)),                };
+                    throughput_per_s: Some(F64Summary::new(current, current, current)),
+                    latency_p50_us: None,
+                    latency_p95_us: None,
+                    latency_p99_us: None,
+                    error_rate: None,
+                    time_to_ready_ms: None,
+                    instructions: None,
+                    estimated_cycles: None,
+                    l1_hits: None,
+                    ll_hits: None,
+                    ram_hits: None,
+                    alloc_bytes: None,
+                    allocs: None,
+                    custom: Default::default(),
+                };
 
                 // Create budget with the generated thresholds
                 let mut budgets = BTreeMap::new();
@@ -1540,6 +1682,7 @@ mod tests {
                         threshold,
                         warn_threshold,
                         direction,
+                        auto_threshold: None,
                     },
                 );
 
@@ -1591,6 +1734,19 @@ mod tests {
                         energy_uj: None,
                         binary_bytes: None,
                         throughput_per_s: None,
+                        latency_p50_us: None,
+                        latency_p95_us: None,
+                        latency_p99_us: None,
+                        error_rate: None,
+                        time_to_ready_ms: None,
+                        instructions: None,
+                        estimated_cycles: None,
+                        l1_hits: None,
+                        ll_hits: None,
+                        ram_hits: None,
+                        alloc_bytes: None,
+                        allocs: None,
+                        custom: Default::default(),
                         };                    let cs = Stats {
                         wall_ms: U64Summary::new(current as u64, current as u64, current as u64),
                         cpu_ms: None,
@@ -1603,10 +1759,24 @@ mod tests {
                         energy_uj: None,
                         binary_bytes: None,
                         throughput_per_s: None,
+                        latency_p50_us: None,
+                        latency_p95_us: None,
+                        latency_p99_us: None,
+                        error_rate: None,
+                        time_to_ready_ms: None,
+                        instructions: None,
+                        estimated_cycles: None,
+                        l1_hits: None,
+                        ll_hits: None,
+                        ram_hits: None,
+                        alloc_bytes: None,
+                        allocs: None,
+                        custom: Default::default(),
                         };                    let mut b = BTreeMap::new();
                     b.insert(Metric::WallMs, Budget {
                         noise_threshold: None,
-                        noise_policy: perfgate_types::NoisePolicy::Ignore,  threshold, warn_threshold, direction });
+                        noise_policy: perfgate_types::NoisePolicy::Ignore,  threshold, warn_threshold, direction,
+                        auto_threshold: None, });
                     (bs, cs, Metric::WallMs, b)
                 } else {
                     let bs = Stats {
@@ -1620,7 +1790,21 @@ mod tests {
                         network_packets: None,
                         energy_uj: None,
                         binary_bytes: None,
-                        throughput_per_s: Some(F64Summary::new(baseline, baseline, baseline)),                    };
+                        throughput_per_s: Some(F64Summary::new(baseline, baseline, baseline)),
+                        latency_p50_us: None,
+                        latency_p95_us: None,
+                        latency_p99_us: None,
+                        error_rate: None,
+                        time_to_ready_ms: None,
+                        instructions: None,
+                        estimated_cycles: None,
+                        l1_hits: None,
+                        ll_hits: None,
+                        ram_hits: None,
+                        alloc_bytes: None,
+                        allocs: None,
+                        custom: Default::default(),
+                    };
                     let cs = Stats {
                         wall_ms: U64Summary::new(1000, 1000, 1000),
                         cpu_ms: None,
@@ -1632,11 +1816,26 @@ mod tests {
                         network_packets: None,
                         energy_uj: None,
                         binary_bytes: None,
-                        throughput_per_s: Some(F64Summary::new(current, current, current)),                    };
+                        throughput_per_s: Some(F64Summary::new(current, current, current)),
+                        latency_p50_us: None,
+                        latency_p95_us: None,
+                        latency_p99_us: None,
+                        error_rate: None,
+                        time_to_ready_ms: None,
+                        instructions: None,
+                        estimated_cycles: None,
+                        l1_hits: None,
+                        ll_hits: None,
+                        ram_hits: None,
+                        alloc_bytes: None,
+                        allocs: None,
+                        custom: Default::default(),
+                    };
                     let mut b = BTreeMap::new();
                     b.insert(Metric::ThroughputPerS, Budget {
                         noise_threshold: None,
-                        noise_policy: perfgate_types::NoisePolicy::Ignore,  threshold, warn_threshold, direction });
+                        noise_policy: perfgate_types::NoisePolicy::Ignore,  threshold, warn_threshold, direction,
+                        auto_threshold: None, });
                     (bs, cs, Metric::ThroughputPerS, b)
                 };
 
@@ -1679,7 +1878,21 @@ mod tests {
                     network_packets: None,
                     energy_uj: None,
                     binary_bytes: None,
-                    throughput_per_s: Some(F64Summary::new(baseline, baseline, baseline)),                };
+                    throughput_per_s: Some(F64Summary::new(baseline, baseline, baseline)),
+                    latency_p50_us: None,
+                    latency_p95_us: None,
+                    latency_p99_us: None,
+                    error_rate: None,
+                    time_to_ready_ms: None,
+                    instructions: None,
+                    estimated_cycles: None,
+                    l1_hits: None,
+                    ll_hits: None,
+                    ram_hits: None,
+                    alloc_bytes: None,
+                    allocs: None,
+                    custom: Default::default(),
+                };
 
                 // For Direction::Higher, regression = max(0, (baseline - current) / baseline)
                 // To get regression = threshold, we need: (baseline - current) / baseline = threshold
@@ -1700,6 +1913,19 @@ mod tests {
                         energy_uj: None,
                         binary_bytes: None,
                         throughput_per_s: Some(F64Summary::new(current_at_threshold_higher, current_at_threshold_higher, current_at_threshold_higher)),
+                        latency_p50_us: None,
+                        latency_p95_us: None,
+                        latency_p99_us: None,
+                        error_rate: None,
+                        time_to_ready_ms: None,
+                        instructions: None,
+                        estimated_cycles: None,
+                        l1_hits: None,
+                        ll_hits: None,
+                        ram_hits: None,
+                        alloc_bytes: None,
+                        allocs: None,
+                        custom: Default::default(),
                     };
 
                     let mut budgets = BTreeMap::new();
@@ -1711,6 +1937,7 @@ mod tests {
                             threshold,
                             warn_threshold,
                             direction: Direction::Higher,
+                        auto_threshold: None,
                         },
                     );
 
@@ -1802,6 +2029,19 @@ mod tests {
                     mean,
                     stddev,
                 }),
+                latency_p50_us: None,
+                latency_p95_us: None,
+                latency_p99_us: None,
+                error_rate: None,
+                time_to_ready_ms: None,
+                instructions: None,
+                estimated_cycles: None,
+                l1_hits: None,
+                ll_hits: None,
+                ram_hits: None,
+                alloc_bytes: None,
+                allocs: None,
+                custom: Default::default(),
             }
         }
 
@@ -1875,6 +2115,7 @@ mod tests {
                     threshold,
                     warn_threshold,
                     direction: Direction::Lower,
+                    auto_threshold: None,
                 };
 
                 if status == MetricStatus::Skip {
@@ -1924,7 +2165,21 @@ mod tests {
                     network_packets: None,
                     energy_uj: None,
                     binary_bytes: None,
-                    throughput_per_s: None,                };
+                    throughput_per_s: None,
+                    latency_p50_us: None,
+                    latency_p95_us: None,
+                    latency_p99_us: None,
+                    error_rate: None,
+                    time_to_ready_ms: None,
+                    instructions: None,
+                    estimated_cycles: None,
+                    l1_hits: None,
+                    ll_hits: None,
+                    ram_hits: None,
+                    alloc_bytes: None,
+                    allocs: None,
+                    custom: Default::default(),
+                };
 
                 // Compute current values to achieve desired statuses
                 let wall_ms_current = current_for_status(baseline, threshold, warn_threshold, wall_ms_status);
@@ -1957,6 +2212,19 @@ mod tests {
                     energy_uj: None,
                     binary_bytes: None,
                     throughput_per_s: None,
+                    latency_p50_us: None,
+                    latency_p95_us: None,
+                    latency_p99_us: None,
+                    error_rate: None,
+                    time_to_ready_ms: None,
+                    instructions: None,
+                    estimated_cycles: None,
+                    l1_hits: None,
+                    ll_hits: None,
+                    ram_hits: None,
+                    alloc_bytes: None,
+                    allocs: None,
+                    custom: Default::default(),
                 };
 
                 let mut wall_budget = Budget {
@@ -1965,6 +2233,7 @@ mod tests {
                     threshold,
                     warn_threshold,
                     direction: Direction::Lower,
+                    auto_threshold: None,
                 };
                 if wall_ms_status == MetricStatus::Skip {
                     wall_budget.noise_threshold = Some(0.1);
@@ -1977,6 +2246,7 @@ mod tests {
                     threshold,
                     warn_threshold,
                     direction: Direction::Lower,
+                    auto_threshold: None,
                 };
                 if max_rss_status == MetricStatus::Skip {
                     rss_budget.noise_threshold = Some(0.1);
@@ -2028,7 +2298,21 @@ mod tests {
                     network_packets: None,
                     energy_uj: None,
                     binary_bytes: None,
-                    throughput_per_s: Some(F64Summary::new(baseline_throughput, baseline_throughput, baseline_throughput)),                };
+                    throughput_per_s: Some(F64Summary::new(baseline_throughput, baseline_throughput, baseline_throughput)),
+                    latency_p50_us: None,
+                    latency_p95_us: None,
+                    latency_p99_us: None,
+                    error_rate: None,
+                    time_to_ready_ms: None,
+                    instructions: None,
+                    estimated_cycles: None,
+                    l1_hits: None,
+                    ll_hits: None,
+                    ram_hits: None,
+                    alloc_bytes: None,
+                    allocs: None,
+                    custom: Default::default(),
+                };
 
                 // Compute current values to achieve desired statuses
                 let wall_ms_current = current_for_status(baseline, threshold, warn_threshold, wall_ms_status);
@@ -2085,6 +2369,19 @@ mod tests {
                         mean: throughput_cv.map(|_cv| throughput_current),
                         stddev: throughput_cv.map(|cv| (throughput_current) * cv),
                     }),
+                    latency_p50_us: None,
+                    latency_p95_us: None,
+                    latency_p99_us: None,
+                    error_rate: None,
+                    time_to_ready_ms: None,
+                    instructions: None,
+                    estimated_cycles: None,
+                    l1_hits: None,
+                    ll_hits: None,
+                    ram_hits: None,
+                    alloc_bytes: None,
+                    allocs: None,
+                    custom: Default::default(),
                 };
 
                 let mut wall_budget = Budget {
@@ -2093,6 +2390,7 @@ mod tests {
                     threshold,
                     warn_threshold,
                     direction: Direction::Lower,
+                    auto_threshold: None,
                 };
                 if wall_ms_status == MetricStatus::Skip {
                     wall_budget.noise_threshold = Some(0.1);
@@ -2105,6 +2403,7 @@ mod tests {
                     threshold,
                     warn_threshold,
                     direction: Direction::Lower,
+                    auto_threshold: None,
                 };
                 if max_rss_status == MetricStatus::Skip {
                     rss_budget.noise_threshold = Some(0.1);
@@ -2117,6 +2416,7 @@ mod tests {
                     threshold,
                     warn_threshold,
                     direction: Direction::Higher,
+                    auto_threshold: None,
                 };
                 if throughput_status == MetricStatus::Skip {
                     throughput_budget.noise_threshold = Some(0.1);
@@ -2167,7 +2467,21 @@ mod tests {
                     network_packets: None,
                     energy_uj: None,
                     binary_bytes: None,
-                    throughput_per_s: None,                };
+                    throughput_per_s: None,
+                    latency_p50_us: None,
+                    latency_p95_us: None,
+                    latency_p99_us: None,
+                    error_rate: None,
+                    time_to_ready_ms: None,
+                    instructions: None,
+                    estimated_cycles: None,
+                    l1_hits: None,
+                    ll_hits: None,
+                    ram_hits: None,
+                    alloc_bytes: None,
+                    allocs: None,
+                    custom: Default::default(),
+                };
 
                 // wall_ms will be Fail, max_rss will be the random status
                 let wall_ms_current = current_for_status(baseline, threshold, warn_threshold, MetricStatus::Fail);
@@ -2185,6 +2499,19 @@ mod tests {
                     energy_uj: None,
                     binary_bytes: None,
                     throughput_per_s: None,
+                    latency_p50_us: None,
+                    latency_p95_us: None,
+                    latency_p99_us: None,
+                    error_rate: None,
+                    time_to_ready_ms: None,
+                    instructions: None,
+                    estimated_cycles: None,
+                    l1_hits: None,
+                    ll_hits: None,
+                    ram_hits: None,
+                    alloc_bytes: None,
+                    allocs: None,
+                    custom: Default::default(),
                 };
 
                 let mut budgets = BTreeMap::new();
@@ -2196,6 +2523,7 @@ mod tests {
                         threshold,
                         warn_threshold,
                         direction: Direction::Lower,
+                        auto_threshold: None,
                     },
                 );
                 budgets.insert(
@@ -2206,6 +2534,7 @@ mod tests {
                         threshold,
                         warn_threshold,
                         direction: Direction::Lower,
+                        auto_threshold: None,
                     },
                 );
 
@@ -2248,7 +2577,21 @@ mod tests {
                     network_packets: None,
                     energy_uj: None,
                     binary_bytes: None,
-                    throughput_per_s: None,                };
+                    throughput_per_s: None,
+                    latency_p50_us: None,
+                    latency_p95_us: None,
+                    latency_p99_us: None,
+                    error_rate: None,
+                    time_to_ready_ms: None,
+                    instructions: None,
+                    estimated_cycles: None,
+                    l1_hits: None,
+                    ll_hits: None,
+                    ram_hits: None,
+                    alloc_bytes: None,
+                    allocs: None,
+                    custom: Default::default(),
+                };
 
                 // wall_ms will be Warn, max_rss will be Pass or Warn
                 let wall_ms_current = current_for_status(baseline, threshold, warn_threshold, MetricStatus::Warn);
@@ -2266,6 +2609,19 @@ mod tests {
                     energy_uj: None,
                     binary_bytes: None,
                     throughput_per_s: None,
+                    latency_p50_us: None,
+                    latency_p95_us: None,
+                    latency_p99_us: None,
+                    error_rate: None,
+                    time_to_ready_ms: None,
+                    instructions: None,
+                    estimated_cycles: None,
+                    l1_hits: None,
+                    ll_hits: None,
+                    ram_hits: None,
+                    alloc_bytes: None,
+                    allocs: None,
+                    custom: Default::default(),
                 };
 
                 let mut budgets = BTreeMap::new();
@@ -2277,6 +2633,7 @@ mod tests {
                         threshold,
                         warn_threshold,
                         direction: Direction::Lower,
+                        auto_threshold: None,
                     },
                 );
                 budgets.insert(
@@ -2287,6 +2644,7 @@ mod tests {
                         threshold,
                         warn_threshold,
                         direction: Direction::Lower,
+                        auto_threshold: None,
                     },
                 );
 
@@ -2338,6 +2696,19 @@ mod tests {
                     } else {
                         None
                     },
+                    latency_p50_us: None,
+                    latency_p95_us: None,
+                    latency_p99_us: None,
+                    error_rate: None,
+                    time_to_ready_ms: None,
+                    instructions: None,
+                    estimated_cycles: None,
+                    l1_hits: None,
+                    ll_hits: None,
+                    ram_hits: None,
+                    alloc_bytes: None,
+                    allocs: None,
+                    custom: Default::default(),
                 };
 
                 // Current stats are same as baseline (Pass status)
@@ -2352,6 +2723,7 @@ mod tests {
                         threshold,
                         warn_threshold,
                         direction: Direction::Lower,
+                        auto_threshold: None,
                     },
                 );
                 if num_metrics >= 2 {
@@ -2363,6 +2735,7 @@ mod tests {
                             threshold,
                             warn_threshold,
                             direction: Direction::Lower,
+                            auto_threshold: None,
                         },                    );
                 }
                 if num_metrics >= 3 {
@@ -2374,6 +2747,7 @@ mod tests {
                             threshold,
                             warn_threshold,
                             direction: Direction::Higher,
+                        auto_threshold: None,
                         },
                     );
                 }
@@ -2427,6 +2801,19 @@ mod tests {
                 energy_uj: None,
                 binary_bytes: None,
                 throughput_per_s: None,
+                latency_p50_us: None,
+                latency_p95_us: None,
+                latency_p99_us: None,
+                error_rate: None,
+                time_to_ready_ms: None,
+                instructions: None,
+                estimated_cycles: None,
+                l1_hits: None,
+                ll_hits: None,
+                ram_hits: None,
+                alloc_bytes: None,
+                allocs: None,
+                custom: Default::default(),
             };
             let current = Stats {
                 wall_ms: U64Summary::new(current_wall, current_wall, current_wall),
@@ -2440,12 +2827,26 @@ mod tests {
                 energy_uj: None,
                 binary_bytes: None,
                 throughput_per_s: None,
+                latency_p50_us: None,
+                latency_p95_us: None,
+                latency_p99_us: None,
+                error_rate: None,
+                time_to_ready_ms: None,
+                instructions: None,
+                estimated_cycles: None,
+                l1_hits: None,
+                ll_hits: None,
+                ram_hits: None,
+                alloc_bytes: None,
+                allocs: None,
+                custom: Default::default(),
             };
                 let mut budgets = BTreeMap::new();
                 budgets.insert(Metric::WallMs, Budget {
                         noise_threshold: None,
                         noise_policy: perfgate_types::NoisePolicy::Ignore,
                     threshold, warn_threshold, direction: Direction::Lower,
+                        auto_threshold: None,
                 });
 
                 let r1 = compare_stats(&baseline, &current, &budgets).unwrap();
@@ -2479,6 +2880,10 @@ mod tests {
                         memory_bytes: None,
                         hostname_hash: None,
                     },
+                    profile: None,
+                    concurrency: None,
+                    interleave: None,
+                    noise_warnings: Vec::new(),
                 },
                 bench: BenchMeta {
                     name: name.into(),
@@ -2488,6 +2893,8 @@ mod tests {
                     warmup: 0,
                     work_units: None,
                     timeout_ms: None,
+                    service: None,
+                    load: None,
                 },
                 samples: vec![sample],
                 stats,
@@ -2508,6 +2915,7 @@ mod tests {
                         noise_threshold: None,
                         noise_policy: perfgate_types::NoisePolicy::Ignore,
                     threshold, warn_threshold, direction: Direction::Lower,
+                        auto_threshold: None,
                 });
                 let stats_map = BTreeMap::new();
 
@@ -2541,14 +2949,18 @@ mod tests {
                     warmup: 0,
                     work_units: None,
                     timeout_ms: None,
+                    service: None,
+                    load: None,
                 },
                 baseline_ref: CompareRef {
                     path: None,
                     run_id: None,
+                    profile: None,
                 },
                 current_ref: CompareRef {
                     path: None,
                     run_id: None,
+                    profile: None,
                 },
                 budgets,
                 deltas,
@@ -2746,6 +3158,19 @@ mod tests {
                     energy_uj: None,
                     binary_bytes: None,
                     throughput_per_s: None,
+                    latency_p50_us: None,
+                    latency_p95_us: None,
+                    latency_p99_us: None,
+                    error_rate: None,
+                    time_to_ready_ms: None,
+                    instructions: None,
+                    estimated_cycles: None,
+                    l1_hits: None,
+                    ll_hits: None,
+                    ram_hits: None,
+                    alloc_bytes: None,
+                    allocs: None,
+                    custom: Default::default(),
                 };
 
                 let mut budgets = BTreeMap::new();
@@ -2798,6 +3223,19 @@ mod tests {
                     energy_uj: None,
                     binary_bytes: None,
                     throughput_per_s: None,
+                    latency_p50_us: None,
+                    latency_p95_us: None,
+                    latency_p99_us: None,
+                    error_rate: None,
+                    time_to_ready_ms: None,
+                    instructions: None,
+                    estimated_cycles: None,
+                    l1_hits: None,
+                    ll_hits: None,
+                    ram_hits: None,
+                    alloc_bytes: None,
+                    allocs: None,
+                    custom: Default::default(),
                 };
                 let mut budgets = BTreeMap::new();
                 budgets.insert(Metric::WallMs, budget);
@@ -2834,6 +3272,19 @@ mod tests {
                     energy_uj: None,
                     binary_bytes: None,
                     throughput_per_s: None,
+                    latency_p50_us: None,
+                    latency_p95_us: None,
+                    latency_p99_us: None,
+                    error_rate: None,
+                    time_to_ready_ms: None,
+                    instructions: None,
+                    estimated_cycles: None,
+                    l1_hits: None,
+                    ll_hits: None,
+                    ram_hits: None,
+                    alloc_bytes: None,
+                    allocs: None,
+                    custom: Default::default(),
                 };
                 let mut budgets = BTreeMap::new();
                 budgets.insert(Metric::WallMs, budget);
@@ -2868,6 +3319,19 @@ mod tests {
                 binary_bytes: None,
                 stdout: None,
                 stderr: None,
+                latency_p50_us: None,
+                latency_p95_us: None,
+                latency_p99_us: None,
+                error_rate: None,
+                throughput_per_s: None,
+                time_to_ready_ms: None,
+                instructions: None,
+                estimated_cycles: None,
+                l1_hits: None,
+                ll_hits: None,
+                ram_hits: None,
+                alloc_bytes: None,
+                allocs: None,
             },
             Sample {
                 wall_ms: 200,
@@ -2885,6 +3349,19 @@ mod tests {
                 binary_bytes: None,
                 stdout: None,
                 stderr: None,
+                latency_p50_us: None,
+                latency_p95_us: None,
+                latency_p99_us: None,
+                error_rate: None,
+                throughput_per_s: None,
+                time_to_ready_ms: None,
+                instructions: None,
+                estimated_cycles: None,
+                l1_hits: None,
+                ll_hits: None,
+                ram_hits: None,
+                alloc_bytes: None,
+                allocs: None,
             },
         ];
 
@@ -2923,6 +3400,19 @@ mod tests {
                 binary_bytes: None,
                 stdout: None,
                 stderr: None,
+                latency_p50_us: None,
+                latency_p95_us: None,
+                latency_p99_us: None,
+                error_rate: None,
+                throughput_per_s: None,
+                time_to_ready_ms: None,
+                instructions: None,
+                estimated_cycles: None,
+                l1_hits: None,
+                ll_hits: None,
+                ram_hits: None,
+                alloc_bytes: None,
+                allocs: None,
             },
             Sample {
                 wall_ms: 110,
@@ -2940,6 +3430,19 @@ mod tests {
                 binary_bytes: None,
                 stdout: None,
                 stderr: None,
+                latency_p50_us: None,
+                latency_p95_us: None,
+                latency_p99_us: None,
+                error_rate: None,
+                throughput_per_s: None,
+                time_to_ready_ms: None,
+                instructions: None,
+                estimated_cycles: None,
+                l1_hits: None,
+                ll_hits: None,
+                ram_hits: None,
+                alloc_bytes: None,
+                allocs: None,
             },
             Sample {
                 wall_ms: 105,
@@ -2957,6 +3460,19 @@ mod tests {
                 binary_bytes: None,
                 stdout: None,
                 stderr: None,
+                latency_p50_us: None,
+                latency_p95_us: None,
+                latency_p99_us: None,
+                error_rate: None,
+                throughput_per_s: None,
+                time_to_ready_ms: None,
+                instructions: None,
+                estimated_cycles: None,
+                l1_hits: None,
+                ll_hits: None,
+                ram_hits: None,
+                alloc_bytes: None,
+                allocs: None,
             },
         ];
 
@@ -2990,6 +3506,19 @@ mod tests {
                 binary_bytes: None,
                 stdout: None,
                 stderr: None,
+                latency_p50_us: None,
+                latency_p95_us: None,
+                latency_p99_us: None,
+                error_rate: None,
+                throughput_per_s: None,
+                time_to_ready_ms: None,
+                instructions: None,
+                estimated_cycles: None,
+                l1_hits: None,
+                ll_hits: None,
+                ram_hits: None,
+                alloc_bytes: None,
+                allocs: None,
             },
             Sample {
                 wall_ms: 110,
@@ -3007,6 +3536,19 @@ mod tests {
                 binary_bytes: None,
                 stdout: None,
                 stderr: None,
+                latency_p50_us: None,
+                latency_p95_us: None,
+                latency_p99_us: None,
+                error_rate: None,
+                throughput_per_s: None,
+                time_to_ready_ms: None,
+                instructions: None,
+                estimated_cycles: None,
+                l1_hits: None,
+                ll_hits: None,
+                ram_hits: None,
+                alloc_bytes: None,
+                allocs: None,
             },
         ];
 
@@ -3044,6 +3586,19 @@ mod tests {
                 binary_bytes: None,
                 stdout: None,
                 stderr: None,
+                latency_p50_us: None,
+                latency_p95_us: None,
+                latency_p99_us: None,
+                error_rate: None,
+                throughput_per_s: None,
+                time_to_ready_ms: None,
+                instructions: None,
+                estimated_cycles: None,
+                l1_hits: None,
+                ll_hits: None,
+                ram_hits: None,
+                alloc_bytes: None,
+                allocs: None,
             },
             Sample {
                 wall_ms: 100,
@@ -3061,6 +3616,19 @@ mod tests {
                 binary_bytes: None,
                 stdout: None,
                 stderr: None,
+                latency_p50_us: None,
+                latency_p95_us: None,
+                latency_p99_us: None,
+                error_rate: None,
+                throughput_per_s: None,
+                time_to_ready_ms: None,
+                instructions: None,
+                estimated_cycles: None,
+                l1_hits: None,
+                ll_hits: None,
+                ram_hits: None,
+                alloc_bytes: None,
+                allocs: None,
             },
             Sample {
                 wall_ms: 100,
@@ -3078,6 +3646,19 @@ mod tests {
                 binary_bytes: None,
                 stdout: None,
                 stderr: None,
+                latency_p50_us: None,
+                latency_p95_us: None,
+                latency_p99_us: None,
+                error_rate: None,
+                throughput_per_s: None,
+                time_to_ready_ms: None,
+                instructions: None,
+                estimated_cycles: None,
+                l1_hits: None,
+                ll_hits: None,
+                ram_hits: None,
+                alloc_bytes: None,
+                allocs: None,
             },
         ];
 
@@ -3110,6 +3691,19 @@ mod tests {
             energy_uj: None,
             binary_bytes: None,
             throughput_per_s: None,
+            latency_p50_us: None,
+            latency_p95_us: None,
+            latency_p99_us: None,
+            error_rate: None,
+            time_to_ready_ms: None,
+            instructions: None,
+            estimated_cycles: None,
+            l1_hits: None,
+            ll_hits: None,
+            ram_hits: None,
+            alloc_bytes: None,
+            allocs: None,
+            custom: Default::default(),
         };
         // Current has 100% increase in cpu_ms (50 -> 100)
         let current = Stats {
@@ -3124,6 +3718,19 @@ mod tests {
             energy_uj: None,
             binary_bytes: None,
             throughput_per_s: None,
+            latency_p50_us: None,
+            latency_p95_us: None,
+            latency_p99_us: None,
+            error_rate: None,
+            time_to_ready_ms: None,
+            instructions: None,
+            estimated_cycles: None,
+            l1_hits: None,
+            ll_hits: None,
+            ram_hits: None,
+            alloc_bytes: None,
+            allocs: None,
+            custom: Default::default(),
         };
         let mut budgets = BTreeMap::new();
         budgets.insert(Metric::CpuMs, Budget::new(0.20, 0.10, Direction::Lower));
@@ -3165,6 +3772,19 @@ mod tests {
             energy_uj: None,
             binary_bytes: None,
             throughput_per_s: None,
+            latency_p50_us: None,
+            latency_p95_us: None,
+            latency_p99_us: None,
+            error_rate: None,
+            time_to_ready_ms: None,
+            instructions: None,
+            estimated_cycles: None,
+            l1_hits: None,
+            ll_hits: None,
+            ram_hits: None,
+            alloc_bytes: None,
+            allocs: None,
+            custom: Default::default(),
         };
         // Current has 50% decrease in cpu_ms (100 -> 50) - improvement!
         let current = Stats {
@@ -3179,6 +3799,19 @@ mod tests {
             energy_uj: None,
             binary_bytes: None,
             throughput_per_s: None,
+            latency_p50_us: None,
+            latency_p95_us: None,
+            latency_p99_us: None,
+            error_rate: None,
+            time_to_ready_ms: None,
+            instructions: None,
+            estimated_cycles: None,
+            l1_hits: None,
+            ll_hits: None,
+            ram_hits: None,
+            alloc_bytes: None,
+            allocs: None,
+            custom: Default::default(),
         };
         let mut budgets = BTreeMap::new();
         budgets.insert(Metric::CpuMs, Budget::new(0.20, 0.10, Direction::Lower));
@@ -3217,6 +3850,19 @@ mod tests {
             energy_uj: None,
             binary_bytes: None,
             throughput_per_s: None,
+            latency_p50_us: None,
+            latency_p95_us: None,
+            latency_p99_us: None,
+            error_rate: None,
+            time_to_ready_ms: None,
+            instructions: None,
+            estimated_cycles: None,
+            l1_hits: None,
+            ll_hits: None,
+            ram_hits: None,
+            alloc_bytes: None,
+            allocs: None,
+            custom: Default::default(),
         };
         let current = Stats {
             wall_ms: U64Summary::new(100, 100, 100),
@@ -3230,6 +3876,19 @@ mod tests {
             energy_uj: None,
             binary_bytes: None,
             throughput_per_s: None,
+            latency_p50_us: None,
+            latency_p95_us: None,
+            latency_p99_us: None,
+            error_rate: None,
+            time_to_ready_ms: None,
+            instructions: None,
+            estimated_cycles: None,
+            l1_hits: None,
+            ll_hits: None,
+            ram_hits: None,
+            alloc_bytes: None,
+            allocs: None,
+            custom: Default::default(),
         };
         let mut budgets = BTreeMap::new();
         budgets.insert(Metric::CpuMs, Budget::new(0.20, 0.10, Direction::Lower));
@@ -3258,6 +3917,19 @@ mod tests {
             energy_uj: None,
             binary_bytes: None,
             throughput_per_s: None,
+            latency_p50_us: None,
+            latency_p95_us: None,
+            latency_p99_us: None,
+            error_rate: None,
+            time_to_ready_ms: None,
+            instructions: None,
+            estimated_cycles: None,
+            l1_hits: None,
+            ll_hits: None,
+            ram_hits: None,
+            alloc_bytes: None,
+            allocs: None,
+            custom: Default::default(),
         };
         let current = Stats {
             wall_ms: U64Summary::new(100, 100, 100),
@@ -3271,6 +3943,19 @@ mod tests {
             energy_uj: None,
             binary_bytes: None,
             throughput_per_s: None,
+            latency_p50_us: None,
+            latency_p95_us: None,
+            latency_p99_us: None,
+            error_rate: None,
+            time_to_ready_ms: None,
+            instructions: None,
+            estimated_cycles: None,
+            l1_hits: None,
+            ll_hits: None,
+            ram_hits: None,
+            alloc_bytes: None,
+            allocs: None,
+            custom: Default::default(),
         };
         let mut budgets = BTreeMap::new();
         budgets.insert(Metric::CpuMs, Budget::new(0.20, 0.10, Direction::Lower));
@@ -3299,6 +3984,19 @@ mod tests {
             energy_uj: None,
             binary_bytes: None,
             throughput_per_s: None,
+            latency_p50_us: None,
+            latency_p95_us: None,
+            latency_p99_us: None,
+            error_rate: None,
+            time_to_ready_ms: None,
+            instructions: None,
+            estimated_cycles: None,
+            l1_hits: None,
+            ll_hits: None,
+            ram_hits: None,
+            alloc_bytes: None,
+            allocs: None,
+            custom: Default::default(),
         };
         // Current has 15% increase in cpu_ms (100 -> 115)
         let current = Stats {
@@ -3313,6 +4011,19 @@ mod tests {
             energy_uj: None,
             binary_bytes: None,
             throughput_per_s: None,
+            latency_p50_us: None,
+            latency_p95_us: None,
+            latency_p99_us: None,
+            error_rate: None,
+            time_to_ready_ms: None,
+            instructions: None,
+            estimated_cycles: None,
+            l1_hits: None,
+            ll_hits: None,
+            ram_hits: None,
+            alloc_bytes: None,
+            allocs: None,
+            custom: Default::default(),
         };
         let mut budgets = BTreeMap::new();
         budgets.insert(Metric::CpuMs, Budget::new(0.20, 0.10, Direction::Lower));
@@ -3346,6 +4057,19 @@ mod tests {
             energy_uj: None,
             binary_bytes: None,
             throughput_per_s: None,
+            latency_p50_us: None,
+            latency_p95_us: None,
+            latency_p99_us: None,
+            error_rate: None,
+            time_to_ready_ms: None,
+            instructions: None,
+            estimated_cycles: None,
+            l1_hits: None,
+            ll_hits: None,
+            ram_hits: None,
+            alloc_bytes: None,
+            allocs: None,
+            custom: Default::default(),
         };
         let current = Stats {
             wall_ms: U64Summary::new(1100, 1100, 1100),
@@ -3359,6 +4083,19 @@ mod tests {
             energy_uj: None,
             binary_bytes: None,
             throughput_per_s: None,
+            latency_p50_us: None,
+            latency_p95_us: None,
+            latency_p99_us: None,
+            error_rate: None,
+            time_to_ready_ms: None,
+            instructions: None,
+            estimated_cycles: None,
+            l1_hits: None,
+            ll_hits: None,
+            ram_hits: None,
+            alloc_bytes: None,
+            allocs: None,
+            custom: Default::default(),
         };
         let mut budgets = BTreeMap::new();
         budgets.insert(Metric::WallMs, Budget::new(0.20, 0.18, Direction::Lower));
@@ -3383,6 +4120,19 @@ mod tests {
             energy_uj: None,
             binary_bytes: None,
             throughput_per_s: Some(F64Summary::new(110.0, 110.0, 110.0)),
+            latency_p50_us: None,
+            latency_p95_us: None,
+            latency_p99_us: None,
+            error_rate: None,
+            time_to_ready_ms: None,
+            instructions: None,
+            estimated_cycles: None,
+            l1_hits: None,
+            ll_hits: None,
+            ram_hits: None,
+            alloc_bytes: None,
+            allocs: None,
+            custom: Default::default(),
         };
         let current = Stats {
             wall_ms: U64Summary::new(1000, 1000, 1000),
@@ -3396,6 +4146,19 @@ mod tests {
             energy_uj: None,
             binary_bytes: None,
             throughput_per_s: Some(F64Summary::new(100.0, 100.0, 100.0)),
+            latency_p50_us: None,
+            latency_p95_us: None,
+            latency_p99_us: None,
+            error_rate: None,
+            time_to_ready_ms: None,
+            instructions: None,
+            estimated_cycles: None,
+            l1_hits: None,
+            ll_hits: None,
+            ram_hits: None,
+            alloc_bytes: None,
+            allocs: None,
+            custom: Default::default(),
         };
         let mut budgets = BTreeMap::new();
         budgets.insert(
@@ -3423,6 +4186,19 @@ mod tests {
             energy_uj: None,
             binary_bytes: None,
             throughput_per_s: Some(F64Summary::new(100.0, 100.0, 100.0)),
+            latency_p50_us: None,
+            latency_p95_us: None,
+            latency_p99_us: None,
+            error_rate: None,
+            time_to_ready_ms: None,
+            instructions: None,
+            estimated_cycles: None,
+            l1_hits: None,
+            ll_hits: None,
+            ram_hits: None,
+            alloc_bytes: None,
+            allocs: None,
+            custom: Default::default(),
         };
         let current = Stats {
             wall_ms: U64Summary::new(1000, 1000, 1000),
@@ -3436,6 +4212,19 @@ mod tests {
             energy_uj: None,
             binary_bytes: None,
             throughput_per_s: Some(F64Summary::new(120.0, 120.0, 120.0)),
+            latency_p50_us: None,
+            latency_p95_us: None,
+            latency_p99_us: None,
+            error_rate: None,
+            time_to_ready_ms: None,
+            instructions: None,
+            estimated_cycles: None,
+            l1_hits: None,
+            ll_hits: None,
+            ram_hits: None,
+            alloc_bytes: None,
+            allocs: None,
+            custom: Default::default(),
         };
         let mut budgets = BTreeMap::new();
         budgets.insert(
@@ -3534,6 +4323,19 @@ mod tests {
                     binary_bytes: None,
                     stdout: None,
                     stderr: None,
+                    latency_p50_us: None,
+                    latency_p95_us: None,
+                    latency_p99_us: None,
+                    error_rate: None,
+                    throughput_per_s: None,
+                    time_to_ready_ms: None,
+                    instructions: None,
+                    estimated_cycles: None,
+                    l1_hits: None,
+                    ll_hits: None,
+                    ram_hits: None,
+                    alloc_bytes: None,
+                    allocs: None,
                 },
                 Sample {
                     wall_ms: 200,
@@ -3551,6 +4353,19 @@ mod tests {
                     binary_bytes: None,
                     stdout: None,
                     stderr: None,
+                    latency_p50_us: None,
+                    latency_p95_us: None,
+                    latency_p99_us: None,
+                    error_rate: None,
+                    throughput_per_s: None,
+                    time_to_ready_ms: None,
+                    instructions: None,
+                    estimated_cycles: None,
+                    l1_hits: None,
+                    ll_hits: None,
+                    ram_hits: None,
+                    alloc_bytes: None,
+                    allocs: None,
                 },
                 Sample {
                     wall_ms: 150,
@@ -3568,6 +4383,19 @@ mod tests {
                     binary_bytes: None,
                     stdout: None,
                     stderr: None,
+                    latency_p50_us: None,
+                    latency_p95_us: None,
+                    latency_p99_us: None,
+                    error_rate: None,
+                    throughput_per_s: None,
+                    time_to_ready_ms: None,
+                    instructions: None,
+                    estimated_cycles: None,
+                    l1_hits: None,
+                    ll_hits: None,
+                    ram_hits: None,
+                    alloc_bytes: None,
+                    allocs: None,
                 },
             ];
 
@@ -3604,6 +4432,19 @@ mod tests {
                 binary_bytes: None,
                 stdout: None,
                 stderr: None,
+                latency_p50_us: None,
+                latency_p95_us: None,
+                latency_p99_us: None,
+                error_rate: None,
+                throughput_per_s: None,
+                time_to_ready_ms: None,
+                instructions: None,
+                estimated_cycles: None,
+                l1_hits: None,
+                ll_hits: None,
+                ram_hits: None,
+                alloc_bytes: None,
+                allocs: None,
             }];
 
             // Even with work_units specified, should still fail
@@ -3641,6 +4482,19 @@ mod tests {
                 energy_uj: None,
                 binary_bytes: None,
                 throughput_per_s: None,
+                latency_p50_us: None,
+                latency_p95_us: None,
+                latency_p99_us: None,
+                error_rate: None,
+                time_to_ready_ms: None,
+                instructions: None,
+                estimated_cycles: None,
+                l1_hits: None,
+                ll_hits: None,
+                ram_hits: None,
+                alloc_bytes: None,
+                allocs: None,
+                custom: Default::default(),
             };
 
             let current = Stats {
@@ -3655,6 +4509,19 @@ mod tests {
                 energy_uj: None,
                 binary_bytes: None,
                 throughput_per_s: None,
+                latency_p50_us: None,
+                latency_p95_us: None,
+                latency_p99_us: None,
+                error_rate: None,
+                time_to_ready_ms: None,
+                instructions: None,
+                estimated_cycles: None,
+                l1_hits: None,
+                ll_hits: None,
+                ram_hits: None,
+                alloc_bytes: None,
+                allocs: None,
+                custom: Default::default(),
             };
 
             let mut budgets = BTreeMap::new();
@@ -3685,6 +4552,19 @@ mod tests {
                 energy_uj: None,
                 binary_bytes: None,
                 throughput_per_s: Some(F64Summary::new(0.0, 0.0, 0.0)),
+                latency_p50_us: None,
+                latency_p95_us: None,
+                latency_p99_us: None,
+                error_rate: None,
+                time_to_ready_ms: None,
+                instructions: None,
+                estimated_cycles: None,
+                l1_hits: None,
+                ll_hits: None,
+                ram_hits: None,
+                alloc_bytes: None,
+                allocs: None,
+                custom: Default::default(),
             };
 
             let current = Stats {
@@ -3699,6 +4579,19 @@ mod tests {
                 energy_uj: None,
                 binary_bytes: None,
                 throughput_per_s: Some(F64Summary::new(100.0, 100.0, 100.0)),
+                latency_p50_us: None,
+                latency_p95_us: None,
+                latency_p99_us: None,
+                error_rate: None,
+                time_to_ready_ms: None,
+                instructions: None,
+                estimated_cycles: None,
+                l1_hits: None,
+                ll_hits: None,
+                ram_hits: None,
+                alloc_bytes: None,
+                allocs: None,
+                custom: Default::default(),
             };
 
             let mut budgets = BTreeMap::new();
@@ -3732,6 +4625,19 @@ mod tests {
                 energy_uj: None,
                 binary_bytes: None,
                 throughput_per_s: None,
+                latency_p50_us: None,
+                latency_p95_us: None,
+                latency_p99_us: None,
+                error_rate: None,
+                time_to_ready_ms: None,
+                instructions: None,
+                estimated_cycles: None,
+                l1_hits: None,
+                ll_hits: None,
+                ram_hits: None,
+                alloc_bytes: None,
+                allocs: None,
+                custom: Default::default(),
             };
 
             let current = Stats {
@@ -3746,6 +4652,19 @@ mod tests {
                 energy_uj: None,
                 binary_bytes: None,
                 throughput_per_s: None,
+                latency_p50_us: None,
+                latency_p95_us: None,
+                latency_p99_us: None,
+                error_rate: None,
+                time_to_ready_ms: None,
+                instructions: None,
+                estimated_cycles: None,
+                l1_hits: None,
+                ll_hits: None,
+                ram_hits: None,
+                alloc_bytes: None,
+                allocs: None,
+                custom: Default::default(),
             };
 
             let mut budgets = BTreeMap::new();
@@ -3777,6 +4696,19 @@ mod tests {
                 energy_uj: None,
                 binary_bytes: None,
                 throughput_per_s: Some(F64Summary::new(-10.0, -10.0, -10.0)),
+                latency_p50_us: None,
+                latency_p95_us: None,
+                latency_p99_us: None,
+                error_rate: None,
+                time_to_ready_ms: None,
+                instructions: None,
+                estimated_cycles: None,
+                l1_hits: None,
+                ll_hits: None,
+                ram_hits: None,
+                alloc_bytes: None,
+                allocs: None,
+                custom: Default::default(),
             };
 
             let current = Stats {
@@ -3791,6 +4723,19 @@ mod tests {
                 energy_uj: None,
                 binary_bytes: None,
                 throughput_per_s: Some(F64Summary::new(100.0, 100.0, 100.0)),
+                latency_p50_us: None,
+                latency_p95_us: None,
+                latency_p99_us: None,
+                error_rate: None,
+                time_to_ready_ms: None,
+                instructions: None,
+                estimated_cycles: None,
+                l1_hits: None,
+                ll_hits: None,
+                ram_hits: None,
+                alloc_bytes: None,
+                allocs: None,
+                custom: Default::default(),
             };
 
             let mut budgets = BTreeMap::new();
@@ -3886,14 +4831,18 @@ mod tests {
                     warmup: 1,
                     work_units: None,
                     timeout_ms: None,
+                    service: None,
+                    load: None,
                 },
                 baseline_ref: CompareRef {
                     path: Some("baseline.json".to_string()),
                     run_id: None,
+                    profile: None,
                 },
                 current_ref: CompareRef {
                     path: Some("current.json".to_string()),
                     run_id: None,
+                    profile: None,
                 },
                 budgets,
                 deltas,
@@ -4264,6 +5213,19 @@ mod tests {
                 energy_uj: None,
                 binary_bytes: None,
                 throughput_per_s: None,
+                latency_p50_us: None,
+                latency_p95_us: None,
+                latency_p99_us: None,
+                error_rate: None,
+                time_to_ready_ms: None,
+                instructions: None,
+                estimated_cycles: None,
+                l1_hits: None,
+                ll_hits: None,
+                ram_hits: None,
+                alloc_bytes: None,
+                allocs: None,
+                custom: Default::default(),
             };
             let cv = metric_cv(&stats, Metric::WallMs).expect("should return Some");
             assert!((cv - 0.1).abs() < f64::EPSILON);
@@ -4289,6 +5251,19 @@ mod tests {
                 energy_uj: None,
                 binary_bytes: None,
                 throughput_per_s: None,
+                latency_p50_us: None,
+                latency_p95_us: None,
+                latency_p99_us: None,
+                error_rate: None,
+                time_to_ready_ms: None,
+                instructions: None,
+                estimated_cycles: None,
+                l1_hits: None,
+                ll_hits: None,
+                ram_hits: None,
+                alloc_bytes: None,
+                allocs: None,
+                custom: Default::default(),
             };
             let cv = metric_cv(&stats, Metric::CpuMs).expect("should return Some");
             assert!((cv - 0.1).abs() < f64::EPSILON);
@@ -4308,6 +5283,19 @@ mod tests {
                 energy_uj: None,
                 binary_bytes: None,
                 throughput_per_s: None,
+                latency_p50_us: None,
+                latency_p95_us: None,
+                latency_p99_us: None,
+                error_rate: None,
+                time_to_ready_ms: None,
+                instructions: None,
+                estimated_cycles: None,
+                l1_hits: None,
+                ll_hits: None,
+                ram_hits: None,
+                alloc_bytes: None,
+                allocs: None,
+                custom: Default::default(),
             };
             assert!(metric_cv(&stats, Metric::CpuMs).is_none());
             assert!(metric_cv(&stats, Metric::MaxRssKb).is_none());
@@ -4334,6 +5322,19 @@ mod tests {
                     mean: Some(1000.0),
                     stddev: Some(50.0),
                 }),
+                latency_p50_us: None,
+                latency_p95_us: None,
+                latency_p99_us: None,
+                error_rate: None,
+                time_to_ready_ms: None,
+                instructions: None,
+                estimated_cycles: None,
+                l1_hits: None,
+                ll_hits: None,
+                ram_hits: None,
+                alloc_bytes: None,
+                allocs: None,
+                custom: Default::default(),
             };
             let cv = metric_cv(&stats, Metric::ThroughputPerS).expect("should return Some");
             assert!((cv - 0.05).abs() < f64::EPSILON);
@@ -4361,6 +5362,19 @@ mod tests {
                     network_packets: None,
                     energy_uj: None,
                     binary_bytes: None,
+                    latency_p50_us: None,
+                    latency_p95_us: None,
+                    latency_p99_us: None,
+                    error_rate: None,
+                    throughput_per_s: None,
+                    time_to_ready_ms: None,
+                    instructions: None,
+                    estimated_cycles: None,
+                    l1_hits: None,
+                    ll_hits: None,
+                    ram_hits: None,
+                    alloc_bytes: None,
+                    allocs: None,
                     stdout: None,
                     stderr: None,
                 })
@@ -4384,6 +5398,10 @@ mod tests {
                         memory_bytes: None,
                         hostname_hash: None,
                     },
+                    profile: None,
+                    concurrency: None,
+                    interleave: None,
+                    noise_warnings: Vec::new(),
                 },
                 bench: perfgate_types::BenchMeta {
                     name: "test".to_string(),
@@ -4393,6 +5411,8 @@ mod tests {
                     warmup: 0,
                     work_units: None,
                     timeout_ms: None,
+                    service: None,
+                    load: None,
                 },
                 samples,
                 stats,
@@ -220,6 +220,7 @@ pub fn summarize_paired_diffs(
             p_value: None, // Paired t-test p-value could be added here
             ci_lower: Some(ci_lower),
             ci_upper: Some(ci_upper),
+            adjusted_p_value: None,
             baseline_samples: count,
             current_samples: count,
         }
@@ -72,10 +72,10 @@ pub struct Report {
 ///     bench: BenchMeta {
 ///         name: "my-bench".into(), cwd: None,
 ///         command: vec!["echo".into()], repeat: 5, warmup: 0,
-///         work_units: None, timeout_ms: None,
+///         work_units: None, timeout_ms: None, service: None, load: None,
 ///     },
-///     baseline_ref: CompareRef { path: None, run_id: None },
-///     current_ref: CompareRef { path: None, run_id: None },
+///     baseline_ref: CompareRef { path: None, run_id: None, profile: None },
+///     current_ref: CompareRef { path: None, run_id: None, profile: None },
 ///     budgets: BTreeMap::new(),
 ///     deltas: BTreeMap::new(),
 ///     verdict: Verdict {
@@ -141,6 +141,7 @@ pub fn compute_significance(
         current_samples: current.len() as u32,
         ci_lower: None, // Could be calculated here if needed
         ci_upper: None, // Could be calculated here if needed
+        adjusted_p_value: None,
     })
 }
 
@@ -201,6 +202,41 @@ pub fn mean_and_variance(values: &[f64]) -> Option<(f64, f64)> {
     }
 }
 
+/// Benjamini–Hochberg adjusted p-values, in the same order as `p_values`.
+///
+/// Controls the false discovery rate when many significance tests are
+/// evaluated together (e.g. every metric of every bench in a suite). The
+/// adjusted value for the `i`-th smallest of `m` p-values is
+/// `min_{j >= i} (m * p_(j) / j)`, capped at 1.0, so comparing adjusted
+/// values against `alpha` is equivalent to the step-up procedure.
+///
+/// # Example
+///
+/// ```
+/// use perfgate::domain::significance::benjamini_hochberg;
+///
+/// let adjusted = benjamini_hochberg(&[0.01, 0.04, 0.03, 0.20]);
+/// assert!((adjusted[0] - 0.04).abs() < 1e-12);
+/// assert!((adjusted[1] - 0.0533333333).abs() < 1e-9);
+/// assert!((adjusted[2] - 0.0533333333).abs() < 1e-9);
+/// assert!((adjusted[3] - 0.20).abs() < 1e-12);
+/// ```
+#[must_use = "pure computation; call site should use the adjusted p-values"]
+pub fn benjamini_hochberg(p_values: &[f64]) -> Vec<f64> {
+    let m = p_values.len();
+    let mut order: Vec<usize> = (0..m).collect();
+    order.sort_by(|&a, &b| p_values[a].total_cmp(&p_values[b]));
+
+    let mut adjusted = vec![1.0; m];
+    let mut running_min = 1.0_f64;
+    for (rank, &index) in order.iter().enumerate().rev() {
+        let scaled = p_values[index] * m as f64 / (rank + 1) as f64;
+        running_min = running_min.min(scaled);
+        adjusted[index] = running_min.clamp(0.0, 1.0);
+    }
+    adjusted
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -739,4 +775,30 @@ mod tests {
             assert!(sig.significant);
         }
     }
+
+    #[test]
+    fn benjamini_hochberg_matches_reference_values() {
+        // Reference values from R: p.adjust(p, method = "BH")
+        let p = [0.001, 0.008, 0.039, 0.041, 0.042, 0.06, 0.074, 0.205];
+        let expected = [
+            0.008,
+            0.032,
+            0.0672,
+            0.0672,
+            0.0672,
+            0.08,
+            0.0845714286,
+            0.205,
+        ];
+        let adjusted = benjamini_hochberg(&p);
+        for (got, want) in adjusted.iter().zip(expected) {
+            assert_relative_eq!(*got, want, epsilon = 1e-9);
+        }
+    }
+
+    #[test]
+    fn benjamini_hochberg_handles_empty_and_single_inputs() {
+        assert!(benjamini_hochberg(&[]).is_empty());
+        assert_eq!(benjamini_hochberg(&[0.03]), vec![0.03]);
+    }
 }
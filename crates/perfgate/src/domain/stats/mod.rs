@@ -218,10 +218,45 @@ pub fn mean_and_variance(values: &[f64]) -> Option<(f64, f64)> {
     }
 }
 
+/// Geometric mean of strictly positive values.
+///
+/// Returns `None` if `values` is empty or contains a non-positive or
+/// non-finite value.
+///
+/// # Examples
+///
+/// ```
+/// use perfgate::domain::stats::geometric_mean;
+///
+/// let g = geometric_mean(&[1.0, 4.0]).unwrap();
+/// assert!((g - 2.0).abs() < 1e-10);
+///
+/// assert!(geometric_mean(&[]).is_none());
+/// assert!(geometric_mean(&[1.0, 0.0]).is_none());
+/// ```
+#[must_use = "pure computation; call site should use the returned geometric mean"]
+pub fn geometric_mean(values: &[f64]) -> Option<f64> {
+    if values.is_empty() || values.iter().any(|v| !v.is_finite() || *v <= 0.0) {
+        return None;
+    }
+
+    let log_sum: f64 = values.iter().map(|v| v.ln()).sum();
+    let mean = (log_sum / values.len() as f64).exp();
+    mean.is_finite().then_some(mean)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn geometric_mean_of_ratios() {
+        let g = geometric_mean(&[0.5, 2.0, 1.0]).unwrap();
+        assert!((g - 1.0).abs() < 1e-12);
+        assert!(geometric_mean(&[-1.0]).is_none());
+        assert!(geometric_mean(&[f64::NAN]).is_none());
+    }
+
     #[test]
     fn summarize_u64_empty_returns_error() {
         let result = summarize_u64(&[]);
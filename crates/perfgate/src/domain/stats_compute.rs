@@ -1,5 +1,5 @@
 use super::{DomainError, summarize_f64, summarize_u64};
-use perfgate_types::Stats;
+use perfgate_types::{Stats, U64Summary};
 
 /// Compute perfgate stats from samples.
 ///
@@ -17,12 +17,22 @@ use perfgate_types::Stats;
 ///         cpu_ms: None, page_faults: None, ctx_switches: None,
 ///         max_rss_kb: None, io_read_bytes: None, io_write_bytes: None,
 ///         network_packets: None, energy_uj: None, binary_bytes: None, stdout: None, stderr: None,
+///         latency_p50_us: None, latency_p95_us: None, latency_p99_us: None, error_rate: None, throughput_per_s: None,
+///         time_to_ready_ms: None,
+///         instructions: None, estimated_cycles: None,
+///         l1_hits: None, ll_hits: None, ram_hits: None,
+///         alloc_bytes: None, allocs: None,
 ///     },
 ///     Sample {
 ///         wall_ms: 120, exit_code: 0, warmup: false, timed_out: false,
 ///         cpu_ms: None, page_faults: None, ctx_switches: None,
 ///         max_rss_kb: None, io_read_bytes: None, io_write_bytes: None,
 ///         network_packets: None, energy_uj: None, binary_bytes: None, stdout: None, stderr: None,
+///         latency_p50_us: None, latency_p95_us: None, latency_p99_us: None, error_rate: None, throughput_per_s: None,
+///         time_to_ready_ms: None,
+///         instructions: None, estimated_cycles: None,
+///         l1_hits: None, ll_hits: None, ram_hits: None,
+///         alloc_bytes: None, allocs: None,
 ///     },
 /// ];
 ///
@@ -106,7 +116,29 @@ pub fn compute_stats(
         Some(summarize_u64(&binary_vals)?)
     };
 
+    let latency_p50_us = summarize_optional_u64(&measured, |s| s.latency_p50_us)?;
+    let latency_p95_us = summarize_optional_u64(&measured, |s| s.latency_p95_us)?;
+    let latency_p99_us = summarize_optional_u64(&measured, |s| s.latency_p99_us)?;
+    let time_to_ready_ms = summarize_optional_u64(&measured, |s| s.time_to_ready_ms)?;
+    let instructions = summarize_optional_u64(&measured, |s| s.instructions)?;
+    let estimated_cycles = summarize_optional_u64(&measured, |s| s.estimated_cycles)?;
+    let l1_hits = summarize_optional_u64(&measured, |s| s.l1_hits)?;
+    let ll_hits = summarize_optional_u64(&measured, |s| s.ll_hits)?;
+    let ram_hits = summarize_optional_u64(&measured, |s| s.ram_hits)?;
+    let alloc_bytes = summarize_optional_u64(&measured, |s| s.alloc_bytes)?;
+    let allocs = summarize_optional_u64(&measured, |s| s.allocs)?;
+
+    let error_vals: Vec<f64> = measured.iter().filter_map(|s| s.error_rate).collect();
+    let error_rate = if error_vals.is_empty() {
+        None
+    } else {
+        Some(summarize_f64(&error_vals)?)
+    };
+
+    // Load samples measure their own throughput; otherwise derive it from work units.
+    let sample_thr: Vec<f64> = measured.iter().filter_map(|s| s.throughput_per_s).collect();
     let throughput_per_s = match work_units {
+        _ if !sample_thr.is_empty() => Some(summarize_f64(&sample_thr)?),
         Some(work) => {
             let thr: Vec<f64> = measured
                 .iter()
@@ -136,5 +168,30 @@ pub fn compute_stats(
         energy_uj,
         binary_bytes,
         throughput_per_s,
+        latency_p50_us,
+        latency_p95_us,
+        latency_p99_us,
+        error_rate,
+        time_to_ready_ms,
+        instructions,
+        estimated_cycles,
+        l1_hits,
+        ll_hits,
+        ram_hits,
+        alloc_bytes,
+        allocs,
+        custom: Default::default(),
     })
 }
+
+fn summarize_optional_u64(
+    samples: &[&perfgate_types::Sample],
+    value: impl Fn(&perfgate_types::Sample) -> Option<u64>,
+) -> Result<Option<U64Summary>, DomainError> {
+    let values: Vec<u64> = samples.iter().filter_map(|s| value(s)).collect();
+    if values.is_empty() {
+        Ok(None)
+    } else {
+        Ok(Some(summarize_u64(&values)?))
+    }
+}
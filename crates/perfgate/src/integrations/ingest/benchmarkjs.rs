@@ -0,0 +1,213 @@
+//! Parser for Benchmark.js results serialized as JSON.
+//!
+//! Benchmark.js has no JSON reporter; suites typically write
+//! `JSON.stringify(this)` or `JSON.stringify(Array.from(this))` from their
+//! `complete` handler, which yields an array of Benchmark objects:
+//!
+//! ```json
+//! [{"name": "RegExp#test", "hz": 5300000, "stats": {"mean": 1.9e-7,
+//!   "deviation": 2e-9, "sample": [1.9e-7, 1.8e-7]}}]
+//! ```
+//!
+//! An object with a `benchmarks` array is accepted too. `stats.sample` and
+//! `stats.mean` (seconds per operation) map to `wall_ms`, and `hz`
+//! (operations per second) maps to `throughput_per_s`.
+
+use anyhow::{Context, bail};
+use perfgate_types::{F64Summary, RunReceipt, Stats};
+use serde::Deserialize;
+use serde_json::Value;
+
+use super::{f64_summary, make_receipt, to_u64, to_u64_summary, wall_sample};
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum BenchmarkJsOutput {
+    List(Vec<BenchmarkJsEntry>),
+    Wrapped { benchmarks: Vec<BenchmarkJsEntry> },
+}
+
+/// A serialized Benchmark.js `Benchmark` object.
+#[derive(Debug, Deserialize)]
+struct BenchmarkJsEntry {
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default)]
+    id: Option<u64>,
+    hz: f64,
+    stats: BenchmarkJsStats,
+    #[serde(default)]
+    aborted: bool,
+    #[serde(default)]
+    error: Option<Value>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BenchmarkJsStats {
+    /// Mean seconds per operation.
+    mean: f64,
+    /// Standard deviation of the sample, in seconds.
+    #[serde(default)]
+    deviation: Option<f64>,
+    /// Seconds per operation of each measured cycle.
+    #[serde(default)]
+    sample: Vec<f64>,
+}
+
+/// Parse Benchmark.js JSON into a `RunReceipt`.
+///
+/// If the input contains multiple benchmarks, only the first is used;
+/// [`parse_benchmarkjs_all`] keeps every one. Use `name` to override the
+/// benchmark name (defaults to the Benchmark's `name`, then its `id`).
+pub fn parse_benchmarkjs(input: &str, name: Option<&str>) -> anyhow::Result<RunReceipt> {
+    let entries = parse_entries(input)?;
+    entry_to_receipt(&entries[0], 0, name)
+}
+
+/// Parse Benchmark.js JSON into one `RunReceipt` per benchmark.
+pub fn parse_benchmarkjs_all(input: &str) -> anyhow::Result<Vec<RunReceipt>> {
+    let entries = parse_entries(input)?;
+    entries
+        .iter()
+        .enumerate()
+        .map(|(index, entry)| entry_to_receipt(entry, index, None))
+        .collect()
+}
+
+fn parse_entries(input: &str) -> anyhow::Result<Vec<BenchmarkJsEntry>> {
+    let output: BenchmarkJsOutput =
+        serde_json::from_str(input).context("failed to parse Benchmark.js JSON")?;
+    let entries = match output {
+        BenchmarkJsOutput::List(entries) => entries,
+        BenchmarkJsOutput::Wrapped { benchmarks } => benchmarks,
+    };
+    if entries.is_empty() {
+        bail!("Benchmark.js JSON contains no benchmarks");
+    }
+    Ok(entries)
+}
+
+fn entry_to_receipt(
+    entry: &BenchmarkJsEntry,
+    index: usize,
+    name: Option<&str>,
+) -> anyhow::Result<RunReceipt> {
+    let bench_name = match (name, &entry.name, entry.id) {
+        (Some(name), _, _) => name.to_string(),
+        (None, Some(name), _) => name.clone(),
+        (None, None, Some(id)) => format!("benchmark-{id}"),
+        (None, None, None) => format!("benchmark-{}", index + 1),
+    };
+    if let Some(error) = &entry.error {
+        bail!("Benchmark.js benchmark '{bench_name}' failed: {error}");
+    }
+    if entry.aborted {
+        bail!("Benchmark.js benchmark '{bench_name}' was aborted");
+    }
+
+    let sample_ms: Vec<f64> = entry.stats.sample.iter().map(|s| s * 1e3).collect();
+    for value in sample_ms.iter().chain([&entry.stats.mean, &entry.hz]) {
+        if !value.is_finite() || *value < 0.0 {
+            bail!(
+                "Benchmark.js benchmark '{bench_name}' has a value that is not finite and non-negative"
+            );
+        }
+    }
+
+    let wall = if sample_ms.is_empty() {
+        let mean_ms = entry.stats.mean * 1e3;
+        F64Summary {
+            mean: Some(mean_ms),
+            stddev: entry.stats.deviation.map(|deviation| deviation * 1e3),
+            ..F64Summary::new(mean_ms, mean_ms, mean_ms)
+        }
+    } else {
+        f64_summary(&sample_ms)
+    };
+    let throughput = F64Summary {
+        mean: Some(entry.hz),
+        ..F64Summary::new(entry.hz, entry.hz, entry.hz)
+    };
+
+    let stats = Stats {
+        wall_ms: to_u64_summary(&wall),
+        cpu_ms: None,
+        page_faults: None,
+        ctx_switches: None,
+        max_rss_kb: None,
+        io_read_bytes: None,
+        io_write_bytes: None,
+        network_packets: None,
+        energy_uj: None,
+        binary_bytes: None,
+        throughput_per_s: Some(throughput),
+        latency_p50_us: None,
+        latency_p95_us: None,
+        latency_p99_us: None,
+        error_rate: None,
+        time_to_ready_ms: None,
+        instructions: None,
+        estimated_cycles: None,
+        l1_hits: None,
+        ll_hits: None,
+        ram_hits: None,
+        alloc_bytes: None,
+        allocs: None,
+        custom: Default::default(),
+    };
+
+    let samples = sample_ms
+        .iter()
+        .map(|ms| wall_sample(to_u64(*ms)))
+        .collect();
+    let mut receipt = make_receipt(&bench_name, samples, stats);
+    receipt.bench.command = vec!["(ingested Benchmark.js output)".to_string()];
+    receipt.run.host.os = "unknown".to_string();
+    receipt.run.host.arch = "unknown".to_string();
+    Ok(receipt)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn samples_map_to_wall_ms_and_hz_to_throughput() {
+        let input = r#"[
+          {"name": "RegExp#test", "id": 1, "hz": 400.0, "count": 20, "cycles": 4,
+           "stats": {"mean": 0.0025, "deviation": 0.0005, "rme": 1.1,
+                     "sample": [0.002, 0.003, 0.0025]},
+           "times": {"cycle": 0.05, "elapsed": 5.2, "period": 0.0025}},
+          {"id": 2, "hz": 100.0, "stats": {"mean": 0.01}}
+        ]"#;
+
+        let receipts = parse_benchmarkjs_all(input).unwrap();
+        assert_eq!(receipts[0].bench.name, "RegExp#test");
+        let walls: Vec<u64> = receipts[0].samples.iter().map(|s| s.wall_ms).collect();
+        assert_eq!(walls, vec![2, 3, 3]);
+        assert!((receipts[0].stats.wall_ms.mean.unwrap() - 2.5).abs() < 1e-9);
+        assert_eq!(
+            receipts[0].stats.throughput_per_s.as_ref().unwrap().median,
+            400.0
+        );
+
+        assert_eq!(receipts[1].bench.name, "benchmark-2");
+        assert!(receipts[1].samples.is_empty());
+        assert_eq!(receipts[1].stats.wall_ms.median, 10);
+    }
+
+    #[test]
+    fn wrapped_output_and_name_override() {
+        let input = r#"{"benchmarks": [{"name": "a", "hz": 2.0, "stats": {"mean": 0.5}}]}"#;
+        let receipt = parse_benchmarkjs(input, Some("js-a")).unwrap();
+        assert_eq!(receipt.bench.name, "js-a");
+        assert_eq!(receipt.stats.wall_ms.median, 500);
+    }
+
+    #[test]
+    fn aborted_benchmark_is_rejected() {
+        let input = r#"[{"name": "a", "hz": 0.0, "aborted": true, "stats": {"mean": 0.0}}]"#;
+        let err = parse_benchmarkjs(input, None).unwrap_err();
+        assert!(err.to_string().contains("aborted"), "{err}");
+    }
+}
@@ -0,0 +1,282 @@
+//! Parser for Valgrind cachegrind results.
+//!
+//! Reads either a raw `cachegrind.out.<pid>` file, using its `events:` and
+//! `summary:` lines, or `cg_annotate` output, using the event header above its
+//! `PROGRAM TOTALS` row. Event counts map as follows:
+//!
+//! - `Ir` -> `instructions`
+//! - with `--cache-sim=yes`, the `I1mr`/`D1mr`/`D1mw` and `ILmr`/`DLmr`/`DLmw`
+//!   misses against `Ir + Dr + Dw` accesses -> `l1_hits`, `ll_hits`,
+//!   `ram_hits`, and `estimated_cycles` as `l1 + 5 * ll + 35 * ram`, the
+//!   model iai-callgrind reports
+//! - every other event (including the raw miss counts) -> `stats.custom`
+//!
+//! Cachegrind simulates execution instead of timing it, so `wall_ms` is 0.
+
+use std::collections::BTreeMap;
+
+use anyhow::{Context, bail};
+use perfgate_types::{CustomMetric, RunReceipt, Stats, U64Summary};
+
+use super::{compute_u64_summary, f64_summary, make_receipt, wall_sample};
+
+/// Parse cachegrind output into a `RunReceipt` named `name`, or after the
+/// profiled program.
+pub fn parse_cachegrind(input: &str, name: Option<&str>) -> anyhow::Result<RunReceipt> {
+    let (command, counts) = match parse_out_file(input)? {
+        Some(parsed) => parsed,
+        None => parse_annotate(input)?,
+    };
+
+    let count = |event: &str| counts.get(event).copied();
+    let cache = (|| {
+        let accesses = count("Ir")? + count("Dr")? + count("Dw")?;
+        let l1_misses = count("I1mr")? + count("D1mr")? + count("D1mw")?;
+        let ll_misses = count("ILmr")? + count("DLmr")? + count("DLmw")?;
+        let l1_hits = accesses.saturating_sub(l1_misses);
+        let ll_hits = l1_misses.saturating_sub(ll_misses);
+        Some((l1_hits, ll_hits, ll_misses))
+    })();
+    let estimated_cycles = cache.map(|(l1, ll, ram)| l1 + 5 * ll + 35 * ram);
+
+    let mut sample = wall_sample(0);
+    sample.instructions = count("Ir");
+    sample.l1_hits = cache.map(|(l1, _, _)| l1);
+    sample.ll_hits = cache.map(|(_, ll, _)| ll);
+    sample.ram_hits = cache.map(|(_, _, ram)| ram);
+    sample.estimated_cycles = estimated_cycles;
+
+    let summary = |value: Option<u64>| value.map(|v| compute_u64_summary(&[v]));
+    let custom = counts
+        .iter()
+        .filter(|(event, _)| event.as_str() != "Ir")
+        .map(|(event, value)| {
+            (
+                event.clone(),
+                CustomMetric {
+                    unit: Some("count".to_string()),
+                    summary: f64_summary(&[*value as f64]),
+                },
+            )
+        })
+        .collect();
+
+    let stats = Stats {
+        wall_ms: U64Summary::new(0, 0, 0),
+        cpu_ms: None,
+        page_faults: None,
+        ctx_switches: None,
+        max_rss_kb: None,
+        io_read_bytes: None,
+        io_write_bytes: None,
+        network_packets: None,
+        energy_uj: None,
+        binary_bytes: None,
+        throughput_per_s: None,
+        latency_p50_us: None,
+        latency_p95_us: None,
+        latency_p99_us: None,
+        error_rate: None,
+        time_to_ready_ms: None,
+        instructions: summary(sample.instructions),
+        estimated_cycles: summary(sample.estimated_cycles),
+        l1_hits: summary(sample.l1_hits),
+        ll_hits: summary(sample.ll_hits),
+        ram_hits: summary(sample.ram_hits),
+        alloc_bytes: None,
+        allocs: None,
+        custom,
+    };
+
+    let default_name = command
+        .as_deref()
+        .and_then(|cmd| cmd.split_whitespace().next())
+        .and_then(|program| program.rsplit('/').next())
+        .filter(|program| !program.is_empty())
+        .unwrap_or("cachegrind");
+    let mut receipt = make_receipt(name.unwrap_or(default_name), vec![sample], stats);
+    receipt.bench.command = vec![match command {
+        Some(command) => format!("(cachegrind: {command})"),
+        None => "(cachegrind)".to_string(),
+    }];
+    Ok(receipt)
+}
+
+type EventCounts = BTreeMap<String, u64>;
+
+/// Parse a raw `cachegrind.out` file; `None` when the input is not one.
+fn parse_out_file(input: &str) -> anyhow::Result<Option<(Option<String>, EventCounts)>> {
+    let mut command = None;
+    let mut events: Option<Vec<&str>> = None;
+    let mut summary = None;
+    for line in input.lines() {
+        if let Some(cmd) = line.strip_prefix("cmd:") {
+            command = Some(cmd.trim().to_string());
+        } else if let Some(names) = line.strip_prefix("events:") {
+            events = Some(names.split_whitespace().collect());
+        } else if let Some(values) = line
+            .strip_prefix("summary:")
+            .or_else(|| line.strip_prefix("totals:"))
+        {
+            summary = Some(values);
+        }
+    }
+
+    let Some(events) = events else {
+        return Ok(None);
+    };
+    let Some(summary) = summary else {
+        bail!("cachegrind output has an events: line but no summary: line");
+    };
+
+    let mut counts = EventCounts::new();
+    let mut values = summary.split_whitespace();
+    for event in events {
+        // Trailing zero counts may be omitted.
+        let value = match values.next() {
+            Some(raw) => raw
+                .parse()
+                .with_context(|| format!("invalid cachegrind count {raw:?} for {event}"))?,
+            None => 0,
+        };
+        counts.insert(event.to_string(), value);
+    }
+    Ok(Some((command, counts)))
+}
+
+/// Parse the `PROGRAM TOTALS` row of `cg_annotate` output.
+fn parse_annotate(input: &str) -> anyhow::Result<(Option<String>, EventCounts)> {
+    let lines: Vec<&str> = input.lines().collect();
+    let Some(totals) = lines
+        .iter()
+        .position(|line| line.trim_end().ends_with("PROGRAM TOTALS"))
+    else {
+        bail!(
+            "no cachegrind summary found; expected a cachegrind.out file or cg_annotate output with PROGRAM TOTALS"
+        );
+    };
+
+    let command = lines
+        .iter()
+        .find_map(|line| line.strip_prefix("Command:"))
+        .map(|cmd| cmd.trim().to_string());
+    let header = lines[..totals]
+        .iter()
+        .rev()
+        .map(|line| line.trim())
+        .find(|line| !line.is_empty() && !line.starts_with("--"))
+        .context("cg_annotate output has no event header above PROGRAM TOTALS")?;
+    let events: Vec<&str> = header
+        .split_whitespace()
+        .map(|event| event.trim_end_matches('_'))
+        .collect();
+
+    let values: Vec<u64> = lines[totals]
+        .trim_end()
+        .trim_end_matches("PROGRAM TOTALS")
+        .split_whitespace()
+        // cg_annotate 3.22+ prints a "(100.0%)" share after each count.
+        .filter(|token| !token.starts_with('('))
+        .map(|token| {
+            token
+                .replace(',', "")
+                .parse::<u64>()
+                .with_context(|| format!("invalid cg_annotate count {token:?}"))
+        })
+        .collect::<anyhow::Result<_>>()?;
+    if values.len() != events.len() {
+        bail!(
+            "cg_annotate PROGRAM TOTALS has {} counts for {} events",
+            values.len(),
+            events.len()
+        );
+    }
+
+    let counts = events.into_iter().map(str::to_string).zip(values).collect();
+    Ok((command, counts))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OUT_FILE: &str = "\
+desc: I1 cache:         32768 B, 64 B, 8-way associative
+desc: D1 cache:         32768 B, 64 B, 8-way associative
+desc: LL cache:         8388608 B, 64 B, 16-way associative
+cmd: ./target/release/sort --size 1000
+events: Ir I1mr ILmr Dr D1mr DLmr Dw D1mw DLmw Bc Bcm
+fl=src/main.rs
+fn=main
+1 100 1 1 40 2 1 20 1 0 10 1
+summary: 10000 100 50 4000 200 20 2000 100 10 900 45
+";
+
+    #[test]
+    fn parses_cachegrind_out_file_with_cache_sim() {
+        let receipt = parse_cachegrind(OUT_FILE, None).unwrap();
+
+        assert_eq!(receipt.bench.name, "sort");
+        assert_eq!(
+            receipt.bench.command,
+            vec!["(cachegrind: ./target/release/sort --size 1000)"]
+        );
+        let sample = &receipt.samples[0];
+        assert_eq!(sample.wall_ms, 0);
+        assert_eq!(sample.instructions, Some(10_000));
+        // 16000 accesses, 400 L1 misses, 80 LL misses.
+        assert_eq!(sample.l1_hits, Some(15_600));
+        assert_eq!(sample.ll_hits, Some(320));
+        assert_eq!(sample.ram_hits, Some(80));
+        assert_eq!(sample.estimated_cycles, Some(15_600 + 5 * 320 + 35 * 80));
+        assert_eq!(receipt.stats.instructions.unwrap().median, 10_000);
+        assert_eq!(receipt.stats.custom["Bcm"].summary.median, 45.0);
+        assert!(!receipt.stats.custom.contains_key("Ir"));
+    }
+
+    #[test]
+    fn instruction_only_profile_has_no_cache_metrics() {
+        let input = "cmd: ./bench\nevents: Ir\nsummary: 123456\n";
+        let receipt = parse_cachegrind(input, Some("parse")).unwrap();
+
+        assert_eq!(receipt.bench.name, "parse");
+        assert_eq!(receipt.samples[0].instructions, Some(123_456));
+        assert_eq!(receipt.samples[0].estimated_cycles, None);
+        assert!(receipt.stats.l1_hits.is_none());
+        assert!(receipt.stats.custom.is_empty());
+    }
+
+    #[test]
+    fn parses_cg_annotate_totals() {
+        let input = "\
+--------------------------------------------------------------------------------
+-- Metadata
+--------------------------------------------------------------------------------
+Invocation:       /usr/bin/cg_annotate cachegrind.out.123
+Command:          ./bench --quick
+Events recorded:  Ir Bc Bcm
+
+--------------------------------------------------------------------------------
+-- Summary
+--------------------------------------------------------------------------------
+Ir_______________ Bc______________ Bcm_________
+
+8,195,056 (100.0%) 1,024,000 (100.0%) 3,210 (100.0%)  PROGRAM TOTALS
+";
+        let receipt = parse_cachegrind(input, None).unwrap();
+
+        assert_eq!(receipt.bench.name, "bench");
+        assert_eq!(receipt.samples[0].instructions, Some(8_195_056));
+        assert_eq!(receipt.stats.custom["Bc"].summary.median, 1_024_000.0);
+        assert_eq!(receipt.stats.custom["Bcm"].summary.median, 3_210.0);
+    }
+
+    #[test]
+    fn rejects_unrelated_input() {
+        let err = parse_cachegrind("hello", None).unwrap_err();
+        assert!(
+            err.to_string().contains("no cachegrind summary"),
+            "got: {err}"
+        );
+    }
+}
@@ -96,6 +96,44 @@ pub fn parse_criterion(input: &str, name: Option<&str>) -> anyhow::Result<RunRec
     }
 }
 
+/// Parse Criterion output into one `RunReceipt` per benchmark.
+///
+/// cargo-criterion JSONL yields a receipt per `benchmark-complete` message and
+/// `raw.csv` a receipt per group/function/value identity, each named after the
+/// benchmark id. `new/estimates.json` describes a single benchmark.
+pub fn parse_criterion_all(input: &str) -> anyhow::Result<Vec<RunReceipt>> {
+    let trimmed = input.trim();
+    if trimmed.is_empty() {
+        bail!("Criterion input is empty");
+    }
+
+    if looks_like_raw_csv(trimmed) {
+        return Ok(raw_csv_samples(trimmed)?
+            .into_iter()
+            .map(samples_to_receipt)
+            .collect());
+    }
+
+    match serde_json::from_str::<Value>(trimmed) {
+        Ok(value) => Ok(vec![criterion_json_value_to_receipt(value, None)?]),
+        Err(_parse_error) if looks_like_jsonl(trimmed) => {
+            let messages = cargo_criterion_jsonl_messages(trimmed)?;
+            if messages.is_empty() {
+                bail!("cargo-criterion JSONL contains no benchmark-complete message");
+            }
+            messages
+                .into_iter()
+                .map(|message| cargo_criterion_message_to_receipt(message, None))
+                .collect()
+        }
+        Err(parse_error) => {
+            bail!(
+                "failed to parse Criterion output; expected cargo-criterion JSON/JSONL benchmark-complete output, Criterion raw.csv, or Criterion new/estimates.json: {parse_error}"
+            )
+        }
+    }
+}
+
 fn criterion_json_value_to_receipt(value: Value, name: Option<&str>) -> anyhow::Result<RunReceipt> {
     if value.get("reason").and_then(Value::as_str) == Some("benchmark-complete") {
         let message: CargoCriterionMessage = serde_json::from_value(value)
@@ -110,6 +148,14 @@ fn criterion_json_value_to_receipt(value: Value, name: Option<&str>) -> anyhow::
 }
 
 fn cargo_criterion_jsonl_to_receipt(input: &str, name: Option<&str>) -> anyhow::Result<RunReceipt> {
+    match cargo_criterion_jsonl_messages(input)?.into_iter().next() {
+        Some(message) => cargo_criterion_message_to_receipt(message, name),
+        None => bail!("cargo-criterion JSONL contains no benchmark-complete message"),
+    }
+}
+
+fn cargo_criterion_jsonl_messages(input: &str) -> anyhow::Result<Vec<CargoCriterionMessage>> {
+    let mut messages = Vec::new();
     for (index, line) in input.lines().enumerate() {
         let line = line.trim();
         if line.is_empty() {
@@ -120,11 +166,10 @@ fn cargo_criterion_jsonl_to_receipt(input: &str, name: Option<&str>) -> anyhow::
         if value.get("reason").and_then(Value::as_str) == Some("benchmark-complete") {
             let message: CargoCriterionMessage = serde_json::from_value(value)
                 .context("failed to parse cargo-criterion benchmark-complete message")?;
-            return cargo_criterion_message_to_receipt(message, name);
+            messages.push(message);
         }
     }
-
-    bail!("cargo-criterion JSONL contains no benchmark-complete message")
+    Ok(messages)
 }
 
 fn cargo_criterion_message_to_receipt(
@@ -187,14 +232,29 @@ fn cargo_criterion_message_to_receipt(
 }
 
 fn raw_csv_to_receipt(input: &str, name: Option<&str>) -> anyhow::Result<RunReceipt> {
+    let mut groups = raw_csv_samples(input)?;
+    if groups.len() > 1 {
+        bail!(
+            "Criterion raw.csv contains multiple benchmark identities ('{}' and '{}'); import one benchmark file at a time",
+            groups[0].name,
+            groups[1].name
+        );
+    }
+    let mut samples = groups.remove(0);
+    if let Some(name) = name {
+        samples.name = name.to_string();
+    }
+    Ok(samples_to_receipt(samples))
+}
+
+/// Parse `raw.csv` rows into one `CriterionSamples` per benchmark identity, in
+/// the order the identities first appear.
+fn raw_csv_samples(input: &str) -> anyhow::Result<Vec<CriterionSamples>> {
     let mut lines = input.lines().filter(|line| !line.trim().is_empty());
     let header = lines.next().context("Criterion raw.csv is empty")?;
     validate_raw_csv_header(header)?;
 
-    let mut identity: Option<String> = None;
-    let mut wall_values = Vec::new();
-    let mut samples = Vec::new();
-    let mut work_units: Option<u64> = None;
+    let mut groups: Vec<(CriterionSamples, Vec<u64>)> = Vec::new();
 
     for (line_index, line) in lines.enumerate() {
         let fields: Vec<&str> = line.split(',').map(str::trim).collect();
@@ -206,27 +266,32 @@ fn raw_csv_to_receipt(input: &str, name: Option<&str>) -> anyhow::Result<RunRece
             );
         }
 
-        let row_identity = raw_csv_identity(fields[0], fields[1], fields[2]);
-        if let Some(existing) = &identity {
-            if existing != &row_identity {
-                bail!(
-                    "Criterion raw.csv contains multiple benchmark identities ('{}' and '{}'); import one benchmark file at a time",
-                    existing,
-                    row_identity
-                );
+        let identity = raw_csv_identity(fields[0], fields[1], fields[2]);
+        let index = match groups.iter().position(|(group, _)| group.name == identity) {
+            Some(index) => index,
+            None => {
+                groups.push((
+                    CriterionSamples {
+                        name: identity,
+                        samples: Vec::new(),
+                        stats: compute_u64_summary(&[]),
+                        work_units: None,
+                    },
+                    Vec::new(),
+                ));
+                groups.len() - 1
             }
-        } else {
-            identity = Some(row_identity);
-        }
+        };
+        let (group, wall_values) = &mut groups[index];
 
-        if work_units.is_none() && !fields[3].is_empty() {
+        if group.work_units.is_none() && !fields[3].is_empty() {
             let parsed = fields[3].parse::<u64>().with_context(|| {
                 format!(
                     "Criterion raw.csv line {} throughput_num is not an integer",
                     line_index + 2
                 )
             })?;
-            work_units = Some(parsed);
+            group.work_units = Some(parsed);
         }
 
         let measured = fields[5].parse::<f64>().with_context(|| {
@@ -252,23 +317,20 @@ fn raw_csv_to_receipt(input: &str, name: Option<&str>) -> anyhow::Result<RunRece
         let per_iteration = measured / iterations as f64;
         let wall_ms = measurement_to_wall_ms(per_iteration, unit, "sample_measured_value")?;
         wall_values.push(wall_ms);
-        samples.push(sample(wall_ms));
+        group.samples.push(sample(wall_ms));
     }
 
-    if samples.is_empty() {
+    if groups.is_empty() {
         bail!("Criterion raw.csv contains no sample rows");
     }
 
-    let samples = CriterionSamples {
-        name: name
-            .map(str::to_string)
-            .or(identity)
-            .unwrap_or_else(|| "criterion-bench".to_string()),
-        stats: compute_u64_summary(&wall_values),
-        samples,
-        work_units,
-    };
-    Ok(samples_to_receipt(samples))
+    Ok(groups
+        .into_iter()
+        .map(|(mut group, wall_values)| {
+            group.stats = compute_u64_summary(&wall_values);
+            group
+        })
+        .collect())
 }
 
 fn estimates_to_receipt(
@@ -320,6 +382,10 @@ fn samples_to_receipt(input: CriterionSamples) -> RunReceipt {
                 memory_bytes: None,
                 hostname_hash: None,
             },
+            profile: None,
+            concurrency: None,
+            interleave: None,
+            noise_warnings: Vec::new(),
         },
         bench: BenchMeta {
             name: input.name,
@@ -329,6 +395,8 @@ fn samples_to_receipt(input: CriterionSamples) -> RunReceipt {
             warmup: 0,
             work_units: input.work_units,
             timeout_ms: None,
+            service: None,
+            load: None,
         },
         samples: input.samples,
         stats: Stats {
@@ -343,6 +411,19 @@ fn samples_to_receipt(input: CriterionSamples) -> RunReceipt {
             energy_uj: None,
             binary_bytes: None,
             throughput_per_s: None,
+            latency_p50_us: None,
+            latency_p95_us: None,
+            latency_p99_us: None,
+            error_rate: None,
+            time_to_ready_ms: None,
+            instructions: None,
+            estimated_cycles: None,
+            l1_hits: None,
+            ll_hits: None,
+            ram_hits: None,
+            alloc_bytes: None,
+            allocs: None,
+            custom: Default::default(),
         },
     }
 }
@@ -476,6 +557,19 @@ fn sample(wall_ms: u64) -> Sample {
         binary_bytes: None,
         stdout: None,
         stderr: None,
+        latency_p50_us: None,
+        latency_p95_us: None,
+        latency_p99_us: None,
+        error_rate: None,
+        throughput_per_s: None,
+        time_to_ready_ms: None,
+        instructions: None,
+        estimated_cycles: None,
+        l1_hits: None,
+        ll_hits: None,
+        ram_hits: None,
+        alloc_bytes: None,
+        allocs: None,
     }
 }
 
@@ -625,6 +719,34 @@ Parser,small,,,elements,100000000,ns,20
         assert!(err.to_string().contains("multiple benchmark identities"));
     }
 
+    #[test]
+    fn parse_criterion_all_splits_raw_csv_and_jsonl_by_benchmark() {
+        let input = r#"group,function,value,throughput_num,throughput_type,sample_measured_value,unit,iteration_count
+Parser,large,,,elements,50000000,ns,10
+Parser,small,,,elements,10000000,ns,10
+Parser,large,,,elements,60000000,ns,10
+"#;
+        let receipts = parse_criterion_all(input).unwrap();
+        assert_eq!(receipts.len(), 2);
+        assert_eq!(receipts[0].bench.name, "Parser/large");
+        assert_eq!(receipts[0].samples.len(), 2);
+        assert_eq!(receipts[1].bench.name, "Parser/small");
+        assert_eq!(receipts[1].stats.wall_ms.median, 1);
+
+        let second = CARGO_CRITERION_JSONL
+            .lines()
+            .nth(1)
+            .expect("benchmark-complete line")
+            .replace("parser/large", "parser/small");
+        let jsonl = format!("{}\n{second}\n", CARGO_CRITERION_JSONL.trim_end());
+        let names: Vec<String> = parse_criterion_all(&jsonl)
+            .unwrap()
+            .into_iter()
+            .map(|receipt| receipt.bench.name)
+            .collect();
+        assert_eq!(names, ["parser/large", "parser/small"]);
+    }
+
     #[test]
     fn parse_criterion_rejects_jsonl_without_benchmark_complete() {
         let input = r#"{"reason":"warmup","id":"parser"}
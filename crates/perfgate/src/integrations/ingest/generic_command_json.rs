@@ -2,8 +2,8 @@
 //!
 //! This adapter is intentionally conservative. It accepts JSON that already
 //! names perfgate metrics, units, directions, and samples or summaries. It does
-//! not perform arbitrary field mapping; that belongs to the later custom
-//! JSON/CSV mapping slice.
+//! not perform arbitrary field mapping; output in other shapes goes through
+//! the mapping-driven adapter in `mapped.rs`.
 
 use std::collections::BTreeMap;
 
@@ -148,12 +148,25 @@ pub fn parse_generic_command_json(input: &str, name: Option<&str>) -> anyhow::Re
         energy_uj: None,
         binary_bytes: None,
         throughput_per_s: None,
+        latency_p50_us: None,
+        latency_p95_us: None,
+        latency_p99_us: None,
+        error_rate: None,
+        time_to_ready_ms: None,
+        instructions: None,
+        estimated_cycles: None,
+        l1_hits: None,
+        ll_hits: None,
+        ram_hits: None,
+        alloc_bytes: None,
+        allocs: None,
+        custom: Default::default(),
     };
 
     for (metric_name, metric_input) in &input.metrics {
         let Some(metric) = Metric::parse_key(metric_name) else {
             bail!(
-                "unsupported metric '{}' in generic command JSON; use a known perfgate metric or wait for custom JSON/CSV mapping",
+                "unsupported metric '{}' in generic command JSON; use a known perfgate metric or --format mapped",
                 metric_name
             );
         };
@@ -168,6 +181,11 @@ pub fn parse_generic_command_json(input: &str, name: Option<&str>) -> anyhow::Re
             stats.throughput_per_s = Some(f64_summary_from_metric(metric_input, &values)?);
             continue;
         }
+        if metric == Metric::ErrorRate {
+            let values = metric_sample_values(metric, metric_input)?;
+            stats.error_rate = Some(f64_summary_from_metric(metric_input, &values)?);
+            continue;
+        }
 
         let values = metric_sample_values(metric, metric_input)?;
         let summary = u64_summary_from_metric(metric, metric_input, &values)?;
@@ -195,6 +213,10 @@ pub fn parse_generic_command_json(input: &str, name: Option<&str>) -> anyhow::Re
             started_at: timestamp.clone(),
             ended_at: timestamp,
             host: host_info(input.host),
+            profile: None,
+            concurrency: None,
+            interleave: None,
+            noise_warnings: Vec::new(),
         },
         bench: BenchMeta {
             name: bench_name,
@@ -206,6 +228,8 @@ pub fn parse_generic_command_json(input: &str, name: Option<&str>) -> anyhow::Re
             warmup: bench.and_then(|bench| bench.warmup).unwrap_or(0),
             work_units: bench.and_then(|bench| bench.work_units),
             timeout_ms: bench.and_then(|bench| bench.timeout_ms),
+            service: None,
+            load: None,
         },
         samples,
         stats,
@@ -267,7 +291,7 @@ fn validate_metric_mapping(metric: Metric, input: &GenericMetric) -> anyhow::Res
 fn validate_unit(metric: Metric, unit: &str) -> anyhow::Result<()> {
     let normalized = normalize_label(unit);
     let valid = match metric {
-        Metric::WallMs | Metric::CpuMs => matches!(
+        Metric::WallMs | Metric::CpuMs | Metric::TimeToReadyMs => matches!(
             normalized.as_str(),
             "ms" | "millisecond" | "milliseconds" | "s" | "sec" | "second" | "seconds"
         ),
@@ -275,17 +299,35 @@ fn validate_unit(metric: Metric, unit: &str) -> anyhow::Result<()> {
             normalized.as_str(),
             "kb" | "kib" | "kilobyte" | "kilobytes" | "bytes" | "byte" | "b"
         ),
-        Metric::IoReadBytes | Metric::IoWriteBytes | Metric::BinaryBytes => {
+        Metric::IoReadBytes | Metric::IoWriteBytes | Metric::BinaryBytes | Metric::AllocBytes => {
             matches!(normalized.as_str(), "bytes" | "byte" | "b")
         }
+        Metric::Allocs => matches!(normalized.as_str(), "count" | "counts" | "allocs"),
         Metric::PageFaults | Metric::CtxSwitches | Metric::NetworkPackets => matches!(
             normalized.as_str(),
             "count" | "counts" | "events" | "event" | "packets" | "packet"
         ),
+        Metric::Instructions | Metric::L1Hits | Metric::LlHits | Metric::RamHits => {
+            matches!(normalized.as_str(), "count" | "counts" | "events" | "event")
+        }
+        Metric::EstimatedCycles => matches!(normalized.as_str(), "cycles" | "cycle" | "count"),
         Metric::EnergyUj => matches!(
             normalized.as_str(),
             "uj" | "microjoule" | "microjoules" | "micro_joule" | "micro_joules"
         ),
+        Metric::LatencyP50Us | Metric::LatencyP95Us | Metric::LatencyP99Us => matches!(
+            normalized.as_str(),
+            "us" | "microsecond"
+                | "microseconds"
+                | "ms"
+                | "millisecond"
+                | "milliseconds"
+                | "s"
+                | "sec"
+                | "second"
+                | "seconds"
+        ),
+        Metric::ErrorRate => matches!(normalized.as_str(), "ratio" | "fraction"),
         Metric::ThroughputPerS => matches!(
             normalized.as_str(),
             "per_s"
@@ -367,6 +409,19 @@ fn wall_samples(input: &GenericMetric, values: &[f64]) -> anyhow::Result<Vec<Sam
                 binary_bytes: None,
                 stdout: None,
                 stderr: None,
+                latency_p50_us: None,
+                latency_p95_us: None,
+                latency_p99_us: None,
+                error_rate: None,
+                throughput_per_s: None,
+                time_to_ready_ms: None,
+                instructions: None,
+                estimated_cycles: None,
+                l1_hits: None,
+                ll_hits: None,
+                ram_hits: None,
+                alloc_bytes: None,
+                allocs: None,
             })
         })
         .collect()
@@ -477,16 +532,27 @@ fn assign_u64_summary(
     summary: U64Summary,
 ) -> anyhow::Result<()> {
     match metric {
+        Metric::AllocBytes => stats.alloc_bytes = Some(summary),
+        Metric::Allocs => stats.allocs = Some(summary),
         Metric::BinaryBytes => stats.binary_bytes = Some(summary),
         Metric::CpuMs => stats.cpu_ms = Some(summary),
         Metric::CtxSwitches => stats.ctx_switches = Some(summary),
         Metric::EnergyUj => stats.energy_uj = Some(summary),
+        Metric::EstimatedCycles => stats.estimated_cycles = Some(summary),
+        Metric::Instructions => stats.instructions = Some(summary),
         Metric::IoReadBytes => stats.io_read_bytes = Some(summary),
         Metric::IoWriteBytes => stats.io_write_bytes = Some(summary),
+        Metric::L1Hits => stats.l1_hits = Some(summary),
+        Metric::LatencyP50Us => stats.latency_p50_us = Some(summary),
+        Metric::LatencyP95Us => stats.latency_p95_us = Some(summary),
+        Metric::LatencyP99Us => stats.latency_p99_us = Some(summary),
+        Metric::LlHits => stats.ll_hits = Some(summary),
         Metric::MaxRssKb => stats.max_rss_kb = Some(summary),
         Metric::NetworkPackets => stats.network_packets = Some(summary),
         Metric::PageFaults => stats.page_faults = Some(summary),
-        Metric::WallMs | Metric::ThroughputPerS => {
+        Metric::RamHits => stats.ram_hits = Some(summary),
+        Metric::TimeToReadyMs => stats.time_to_ready_ms = Some(summary),
+        Metric::WallMs | Metric::ThroughputPerS | Metric::ErrorRate => {
             bail!(
                 "internal error: unsupported u64 summary metric {}",
                 metric.as_str()
@@ -519,16 +585,27 @@ fn apply_u64_sample_values(
     for (sample, value) in samples.iter_mut().zip(values) {
         let value = f64_to_u64(*value, metric.as_str())?;
         match metric {
+            Metric::AllocBytes => sample.alloc_bytes = Some(value),
+            Metric::Allocs => sample.allocs = Some(value),
             Metric::BinaryBytes => sample.binary_bytes = Some(value),
             Metric::CpuMs => sample.cpu_ms = Some(value),
             Metric::CtxSwitches => sample.ctx_switches = Some(value),
             Metric::EnergyUj => sample.energy_uj = Some(value),
+            Metric::EstimatedCycles => sample.estimated_cycles = Some(value),
+            Metric::Instructions => sample.instructions = Some(value),
             Metric::IoReadBytes => sample.io_read_bytes = Some(value),
             Metric::IoWriteBytes => sample.io_write_bytes = Some(value),
+            Metric::L1Hits => sample.l1_hits = Some(value),
+            Metric::LatencyP50Us => sample.latency_p50_us = Some(value),
+            Metric::LatencyP95Us => sample.latency_p95_us = Some(value),
+            Metric::LatencyP99Us => sample.latency_p99_us = Some(value),
+            Metric::LlHits => sample.ll_hits = Some(value),
             Metric::MaxRssKb => sample.max_rss_kb = Some(value),
             Metric::NetworkPackets => sample.network_packets = Some(value),
             Metric::PageFaults => sample.page_faults = Some(value),
-            Metric::WallMs | Metric::ThroughputPerS => {}
+            Metric::RamHits => sample.ram_hits = Some(value),
+            Metric::TimeToReadyMs => sample.time_to_ready_ms = Some(value),
+            Metric::WallMs | Metric::ThroughputPerS | Metric::ErrorRate => {}
         }
     }
 
@@ -547,7 +624,7 @@ fn normalize_metric_value(metric: Metric, unit: Option<&str>, value: f64) -> any
     let normalized = normalize_label(unit);
 
     let value = match metric {
-        Metric::WallMs | Metric::CpuMs => match normalized.as_str() {
+        Metric::WallMs | Metric::CpuMs | Metric::TimeToReadyMs => match normalized.as_str() {
             "s" | "sec" | "second" | "seconds" => value * 1000.0,
             _ => value,
         },
@@ -555,6 +632,13 @@ fn normalize_metric_value(metric: Metric, unit: Option<&str>, value: f64) -> any
             "bytes" | "byte" | "b" => value / 1024.0,
             _ => value,
         },
+        Metric::LatencyP50Us | Metric::LatencyP95Us | Metric::LatencyP99Us => {
+            match normalized.as_str() {
+                "ms" | "millisecond" | "milliseconds" => value * 1000.0,
+                "s" | "sec" | "second" | "seconds" => value * 1_000_000.0,
+                _ => value,
+            }
+        }
         _ => value,
     };
 
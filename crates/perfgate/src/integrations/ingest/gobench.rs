@@ -2,165 +2,339 @@
 //!
 //! Parses text lines produced by `go test -bench . -benchmem`, e.g.:
 //! ```text
-//! BenchmarkFoo-8    1000    1234 ns/op    567 B/op    3 allocs/op
+//! BenchmarkFoo-8    1000    1234 ns/op    25.93 MB/s    567 B/op    3 allocs/op
 //! ```
 //!
-//! Maps `ns/op` to `wall_ms` and `B/op` to `max_rss_kb` (as a proxy for
-//! memory usage per operation).
+//! Each line is one sample, so `-count N` repetitions of a benchmark become
+//! N samples. Values are mapped as follows:
+//!
+//! - `ns/op` -> `wall_ms`
+//! - `MB/s` -> `throughput_per_s`, in bytes per second
+//! - `B/op` -> `alloc_bytes`, `allocs/op` -> `allocs`
+//! - any other unit (from `b.ReportMetric`) -> `stats.custom`, keyed by unit
+//!
+//! The trailing `-N` GOMAXPROCS suffix is not part of the bench name, so
+//! results stay comparable across machines with different core counts. A
+//! benchmark run with several `-cpu` values gets one bench per value, named
+//! with a `/cpu=N` segment.
+
+use std::collections::{BTreeMap, BTreeSet};
 
-use anyhow::Context;
-use perfgate_types::{RunReceipt, Sample, Stats, U64Summary};
-use regex::Regex;
+use anyhow::{Context, bail};
+use perfgate_types::{CustomMetric, F64Summary, RunReceipt, Sample, Stats};
 
-use super::make_receipt;
+use super::{f64_summary, make_receipt, to_u64, to_u64_summary};
 
-/// A parsed Go benchmark line.
+/// A parsed Go benchmark result line.
 #[derive(Debug)]
 struct GoBenchLine {
+    /// Benchmark name without the GOMAXPROCS suffix.
     name: String,
+    /// GOMAXPROCS the line ran with (`None` when Go printed no suffix, i.e. 1).
+    procs: Option<u32>,
     iterations: u64,
     ns_per_op: f64,
-    bytes_per_op: Option<u64>,
-    allocs_per_op: Option<u64>,
+    mb_per_s: Option<f64>,
+    bytes_per_op: Option<f64>,
+    allocs_per_op: Option<f64>,
+    /// `b.ReportMetric` values, keyed by unit.
+    custom: Vec<(String, f64)>,
+}
+
+/// All result lines of one benchmark at one GOMAXPROCS setting.
+#[derive(Debug)]
+struct GoBench<'a> {
+    name: String,
+    procs: Option<u32>,
+    lines: Vec<&'a GoBenchLine>,
 }
 
 /// Parse Go benchmark text output into a `RunReceipt`.
 ///
 /// The parser recognizes lines matching the standard Go benchmark output format:
-/// `BenchmarkName-N  iterations  value ns/op  [value B/op  value allocs/op]`
+/// `BenchmarkName-N  iterations  value ns/op  [value unit]...`
 ///
-/// If multiple benchmark functions are present, only the first is used.
-/// Use `name` to override the benchmark name.
+/// If multiple benchmark functions are present, only the first is used;
+/// [`parse_gobench_all`] keeps every function. Use `name` to override the
+/// benchmark name.
 pub fn parse_gobench(input: &str, name: Option<&str>) -> anyhow::Result<RunReceipt> {
     let lines = parse_gobench_lines(input)?;
+    let benches = group_lines(&lines);
 
-    let first = lines
+    let first = benches
         .first()
         .context("no benchmark results found in Go bench output")?;
+    Ok(bench_to_receipt(first, name))
+}
 
-    let bench_name = name
-        .map(|n| n.to_string())
-        .unwrap_or_else(|| first.name.clone());
-
-    // Convert ns/op to milliseconds.
-    let wall_ms = ns_to_ms(first.ns_per_op);
-
-    // Since Go bench gives us a single aggregated result (not individual samples),
-    // we create a single sample. The `iterations` field tells us how many runs
-    // the Go testing framework performed internally.
-    let sample = Sample {
-        wall_ms,
-        exit_code: 0,
-        warmup: false,
-        timed_out: false,
-        cpu_ms: None,
-        page_faults: None,
-        ctx_switches: None,
-        max_rss_kb: None,
-        io_read_bytes: None,
-        io_write_bytes: None,
-        network_packets: None,
-        energy_uj: None,
-        binary_bytes: None,
-        stdout: None,
-        stderr: None,
-    };
+/// Parse Go benchmark text output into one `RunReceipt` per benchmark
+/// function, each named after the function (e.g. `BenchmarkFoo`).
+///
+/// Repeated lines of a function (`-count`) become its samples.
+pub fn parse_gobench_all(input: &str) -> anyhow::Result<Vec<RunReceipt>> {
+    let lines = parse_gobench_lines(input)?;
+    let benches = group_lines(&lines);
+    if benches.is_empty() {
+        bail!("no benchmark results found in Go bench output");
+    }
 
-    let wall_stats = U64Summary {
-        median: wall_ms,
-        min: wall_ms,
-        max: wall_ms,
-        // IMPORTANT: Use f64 division here, NOT ns_to_ms(). See the GOTCHA
-        // on ns_to_ms — integer truncation would lose sub-ms precision that
-        // budget evaluation and significance testing rely on.
-        mean: Some(first.ns_per_op / 1_000_000.0),
-        stddev: None,
+    Ok(benches
+        .iter()
+        .map(|bench| bench_to_receipt(bench, None))
+        .collect())
+}
+
+/// Group lines by benchmark and GOMAXPROCS, in order of first appearance.
+fn group_lines(lines: &[GoBenchLine]) -> Vec<GoBench<'_>> {
+    let mut benches: Vec<GoBench<'_>> = Vec::new();
+    for line in lines {
+        match benches
+            .iter_mut()
+            .find(|bench| bench.name == line.name && bench.procs == line.procs)
+        {
+            Some(bench) => bench.lines.push(line),
+            None => benches.push(GoBench {
+                name: line.name.clone(),
+                procs: line.procs,
+                lines: vec![line],
+            }),
+        }
+    }
+
+    let mut procs_by_name: BTreeMap<&str, BTreeSet<Option<u32>>> = BTreeMap::new();
+    for line in lines {
+        procs_by_name
+            .entry(line.name.as_str())
+            .or_default()
+            .insert(line.procs);
+    }
+    let multi_cpu: BTreeSet<String> = procs_by_name
+        .into_iter()
+        .filter(|(_, procs)| procs.len() > 1)
+        .map(|(name, _)| name.to_string())
+        .collect();
+
+    for bench in &mut benches {
+        if multi_cpu.contains(&bench.name) {
+            bench.name = format!("{}/cpu={}", bench.name, bench.procs.unwrap_or(1));
+        }
+    }
+    benches
+}
+
+fn bench_to_receipt(bench: &GoBench<'_>, name: Option<&str>) -> RunReceipt {
+    let bench_name = name.unwrap_or(&bench.name);
+
+    let samples: Vec<Sample> = bench
+        .lines
+        .iter()
+        .map(|line| Sample {
+            wall_ms: ns_to_ms(line.ns_per_op),
+            exit_code: 0,
+            warmup: false,
+            timed_out: false,
+            cpu_ms: None,
+            page_faults: None,
+            ctx_switches: None,
+            max_rss_kb: None,
+            io_read_bytes: None,
+            io_write_bytes: None,
+            network_packets: None,
+            energy_uj: None,
+            binary_bytes: None,
+            stdout: None,
+            stderr: None,
+            latency_p50_us: None,
+            latency_p95_us: None,
+            latency_p99_us: None,
+            error_rate: None,
+            throughput_per_s: line.mb_per_s.map(|mb| mb * 1e6),
+            time_to_ready_ms: None,
+            instructions: None,
+            estimated_cycles: None,
+            l1_hits: None,
+            ll_hits: None,
+            ram_hits: None,
+            alloc_bytes: line.bytes_per_op.map(to_u64),
+            allocs: line.allocs_per_op.map(to_u64),
+        })
+        .collect();
+
+    // IMPORTANT: summarize the f64 values, NOT the rounded sample wall_ms.
+    // See the GOTCHA on ns_to_ms — integer truncation would lose sub-ms
+    // precision that budget evaluation and significance testing rely on.
+    let wall_ms: Vec<f64> = bench.lines.iter().map(|l| l.ns_per_op / 1e6).collect();
+    let summarize = |value: fn(&GoBenchLine) -> Option<f64>| -> Option<F64Summary> {
+        let values: Vec<f64> = bench.lines.iter().filter_map(|l| value(l)).collect();
+        (!values.is_empty()).then(|| f64_summary(&values))
     };
 
-    // Map B/op to max_rss_kb if available (B -> KB).
-    let max_rss_kb = first.bytes_per_op.map(|b| {
-        let kb = b.div_ceil(1024);
-        U64Summary {
-            median: kb,
-            min: kb,
-            max: kb,
-            mean: Some(b as f64 / 1024.0),
-            stddev: None,
+    let mut units: Vec<&str> = Vec::new();
+    for (unit, _) in bench.lines.iter().flat_map(|line| &line.custom) {
+        if !units.contains(&unit.as_str()) {
+            units.push(unit);
         }
-    });
+    }
+    let custom = units
+        .into_iter()
+        .map(|unit| {
+            let values: Vec<f64> = bench
+                .lines
+                .iter()
+                .flat_map(|line| &line.custom)
+                .filter(|(u, _)| u == unit)
+                .map(|(_, value)| *value)
+                .collect();
+            (
+                unit.to_string(),
+                CustomMetric {
+                    unit: Some(unit.to_string()),
+                    summary: f64_summary(&values),
+                },
+            )
+        })
+        .collect();
 
     let stats = Stats {
-        wall_ms: wall_stats,
+        wall_ms: to_u64_summary(&f64_summary(&wall_ms)),
         cpu_ms: None,
         page_faults: None,
         ctx_switches: None,
-        max_rss_kb,
+        max_rss_kb: None,
         io_read_bytes: None,
         io_write_bytes: None,
         network_packets: None,
         energy_uj: None,
         binary_bytes: None,
-        throughput_per_s: None,
+        throughput_per_s: summarize(|l| l.mb_per_s.map(|mb| mb * 1e6)),
+        latency_p50_us: None,
+        latency_p95_us: None,
+        latency_p99_us: None,
+        error_rate: None,
+        time_to_ready_ms: None,
+        instructions: None,
+        estimated_cycles: None,
+        l1_hits: None,
+        ll_hits: None,
+        ram_hits: None,
+        alloc_bytes: summarize(|l| l.bytes_per_op).as_ref().map(to_u64_summary),
+        allocs: summarize(|l| l.allocs_per_op).as_ref().map(to_u64_summary),
+        custom,
     };
 
-    let mut receipt = make_receipt(&bench_name, vec![sample], stats);
-
-    // Store allocs/op info in the bench command metadata for reference.
-    if let Some(allocs) = first.allocs_per_op {
-        receipt.bench.command = vec![
-            format!(
-                "(go bench: {} iterations, {} ns/op",
-                first.iterations, first.ns_per_op
-            ),
-            format!("{} allocs/op)", allocs),
-        ];
-    } else {
-        receipt.bench.command = vec![format!(
-            "(go bench: {} iterations, {} ns/op)",
-            first.iterations, first.ns_per_op
-        )];
+    let mut receipt = make_receipt(bench_name, samples, stats);
+
+    let iterations: u64 = bench.lines.iter().map(|line| line.iterations).sum();
+    let mut command = format!(
+        "(go bench: {} run(s), {} iterations",
+        bench.lines.len(),
+        iterations
+    );
+    if let Some(procs) = bench.procs {
+        command.push_str(&format!(", GOMAXPROCS={procs}"));
     }
+    command.push(')');
+    receipt.bench.command = vec![command];
 
-    Ok(receipt)
+    receipt
 }
 
-/// Parse all Go benchmark lines and return them. Supports multiple benchmarks
-/// being parsed, though only the first is currently used for the receipt.
+/// Parse all Go benchmark result lines and return them in input order.
+///
+/// When a benchmark logs output, Go prints its name on a line of its own
+/// and the results on a later, indented line; both layouts are accepted.
 fn parse_gobench_lines(input: &str) -> anyhow::Result<Vec<GoBenchLine>> {
-    // Match lines like: BenchmarkXxx-8    1000    1234 ns/op
-    // Optional: 567 B/op, 3 allocs/op
-    let re = Regex::new(
-        r"(?m)^(Benchmark\S+)\s+(\d+)\s+([\d.]+)\s+ns/op(?:\s+(\d+)\s+B/op)?(?:\s+(\d+)\s+allocs/op)?",
-    )?;
-
     let mut lines = Vec::new();
-    for cap in re.captures_iter(input) {
-        let name = cap[1].to_string();
-        let iterations: u64 = cap[2].parse().context("invalid iteration count")?;
-        let ns_per_op: f64 = cap[3].parse().context("invalid ns/op value")?;
-        let bytes_per_op = cap
-            .get(4)
-            .map(|m| m.as_str().parse::<u64>())
-            .transpose()
-            .context("invalid B/op value")?;
-        let allocs_per_op = cap
-            .get(5)
-            .map(|m| m.as_str().parse::<u64>())
-            .transpose()
-            .context("invalid allocs/op value")?;
-
-        lines.push(GoBenchLine {
-            name,
-            iterations,
-            ns_per_op,
-            bytes_per_op,
-            allocs_per_op,
-        });
+    let mut pending_name: Option<&str> = None;
+
+    for raw in input.lines() {
+        let mut tokens = raw.split_whitespace();
+        let (full_name, rest): (&str, Vec<&str>) = if raw.starts_with("Benchmark") {
+            let Some(name) = tokens.next() else {
+                continue;
+            };
+            let rest: Vec<&str> = tokens.collect();
+            if rest.is_empty() {
+                pending_name = Some(name);
+                continue;
+            }
+            (name, rest)
+        } else if let Some(name) = pending_name {
+            let rest: Vec<&str> = tokens.collect();
+            match rest.first() {
+                Some(first) if first.parse::<u64>().is_ok() => (name, rest),
+                _ => continue,
+            }
+        } else {
+            continue;
+        };
+        pending_name = None;
+
+        let Some(line) = parse_result(full_name, &rest)? else {
+            continue;
+        };
+        lines.push(line);
     }
 
     Ok(lines)
 }
 
+/// Parse `iterations value unit [value unit]...` for one benchmark.
+///
+/// Returns `None` for lines that are not results (e.g. `--- FAIL` output).
+fn parse_result(full_name: &str, rest: &[&str]) -> anyhow::Result<Option<GoBenchLine>> {
+    let Some(iterations) = rest.first().and_then(|t| t.parse::<u64>().ok()) else {
+        return Ok(None);
+    };
+
+    let (name, procs) = split_procs(full_name);
+    let mut line = GoBenchLine {
+        name: name.to_string(),
+        procs,
+        iterations,
+        ns_per_op: f64::NAN,
+        mb_per_s: None,
+        bytes_per_op: None,
+        allocs_per_op: None,
+        custom: Vec::new(),
+    };
+
+    for pair in rest[1..].chunks(2) {
+        let [value, unit] = pair else {
+            bail!("Go bench line for {full_name} has a value without a unit");
+        };
+        let value: f64 = value
+            .parse()
+            .with_context(|| format!("invalid {unit} value '{value}' for {full_name}"))?;
+        if !value.is_finite() {
+            bail!("{unit} value for {full_name} is not finite");
+        }
+        match *unit {
+            "ns/op" => line.ns_per_op = value,
+            "MB/s" => line.mb_per_s = Some(value),
+            "B/op" => line.bytes_per_op = Some(value),
+            "allocs/op" => line.allocs_per_op = Some(value),
+            other => line.custom.push((other.to_string(), value)),
+        }
+    }
+
+    if line.ns_per_op.is_nan() {
+        return Ok(None);
+    }
+    Ok(Some(line))
+}
+
+/// Split the `-N` GOMAXPROCS suffix off a benchmark name.
+fn split_procs(full_name: &str) -> (&str, Option<u32>) {
+    if let Some((name, suffix)) = full_name.rsplit_once('-')
+        && !name.is_empty()
+        && let Ok(procs) = suffix.parse::<u32>()
+    {
+        return (name, Some(procs));
+    }
+    (full_name, None)
+}
+
 /// Integer ns-to-ms conversion for sample `wall_ms` values (u64).
 ///
 /// GOTCHA: This intentionally truncates to integer milliseconds -- it is only
@@ -192,23 +366,28 @@ mod tests {
         assert_eq!(receipt.bench.name, "foo-bench");
         assert_eq!(receipt.samples.len(), 1);
         assert_eq!(receipt.stats.wall_ms.median, 50);
-        // 567 B = 1 KB (rounded up)
-        assert!(receipt.stats.max_rss_kb.is_some());
-        assert_eq!(receipt.stats.max_rss_kb.unwrap().median, 1);
+        assert!(receipt.stats.max_rss_kb.is_none());
+        assert_eq!(receipt.stats.alloc_bytes.unwrap().median, 567);
+        assert_eq!(receipt.stats.allocs.unwrap().median, 3);
     }
 
     #[test]
     fn parse_gobench_default_name() {
         let input = "BenchmarkBar-4\t  500\t  2000000 ns/op\n";
         let receipt = parse_gobench(input, None).unwrap();
-        assert_eq!(receipt.bench.name, "BenchmarkBar-4");
+        assert_eq!(receipt.bench.name, "BenchmarkBar");
+        assert_eq!(
+            receipt.bench.command,
+            vec!["(go bench: 1 run(s), 500 iterations, GOMAXPROCS=4)"]
+        );
     }
 
     #[test]
     fn parse_gobench_no_memory() {
         let input = "BenchmarkSimple-8\t  10000\t  500 ns/op\n";
         let receipt = parse_gobench(input, None).unwrap();
-        assert!(receipt.stats.max_rss_kb.is_none());
+        assert!(receipt.stats.alloc_bytes.is_none());
+        assert!(receipt.stats.allocs.is_none());
         // 500 ns = sub-millisecond, should clamp to 1ms
         assert_eq!(receipt.stats.wall_ms.median, 1);
     }
@@ -220,7 +399,23 @@ BenchmarkA-8\t  1000\t  100000 ns/op\n\
 BenchmarkB-8\t  2000\t  200000 ns/op\n";
         // Should use first benchmark
         let receipt = parse_gobench(input, None).unwrap();
-        assert_eq!(receipt.bench.name, "BenchmarkA-8");
+        assert_eq!(receipt.bench.name, "BenchmarkA");
+    }
+
+    #[test]
+    fn parse_gobench_all_keeps_every_function() {
+        let input = "\
+BenchmarkA-8\t  1000\t  1000000 ns/op\n\
+BenchmarkB-8\t  2000\t  2000000 ns/op\n\
+BenchmarkA-8\t  1000\t  3000000 ns/op\n";
+        let receipts = parse_gobench_all(input).unwrap();
+        assert_eq!(receipts.len(), 2);
+        assert_eq!(receipts[0].bench.name, "BenchmarkA");
+        assert_eq!(receipts[0].samples.len(), 2);
+        assert_eq!(receipts[0].stats.wall_ms.median, 2);
+        assert_eq!(receipts[1].bench.name, "BenchmarkB");
+        assert_eq!(receipts[1].stats.wall_ms.median, 2);
+        assert!(parse_gobench_all("PASS\n").is_err());
     }
 
     #[test]
@@ -235,7 +430,7 @@ PASS
 ok  \texample.com/mypackage\t1.523s
 ";
         let receipt = parse_gobench(input, None).unwrap();
-        assert_eq!(receipt.bench.name, "BenchmarkHash-8");
+        assert_eq!(receipt.bench.name, "BenchmarkHash");
         // 300_000 ns = 0.3 ms -> sub-millisecond clamp to 1
         assert_eq!(receipt.stats.wall_ms.median, 1);
     }
@@ -253,4 +448,60 @@ ok  \texample.com/mypackage\t1.523s
         // 1234.56 ns = ~0.001 ms -> should clamp to 1
         assert_eq!(receipt.stats.wall_ms.median, 1);
     }
+
+    #[test]
+    fn parse_gobench_count_repetitions_become_samples() {
+        let input = "\
+BenchmarkEncode/json-8\t  1000\t 2000000 ns/op\t  50.00 MB/s\t  4096 B/op\t  12 allocs/op\t  3.000 hits/op
+BenchmarkEncode/json-8\t  1000\t 4000000 ns/op\t  25.00 MB/s\t  4096 B/op\t  12 allocs/op\t  5.000 hits/op
+BenchmarkEncode/json-8\t  1000\t 3000000 ns/op\t  33.33 MB/s\t  4100 B/op\t  13 allocs/op\t  4.000 hits/op
+";
+        let receipt = parse_gobench(input, None).unwrap();
+        assert_eq!(receipt.bench.name, "BenchmarkEncode/json");
+        assert_eq!(receipt.samples.len(), 3);
+        let walls: Vec<u64> = receipt.samples.iter().map(|s| s.wall_ms).collect();
+        assert_eq!(walls, vec![2, 4, 3]);
+        assert_eq!(receipt.samples[2].allocs, Some(13));
+
+        assert_eq!(receipt.stats.wall_ms.median, 3);
+        assert_eq!(receipt.stats.wall_ms.mean, Some(3.0));
+        let throughput = receipt.stats.throughput_per_s.unwrap();
+        assert_eq!(throughput.median, 33.33e6);
+        assert_eq!(receipt.stats.alloc_bytes.unwrap().median, 4096);
+        assert_eq!(receipt.stats.allocs.unwrap().max, 13);
+
+        let hits = &receipt.stats.custom["hits/op"];
+        assert_eq!(hits.unit.as_deref(), Some("hits/op"));
+        assert_eq!(hits.summary.median, 4.0);
+    }
+
+    #[test]
+    fn parse_gobench_cpu_values_become_a_parameter() {
+        let input = "\
+BenchmarkSum     \t  1000\t 1000000 ns/op
+BenchmarkSum-4   \t  1000\t 2000000 ns/op
+BenchmarkSum-4   \t  1000\t 2000000 ns/op
+BenchmarkOther-4 \t  1000\t 5000000 ns/op
+";
+        let receipts = parse_gobench_all(input).unwrap();
+        let names: Vec<&str> = receipts.iter().map(|r| r.bench.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec!["BenchmarkSum/cpu=1", "BenchmarkSum/cpu=4", "BenchmarkOther"]
+        );
+        assert_eq!(receipts[1].samples.len(), 2);
+    }
+
+    #[test]
+    fn parse_gobench_results_after_logged_output() {
+        let input = "\
+BenchmarkLogs-8
+    bench_test.go:12: warming cache
+   10000\t    250000 ns/op\t  64 B/op\t  1 allocs/op
+--- BENCH: BenchmarkLogs-8
+";
+        let receipt = parse_gobench(input, None).unwrap();
+        assert_eq!(receipt.bench.name, "BenchmarkLogs");
+        assert_eq!(receipt.stats.alloc_bytes.unwrap().median, 64);
+    }
 }
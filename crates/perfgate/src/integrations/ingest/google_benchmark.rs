@@ -0,0 +1,460 @@
+//! Parser for Google Benchmark JSON output.
+//!
+//! `--benchmark_format=json` (or `--benchmark_out=<file>`) writes a
+//! `context` object and a `benchmarks` array. Each entry is either one
+//! repetition (`"run_type": "iteration"`) or an aggregate over the
+//! repetitions of a run (`"run_type": "aggregate"`, with `aggregate_name`
+//! `mean`, `median`, `stddev`, `cv`, ...). Entries sharing a `run_name`
+//! become one bench:
+//!
+//! - `real_time` / `cpu_time` (in `time_unit`) map to `wall_ms` / `cpu_ms`
+//! - `items_per_second` maps to `throughput_per_s`, falling back to
+//!   `bytes_per_second` when no items rate is reported
+//! - every other numeric field is a user counter and lands in
+//!   `stats.custom`
+//!
+//! Repetitions become samples. When only aggregates are present
+//! (`--benchmark_report_aggregates_only`), summaries come from the
+//! `mean`/`median`/`stddev` rows instead.
+
+use std::collections::BTreeMap;
+
+use anyhow::{Context, bail};
+use perfgate_types::{CustomMetric, F64Summary, RunReceipt, Stats};
+use serde::Deserialize;
+use serde_json::Value;
+
+use super::{f64_summary, make_receipt, to_u64, to_u64_summary, wall_sample};
+
+/// Top-level Google Benchmark JSON structure.
+#[derive(Debug, Deserialize)]
+struct GoogleBenchmarkOutput {
+    #[serde(default)]
+    context: Option<GoogleBenchmarkContext>,
+    benchmarks: Vec<GoogleBenchmarkEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GoogleBenchmarkContext {
+    #[serde(default)]
+    num_cpus: Option<u32>,
+}
+
+/// A single entry of the `benchmarks` array.
+#[derive(Debug, Deserialize)]
+struct GoogleBenchmarkEntry {
+    name: String,
+    #[serde(default)]
+    run_name: Option<String>,
+    #[serde(default)]
+    run_type: Option<String>,
+    #[serde(default)]
+    aggregate_name: Option<String>,
+    #[serde(default)]
+    real_time: Option<f64>,
+    #[serde(default)]
+    cpu_time: Option<f64>,
+    #[serde(default)]
+    time_unit: Option<String>,
+    #[serde(default)]
+    error_occurred: bool,
+    #[serde(default)]
+    error_message: Option<String>,
+    /// Rates and user counters; everything not named above.
+    #[serde(flatten)]
+    extra: BTreeMap<String, Value>,
+}
+
+/// Bookkeeping fields that are numeric but are not measurements.
+const NON_COUNTER_FIELDS: &[&str] = &[
+    "family_index",
+    "per_family_instance_index",
+    "repetitions",
+    "repetition_index",
+    "threads",
+    "iterations",
+    "complexity_n",
+];
+
+const ITEMS_PER_SECOND: &str = "items_per_second";
+const BYTES_PER_SECOND: &str = "bytes_per_second";
+
+/// Values for one measurement across the entries of a run.
+#[derive(Debug, Default)]
+struct Series {
+    repetitions: Vec<f64>,
+    mean: Option<f64>,
+    median: Option<f64>,
+    stddev: Option<f64>,
+}
+
+impl Series {
+    fn push(&mut self, aggregate: Option<&str>, value: f64) {
+        match aggregate {
+            None => self.repetitions.push(value),
+            Some("mean") => self.mean = Some(value),
+            Some("median") => self.median = Some(value),
+            Some("stddev") => self.stddev = Some(value),
+            Some(_) => {}
+        }
+    }
+
+    /// Summarize the repetitions, or the aggregate rows when the output
+    /// carries no repetitions.
+    fn summary(&self) -> Option<F64Summary> {
+        if !self.repetitions.is_empty() {
+            return Some(f64_summary(&self.repetitions));
+        }
+        let center = self.median.or(self.mean)?;
+        Some(F64Summary {
+            median: center,
+            min: center,
+            max: center,
+            mean: self.mean,
+            stddev: self.stddev,
+        })
+    }
+}
+
+/// All entries of one Google Benchmark run, grouped by `run_name`.
+#[derive(Debug, Default)]
+struct GoogleBenchmarkRun {
+    name: String,
+    wall_ms: Series,
+    cpu_ms: Series,
+    counters: BTreeMap<String, Series>,
+}
+
+/// Parse Google Benchmark JSON output into a `RunReceipt`.
+///
+/// If the output covers multiple benchmarks, only the first run is used;
+/// [`parse_google_benchmark_all`] keeps every run. Use `name` to override
+/// the benchmark name (defaults to the run name, e.g. `BM_Sort/1024`).
+pub fn parse_google_benchmark(input: &str, name: Option<&str>) -> anyhow::Result<RunReceipt> {
+    let (runs, cpu_count) = parse_runs(input)?;
+    run_to_receipt(&runs[0], name, cpu_count)
+}
+
+/// Parse Google Benchmark JSON output into one `RunReceipt` per run, each
+/// named after its run name.
+pub fn parse_google_benchmark_all(input: &str) -> anyhow::Result<Vec<RunReceipt>> {
+    let (runs, cpu_count) = parse_runs(input)?;
+    runs.iter()
+        .map(|run| run_to_receipt(run, None, cpu_count))
+        .collect()
+}
+
+fn parse_runs(input: &str) -> anyhow::Result<(Vec<GoogleBenchmarkRun>, Option<u32>)> {
+    let output: GoogleBenchmarkOutput =
+        serde_json::from_str(input).context("failed to parse Google Benchmark JSON")?;
+    let cpu_count = output.context.and_then(|context| context.num_cpus);
+
+    let mut runs: Vec<GoogleBenchmarkRun> = Vec::new();
+    let mut index_by_name: BTreeMap<String, usize> = BTreeMap::new();
+    for entry in &output.benchmarks {
+        if entry.error_occurred {
+            bail!(
+                "Google Benchmark '{}' reported an error: {}",
+                entry.name,
+                entry.error_message.as_deref().unwrap_or("(no message)")
+            );
+        }
+
+        let (run_name, aggregate) = run_identity(entry);
+        let index = *index_by_name.entry(run_name.clone()).or_insert_with(|| {
+            runs.push(GoogleBenchmarkRun {
+                name: run_name,
+                ..GoogleBenchmarkRun::default()
+            });
+            runs.len() - 1
+        });
+        let run = &mut runs[index];
+
+        // Coefficient-of-variation and complexity rows are ratios, not times.
+        if matches!(aggregate, Some("cv" | "BigO" | "RMS")) {
+            continue;
+        }
+        let scale = time_unit_to_ms(entry.time_unit.as_deref(), &entry.name)?;
+        if let Some(real_time) = entry.real_time {
+            run.wall_ms.push(
+                aggregate,
+                checked(real_time, &entry.name, "real_time")? * scale,
+            );
+        }
+        if let Some(cpu_time) = entry.cpu_time {
+            run.cpu_ms.push(
+                aggregate,
+                checked(cpu_time, &entry.name, "cpu_time")? * scale,
+            );
+        }
+        for (key, value) in &entry.extra {
+            if NON_COUNTER_FIELDS.contains(&key.as_str()) {
+                continue;
+            }
+            let Some(value) = value.as_f64() else {
+                continue;
+            };
+            if !value.is_finite() {
+                bail!(
+                    "Google Benchmark '{}' counter '{key}' must be finite",
+                    entry.name
+                );
+            }
+            run.counters
+                .entry(key.clone())
+                .or_default()
+                .push(aggregate, value);
+        }
+    }
+
+    if runs.is_empty() {
+        bail!("Google Benchmark JSON contains no benchmarks");
+    }
+    Ok((runs, cpu_count))
+}
+
+/// The run an entry belongs to and, for aggregate rows, the aggregate name.
+///
+/// Outputs from before `run_name`/`run_type` existed mark aggregates only
+/// through a `_mean`/`_median`/`_stddev` name suffix.
+fn run_identity(entry: &GoogleBenchmarkEntry) -> (String, Option<&str>) {
+    let aggregate = match entry.run_type.as_deref() {
+        Some("aggregate") => entry.aggregate_name.as_deref(),
+        Some(_) => None,
+        None => ["mean", "median", "stddev", "cv"]
+            .into_iter()
+            .find(|suffix| entry.name.ends_with(&format!("_{suffix}"))),
+    };
+    let run_name = match (&entry.run_name, aggregate) {
+        (Some(run_name), _) => run_name.clone(),
+        (None, Some(suffix)) => entry
+            .name
+            .strip_suffix(&format!("_{suffix}"))
+            .unwrap_or(&entry.name)
+            .to_string(),
+        (None, None) => entry.name.clone(),
+    };
+    (run_name, aggregate)
+}
+
+fn time_unit_to_ms(unit: Option<&str>, name: &str) -> anyhow::Result<f64> {
+    match unit.unwrap_or("ns") {
+        "ns" => Ok(1e-6),
+        "us" => Ok(1e-3),
+        "ms" => Ok(1.0),
+        "s" => Ok(1e3),
+        other => bail!("Google Benchmark '{name}' has unsupported time_unit '{other}'"),
+    }
+}
+
+fn checked(value: f64, name: &str, field: &str) -> anyhow::Result<f64> {
+    if !value.is_finite() || value < 0.0 {
+        bail!("Google Benchmark '{name}' field '{field}' must be finite and non-negative");
+    }
+    Ok(value)
+}
+
+fn run_to_receipt(
+    run: &GoogleBenchmarkRun,
+    name: Option<&str>,
+    cpu_count: Option<u32>,
+) -> anyhow::Result<RunReceipt> {
+    let bench_name = name.unwrap_or(&run.name);
+    let wall_ms = run
+        .wall_ms
+        .summary()
+        .with_context(|| format!("Google Benchmark '{}' reports no real_time", run.name))?;
+
+    let samples = if run.cpu_ms.repetitions.len() == run.wall_ms.repetitions.len() {
+        run.wall_ms
+            .repetitions
+            .iter()
+            .zip(&run.cpu_ms.repetitions)
+            .map(|(wall, cpu)| {
+                let mut sample = wall_sample(to_u64(*wall));
+                sample.cpu_ms = Some(to_u64(*cpu));
+                sample
+            })
+            .collect()
+    } else {
+        run.wall_ms
+            .repetitions
+            .iter()
+            .map(|wall| wall_sample(to_u64(*wall)))
+            .collect()
+    };
+
+    let throughput_key = if run.counters.contains_key(ITEMS_PER_SECOND) {
+        ITEMS_PER_SECOND
+    } else {
+        BYTES_PER_SECOND
+    };
+    let throughput_per_s = run.counters.get(throughput_key).and_then(Series::summary);
+    let custom = run
+        .counters
+        .iter()
+        .filter(|(key, _)| key.as_str() != throughput_key)
+        .filter_map(|(key, series)| {
+            let summary = series.summary()?;
+            let unit = (key == BYTES_PER_SECOND).then(|| "bytes/s".to_string());
+            Some((key.clone(), CustomMetric { unit, summary }))
+        })
+        .collect();
+
+    let stats = Stats {
+        wall_ms: to_u64_summary(&wall_ms),
+        cpu_ms: run.cpu_ms.summary().as_ref().map(to_u64_summary),
+        page_faults: None,
+        ctx_switches: None,
+        max_rss_kb: None,
+        io_read_bytes: None,
+        io_write_bytes: None,
+        network_packets: None,
+        energy_uj: None,
+        binary_bytes: None,
+        throughput_per_s,
+        latency_p50_us: None,
+        latency_p95_us: None,
+        latency_p99_us: None,
+        error_rate: None,
+        time_to_ready_ms: None,
+        instructions: None,
+        estimated_cycles: None,
+        l1_hits: None,
+        ll_hits: None,
+        ram_hits: None,
+        alloc_bytes: None,
+        allocs: None,
+        custom,
+    };
+
+    let mut receipt = make_receipt(bench_name, samples, stats);
+    receipt.bench.command = vec![format!("(google benchmark: {})", run.name)];
+    receipt.run.host.os = "unknown".to_string();
+    receipt.run.host.arch = "unknown".to_string();
+    receipt.run.host.cpu_count = cpu_count;
+    Ok(receipt)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REPETITIONS: &str = r#"{
+      "context": {"date": "2026-01-01T00:00:00+00:00", "num_cpus": 8},
+      "benchmarks": [
+        {"name": "BM_Sort/1024", "run_name": "BM_Sort/1024", "run_type": "iteration",
+         "repetitions": 3, "repetition_index": 0, "threads": 1, "iterations": 1000,
+         "real_time": 2000000.0, "cpu_time": 1900000.0, "time_unit": "ns",
+         "items_per_second": 500.0, "bytes_per_second": 4096.0, "Comparisons": 10.0},
+        {"name": "BM_Sort/1024", "run_name": "BM_Sort/1024", "run_type": "iteration",
+         "repetitions": 3, "repetition_index": 1, "threads": 1, "iterations": 1000,
+         "real_time": 3000000.0, "cpu_time": 2900000.0, "time_unit": "ns",
+         "items_per_second": 400.0, "bytes_per_second": 4096.0, "Comparisons": 12.0},
+        {"name": "BM_Sort/1024", "run_name": "BM_Sort/1024", "run_type": "iteration",
+         "repetitions": 3, "repetition_index": 2, "threads": 1, "iterations": 1000,
+         "real_time": 4000000.0, "cpu_time": 3900000.0, "time_unit": "ns",
+         "items_per_second": 300.0, "bytes_per_second": 4096.0, "Comparisons": 14.0},
+        {"name": "BM_Sort/1024_mean", "run_name": "BM_Sort/1024", "run_type": "aggregate",
+         "aggregate_name": "mean", "aggregate_unit": "time", "repetitions": 3, "threads": 1,
+         "iterations": 3, "real_time": 3000000.0, "cpu_time": 2900000.0, "time_unit": "ns"},
+        {"name": "BM_Sort/1024_cv", "run_name": "BM_Sort/1024", "run_type": "aggregate",
+         "aggregate_name": "cv", "aggregate_unit": "percentage", "repetitions": 3,
+         "threads": 1, "iterations": 3, "real_time": 0.27, "cpu_time": 0.28, "time_unit": "ns"},
+        {"name": "BM_Copy", "run_name": "BM_Copy", "run_type": "iteration",
+         "repetitions": 1, "repetition_index": 0, "threads": 1, "iterations": 50,
+         "real_time": 1.5, "cpu_time": 1.5, "time_unit": "ms"}
+      ]
+    }"#;
+
+    #[test]
+    fn repetitions_become_samples_with_throughput_and_counters() {
+        let receipt = parse_google_benchmark(REPETITIONS, None).unwrap();
+
+        assert_eq!(receipt.bench.name, "BM_Sort/1024");
+        assert_eq!(receipt.run.host.cpu_count, Some(8));
+        let walls: Vec<u64> = receipt.samples.iter().map(|s| s.wall_ms).collect();
+        assert_eq!(walls, vec![2, 3, 4]);
+        assert_eq!(receipt.samples[0].cpu_ms, Some(2));
+        assert_eq!(receipt.stats.wall_ms.median, 3);
+        assert_eq!(receipt.stats.wall_ms.mean, Some(3.0));
+        assert_eq!(receipt.stats.cpu_ms.as_ref().unwrap().median, 3);
+
+        let throughput = receipt.stats.throughput_per_s.unwrap();
+        assert_eq!(throughput.median, 400.0);
+        assert_eq!(throughput.min, 300.0);
+
+        let comparisons = &receipt.stats.custom["Comparisons"];
+        assert_eq!(comparisons.summary.median, 12.0);
+        assert_eq!(comparisons.unit, None);
+        let bytes = &receipt.stats.custom["bytes_per_second"];
+        assert_eq!(bytes.unit.as_deref(), Some("bytes/s"));
+        assert!(!receipt.stats.custom.contains_key("iterations"));
+    }
+
+    #[test]
+    fn all_keeps_every_run_in_input_order() {
+        let receipts = parse_google_benchmark_all(REPETITIONS).unwrap();
+        let names: Vec<&str> = receipts.iter().map(|r| r.bench.name.as_str()).collect();
+        assert_eq!(names, vec!["BM_Sort/1024", "BM_Copy"]);
+
+        let copy = &receipts[1];
+        assert_eq!(copy.stats.wall_ms.median, 2);
+        assert_eq!(copy.stats.wall_ms.mean, Some(1.5));
+        assert!(copy.stats.throughput_per_s.is_none());
+        assert!(copy.stats.custom.is_empty());
+    }
+
+    #[test]
+    fn aggregates_only_output_uses_aggregate_rows() {
+        // Pre-1.5 output: no run_type, aggregates marked by name suffix.
+        let input = r#"{"benchmarks": [
+          {"name": "BM_Hash_mean", "iterations": 3, "real_time": 12.0, "cpu_time": 11.0,
+           "time_unit": "us", "bytes_per_second": 1048576.0},
+          {"name": "BM_Hash_median", "iterations": 3, "real_time": 10.0, "cpu_time": 9.0,
+           "time_unit": "us", "bytes_per_second": 1048576.0},
+          {"name": "BM_Hash_stddev", "iterations": 3, "real_time": 2.0, "cpu_time": 2.0,
+           "time_unit": "us", "bytes_per_second": 0.0}
+        ]}"#;
+
+        let receipt = parse_google_benchmark(input, Some("hash")).unwrap();
+        assert_eq!(receipt.bench.name, "hash");
+        assert!(receipt.samples.is_empty());
+        assert_eq!(receipt.stats.wall_ms.median, 1);
+        assert_eq!(receipt.stats.wall_ms.mean, Some(0.012));
+        assert_eq!(receipt.stats.wall_ms.stddev, Some(0.002));
+
+        let throughput = receipt.stats.throughput_per_s.unwrap();
+        assert_eq!(throughput.median, 1048576.0);
+        assert!(receipt.stats.custom.is_empty());
+    }
+
+    #[test]
+    fn errored_benchmark_is_rejected() {
+        let input = r#"{"benchmarks": [
+          {"name": "BM_Broken", "run_type": "iteration", "error_occurred": true,
+           "error_message": "setup failed", "iterations": 0, "real_time": 0.0,
+           "cpu_time": 0.0, "time_unit": "ns"}
+        ]}"#;
+
+        let err = parse_google_benchmark(input, None).unwrap_err();
+        assert!(err.to_string().contains("setup failed"), "{err}");
+    }
+
+    #[test]
+    fn unknown_time_unit_is_rejected() {
+        let input = r#"{"benchmarks": [
+          {"name": "BM_X", "run_type": "iteration", "iterations": 1, "real_time": 1.0,
+           "cpu_time": 1.0, "time_unit": "min"}
+        ]}"#;
+
+        let err = parse_google_benchmark(input, None).unwrap_err();
+        assert!(err.to_string().contains("time_unit 'min'"), "{err}");
+    }
+
+    #[test]
+    fn empty_benchmarks_is_rejected() {
+        let err = parse_google_benchmark(r#"{"benchmarks": []}"#, None).unwrap_err();
+        assert!(err.to_string().contains("no benchmarks"), "{err}");
+    }
+}
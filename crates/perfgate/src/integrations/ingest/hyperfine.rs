@@ -42,17 +42,35 @@ struct HyperfineOutput {
 /// Parse a hyperfine JSON export into a `RunReceipt`.
 ///
 /// If the export contains multiple results (multiple commands benchmarked),
-/// only the first result is used. Use the `name` parameter to override
-/// the benchmark name (defaults to the command string).
+/// only the first result is used; [`parse_hyperfine_all`] keeps every
+/// command. Use the `name` parameter to override the benchmark name
+/// (defaults to the command string).
 pub fn parse_hyperfine(input: &str, name: Option<&str>) -> anyhow::Result<RunReceipt> {
-    let output: HyperfineOutput =
-        serde_json::from_str(input).context("failed to parse hyperfine JSON")?;
+    let output = parse_output(input)?;
+    result_to_receipt(&output.results[0], name)
+}
 
-    let result = output
+/// Parse a hyperfine JSON export into one `RunReceipt` per benchmarked
+/// command, each named after its command string.
+pub fn parse_hyperfine_all(input: &str) -> anyhow::Result<Vec<RunReceipt>> {
+    let output = parse_output(input)?;
+    output
         .results
-        .first()
-        .context("hyperfine JSON contains no results")?;
+        .iter()
+        .map(|result| result_to_receipt(result, None))
+        .collect()
+}
+
+fn parse_output(input: &str) -> anyhow::Result<HyperfineOutput> {
+    let output: HyperfineOutput =
+        serde_json::from_str(input).context("failed to parse hyperfine JSON")?;
+    if output.results.is_empty() {
+        bail!("hyperfine JSON contains no results");
+    }
+    Ok(output)
+}
 
+fn result_to_receipt(result: &HyperfineResult, name: Option<&str>) -> anyhow::Result<RunReceipt> {
     let bench_name = name
         .map(|n| n.to_string())
         .unwrap_or_else(|| result.command.clone());
@@ -96,6 +114,19 @@ pub fn parse_hyperfine(input: &str, name: Option<&str>) -> anyhow::Result<RunRec
             binary_bytes: None,
             stdout: None,
             stderr: None,
+            latency_p50_us: None,
+            latency_p95_us: None,
+            latency_p99_us: None,
+            error_rate: None,
+            throughput_per_s: None,
+            time_to_ready_ms: None,
+            instructions: None,
+            estimated_cycles: None,
+            l1_hits: None,
+            ll_hits: None,
+            ram_hits: None,
+            alloc_bytes: None,
+            allocs: None,
         });
     }
 
@@ -118,6 +149,19 @@ pub fn parse_hyperfine(input: &str, name: Option<&str>) -> anyhow::Result<RunRec
         energy_uj: None,
         binary_bytes: None,
         throughput_per_s: None,
+        latency_p50_us: None,
+        latency_p95_us: None,
+        latency_p99_us: None,
+        error_rate: None,
+        time_to_ready_ms: None,
+        instructions: None,
+        estimated_cycles: None,
+        l1_hits: None,
+        ll_hits: None,
+        ram_hits: None,
+        alloc_bytes: None,
+        allocs: None,
+        custom: Default::default(),
     };
 
     Ok(make_hyperfine_receipt(
@@ -209,6 +253,10 @@ fn make_hyperfine_receipt(
                 memory_bytes: None,
                 hostname_hash: None,
             },
+            profile: None,
+            concurrency: None,
+            interleave: None,
+            noise_warnings: Vec::new(),
         },
         bench: BenchMeta {
             name: name.to_string(),
@@ -218,6 +266,8 @@ fn make_hyperfine_receipt(
             warmup: 0,
             work_units: None,
             timeout_ms: None,
+            service: None,
+            load: None,
         },
         samples,
         stats,
@@ -263,6 +313,22 @@ mod tests {
         assert_eq!(receipt.run.host.arch, "unknown");
     }
 
+    #[test]
+    fn parse_hyperfine_all_keeps_every_command() {
+        let input = r#"{"results": [
+            {"command": "sleep 0.1", "times": [0.1], "mean": 0.1, "stddev": 0.0,
+             "median": 0.1, "min": 0.1, "max": 0.1},
+            {"command": "sleep 0.2", "times": [0.2, 0.2], "mean": 0.2, "stddev": 0.0,
+             "median": 0.2, "min": 0.2, "max": 0.2}
+        ]}"#;
+        let receipts = parse_hyperfine_all(input).unwrap();
+        assert_eq!(receipts.len(), 2);
+        assert_eq!(receipts[0].bench.name, "sleep 0.1");
+        assert_eq!(receipts[1].bench.name, "sleep 0.2");
+        assert_eq!(receipts[1].samples.len(), 2);
+        assert!(parse_hyperfine_all(r#"{"results": []}"#).is_err());
+    }
+
     #[test]
     fn parse_hyperfine_default_name() {
         let receipt = parse_hyperfine(HYPERFINE_JSON, None).unwrap();
@@ -0,0 +1,443 @@
+//! Parser for JMH (Java Microbenchmark Harness) JSON results.
+//!
+//! `-rf json` writes an array with one entry per benchmark method, mode, and
+//! `@Param` combination. Each entry carries a `primaryMetric` with a
+//! `score`, `scoreUnit`, `scorePercentiles`, and `rawData` (one array of
+//! iteration scores per fork), plus optional `secondaryMetrics` from
+//! profilers such as `-prof gc`.
+//!
+//! The benchmark mode decides what the score means:
+//!
+//! - `thrpt` scores (`ops/s`, `ops/ms`, ...) map to higher-is-better
+//!   `throughput_per_s`; `wall_ms` is the matching time per operation
+//! - `avgt`, `sample`, and `ss` scores (`ms/op`, `us/op`, ...) map to
+//!   lower-is-better `wall_ms`; `sample` mode percentiles also fill
+//!   `latency_p50_us`/`latency_p95_us`/`latency_p99_us`
+//!
+//! Measurement iterations become samples. Secondary metrics are recorded in
+//! `stats.custom`.
+
+use std::collections::BTreeMap;
+
+use anyhow::{Context, bail};
+use perfgate_types::{CustomMetric, F64Summary, RunReceipt, Stats, U64Summary};
+use serde::Deserialize;
+
+use super::{f64_summary, make_receipt, to_u64, to_u64_summary, wall_sample};
+
+/// A single JMH result entry.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct JmhResult {
+    benchmark: String,
+    mode: String,
+    #[serde(default)]
+    threads: Option<u32>,
+    #[serde(default)]
+    forks: Option<u32>,
+    #[serde(default)]
+    jdk_version: Option<String>,
+    #[serde(default)]
+    params: BTreeMap<String, String>,
+    primary_metric: JmhMetric,
+    #[serde(default)]
+    secondary_metrics: BTreeMap<String, JmhMetric>,
+}
+
+/// A primary or secondary JMH metric.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct JmhMetric {
+    score: f64,
+    score_unit: String,
+    #[serde(default)]
+    score_percentiles: BTreeMap<String, f64>,
+    #[serde(default)]
+    raw_data: Vec<Vec<f64>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum JmhMode {
+    Throughput,
+    AverageTime,
+    SampleTime,
+    SingleShot,
+}
+
+impl JmhMode {
+    fn parse(raw: &str) -> Option<Self> {
+        match raw {
+            "thrpt" => Some(Self::Throughput),
+            "avgt" => Some(Self::AverageTime),
+            "sample" => Some(Self::SampleTime),
+            "ss" => Some(Self::SingleShot),
+            _ => None,
+        }
+    }
+}
+
+/// Parse JMH JSON results into a `RunReceipt`.
+///
+/// If the results cover multiple benchmarks, only the first is used;
+/// [`parse_jmh_all`] keeps every one. Use `name` to override the benchmark
+/// name (defaults to the benchmark method plus its `@Param` values).
+pub fn parse_jmh(input: &str, name: Option<&str>) -> anyhow::Result<RunReceipt> {
+    let results = parse_results(input)?;
+    let names = bench_names(&results);
+    result_to_receipt(&results[0], name.unwrap_or(&names[0]))
+}
+
+/// Parse JMH JSON results into one `RunReceipt` per benchmark, mode, and
+/// `@Param` combination.
+///
+/// Names are `<benchmark>/<param>=<value>/...`, with the mode appended when
+/// the same benchmark and parameters were run in more than one mode.
+pub fn parse_jmh_all(input: &str) -> anyhow::Result<Vec<RunReceipt>> {
+    let results = parse_results(input)?;
+    let names = bench_names(&results);
+    results
+        .iter()
+        .zip(&names)
+        .map(|(result, name)| result_to_receipt(result, name))
+        .collect()
+}
+
+fn parse_results(input: &str) -> anyhow::Result<Vec<JmhResult>> {
+    let results: Vec<JmhResult> =
+        serde_json::from_str(input).context("failed to parse JMH JSON")?;
+    if results.is_empty() {
+        bail!("JMH JSON contains no benchmark results");
+    }
+    Ok(results)
+}
+
+fn bench_names(results: &[JmhResult]) -> Vec<String> {
+    let base: Vec<String> = results
+        .iter()
+        .map(|result| {
+            let mut name = result.benchmark.clone();
+            for (key, value) in &result.params {
+                name.push_str(&format!("/{key}={value}"));
+            }
+            name
+        })
+        .collect();
+    base.iter()
+        .zip(results)
+        .map(|(name, result)| {
+            if base.iter().filter(|other| *other == name).count() > 1 {
+                format!("{name}/{}", result.mode)
+            } else {
+                name.clone()
+            }
+        })
+        .collect()
+}
+
+fn result_to_receipt(result: &JmhResult, bench_name: &str) -> anyhow::Result<RunReceipt> {
+    let mode = JmhMode::parse(&result.mode).with_context(|| {
+        format!(
+            "JMH benchmark '{}' has unsupported mode '{}'",
+            result.benchmark, result.mode
+        )
+    })?;
+    let metric = &result.primary_metric;
+    let unit = metric.score_unit.as_str();
+    let raw: Vec<f64> = metric.raw_data.iter().flatten().copied().collect();
+    for value in raw.iter().chain([&metric.score]) {
+        if !value.is_finite() || *value < 0.0 {
+            bail!(
+                "JMH benchmark '{}' has a score that is not finite and non-negative",
+                result.benchmark
+            );
+        }
+    }
+
+    let mut stats = Stats {
+        wall_ms: U64Summary::new(0, 0, 0),
+        cpu_ms: None,
+        page_faults: None,
+        ctx_switches: None,
+        max_rss_kb: None,
+        io_read_bytes: None,
+        io_write_bytes: None,
+        network_packets: None,
+        energy_uj: None,
+        binary_bytes: None,
+        throughput_per_s: None,
+        latency_p50_us: None,
+        latency_p95_us: None,
+        latency_p99_us: None,
+        error_rate: None,
+        time_to_ready_ms: None,
+        instructions: None,
+        estimated_cycles: None,
+        l1_hits: None,
+        ll_hits: None,
+        ram_hits: None,
+        alloc_bytes: None,
+        allocs: None,
+        custom: BTreeMap::new(),
+    };
+
+    let samples = if mode == JmhMode::Throughput {
+        let scale = throughput_unit_to_per_s(unit).with_context(|| {
+            format!(
+                "JMH benchmark '{}' in thrpt mode has unsupported score unit '{unit}'",
+                result.benchmark
+            )
+        })?;
+        let per_s: Vec<f64> = raw.iter().map(|value| value * scale).collect();
+        let throughput = score_summary(metric, &per_s, scale);
+        // Time per operation is the reciprocal of the rate, so the fastest
+        // iteration has the smallest wall time.
+        let wall = if per_s.is_empty() {
+            F64Summary {
+                median: per_op_ms(throughput.median),
+                min: per_op_ms(throughput.max),
+                max: per_op_ms(throughput.min),
+                mean: throughput.mean.map(per_op_ms),
+                stddev: None,
+            }
+        } else {
+            let per_op: Vec<f64> = per_s.iter().map(|ops| per_op_ms(*ops)).collect();
+            f64_summary(&per_op)
+        };
+        stats.wall_ms = to_u64_summary(&wall);
+        stats.throughput_per_s = Some(throughput);
+        per_s
+            .iter()
+            .map(|ops| {
+                let mut sample = wall_sample(to_u64(per_op_ms(*ops)));
+                sample.throughput_per_s = Some(*ops);
+                sample
+            })
+            .collect()
+    } else {
+        let scale = time_unit_to_ms(unit).with_context(|| {
+            format!(
+                "JMH benchmark '{}' in {} mode has unsupported score unit '{unit}'",
+                result.benchmark, result.mode
+            )
+        })?;
+        let ms: Vec<f64> = raw.iter().map(|value| value * scale).collect();
+        stats.wall_ms = to_u64_summary(&score_summary(metric, &ms, scale));
+        if mode == JmhMode::SampleTime {
+            let percentile_us = |key: &str| {
+                metric.score_percentiles.get(key).map(|value| {
+                    let us = to_u64(value * scale * 1000.0);
+                    U64Summary::new(us, us, us)
+                })
+            };
+            stats.latency_p50_us = percentile_us("50.0");
+            stats.latency_p95_us = percentile_us("95.0");
+            stats.latency_p99_us = percentile_us("99.0");
+        }
+        ms.iter().map(|ms| wall_sample(to_u64(*ms))).collect()
+    };
+
+    for (key, metric) in &result.secondary_metrics {
+        let raw: Vec<f64> = metric.raw_data.iter().flatten().copied().collect();
+        if !metric.score.is_finite() || raw.iter().any(|value| !value.is_finite()) {
+            continue;
+        }
+        stats.custom.insert(
+            key.trim_start_matches('·').to_string(),
+            CustomMetric {
+                unit: Some(metric.score_unit.clone()),
+                summary: score_summary(metric, &raw, 1.0),
+            },
+        );
+    }
+
+    let mut receipt = make_receipt(bench_name, samples, stats);
+    let mut command = format!("(jmh {}: {}", result.mode, result.benchmark);
+    if let Some(forks) = result.forks {
+        command.push_str(&format!(", {forks} forks"));
+    }
+    if let Some(threads) = result.threads {
+        command.push_str(&format!(", {threads} threads"));
+    }
+    if let Some(jdk) = &result.jdk_version {
+        command.push_str(&format!(", JDK {jdk}"));
+    }
+    command.push(')');
+    receipt.bench.command = vec![command];
+    receipt.run.host.os = "unknown".to_string();
+    receipt.run.host.arch = "unknown".to_string();
+    Ok(receipt)
+}
+
+/// Summarize scaled iteration scores, or the scaled score and percentiles
+/// when JMH reported no raw data.
+fn score_summary(metric: &JmhMetric, scaled: &[f64], scale: f64) -> F64Summary {
+    if !scaled.is_empty() {
+        return f64_summary(scaled);
+    }
+    let score = metric.score * scale;
+    let percentile = |key: &str| metric.score_percentiles.get(key).map(|value| value * scale);
+    F64Summary {
+        median: percentile("50.0").unwrap_or(score),
+        min: percentile("0.0").unwrap_or(score),
+        max: percentile("100.0").unwrap_or(score),
+        mean: Some(score),
+        // scoreError is a 99.9% confidence half-width, not a stddev.
+        stddev: None,
+    }
+}
+
+fn per_op_ms(ops_per_s: f64) -> f64 {
+    if ops_per_s > 0.0 {
+        1000.0 / ops_per_s
+    } else {
+        0.0
+    }
+}
+
+fn throughput_unit_to_per_s(unit: &str) -> Option<f64> {
+    match unit {
+        "ops/ns" => Some(1e9),
+        "ops/us" => Some(1e6),
+        "ops/ms" => Some(1e3),
+        "ops/s" => Some(1.0),
+        "ops/min" => Some(1.0 / 60.0),
+        "ops/hr" => Some(1.0 / 3600.0),
+        _ => None,
+    }
+}
+
+fn time_unit_to_ms(unit: &str) -> Option<f64> {
+    match unit {
+        "ns/op" => Some(1e-6),
+        "us/op" => Some(1e-3),
+        "ms/op" => Some(1.0),
+        "s/op" => Some(1e3),
+        "min/op" => Some(60e3),
+        "hr/op" => Some(3600e3),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const JMH_RESULTS: &str = r#"[
+      {
+        "jmhVersion": "1.37",
+        "benchmark": "org.example.ParserBench.parse",
+        "mode": "thrpt",
+        "threads": 1,
+        "forks": 2,
+        "jdkVersion": "21.0.2",
+        "params": {"size": "1024", "codec": "json"},
+        "primaryMetric": {
+          "score": 1500.0,
+          "scoreError": 120.0,
+          "scoreUnit": "ops/ms",
+          "scorePercentiles": {"0.0": 1000.0, "50.0": 1500.0, "100.0": 2000.0},
+          "rawData": [[1000.0, 1500.0], [2000.0]]
+        },
+        "secondaryMetrics": {
+          "·gc.alloc.rate.norm": {
+            "score": 512.0,
+            "scoreError": "NaN",
+            "scoreUnit": "B/op",
+            "scorePercentiles": {},
+            "rawData": [[512.0, 512.0], [512.0]]
+          }
+        }
+      },
+      {
+        "jmhVersion": "1.37",
+        "benchmark": "org.example.ParserBench.parse",
+        "mode": "avgt",
+        "params": {"size": "1024", "codec": "json"},
+        "primaryMetric": {
+          "score": 2.5,
+          "scoreUnit": "ms/op",
+          "rawData": [[2.0, 3.0], [2.5]]
+        }
+      },
+      {
+        "jmhVersion": "1.37",
+        "benchmark": "org.example.ParserBench.lookup",
+        "mode": "sample",
+        "primaryMetric": {
+          "score": 40.0,
+          "scoreUnit": "us/op",
+          "scorePercentiles": {"0.0": 10.0, "50.0": 35.0, "95.0": 80.0, "99.0": 120.0, "100.0": 400.0}
+        }
+      }
+    ]"#;
+
+    #[test]
+    fn throughput_mode_maps_to_throughput_and_time_per_op() {
+        let receipt = parse_jmh(JMH_RESULTS, None).unwrap();
+
+        assert_eq!(
+            receipt.bench.name,
+            "org.example.ParserBench.parse/codec=json/size=1024/thrpt"
+        );
+        assert_eq!(receipt.samples.len(), 3);
+        assert_eq!(receipt.samples[0].throughput_per_s, Some(1_000_000.0));
+
+        let throughput = receipt.stats.throughput_per_s.as_ref().unwrap();
+        assert_eq!(throughput.median, 1_500_000.0);
+        assert_eq!(throughput.min, 1_000_000.0);
+        assert_eq!(receipt.stats.wall_ms.max, 1);
+        let wall_mean = receipt.stats.wall_ms.mean.unwrap();
+        assert!((wall_mean - (1e-3 + 1e-3 / 1.5 + 5e-4) / 3.0).abs() < 1e-12);
+
+        let alloc = &receipt.stats.custom["gc.alloc.rate.norm"];
+        assert_eq!(alloc.unit.as_deref(), Some("B/op"));
+        assert_eq!(alloc.summary.median, 512.0);
+        assert!(receipt.bench.command[0].contains("2 forks"));
+    }
+
+    #[test]
+    fn time_modes_map_to_wall_time_and_latency_percentiles() {
+        let receipts = parse_jmh_all(JMH_RESULTS).unwrap();
+        let names: Vec<&str> = receipts.iter().map(|r| r.bench.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec![
+                "org.example.ParserBench.parse/codec=json/size=1024/thrpt",
+                "org.example.ParserBench.parse/codec=json/size=1024/avgt",
+                "org.example.ParserBench.lookup",
+            ]
+        );
+
+        let avgt = &receipts[1];
+        let walls: Vec<u64> = avgt.samples.iter().map(|s| s.wall_ms).collect();
+        assert_eq!(walls, vec![2, 3, 3]);
+        assert_eq!(avgt.stats.wall_ms.mean, Some(2.5));
+        assert!(avgt.stats.throughput_per_s.is_none());
+
+        let sample = &receipts[2];
+        assert!(sample.samples.is_empty());
+        assert_eq!(sample.stats.wall_ms.mean, Some(0.04));
+        assert_eq!(sample.stats.latency_p50_us.as_ref().unwrap().median, 35);
+        assert_eq!(sample.stats.latency_p95_us.as_ref().unwrap().median, 80);
+        assert_eq!(sample.stats.latency_p99_us.as_ref().unwrap().median, 120);
+    }
+
+    #[test]
+    fn mismatched_mode_and_unit_is_rejected() {
+        let input = r#"[{"benchmark": "b.B.m", "mode": "thrpt",
+          "primaryMetric": {"score": 1.0, "scoreUnit": "ms/op", "rawData": [[1.0]]}}]"#;
+        let err = parse_jmh(input, None).unwrap_err();
+        assert!(err.to_string().contains("score unit 'ms/op'"), "{err}");
+
+        let input = r#"[{"benchmark": "b.B.m", "mode": "all",
+          "primaryMetric": {"score": 1.0, "scoreUnit": "ms/op"}}]"#;
+        let err = parse_jmh(input, None).unwrap_err();
+        assert!(err.to_string().contains("unsupported mode 'all'"), "{err}");
+    }
+
+    #[test]
+    fn empty_results_are_rejected() {
+        let err = parse_jmh("[]", None).unwrap_err();
+        assert!(err.to_string().contains("no benchmark results"), "{err}");
+    }
+}
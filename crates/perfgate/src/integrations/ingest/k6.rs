@@ -0,0 +1,144 @@
+//! Parser for k6 end-of-test summary JSON.
+//!
+//! Both summary shapes are understood:
+//!
+//! - `k6 run --summary-export <file>`: metric fields sit directly on the
+//!   metric object (`{"http_req_duration": {"med": 11.2, "p(95)": 25.0}}`)
+//! - `handleSummary(data)` writing `JSON.stringify(data)`: fields sit under
+//!   `values` (`{"http_req_duration": {"type": "trend", "values": {...}}}`)
+//!
+//! `http_req_duration` (milliseconds) maps to the latency percentiles,
+//! `http_req_failed` to `error_rate`, and `http_reqs.rate` scaled by the
+//! success rate to `throughput_per_s`. The whole test run is one sample.
+
+use std::collections::BTreeMap;
+
+use anyhow::{Context, bail};
+use perfgate_types::RunReceipt;
+use serde::Deserialize;
+use serde_json::Value;
+
+use super::{LoadRun, load_receipt};
+
+#[derive(Debug, Deserialize)]
+struct K6Summary {
+    metrics: BTreeMap<String, Value>,
+    #[serde(default)]
+    state: Option<K6State>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct K6State {
+    #[serde(default)]
+    test_run_duration_ms: Option<f64>,
+}
+
+/// Parse k6 summary JSON into a `RunReceipt` named `name`, or `k6`.
+pub fn parse_k6(input: &str, name: Option<&str>) -> anyhow::Result<RunReceipt> {
+    let summary: K6Summary =
+        serde_json::from_str(input).context("failed to parse k6 summary JSON")?;
+
+    let Some(duration) = summary.metrics.get("http_req_duration") else {
+        bail!("k6 summary has no http_req_duration metric; was the test an HTTP test?");
+    };
+    let reqs = summary.metrics.get("http_reqs");
+    let error_rate = summary
+        .metrics
+        .get("http_req_failed")
+        .and_then(|failed| field(failed, "rate").or_else(|| field(failed, "value")));
+    let request_count = reqs.and_then(|reqs| field(reqs, "count"));
+    let request_rate = reqs.and_then(|reqs| field(reqs, "rate"));
+
+    let wall_ms = summary
+        .state
+        .and_then(|state| state.test_run_duration_ms)
+        .or_else(|| Some(request_count? / request_rate? * 1000.0))
+        .unwrap_or(0.0);
+
+    let run = LoadRun {
+        wall_ms,
+        latency_p50_us: field(duration, "med").map(|ms| ms * 1000.0),
+        latency_p95_us: field(duration, "p(95)").map(|ms| ms * 1000.0),
+        latency_p99_us: field(duration, "p(99)").map(|ms| ms * 1000.0),
+        error_rate,
+        throughput_per_s: request_rate.map(|rate| rate * (1.0 - error_rate.unwrap_or(0.0))),
+    };
+
+    let mut receipt = load_receipt(name.unwrap_or("k6"), &run)?;
+    receipt.bench.command = vec![match request_count {
+        Some(count) => format!("(k6 summary: {count} requests)"),
+        None => "(k6 summary)".to_string(),
+    }];
+    Ok(receipt)
+}
+
+/// A numeric field of a k6 metric in either summary shape.
+fn field(metric: &Value, key: &str) -> Option<f64> {
+    metric
+        .get("values")
+        .unwrap_or(metric)
+        .get(key)
+        .and_then(Value::as_f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SUMMARY_EXPORT: &str = r#"{
+      "root_group": {"name": "", "checks": {}, "groups": {}},
+      "metrics": {
+        "http_req_duration": {"avg": 14.5, "min": 2.1, "med": 12.0, "max": 90.0, "p(90)": 20.0, "p(95)": 25.5},
+        "http_req_failed": {"passes": 10, "fails": 990, "value": 0.01},
+        "http_reqs": {"count": 1000, "rate": 100.0},
+        "vus": {"value": 10, "min": 10, "max": 10}
+      }
+    }"#;
+
+    #[test]
+    fn parses_summary_export() {
+        let receipt = parse_k6(SUMMARY_EXPORT, None).unwrap();
+
+        assert_eq!(receipt.bench.name, "k6");
+        assert_eq!(receipt.bench.command, vec!["(k6 summary: 1000 requests)"]);
+        assert_eq!(receipt.samples.len(), 1);
+        let sample = &receipt.samples[0];
+        assert_eq!(sample.wall_ms, 10_000);
+        assert_eq!(sample.latency_p50_us, Some(12_000));
+        assert_eq!(sample.latency_p95_us, Some(25_500));
+        assert_eq!(sample.latency_p99_us, None);
+        assert_eq!(sample.error_rate, Some(0.01));
+        assert_eq!(sample.throughput_per_s, Some(99.0));
+        assert_eq!(receipt.stats.latency_p95_us.unwrap().median, 25_500);
+        assert_eq!(receipt.stats.error_rate.unwrap().median, 0.01);
+    }
+
+    #[test]
+    fn parses_handle_summary_data() {
+        let input = r#"{
+          "state": {"testRunDurationMs": 30000.5},
+          "metrics": {
+            "http_req_duration": {"type": "trend", "contains": "time",
+              "values": {"med": 1.5, "p(95)": 4.0, "p(99)": 9.25}},
+            "http_req_failed": {"type": "rate", "values": {"rate": 0, "passes": 0, "fails": 300}},
+            "http_reqs": {"type": "counter", "values": {"count": 300, "rate": 10.0}}
+          }
+        }"#;
+        let receipt = parse_k6(input, Some("api/checkout")).unwrap();
+
+        assert_eq!(receipt.bench.name, "api/checkout");
+        let sample = &receipt.samples[0];
+        assert_eq!(sample.wall_ms, 30_001);
+        assert_eq!(sample.latency_p50_us, Some(1_500));
+        assert_eq!(sample.latency_p99_us, Some(9_250));
+        assert_eq!(sample.error_rate, Some(0.0));
+        assert_eq!(sample.throughput_per_s, Some(10.0));
+    }
+
+    #[test]
+    fn rejects_summary_without_http_metrics() {
+        let err = parse_k6(r#"{"metrics": {"iterations": {"count": 3}}}"#, None).unwrap_err();
+        assert!(err.to_string().contains("http_req_duration"), "got: {err}");
+    }
+}
@@ -0,0 +1,877 @@
+//! Mapping-driven parser for JSON and CSV benchmark output.
+//!
+//! For in-house harnesses without a dedicated adapter, a small TOML mapping
+//! declares where bench names and metric samples or summaries live (JSON
+//! pointers for JSON input, column names for CSV input) and which unit and
+//! direction each metric uses:
+//!
+//! ```toml
+//! format = "json"
+//! records = "/benchmarks"
+//! name = "/id"
+//!
+//! [metrics.wall_ms]
+//! samples = "/timings"
+//! unit = "ns"
+//! direction = "lower_is_better"
+//! ```
+//!
+//! JSON records are the elements of the `records` array (or the object it
+//! points to); CSV rows are grouped by their `name` column, with one sample
+//! per row. Values are converted to perfgate's canonical units, and declared
+//! directions must match perfgate's direction for the metric.
+
+use std::collections::BTreeMap;
+
+use anyhow::{Context, bail};
+use perfgate_types::{Direction, F64Summary, Metric, RunReceipt, Sample, Stats, U64Summary};
+use serde::Deserialize;
+use serde_json::Value;
+
+use super::{f64_summary, make_receipt, to_u64, to_u64_summary, wall_sample};
+
+const DEFAULT_BENCH_NAME: &str = "mapped-bench";
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Mapping {
+    format: MappingFormat,
+    /// JSON pointer to the array (or single object) of benchmark records.
+    #[serde(default)]
+    records: Option<String>,
+    /// JSON pointer within a record, or CSV column, holding the bench name.
+    #[serde(default)]
+    name: Option<String>,
+    /// CSV field delimiter (default `,`).
+    #[serde(default)]
+    delimiter: Option<char>,
+    metrics: BTreeMap<String, MetricMapping>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum MappingFormat {
+    Json,
+    Csv,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct MetricMapping {
+    unit: String,
+    direction: String,
+    #[serde(default)]
+    samples: Option<String>,
+    #[serde(default)]
+    median: Option<String>,
+    #[serde(default)]
+    min: Option<String>,
+    #[serde(default)]
+    max: Option<String>,
+    #[serde(default)]
+    mean: Option<String>,
+    #[serde(default)]
+    stddev: Option<String>,
+}
+
+/// A validated metric mapping with its unit scale resolved.
+struct MappedMetric<'a> {
+    metric: Metric,
+    scale: f64,
+    source: MetricSource<'a>,
+}
+
+enum MetricSource<'a> {
+    Samples(&'a str),
+    Summary {
+        median: &'a str,
+        min: &'a str,
+        max: &'a str,
+        mean: Option<&'a str>,
+        stddev: Option<&'a str>,
+    },
+}
+
+/// CSV column indexes of one metric mapping.
+#[derive(Clone, Copy)]
+enum CsvColumns {
+    Samples(usize),
+    Summary {
+        median: usize,
+        min: usize,
+        max: usize,
+        mean: Option<usize>,
+        stddev: Option<usize>,
+    },
+}
+
+/// Metric values of one bench, already in perfgate's canonical units.
+enum MetricValues {
+    Samples(Vec<f64>),
+    Summary(F64Summary),
+}
+
+struct MappedBench {
+    name: String,
+    metrics: BTreeMap<Metric, MetricValues>,
+}
+
+/// Parse JSON or CSV benchmark output with a TOML `mapping` into a
+/// `RunReceipt` for the first benchmark in the input. Use `name` to override
+/// the benchmark name.
+pub fn parse_mapped(input: &str, mapping: &str, name: Option<&str>) -> anyhow::Result<RunReceipt> {
+    let bench = mapped_benches(input, mapping)?.remove(0);
+    bench_to_receipt(bench, name)
+}
+
+/// Parse JSON or CSV benchmark output with a TOML `mapping` into one
+/// `RunReceipt` per benchmark.
+pub fn parse_mapped_all(input: &str, mapping: &str) -> anyhow::Result<Vec<RunReceipt>> {
+    mapped_benches(input, mapping)?
+        .into_iter()
+        .map(|bench| bench_to_receipt(bench, None))
+        .collect()
+}
+
+fn mapped_benches(input: &str, mapping: &str) -> anyhow::Result<Vec<MappedBench>> {
+    let mapping: Mapping = toml::from_str(mapping).context("failed to parse ingest mapping")?;
+    let metrics = validate_mapping(&mapping)?;
+    let benches = match mapping.format {
+        MappingFormat::Json => json_benches(input, &mapping, &metrics)?,
+        MappingFormat::Csv => csv_benches(input, &mapping, &metrics)?,
+    };
+    if benches.is_empty() {
+        bail!("mapped input contains no benchmark records");
+    }
+    Ok(benches)
+}
+
+fn validate_mapping(mapping: &Mapping) -> anyhow::Result<Vec<MappedMetric<'_>>> {
+    if !mapping.metrics.contains_key(Metric::WallMs.as_str()) {
+        bail!("ingest mapping requires a [metrics.wall_ms] table");
+    }
+    match mapping.format {
+        MappingFormat::Json => {
+            if mapping.delimiter.is_some() {
+                bail!("ingest mapping sets `delimiter`, which only applies to format = \"csv\"");
+            }
+            for pointer in mapping.records.iter().chain(&mapping.name) {
+                validate_pointer(pointer)?;
+            }
+        }
+        MappingFormat::Csv => {
+            if mapping.records.is_some() {
+                bail!("ingest mapping sets `records`, which only applies to format = \"json\"");
+            }
+        }
+    }
+
+    let mut metrics = Vec::new();
+    for (key, input) in &mapping.metrics {
+        let metric = Metric::parse_key(key)
+            .with_context(|| format!("ingest mapping metric '{key}' is not a perfgate metric"))?;
+        let scale = unit_scale(metric, &input.unit).with_context(|| {
+            format!(
+                "ingest mapping metric '{key}' has unsupported unit '{}'",
+                input.unit
+            )
+        })?;
+
+        let direction = parse_direction(&input.direction).with_context(|| {
+            format!(
+                "ingest mapping metric '{key}' has ambiguous direction '{}'; use lower_is_better or higher_is_better",
+                input.direction
+            )
+        })?;
+        if direction != metric.default_direction() {
+            bail!(
+                "ingest mapping metric '{key}' declares direction '{}' but perfgate expects '{}'",
+                direction_label(direction),
+                direction_label(metric.default_direction())
+            );
+        }
+
+        let summary = [
+            &input.median,
+            &input.min,
+            &input.max,
+            &input.mean,
+            &input.stddev,
+        ];
+        let source = match (&input.samples, &input.median, &input.min, &input.max) {
+            (Some(_), ..) if summary.iter().any(|field| field.is_some()) => bail!(
+                "ingest mapping metric '{key}' sets both `samples` and summary fields; use one"
+            ),
+            (Some(samples), ..) => MetricSource::Samples(samples),
+            (None, Some(median), Some(min), Some(max)) => MetricSource::Summary {
+                median,
+                min,
+                max,
+                mean: input.mean.as_deref(),
+                stddev: input.stddev.as_deref(),
+            },
+            _ => bail!(
+                "ingest mapping metric '{key}' requires `samples` or `median`, `min` and `max`"
+            ),
+        };
+        if mapping.format == MappingFormat::Json {
+            match &source {
+                MetricSource::Samples(pointer) => validate_pointer(pointer)?,
+                MetricSource::Summary { .. } => {
+                    for pointer in summary.into_iter().flatten() {
+                        validate_pointer(pointer)?;
+                    }
+                }
+            }
+        }
+        metrics.push(MappedMetric {
+            metric,
+            scale,
+            source,
+        });
+    }
+    Ok(metrics)
+}
+
+fn validate_pointer(pointer: &str) -> anyhow::Result<()> {
+    if !pointer.is_empty() && !pointer.starts_with('/') {
+        bail!("ingest mapping JSON pointer '{pointer}' must be empty or start with '/'");
+    }
+    Ok(())
+}
+
+fn json_benches(
+    input: &str,
+    mapping: &Mapping,
+    metrics: &[MappedMetric<'_>],
+) -> anyhow::Result<Vec<MappedBench>> {
+    let root: Value = serde_json::from_str(input).context("failed to parse mapped JSON input")?;
+    let records_pointer = mapping.records.as_deref().unwrap_or("");
+    let records: Vec<(String, &Value)> = match root.pointer(records_pointer) {
+        Some(Value::Array(records)) => records
+            .iter()
+            .enumerate()
+            .map(|(index, record)| (format!("{records_pointer}/{index}"), record))
+            .collect(),
+        Some(record @ Value::Object(_)) => vec![(records_pointer.to_string(), record)],
+        Some(_) => bail!("mapped JSON records at '{records_pointer}' must be an array or object"),
+        None => bail!("mapped JSON input has no records at '{records_pointer}'"),
+    };
+    if mapping.name.is_none() && records.len() > 1 {
+        bail!(
+            "mapped JSON input has {} records at '{records_pointer}' but the mapping has no `name`",
+            records.len()
+        );
+    }
+
+    let mut benches = Vec::new();
+    for (path, record) in records {
+        let name = match &mapping.name {
+            Some(pointer) => match record.pointer(pointer) {
+                Some(Value::String(name)) => name.clone(),
+                Some(Value::Number(name)) => name.to_string(),
+                _ => bail!("mapped JSON record '{path}' has no string name at '{pointer}'"),
+            },
+            None => DEFAULT_BENCH_NAME.to_string(),
+        };
+
+        let mut values = BTreeMap::new();
+        for mapped in metrics {
+            let number = |pointer: &str| -> anyhow::Result<f64> {
+                let value = record
+                    .pointer(pointer)
+                    .and_then(Value::as_f64)
+                    .with_context(|| {
+                        format!("mapped JSON record '{path}' has no number at '{pointer}'")
+                    })?;
+                scale_value(mapped, value, || format!("'{path}{pointer}'"))
+            };
+            let metric_values = match &mapped.source {
+                MetricSource::Samples(pointer) => {
+                    let samples = match record.pointer(pointer) {
+                        Some(Value::Array(items)) => items
+                            .iter()
+                            .enumerate()
+                            .map(|(index, item)| {
+                                let value = item.as_f64().with_context(|| {
+                                    format!(
+                                        "mapped JSON value at '{path}{pointer}/{index}' is not a number"
+                                    )
+                                })?;
+                                scale_value(mapped, value, || format!("'{path}{pointer}/{index}'"))
+                            })
+                            .collect::<anyhow::Result<Vec<_>>>()?,
+                        Some(Value::Number(_)) => vec![number(pointer)?],
+                        _ => bail!(
+                            "mapped JSON record '{path}' has no number or number array at '{pointer}'"
+                        ),
+                    };
+                    MetricValues::Samples(samples)
+                }
+                MetricSource::Summary {
+                    median,
+                    min,
+                    max,
+                    mean,
+                    stddev,
+                } => MetricValues::Summary(F64Summary {
+                    median: number(median)?,
+                    min: number(min)?,
+                    max: number(max)?,
+                    mean: mean.map(number).transpose()?,
+                    stddev: stddev.map(number).transpose()?,
+                }),
+            };
+            values.insert(mapped.metric, metric_values);
+        }
+        benches.push(MappedBench {
+            name,
+            metrics: values,
+        });
+    }
+    Ok(benches)
+}
+
+fn csv_benches(
+    input: &str,
+    mapping: &Mapping,
+    metrics: &[MappedMetric<'_>],
+) -> anyhow::Result<Vec<MappedBench>> {
+    let delimiter = mapping.delimiter.unwrap_or(',');
+    let mut lines = input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty());
+    let (_, header) = lines.next().context("mapped CSV input is empty")?;
+    let header = split_csv_line(header.trim_start_matches('\u{feff}'), delimiter);
+    let column = |name: &str| -> anyhow::Result<usize> {
+        header
+            .iter()
+            .position(|column| column == name)
+            .with_context(|| {
+                format!(
+                    "mapped CSV has no column '{name}'; columns are: {}",
+                    header.join(", ")
+                )
+            })
+    };
+
+    let name_column = mapping.name.as_deref().map(column).transpose()?;
+    let mut columns = Vec::new();
+    for mapped in metrics {
+        columns.push(match &mapped.source {
+            MetricSource::Samples(name) => CsvColumns::Samples(column(name)?),
+            MetricSource::Summary {
+                median,
+                min,
+                max,
+                mean,
+                stddev,
+            } => CsvColumns::Summary {
+                median: column(median)?,
+                min: column(min)?,
+                max: column(max)?,
+                mean: mean.map(column).transpose()?,
+                stddev: stddev.map(column).transpose()?,
+            },
+        });
+    }
+
+    let mut benches: Vec<(MappedBench, usize)> = Vec::new();
+    for (index, line) in lines {
+        let line_number = index + 1;
+        let fields = split_csv_line(line, delimiter);
+        if fields.len() != header.len() {
+            bail!(
+                "mapped CSV line {line_number} has {} fields; the header has {}",
+                fields.len(),
+                header.len()
+            );
+        }
+        let number = |mapped: &MappedMetric<'_>, column: usize| -> anyhow::Result<f64> {
+            let raw = fields[column].trim();
+            let value = raw.parse::<f64>().with_context(|| {
+                format!(
+                    "mapped CSV line {line_number}, column '{}': '{raw}' is not a number",
+                    header[column]
+                )
+            })?;
+            scale_value(mapped, value, || {
+                format!("line {line_number}, column '{}'", header[column])
+            })
+        };
+
+        let name = match name_column {
+            Some(column) if fields[column].trim().is_empty() => {
+                bail!(
+                    "mapped CSV line {line_number}, column '{}' has an empty bench name",
+                    header[column]
+                )
+            }
+            Some(column) => fields[column].trim().to_string(),
+            None => DEFAULT_BENCH_NAME.to_string(),
+        };
+        let position = match benches.iter().position(|(bench, _)| bench.name == name) {
+            Some(position) => position,
+            None => {
+                benches.push((
+                    MappedBench {
+                        name: name.clone(),
+                        metrics: BTreeMap::new(),
+                    },
+                    line_number,
+                ));
+                benches.len() - 1
+            }
+        };
+        let (bench, first_line) = &mut benches[position];
+
+        for (mapped, columns) in metrics.iter().zip(&columns) {
+            match *columns {
+                CsvColumns::Samples(column) => {
+                    let value = number(mapped, column)?;
+                    if let MetricValues::Samples(samples) = bench
+                        .metrics
+                        .entry(mapped.metric)
+                        .or_insert_with(|| MetricValues::Samples(Vec::new()))
+                    {
+                        samples.push(value);
+                    }
+                }
+                CsvColumns::Summary {
+                    median,
+                    min,
+                    max,
+                    mean,
+                    stddev,
+                } => {
+                    if bench.metrics.contains_key(&mapped.metric) {
+                        bail!(
+                            "mapped CSV line {line_number} repeats bench '{name}' from line {first_line}; metric '{}' is mapped as a summary, which takes one row per bench",
+                            mapped.metric.as_str()
+                        );
+                    }
+                    let summary = F64Summary {
+                        median: number(mapped, median)?,
+                        min: number(mapped, min)?,
+                        max: number(mapped, max)?,
+                        mean: mean.map(|column| number(mapped, column)).transpose()?,
+                        stddev: stddev.map(|column| number(mapped, column)).transpose()?,
+                    };
+                    bench
+                        .metrics
+                        .insert(mapped.metric, MetricValues::Summary(summary));
+                }
+            }
+        }
+    }
+    Ok(benches.into_iter().map(|(bench, _)| bench).collect())
+}
+
+/// Split one CSV line, honouring double-quoted fields with `""` escapes.
+fn split_csv_line(line: &str, delimiter: char) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            c if c == delimiter && !quoted => fields.push(std::mem::take(&mut field)),
+            c => field.push(c),
+        }
+    }
+    fields.push(field);
+    fields
+}
+
+fn scale_value(
+    mapped: &MappedMetric<'_>,
+    value: f64,
+    location: impl FnOnce() -> String,
+) -> anyhow::Result<f64> {
+    if !value.is_finite() || value < 0.0 {
+        bail!(
+            "mapped value at {} for metric '{}' must be finite and non-negative",
+            location(),
+            mapped.metric.as_str()
+        );
+    }
+    Ok(value * mapped.scale)
+}
+
+fn bench_to_receipt(bench: MappedBench, name: Option<&str>) -> anyhow::Result<RunReceipt> {
+    let bench_name = name.map(str::to_string).unwrap_or(bench.name);
+    let mut samples = Vec::new();
+    let mut stats = Stats {
+        wall_ms: U64Summary::new(0, 0, 0),
+        cpu_ms: None,
+        page_faults: None,
+        ctx_switches: None,
+        max_rss_kb: None,
+        io_read_bytes: None,
+        io_write_bytes: None,
+        network_packets: None,
+        energy_uj: None,
+        binary_bytes: None,
+        throughput_per_s: None,
+        latency_p50_us: None,
+        latency_p95_us: None,
+        latency_p99_us: None,
+        error_rate: None,
+        time_to_ready_ms: None,
+        instructions: None,
+        estimated_cycles: None,
+        l1_hits: None,
+        ll_hits: None,
+        ram_hits: None,
+        alloc_bytes: None,
+        allocs: None,
+        custom: Default::default(),
+    };
+
+    for (metric, values) in &bench.metrics {
+        let metric = *metric;
+        if let MetricValues::Samples(values) = values {
+            if values.is_empty() {
+                bail!(
+                    "mapped bench '{bench_name}' has no samples for metric '{}'",
+                    metric.as_str()
+                );
+            }
+            if metric == Metric::WallMs {
+                samples = values
+                    .iter()
+                    .map(|value| wall_sample(to_u64(*value)))
+                    .collect();
+            }
+        }
+        let summary = match values {
+            MetricValues::Samples(values) => f64_summary(values),
+            MetricValues::Summary(summary) => summary.clone(),
+        };
+        match metric {
+            Metric::ErrorRate => stats.error_rate = Some(summary),
+            Metric::ThroughputPerS => stats.throughput_per_s = Some(summary),
+            _ => set_u64_summary(&mut stats, metric, to_u64_summary(&summary)),
+        }
+    }
+
+    for (metric, values) in &bench.metrics {
+        let MetricValues::Samples(values) = values else {
+            continue;
+        };
+        if *metric == Metric::WallMs || samples.is_empty() {
+            continue;
+        }
+        if values.len() != samples.len() {
+            bail!(
+                "mapped bench '{bench_name}' has {} samples for metric '{}' but {} for wall_ms; sample series must align",
+                values.len(),
+                metric.as_str(),
+                samples.len()
+            );
+        }
+        for (sample, value) in samples.iter_mut().zip(values) {
+            set_sample_value(sample, *metric, to_u64(*value));
+        }
+    }
+
+    let mut receipt = make_receipt(&bench_name, samples, stats);
+    receipt.bench.command = vec!["(ingested mapped benchmark output)".to_string()];
+    receipt.run.host.os = "unknown".to_string();
+    receipt.run.host.arch = "unknown".to_string();
+    Ok(receipt)
+}
+
+/// Multiplier from `unit` to the canonical unit of `metric`.
+fn unit_scale(metric: Metric, unit: &str) -> Option<f64> {
+    let unit = unit.trim().to_ascii_lowercase();
+    let ms = match unit.as_str() {
+        "ns" | "nanosecond" | "nanoseconds" => Some(1e-6),
+        "us" | "µs" | "microsecond" | "microseconds" => Some(1e-3),
+        "ms" | "millisecond" | "milliseconds" => Some(1.0),
+        "s" | "sec" | "second" | "seconds" => Some(1e3),
+        _ => None,
+    };
+    let bytes = match unit.as_str() {
+        "b" | "byte" | "bytes" => Some(1.0),
+        "kb" | "kib" => Some(1024.0),
+        "mb" | "mib" => Some(1024.0 * 1024.0),
+        "gb" | "gib" => Some(1024.0 * 1024.0 * 1024.0),
+        _ => None,
+    };
+    match metric {
+        Metric::CpuMs | Metric::TimeToReadyMs | Metric::WallMs => ms,
+        Metric::LatencyP50Us | Metric::LatencyP95Us | Metric::LatencyP99Us => {
+            ms.map(|scale| scale * 1e3)
+        }
+        Metric::MaxRssKb => bytes.map(|scale| scale / 1024.0),
+        Metric::AllocBytes | Metric::BinaryBytes | Metric::IoReadBytes | Metric::IoWriteBytes => {
+            bytes
+        }
+        Metric::Allocs => matches!(unit.as_str(), "count" | "allocs").then_some(1.0),
+        Metric::CtxSwitches | Metric::NetworkPackets | Metric::PageFaults => {
+            matches!(unit.as_str(), "count" | "events" | "packets").then_some(1.0)
+        }
+        Metric::Instructions | Metric::L1Hits | Metric::LlHits | Metric::RamHits => {
+            matches!(unit.as_str(), "count" | "events").then_some(1.0)
+        }
+        Metric::EstimatedCycles => matches!(unit.as_str(), "count" | "cycles").then_some(1.0),
+        Metric::EnergyUj => match unit.as_str() {
+            "uj" | "µj" | "microjoules" => Some(1.0),
+            "mj" | "millijoules" => Some(1e3),
+            "j" | "joules" => Some(1e6),
+            _ => None,
+        },
+        Metric::ErrorRate => match unit.as_str() {
+            "ratio" | "fraction" => Some(1.0),
+            "percent" | "%" => Some(0.01),
+            _ => None,
+        },
+        Metric::ThroughputPerS => matches!(
+            unit.as_str(),
+            "per_s" | "/s" | "ops/s" | "ops_per_s" | "req/s" | "requests_per_s" | "rps"
+        )
+        .then_some(1.0),
+    }
+}
+
+fn parse_direction(raw: &str) -> Option<Direction> {
+    match raw
+        .trim()
+        .to_ascii_lowercase()
+        .replace(['-', ' '], "_")
+        .as_str()
+    {
+        "lower" | "lower_is_better" => Some(Direction::Lower),
+        "higher" | "higher_is_better" => Some(Direction::Higher),
+        _ => None,
+    }
+}
+
+fn direction_label(direction: Direction) -> &'static str {
+    match direction {
+        Direction::Lower => "lower_is_better",
+        Direction::Higher => "higher_is_better",
+    }
+}
+
+fn set_u64_summary(stats: &mut Stats, metric: Metric, summary: U64Summary) {
+    match metric {
+        Metric::AllocBytes => stats.alloc_bytes = Some(summary),
+        Metric::Allocs => stats.allocs = Some(summary),
+        Metric::BinaryBytes => stats.binary_bytes = Some(summary),
+        Metric::CpuMs => stats.cpu_ms = Some(summary),
+        Metric::CtxSwitches => stats.ctx_switches = Some(summary),
+        Metric::EnergyUj => stats.energy_uj = Some(summary),
+        Metric::EstimatedCycles => stats.estimated_cycles = Some(summary),
+        Metric::Instructions => stats.instructions = Some(summary),
+        Metric::IoReadBytes => stats.io_read_bytes = Some(summary),
+        Metric::IoWriteBytes => stats.io_write_bytes = Some(summary),
+        Metric::L1Hits => stats.l1_hits = Some(summary),
+        Metric::LatencyP50Us => stats.latency_p50_us = Some(summary),
+        Metric::LatencyP95Us => stats.latency_p95_us = Some(summary),
+        Metric::LatencyP99Us => stats.latency_p99_us = Some(summary),
+        Metric::LlHits => stats.ll_hits = Some(summary),
+        Metric::MaxRssKb => stats.max_rss_kb = Some(summary),
+        Metric::NetworkPackets => stats.network_packets = Some(summary),
+        Metric::PageFaults => stats.page_faults = Some(summary),
+        Metric::RamHits => stats.ram_hits = Some(summary),
+        Metric::TimeToReadyMs => stats.time_to_ready_ms = Some(summary),
+        Metric::WallMs => stats.wall_ms = summary,
+        Metric::ErrorRate | Metric::ThroughputPerS => {}
+    }
+}
+
+fn set_sample_value(sample: &mut Sample, metric: Metric, value: u64) {
+    match metric {
+        Metric::AllocBytes => sample.alloc_bytes = Some(value),
+        Metric::Allocs => sample.allocs = Some(value),
+        Metric::BinaryBytes => sample.binary_bytes = Some(value),
+        Metric::CpuMs => sample.cpu_ms = Some(value),
+        Metric::CtxSwitches => sample.ctx_switches = Some(value),
+        Metric::EnergyUj => sample.energy_uj = Some(value),
+        Metric::EstimatedCycles => sample.estimated_cycles = Some(value),
+        Metric::Instructions => sample.instructions = Some(value),
+        Metric::IoReadBytes => sample.io_read_bytes = Some(value),
+        Metric::IoWriteBytes => sample.io_write_bytes = Some(value),
+        Metric::L1Hits => sample.l1_hits = Some(value),
+        Metric::LatencyP50Us => sample.latency_p50_us = Some(value),
+        Metric::LatencyP95Us => sample.latency_p95_us = Some(value),
+        Metric::LatencyP99Us => sample.latency_p99_us = Some(value),
+        Metric::LlHits => sample.ll_hits = Some(value),
+        Metric::MaxRssKb => sample.max_rss_kb = Some(value),
+        Metric::NetworkPackets => sample.network_packets = Some(value),
+        Metric::PageFaults => sample.page_faults = Some(value),
+        Metric::RamHits => sample.ram_hits = Some(value),
+        Metric::TimeToReadyMs => sample.time_to_ready_ms = Some(value),
+        Metric::WallMs | Metric::ErrorRate | Metric::ThroughputPerS => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const JSON_MAPPING: &str = r#"
+        format = "json"
+        records = "/suite/benchmarks"
+        name = "/id"
+
+        [metrics.wall_ms]
+        samples = "/timings"
+        unit = "ns"
+        direction = "lower_is_better"
+
+        [metrics.max_rss_kb]
+        median = "/memory/median"
+        min = "/memory/min"
+        max = "/memory/max"
+        unit = "bytes"
+        direction = "lower"
+    "#;
+
+    const JSON_INPUT: &str = r#"{"suite": {"benchmarks": [
+        {"id": "parse", "timings": [2000000, 4000000, 3000000],
+         "memory": {"median": 2048, "min": 1024, "max": 4096}},
+        {"id": "render", "timings": [10000000, 12000000, 11000000],
+         "memory": {"median": 8192, "min": 8192, "max": 8192}}
+    ]}}"#;
+
+    #[test]
+    fn maps_nested_json_records() {
+        let receipts = parse_mapped_all(JSON_INPUT, JSON_MAPPING).unwrap();
+        assert_eq!(receipts.len(), 2);
+
+        let parse = &receipts[0];
+        assert_eq!(parse.bench.name, "parse");
+        assert_eq!(parse.run.host.os, "unknown");
+        let walls: Vec<u64> = parse.samples.iter().map(|sample| sample.wall_ms).collect();
+        assert_eq!(walls, [2, 4, 3]);
+        assert_eq!(parse.stats.wall_ms.median, 3);
+        assert_eq!(parse.stats.wall_ms.mean, Some(3.0));
+        let rss = parse.stats.max_rss_kb.as_ref().unwrap();
+        assert_eq!((rss.median, rss.min, rss.max), (2, 1, 4));
+
+        assert_eq!(receipts[1].bench.name, "render");
+        assert_eq!(receipts[1].stats.wall_ms.median, 11);
+
+        let first = parse_mapped(JSON_INPUT, JSON_MAPPING, Some("renamed")).unwrap();
+        assert_eq!(first.bench.name, "renamed");
+    }
+
+    #[test]
+    fn groups_csv_rows_by_bench_name() {
+        let mapping = r#"
+            format = "csv"
+            name = "benchmark"
+
+            [metrics.wall_ms]
+            samples = "elapsed_us"
+            unit = "us"
+            direction = "lower_is_better"
+
+            [metrics.throughput_per_s]
+            samples = "ops"
+            unit = "ops/s"
+            direction = "higher_is_better"
+        "#;
+        let input = "benchmark,elapsed_us,ops\n\
+                     \"sort, large\",5000,200\n\
+                     sort-small,1000,1000\n\
+                     \"sort, large\",7000,140\n";
+        let receipts = parse_mapped_all(input, mapping).unwrap();
+        assert_eq!(receipts.len(), 2);
+        assert_eq!(receipts[0].bench.name, "sort, large");
+        assert_eq!(receipts[0].samples.len(), 2);
+        assert_eq!(receipts[0].stats.wall_ms.median, 6);
+        let throughput = receipts[0].stats.throughput_per_s.as_ref().unwrap();
+        assert_eq!(throughput.median, 170.0);
+        assert_eq!(receipts[1].bench.name, "sort-small");
+        assert_eq!(receipts[1].stats.wall_ms.median, 1);
+    }
+
+    #[test]
+    fn errors_point_at_offending_row_or_path() {
+        let err = parse_mapped_all(
+            r#"{"suite": {"benchmarks": [{"id": "parse", "timings": [1, "x"],
+                "memory": {"median": 1, "min": 1, "max": 1}}]}}"#,
+            JSON_MAPPING,
+        )
+        .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("'/suite/benchmarks/0/timings/1' is not a number"),
+            "{err}"
+        );
+
+        let mapping = r#"
+            format = "csv"
+            name = "name"
+            [metrics.wall_ms]
+            samples = "ms"
+            unit = "ms"
+            direction = "lower"
+        "#;
+        let err = parse_mapped_all("name,ms\na,1\nb,slow\n", mapping).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("line 3, column 'ms': 'slow' is not a number"),
+            "{err}"
+        );
+        let err = parse_mapped_all("name,elapsed\na,1\n", mapping).unwrap_err();
+        assert!(err.to_string().contains("no column 'ms'"), "{err}");
+    }
+
+    #[test]
+    fn rejects_invalid_mappings() {
+        let with_metric = |metric: &str| {
+            format!(
+                "format = \"json\"\n[metrics.wall_ms]\nsamples = \"/t\"\nunit = \"ms\"\ndirection = \"lower\"\n{metric}"
+            )
+        };
+        let cases = [
+            (
+                "format = \"json\"\n[metrics.cpu_ms]\nsamples = \"/t\"\nunit = \"ms\"\ndirection = \"lower\"\n",
+                "requires a [metrics.wall_ms] table",
+            ),
+            (
+                &with_metric(
+                    "[metrics.max_rss_kb]\nsamples = \"/m\"\nunit = \"furlongs\"\ndirection = \"lower\"\n",
+                ),
+                "unsupported unit 'furlongs'",
+            ),
+            (
+                &with_metric(
+                    "[metrics.throughput_per_s]\nsamples = \"/o\"\nunit = \"ops/s\"\ndirection = \"lower\"\n",
+                ),
+                "perfgate expects 'higher_is_better'",
+            ),
+            (
+                &with_metric(
+                    "[metrics.cpu_ms]\nmedian = \"/c\"\nunit = \"ms\"\ndirection = \"lower\"\n",
+                ),
+                "requires `samples` or `median`, `min` and `max`",
+            ),
+            (
+                &with_metric(
+                    "[metrics.bogus]\nsamples = \"/b\"\nunit = \"ms\"\ndirection = \"lower\"\n",
+                ),
+                "'bogus' is not a perfgate metric",
+            ),
+            (
+                "format = \"json\"\nname = \"id\"\n[metrics.wall_ms]\nsamples = \"/t\"\nunit = \"ms\"\ndirection = \"lower\"\n",
+                "must be empty or start with '/'",
+            ),
+        ];
+        for (mapping, expected) in cases {
+            let err = parse_mapped_all("{\"t\": [1]}", mapping).unwrap_err();
+            assert!(format!("{err:#}").contains(expected), "{expected}: {err:#}");
+        }
+    }
+}
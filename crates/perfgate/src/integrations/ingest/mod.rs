@@ -1,33 +1,71 @@
 //! Import benchmark results from external frameworks into perfgate's native format.
 //!
 //! Supports:
+//! - **Benchmark.js** (serialized `Benchmark` objects)
+//! - **cachegrind** (`cachegrind.out.<pid>` files and `cg_annotate` output)
 //! - **Criterion** (`target/criterion/**/new/estimates.json`)
 //! - **hyperfine** (`--export-json` output)
+//! - **Google Benchmark** (`--benchmark_format=json` output)
 //! - **Go benchmark** (`go test -bench . -benchmem` text output)
+//! - **JMH** (`-rf json` output)
+//! - **k6** (`--summary-export` or `handleSummary` JSON)
+//! - **oha** (`--output-format json` output)
+//! - **perf stat** (`perf stat -x,` CSV)
+//! - **OpenTelemetry** (OTLP JSON, or OTLP protobuf as probe receipts)
 //! - **pytest-benchmark** (`.benchmarks/*.json`)
+//! - **tinybench / vitest bench** (task results, `vitest bench --outputJson`)
+//! - **vegeta** (`vegeta report -type=json`)
+//! - **wrk** (JSON written by a Lua `done` hook)
+//! - **Mapped JSON/CSV** (any JSON or CSV, described by a TOML field mapping)
 
+mod benchmarkjs;
+mod cachegrind;
 mod criterion;
 mod generic_command_json;
 mod gobench;
+mod google_benchmark;
 mod hyperfine;
+mod jmh;
+mod k6;
+mod mapped;
+mod oha;
 mod otel;
+mod otlp_proto;
+mod perf_stat;
 mod probes;
 mod pytest;
+mod tinybench;
+mod vegeta;
+mod wrk;
 
+use std::collections::BTreeMap;
+
+use anyhow::bail;
 use perfgate_types::{
-    BenchMeta, HostInfo, PROBE_SCHEMA_V1, ProbeReceipt, RUN_SCHEMA_V1, RunMeta, RunReceipt, Sample,
-    Stats, ToolInfo, U64Summary,
+    BENCH_NAME_MAX_LEN, BenchMeta, F64Summary, HostInfo, PROBE_SCHEMA_V1, ProbeReceipt,
+    RUN_SCHEMA_V1, RunMeta, RunReceipt, Sample, Stats, ToolInfo, U64Summary, validate_bench_name,
 };
 use time::OffsetDateTime;
 use uuid::Uuid;
 
-pub use criterion::parse_criterion;
+pub use benchmarkjs::{parse_benchmarkjs, parse_benchmarkjs_all};
+pub use cachegrind::parse_cachegrind;
+pub use criterion::{parse_criterion, parse_criterion_all};
 pub use generic_command_json::parse_generic_command_json;
-pub use gobench::parse_gobench;
-pub use hyperfine::parse_hyperfine;
-pub use otel::parse_otel_json;
+pub use gobench::{parse_gobench, parse_gobench_all};
+pub use google_benchmark::{parse_google_benchmark, parse_google_benchmark_all};
+pub use hyperfine::{parse_hyperfine, parse_hyperfine_all};
+pub use jmh::{parse_jmh, parse_jmh_all};
+pub use k6::parse_k6;
+pub use mapped::{parse_mapped, parse_mapped_all};
+pub use oha::parse_oha;
+pub use otel::{OtelProbeIngestRequest, ingest_otel_probes, parse_otel_json, parse_otel_json_all};
+pub use perf_stat::{parse_perf_stat, perf_stat_warnings};
 pub use probes::{ProbeIngestRequest, ingest_probes_jsonl};
-pub use pytest::parse_pytest_benchmark;
+pub use pytest::{parse_pytest_benchmark, parse_pytest_benchmark_all};
+pub use tinybench::{parse_tinybench, parse_tinybench_all};
+pub use vegeta::parse_vegeta;
+pub use wrk::parse_wrk;
 
 /// Supported ingest formats.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -36,8 +74,19 @@ pub enum IngestFormat {
     GenericCommandJson,
     Hyperfine,
     GoBench,
+    GoogleBenchmark,
+    Jmh,
     PytestBenchmark,
     Otel,
+    Tinybench,
+    BenchmarkJs,
+    K6,
+    Oha,
+    Vegeta,
+    Wrk,
+    PerfStat,
+    Cachegrind,
+    Mapped,
 }
 
 impl IngestFormat {
@@ -50,8 +99,21 @@ impl IngestFormat {
             }
             "hyperfine" => Some(Self::Hyperfine),
             "gobench" | "go" => Some(Self::GoBench),
+            "google-benchmark" | "google_benchmark" | "googlebenchmark" | "gbench" => {
+                Some(Self::GoogleBenchmark)
+            }
+            "jmh" => Some(Self::Jmh),
             "pytest" | "pytest-benchmark" | "pytest_benchmark" => Some(Self::PytestBenchmark),
             "otel" | "opentelemetry" => Some(Self::Otel),
+            "tinybench" | "vitest" | "vitest-bench" => Some(Self::Tinybench),
+            "benchmarkjs" | "benchmark-js" | "benchmark.js" => Some(Self::BenchmarkJs),
+            "k6" => Some(Self::K6),
+            "oha" => Some(Self::Oha),
+            "vegeta" => Some(Self::Vegeta),
+            "wrk" => Some(Self::Wrk),
+            "perf-stat" | "perf_stat" | "perf" => Some(Self::PerfStat),
+            "cachegrind" => Some(Self::Cachegrind),
+            "mapped" | "mapping" => Some(Self::Mapped),
             _ => None,
         }
     }
@@ -69,6 +131,8 @@ pub struct IngestRequest {
     pub include_spans: Vec<String>,
     /// Optional exclude filter for span names (exact match).
     pub exclude_spans: Vec<String>,
+    /// TOML field mapping, required by [`IngestFormat::Mapped`].
+    pub mapping: Option<String>,
 }
 
 /// Perform an ingest operation, returning a `RunReceipt`.
@@ -80,6 +144,10 @@ pub fn ingest(request: &IngestRequest) -> anyhow::Result<RunReceipt> {
         }
         IngestFormat::Hyperfine => parse_hyperfine(&request.input, request.name.as_deref()),
         IngestFormat::GoBench => parse_gobench(&request.input, request.name.as_deref()),
+        IngestFormat::GoogleBenchmark => {
+            parse_google_benchmark(&request.input, request.name.as_deref())
+        }
+        IngestFormat::Jmh => parse_jmh(&request.input, request.name.as_deref()),
         IngestFormat::PytestBenchmark => {
             parse_pytest_benchmark(&request.input, request.name.as_deref())
         }
@@ -89,7 +157,113 @@ pub fn ingest(request: &IngestRequest) -> anyhow::Result<RunReceipt> {
             &request.include_spans,
             &request.exclude_spans,
         ),
+        IngestFormat::Tinybench => parse_tinybench(&request.input, request.name.as_deref()),
+        IngestFormat::BenchmarkJs => parse_benchmarkjs(&request.input, request.name.as_deref()),
+        IngestFormat::K6 => parse_k6(&request.input, request.name.as_deref()),
+        IngestFormat::Oha => parse_oha(&request.input, request.name.as_deref()),
+        IngestFormat::Vegeta => parse_vegeta(&request.input, request.name.as_deref()),
+        IngestFormat::Wrk => parse_wrk(&request.input, request.name.as_deref()),
+        IngestFormat::PerfStat => parse_perf_stat(&request.input, request.name.as_deref()),
+        IngestFormat::Cachegrind => parse_cachegrind(&request.input, request.name.as_deref()),
+        IngestFormat::Mapped => parse_mapped(
+            &request.input,
+            required_mapping(request)?,
+            request.name.as_deref(),
+        ),
+    }
+}
+
+/// Perform an ingest operation, returning one `RunReceipt` per benchmark in
+/// the input, keyed by bench name.
+///
+/// Names derived from the input are sanitized into valid bench names (see
+/// [`sanitize_bench_name`]); `request.name`, when set, is prepended as a
+/// `name/` prefix. Two benchmarks that map to the same bench name are an
+/// error rather than one silently replacing the other.
+pub fn ingest_all(request: &IngestRequest) -> anyhow::Result<BTreeMap<String, RunReceipt>> {
+    let receipts = match request.format {
+        IngestFormat::Criterion => parse_criterion_all(&request.input)?,
+        IngestFormat::GenericCommandJson => vec![parse_generic_command_json(&request.input, None)?],
+        IngestFormat::Hyperfine => parse_hyperfine_all(&request.input)?,
+        IngestFormat::GoBench => parse_gobench_all(&request.input)?,
+        IngestFormat::GoogleBenchmark => parse_google_benchmark_all(&request.input)?,
+        IngestFormat::Jmh => parse_jmh_all(&request.input)?,
+        IngestFormat::PytestBenchmark => parse_pytest_benchmark_all(&request.input)?,
+        IngestFormat::Otel => parse_otel_json_all(
+            &request.input,
+            &request.include_spans,
+            &request.exclude_spans,
+        )?,
+        IngestFormat::Tinybench => parse_tinybench_all(&request.input)?,
+        IngestFormat::BenchmarkJs => parse_benchmarkjs_all(&request.input)?,
+        IngestFormat::K6 => vec![parse_k6(&request.input, None)?],
+        IngestFormat::Oha => vec![parse_oha(&request.input, None)?],
+        IngestFormat::Vegeta => vec![parse_vegeta(&request.input, None)?],
+        IngestFormat::Wrk => vec![parse_wrk(&request.input, None)?],
+        IngestFormat::PerfStat => vec![parse_perf_stat(&request.input, None)?],
+        IngestFormat::Cachegrind => vec![parse_cachegrind(&request.input, None)?],
+        IngestFormat::Mapped => parse_mapped_all(&request.input, required_mapping(request)?)?,
+    };
+
+    let mut sources: BTreeMap<String, String> = BTreeMap::new();
+    let mut keyed = BTreeMap::new();
+    for mut receipt in receipts {
+        let source = receipt.bench.name.clone();
+        let mut name = sanitize_bench_name(&source)?;
+        if let Some(prefix) = &request.name {
+            name = format!("{prefix}/{name}");
+            if let Err(err) = validate_bench_name(&name) {
+                bail!("ingested bench name {name:?} is invalid: {err}");
+            }
+        }
+        if let Some(existing) = sources.get(&name) {
+            bail!("benchmarks {existing:?} and {source:?} both map to bench name {name:?}");
+        }
+        sources.insert(name.clone(), source);
+        receipt.bench.name = name.clone();
+        keyed.insert(name, receipt);
+    }
+    Ok(keyed)
+}
+
+fn required_mapping(request: &IngestRequest) -> anyhow::Result<&str> {
+    request
+        .mapping
+        .as_deref()
+        .ok_or_else(|| anyhow::anyhow!("mapped ingest requires a TOML field mapping"))
+}
+
+/// Turn a benchmark name from an external tool into a valid bench name.
+///
+/// Letters are lowercased, runs of characters outside `[a-z0-9_.-]` become a
+/// single `-`, `/` separates segments, empty and `.`/`..` segments are
+/// dropped, and the result is truncated to [`BENCH_NAME_MAX_LEN`].
+pub fn sanitize_bench_name(raw: &str) -> anyhow::Result<String> {
+    let mut segments = Vec::new();
+    for segment in raw.split('/') {
+        let mut cleaned = String::new();
+        for c in segment.chars().flat_map(char::to_lowercase) {
+            if c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '_' | '.') {
+                cleaned.push(c);
+            } else if !cleaned.ends_with('-') {
+                cleaned.push('-');
+            }
+        }
+        let cleaned = cleaned.trim_matches('-');
+        if !cleaned.is_empty() && cleaned != "." && cleaned != ".." {
+            segments.push(cleaned.to_string());
+        }
+    }
+
+    let mut name = segments.join("/");
+    if name.len() > BENCH_NAME_MAX_LEN {
+        name.truncate(BENCH_NAME_MAX_LEN);
+        name = name.trim_end_matches(['-', '/']).to_string();
+    }
+    if let Err(err) = validate_bench_name(&name) {
+        bail!("cannot derive a bench name from {raw:?}: {err}");
     }
+    Ok(name)
 }
 
 /// Build scaffolding for a `ProbeReceipt` with sensible defaults.
@@ -120,6 +294,10 @@ fn make_probe_receipt(
                 memory_bytes: None,
                 hostname_hash: None,
             },
+            profile: None,
+            concurrency: None,
+            interleave: None,
+            noise_warnings: Vec::new(),
         },
         bench: bench_name.map(|name| BenchMeta {
             name: name.to_string(),
@@ -129,6 +307,8 @@ fn make_probe_receipt(
             warmup: 0,
             work_units: None,
             timeout_ms: None,
+            service: None,
+            load: None,
         }),
         scenario,
         probes,
@@ -160,6 +340,10 @@ fn make_receipt(name: &str, samples: Vec<Sample>, stats: Stats) -> RunReceipt {
                 memory_bytes: None,
                 hostname_hash: None,
             },
+            profile: None,
+            concurrency: None,
+            interleave: None,
+            noise_warnings: Vec::new(),
         },
         bench: BenchMeta {
             name: name.to_string(),
@@ -169,6 +353,8 @@ fn make_receipt(name: &str, samples: Vec<Sample>, stats: Stats) -> RunReceipt {
             warmup: 0,
             work_units: None,
             timeout_ms: None,
+            service: None,
+            load: None,
         },
         samples,
         stats,
@@ -217,6 +403,157 @@ fn compute_u64_summary(values: &[u64]) -> U64Summary {
     }
 }
 
+/// Summarize f64 values (population stddev).
+fn f64_summary(values: &[f64]) -> F64Summary {
+    let mut sorted = values.to_vec();
+    sorted.sort_by(f64::total_cmp);
+    let mid = sorted.len() / 2;
+    let median = if sorted.len().is_multiple_of(2) {
+        (sorted[mid - 1] + sorted[mid]) / 2.0
+    } else {
+        sorted[mid]
+    };
+    let mean = values.iter().sum::<f64>() / values.len() as f64;
+    let variance = values
+        .iter()
+        .map(|value| (value - mean).powi(2))
+        .sum::<f64>()
+        / values.len() as f64;
+    F64Summary {
+        median,
+        min: sorted[0],
+        max: sorted[sorted.len() - 1],
+        mean: Some(mean),
+        stddev: Some(variance.sqrt()),
+    }
+}
+
+/// Round a summary to integer units, keeping the mean and stddev in f64 so
+/// sub-unit precision survives for budgets and significance tests.
+fn to_u64_summary(summary: &F64Summary) -> U64Summary {
+    U64Summary {
+        median: to_u64(summary.median),
+        min: to_u64(summary.min),
+        max: to_u64(summary.max),
+        mean: summary.mean,
+        stddev: summary.stddev,
+    }
+}
+
+fn to_u64(value: f64) -> u64 {
+    if value > 0.0 && value < 1.0 {
+        1
+    } else {
+        value.round() as u64
+    }
+}
+
+/// A measured (non-warmup, successful) sample carrying only wall time.
+fn wall_sample(wall_ms: u64) -> Sample {
+    Sample {
+        wall_ms,
+        exit_code: 0,
+        warmup: false,
+        timed_out: false,
+        cpu_ms: None,
+        page_faults: None,
+        ctx_switches: None,
+        max_rss_kb: None,
+        io_read_bytes: None,
+        io_write_bytes: None,
+        network_packets: None,
+        energy_uj: None,
+        binary_bytes: None,
+        stdout: None,
+        stderr: None,
+        latency_p50_us: None,
+        latency_p95_us: None,
+        latency_p99_us: None,
+        error_rate: None,
+        throughput_per_s: None,
+        time_to_ready_ms: None,
+        instructions: None,
+        estimated_cycles: None,
+        l1_hits: None,
+        ll_hits: None,
+        ram_hits: None,
+        alloc_bytes: None,
+        allocs: None,
+    }
+}
+
+/// Summary of one HTTP load-test run, in perfgate's units.
+struct LoadRun {
+    wall_ms: f64,
+    latency_p50_us: Option<f64>,
+    latency_p95_us: Option<f64>,
+    latency_p99_us: Option<f64>,
+    /// Fraction of failed requests, 0.0 to 1.0.
+    error_rate: Option<f64>,
+    /// Successful requests per second.
+    throughput_per_s: Option<f64>,
+}
+
+/// Build a single-sample receipt for a load-test run, the way the built-in
+/// HTTP load generator records one sample.
+fn load_receipt(name: &str, run: &LoadRun) -> anyhow::Result<RunReceipt> {
+    let values = [
+        Some(run.wall_ms),
+        run.latency_p50_us,
+        run.latency_p95_us,
+        run.latency_p99_us,
+        run.error_rate,
+        run.throughput_per_s,
+    ];
+    if values
+        .into_iter()
+        .flatten()
+        .any(|v| !v.is_finite() || v < 0.0)
+    {
+        bail!("load test summary for {name:?} has a negative or non-finite value");
+    }
+    if run.error_rate.is_some_and(|rate| rate > 1.0) {
+        bail!("load test summary for {name:?} has an error rate above 1.0");
+    }
+
+    let mut sample = wall_sample(to_u64(run.wall_ms));
+    sample.latency_p50_us = run.latency_p50_us.map(to_u64);
+    sample.latency_p95_us = run.latency_p95_us.map(to_u64);
+    sample.latency_p99_us = run.latency_p99_us.map(to_u64);
+    sample.error_rate = run.error_rate;
+    sample.throughput_per_s = run.throughput_per_s;
+
+    let u64_summary = |value: Option<f64>| value.map(|v| to_u64_summary(&f64_summary(&[v])));
+    let stats = Stats {
+        wall_ms: to_u64_summary(&f64_summary(&[run.wall_ms])),
+        cpu_ms: None,
+        page_faults: None,
+        ctx_switches: None,
+        max_rss_kb: None,
+        io_read_bytes: None,
+        io_write_bytes: None,
+        network_packets: None,
+        energy_uj: None,
+        binary_bytes: None,
+        throughput_per_s: run.throughput_per_s.map(|v| f64_summary(&[v])),
+        latency_p50_us: u64_summary(run.latency_p50_us),
+        latency_p95_us: u64_summary(run.latency_p95_us),
+        latency_p99_us: u64_summary(run.latency_p99_us),
+        error_rate: run.error_rate.map(|v| f64_summary(&[v])),
+        time_to_ready_ms: None,
+        instructions: None,
+        estimated_cycles: None,
+        l1_hits: None,
+        ll_hits: None,
+        ram_hits: None,
+        alloc_bytes: None,
+        allocs: None,
+        custom: Default::default(),
+    };
+
+    Ok(make_receipt(name, vec![sample], stats))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -245,6 +582,16 @@ mod tests {
         );
         assert_eq!(IngestFormat::parse("gobench"), Some(IngestFormat::GoBench));
         assert_eq!(IngestFormat::parse("go"), Some(IngestFormat::GoBench));
+        assert_eq!(
+            IngestFormat::parse("google-benchmark"),
+            Some(IngestFormat::GoogleBenchmark)
+        );
+        assert_eq!(
+            IngestFormat::parse("gbench"),
+            Some(IngestFormat::GoogleBenchmark)
+        );
+        assert_eq!(IngestFormat::parse("jmh"), Some(IngestFormat::Jmh));
+        assert_eq!(IngestFormat::parse("JMH"), Some(IngestFormat::Jmh));
         assert_eq!(
             IngestFormat::parse("pytest"),
             Some(IngestFormat::PytestBenchmark)
@@ -262,9 +609,93 @@ mod tests {
             IngestFormat::parse("opentelemetry"),
             Some(IngestFormat::Otel)
         );
+        assert_eq!(
+            IngestFormat::parse("tinybench"),
+            Some(IngestFormat::Tinybench)
+        );
+        assert_eq!(IngestFormat::parse("vitest"), Some(IngestFormat::Tinybench));
+        assert_eq!(
+            IngestFormat::parse("benchmark.js"),
+            Some(IngestFormat::BenchmarkJs)
+        );
+        assert_eq!(IngestFormat::parse("mapped"), Some(IngestFormat::Mapped));
+        assert_eq!(IngestFormat::parse("k6"), Some(IngestFormat::K6));
+        assert_eq!(IngestFormat::parse("OHA"), Some(IngestFormat::Oha));
+        assert_eq!(IngestFormat::parse("vegeta"), Some(IngestFormat::Vegeta));
+        assert_eq!(IngestFormat::parse("wrk"), Some(IngestFormat::Wrk));
+        assert_eq!(
+            IngestFormat::parse("perf-stat"),
+            Some(IngestFormat::PerfStat)
+        );
+        assert_eq!(
+            IngestFormat::parse("cachegrind"),
+            Some(IngestFormat::Cachegrind)
+        );
         assert_eq!(IngestFormat::parse("unknown"), None);
     }
 
+    #[test]
+    fn test_sanitize_bench_name() {
+        assert_eq!(sanitize_bench_name("sleep 0.1").unwrap(), "sleep-0.1");
+        assert_eq!(
+            sanitize_bench_name("./target/release/app --Fast").unwrap(),
+            "target/release/app-fast"
+        );
+        assert_eq!(
+            sanitize_bench_name("tests/test_perf.py::test_sort").unwrap(),
+            "tests/test_perf.py-test_sort"
+        );
+        assert_eq!(
+            sanitize_bench_name("parse//json/../x").unwrap(),
+            "parse/json/x"
+        );
+        assert_eq!(sanitize_bench_name(&"a".repeat(80)).unwrap().len(), 64);
+        assert!(sanitize_bench_name("!!!").is_err());
+    }
+
+    #[test]
+    fn test_ingest_all_keys_receipts_by_sanitized_name() {
+        let input = r#"{"results": [
+            {"command": "sleep 0.1", "times": [0.1], "mean": 0.1, "stddev": 0.0,
+             "median": 0.1, "min": 0.1, "max": 0.1},
+            {"command": "sleep 0.2", "times": [0.2], "mean": 0.2, "stddev": 0.0,
+             "median": 0.2, "min": 0.2, "max": 0.2}
+        ]}"#;
+        let request = IngestRequest {
+            format: IngestFormat::Hyperfine,
+            input: input.to_string(),
+            name: Some("cli".to_string()),
+            include_spans: Vec::new(),
+            exclude_spans: Vec::new(),
+            mapping: None,
+        };
+        let receipts = ingest_all(&request).unwrap();
+        let names: Vec<&str> = receipts.keys().map(String::as_str).collect();
+        assert_eq!(names, ["cli/sleep-0.1", "cli/sleep-0.2"]);
+        assert_eq!(receipts["cli/sleep-0.2"].bench.name, "cli/sleep-0.2");
+        assert_eq!(receipts["cli/sleep-0.2"].stats.wall_ms.median, 200);
+    }
+
+    #[test]
+    fn test_ingest_all_rejects_colliding_names() {
+        let input = r#"{"results": [
+            {"command": "sleep 1", "times": [1.0], "mean": 1.0, "stddev": 0.0,
+             "median": 1.0, "min": 1.0, "max": 1.0},
+            {"command": "Sleep  1", "times": [1.0], "mean": 1.0, "stddev": 0.0,
+             "median": 1.0, "min": 1.0, "max": 1.0}
+        ]}"#;
+        let request = IngestRequest {
+            format: IngestFormat::Hyperfine,
+            input: input.to_string(),
+            name: None,
+            include_spans: Vec::new(),
+            exclude_spans: Vec::new(),
+            mapping: None,
+        };
+        let err = ingest_all(&request).unwrap_err().to_string();
+        assert!(err.contains("both map to bench name \"sleep-1\""), "{err}");
+    }
+
     #[test]
     fn test_compute_u64_summary_basic() {
         let values = vec![100, 200, 300, 400, 500];
@@ -323,6 +754,19 @@ mod tests {
             binary_bytes: None,
             stdout: None,
             stderr: None,
+            latency_p50_us: None,
+            latency_p95_us: None,
+            latency_p99_us: None,
+            error_rate: None,
+            throughput_per_s: None,
+            time_to_ready_ms: None,
+            instructions: None,
+            estimated_cycles: None,
+            l1_hits: None,
+            ll_hits: None,
+            ram_hits: None,
+            alloc_bytes: None,
+            allocs: None,
         }];
         let stats = Stats {
             wall_ms: U64Summary::new(100, 100, 100),
@@ -336,6 +780,19 @@ mod tests {
             energy_uj: None,
             binary_bytes: None,
             throughput_per_s: None,
+            latency_p50_us: None,
+            latency_p95_us: None,
+            latency_p99_us: None,
+            error_rate: None,
+            time_to_ready_ms: None,
+            instructions: None,
+            estimated_cycles: None,
+            l1_hits: None,
+            ll_hits: None,
+            ram_hits: None,
+            alloc_bytes: None,
+            allocs: None,
+            custom: Default::default(),
         };
         let receipt = make_receipt("test-bench", samples, stats);
         assert_eq!(receipt.schema, RUN_SCHEMA_V1);
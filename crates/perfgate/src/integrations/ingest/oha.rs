@@ -0,0 +1,127 @@
+//! Parser for oha JSON output (`oha --output-format json`, or `-j` on older
+//! releases).
+//!
+//! All times are seconds. `latencyPercentiles` maps to the latency
+//! percentiles and `summary.total` to `wall_ms`. oha counts any response as
+//! a success, so `error_rate` is recomputed the way perfgate's own load
+//! benches count it: transport errors from `errorDistribution` plus
+//! responses with status >= 400. `throughput_per_s` is successful requests
+//! per second. The whole run is one sample.
+
+use std::collections::BTreeMap;
+
+use anyhow::Context;
+use perfgate_types::RunReceipt;
+use serde::Deserialize;
+
+use super::{LoadRun, load_receipt};
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct OhaOutput {
+    summary: OhaSummary,
+    #[serde(default)]
+    latency_percentiles: BTreeMap<String, Option<f64>>,
+    #[serde(default)]
+    status_code_distribution: BTreeMap<String, u64>,
+    #[serde(default)]
+    error_distribution: BTreeMap<String, u64>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct OhaSummary {
+    /// Fraction of requests that got a response, whatever its status.
+    success_rate: f64,
+    /// Run duration in seconds.
+    total: f64,
+    requests_per_sec: f64,
+}
+
+/// Parse oha JSON into a `RunReceipt` named `name`, or `oha`.
+pub fn parse_oha(input: &str, name: Option<&str>) -> anyhow::Result<RunReceipt> {
+    let output: OhaOutput = serde_json::from_str(input).context("failed to parse oha JSON")?;
+
+    let responses: u64 = output.status_code_distribution.values().sum();
+    let failed_responses: u64 = output
+        .status_code_distribution
+        .iter()
+        .filter(|(status, _)| status.parse::<u16>().is_ok_and(|status| status >= 400))
+        .map(|(_, count)| count)
+        .sum();
+    let transport_errors: u64 = output.error_distribution.values().sum();
+    let total = responses + transport_errors;
+    let error_rate = if total > 0 {
+        (failed_responses + transport_errors) as f64 / total as f64
+    } else {
+        1.0 - output.summary.success_rate
+    };
+
+    let percentile_us = |key: &str| {
+        output
+            .latency_percentiles
+            .get(key)
+            .copied()
+            .flatten()
+            .map(|seconds| seconds * 1_000_000.0)
+    };
+    let run = LoadRun {
+        wall_ms: output.summary.total * 1000.0,
+        latency_p50_us: percentile_us("p50"),
+        latency_p95_us: percentile_us("p95"),
+        latency_p99_us: percentile_us("p99"),
+        error_rate: Some(error_rate),
+        throughput_per_s: Some(output.summary.requests_per_sec * (1.0 - error_rate)),
+    };
+
+    let mut receipt = load_receipt(name.unwrap_or("oha"), &run)?;
+    receipt.bench.command = vec![format!("(oha: {total} requests)")];
+    Ok(receipt)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OHA_JSON: &str = r#"{
+      "summary": {"successRate": 0.99, "total": 10.0, "slowest": 0.2, "fastest": 0.001,
+                  "average": 0.012, "requestsPerSec": 100.0, "totalData": 1000, "sizePerRequest": 1, "sizePerSec": 100.0},
+      "responseTimeHistogram": {"0.001": 10},
+      "latencyPercentiles": {"p10": 0.002, "p50": 0.0105, "p90": 0.03, "p95": 0.04, "p99": 0.09, "p99.9": 0.15},
+      "rps": {"mean": 100.0},
+      "details": {"DNSDialup": {"average": 0.001}},
+      "statusCodeDistribution": {"200": 980, "503": 10},
+      "errorDistribution": {"connection closed before message completed": 10}
+    }"#;
+
+    #[test]
+    fn parses_oha_json() {
+        let receipt = parse_oha(OHA_JSON, Some("api")).unwrap();
+
+        assert_eq!(receipt.bench.name, "api");
+        assert_eq!(receipt.bench.command, vec!["(oha: 1000 requests)"]);
+        let sample = &receipt.samples[0];
+        assert_eq!(sample.wall_ms, 10_000);
+        assert_eq!(sample.latency_p50_us, Some(10_500));
+        assert_eq!(sample.latency_p95_us, Some(40_000));
+        assert_eq!(sample.latency_p99_us, Some(90_000));
+        // 10 transport errors and 10 status 503 responses out of 1000.
+        assert_eq!(sample.error_rate, Some(0.02));
+        assert_eq!(sample.throughput_per_s, Some(98.0));
+    }
+
+    #[test]
+    fn falls_back_to_success_rate_without_distributions() {
+        let input = r#"{
+          "summary": {"successRate": 0.75, "total": 2.0, "requestsPerSec": 40.0},
+          "latencyPercentiles": {"p50": null, "p95": null, "p99": null}
+        }"#;
+        let receipt = parse_oha(input, None).unwrap();
+
+        assert_eq!(receipt.bench.name, "oha");
+        let sample = &receipt.samples[0];
+        assert_eq!(sample.latency_p50_us, None);
+        assert_eq!(sample.error_rate, Some(0.25));
+        assert_eq!(sample.throughput_per_s, Some(30.0));
+    }
+}
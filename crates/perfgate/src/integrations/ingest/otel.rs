@@ -1,8 +1,11 @@
+use std::collections::{BTreeMap, HashMap};
+
 use anyhow::{Context, anyhow};
 use serde::Deserialize;
+use time::OffsetDateTime;
 
-use super::{compute_u64_summary, make_receipt};
-use perfgate_types::{Sample, Stats};
+use super::{compute_u64_summary, make_probe_receipt, make_receipt, otlp_proto};
+use perfgate_types::{ProbeMetricValue, ProbeObservation, ProbeReceipt, Sample, Stats};
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -28,9 +31,85 @@ struct ScopeSpans {
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct Span {
+    #[serde(default)]
+    trace_id: String,
+    #[serde(default)]
+    span_id: String,
+    #[serde(default)]
+    parent_span_id: String,
     name: String,
     start_time_unix_nano: String,
     end_time_unix_nano: String,
+    #[serde(default)]
+    attributes: Vec<KeyValue>,
+}
+
+#[derive(Debug, Deserialize)]
+struct KeyValue {
+    key: String,
+    #[serde(default)]
+    value: AnyValue,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AnyValue {
+    string_value: Option<String>,
+    bool_value: Option<bool>,
+    // OTLP JSON encodes int64 as a string, but numbers are accepted too.
+    int_value: Option<serde_json::Value>,
+    double_value: Option<f64>,
+}
+
+impl AnyValue {
+    fn into_string(self) -> Option<String> {
+        if let Some(text) = self.string_value {
+            return Some(text);
+        }
+        if let Some(flag) = self.bool_value {
+            return Some(flag.to_string());
+        }
+        if let Some(int) = self.int_value {
+            return Some(int.as_str().map_or_else(|| int.to_string(), str::to_string));
+        }
+        self.double_value.map(|double| double.to_string())
+    }
+}
+
+/// A span decoded from either OTLP JSON or OTLP protobuf.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(super) struct TraceSpan {
+    pub(super) trace_id: String,
+    pub(super) span_id: String,
+    pub(super) parent_span_id: String,
+    pub(super) name: String,
+    pub(super) start_ns: u64,
+    pub(super) end_ns: u64,
+    /// Scalar attributes rendered as strings.
+    pub(super) attributes: BTreeMap<String, String>,
+}
+
+impl TraceSpan {
+    fn duration_ns(&self) -> u64 {
+        self.end_ns - self.start_ns
+    }
+}
+
+/// Request to ingest an OTLP trace export into a `perfgate.probe.v1` receipt.
+pub struct OtelProbeIngestRequest {
+    /// Raw trace export: OTLP JSON, or binary OTLP protobuf (`TracesData` or
+    /// `ExportTraceServiceRequest`).
+    pub input: Vec<u8>,
+    /// Optional benchmark name to attach as receipt metadata.
+    pub bench: Option<String>,
+    /// Optional scenario name to attach as receipt metadata.
+    pub scenario: Option<String>,
+    /// Span attribute keys whose values split a span name into separate probes.
+    pub group_by: Vec<String>,
+    /// Span names to keep (exact match); empty keeps every span.
+    pub include_spans: Vec<String>,
+    /// Span names to drop (exact match).
+    pub exclude_spans: Vec<String>,
 }
 
 pub fn parse_otel_json(
@@ -39,25 +118,138 @@ pub fn parse_otel_json(
     include_spans: &[String],
     exclude_spans: &[String],
 ) -> anyhow::Result<perfgate_types::RunReceipt> {
+    let durations_ms: Vec<u64> = span_durations(input, include_spans, exclude_spans)?
+        .into_iter()
+        .map(|(_, duration_ms)| duration_ms)
+        .collect();
+
+    let bench_name = name
+        .map(ToOwned::to_owned)
+        .unwrap_or_else(|| "otel-spans".to_string());
+
+    Ok(durations_to_receipt(&bench_name, &durations_ms))
+}
+
+/// Parse an OTel JSON trace export into one `RunReceipt` per span name, with
+/// one sample per span.
+pub fn parse_otel_json_all(
+    input: &str,
+    include_spans: &[String],
+    exclude_spans: &[String],
+) -> anyhow::Result<Vec<perfgate_types::RunReceipt>> {
+    let mut by_name: BTreeMap<String, Vec<u64>> = BTreeMap::new();
+    for (name, duration_ms) in span_durations(input, include_spans, exclude_spans)? {
+        by_name.entry(name).or_default().push(duration_ms);
+    }
+    Ok(by_name
+        .iter()
+        .map(|(name, durations_ms)| durations_to_receipt(name, durations_ms))
+        .collect())
+}
+
+/// Ingest an OTLP trace export as one probe observation per span.
+///
+/// Each observation carries `wall_ms` (span duration) and `self_ms` (duration
+/// not covered by direct child spans). The probe name is the span name,
+/// suffixed with `[key=value,...]` for the `group_by` attributes the span has.
+pub fn ingest_otel_probes(request: &OtelProbeIngestRequest) -> anyhow::Result<ProbeReceipt> {
+    let spans = decode_spans(&request.input)?;
+
+    let by_id: HashMap<(&str, &str), usize> = spans
+        .iter()
+        .enumerate()
+        .filter(|(_, span)| !span.span_id.is_empty())
+        .map(|(index, span)| ((span.trace_id.as_str(), span.span_id.as_str()), index))
+        .collect();
+    let parent_of = |span: &TraceSpan| {
+        by_id
+            .get(&(span.trace_id.as_str(), span.parent_span_id.as_str()))
+            .copied()
+    };
+    let mut children: HashMap<usize, Vec<usize>> = HashMap::new();
+    for (index, span) in spans.iter().enumerate() {
+        if let Some(parent) = parent_of(span) {
+            children.entry(parent).or_default().push(index);
+        }
+    }
+
+    let mut probes = Vec::new();
+    for (index, span) in spans.iter().enumerate() {
+        if !span_selected(&span.name, &request.include_spans, &request.exclude_spans) {
+            continue;
+        }
+
+        let child_intervals: Vec<(u64, u64)> = children
+            .get(&index)
+            .into_iter()
+            .flatten()
+            .map(|child| (spans[*child].start_ns, spans[*child].end_ns))
+            .collect();
+        let self_ns = span.duration_ns() - covered_ns(span, child_intervals);
+
+        let mut metrics = BTreeMap::new();
+        metrics.insert("wall_ms".to_string(), ms_metric(span.duration_ns()));
+        metrics.insert("self_ms".to_string(), ms_metric(self_ns));
+
+        let attributes: BTreeMap<String, String> = request
+            .group_by
+            .iter()
+            .filter_map(|key| {
+                span.attributes
+                    .get(key)
+                    .map(|value| (key.clone(), value.clone()))
+            })
+            .collect();
+
+        probes.push(ProbeObservation {
+            name: probe_name(span, &request.group_by),
+            parent: parent_of(span).map(|parent| probe_name(&spans[parent], &request.group_by)),
+            scope: None,
+            iteration: None,
+            started_at: format_unix_nanos(span.start_ns),
+            ended_at: format_unix_nanos(span.end_ns),
+            items: None,
+            metrics,
+            attributes,
+        });
+    }
+
+    if probes.is_empty() {
+        return Err(anyhow!(
+            "no spans available for probe ingest: {}",
+            no_spans_hint(&request.include_spans)
+        ));
+    }
+
+    Ok(make_probe_receipt(
+        request.bench.as_deref(),
+        request.scenario.clone(),
+        probes,
+    ))
+}
+
+/// Decode OTLP JSON or OTLP protobuf; JSON is recognised by its leading `{`.
+fn decode_spans(input: &[u8]) -> anyhow::Result<Vec<TraceSpan>> {
+    if input.trim_ascii_start().starts_with(b"{") {
+        let text = std::str::from_utf8(input).context("OTel JSON trace export is not UTF-8")?;
+        json_spans(text)
+    } else {
+        otlp_proto::decode_traces(input).context("failed to parse OTLP protobuf trace export")
+    }
+}
+
+fn json_spans(input: &str) -> anyhow::Result<Vec<TraceSpan>> {
     let trace: OTelTrace =
         serde_json::from_str(input).context("failed to parse OTel JSON trace export")?;
 
-    let mut durations_ms = Vec::new();
-
+    let mut spans = Vec::new();
     for resource in trace.resource_spans {
         for scope in resource.scope_spans {
             for span in scope.spans {
-                if !include_spans.is_empty() && !include_spans.iter().any(|s| s == &span.name) {
-                    continue;
-                }
-                if exclude_spans.iter().any(|s| s == &span.name) {
-                    continue;
-                }
-
-                let start_ns: u128 = span.start_time_unix_nano.parse().with_context(|| {
+                let start_ns: u64 = span.start_time_unix_nano.parse().with_context(|| {
                     format!("invalid start_time_unix_nano for span '{}'", span.name)
                 })?;
-                let end_ns: u128 = span.end_time_unix_nano.parse().with_context(|| {
+                let end_ns: u64 = span.end_time_unix_nano.parse().with_context(|| {
                     format!("invalid end_time_unix_nano for span '{}'", span.name)
                 })?;
 
@@ -68,28 +260,116 @@ pub fn parse_otel_json(
                     ));
                 }
 
-                let duration_ns = end_ns - start_ns;
-                let duration_ms = (duration_ns / 1_000_000) as u64;
-                durations_ms.push(duration_ms);
+                spans.push(TraceSpan {
+                    trace_id: span.trace_id,
+                    span_id: span.span_id,
+                    parent_span_id: span.parent_span_id,
+                    name: span.name,
+                    start_ns,
+                    end_ns,
+                    attributes: span
+                        .attributes
+                        .into_iter()
+                        .filter_map(|kv| kv.value.into_string().map(|value| (kv.key, value)))
+                        .collect(),
+                });
             }
         }
     }
+    Ok(spans)
+}
+
+fn span_selected(name: &str, include_spans: &[String], exclude_spans: &[String]) -> bool {
+    (include_spans.is_empty() || include_spans.iter().any(|s| s == name))
+        && !exclude_spans.iter().any(|s| s == name)
+}
+
+fn no_spans_hint(include_spans: &[String]) -> String {
+    if include_spans.is_empty() {
+        "no spans found in the OTel export".to_string()
+    } else {
+        format!(
+            "no spans matched include filter [{}]",
+            include_spans.join(", ")
+        )
+    }
+}
+
+/// Nanoseconds of `span` covered by the union of its children, clipped to the
+/// span itself so overlapping or escaping children are not double-counted.
+fn covered_ns(span: &TraceSpan, mut intervals: Vec<(u64, u64)>) -> u64 {
+    intervals.sort_unstable();
+    let mut covered = 0;
+    let mut cursor = span.start_ns;
+    for (start, end) in intervals {
+        let start = start.max(cursor);
+        let end = end.min(span.end_ns);
+        if end > start {
+            covered += end - start;
+            cursor = end;
+        }
+    }
+    covered
+}
+
+fn probe_name(span: &TraceSpan, group_by: &[String]) -> String {
+    let groups: Vec<String> = group_by
+        .iter()
+        .filter_map(|key| {
+            span.attributes
+                .get(key)
+                .map(|value| format!("{key}={value}"))
+        })
+        .collect();
+    if groups.is_empty() {
+        span.name.clone()
+    } else {
+        format!("{}[{}]", span.name, groups.join(","))
+    }
+}
+
+fn ms_metric(nanos: u64) -> ProbeMetricValue {
+    ProbeMetricValue {
+        value: nanos as f64 / 1_000_000.0,
+        unit: Some("ms".to_string()),
+        statistic: None,
+    }
+}
+
+fn format_unix_nanos(nanos: u64) -> Option<String> {
+    OffsetDateTime::from_unix_timestamp_nanos(i128::from(nanos))
+        .ok()?
+        .format(&time::format_description::well_known::Rfc3339)
+        .ok()
+}
+
+/// Durations in milliseconds of the spans that pass the filters, with their
+/// span names.
+fn span_durations(
+    input: &str,
+    include_spans: &[String],
+    exclude_spans: &[String],
+) -> anyhow::Result<Vec<(String, u64)>> {
+    let durations_ms: Vec<(String, u64)> = json_spans(input)?
+        .into_iter()
+        .filter(|span| span_selected(&span.name, include_spans, exclude_spans))
+        .map(|span| {
+            let duration_ms = span.duration_ns() / 1_000_000;
+            (span.name, duration_ms)
+        })
+        .collect();
 
     if durations_ms.is_empty() {
-        let include_hint = if include_spans.is_empty() {
-            "no spans found in the OTel export".to_string()
-        } else {
-            format!(
-                "no spans matched include filter [{}]",
-                include_spans.join(", ")
-            )
-        };
         return Err(anyhow!(
             "no span durations available for ingest: {}",
-            include_hint
+            no_spans_hint(include_spans)
         ));
     }
 
+    Ok(durations_ms)
+}
+
+fn durations_to_receipt(name: &str, durations_ms: &[u64]) -> perfgate_types::RunReceipt {
     let samples: Vec<Sample> = durations_ms
         .iter()
         .map(|wall_ms| Sample {
@@ -108,11 +388,24 @@ pub fn parse_otel_json(
             binary_bytes: None,
             stdout: None,
             stderr: None,
+            latency_p50_us: None,
+            latency_p95_us: None,
+            latency_p99_us: None,
+            error_rate: None,
+            throughput_per_s: None,
+            time_to_ready_ms: None,
+            instructions: None,
+            estimated_cycles: None,
+            l1_hits: None,
+            ll_hits: None,
+            ram_hits: None,
+            alloc_bytes: None,
+            allocs: None,
         })
         .collect();
 
     let stats = Stats {
-        wall_ms: compute_u64_summary(&durations_ms),
+        wall_ms: compute_u64_summary(durations_ms),
         cpu_ms: None,
         page_faults: None,
         ctx_switches: None,
@@ -123,13 +416,22 @@ pub fn parse_otel_json(
         energy_uj: None,
         binary_bytes: None,
         throughput_per_s: None,
+        latency_p50_us: None,
+        latency_p95_us: None,
+        latency_p99_us: None,
+        error_rate: None,
+        time_to_ready_ms: None,
+        instructions: None,
+        estimated_cycles: None,
+        l1_hits: None,
+        ll_hits: None,
+        ram_hits: None,
+        alloc_bytes: None,
+        allocs: None,
+        custom: Default::default(),
     };
 
-    let bench_name = name
-        .map(ToOwned::to_owned)
-        .unwrap_or_else(|| "otel-spans".to_string());
-
-    Ok(make_receipt(&bench_name, samples, stats))
+    make_receipt(name, samples, stats)
 }
 
 #[cfg(test)]
@@ -168,6 +470,20 @@ mod tests {
         assert_eq!(receipt.stats.wall_ms.max, 70);
     }
 
+    #[test]
+    fn ingest_otel_all_splits_by_span_name() {
+        let receipts =
+            parse_otel_json_all(TRACE_JSON, &[], &["resolve_imports".to_string()]).unwrap();
+        assert_eq!(receipts.len(), 1);
+        assert_eq!(receipts[0].bench.name, "ast_parsing");
+        assert_eq!(receipts[0].samples.len(), 2);
+
+        let receipts = parse_otel_json_all(TRACE_JSON, &[], &[]).unwrap();
+        let names: Vec<&str> = receipts.iter().map(|r| r.bench.name.as_str()).collect();
+        assert_eq!(names, ["ast_parsing", "resolve_imports"]);
+        assert_eq!(receipts[1].stats.wall_ms.median, 60);
+    }
+
     #[test]
     fn ingest_otel_missing_span_returns_error() {
         let err = parse_otel_json(TRACE_JSON, None, &["does_not_exist".to_string()], &[])
@@ -178,4 +494,115 @@ mod tests {
             "unexpected error: {err}"
         );
     }
+
+    const NESTED_TRACE_JSON: &str = r#"{
+      "resourceSpans": [{"scopeSpans": [{"spans": [
+        {"traceId": "t1", "spanId": "a", "name": "GET", "startTimeUnixNano": "0", "endTimeUnixNano": "100000000",
+         "attributes": [{"key": "http.route", "value": {"stringValue": "/users/{id}"}}]},
+        {"traceId": "t1", "spanId": "b", "parentSpanId": "a", "name": "db.query", "startTimeUnixNano": "10000000", "endTimeUnixNano": "40000000",
+         "attributes": [{"key": "db.operation", "value": {"stringValue": "SELECT"}}, {"key": "db.rows", "value": {"intValue": "3"}}]},
+        {"traceId": "t1", "spanId": "c", "parentSpanId": "a", "name": "db.query", "startTimeUnixNano": "30000000", "endTimeUnixNano": "60000000",
+         "attributes": [{"key": "db.operation", "value": {"stringValue": "UPDATE"}}]}
+      ]}]}]
+    }"#;
+
+    fn probe_request(input: impl Into<Vec<u8>>, group_by: &[&str]) -> OtelProbeIngestRequest {
+        OtelProbeIngestRequest {
+            input: input.into(),
+            bench: Some("api".to_string()),
+            scenario: None,
+            group_by: group_by.iter().map(|key| key.to_string()).collect(),
+            include_spans: Vec::new(),
+            exclude_spans: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn otel_probes_compute_self_time_from_children() {
+        let receipt = ingest_otel_probes(&probe_request(NESTED_TRACE_JSON, &[])).unwrap();
+
+        assert_eq!(receipt.schema, perfgate_types::PROBE_SCHEMA_V1);
+        assert_eq!(receipt.probes.len(), 3);
+        let root = &receipt.probes[0];
+        assert_eq!(root.name, "GET");
+        assert_eq!(root.parent, None);
+        assert_eq!(root.metrics["wall_ms"].value, 100.0);
+        // Overlapping children cover 10..60 ms once.
+        assert_eq!(root.metrics["self_ms"].value, 50.0);
+        assert_eq!(root.metrics["self_ms"].unit.as_deref(), Some("ms"));
+        assert_eq!(root.started_at.as_deref(), Some("1970-01-01T00:00:00Z"));
+
+        let child = &receipt.probes[1];
+        assert_eq!(child.parent.as_deref(), Some("GET"));
+        assert_eq!(child.metrics["wall_ms"].value, 30.0);
+        assert_eq!(child.metrics["self_ms"].value, 30.0);
+    }
+
+    #[test]
+    fn otel_probes_group_by_span_attributes() {
+        let receipt = ingest_otel_probes(&probe_request(
+            NESTED_TRACE_JSON,
+            &["http.route", "db.operation"],
+        ))
+        .unwrap();
+
+        let names: Vec<&str> = receipt.probes.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(
+            names,
+            [
+                "GET[http.route=/users/{id}]",
+                "db.query[db.operation=SELECT]",
+                "db.query[db.operation=UPDATE]",
+            ]
+        );
+        assert_eq!(
+            receipt.probes[1].parent.as_deref(),
+            Some("GET[http.route=/users/{id}]")
+        );
+        assert_eq!(receipt.probes[1].attributes["db.operation"], "SELECT");
+        assert!(!receipt.probes[1].attributes.contains_key("db.rows"));
+    }
+
+    #[test]
+    fn otel_probes_read_binary_protobuf() {
+        let mut root = TraceSpan {
+            trace_id: "0af7651916cd43dd8448eb211c80319c".to_string(),
+            span_id: "b7ad6b7169203331".to_string(),
+            name: "GET".to_string(),
+            start_ns: 1_000_000_000,
+            end_ns: 1_080_000_000,
+            ..TraceSpan::default()
+        };
+        root.attributes
+            .insert("http.route".to_string(), "/health".to_string());
+        let child = TraceSpan {
+            span_id: "00f067aa0ba902b7".to_string(),
+            parent_span_id: root.span_id.clone(),
+            name: "db.query".to_string(),
+            start_ns: 1_010_000_000,
+            end_ns: 1_030_000_000,
+            attributes: BTreeMap::new(),
+            ..root.clone()
+        };
+
+        let mut request = probe_request(otlp_proto::encode_traces(&[root, child]), &["http.route"]);
+        request.exclude_spans = vec!["db.query".to_string()];
+        let receipt = ingest_otel_probes(&request).unwrap();
+
+        assert_eq!(receipt.probes.len(), 1);
+        assert_eq!(receipt.probes[0].name, "GET[http.route=/health]");
+        assert_eq!(receipt.probes[0].metrics["wall_ms"].value, 80.0);
+        assert_eq!(receipt.probes[0].metrics["self_ms"].value, 60.0);
+    }
+
+    #[test]
+    fn otel_probes_without_matching_spans_fail() {
+        let mut request = probe_request(NESTED_TRACE_JSON, &[]);
+        request.include_spans = vec!["missing".to_string()];
+        let err = ingest_otel_probes(&request).unwrap_err();
+        assert!(
+            err.to_string().contains("no spans matched include filter"),
+            "unexpected error: {err}"
+        );
+    }
 }
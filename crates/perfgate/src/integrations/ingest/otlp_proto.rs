@@ -0,0 +1,296 @@
+//! Minimal decoder for binary OTLP trace exports.
+//!
+//! Reads `TracesData` and `ExportTraceServiceRequest` messages (both carry
+//! `ResourceSpans` in field 1) and keeps only the span fields perfgate uses.
+//! Unknown fields are skipped, as protobuf readers are expected to do.
+
+use anyhow::{Context, anyhow, bail};
+
+use super::otel::TraceSpan;
+
+enum Field<'a> {
+    Varint(u64),
+    Fixed64(u64),
+    Bytes(&'a [u8]),
+    Fixed32,
+}
+
+struct Reader<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(buf: &'a [u8]) -> Self {
+        Self { buf, pos: 0 }
+    }
+
+    fn next_field(&mut self) -> anyhow::Result<Option<(u64, Field<'a>)>> {
+        if self.pos >= self.buf.len() {
+            return Ok(None);
+        }
+        let key = self.varint()?;
+        let number = key >> 3;
+        let field = match key & 0x7 {
+            0 => Field::Varint(self.varint()?),
+            1 => Field::Fixed64(u64::from_le_bytes(self.take(8)?.try_into()?)),
+            2 => {
+                let len = usize::try_from(self.varint()?)?;
+                Field::Bytes(self.take(len)?)
+            }
+            5 => {
+                self.take(4)?;
+                Field::Fixed32
+            }
+            wire => bail!("unsupported protobuf wire type {wire} for field {number}"),
+        };
+        Ok(Some((number, field)))
+    }
+
+    fn varint(&mut self) -> anyhow::Result<u64> {
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = *self
+                .buf
+                .get(self.pos)
+                .ok_or_else(|| anyhow!("truncated protobuf varint"))?;
+            self.pos += 1;
+            value |= u64::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        bail!("protobuf varint is longer than 10 bytes")
+    }
+
+    fn take(&mut self, len: usize) -> anyhow::Result<&'a [u8]> {
+        let end = self
+            .pos
+            .checked_add(len)
+            .filter(|end| *end <= self.buf.len())
+            .ok_or_else(|| anyhow!("truncated protobuf message"))?;
+        let bytes = &self.buf[self.pos..end];
+        self.pos = end;
+        Ok(bytes)
+    }
+}
+
+/// Decode every span in a binary OTLP trace export.
+pub(super) fn decode_traces(buf: &[u8]) -> anyhow::Result<Vec<TraceSpan>> {
+    let mut spans = Vec::new();
+    let mut reader = Reader::new(buf);
+    while let Some((number, field)) = reader.next_field()? {
+        if let (1, Field::Bytes(resource_spans)) = (number, field) {
+            decode_resource_spans(resource_spans, &mut spans)?;
+        }
+    }
+    Ok(spans)
+}
+
+fn decode_resource_spans(buf: &[u8], spans: &mut Vec<TraceSpan>) -> anyhow::Result<()> {
+    let mut reader = Reader::new(buf);
+    while let Some((number, field)) = reader.next_field()? {
+        if let (2, Field::Bytes(scope_spans)) = (number, field) {
+            let mut scope = Reader::new(scope_spans);
+            while let Some((number, field)) = scope.next_field()? {
+                if let (2, Field::Bytes(span)) = (number, field) {
+                    spans.push(decode_span(span)?);
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn decode_span(buf: &[u8]) -> anyhow::Result<TraceSpan> {
+    let mut span = TraceSpan::default();
+    let mut reader = Reader::new(buf);
+    while let Some((number, field)) = reader.next_field()? {
+        match (number, field) {
+            (1, Field::Bytes(id)) => span.trace_id = hex(id),
+            (2, Field::Bytes(id)) => span.span_id = hex(id),
+            (4, Field::Bytes(id)) => span.parent_span_id = hex(id),
+            (5, Field::Bytes(name)) => span.name = utf8(name).context("span name")?,
+            (7, Field::Fixed64(nanos)) => span.start_ns = nanos,
+            (8, Field::Fixed64(nanos)) => span.end_ns = nanos,
+            (9, Field::Bytes(key_value)) => {
+                if let Some((key, value)) = decode_key_value(key_value)? {
+                    span.attributes.insert(key, value);
+                }
+            }
+            _ => {}
+        }
+    }
+    if span.end_ns < span.start_ns {
+        bail!(
+            "span '{}' has end_time_unix_nano earlier than start_time_unix_nano",
+            span.name
+        );
+    }
+    Ok(span)
+}
+
+/// Decode a `KeyValue`, keeping scalar values only.
+fn decode_key_value(buf: &[u8]) -> anyhow::Result<Option<(String, String)>> {
+    let mut key = None;
+    let mut value = None;
+    let mut reader = Reader::new(buf);
+    while let Some((number, field)) = reader.next_field()? {
+        match (number, field) {
+            (1, Field::Bytes(bytes)) => key = Some(utf8(bytes).context("attribute key")?),
+            (2, Field::Bytes(any_value)) => value = decode_any_value(any_value)?,
+            _ => {}
+        }
+    }
+    Ok(key.zip(value))
+}
+
+fn decode_any_value(buf: &[u8]) -> anyhow::Result<Option<String>> {
+    let mut value = None;
+    let mut reader = Reader::new(buf);
+    while let Some((number, field)) = reader.next_field()? {
+        value = match (number, field) {
+            (1, Field::Bytes(text)) => Some(utf8(text).context("attribute value")?),
+            (2, Field::Varint(flag)) => Some((flag != 0).to_string()),
+            (3, Field::Varint(int)) => Some((int as i64).to_string()),
+            (4, Field::Fixed64(bits)) => Some(f64::from_bits(bits).to_string()),
+            _ => value,
+        };
+    }
+    Ok(value)
+}
+
+fn utf8(bytes: &[u8]) -> anyhow::Result<String> {
+    String::from_utf8(bytes.to_vec()).context("invalid UTF-8 in OTLP protobuf string")
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+/// Build spans as `TracesData` protobuf bytes, for tests.
+#[cfg(test)]
+pub(super) fn encode_traces(spans: &[TraceSpan]) -> Vec<u8> {
+    fn key(out: &mut Vec<u8>, number: u64, wire: u64) {
+        varint(out, (number << 3) | wire);
+    }
+    fn varint(out: &mut Vec<u8>, mut value: u64) {
+        while value >= 0x80 {
+            out.push((value as u8) | 0x80);
+            value >>= 7;
+        }
+        out.push(value as u8);
+    }
+    fn bytes(out: &mut Vec<u8>, number: u64, value: &[u8]) {
+        key(out, number, 2);
+        varint(out, value.len() as u64);
+        out.extend_from_slice(value);
+    }
+    fn unhex(id: &str) -> Vec<u8> {
+        (0..id.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&id[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    let mut scope = Vec::new();
+    for span in spans {
+        let mut encoded = Vec::new();
+        bytes(&mut encoded, 1, &unhex(&span.trace_id));
+        bytes(&mut encoded, 2, &unhex(&span.span_id));
+        if !span.parent_span_id.is_empty() {
+            bytes(&mut encoded, 4, &unhex(&span.parent_span_id));
+        }
+        bytes(&mut encoded, 5, span.name.as_bytes());
+        key(&mut encoded, 6, 0);
+        varint(&mut encoded, 2);
+        key(&mut encoded, 7, 1);
+        encoded.extend_from_slice(&span.start_ns.to_le_bytes());
+        key(&mut encoded, 8, 1);
+        encoded.extend_from_slice(&span.end_ns.to_le_bytes());
+        for (attr_key, attr_value) in &span.attributes {
+            let mut any_value = Vec::new();
+            bytes(&mut any_value, 1, attr_value.as_bytes());
+            let mut key_value = Vec::new();
+            bytes(&mut key_value, 1, attr_key.as_bytes());
+            bytes(&mut key_value, 2, &any_value);
+            bytes(&mut encoded, 9, &key_value);
+        }
+        bytes(&mut scope, 2, &encoded);
+    }
+    let mut resource = Vec::new();
+    bytes(&mut resource, 2, &scope);
+    let mut traces = Vec::new();
+    bytes(&mut traces, 1, &resource);
+    traces
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    fn span(span_id: &str, parent: &str, name: &str, start_ns: u64, end_ns: u64) -> TraceSpan {
+        TraceSpan {
+            trace_id: "0af7651916cd43dd8448eb211c80319c".to_string(),
+            span_id: span_id.to_string(),
+            parent_span_id: parent.to_string(),
+            name: name.to_string(),
+            start_ns,
+            end_ns,
+            attributes: BTreeMap::new(),
+        }
+    }
+
+    #[test]
+    fn decodes_spans_and_scalar_attributes() {
+        let mut root = span("b7ad6b7169203331", "", "GET", 1_000, 9_000);
+        root.attributes
+            .insert("http.route".to_string(), "/users/{id}".to_string());
+        let child = span(
+            "00f067aa0ba902b7",
+            "b7ad6b7169203331",
+            "SELECT",
+            2_000,
+            5_000,
+        );
+
+        let decoded = decode_traces(&encode_traces(&[root.clone(), child.clone()])).unwrap();
+        assert_eq!(decoded, vec![root, child]);
+    }
+
+    #[test]
+    fn decodes_non_string_attribute_values() {
+        // KeyValue { key: "n", value: AnyValue { int_value: -3 } }; negative
+        // int64 values are ten-byte two's complement varints.
+        let mut int = vec![0x0a, 0x01, b'n', 0x12, 0x0b, 0x18, 0xfd];
+        int.extend_from_slice(&[0xff; 8]);
+        int.push(0x01);
+        assert_eq!(
+            decode_key_value(&int).unwrap(),
+            Some(("n".to_string(), "-3".to_string()))
+        );
+
+        let double = [
+            0x0a, 0x01, b'r', 0x12, 0x09, 0x21, 0, 0, 0, 0, 0, 0, 0xf8, 0x3f,
+        ];
+        assert_eq!(
+            decode_key_value(&double).unwrap(),
+            Some(("r".to_string(), "1.5".to_string()))
+        );
+
+        let flag = [0x0a, 0x02, b'o', b'k', 0x12, 0x02, 0x10, 0x01];
+        assert_eq!(
+            decode_key_value(&flag).unwrap(),
+            Some(("ok".to_string(), "true".to_string()))
+        );
+    }
+
+    #[test]
+    fn rejects_truncated_messages() {
+        let mut encoded = encode_traces(&[span("b7ad6b7169203331", "", "GET", 1, 2)]);
+        encoded.truncate(encoded.len() - 3);
+        let err = decode_traces(&encoded).unwrap_err();
+        assert!(err.to_string().contains("truncated"), "got: {err}");
+    }
+}
@@ -0,0 +1,449 @@
+//! Parser for `perf stat -x,` CSV output.
+//!
+//! Each counter line is
+//! `value,unit,event[,variance%],run-time,running%,metric-value,metric-unit`,
+//! where the variance column is only present with `perf stat -r N`, and
+//! `value` is the mean over the N runs. Write the CSV with `-o <file>` so the
+//! profiled program's own output does not end up in it.
+//!
+//! Counters map as follows:
+//!
+//! - `duration_time` -> `wall_ms`
+//! - `task-clock` / `cpu-clock` -> `cpu_ms`
+//! - `instructions` -> `instructions`
+//! - `page-faults` / `faults` -> `page_faults`
+//! - `context-switches` / `cs` -> `ctx_switches`
+//! - any other event -> `stats.custom`, keyed by event name
+//!
+//! Several `perf stat` invocations appended to one file (`--append`, or the
+//! same event appearing again) become one sample each. A counter that ran
+//! for less than 100% of the time was multiplexed and perf scaled its value
+//! from a partial count; those are recorded in the receipt's
+//! `run.noise_warnings`, which [`perf_stat_warnings`] also returns.
+
+use std::collections::BTreeMap;
+
+use anyhow::{Context, bail};
+use perfgate_types::{CustomMetric, F64Summary, RunReceipt, Sample, Stats, U64Summary};
+
+use super::{f64_summary, make_receipt, to_u64, to_u64_summary};
+
+/// One counter line of `perf stat -x,` output.
+#[derive(Debug)]
+struct Counter {
+    event: String,
+    /// `None` for `<not counted>` and `<not supported>` counters.
+    value: Option<f64>,
+    unit: String,
+    /// Relative standard deviation across `-r` runs, in percent.
+    variance_pct: Option<f64>,
+    /// Share of the run time the counter was scheduled on the PMU.
+    running_pct: Option<f64>,
+}
+
+/// Parse `perf stat -x,` CSV into a `RunReceipt` named `name`, or
+/// `perf-stat`.
+pub fn parse_perf_stat(input: &str, name: Option<&str>) -> anyhow::Result<RunReceipt> {
+    let runs = parse_runs(input)?;
+
+    let samples: Vec<Sample> = runs
+        .iter()
+        .map(|run| -> anyhow::Result<Sample> {
+            let wall_ms = named_ms(run, &["duration_time"])?.unwrap_or(0.0);
+            Ok(Sample {
+                wall_ms: to_u64(wall_ms),
+                exit_code: 0,
+                warmup: false,
+                timed_out: false,
+                cpu_ms: named_ms(run, &["task-clock", "cpu-clock"])?.map(to_u64),
+                page_faults: named(run, &["page-faults", "faults"]).map(to_u64),
+                ctx_switches: named(run, &["context-switches", "cs"]).map(to_u64),
+                max_rss_kb: None,
+                io_read_bytes: None,
+                io_write_bytes: None,
+                network_packets: None,
+                energy_uj: None,
+                binary_bytes: None,
+                stdout: None,
+                stderr: None,
+                latency_p50_us: None,
+                latency_p95_us: None,
+                latency_p99_us: None,
+                error_rate: None,
+                throughput_per_s: None,
+                time_to_ready_ms: None,
+                instructions: named(run, &["instructions"]).map(to_u64),
+                estimated_cycles: None,
+                l1_hits: None,
+                ll_hits: None,
+                ram_hits: None,
+                alloc_bytes: None,
+                allocs: None,
+            })
+        })
+        .collect::<anyhow::Result<_>>()?;
+
+    // A single `-r N` run carries the spread of its N runs as a variance
+    // percentage; use it as the summary's standard deviation.
+    let summarize = |events: &[&str], scale: f64| -> Option<F64Summary> {
+        let counters: Vec<&Counter> = runs.iter().filter_map(|run| find(run, events)).collect();
+        let values: Vec<f64> = counters
+            .iter()
+            .filter_map(|counter| counter.value.map(|value| value * scale))
+            .collect();
+        if values.is_empty() {
+            return None;
+        }
+        let mut summary = f64_summary(&values);
+        if let ([value], [counter]) = (values.as_slice(), counters.as_slice())
+            && let Some(variance_pct) = counter.variance_pct
+        {
+            summary.stddev = Some(value * variance_pct / 100.0);
+        }
+        Some(summary)
+    };
+    let u64_summary =
+        |events: &[&str], scale: f64| summarize(events, scale).as_ref().map(to_u64_summary);
+    let ms_scale = |events: &[&str]| -> anyhow::Result<f64> {
+        match runs.iter().find_map(|run| find(run, events)) {
+            Some(counter) => unit_ms(counter),
+            None => Ok(1.0),
+        }
+    };
+
+    let wall_ms = u64_summary(&["duration_time"], ms_scale(&["duration_time"])?)
+        .unwrap_or_else(|| U64Summary::new(0, 0, 0));
+
+    let mut custom = BTreeMap::new();
+    for run in &runs {
+        for counter in run.iter().filter(|counter| !is_named(&counter.event)) {
+            if custom.contains_key(&counter.event) {
+                continue;
+            }
+            if let Some(summary) = summarize(&[counter.event.as_str()], 1.0) {
+                let unit = if counter.unit.is_empty() {
+                    "count"
+                } else {
+                    counter.unit.as_str()
+                };
+                custom.insert(
+                    counter.event.clone(),
+                    CustomMetric {
+                        unit: Some(unit.to_string()),
+                        summary,
+                    },
+                );
+            }
+        }
+    }
+
+    let stats = Stats {
+        wall_ms,
+        cpu_ms: u64_summary(
+            &["task-clock", "cpu-clock"],
+            ms_scale(&["task-clock", "cpu-clock"])?,
+        ),
+        page_faults: u64_summary(&["page-faults", "faults"], 1.0),
+        ctx_switches: u64_summary(&["context-switches", "cs"], 1.0),
+        max_rss_kb: None,
+        io_read_bytes: None,
+        io_write_bytes: None,
+        network_packets: None,
+        energy_uj: None,
+        binary_bytes: None,
+        throughput_per_s: None,
+        latency_p50_us: None,
+        latency_p95_us: None,
+        latency_p99_us: None,
+        error_rate: None,
+        time_to_ready_ms: None,
+        instructions: u64_summary(&["instructions"], 1.0),
+        estimated_cycles: None,
+        l1_hits: None,
+        ll_hits: None,
+        ram_hits: None,
+        alloc_bytes: None,
+        allocs: None,
+        custom,
+    };
+
+    let mut receipt = make_receipt(name.unwrap_or("perf-stat"), samples, stats);
+    let mut command = format!("(perf stat: {} run(s)", runs.len());
+    let multiplexed = multiplexed_counters(&runs);
+    if !multiplexed.is_empty() {
+        let counters: Vec<String> = multiplexed
+            .iter()
+            .map(|(event, pct)| format!("{event} {pct:.2}%"))
+            .collect();
+        command.push_str(&format!(", multiplexed: {}", counters.join(", ")));
+    }
+    command.push(')');
+    receipt.bench.command = vec![command];
+    receipt.run.noise_warnings = noise_warnings(&runs);
+    Ok(receipt)
+}
+
+/// Noise warnings for `perf stat -x,` CSV: counters that were multiplexed
+/// (scaled from a partial count) or not counted at all.
+pub fn perf_stat_warnings(input: &str) -> anyhow::Result<Vec<String>> {
+    Ok(noise_warnings(&parse_runs(input)?))
+}
+
+fn noise_warnings(runs: &[Vec<Counter>]) -> Vec<String> {
+    let mut warnings: Vec<String> = multiplexed_counters(runs)
+        .into_iter()
+        .map(|(event, pct)| {
+            format!(
+                "{event} was counted {pct:.2}% of the time (multiplexed); perf scaled its value from a partial count"
+            )
+        })
+        .collect();
+
+    let mut not_counted: Vec<&str> = Vec::new();
+    for counter in runs.iter().flatten() {
+        if counter.value.is_none() && !not_counted.contains(&counter.event.as_str()) {
+            not_counted.push(&counter.event);
+        }
+    }
+    if !not_counted.is_empty() {
+        warnings.push(format!(
+            "not counted or not supported: {}",
+            not_counted.join(", ")
+        ));
+    }
+    warnings
+}
+
+/// Events that ran for less than 100% of the time, with their lowest
+/// running percentage across runs.
+fn multiplexed_counters(runs: &[Vec<Counter>]) -> Vec<(String, f64)> {
+    let mut lowest: Vec<(String, f64)> = Vec::new();
+    for counter in runs.iter().flatten() {
+        let Some(pct) = counter.running_pct.filter(|pct| *pct < 100.0) else {
+            continue;
+        };
+        if counter.value.is_none() {
+            continue;
+        }
+        match lowest.iter_mut().find(|(event, _)| *event == counter.event) {
+            Some((_, min)) => *min = min.min(pct),
+            None => lowest.push((counter.event.clone(), pct)),
+        }
+    }
+    lowest
+}
+
+fn parse_runs(input: &str) -> anyhow::Result<Vec<Vec<Counter>>> {
+    let mut runs: Vec<Vec<Counter>> = vec![Vec::new()];
+    for (index, line) in input.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        if let Some(comment) = line.strip_prefix('#') {
+            if comment.trim_start().starts_with("started on") && !runs[runs.len() - 1].is_empty() {
+                runs.push(Vec::new());
+            }
+            continue;
+        }
+
+        let counter =
+            parse_line(line).with_context(|| format!("perf stat CSV line {}", index + 1))?;
+        if runs[runs.len() - 1]
+            .iter()
+            .any(|seen| seen.event == counter.event)
+        {
+            runs.push(Vec::new());
+        }
+        let last = runs.len() - 1;
+        runs[last].push(counter);
+    }
+
+    runs.retain(|run| !run.is_empty());
+    if runs.is_empty() {
+        bail!("no counters found in perf stat output; was it written with `perf stat -x,`?");
+    }
+    Ok(runs)
+}
+
+fn parse_line(line: &str) -> anyhow::Result<Counter> {
+    let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+    if fields.len() < 3 || fields[2].is_empty() {
+        bail!("expected `value,unit,event,...`, got {line:?}");
+    }
+
+    let value = match fields[0] {
+        "<not counted>" | "<not supported>" => None,
+        raw => Some(
+            raw.parse::<f64>()
+                .with_context(|| format!("invalid counter value {raw:?}"))?,
+        ),
+    };
+
+    let (variance_pct, rest) = match fields.get(3) {
+        Some(field) if field.ends_with('%') => (
+            Some(
+                field
+                    .trim_end_matches('%')
+                    .parse::<f64>()
+                    .with_context(|| format!("invalid variance {field:?}"))?,
+            ),
+            &fields[4..],
+        ),
+        _ => (None, fields.get(3..).unwrap_or_default()),
+    };
+    let running_pct = rest.get(1).and_then(|pct| pct.parse::<f64>().ok());
+
+    Ok(Counter {
+        event: fields[2].to_string(),
+        value,
+        unit: fields[1].to_string(),
+        variance_pct,
+        running_pct,
+    })
+}
+
+/// Event name without `:u`/`:k`-style modifiers.
+fn base_event(event: &str) -> &str {
+    event.split(':').next().unwrap_or(event)
+}
+
+fn is_named(event: &str) -> bool {
+    matches!(
+        base_event(event),
+        "duration_time"
+            | "task-clock"
+            | "cpu-clock"
+            | "instructions"
+            | "page-faults"
+            | "faults"
+            | "context-switches"
+            | "cs"
+    )
+}
+
+fn find<'a>(run: &'a [Counter], events: &[&str]) -> Option<&'a Counter> {
+    run.iter().find(|counter| {
+        events.contains(&counter.event.as_str()) || events.contains(&base_event(&counter.event))
+    })
+}
+
+fn named(run: &[Counter], events: &[&str]) -> Option<f64> {
+    find(run, events).and_then(|counter| counter.value)
+}
+
+fn named_ms(run: &[Counter], events: &[&str]) -> anyhow::Result<Option<f64>> {
+    let Some(counter) = find(run, events) else {
+        return Ok(None);
+    };
+    let scale = unit_ms(counter)?;
+    Ok(counter.value.map(|value| value * scale))
+}
+
+/// Milliseconds per unit of a time counter.
+fn unit_ms(counter: &Counter) -> anyhow::Result<f64> {
+    Ok(match counter.unit.as_str() {
+        "ns" => 1e-6,
+        "us" | "usec" => 1e-3,
+        "ms" | "msec" => 1.0,
+        "s" | "sec" => 1000.0,
+        other => bail!("unsupported time unit {other:?} for {}", counter.event),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REPEATED: &str = "\
+# started on Sat Oct 17 10:00:00 2026
+
+1204.51,msec,task-clock,0.52%,1204510000,100.00,0.998,CPUs utilized
+1320000000,ns,duration_time,0.40%,1320000000,100.00,,
+15,,context-switches,10.00%,1204510000,100.00,12.45,/sec
+812,,page-faults,0.00%,1204510000,100.00,674.14,/sec
+4567890123,,cycles:u,1.10%,602000000,49.98,3.792,GHz
+9876543210,,instructions:u,0.05%,602510000,50.02,2.16,insn per cycle
+<not counted>,,branch-misses,,0,0.00,,
+";
+
+    #[test]
+    fn maps_named_counters_and_custom_events() {
+        let receipt = parse_perf_stat(REPEATED, None).unwrap();
+
+        assert_eq!(receipt.bench.name, "perf-stat");
+        assert_eq!(receipt.samples.len(), 1);
+        let sample = &receipt.samples[0];
+        assert_eq!(sample.wall_ms, 1320);
+        assert_eq!(sample.cpu_ms, Some(1205));
+        assert_eq!(sample.ctx_switches, Some(15));
+        assert_eq!(sample.page_faults, Some(812));
+        assert_eq!(sample.instructions, Some(9_876_543_210));
+
+        let cycles = &receipt.stats.custom["cycles:u"];
+        assert_eq!(cycles.unit.as_deref(), Some("count"));
+        assert_eq!(cycles.summary.median, 4_567_890_123.0);
+        assert!(!receipt.stats.custom.contains_key("branch-misses"));
+
+        // `-r` variance becomes the standard deviation of the mean value.
+        let cpu = receipt.stats.cpu_ms.unwrap();
+        assert!((cpu.stddev.unwrap() - 1204.51 * 0.0052).abs() < 1e-9);
+        assert_eq!(
+            receipt.bench.command,
+            vec!["(perf stat: 1 run(s), multiplexed: cycles:u 49.98%, instructions:u 50.02%)"]
+        );
+    }
+
+    #[test]
+    fn reports_multiplexing_and_uncounted_events() {
+        let warnings = perf_stat_warnings(REPEATED).unwrap();
+        assert_eq!(
+            parse_perf_stat(REPEATED, None).unwrap().run.noise_warnings,
+            warnings
+        );
+        assert_eq!(warnings.len(), 3);
+        assert!(warnings[0].starts_with("cycles:u was counted 49.98% of the time"));
+        assert!(warnings[1].starts_with("instructions:u was counted 50.02%"));
+        assert_eq!(warnings[2], "not counted or not supported: branch-misses");
+    }
+
+    #[test]
+    fn appended_runs_become_samples() {
+        let input = "\
+# started on Sat Oct 17 10:00:00 2026
+
+10.0,msec,task-clock,10000000,100.00,0.9,CPUs utilized
+1000,,instructions,10000000,100.00,,
+# started on Sat Oct 17 10:00:01 2026
+
+12.0,msec,task-clock,12000000,100.00,0.9,CPUs utilized
+1200,,instructions,12000000,100.00,,
+14.0,msec,task-clock,14000000,100.00,0.9,CPUs utilized
+1400,,instructions,14000000,100.00,,
+";
+        let receipt = parse_perf_stat(input, Some("sort")).unwrap();
+
+        assert_eq!(receipt.bench.name, "sort");
+        assert_eq!(receipt.samples.len(), 3);
+        assert_eq!(receipt.samples[2].cpu_ms, Some(14));
+        // Without duration_time there is no wall clock to compare.
+        assert_eq!(receipt.samples[0].wall_ms, 0);
+        let instructions = receipt.stats.instructions.unwrap();
+        assert_eq!(instructions.median, 1200);
+        assert_eq!(instructions.min, 1000);
+        assert!(perf_stat_warnings(input).unwrap().is_empty());
+    }
+
+    #[test]
+    fn rejects_human_readable_output() {
+        let err = parse_perf_stat(
+            " Performance counter stats for './sort':\n\n    1,204.51 msec task-clock\n",
+            None,
+        )
+        .unwrap_err();
+        assert!(
+            format!("{err:#}").contains("expected `value,unit,event"),
+            "got: {err:#}"
+        );
+    }
+}
@@ -81,17 +81,39 @@ struct PytestStats {
 
 /// Parse a pytest-benchmark JSON file into a `RunReceipt`.
 ///
-/// If the JSON contains multiple benchmarks, only the first benchmark is used.
-/// Use `--name` at the CLI layer to make that selection explicit in the
-/// resulting receipt.
+/// If the JSON contains multiple benchmarks, only the first benchmark is used;
+/// [`parse_pytest_benchmark_all`] keeps every benchmark. Use `--name` at the
+/// CLI layer to make that selection explicit in the resulting receipt.
 pub fn parse_pytest_benchmark(input: &str, name: Option<&str>) -> anyhow::Result<RunReceipt> {
+    let output = parse_output(input)?;
+    benchmark_to_receipt(&output, &output.benchmarks[0], name)
+}
+
+/// Parse a pytest-benchmark JSON file into one `RunReceipt` per benchmark,
+/// each named after the benchmark's `fullname` (or `name`).
+pub fn parse_pytest_benchmark_all(input: &str) -> anyhow::Result<Vec<RunReceipt>> {
+    let output = parse_output(input)?;
+    output
+        .benchmarks
+        .iter()
+        .map(|bench| benchmark_to_receipt(&output, bench, None))
+        .collect()
+}
+
+fn parse_output(input: &str) -> anyhow::Result<PytestOutput> {
     let output: PytestOutput =
         serde_json::from_str(input).context("failed to parse pytest-benchmark JSON")?;
+    if output.benchmarks.is_empty() {
+        bail!("pytest-benchmark JSON contains no benchmarks");
+    }
+    Ok(output)
+}
 
-    let bench = output
-        .benchmarks
-        .first()
-        .context("pytest-benchmark JSON contains no benchmarks")?;
+fn benchmark_to_receipt(
+    output: &PytestOutput,
+    bench: &PytestBenchmark,
+    name: Option<&str>,
+) -> anyhow::Result<RunReceipt> {
     validate_stats(&bench.stats)?;
 
     let bench_name = name
@@ -119,12 +141,25 @@ pub fn parse_pytest_benchmark(input: &str, name: Option<&str>) -> anyhow::Result
             energy_uj: None,
             binary_bytes: None,
             throughput_per_s: throughput,
+            latency_p50_us: None,
+            latency_p95_us: None,
+            latency_p99_us: None,
+            error_rate: None,
+            time_to_ready_ms: None,
+            instructions: None,
+            estimated_cycles: None,
+            l1_hits: None,
+            ll_hits: None,
+            ram_hits: None,
+            alloc_bytes: None,
+            allocs: None,
+            custom: Default::default(),
         },
         host: host_info(output.machine_info.as_ref()),
         python_runtime: python_runtime(output.machine_info.as_ref()),
         repeat: repeat_count(&bench.stats),
         warmup: warmup_count(bench.options.as_ref()),
-        source_version: output.version,
+        source_version: output.version.clone(),
         timer: bench
             .options
             .as_ref()
@@ -224,6 +259,19 @@ fn raw_samples(stats: &PytestStats) -> anyhow::Result<Vec<Sample>> {
                 binary_bytes: None,
                 stdout: None,
                 stderr: None,
+                latency_p50_us: None,
+                latency_p95_us: None,
+                latency_p99_us: None,
+                error_rate: None,
+                throughput_per_s: None,
+                time_to_ready_ms: None,
+                instructions: None,
+                estimated_cycles: None,
+                l1_hits: None,
+                ll_hits: None,
+                ram_hits: None,
+                alloc_bytes: None,
+                allocs: None,
             })
         })
         .collect()
@@ -293,6 +341,10 @@ fn make_pytest_receipt(input: PytestReceiptInput) -> RunReceipt {
             started_at: timestamp.clone(),
             ended_at: timestamp,
             host: input.host,
+            profile: None,
+            concurrency: None,
+            interleave: None,
+            noise_warnings: Vec::new(),
         },
         bench: BenchMeta {
             name: input.name,
@@ -302,6 +354,8 @@ fn make_pytest_receipt(input: PytestReceiptInput) -> RunReceipt {
             warmup: input.warmup,
             work_units: None,
             timeout_ms: None,
+            service: None,
+            load: None,
         },
         samples: input.samples,
         stats: input.stats,
@@ -512,6 +566,23 @@ mod tests {
         assert_eq!(receipt.run.host.hostname_hash, None);
     }
 
+    #[test]
+    fn parse_all_keeps_every_benchmark() {
+        let input = r#"{"benchmarks": [
+            {"name": "test_a", "stats": {"min": 0.01, "max": 0.01, "mean": 0.01,
+             "stddev": 0.0, "median": 0.01, "data": [0.01]}},
+            {"name": "test_b", "fullname": "tests/test_perf.py::test_b",
+             "stats": {"min": 0.02, "max": 0.02, "mean": 0.02, "stddev": 0.0,
+             "median": 0.02}}
+        ]}"#;
+        let receipts = parse_pytest_benchmark_all(input).unwrap();
+        assert_eq!(receipts.len(), 2);
+        assert_eq!(receipts[0].bench.name, "test_a");
+        assert_eq!(receipts[0].samples.len(), 1);
+        assert_eq!(receipts[1].bench.name, "tests/test_perf.py::test_b");
+        assert_eq!(receipts[1].stats.wall_ms.median, 20);
+    }
+
     #[test]
     fn accepts_name_override() {
         let receipt = parse_pytest_benchmark(PYTEST_JSON_WITH_DATA, Some("parser-sort")).unwrap();
@@ -0,0 +1,317 @@
+//! Parser for tinybench task results and vitest bench JSON.
+//!
+//! `vitest bench --outputJson <file>` writes `files[].groups[].benchmarks[]`,
+//! where each benchmark is a tinybench result with its `name` inlined.
+//! tinybench itself has no JSON writer; a script can print
+//! `JSON.stringify(bench.tasks.map(({ name, result }) => ({ name, result })))`,
+//! which this parser reads as an array of `{name, result}` tasks.
+//!
+//! All times are milliseconds. Both result shapes are understood:
+//!
+//! - tinybench 2 / vitest: flat `mean`, `min`, `max`, `median`, `sd`,
+//!   `samples`, and `hz` fields
+//! - tinybench 3+: `latency` and `throughput` statistics objects
+//!
+//! Latency maps to `wall_ms` (with `samples` as raw samples when present)
+//! and `hz` or `throughput` maps to `throughput_per_s`.
+
+use anyhow::{Context, bail};
+use perfgate_types::{F64Summary, RunReceipt, Stats};
+use serde::Deserialize;
+use serde_json::Value;
+
+use super::{f64_summary, make_receipt, to_u64, to_u64_summary, wall_sample};
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum TinybenchOutput {
+    Vitest { files: Vec<VitestFile> },
+    Tasks(Vec<TinybenchTask>),
+}
+
+#[derive(Debug, Deserialize)]
+struct VitestFile {
+    groups: Vec<VitestGroup>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct VitestGroup {
+    full_name: String,
+    benchmarks: Vec<VitestBenchmark>,
+}
+
+#[derive(Debug, Deserialize)]
+struct VitestBenchmark {
+    name: String,
+    #[serde(flatten)]
+    result: TaskResult,
+}
+
+#[derive(Debug, Deserialize)]
+struct TinybenchTask {
+    name: String,
+    #[serde(default)]
+    result: Option<TaskResult>,
+}
+
+/// A tinybench task result, in either the flat (tinybench 2, vitest) or
+/// the nested (tinybench 3+) shape.
+#[derive(Debug, Deserialize)]
+struct TaskResult {
+    #[serde(default)]
+    error: Option<Value>,
+    #[serde(default)]
+    hz: Option<f64>,
+    #[serde(default)]
+    mean: Option<f64>,
+    #[serde(default)]
+    min: Option<f64>,
+    #[serde(default)]
+    max: Option<f64>,
+    #[serde(default)]
+    median: Option<f64>,
+    #[serde(default)]
+    sd: Option<f64>,
+    #[serde(default)]
+    samples: Vec<f64>,
+    #[serde(default)]
+    latency: Option<Statistics>,
+    #[serde(default)]
+    throughput: Option<Statistics>,
+}
+
+/// tinybench 3+ statistics object.
+#[derive(Debug, Deserialize)]
+struct Statistics {
+    mean: f64,
+    min: f64,
+    max: f64,
+    #[serde(default)]
+    p50: Option<f64>,
+    #[serde(default)]
+    sd: Option<f64>,
+    #[serde(default)]
+    samples: Vec<f64>,
+}
+
+/// Parse tinybench or vitest bench JSON into a `RunReceipt`.
+///
+/// If the input contains multiple benchmarks, only the first is used;
+/// [`parse_tinybench_all`] keeps every one. Use `name` to override the
+/// benchmark name.
+pub fn parse_tinybench(input: &str, name: Option<&str>) -> anyhow::Result<RunReceipt> {
+    let benches = parse_benches(input)?;
+    let (bench_name, result) = &benches[0];
+    result_to_receipt(name.unwrap_or(bench_name), result)
+}
+
+/// Parse tinybench or vitest bench JSON into one `RunReceipt` per
+/// benchmark. vitest benchmarks are named `<group>/<benchmark>`, with the
+/// group's ` > ` separators turned into `/`.
+pub fn parse_tinybench_all(input: &str) -> anyhow::Result<Vec<RunReceipt>> {
+    parse_benches(input)?
+        .iter()
+        .map(|(name, result)| result_to_receipt(name, result))
+        .collect()
+}
+
+fn parse_benches(input: &str) -> anyhow::Result<Vec<(String, TaskResult)>> {
+    let output: TinybenchOutput =
+        serde_json::from_str(input).context("failed to parse tinybench/vitest bench JSON")?;
+
+    let benches: Vec<(String, TaskResult)> = match output {
+        TinybenchOutput::Vitest { files } => files
+            .into_iter()
+            .flat_map(|file| file.groups)
+            .flat_map(|group| {
+                let prefix = group.full_name.replace(" > ", "/");
+                group
+                    .benchmarks
+                    .into_iter()
+                    .map(move |bench| (format!("{prefix}/{}", bench.name), bench.result))
+            })
+            .collect(),
+        TinybenchOutput::Tasks(tasks) => tasks
+            .into_iter()
+            .map(|task| {
+                let result = task
+                    .result
+                    .with_context(|| format!("tinybench task '{}' has no result", task.name))?;
+                Ok((task.name, result))
+            })
+            .collect::<anyhow::Result<_>>()?,
+    };
+
+    if benches.is_empty() {
+        bail!("tinybench/vitest bench JSON contains no benchmarks");
+    }
+    Ok(benches)
+}
+
+fn result_to_receipt(name: &str, result: &TaskResult) -> anyhow::Result<RunReceipt> {
+    if let Some(error) = &result.error {
+        bail!("benchmark '{name}' failed: {error}");
+    }
+
+    let (samples, wall) = match &result.latency {
+        Some(latency) => (&latency.samples, statistics_summary(latency)),
+        None => {
+            let mean = result
+                .mean
+                .with_context(|| format!("benchmark '{name}' has no mean or latency"))?;
+            let center = result.median.unwrap_or(mean);
+            (
+                &result.samples,
+                F64Summary {
+                    median: center,
+                    min: result.min.unwrap_or(center),
+                    max: result.max.unwrap_or(center),
+                    mean: Some(mean),
+                    stddev: result.sd,
+                },
+            )
+        }
+    };
+    let wall = if samples.is_empty() {
+        wall
+    } else {
+        f64_summary(samples)
+    };
+    for value in [wall.median, wall.min, wall.max]
+        .iter()
+        .chain(samples.iter())
+    {
+        if !value.is_finite() || *value < 0.0 {
+            bail!("benchmark '{name}' has a time that is not finite and non-negative");
+        }
+    }
+
+    let throughput_per_s = match (&result.throughput, result.hz) {
+        (Some(throughput), _) => Some(statistics_summary(throughput)),
+        (None, Some(hz)) if hz.is_finite() => Some(F64Summary {
+            mean: Some(hz),
+            ..F64Summary::new(hz, hz, hz)
+        }),
+        (None, _) => None,
+    };
+
+    let stats = Stats {
+        wall_ms: to_u64_summary(&wall),
+        cpu_ms: None,
+        page_faults: None,
+        ctx_switches: None,
+        max_rss_kb: None,
+        io_read_bytes: None,
+        io_write_bytes: None,
+        network_packets: None,
+        energy_uj: None,
+        binary_bytes: None,
+        throughput_per_s,
+        latency_p50_us: None,
+        latency_p95_us: None,
+        latency_p99_us: None,
+        error_rate: None,
+        time_to_ready_ms: None,
+        instructions: None,
+        estimated_cycles: None,
+        l1_hits: None,
+        ll_hits: None,
+        ram_hits: None,
+        alloc_bytes: None,
+        allocs: None,
+        custom: Default::default(),
+    };
+
+    let samples = samples.iter().map(|ms| wall_sample(to_u64(*ms))).collect();
+    let mut receipt = make_receipt(name, samples, stats);
+    receipt.bench.command = vec!["(ingested tinybench/vitest bench output)".to_string()];
+    receipt.run.host.os = "unknown".to_string();
+    receipt.run.host.arch = "unknown".to_string();
+    Ok(receipt)
+}
+
+fn statistics_summary(statistics: &Statistics) -> F64Summary {
+    F64Summary {
+        median: statistics.p50.unwrap_or(statistics.mean),
+        min: statistics.min,
+        max: statistics.max,
+        mean: Some(statistics.mean),
+        stddev: statistics.sd,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vitest_output_json_names_benches_by_group() {
+        let input = r#"{"files": [{
+          "filepath": "/repo/src/sort.bench.ts",
+          "groups": [{
+            "fullName": "src/sort.bench.ts > sorting",
+            "benchmarks": [
+              {"id": "1", "name": "quicksort", "rank": 1, "rme": 1.2, "samples": [2.0, 4.0, 3.0],
+               "totalTime": 9.0, "min": 2.0, "max": 4.0, "hz": 333.3, "period": 3.0,
+               "mean": 3.0, "sd": 0.8, "median": 3.0, "sampleCount": 3},
+              {"id": "2", "name": "bubble sort", "rank": 2, "rme": 0.4, "samples": [],
+               "min": 10.0, "max": 14.0, "hz": 83.3, "mean": 12.0, "sd": 1.0, "median": 11.5}
+            ]
+          }]
+        }]}"#;
+
+        let receipts = parse_tinybench_all(input).unwrap();
+        let names: Vec<&str> = receipts.iter().map(|r| r.bench.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec![
+                "src/sort.bench.ts/sorting/quicksort",
+                "src/sort.bench.ts/sorting/bubble sort"
+            ]
+        );
+
+        let quick = &receipts[0];
+        let walls: Vec<u64> = quick.samples.iter().map(|s| s.wall_ms).collect();
+        assert_eq!(walls, vec![2, 4, 3]);
+        assert_eq!(quick.stats.wall_ms.median, 3);
+        assert_eq!(quick.stats.throughput_per_s.as_ref().unwrap().median, 333.3);
+
+        let bubble = &receipts[1];
+        assert!(bubble.samples.is_empty());
+        assert_eq!(bubble.stats.wall_ms.median, 12);
+        assert_eq!(bubble.stats.wall_ms.mean, Some(12.0));
+        assert_eq!(bubble.stats.wall_ms.stddev, Some(1.0));
+    }
+
+    #[test]
+    fn tinybench_v3_tasks_use_latency_and_throughput() {
+        let input = r#"[
+          {"name": "parse", "result": {
+            "latency": {"mean": 0.5, "min": 0.25, "max": 1.5, "p50": 0.4, "sd": 0.1, "samples": []},
+            "throughput": {"mean": 2000.0, "min": 666.0, "max": 4000.0, "p50": 2500.0, "sd": 300.0}
+          }}
+        ]"#;
+
+        let receipt = parse_tinybench(input, Some("js-parse")).unwrap();
+        assert_eq!(receipt.bench.name, "js-parse");
+        assert_eq!(receipt.stats.wall_ms.mean, Some(0.5));
+        assert_eq!(receipt.stats.wall_ms.median, 1);
+        let throughput = receipt.stats.throughput_per_s.unwrap();
+        assert_eq!(throughput.median, 2500.0);
+        assert_eq!(throughput.min, 666.0);
+    }
+
+    #[test]
+    fn failed_or_missing_results_are_rejected() {
+        let err =
+            parse_tinybench(r#"[{"name": "x", "result": {"error": "boom"}}]"#, None).unwrap_err();
+        assert!(err.to_string().contains("boom"), "{err}");
+
+        let err = parse_tinybench(r#"[{"name": "x"}]"#, None).unwrap_err();
+        assert!(err.to_string().contains("has no result"), "{err}");
+
+        let err = parse_tinybench("[]", None).unwrap_err();
+        assert!(err.to_string().contains("no benchmarks"), "{err}");
+    }
+}
@@ -0,0 +1,95 @@
+//! Parser for vegeta JSON reports (`vegeta report -type=json`).
+//!
+//! Latencies and durations are nanoseconds. `latencies.50th/95th/99th` map to
+//! the latency percentiles, `1 - success` to `error_rate` (vegeta counts
+//! status codes outside 200-399 and transport errors as failures), and
+//! `throughput` (successful requests per second) to `throughput_per_s`.
+//! `duration + wait` is the run's `wall_ms`. The whole attack is one sample.
+
+use anyhow::Context;
+use perfgate_types::RunReceipt;
+use serde::Deserialize;
+
+use super::{LoadRun, load_receipt};
+
+#[derive(Debug, Deserialize)]
+struct VegetaReport {
+    latencies: VegetaLatencies,
+    /// Attack duration in nanoseconds.
+    duration: f64,
+    /// Wait for the last responses after the attack, in nanoseconds.
+    #[serde(default)]
+    wait: f64,
+    requests: u64,
+    throughput: f64,
+    success: f64,
+}
+
+#[derive(Debug, Deserialize)]
+struct VegetaLatencies {
+    #[serde(rename = "50th")]
+    p50: f64,
+    #[serde(rename = "95th")]
+    p95: f64,
+    #[serde(rename = "99th")]
+    p99: f64,
+}
+
+/// Parse a vegeta JSON report into a `RunReceipt` named `name`, or `vegeta`.
+pub fn parse_vegeta(input: &str, name: Option<&str>) -> anyhow::Result<RunReceipt> {
+    let report: VegetaReport =
+        serde_json::from_str(input).context("failed to parse vegeta JSON report")?;
+
+    let run = LoadRun {
+        wall_ms: (report.duration + report.wait) / 1_000_000.0,
+        latency_p50_us: Some(report.latencies.p50 / 1000.0),
+        latency_p95_us: Some(report.latencies.p95 / 1000.0),
+        latency_p99_us: Some(report.latencies.p99 / 1000.0),
+        error_rate: Some(1.0 - report.success),
+        throughput_per_s: Some(report.throughput),
+    };
+
+    let mut receipt = load_receipt(name.unwrap_or("vegeta"), &run)?;
+    receipt.bench.command = vec![format!("(vegeta report: {} requests)", report.requests)];
+    Ok(receipt)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_vegeta_report() {
+        let input = r#"{
+          "latencies": {"total": 1500000000000, "mean": 1000000, "50th": 850000, "90th": 2000000,
+                        "95th": 3100000, "99th": 9000000, "max": 20000000, "min": 300000},
+          "bytes_in": {"total": 15000, "mean": 10},
+          "bytes_out": {"total": 0, "mean": 0},
+          "earliest": "2026-01-01T00:00:00Z", "latest": "2026-01-01T00:00:30Z", "end": "2026-01-01T00:00:30.001Z",
+          "duration": 30000000000, "wait": 1000000, "requests": 1500, "rate": 50.0,
+          "throughput": 49.0, "success": 0.98,
+          "status_codes": {"200": 1470, "500": 30},
+          "errors": ["500 Internal Server Error"]
+        }"#;
+        let receipt = parse_vegeta(input, None).unwrap();
+
+        assert_eq!(receipt.bench.name, "vegeta");
+        assert_eq!(
+            receipt.bench.command,
+            vec!["(vegeta report: 1500 requests)"]
+        );
+        let sample = &receipt.samples[0];
+        assert_eq!(sample.wall_ms, 30_001);
+        assert_eq!(sample.latency_p50_us, Some(850));
+        assert_eq!(sample.latency_p95_us, Some(3_100));
+        assert_eq!(sample.latency_p99_us, Some(9_000));
+        assert!((sample.error_rate.unwrap() - 0.02).abs() < 1e-12);
+        assert_eq!(sample.throughput_per_s, Some(49.0));
+    }
+
+    #[test]
+    fn rejects_text_report() {
+        let err = parse_vegeta("Requests [total, rate] 1500, 50.00", None).unwrap_err();
+        assert!(err.to_string().contains("vegeta"), "got: {err}");
+    }
+}
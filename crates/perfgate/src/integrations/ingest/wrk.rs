@@ -0,0 +1,146 @@
+//! Parser for wrk results written as JSON by a Lua `done` hook.
+//!
+//! wrk has no JSON output of its own. A `done(summary, latency, requests)`
+//! function can write its `summary` table plus latency percentiles:
+//!
+//! ```json
+//! {"duration": 10000000, "requests": 52000,
+//!  "errors": {"connect": 0, "read": 0, "write": 0, "status": 12, "timeout": 3},
+//!  "latency": {"mean": 1912.4, "percentiles": {"50": 1800, "95": 3500, "99": 7900}}}
+//! ```
+//!
+//! Times are microseconds, as wrk reports them. Percentile keys may be
+//! written as `50`, `50.0` or `p50`. wrk's `requests` counts responses, so
+//! connect errors and timeouts, which never got one, are added to it for the
+//! attempted total. All errors over that total give `error_rate`, and
+//! responses without a read, write or status error over `duration` give
+//! `throughput_per_s`. The whole run is one sample.
+
+use std::collections::BTreeMap;
+
+use anyhow::Context;
+use perfgate_types::RunReceipt;
+use serde::Deserialize;
+
+use super::{LoadRun, load_receipt};
+
+#[derive(Debug, Deserialize)]
+struct WrkSummary {
+    /// Run duration in microseconds.
+    duration: f64,
+    requests: u64,
+    #[serde(default)]
+    errors: WrkErrors,
+    latency: WrkLatency,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct WrkErrors {
+    #[serde(default)]
+    connect: u64,
+    #[serde(default)]
+    read: u64,
+    #[serde(default)]
+    write: u64,
+    #[serde(default)]
+    status: u64,
+    #[serde(default)]
+    timeout: u64,
+}
+
+#[derive(Debug, Deserialize)]
+struct WrkLatency {
+    #[serde(default)]
+    percentiles: BTreeMap<String, f64>,
+}
+
+/// Parse wrk Lua JSON output into a `RunReceipt` named `name`, or `wrk`.
+pub fn parse_wrk(input: &str, name: Option<&str>) -> anyhow::Result<RunReceipt> {
+    let summary: WrkSummary =
+        serde_json::from_str(input).context("failed to parse wrk Lua JSON output")?;
+
+    let errors = &summary.errors;
+    let failed_responses = errors.read + errors.write + errors.status;
+    let unanswered = errors.connect + errors.timeout;
+    let attempted = summary.requests + unanswered;
+    let error_rate = if attempted > 0 {
+        (failed_responses + unanswered) as f64 / attempted as f64
+    } else {
+        1.0
+    };
+    let successful = summary.requests.saturating_sub(failed_responses);
+
+    let percentile = |wanted: f64| {
+        summary
+            .latency
+            .percentiles
+            .iter()
+            .find(|(key, _)| {
+                key.trim_start_matches('p')
+                    .parse::<f64>()
+                    .is_ok_and(|p| p == wanted)
+            })
+            .map(|(_, us)| *us)
+    };
+    let run = LoadRun {
+        wall_ms: summary.duration / 1000.0,
+        latency_p50_us: percentile(50.0),
+        latency_p95_us: percentile(95.0),
+        latency_p99_us: percentile(99.0),
+        error_rate: Some(error_rate),
+        throughput_per_s: (summary.duration > 0.0)
+            .then(|| successful as f64 / (summary.duration / 1_000_000.0)),
+    };
+
+    let mut receipt = load_receipt(name.unwrap_or("wrk"), &run)?;
+    receipt.bench.command = vec![format!("(wrk: {} requests)", summary.requests)];
+    Ok(receipt)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_wrk_lua_json() {
+        let input = r#"{
+          "duration": 10000000, "requests": 49900, "bytes": 6400000,
+          "errors": {"connect": 0, "read": 0, "write": 0, "status": 400, "timeout": 100},
+          "latency": {"min": 210, "max": 51000, "mean": 1912.4, "stdev": 800.2,
+                      "percentiles": {"50.0": 1800, "p95": 3500, "99": 7900}}
+        }"#;
+        let receipt = parse_wrk(input, Some("api/search")).unwrap();
+
+        assert_eq!(receipt.bench.name, "api/search");
+        assert_eq!(receipt.bench.command, vec!["(wrk: 49900 requests)"]);
+        let sample = &receipt.samples[0];
+        assert_eq!(sample.wall_ms, 10_000);
+        assert_eq!(sample.latency_p50_us, Some(1_800));
+        assert_eq!(sample.latency_p95_us, Some(3_500));
+        assert_eq!(sample.latency_p99_us, Some(7_900));
+        assert_eq!(sample.error_rate, Some(0.01));
+        assert_eq!(sample.throughput_per_s, Some(4_950.0));
+    }
+
+    #[test]
+    fn missing_percentiles_stay_unset() {
+        let input = r#"{"duration": 1000000, "requests": 10, "latency": {"mean": 5.0}}"#;
+        let receipt = parse_wrk(input, None).unwrap();
+
+        assert_eq!(receipt.bench.name, "wrk");
+        assert_eq!(receipt.samples[0].latency_p50_us, None);
+        assert_eq!(receipt.samples[0].error_rate, Some(0.0));
+        assert_eq!(receipt.samples[0].throughput_per_s, Some(10.0));
+    }
+
+    #[test]
+    fn connect_errors_and_timeouts_count_as_attempts() {
+        let input = r#"{"duration": 1000000, "requests": 60,
+          "errors": {"connect": 40, "read": 0, "write": 0, "status": 0, "timeout": 0},
+          "latency": {"mean": 5.0}}"#;
+        let receipt = parse_wrk(input, None).unwrap();
+
+        assert_eq!(receipt.samples[0].error_rate, Some(0.4));
+        assert_eq!(receipt.samples[0].throughput_per_s, Some(60.0));
+    }
+}
@@ -928,10 +928,12 @@ mod tests {
             baseline_ref: CompareRef {
                 path: Some("artifacts/perfgate/parser/probes-baseline.json".to_string()),
                 run_id: Some("probe-baseline".to_string()),
+                profile: None,
             },
             current_ref: CompareRef {
                 path: Some("artifacts/perfgate/parser/probes-current.json".to_string()),
                 run_id: Some("probe-current".to_string()),
+                profile: None,
             },
             tool: tool(),
         })
@@ -973,11 +975,13 @@ mod tests {
                 compare_ref: CompareRef {
                     path: Some("artifacts/perfgate/parser/compare.json".to_string()),
                     run_id: Some("parser-current".to_string()),
+                    profile: None,
                 },
                 compare: compare_receipt(),
                 probe_compare_ref: Some(CompareRef {
                     path: Some("artifacts/perfgate/parser/probe-compare.json".to_string()),
                     run_id: Some(probe_compare.run.id.clone()),
+                    profile: None,
                 }),
                 probe_compare: Some(probe_compare.clone()),
                 probe_compare_warning: None,
@@ -1063,14 +1067,18 @@ mod tests {
                 warmup: 0,
                 work_units: None,
                 timeout_ms: None,
+                service: None,
+                load: None,
             },
             baseline_ref: CompareRef {
                 path: Some("baselines/parser.json".to_string()),
                 run_id: Some("parser-baseline".to_string()),
+                profile: None,
             },
             current_ref: CompareRef {
                 path: Some("artifacts/perfgate/parser/run.json".to_string()),
                 run_id: Some("parser-current".to_string()),
+                profile: None,
             },
             budgets: BTreeMap::new(),
             deltas: BTreeMap::from([
@@ -90,7 +90,20 @@ impl FuzzStats {
             io_read_bytes: None,
             io_write_bytes: None,
             network_packets: None,
-        }
+        },
+        latency_p50_us: None,
+        latency_p95_us: None,
+        latency_p99_us: None,
+        error_rate: None,
+        time_to_ready_ms: None,
+        instructions: None,
+        estimated_cycles: None,
+        l1_hits: None,
+        ll_hits: None,
+        ram_hits: None,
+        alloc_bytes: None,
+        allocs: None,
+        custom: Default::default(),
     }
 }
 
@@ -159,6 +172,7 @@ impl FuzzBudget {
             threshold,
             warn_threshold,
             direction: self.direction.to_perfgate(),
+            auto_threshold: None,
         }
     }
 }
@@ -114,6 +114,9 @@ fuzz_target!(|input: SensorInput| {
         },
         complexity: None,
         profile_path: None,
+        confirmation: None,
+        skip_reason: None,
+        baseline_selection: None,
     };
 
     let mut builder = perfgate::app::sensor::SensorReportBuilder::new(tool, input.started_at)
@@ -19,10 +19,10 @@ use tempfile::TempDir;
 // Re-export types we need for fixture creation
 use perfgate_types::{
     AggregateReceipt, BaselineServerConfig, BenchConfigFile, BenchMeta, BudgetOverride,
-    COMPARE_SCHEMA_V1, CompareReceipt, CompareRef, ConfigFile, DefaultsConfig, Delta, HostInfo,
-    Metric, MetricStatistic, MetricStatus, PAIRED_SCHEMA_V1, PairedRunReceipt, PerfgateReport,
-    REPORT_SCHEMA_V1, RUN_SCHEMA_V1, ReportSummary, RunMeta, RunReceipt, Sample, SensorReport,
-    Stats, ToolInfo, U64Summary, Verdict, VerdictCounts, VerdictStatus,
+    BudgetThreshold, COMPARE_SCHEMA_V1, CompareReceipt, CompareRef, ConfigFile, DefaultsConfig,
+    Delta, HostInfo, Metric, MetricStatistic, MetricStatus, PAIRED_SCHEMA_V1, PairedRunReceipt,
+    PerfgateReport, REPORT_SCHEMA_V1, RUN_SCHEMA_V1, ReportSummary, RunMeta, RunReceipt, Sample,
+    SensorReport, Stats, ToolInfo, U64Summary, Verdict, VerdictCounts, VerdictStatus,
 };
 
 // Microcrate imports for direct testing
@@ -193,6 +193,10 @@ impl PerfgateWorld {
                     memory_bytes: None,
                     hostname_hash: None,
                 },
+                profile: None,
+                concurrency: None,
+                interleave: None,
+                noise_warnings: Vec::new(),
             },
             bench: BenchMeta {
                 name: "test-bench".to_string(),
@@ -202,6 +206,8 @@ impl PerfgateWorld {
                 warmup: 0,
                 work_units: None,
                 timeout_ms: None,
+                service: None,
+                load: None,
             },
             samples: vec![Sample {
                 wall_ms: wall_ms_median,
@@ -219,6 +225,19 @@ impl PerfgateWorld {
                 binary_bytes: None,
                 stdout: None,
                 stderr: None,
+                latency_p50_us: None,
+                latency_p95_us: None,
+                latency_p99_us: None,
+                error_rate: None,
+                throughput_per_s: None,
+                time_to_ready_ms: None,
+                instructions: None,
+                estimated_cycles: None,
+                l1_hits: None,
+                ll_hits: None,
+                ram_hits: None,
+                alloc_bytes: None,
+                allocs: None,
             }],
             stats: Stats {
                 wall_ms: U64Summary::new(
@@ -236,6 +255,19 @@ impl PerfgateWorld {
                 energy_uj: None,
                 binary_bytes: None,
                 throughput_per_s: None,
+                latency_p50_us: None,
+                latency_p95_us: None,
+                latency_p99_us: None,
+                error_rate: None,
+                time_to_ready_ms: None,
+                instructions: None,
+                estimated_cycles: None,
+                l1_hits: None,
+                ll_hits: None,
+                ram_hits: None,
+                alloc_bytes: None,
+                allocs: None,
+                custom: Default::default(),
             },
         }
     }
@@ -266,6 +298,19 @@ impl PerfgateWorld {
                 binary_bytes: None,
                 stdout: None,
                 stderr: None,
+                latency_p50_us: None,
+                latency_p95_us: None,
+                latency_p99_us: None,
+                error_rate: None,
+                throughput_per_s: None,
+                time_to_ready_ms: None,
+                instructions: None,
+                estimated_cycles: None,
+                l1_hits: None,
+                ll_hits: None,
+                ram_hits: None,
+                alloc_bytes: None,
+                allocs: None,
             })
             .collect();
 
@@ -286,6 +331,10 @@ impl PerfgateWorld {
                     memory_bytes: None,
                     hostname_hash: None,
                 },
+                profile: None,
+                concurrency: None,
+                interleave: None,
+                noise_warnings: Vec::new(),
             },
             bench: BenchMeta {
                 name: "test-bench".to_string(),
@@ -295,6 +344,8 @@ impl PerfgateWorld {
                 warmup: 0,
                 work_units: None,
                 timeout_ms: None,
+                service: None,
+                load: None,
             },
             samples,
             stats: Stats {
@@ -309,6 +360,19 @@ impl PerfgateWorld {
                 energy_uj: None,
                 binary_bytes: None,
                 throughput_per_s: None,
+                latency_p50_us: None,
+                latency_p95_us: None,
+                latency_p99_us: None,
+                error_rate: None,
+                time_to_ready_ms: None,
+                instructions: None,
+                estimated_cycles: None,
+                l1_hits: None,
+                ll_hits: None,
+                ram_hits: None,
+                alloc_bytes: None,
+                allocs: None,
+                custom: Default::default(),
             },
         }
     }
@@ -393,14 +457,18 @@ impl PerfgateWorld {
                 warmup: 0,
                 work_units: None,
                 timeout_ms: None,
+                service: None,
+                load: None,
             },
             baseline_ref: CompareRef {
                 path: Some("baseline.json".to_string()),
                 run_id: Some("baseline-run-id".to_string()),
+                profile: None,
             },
             current_ref: CompareRef {
                 path: Some("current.json".to_string()),
                 run_id: Some("current-run-id".to_string()),
+                profile: None,
             },
             budgets: BTreeMap::new(),
             deltas,
@@ -2913,6 +2981,8 @@ async fn given_config_file_with_bench(world: &mut PerfgateWorld, bench_name: Str
             baseline_dir: Some("baselines".to_string()),
             baseline_pattern: None,
             markdown_template: None,
+            baseline_history_dir: None,
+            merge_base: None,
         },
         baseline_server: BaselineServerConfig::default(),
         decision_policy: perfgate_types::DecisionPolicyConfig::default(),
@@ -2931,7 +3001,19 @@ async fn given_config_file_with_bench(world: &mut PerfgateWorld, bench_name: Str
             budgets: None,
 
             scaling: None,
+            matrix: None,
+            matrix_overrides: None,
+            service: None,
+            tags: Vec::new(),
+            paths: Vec::new(),
+            always: false,
+            load: None,
+            ready: None,
+            ready_timeout: None,
+            terminate_on_ready: false,
         }],
+        extends: Vec::new(),
+        profiles: Default::default(),
     };
 
     let config_path = world.temp_path().join("perfgate.toml");
@@ -2971,6 +3053,8 @@ async fn given_config_file_with_bench_threshold(
             baseline_dir: Some("baselines".to_string()),
             baseline_pattern: None,
             markdown_template: None,
+            baseline_history_dir: None,
+            merge_base: None,
         },
         baseline_server: BaselineServerConfig::default(),
         decision_policy: perfgate_types::DecisionPolicyConfig::default(),
@@ -2989,7 +3073,19 @@ async fn given_config_file_with_bench_threshold(
             budgets: None,
 
             scaling: None,
+            matrix: None,
+            matrix_overrides: None,
+            service: None,
+            tags: Vec::new(),
+            paths: Vec::new(),
+            always: false,
+            load: None,
+            ready: None,
+            ready_timeout: None,
+            terminate_on_ready: false,
         }],
+        extends: Vec::new(),
+        profiles: Default::default(),
     };
 
     let config_path = world.temp_path().join("perfgate.toml");
@@ -3030,6 +3126,8 @@ async fn given_config_file_with_bench_threshold_warn(
             baseline_dir: Some("baselines".to_string()),
             baseline_pattern: None,
             markdown_template: None,
+            baseline_history_dir: None,
+            merge_base: None,
         },
         baseline_server: BaselineServerConfig::default(),
         decision_policy: perfgate_types::DecisionPolicyConfig::default(),
@@ -3048,7 +3146,19 @@ async fn given_config_file_with_bench_threshold_warn(
             budgets: None,
 
             scaling: None,
+            matrix: None,
+            matrix_overrides: None,
+            service: None,
+            tags: Vec::new(),
+            paths: Vec::new(),
+            always: false,
+            load: None,
+            ready: None,
+            ready_timeout: None,
+            terminate_on_ready: false,
         }],
+        extends: Vec::new(),
+        profiles: Default::default(),
     };
 
     let config_path = world.temp_path().join("perfgate.toml");
@@ -3086,6 +3196,8 @@ async fn given_config_file_with_defaults_repeat_warmup(
             baseline_dir: Some("baselines".to_string()),
             baseline_pattern: None,
             markdown_template: None,
+            baseline_history_dir: None,
+            merge_base: None,
         },
         baseline_server: BaselineServerConfig::default(),
         decision_policy: perfgate_types::DecisionPolicyConfig::default(),
@@ -3093,6 +3205,8 @@ async fn given_config_file_with_defaults_repeat_warmup(
         ratchet: None,
         scenarios: Vec::new(),
         benches: vec![],
+        extends: Vec::new(),
+        profiles: Default::default(),
     };
 
     let config_path = world.temp_path().join("perfgate.toml");
@@ -3126,6 +3240,8 @@ async fn given_config_file_with_defaults_repeat(world: &mut PerfgateWorld, repea
             baseline_dir: Some("baselines".to_string()),
             baseline_pattern: None,
             markdown_template: None,
+            baseline_history_dir: None,
+            merge_base: None,
         },
         baseline_server: BaselineServerConfig::default(),
         decision_policy: perfgate_types::DecisionPolicyConfig::default(),
@@ -3133,6 +3249,8 @@ async fn given_config_file_with_defaults_repeat(world: &mut PerfgateWorld, repea
         ratchet: None,
         scenarios: Vec::new(),
         benches: vec![],
+        extends: Vec::new(),
+        profiles: Default::default(),
     };
 
     let config_path = world.temp_path().join("perfgate.toml");
@@ -3166,6 +3284,16 @@ async fn given_bench_without_explicit_settings(world: &mut PerfgateWorld, bench_
         budgets: None,
 
         scaling: None,
+        matrix: None,
+        matrix_overrides: None,
+        service: None,
+        tags: Vec::new(),
+        paths: Vec::new(),
+        always: false,
+        load: None,
+        ready: None,
+        ready_timeout: None,
+        terminate_on_ready: false,
     });
 
     // Update the config file
@@ -3191,6 +3319,16 @@ async fn given_bench_with_repeat(world: &mut PerfgateWorld, bench_name: String,
         budgets: None,
 
         scaling: None,
+        matrix: None,
+        matrix_overrides: None,
+        service: None,
+        tags: Vec::new(),
+        paths: Vec::new(),
+        always: false,
+        load: None,
+        ready: None,
+        ready_timeout: None,
+        terminate_on_ready: false,
     });
 
     // Update the config file
@@ -3220,6 +3358,8 @@ async fn given_config_file_with_bench_baseline_dir(
             baseline_dir: Some(baseline_dir.clone()),
             baseline_pattern: None,
             markdown_template: None,
+            baseline_history_dir: None,
+            merge_base: None,
         },
         baseline_server: BaselineServerConfig::default(),
         decision_policy: perfgate_types::DecisionPolicyConfig::default(),
@@ -3238,7 +3378,19 @@ async fn given_config_file_with_bench_baseline_dir(
             budgets: None,
 
             scaling: None,
+            matrix: None,
+            matrix_overrides: None,
+            service: None,
+            tags: Vec::new(),
+            paths: Vec::new(),
+            always: false,
+            load: None,
+            ready: None,
+            ready_timeout: None,
+            terminate_on_ready: false,
         }],
+        extends: Vec::new(),
+        profiles: Default::default(),
     };
 
     let config_path = world.temp_path().join("perfgate.toml");
@@ -3277,6 +3429,8 @@ async fn given_config_file_with_bench_baseline_pattern(
             baseline_dir: None,
             baseline_pattern: Some(baseline_pattern),
             markdown_template: None,
+            baseline_history_dir: None,
+            merge_base: None,
         },
         baseline_server: BaselineServerConfig::default(),
         decision_policy: perfgate_types::DecisionPolicyConfig::default(),
@@ -3295,7 +3449,19 @@ async fn given_config_file_with_bench_baseline_pattern(
             budgets: None,
 
             scaling: None,
+            matrix: None,
+            matrix_overrides: None,
+            service: None,
+            tags: Vec::new(),
+            paths: Vec::new(),
+            always: false,
+            load: None,
+            ready: None,
+            ready_timeout: None,
+            terminate_on_ready: false,
         }],
+        extends: Vec::new(),
+        profiles: Default::default(),
     };
 
     let config_path = world.temp_path().join("perfgate.toml");
@@ -3466,6 +3632,16 @@ async fn given_config_file_with_benches(world: &mut PerfgateWorld, bench_names_s
             budgets: None,
 
             scaling: None,
+            matrix: None,
+            matrix_overrides: None,
+            service: None,
+            tags: Vec::new(),
+            paths: Vec::new(),
+            always: false,
+            load: None,
+            ready: None,
+            ready_timeout: None,
+            terminate_on_ready: false,
         })
         .collect();
 
@@ -3481,6 +3657,8 @@ async fn given_config_file_with_benches(world: &mut PerfgateWorld, bench_names_s
             baseline_dir: Some("baselines".to_string()),
             baseline_pattern: None,
             markdown_template: None,
+            baseline_history_dir: None,
+            merge_base: None,
         },
         baseline_server: BaselineServerConfig::default(),
         decision_policy: perfgate_types::DecisionPolicyConfig::default(),
@@ -3488,6 +3666,8 @@ async fn given_config_file_with_benches(world: &mut PerfgateWorld, bench_names_s
         ratchet: None,
         scenarios: Vec::new(),
         benches,
+        extends: Vec::new(),
+        profiles: Default::default(),
     };
 
     let config_path = world.temp_path().join("perfgate.toml");
@@ -3524,6 +3704,16 @@ async fn given_config_file_with_benches_tight(world: &mut PerfgateWorld, bench_n
             budgets: None,
 
             scaling: None,
+            matrix: None,
+            matrix_overrides: None,
+            service: None,
+            tags: Vec::new(),
+            paths: Vec::new(),
+            always: false,
+            load: None,
+            ready: None,
+            ready_timeout: None,
+            terminate_on_ready: false,
         })
         .collect();
 
@@ -3539,6 +3729,8 @@ async fn given_config_file_with_benches_tight(world: &mut PerfgateWorld, bench_n
             baseline_dir: Some("baselines".to_string()),
             baseline_pattern: None,
             markdown_template: None,
+            baseline_history_dir: None,
+            merge_base: None,
         },
         baseline_server: BaselineServerConfig::default(),
         decision_policy: perfgate_types::DecisionPolicyConfig::default(),
@@ -3546,6 +3738,8 @@ async fn given_config_file_with_benches_tight(world: &mut PerfgateWorld, bench_n
         ratchet: None,
         scenarios: Vec::new(),
         benches,
+        extends: Vec::new(),
+        profiles: Default::default(),
     };
 
     let config_path = world.temp_path().join("perfgate.toml");
@@ -3585,6 +3779,16 @@ async fn given_config_file_with_benches_lenient(
             budgets: None,
 
             scaling: None,
+            matrix: None,
+            matrix_overrides: None,
+            service: None,
+            tags: Vec::new(),
+            paths: Vec::new(),
+            always: false,
+            load: None,
+            ready: None,
+            ready_timeout: None,
+            terminate_on_ready: false,
         })
         .collect();
 
@@ -3601,6 +3805,8 @@ async fn given_config_file_with_benches_lenient(
             baseline_dir: Some("baselines".to_string()),
             baseline_pattern: None,
             markdown_template: None,
+            baseline_history_dir: None,
+            merge_base: None,
         },
         baseline_server: BaselineServerConfig::default(),
         decision_policy: perfgate_types::DecisionPolicyConfig::default(),
@@ -3608,6 +3814,8 @@ async fn given_config_file_with_benches_lenient(
         ratchet: None,
         scenarios: Vec::new(),
         benches,
+        extends: Vec::new(),
+        profiles: Default::default(),
     };
 
     let config_path = world.temp_path().join("perfgate.toml");
@@ -3650,6 +3858,16 @@ async fn given_config_file_with_mixed_thresholds(
             budgets: None,
 
             scaling: None,
+            matrix: None,
+            matrix_overrides: None,
+            service: None,
+            tags: Vec::new(),
+            paths: Vec::new(),
+            always: false,
+            load: None,
+            ready: None,
+            ready_timeout: None,
+            terminate_on_ready: false,
         })
         .collect();
 
@@ -3660,7 +3878,9 @@ async fn given_config_file_with_mixed_thresholds(
         BudgetOverride {
             noise_threshold: None,
             noise_policy: None,
-            threshold: Some(100_000.0),
+            threshold: Some(BudgetThreshold::Fixed(100_000.0)),
+            threshold_min: None,
+            threshold_max: None,
             direction: None,
             warn_factor: Some(0.0),
             statistic: None,
@@ -3678,6 +3898,16 @@ async fn given_config_file_with_mixed_thresholds(
         budgets: Some(lenient_budgets),
 
         scaling: None,
+        matrix: None,
+        matrix_overrides: None,
+        service: None,
+        tags: Vec::new(),
+        paths: Vec::new(),
+        always: false,
+        load: None,
+        ready: None,
+        ready_timeout: None,
+        terminate_on_ready: false,
     });
 
     // Default threshold=0.0 makes regressions fail unless overridden
@@ -3693,6 +3923,8 @@ async fn given_config_file_with_mixed_thresholds(
             baseline_dir: Some("baselines".to_string()),
             baseline_pattern: None,
             markdown_template: None,
+            baseline_history_dir: None,
+            merge_base: None,
         },
         baseline_server: BaselineServerConfig::default(),
         decision_policy: perfgate_types::DecisionPolicyConfig::default(),
@@ -3700,6 +3932,8 @@ async fn given_config_file_with_mixed_thresholds(
         ratchet: None,
         scenarios: Vec::new(),
         benches,
+        extends: Vec::new(),
+        profiles: Default::default(),
     };
 
     let config_path = world.temp_path().join("perfgate.toml");
@@ -4370,6 +4604,10 @@ async fn given_run_receipt_for_export(world: &mut PerfgateWorld, bench_name: Str
                 memory_bytes: None,
                 hostname_hash: None,
             },
+            profile: None,
+            concurrency: None,
+            interleave: None,
+            noise_warnings: Vec::new(),
         },
         bench: BenchMeta {
             name: bench_name,
@@ -4379,6 +4617,8 @@ async fn given_run_receipt_for_export(world: &mut PerfgateWorld, bench_name: Str
             warmup: 0,
             work_units: None,
             timeout_ms: None,
+            service: None,
+            load: None,
         },
         samples: vec![Sample {
             wall_ms: 100,
@@ -4396,6 +4636,19 @@ async fn given_run_receipt_for_export(world: &mut PerfgateWorld, bench_name: Str
             binary_bytes: None,
             stdout: None,
             stderr: None,
+            latency_p50_us: None,
+            latency_p95_us: None,
+            latency_p99_us: None,
+            error_rate: None,
+            throughput_per_s: None,
+            time_to_ready_ms: None,
+            instructions: None,
+            estimated_cycles: None,
+            l1_hits: None,
+            ll_hits: None,
+            ram_hits: None,
+            alloc_bytes: None,
+            allocs: None,
         }],
         stats: Stats {
             wall_ms: U64Summary::new(100, 100, 100),
@@ -4409,6 +4662,19 @@ async fn given_run_receipt_for_export(world: &mut PerfgateWorld, bench_name: Str
             energy_uj: None,
             binary_bytes: None,
             throughput_per_s: None,
+            latency_p50_us: None,
+            latency_p95_us: None,
+            latency_p99_us: None,
+            error_rate: None,
+            time_to_ready_ms: None,
+            instructions: None,
+            estimated_cycles: None,
+            l1_hits: None,
+            ll_hits: None,
+            ram_hits: None,
+            alloc_bytes: None,
+            allocs: None,
+            custom: Default::default(),
         },
     }));
 }
@@ -4494,14 +4760,18 @@ async fn given_compare_receipt_for_render(world: &mut PerfgateWorld, status: Str
             warmup: 0,
             work_units: None,
             timeout_ms: None,
+            service: None,
+            load: None,
         },
         baseline_ref: CompareRef {
             path: None,
             run_id: None,
+            profile: None,
         },
         current_ref: CompareRef {
             path: None,
             run_id: None,
+            profile: None,
         },
         budgets: BTreeMap::new(),
         deltas,
@@ -4636,6 +4906,9 @@ async fn given_perfgate_report(world: &mut PerfgateWorld, status: String) {
         },
         complexity: None,
         profile_path: None,
+        confirmation: None,
+        skip_reason: None,
+        baseline_selection: None,
     }));
 }
 
@@ -4800,6 +5073,7 @@ async fn given_budget_direction_lower(
         threshold,
         warn_threshold,
         direction: perfgate_types::Direction::Lower,
+        auto_threshold: None,
     });
 }
 
@@ -4815,6 +5089,7 @@ async fn given_budget_direction_higher(
         threshold,
         warn_threshold,
         direction: perfgate_types::Direction::Higher,
+        auto_threshold: None,
     });
 }
 
@@ -34,6 +34,19 @@ fn make_sample(wall_ms: u64) -> Sample {
         binary_bytes: None,
         stdout: None,
         stderr: None,
+        latency_p50_us: None,
+        latency_p95_us: None,
+        latency_p99_us: None,
+        error_rate: None,
+        throughput_per_s: None,
+        time_to_ready_ms: None,
+        instructions: None,
+        estimated_cycles: None,
+        l1_hits: None,
+        ll_hits: None,
+        ram_hits: None,
+        alloc_bytes: None,
+        allocs: None,
     }
 }
 
@@ -58,6 +71,10 @@ fn make_run_receipt(samples: Vec<u64>) -> RunReceipt {
                 memory_bytes: None,
                 hostname_hash: None,
             },
+            profile: None,
+            concurrency: None,
+            interleave: None,
+            noise_warnings: Vec::new(),
         },
         bench: BenchMeta {
             name: "test-bench".to_string(),
@@ -67,6 +84,8 @@ fn make_run_receipt(samples: Vec<u64>) -> RunReceipt {
             warmup: 0,
             work_units: None,
             timeout_ms: None,
+            service: None,
+            load: None,
         },
         samples: sample_vec,
         stats,
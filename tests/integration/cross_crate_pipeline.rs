@@ -10,10 +10,11 @@ use perfgate::app::{CompareRequest, CompareUseCase};
 use perfgate::domain::{compare_runs, compute_stats, derive_report};
 use perfgate::presentation::render::render_markdown;
 use perfgate_types::{
-    BenchMeta, Budget, COMPARE_SCHEMA_V1, CompareReceipt, CompareRef, ConfigFile, Direction,
-    HostInfo, HostMismatchPolicy, Metric, MetricStatistic, MetricStatus, PAIRED_SCHEMA_V1,
-    PairedBenchMeta, PairedDiffSummary, PairedRunReceipt, PairedSample, PairedSampleHalf,
-    PairedStats, RUN_SCHEMA_V1, RunMeta, RunReceipt, Sample, ToolInfo, U64Summary, VerdictStatus,
+    BenchMeta, Budget, BudgetThreshold, COMPARE_SCHEMA_V1, CompareReceipt, CompareRef, ConfigFile,
+    Direction, HostInfo, HostMismatchPolicy, Metric, MetricStatistic, MetricStatus,
+    PAIRED_SCHEMA_V1, PairedBenchMeta, PairedDiffSummary, PairedRunReceipt, PairedSample,
+    PairedSampleHalf, PairedStats, RUN_SCHEMA_V1, RunMeta, RunReceipt, Sample, ToolInfo,
+    U64Summary, VerdictStatus,
 };
 use std::collections::BTreeMap;
 
@@ -38,6 +39,19 @@ fn sample(wall_ms: u64) -> Sample {
         binary_bytes: None,
         stdout: None,
         stderr: None,
+        latency_p50_us: None,
+        latency_p95_us: None,
+        latency_p99_us: None,
+        error_rate: None,
+        throughput_per_s: None,
+        time_to_ready_ms: None,
+        instructions: None,
+        estimated_cycles: None,
+        l1_hits: None,
+        ll_hits: None,
+        ram_hits: None,
+        alloc_bytes: None,
+        allocs: None,
     }
 }
 
@@ -60,6 +74,10 @@ fn run_receipt(name: &str, samples: Vec<Sample>) -> RunReceipt {
                 memory_bytes: Some(16 * 1024 * 1024 * 1024),
                 hostname_hash: None,
             },
+            profile: None,
+            concurrency: None,
+            interleave: None,
+            noise_warnings: Vec::new(),
         },
         bench: BenchMeta {
             name: name.to_string(),
@@ -69,6 +87,8 @@ fn run_receipt(name: &str, samples: Vec<Sample>) -> RunReceipt {
             warmup: 0,
             work_units: None,
             timeout_ms: None,
+            service: None,
+            load: None,
         },
         samples,
         stats,
@@ -137,6 +157,19 @@ fn types_to_domain_all_optional_metrics_preserved() {
         binary_bytes: Some(4096),
         stdout: None,
         stderr: None,
+        latency_p50_us: None,
+        latency_p95_us: None,
+        latency_p99_us: None,
+        error_rate: None,
+        throughput_per_s: None,
+        time_to_ready_ms: None,
+        instructions: None,
+        estimated_cycles: None,
+        l1_hits: None,
+        ll_hits: None,
+        ram_hits: None,
+        alloc_bytes: None,
+        allocs: None,
     };
 
     let baseline = run_receipt("full-metrics", vec![full_sample(100), full_sample(100)]);
@@ -222,10 +255,12 @@ fn domain_to_app_compare_use_case_then_markdown() {
         baseline_ref: CompareRef {
             path: Some("baseline.json".to_string()),
             run_id: None,
+            profile: None,
         },
         current_ref: CompareRef {
             path: Some("current.json".to_string()),
             run_id: None,
+            profile: None,
         },
         tool: ToolInfo {
             name: "perfgate".to_string(),
@@ -267,10 +302,12 @@ fn domain_derive_report_produces_findings_for_failures() {
         baseline_ref: CompareRef {
             path: None,
             run_id: None,
+            profile: None,
         },
         current_ref: CompareRef {
             path: None,
             run_id: None,
+            profile: None,
         },
         tool: ToolInfo {
             name: "perfgate".to_string(),
@@ -313,10 +350,12 @@ fn domain_to_app_pass_produces_no_findings() {
         baseline_ref: CompareRef {
             path: None,
             run_id: None,
+            profile: None,
         },
         current_ref: CompareRef {
             path: None,
             run_id: None,
+            profile: None,
         },
         tool: ToolInfo {
             name: "perfgate".to_string(),
@@ -383,10 +422,12 @@ fn compare_receipt_json_roundtrip() {
         baseline_ref: CompareRef {
             path: Some("base.json".to_string()),
             run_id: None,
+            profile: None,
         },
         current_ref: CompareRef {
             path: Some("cur.json".to_string()),
             run_id: None,
+            profile: None,
         },
         tool: ToolInfo {
             name: "perfgate".to_string(),
@@ -430,6 +471,10 @@ fn paired_receipt_json_roundtrip() {
                 memory_bytes: Some(16 * 1024 * 1024 * 1024),
                 hostname_hash: Some("abc123".to_string()),
             },
+            profile: None,
+            concurrency: None,
+            interleave: None,
+            noise_warnings: Vec::new(),
         },
         bench: PairedBenchMeta {
             name: "paired-bench".to_string(),
@@ -653,7 +698,10 @@ direction = "lower"
     let mut budgets = BTreeMap::new();
     if let Some(ref overrides) = bench.budgets {
         for (metric, ovr) in overrides {
-            let threshold = ovr.threshold.unwrap_or(global_threshold);
+            let threshold = ovr
+                .threshold
+                .and_then(BudgetThreshold::fixed)
+                .unwrap_or(global_threshold);
             let warn_factor = ovr.warn_factor.unwrap_or(global_warn_factor);
             let direction = ovr.direction.unwrap_or(Direction::Lower);
             budgets.insert(
@@ -664,6 +712,7 @@ direction = "lower"
                     threshold,
                     warn_threshold: threshold * warn_factor,
                     direction,
+                    auto_threshold: None,
                 },
             );
         }
@@ -785,7 +834,7 @@ threshold = 0.30
         .unwrap()
         .get(&Metric::WallMs)
         .unwrap();
-    let threshold_a = ovr_a.threshold.unwrap();
+    let threshold_a = ovr_a.threshold.and_then(BudgetThreshold::fixed).unwrap();
     assert!((threshold_a - 0.05).abs() < f64::EPSILON);
 
     // Resolve budgets for bench-b (loose threshold)
@@ -796,7 +845,7 @@ threshold = 0.30
         .unwrap()
         .get(&Metric::WallMs)
         .unwrap();
-    let threshold_b = ovr_b.threshold.unwrap();
+    let threshold_b = ovr_b.threshold.and_then(BudgetThreshold::fixed).unwrap();
     assert!((threshold_b - 0.30).abs() < f64::EPSILON);
 
     // Same 10% regression: bench-a fails, bench-b passes
@@ -30,6 +30,10 @@ fn make_run_receipt() -> RunReceipt {
                 memory_bytes: None,
                 hostname_hash: None,
             },
+            profile: None,
+            concurrency: None,
+            interleave: None,
+            noise_warnings: Vec::new(),
         },
         bench: BenchMeta {
             name: "test-bench".to_string(),
@@ -39,6 +43,8 @@ fn make_run_receipt() -> RunReceipt {
             warmup: 0,
             work_units: None,
             timeout_ms: None,
+            service: None,
+            load: None,
         },
         samples: vec![
             Sample {
@@ -57,6 +63,19 @@ fn make_run_receipt() -> RunReceipt {
                 binary_bytes: None,
                 stdout: None,
                 stderr: None,
+                latency_p50_us: None,
+                latency_p95_us: None,
+                latency_p99_us: None,
+                error_rate: None,
+                throughput_per_s: None,
+                time_to_ready_ms: None,
+                instructions: None,
+                estimated_cycles: None,
+                l1_hits: None,
+                ll_hits: None,
+                ram_hits: None,
+                alloc_bytes: None,
+                allocs: None,
             },
             Sample {
                 wall_ms: 102,
@@ -74,6 +93,19 @@ fn make_run_receipt() -> RunReceipt {
                 binary_bytes: None,
                 stdout: None,
                 stderr: None,
+                latency_p50_us: None,
+                latency_p95_us: None,
+                latency_p99_us: None,
+                error_rate: None,
+                throughput_per_s: None,
+                time_to_ready_ms: None,
+                instructions: None,
+                estimated_cycles: None,
+                l1_hits: None,
+                ll_hits: None,
+                ram_hits: None,
+                alloc_bytes: None,
+                allocs: None,
             },
             Sample {
                 wall_ms: 98,
@@ -91,6 +123,19 @@ fn make_run_receipt() -> RunReceipt {
                 binary_bytes: None,
                 stdout: None,
                 stderr: None,
+                latency_p50_us: None,
+                latency_p95_us: None,
+                latency_p99_us: None,
+                error_rate: None,
+                throughput_per_s: None,
+                time_to_ready_ms: None,
+                instructions: None,
+                estimated_cycles: None,
+                l1_hits: None,
+                ll_hits: None,
+                ram_hits: None,
+                alloc_bytes: None,
+                allocs: None,
             },
         ],
         stats: Stats {
@@ -105,6 +150,19 @@ fn make_run_receipt() -> RunReceipt {
             energy_uj: None,
             binary_bytes: None,
             throughput_per_s: None,
+            latency_p50_us: None,
+            latency_p95_us: None,
+            latency_p99_us: None,
+            error_rate: None,
+            time_to_ready_ms: None,
+            instructions: None,
+            estimated_cycles: None,
+            l1_hits: None,
+            ll_hits: None,
+            ram_hits: None,
+            alloc_bytes: None,
+            allocs: None,
+            custom: Default::default(),
         },
     }
 }
@@ -171,14 +229,18 @@ fn make_compare_receipt(status: MetricStatus) -> CompareReceipt {
             warmup: 0,
             work_units: None,
             timeout_ms: None,
+            service: None,
+            load: None,
         },
         baseline_ref: CompareRef {
             path: Some("baseline.json".to_string()),
             run_id: None,
+            profile: None,
         },
         current_ref: CompareRef {
             path: Some("current.json".to_string()),
             run_id: None,
+            profile: None,
         },
         budgets,
         deltas,
@@ -399,14 +461,18 @@ fn multiple_metrics_in_compare_flow() {
             warmup: 0,
             work_units: None,
             timeout_ms: None,
+            service: None,
+            load: None,
         },
         baseline_ref: CompareRef {
             path: None,
             run_id: None,
+            profile: None,
         },
         current_ref: CompareRef {
             path: None,
             run_id: None,
+            profile: None,
         },
         budgets,
         deltas,
@@ -33,6 +33,19 @@ fn make_sample(wall_ms: u64) -> Sample {
         binary_bytes: None,
         stdout: None,
         stderr: None,
+        latency_p50_us: None,
+        latency_p95_us: None,
+        latency_p99_us: None,
+        error_rate: None,
+        throughput_per_s: None,
+        time_to_ready_ms: None,
+        instructions: None,
+        estimated_cycles: None,
+        l1_hits: None,
+        ll_hits: None,
+        ram_hits: None,
+        alloc_bytes: None,
+        allocs: None,
     }
 }
 
@@ -55,6 +68,10 @@ fn make_run_receipt_from_samples(samples: Vec<Sample>) -> RunReceipt {
                 memory_bytes: None,
                 hostname_hash: None,
             },
+            profile: None,
+            concurrency: None,
+            interleave: None,
+            noise_warnings: Vec::new(),
         },
         bench: BenchMeta {
             name: "pipeline-bench".to_string(),
@@ -64,6 +81,8 @@ fn make_run_receipt_from_samples(samples: Vec<Sample>) -> RunReceipt {
             warmup: 0,
             work_units: None,
             timeout_ms: None,
+            service: None,
+            load: None,
         },
         samples,
         stats,
@@ -177,6 +196,19 @@ fn full_pipeline_multiple_metrics() {
             binary_bytes: None,
             stdout: None,
             stderr: None,
+            latency_p50_us: None,
+            latency_p95_us: None,
+            latency_p99_us: None,
+            error_rate: None,
+            throughput_per_s: None,
+            time_to_ready_ms: None,
+            instructions: None,
+            estimated_cycles: None,
+            l1_hits: None,
+            ll_hits: None,
+            ram_hits: None,
+            alloc_bytes: None,
+            allocs: None,
         },
         Sample {
             wall_ms: 100,
@@ -194,6 +226,19 @@ fn full_pipeline_multiple_metrics() {
             binary_bytes: None,
             stdout: None,
             stderr: None,
+            latency_p50_us: None,
+            latency_p95_us: None,
+            latency_p99_us: None,
+            error_rate: None,
+            throughput_per_s: None,
+            time_to_ready_ms: None,
+            instructions: None,
+            estimated_cycles: None,
+            l1_hits: None,
+            ll_hits: None,
+            ram_hits: None,
+            alloc_bytes: None,
+            allocs: None,
         },
     ];
     let current_samples: Vec<Sample> = vec![
@@ -213,6 +258,19 @@ fn full_pipeline_multiple_metrics() {
             binary_bytes: None,
             stdout: None,
             stderr: None,
+            latency_p50_us: None,
+            latency_p95_us: None,
+            latency_p99_us: None,
+            error_rate: None,
+            throughput_per_s: None,
+            time_to_ready_ms: None,
+            instructions: None,
+            estimated_cycles: None,
+            l1_hits: None,
+            ll_hits: None,
+            ram_hits: None,
+            alloc_bytes: None,
+            allocs: None,
         },
         Sample {
             wall_ms: 105,
@@ -230,6 +288,19 @@ fn full_pipeline_multiple_metrics() {
             binary_bytes: None,
             stdout: None,
             stderr: None,
+            latency_p50_us: None,
+            latency_p95_us: None,
+            latency_p99_us: None,
+            error_rate: None,
+            throughput_per_s: None,
+            time_to_ready_ms: None,
+            instructions: None,
+            estimated_cycles: None,
+            l1_hits: None,
+            ll_hits: None,
+            ram_hits: None,
+            alloc_bytes: None,
+            allocs: None,
         },
     ];
 
@@ -320,6 +391,9 @@ fn sensor_report_from_run_receipt_pass() {
         },
         complexity: None,
         profile_path: None,
+        confirmation: None,
+        skip_reason: None,
+        baseline_selection: None,
     };
 
     let builder = SensorReportBuilder::new(receipt.tool.clone(), receipt.run.started_at.clone())
@@ -369,6 +443,9 @@ fn sensor_report_serializes_to_valid_json() {
         },
         complexity: None,
         profile_path: None,
+        confirmation: None,
+        skip_reason: None,
+        baseline_selection: None,
     };
 
     let builder = SensorReportBuilder::new(receipt.tool.clone(), receipt.run.started_at.clone())
@@ -457,6 +534,19 @@ fn export_run_receipt_csv_optional_metrics_present() {
         binary_bytes: None,
         stdout: None,
         stderr: None,
+        latency_p50_us: None,
+        latency_p95_us: None,
+        latency_p99_us: None,
+        error_rate: None,
+        throughput_per_s: None,
+        time_to_ready_ms: None,
+        instructions: None,
+        estimated_cycles: None,
+        l1_hits: None,
+        ll_hits: None,
+        ram_hits: None,
+        alloc_bytes: None,
+        allocs: None,
     }];
     let receipt = make_run_receipt_from_samples(samples);
 
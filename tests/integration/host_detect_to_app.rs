@@ -24,6 +24,10 @@ fn make_run_receipt(host: HostInfo) -> RunReceipt {
             started_at: "2024-01-01T00:00:00Z".to_string(),
             ended_at: "2024-01-01T00:00:01Z".to_string(),
             host,
+            profile: None,
+            concurrency: None,
+            interleave: None,
+            noise_warnings: Vec::new(),
         },
         bench: BenchMeta {
             name: "test-bench".to_string(),
@@ -33,6 +37,8 @@ fn make_run_receipt(host: HostInfo) -> RunReceipt {
             warmup: 0,
             work_units: None,
             timeout_ms: None,
+            service: None,
+            load: None,
         },
         samples: vec![],
         stats: Stats {
@@ -47,6 +53,19 @@ fn make_run_receipt(host: HostInfo) -> RunReceipt {
             energy_uj: None,
             binary_bytes: None,
             throughput_per_s: None,
+            latency_p50_us: None,
+            latency_p95_us: None,
+            latency_p99_us: None,
+            error_rate: None,
+            time_to_ready_ms: None,
+            instructions: None,
+            estimated_cycles: None,
+            l1_hits: None,
+            ll_hits: None,
+            ram_hits: None,
+            alloc_bytes: None,
+            allocs: None,
+            custom: Default::default(),
         },
     }
 }
@@ -209,10 +228,12 @@ fn app_compare_with_host_mismatch_warn_policy() {
         baseline_ref: CompareRef {
             path: None,
             run_id: None,
+            profile: None,
         },
         current_ref: CompareRef {
             path: None,
             run_id: None,
+            profile: None,
         },
         tool: ToolInfo {
             name: "perfgate".to_string(),
@@ -252,10 +273,12 @@ fn app_compare_with_host_mismatch_error_policy() {
         baseline_ref: CompareRef {
             path: None,
             run_id: None,
+            profile: None,
         },
         current_ref: CompareRef {
             path: None,
             run_id: None,
+            profile: None,
         },
         tool: ToolInfo {
             name: "perfgate".to_string(),
@@ -296,10 +319,12 @@ fn app_compare_with_host_mismatch_ignore_policy() {
         baseline_ref: CompareRef {
             path: None,
             run_id: None,
+            profile: None,
         },
         current_ref: CompareRef {
             path: None,
             run_id: None,
+            profile: None,
         },
         tool: ToolInfo {
             name: "perfgate".to_string(),
@@ -332,10 +357,12 @@ fn app_compare_matching_hosts_no_mismatch() {
         baseline_ref: CompareRef {
             path: None,
             run_id: None,
+            profile: None,
         },
         current_ref: CompareRef {
             path: None,
             run_id: None,
+            profile: None,
         },
         tool: ToolInfo {
             name: "perfgate".to_string(),
@@ -43,6 +43,9 @@ fn make_pass_report() -> PerfgateReport {
         },
         complexity: None,
         profile_path: None,
+        confirmation: None,
+        skip_reason: None,
+        baseline_selection: None,
     }
 }
 
@@ -83,6 +86,9 @@ fn make_fail_report() -> PerfgateReport {
         },
         complexity: None,
         profile_path: None,
+        confirmation: None,
+        skip_reason: None,
+        baseline_selection: None,
     }
 }
 
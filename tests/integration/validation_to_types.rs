@@ -4,8 +4,8 @@
 //! with perfgate-types, including config validation with bench names.
 
 use perfgate_types::{
-    BaselineServerConfig, BenchConfigFile, ConfigFile, DefaultsConfig, validate_bench_name,
-    validation::ValidationError,
+    BaselineServerConfig, BenchConfigFile, BudgetThreshold, ConfigFile, DefaultsConfig,
+    validate_bench_name, validation::ValidationError,
 };
 
 #[test]
@@ -52,7 +52,19 @@ fn config_file_validates_bench_names() {
             budgets: None,
 
             scaling: None,
+            matrix: None,
+            matrix_overrides: None,
+            service: None,
+            tags: Vec::new(),
+            paths: Vec::new(),
+            always: false,
+            load: None,
+            ready: None,
+            ready_timeout: None,
+            terminate_on_ready: false,
         }],
+        extends: Vec::new(),
+        profiles: Default::default(),
     };
 
     assert!(config.validate().is_ok());
@@ -73,13 +85,25 @@ fn config_file_rejects_invalid_bench_names() {
             work: None,
             timeout: None,
             command: vec!["echo".to_string()],
+            tags: Vec::new(),
+            paths: Vec::new(),
+            always: false,
             repeat: None,
             warmup: None,
             metrics: None,
             budgets: None,
 
             scaling: None,
+            matrix: None,
+            matrix_overrides: None,
+            service: None,
+            load: None,
+            ready: None,
+            ready_timeout: None,
+            terminate_on_ready: false,
         }],
+        extends: Vec::new(),
+        profiles: Default::default(),
     };
 
     assert!(config.validate().is_err());
@@ -107,6 +131,16 @@ fn multiple_benches_all_validated() {
                 budgets: None,
 
                 scaling: None,
+                matrix: None,
+                matrix_overrides: None,
+                service: None,
+                tags: Vec::new(),
+                paths: Vec::new(),
+                always: false,
+                load: None,
+                ready: None,
+                ready_timeout: None,
+                terminate_on_ready: false,
             },
             BenchConfigFile {
                 name: "also-valid".to_string(),
@@ -120,8 +154,20 @@ fn multiple_benches_all_validated() {
                 budgets: None,
 
                 scaling: None,
+                matrix: None,
+                matrix_overrides: None,
+                service: None,
+                tags: Vec::new(),
+                paths: Vec::new(),
+                always: false,
+                load: None,
+                ready: None,
+                ready_timeout: None,
+                terminate_on_ready: false,
             },
         ],
+        extends: Vec::new(),
+        profiles: Default::default(),
     };
 
     assert!(config.validate().is_ok());
@@ -149,6 +195,16 @@ fn validation_fails_on_first_invalid_bench() {
                 budgets: None,
 
                 scaling: None,
+                matrix: None,
+                matrix_overrides: None,
+                service: None,
+                tags: Vec::new(),
+                paths: Vec::new(),
+                always: false,
+                load: None,
+                ready: None,
+                ready_timeout: None,
+                terminate_on_ready: false,
             },
             BenchConfigFile {
                 name: "Invalid".to_string(),
@@ -162,8 +218,20 @@ fn validation_fails_on_first_invalid_bench() {
                 budgets: None,
 
                 scaling: None,
+                matrix: None,
+                matrix_overrides: None,
+                service: None,
+                tags: Vec::new(),
+                paths: Vec::new(),
+                always: false,
+                load: None,
+                ready: None,
+                ready_timeout: None,
+                terminate_on_ready: false,
             },
         ],
+        extends: Vec::new(),
+        profiles: Default::default(),
     };
 
     assert!(config.validate().is_err());
@@ -261,6 +329,8 @@ fn config_empty_benches_is_valid() {
         ratchet: None,
         scenarios: Vec::new(),
         benches: vec![],
+        extends: Vec::new(),
+        profiles: Default::default(),
     };
 
     assert!(config.validate().is_ok());
@@ -292,6 +362,16 @@ fn config_duplicate_bench_names_passes_validation() {
                 budgets: None,
 
                 scaling: None,
+                matrix: None,
+                matrix_overrides: None,
+                service: None,
+                tags: Vec::new(),
+                paths: Vec::new(),
+                always: false,
+                load: None,
+                ready: None,
+                ready_timeout: None,
+                terminate_on_ready: false,
             },
             BenchConfigFile {
                 name: "same-name".to_string(),
@@ -305,8 +385,20 @@ fn config_duplicate_bench_names_passes_validation() {
                 budgets: None,
 
                 scaling: None,
+                matrix: None,
+                matrix_overrides: None,
+                service: None,
+                tags: Vec::new(),
+                paths: Vec::new(),
+                always: false,
+                load: None,
+                ready: None,
+                ready_timeout: None,
+                terminate_on_ready: false,
             },
         ],
+        extends: Vec::new(),
+        profiles: Default::default(),
     };
 
     assert!(config.validate().is_ok());
@@ -372,7 +464,7 @@ threshold = -0.1
             .get(&perfgate_types::Metric::WallMs)
             .unwrap()
             .threshold,
-        Some(-0.1)
+        Some(BudgetThreshold::Fixed(-0.1))
     );
 }
 
@@ -393,7 +485,7 @@ threshold = 2.0
             .get(&perfgate_types::Metric::WallMs)
             .unwrap()
             .threshold,
-        Some(2.0)
+        Some(BudgetThreshold::Fixed(2.0))
     );
 }
 
@@ -442,7 +534,19 @@ fn config_empty_bench_name_rejected() {
             budgets: None,
 
             scaling: None,
+            matrix: None,
+            matrix_overrides: None,
+            service: None,
+            tags: Vec::new(),
+            paths: Vec::new(),
+            always: false,
+            load: None,
+            ready: None,
+            ready_timeout: None,
+            terminate_on_ready: false,
         }],
+        extends: Vec::new(),
+        profiles: Default::default(),
     };
 
     let err = config.validate().unwrap_err();
@@ -471,7 +575,19 @@ fn config_path_traversal_variants_rejected() {
                 budgets: None,
 
                 scaling: None,
+                matrix: None,
+                matrix_overrides: None,
+                service: None,
+                tags: Vec::new(),
+                paths: Vec::new(),
+                always: false,
+                load: None,
+                ready: None,
+                ready_timeout: None,
+                terminate_on_ready: false,
             }],
+            extends: Vec::new(),
+            profiles: Default::default(),
         };
 
         assert!(
@@ -21,7 +21,7 @@ const BADGE_ENDPOINT_TARGET_DIR: &str = "target/xtask/badges";
 const RIPR_PR_DIR: &str = "target/ripr/pr";
 const RIPR_REVIEW_DIR: &str = "target/ripr/review";
 
-const SCHEMA_FILES: [&str; 15] = [
+const SCHEMA_FILES: [&str; 17] = [
     "perfgate.run.v1.schema.json",
     "perfgate.compare.v1.schema.json",
     "perfgate.probe.v1.schema.json",
@@ -36,6 +36,8 @@ const SCHEMA_FILES: [&str; 15] = [
     "perfgate.aggregate.v1.schema.json",
     "perfgate.ratchet.v1.schema.json",
     "perfgate.repair_context.v1.schema.json",
+    "perfgate.run_bundle.v1.schema.json",
+    "perfgate.suite_compare.v1.schema.json",
     "sensor.report.v1.schema.json",
 ];
 
@@ -3692,9 +3694,21 @@ fn cmd_schema(out_dir: &PathBuf) -> anyhow::Result<()> {
         schema_for!(perfgate_types::RepairContextReceipt),
     )?;
 
+    write_schema(
+        out_dir,
+        SCHEMA_FILES[14],
+        schema_for!(perfgate_types::RunBundle),
+    )?;
+
+    write_schema(
+        out_dir,
+        SCHEMA_FILES[15],
+        schema_for!(perfgate_types::SuiteCompareReceipt),
+    )?;
+
     // Sensor report schema is vendored from contracts/, not generated.
     let vendored_schema = PathBuf::from("contracts/schemas/sensor.report.v1.schema.json");
-    let dest = out_dir.join(SCHEMA_FILES[14]);
+    let dest = out_dir.join(SCHEMA_FILES[16]);
     fs::copy(&vendored_schema, &dest).with_context(|| {
         format!(
             "copy vendored schema {} -> {}",
@@ -3797,6 +3811,12 @@ fn cmd_schema_compat(fixtures_dir: &Path) -> anyhow::Result<()> {
             "perfgate.probe_compare.v1" => {
                 serde_json::from_value::<perfgate_types::ProbeCompareReceipt>(value).map(|_| ())
             }
+            "perfgate.run_bundle.v1" => {
+                serde_json::from_value::<perfgate_types::RunBundle>(value).map(|_| ())
+            }
+            "perfgate.suite_compare.v1" => {
+                serde_json::from_value::<perfgate_types::SuiteCompareReceipt>(value).map(|_| ())
+            }
             "perfgate.scenario.v1" => {
                 serde_json::from_value::<perfgate_types::ScenarioReceipt>(value).map(|_| ())
             }
@@ -5800,6 +5820,16 @@ fn validate_versioned_json_example(
                 .context("deserialize perfgate.probe_compare.v1 example")?;
             Ok(Some(perfgate_types::PROBE_COMPARE_SCHEMA_V1))
         }
+        Some(perfgate_types::RUN_BUNDLE_SCHEMA_V1) => {
+            serde_json::from_value::<perfgate_types::RunBundle>(value)
+                .context("deserialize perfgate.run_bundle.v1 example")?;
+            Ok(Some(perfgate_types::RUN_BUNDLE_SCHEMA_V1))
+        }
+        Some(perfgate_types::SUITE_COMPARE_SCHEMA_V1) => {
+            serde_json::from_value::<perfgate_types::SuiteCompareReceipt>(value)
+                .context("deserialize perfgate.suite_compare.v1 example")?;
+            Ok(Some(perfgate_types::SUITE_COMPARE_SCHEMA_V1))
+        }
         Some(perfgate_types::SCENARIO_SCHEMA_V1) => {
             serde_json::from_value::<perfgate_types::ScenarioReceipt>(value)
                 .context("deserialize perfgate.scenario.v1 example")?;